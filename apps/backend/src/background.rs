@@ -8,7 +8,7 @@ use tracing::instrument;
 use crate::{
     entities::{metadata, seen},
     fitness::resolver::ExerciseService,
-    importer::{DeployImportJobInput, ImporterService},
+    importer::{DeployImportJobInput, ImportFailedItemOverride, ImporterService},
     miscellaneous::resolver::MiscellaneousService,
     models::fitness::Exercise,
 };
@@ -61,6 +61,12 @@ pub async fn user_jobs(_information: ScheduledJob, ctx: JobContext) -> Result<()
         .cleanup_user_and_metadata_association()
         .await
         .unwrap();
+    tracing::trace!("Flagging seen items that are likely duplicates");
+    ctx.data::<Arc<MiscellaneousService>>()
+        .unwrap()
+        .detect_duplicate_seen_history()
+        .await
+        .unwrap();
     tracing::trace!("Removing old user summaries and regenerating them");
     ctx.data::<Arc<MiscellaneousService>>()
         .unwrap()
@@ -70,6 +76,28 @@ pub async fn user_jobs(_information: ScheduledJob, ctx: JobContext) -> Result<()
     Ok(())
 }
 
+#[instrument(skip(_information, ctx))]
+pub async fn maintenance_jobs(_information: ScheduledJob, ctx: JobContext) -> Result<(), JobError> {
+    tracing::trace!("Performing database maintenance");
+    ctx.data::<Arc<MiscellaneousService>>()
+        .unwrap()
+        .perform_database_maintenance()
+        .await
+        .unwrap();
+    Ok(())
+}
+
+#[instrument(skip(_information, ctx))]
+pub async fn fitness_jobs(_information: ScheduledJob, ctx: JobContext) -> Result<(), JobError> {
+    tracing::trace!("Sending reminders for workouts due today");
+    ctx.data::<Arc<ExerciseService>>()
+        .unwrap()
+        .send_due_workout_reminders()
+        .await
+        .unwrap();
+    Ok(())
+}
+
 #[instrument(skip(_information, ctx))]
 pub async fn yank_integrations_data(
     _information: ScheduledJob,
@@ -81,6 +109,12 @@ pub async fn yank_integrations_data(
         .yank_integrations_data()
         .await
         .unwrap();
+    tracing::trace!("Syncing activities from Strava for all users");
+    ctx.data::<Arc<ExerciseService>>()
+        .unwrap()
+        .sync_strava_activities()
+        .await
+        .unwrap();
     Ok(())
 }
 
@@ -89,12 +123,19 @@ pub async fn yank_integrations_data(
 #[derive(Debug, Deserialize, Serialize)]
 pub enum ApplicationJob {
     ImportMedia(i32, DeployImportJobInput),
+    ResumeImportMedia(i32, i32),
+    RetryImportFailedItems(i32, i32, Vec<ImportFailedItemOverride>),
     UserCreated(i32),
     RecalculateUserSummary(i32),
     UpdateMetadata(metadata::Model),
+    LocalizeMetadata(i32, String),
     UpdateExerciseJob(Exercise),
+    SyncWgerExercises,
     AfterMediaSeen(seen::Model),
     RecalculateCalendarEvents,
+    EnrichMetadataAwards(i32),
+    DatabaseMaintenance,
+    RefreshUserComingSoon(i32),
 }
 
 impl Job for ApplicationJob {
@@ -117,6 +158,18 @@ pub async fn perform_application_job(
                 .await
                 .unwrap();
         }
+        ApplicationJob::ResumeImportMedia(user_id, report_id) => {
+            importer_service
+                .resume_media_import(user_id, report_id)
+                .await
+                .unwrap();
+        }
+        ApplicationJob::RetryImportFailedItems(user_id, report_id, overrides) => {
+            importer_service
+                .retry_failed_import_items(user_id, report_id, overrides)
+                .await
+                .unwrap();
+        }
         ApplicationJob::UserCreated(user_id) => {
             misc_service.user_created_job(user_id).await.unwrap();
             misc_service.user_created_job(user_id).await.unwrap();
@@ -142,15 +195,39 @@ pub async fn perform_application_job(
                 }
             }
         }
+        ApplicationJob::LocalizeMetadata(metadata_id, language) => {
+            misc_service
+                .localize_metadata(metadata_id, language)
+                .await
+                .unwrap();
+        }
         ApplicationJob::UpdateExerciseJob(exercise) => {
             exercise_service.update_exercise(exercise).await.unwrap();
         }
+        ApplicationJob::SyncWgerExercises => {
+            exercise_service.sync_wger_exercises().await.unwrap();
+        }
         ApplicationJob::AfterMediaSeen(seen) => {
             misc_service.after_media_seen_tasks(seen).await.unwrap();
         }
         ApplicationJob::RecalculateCalendarEvents => {
             misc_service.recalculate_calendar_events().await.unwrap();
         }
+        ApplicationJob::EnrichMetadataAwards(metadata_id) => {
+            misc_service
+                .enrich_metadata_awards(metadata_id)
+                .await
+                .unwrap();
+        }
+        ApplicationJob::DatabaseMaintenance => {
+            misc_service.perform_database_maintenance().await.unwrap();
+        }
+        ApplicationJob::RefreshUserComingSoon(user_id) => {
+            misc_service
+                .refresh_user_coming_soon(user_id)
+                .await
+                .unwrap();
+        }
     };
     let end = Instant::now();
     tracing::trace!("Job completed, took {}s", (end - start).as_secs());
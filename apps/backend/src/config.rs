@@ -1,10 +1,13 @@
-use std::path::PathBuf;
+use std::{fs, path::PathBuf, str::FromStr};
 
-use anyhow::Result;
-use schematic::{derive_enum, Config, ConfigEnum, ConfigLoader, ValidateError};
+use anyhow::{anyhow, bail, Result};
+use schematic::{derive_enum, Config, ConfigEnum, ConfigLoader, Format, ValidateError};
+use sea_orm::Iterable;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 use crate::{
+    migrator::{MetadataLot, MetadataSource},
     providers::{audible::AudibleService, itunes::ITunesService, tmdb::TmdbService},
     traits::{IsFeatureEnabled, MediaProviderLanguages},
     utils::PROJECT_NAME,
@@ -43,6 +46,16 @@ pub struct AnimeConfig {
 
 impl IsFeatureEnabled for AnimeConfig {}
 
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "ARTICLES_")]
+pub struct ArticleConfig {
+    /// The reading speed used to estimate how long an article takes to read.
+    #[setting(default = 238)]
+    pub words_per_minute: i32,
+}
+
+impl IsFeatureEnabled for ArticleConfig {}
+
 fn validate_audible_locale(
     value: &str,
     _partial: &PartialAudibleConfig,
@@ -75,6 +88,12 @@ pub struct AudioBookConfig {
 
 impl IsFeatureEnabled for AudioBookConfig {}
 
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "BOARD_GAMES_")]
+pub struct BoardGameConfig {}
+
+impl IsFeatureEnabled for BoardGameConfig {}
+
 derive_enum!(
     #[derive(ConfigEnum, Default)]
     pub enum OpenlibraryCoverImageSize {
@@ -90,13 +109,21 @@ derive_enum!(
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
 #[config(rename_all = "snake_case", env_prefix = "BOOKS_GOOGLE_BOOKS_")]
-pub struct GoogleBooksConfig {}
+pub struct GoogleBooksConfig {
+    /// The API key for the Google Books API. Optional, but the requests made
+    /// without one share a much lower quota.
+    pub api_key: String,
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
 #[config(rename_all = "snake_case", env_prefix = "BOOKS_OPENLIBRARY_")]
 pub struct OpenlibraryConfig {
     /// The image sizes to fetch from Openlibrary.
     pub cover_image_size: OpenlibraryCoverImageSize,
+    /// Whether to collapse search results that have the same title and authors
+    /// into a single result.
+    #[setting(default = true)]
+    pub dedupe_search_results: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
@@ -112,6 +139,27 @@ pub struct BookConfig {
 
 impl IsFeatureEnabled for BookConfig {}
 
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "COMICS_COMIC_VINE_")]
+pub struct ComicVineConfig {
+    /// The API key for the ComicVine API. **Required** to enable comics.
+    pub api_key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case")]
+pub struct ComicConfig {
+    /// Settings related to ComicVine.
+    #[setting(nested)]
+    pub comic_vine: ComicVineConfig,
+}
+
+impl IsFeatureEnabled for ComicConfig {
+    fn is_enabled(&self) -> bool {
+        !self.comic_vine.api_key.is_empty()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Config, PartialEq, Eq)]
 #[config(rename_all = "snake_case", env_prefix = "DATABASE_")]
 pub struct DatabaseConfig {
@@ -122,11 +170,199 @@ pub struct DatabaseConfig {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config, PartialEq, Eq)]
-pub struct ExerciseConfig {}
+#[config(rename_all = "snake_case", env_prefix = "EXERCISE_STRAVA_")]
+pub struct StravaConfig {
+    /// The client ID of the Strava API application. **Required** to enable the
+    /// Strava integration. [More information](/docs/guides/fitness.md)
+    pub client_id: String,
+    /// The client secret of the Strava API application. **Required** to enable
+    /// the Strava integration.
+    pub client_secret: String,
+}
+
+impl IsFeatureEnabled for StravaConfig {
+    fn is_enabled(&self) -> bool {
+        !self.client_id.is_empty() && !self.client_secret.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config, PartialEq, Eq)]
+#[config(rename_all = "snake_case", env_prefix = "EXERCISE_WGER_")]
+pub struct WgerConfig {
+    /// The base URL of the wger instance (eg: `https://wger.de`). If set, the
+    /// exercise library is supplemented with the exercises of this instance.
+    pub url: String,
+}
+
+impl IsFeatureEnabled for WgerConfig {
+    fn is_enabled(&self) -> bool {
+        !self.url.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config, PartialEq, Eq)]
+#[config(rename_all = "snake_case", env_prefix = "EXERCISE_")]
+pub struct ExerciseConfig {
+    /// Settings related to Strava (activity sync).
+    #[setting(nested)]
+    pub strava: StravaConfig,
+    /// Settings related to wger (exercise library).
+    #[setting(nested)]
+    pub wger: WgerConfig,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "MEDIA_UPCITEMDB_")]
+pub struct UpcItemDbConfig {
+    /// The user key for the UPCitemdb API. If not provided, the rate limited
+    /// trial endpoint will be used for barcode lookups.
+    pub user_key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "MEDIA_WIKIDATA_")]
+pub struct WikidataConfig {
+    /// Whether to tag media with the awards and nominations listed on Wikidata.
+    #[setting(default = true)]
+    pub enabled: bool,
+}
+
+/// The sources are given by their names, eg: `GoogleBooks` or `Anilist`.
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "MEDIA_FALLBACKS_")]
+pub struct MetadataFallbackConfig {
+    /// The sources to fill in the missing details of anime from.
+    #[setting(default = vec![], parse_env = schematic::env::split_comma)]
+    pub anime: Vec<String>,
+    /// The sources to fill in the missing details of articles from.
+    #[setting(default = vec![], parse_env = schematic::env::split_comma)]
+    pub article: Vec<String>,
+    /// The sources to fill in the missing details of audio books from.
+    #[setting(default = vec![], parse_env = schematic::env::split_comma)]
+    pub audio_book: Vec<String>,
+    /// The sources to fill in the missing details of board games from.
+    #[setting(default = vec![], parse_env = schematic::env::split_comma)]
+    pub board_game: Vec<String>,
+    /// The sources to fill in the missing details of books from.
+    #[setting(default = vec!["GoogleBooks".to_owned()], parse_env = schematic::env::split_comma)]
+    pub book: Vec<String>,
+    /// The sources to fill in the missing details of comics from.
+    #[setting(default = vec![], parse_env = schematic::env::split_comma)]
+    pub comic: Vec<String>,
+    /// The sources to fill in the missing details of manga from.
+    #[setting(default = vec![], parse_env = schematic::env::split_comma)]
+    pub manga: Vec<String>,
+    /// The sources to fill in the missing details of movies from.
+    #[setting(default = vec![], parse_env = schematic::env::split_comma)]
+    pub movie: Vec<String>,
+    /// The sources to fill in the missing details of music from.
+    #[setting(default = vec![], parse_env = schematic::env::split_comma)]
+    pub music: Vec<String>,
+    /// The sources to fill in the missing details of podcasts from.
+    #[setting(default = vec![], parse_env = schematic::env::split_comma)]
+    pub podcast: Vec<String>,
+    /// The sources to fill in the missing details of shows from.
+    #[setting(default = vec![], parse_env = schematic::env::split_comma)]
+    pub show: Vec<String>,
+    /// The sources to fill in the missing details of video games from.
+    #[setting(default = vec![], parse_env = schematic::env::split_comma)]
+    pub video_game: Vec<String>,
+    /// The sources to fill in the missing details of visual novels from.
+    #[setting(default = vec![], parse_env = schematic::env::split_comma)]
+    pub visual_novel: Vec<String>,
+}
+
+impl MetadataFallbackConfig {
+    pub fn for_lot(&self, lot: MetadataLot) -> &[String] {
+        match lot {
+            MetadataLot::Anime => &self.anime,
+            MetadataLot::Article => &self.article,
+            MetadataLot::AudioBook => &self.audio_book,
+            MetadataLot::BoardGame => &self.board_game,
+            MetadataLot::Book => &self.book,
+            MetadataLot::Comic => &self.comic,
+            MetadataLot::Manga => &self.manga,
+            MetadataLot::Movie => &self.movie,
+            MetadataLot::Music => &self.music,
+            MetadataLot::Podcast => &self.podcast,
+            MetadataLot::Show => &self.show,
+            MetadataLot::VideoGame => &self.video_game,
+            MetadataLot::VisualNovel => &self.visual_novel,
+        }
+    }
+}
+
+/// The settings of each provider are given as `Source=number` pairs, eg:
+/// `Tmdb=20`.
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "MEDIA_RATE_LIMITS_")]
+pub struct ProviderRateLimitConfig {
+    /// The number of times a request is retried when it is rate limited or
+    /// fails, for the providers that are not listed in `max_retries`.
+    #[setting(default = 3)]
+    pub default_max_retries: u32,
+    /// The number of times a request to a provider is retried, with an
+    /// exponential backoff.
+    #[setting(default = vec![], parse_env = schematic::env::split_comma)]
+    pub max_retries: Vec<String>,
+    /// The maximum number of requests sent to a provider in a second. The
+    /// providers that are not listed are not limited.
+    #[setting(
+        default = vec!["MusicBrainz=1".to_owned(), "Openlibrary=3".to_owned(), "Tmdb=20".to_owned()],
+        parse_env = schematic::env::split_comma
+    )]
+    pub requests_per_second: Vec<String>,
+}
+
+impl ProviderRateLimitConfig {
+    fn value_for(pairs: &[String], source: MetadataSource) -> Option<u32> {
+        pairs.iter().find_map(|pair| {
+            let (name, value) = pair.split_once('=')?;
+            match MetadataSource::from_str(name.trim()) {
+                Ok(s) if s == source => value.trim().parse().ok(),
+                _ => None,
+            }
+        })
+    }
+
+    pub fn max_retries(&self, source: MetadataSource) -> u32 {
+        Self::value_for(&self.max_retries, source).unwrap_or(self.default_max_retries)
+    }
+
+    pub fn requests_per_second(&self, source: MetadataSource) -> Option<u32> {
+        Self::value_for(&self.requests_per_second, source).filter(|r| *r > 0)
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
 #[config(rename_all = "snake_case", env_prefix = "MEDIA_")]
-pub struct MediaConfig {}
+pub struct MediaConfig {
+    /// The other sources that are tried, in order, when the details of a media
+    /// item are missing a description, images, genres, creators or release
+    /// date. The first search result with a matching title is used.
+    #[setting(nested)]
+    pub fallbacks: MetadataFallbackConfig,
+    /// The number of days a media item that no user is associated with is kept
+    /// for before it is removed, along with its images, by the cleanup job.
+    /// Such items are created when they are opened from the search results.
+    #[setting(default = 0)]
+    pub orphaned_metadata_retention_days: i64,
+    /// The number of minutes the responses of the providers are cached for, so
+    /// that the same details are not requested again. They are always requested
+    /// again when the media is updated. Set to `0` to disable.
+    #[setting(default = 60)]
+    pub provider_cache_minutes: i64,
+    /// Settings related to the rate limits and retries of the requests made to
+    /// the providers.
+    #[setting(nested)]
+    pub rate_limits: ProviderRateLimitConfig,
+    /// Settings related to UPCitemdb (barcode lookups).
+    #[setting(nested)]
+    pub upcitemdb: UpcItemDbConfig,
+    /// Settings related to Wikidata (award enrichment).
+    #[setting(nested)]
+    pub wikidata: WikidataConfig,
+}
 
 fn validate_tmdb_locale(value: &str) -> Result<(), ValidateError> {
     if !TmdbService::supported_languages().contains(&value.to_owned()) {
@@ -167,6 +403,12 @@ pub struct MovieConfig {
 
 impl IsFeatureEnabled for MovieConfig {}
 
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "MUSIC_")]
+pub struct MusicConfig {}
+
+impl IsFeatureEnabled for MusicConfig {}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
 #[config(rename_all = "snake_case", env_prefix = "MANGA_ANILIST_")]
 pub struct MangaAnilistConfig {}
@@ -179,6 +421,10 @@ pub struct MangaMalConfig {
     pub client_id: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "MANGA_MANGA_DEX_")]
+pub struct MangaMangaDexConfig {}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
 #[config(rename_all = "snake_case", env_prefix = "MANGA_MANGA_UPDATES_")]
 pub struct MangaMangaUpdatesConfig {}
@@ -189,6 +435,9 @@ pub struct MangaConfig {
     /// Settings related to Anilist (manga).
     #[setting(nested)]
     pub anilist: MangaAnilistConfig,
+    /// Settings related to MangaDex.
+    #[setting(nested)]
+    pub manga_dex: MangaMangaDexConfig,
     /// Settings related to MangaUpdates.
     #[setting(nested)]
     pub manga_updates: MangaMangaUpdatesConfig,
@@ -228,6 +477,15 @@ pub struct ITunesConfig {
     pub locale: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "PODCASTS_PODCAST_INDEX_")]
+pub struct PodcastIndexConfig {
+    /// The API key for the Podcast Index API.
+    pub api_key: String,
+    /// The API secret for the Podcast Index API.
+    pub api_secret: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
 #[config(rename_all = "snake_case")]
 pub struct PodcastConfig {
@@ -237,6 +495,9 @@ pub struct PodcastConfig {
     /// Settings related to iTunes.
     #[setting(nested)]
     pub itunes: ITunesConfig,
+    /// Settings related to Podcast Index.
+    #[setting(nested)]
+    pub podcast_index: PodcastIndexConfig,
 }
 
 impl IsFeatureEnabled for PodcastConfig {}
@@ -340,6 +601,11 @@ pub struct FileStorageConfig {
     pub s3_secret_access_key: String,
     /// The URL for the S3 compatible file storage.
     pub s3_url: String,
+    /// The number of minutes that the signed URLs of the uploaded files are
+    /// valid for. The files are only served using these, so the bucket does not
+    /// need to be public.
+    #[setting(default = 90)]
+    pub signed_url_expiry_minutes: i64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
@@ -351,6 +617,9 @@ pub struct FrontendConfig {
     /// The number of items to display in a list view.
     #[setting(default = 20)]
     pub page_size: i32,
+    /// The URL where the frontend is hosted, used for the links in the emails
+    /// sent to the users. Eg: `https://ryot.example.com`.
+    pub url: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
@@ -367,6 +636,7 @@ pub struct IntegrationConfig {
     #[setting(default = 2)]
     pub minimum_progress_limit: i32,
     /// The maximum progress limit after which a media is considered to be completed.
+    /// Integrations can override it with their own completion threshold.
     #[setting(default = 95)]
     pub maximum_progress_limit: i32,
 }
@@ -390,6 +660,11 @@ pub struct SchedulerConfig {
     /// The url to the SQLite database where job related data needs to be stored.
     #[setting(default = "sqlite::memory:")]
     pub database_url: String,
+    /// The maximum number of media items whose details are fetched from a
+    /// provider at the same time during an import. Providers with strict rate
+    /// limits always use fewer.
+    #[setting(default = 10)]
+    pub import_concurrency: usize,
     /// The number of jobs to process every 5 seconds when updating metadata in
     /// the background.
     #[setting(default = 5)]
@@ -398,6 +673,52 @@ pub struct SchedulerConfig {
     /// calculation.
     #[setting(default = 12)]
     pub user_cleanup_every: i32,
+    /// Deploy a job every x hours that analyzes the database, rebuilds the
+    /// indexes of the tables that are searched and removes media that no user
+    /// is linked to. Disabled if `0`.
+    #[setting(default = 0)]
+    pub maintenance_every: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "SERVER_SMTP_")]
+pub struct SmtpConfig {
+    /// The host of the SMTP server. **Required** to send emails.
+    pub server: String,
+    /// The port of the SMTP server.
+    #[setting(default = 587)]
+    pub port: u16,
+    /// The username used to authenticate with the SMTP server.
+    pub user: String,
+    /// The password used to authenticate with the SMTP server.
+    pub password: String,
+    /// The mailbox that the emails are sent from.
+    #[setting(default = "Ryot <no-reply@ryot.io>")]
+    pub mailbox: String,
+}
+
+impl IsFeatureEnabled for SmtpConfig {
+    fn is_enabled(&self) -> bool {
+        !self.server.is_empty()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "SERVER_GRAPHQL_")]
+pub struct GraphqlConfig {
+    /// The maximum number of fields that a query can select, counting the nested
+    /// ones. Set to `0` to disable.
+    #[setting(default = 1000)]
+    pub max_complexity: usize,
+    /// The maximum depth to which the fields of a query can be nested. Set to
+    /// `0` to disable.
+    #[setting(default = 20)]
+    pub max_depth: usize,
+    /// The maximum number of requests that can be made in a minute using the
+    /// user, or from the same IP address when not logged in. Set to
+    /// `0` to disable.
+    #[setting(default = 300)]
+    pub max_requests_per_minute: u32,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
@@ -433,6 +754,66 @@ pub struct ServerConfig {
     /// Whether videos will be displayed in the media details.
     #[setting(default = false)]
     pub videos_disabled: bool,
+    /// Settings related to sending emails, like the ones to verify an email
+    /// or reset a password.
+    #[setting(nested)]
+    pub smtp: SmtpConfig,
+    /// Settings related to limiting the GraphQL queries that can be made, to
+    /// protect the instance from expensive ones.
+    #[setting(nested)]
+    pub graphql: GraphqlConfig,
+}
+
+derive_enum!(
+    #[derive(ConfigEnum, Default)]
+    pub enum NsfwDisplayFloor {
+        Hide,
+        Blur,
+        #[default]
+        Show,
+    }
+);
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "USERS_PASSWORD_HASHING_")]
+pub struct PasswordHashingConfig {
+    /// The memory (in KiB) used by Argon2id to hash a password.
+    #[setting(default = 19456)]
+    pub memory_cost: u32,
+    /// The number of iterations used by Argon2id to hash a password.
+    #[setting(default = 2)]
+    pub time_cost: u32,
+    /// The degree of parallelism used by Argon2id to hash a password.
+    #[setting(default = 1)]
+    pub parallelism: u32,
+    /// A secret that is mixed into the password hashes. It is not stored in the
+    /// database, so the hashes can not be cracked without it. Changing it once
+    /// set makes the existing passwords unusable.
+    pub pepper: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Config)]
+#[config(rename_all = "snake_case", env_prefix = "USERS_LOGIN_THROTTLING_")]
+pub struct LoginThrottlingConfig {
+    /// The number of failed logins for a username after which it is locked out.
+    /// Set to `0` to disable.
+    #[setting(default = 5)]
+    pub max_attempts_per_user: u32,
+    /// The number of failed logins from an IP address after which it is locked
+    /// out. Set to `0` to disable.
+    #[setting(default = 20)]
+    pub max_attempts_per_ip: u32,
+    /// The number of minutes a lockout lasts, counted from the last failed login.
+    #[setting(default = 15)]
+    pub lockout_minutes: i64,
+    /// Whether the IP address is read from the `X-Forwarded-For` header. Only
+    /// enable this if the server is behind a reverse proxy that sets it.
+    pub trust_forwarded_for: bool,
+    /// The number of reverse proxies in front of the server that add to the
+    /// `X-Forwarded-For` header. The IP address is read from the entry that the
+    /// farthest of them added, since the ones before it can be set by the client.
+    #[setting(default = 1)]
+    pub trusted_proxies: usize,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
@@ -456,12 +837,31 @@ pub struct UsersConfig {
     /// Whether new users will be allowed to sign up to this instance.
     #[setting(default = true)]
     pub allow_registration: bool,
+    /// Whether users will be allowed to reset their password using a link sent
+    /// to their email. Requires SMTP to be configured.
+    #[setting(default = true)]
+    pub allow_password_reset: bool,
+    /// Whether new users need to verify their email before they can login.
+    /// Requires SMTP to be configured.
+    #[setting(default = false)]
+    pub require_email_verification: bool,
+    /// The most permissive way NSFW media can be displayed on this instance.
+    /// Users can still choose a stricter setting for themselves.
+    pub nsfw_display_floor: NsfwDisplayFloor,
     /// Whether users will be allowed to post reviews on this instance.
     #[setting(default = false)]
     pub reviews_disabled: bool,
     /// The number of days till login auth token is valid.
     #[setting(default = 90)]
     pub token_valid_for_days: i64,
+    /// Settings related to how the passwords are hashed. The passwords hashed
+    /// with other settings are hashed again when the user logs in.
+    #[setting(nested)]
+    pub password_hashing: PasswordHashingConfig,
+    /// Settings related to locking out the users and IP addresses with too
+    /// many failed logins.
+    #[setting(nested)]
+    pub login_throttling: LoginThrottlingConfig,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Config)]
@@ -470,12 +870,21 @@ pub struct AppConfig {
     /// Settings related to anime.
     #[setting(nested)]
     pub anime: AnimeConfig,
+    /// Settings related to articles.
+    #[setting(nested)]
+    pub articles: ArticleConfig,
     /// Settings related to audio books.
     #[setting(nested)]
     pub audio_books: AudioBookConfig,
+    /// Settings related to board games.
+    #[setting(nested)]
+    pub board_games: BoardGameConfig,
     /// Settings related to books.
     #[setting(nested)]
     pub books: BookConfig,
+    /// Settings related to comics.
+    #[setting(nested)]
+    pub comics: ComicConfig,
     /// The database related settings.
     #[setting(nested)]
     pub database: DatabaseConfig,
@@ -500,6 +909,9 @@ pub struct AppConfig {
     /// Settings related to movies.
     #[setting(nested)]
     pub movies: MovieConfig,
+    /// Settings related to music.
+    #[setting(nested)]
+    pub music: MusicConfig,
     /// Settings related to podcasts.
     #[setting(nested)]
     pub podcasts: PodcastConfig,
@@ -529,14 +941,20 @@ impl AppConfig {
         let gt = || "****".to_owned();
         let mut cl = self.clone();
         cl.anime.mal.client_id = gt();
+        cl.books.google_books.api_key = gt();
+        cl.comics.comic_vine.api_key = gt();
         cl.database.url = gt();
+        cl.exercise.strava.client_secret = gt();
         cl.file_storage.s3_region = gt();
+        cl.podcasts.podcast_index.api_key = gt();
+        cl.podcasts.podcast_index.api_secret = gt();
         cl.file_storage.s3_bucket_name = gt();
         cl.file_storage.s3_access_key_id = gt();
         cl.file_storage.s3_secret_access_key = gt();
         cl.file_storage.s3_url = gt();
         cl.integration.hasher_salt = gt();
         cl.manga.mal.client_id = gt();
+        cl.media.upcitemdb.user_key = gt();
         cl.movies.tmdb.access_token = gt();
         cl.podcasts.listennotes.api_token = gt();
         cl.shows.tmdb.access_token = gt();
@@ -545,21 +963,185 @@ impl AppConfig {
         cl.video_games.twitch.client_secret = gt();
         cl.server.config_dump_path = gt();
         cl.server.cors_origins = vec![gt()];
+        cl.server.smtp.password = gt();
         cl.users.jwt_secret = gt();
+        cl.users.password_hashing.pepper = gt();
         cl
     }
+
+    /// The settings that can be moved to another instance, which are all of them
+    /// except the masked ones.
+    pub fn exportable_value(&self) -> Value {
+        let mut value = serde_json::to_value(self.masked_value()).unwrap();
+        remove_masked_settings(&mut value);
+        value
+    }
+
+    /// The settings that are required to use a provider but are not set, as the
+    /// dotted paths of their keys (eg: `video_games.twitch.client_id`).
+    pub fn missing_provider_settings(
+        &self,
+        lot: MetadataLot,
+        source: MetadataSource,
+    ) -> Vec<String> {
+        let values = serde_json::to_value(self).unwrap();
+        provider_settings(lot, source)
+            .into_iter()
+            .filter(|key| {
+                values
+                    .pointer(&setting_pointer(key))
+                    .and_then(|v| v.as_str())
+                    .map_or(true, |v| v.is_empty())
+            })
+            .map(|key| key.to_owned())
+            .collect()
+    }
 }
 
-pub fn load_app_config() -> Result<AppConfig> {
-    let config = "config";
-    let app = PROJECT_NAME;
-    let path = PathBuf::from(config);
+/// Whether the dotted path is one of the settings that a provider requires.
+pub fn is_provider_setting(key: &str) -> bool {
+    MetadataLot::iter()
+        .flat_map(|lot| MetadataSource::iter().map(move |source| (lot, source)))
+        .any(|(lot, source)| provider_settings(lot, source).contains(&key))
+}
+
+/// The settings that are required to use a provider.
+fn provider_settings(lot: MetadataLot, source: MetadataSource) -> Vec<&'static str> {
+    match (lot, source) {
+        (_, MetadataSource::ComicVine) => vec!["comics.comic_vine.api_key"],
+        (_, MetadataSource::Igdb) => vec![
+            "video_games.twitch.client_id",
+            "video_games.twitch.client_secret",
+        ],
+        (_, MetadataSource::Listennotes) => vec!["podcasts.listennotes.api_token"],
+        (_, MetadataSource::PodcastIndex) => vec![
+            "podcasts.podcast_index.api_key",
+            "podcasts.podcast_index.api_secret",
+        ],
+        (MetadataLot::Anime, MetadataSource::Mal) => vec!["anime.mal.client_id"],
+        (MetadataLot::Manga, MetadataSource::Mal) => vec!["manga.mal.client_id"],
+        (MetadataLot::Movie, MetadataSource::Tmdb) => vec!["movies.tmdb.access_token"],
+        (MetadataLot::Show, MetadataSource::Tmdb) => vec!["shows.tmdb.access_token"],
+        _ => vec![],
+    }
+}
+
+fn setting_pointer(key: &str) -> String {
+    format!("/{}", key.replace('.', "/"))
+}
+
+fn is_masked_setting(value: &Value) -> bool {
+    match value {
+        Value::String(s) => s == "****",
+        Value::Array(a) => !a.is_empty() && a.iter().all(is_masked_setting),
+        _ => false,
+    }
+}
+
+fn remove_masked_settings(value: &mut Value) {
+    if let Some(object) = value.as_object_mut() {
+        object.retain(|_, v| !is_masked_setting(v));
+        object.values_mut().for_each(remove_masked_settings);
+    }
+}
+
+fn config_file(extension: &str) -> PathBuf {
+    PathBuf::from("config").join(format!("{}.{}", PROJECT_NAME, extension))
+}
 
+pub fn load_app_config() -> Result<AppConfig> {
     let result = ConfigLoader::<AppConfig>::new()
-        .file_optional(path.join(format!("{app}.json")))?
-        .file_optional(path.join(format!("{app}.toml")))?
-        .file_optional(path.join(format!("{app}.yaml")))?
+        .file_optional(config_file("json"))?
+        .file_optional(config_file("toml"))?
+        .file_optional(config_file("yaml"))?
         .load()?;
 
     Ok(result.config)
 }
+
+/// Write the settings to the JSON config file, keeping the ones that are already
+/// in it. Only settings that are strings can be written, and the server needs to
+/// be restarted for them to be used. The TOML and YAML files, as well as the
+/// environment variables, still take precedence over this file.
+pub fn save_app_config_settings(settings: Vec<(String, String)>) -> Result<()> {
+    let current = serde_json::to_value(load_app_config()?)?;
+    let mut file = read_config_file()?;
+    for (key, value) in settings {
+        if !current
+            .pointer(&setting_pointer(&key))
+            .map_or(false, |v| v.is_string())
+        {
+            bail!("{:?} is not a setting that can be changed", key);
+        }
+        let mut target = &mut file;
+        for part in key.split('.') {
+            target = target
+                .as_object_mut()
+                .ok_or_else(|| anyhow!("The config file has an unexpected format"))?
+                .entry(part.to_owned())
+                .or_insert_with(|| serde_json::json!({}));
+        }
+        *target = serde_json::json!(value);
+    }
+    write_config_file(file)
+}
+
+/// Write the settings of a config exported from another instance to the JSON
+/// config file, keeping the ones that are already in it. The masked settings
+/// can not be imported since they are never exported.
+pub fn import_app_config(settings: Value) -> Result<()> {
+    let current = serde_json::to_value(load_app_config()?.masked_value())?;
+    let mut file = read_config_file()?;
+    merge_settings(&mut file, settings, &current, "")?;
+    write_config_file(file)
+}
+
+fn merge_settings(target: &mut Value, settings: Value, current: &Value, path: &str) -> Result<()> {
+    let settings = match settings {
+        Value::Object(s) => s,
+        _ => bail!("The settings of {:?} must be an object", path),
+    };
+    for (key, value) in settings {
+        let key_path = match path.is_empty() {
+            true => key.clone(),
+            false => format!("{}.{}", path, key),
+        };
+        let existing = match current.get(&key) {
+            Some(e) if !is_masked_setting(e) => e,
+            _ => bail!("{:?} is not a setting that can be imported", key_path),
+        };
+        let entry = target
+            .as_object_mut()
+            .ok_or_else(|| anyhow!("The config file has an unexpected format"))?
+            .entry(key)
+            .or_insert_with(|| serde_json::json!({}));
+        match existing.is_object() {
+            true => merge_settings(entry, value, existing, &key_path)?,
+            false => *entry = value,
+        }
+    }
+    Ok(())
+}
+
+fn read_config_file() -> Result<Value> {
+    let path = config_file("json");
+    Ok(match path.exists() {
+        true => serde_json::from_str(&fs::read_to_string(&path)?)?,
+        false => serde_json::json!({}),
+    })
+}
+
+fn write_config_file(file: Value) -> Result<()> {
+    let path = config_file("json");
+    // DEV: Load the config with the new file to make sure that it is still valid
+    ConfigLoader::<AppConfig>::new()
+        .code(serde_json::to_string(&file)?, Format::Json)?
+        .file_optional(config_file("toml"))?
+        .file_optional(config_file("yaml"))?
+        .load()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(&file)?)?;
+    Ok(())
+}
@@ -4,7 +4,7 @@ use async_graphql::SimpleObject;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::models::media::Visibility;
+use crate::models::media::{CollectionActions, Visibility};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, SimpleObject)]
 #[sea_orm(table_name = "collection")]
@@ -18,6 +18,8 @@ pub struct Model {
     #[graphql(skip)]
     pub user_id: i32,
     pub visibility: Visibility,
+    #[graphql(skip)]
+    pub actions: Option<CollectionActions>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
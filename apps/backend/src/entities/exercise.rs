@@ -8,7 +8,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     file_storage::FileStorageService,
-    migrator::{ExerciseEquipment, ExerciseForce, ExerciseLevel, ExerciseLot, ExerciseMechanic},
+    migrator::{
+        ExerciseEquipment, ExerciseForce, ExerciseLevel, ExerciseLot, ExerciseMechanic,
+        ExerciseSource,
+    },
     models::fitness::{ExerciseAttributes, ExerciseMuscles},
     utils::get_stored_asset,
 };
@@ -31,6 +34,11 @@ pub struct Model {
     pub attributes: ExerciseAttributes,
     #[graphql(skip)]
     pub muscles: ExerciseMuscles,
+    pub source: ExerciseSource,
+    #[graphql(skip)]
+    pub created_by_user_id: Option<i32>,
+    #[graphql(skip)]
+    pub external_id: Option<String>,
 }
 
 impl Model {
@@ -51,6 +59,8 @@ impl Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::user_to_exercise::Entity")]
     UserToExercise,
+    #[sea_orm(has_many = "super::workout_set::Entity")]
+    WorkoutSet,
 }
 
 impl Related<super::user_to_exercise::Entity> for Entity {
@@ -59,6 +69,12 @@ impl Related<super::user_to_exercise::Entity> for Entity {
     }
 }
 
+impl Related<super::workout_set::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WorkoutSet.def()
+    }
+}
+
 impl Related<super::user::Entity> for Entity {
     fn to() -> RelationDef {
         super::user_to_exercise::Relation::User.def()
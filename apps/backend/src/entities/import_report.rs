@@ -4,7 +4,10 @@ use async_graphql::SimpleObject;
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::{importer::ImportResultResponse, migrator::ImportSource};
+use crate::{
+    importer::{ImportCheckpoint, ImportResultResponse},
+    migrator::ImportSource,
+};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, SimpleObject)]
 #[sea_orm(table_name = "import_report")]
@@ -18,6 +21,14 @@ pub struct Model {
     pub finished_on: Option<DateTimeUtc>,
     pub details: Option<ImportResultResponse>,
     pub success: Option<bool>,
+    /// The input that the import was deployed with. It is kept until the import
+    /// succeeds, so that it can be resumed if it fails, but for at most
+    /// `IMPORT_INPUT_RETENTION_DAYS` days since it can contain credentials.
+    #[graphql(skip)]
+    pub input: Option<Json>,
+    /// How far the import got. It is kept until the import succeeds, so that it
+    /// can be resumed from there if it fails.
+    pub checkpoint: Option<ImportCheckpoint>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -9,7 +9,11 @@ use serde::{Deserialize, Serialize};
 use crate::{
     entities::{partial_metadata, prelude::PartialMetadata},
     migrator::{MetadataLot, MetadataSource},
-    models::media::{MediaSpecifics, MetadataImages, MetadataVideos},
+    models::media::{
+        MediaSpecifics, MetadataAlternativeTitles, MetadataAwards, MetadataImages,
+        MetadataLocalizations, MetadataVideos,
+    },
+    utils::normalize_text,
 };
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, Default)]
@@ -21,7 +25,13 @@ pub struct Model {
     pub lot: MetadataLot,
     pub last_updated_on: DateTimeUtc,
     pub title: String,
+    pub normalized_title: Option<String>,
+    pub alternative_titles: Option<MetadataAlternativeTitles>,
+    pub language: Option<String>,
+    pub localizations: Option<MetadataLocalizations>,
     pub is_nsfw: bool,
+    pub is_deleted_on_provider: bool,
+    pub awards: Option<MetadataAwards>,
     // FIXME: Remove this
     #[sea_orm(indexed)]
     pub identifier: String,
@@ -41,6 +51,10 @@ pub struct Model {
 pub enum Relation {
     #[sea_orm(has_many = "super::calendar_event::Entity")]
     CalendarEvent,
+    #[sea_orm(has_many = "super::highlight::Entity")]
+    Highlight,
+    #[sea_orm(has_many = "super::koreader_document::Entity")]
+    KoreaderDocument,
     #[sea_orm(has_many = "super::metadata_to_collection::Entity")]
     MetadataToCollection,
     #[sea_orm(has_many = "super::metadata_to_creator::Entity")]
@@ -65,6 +79,18 @@ impl Related<super::calendar_event::Entity> for Entity {
     }
 }
 
+impl Related<super::highlight::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Highlight.def()
+    }
+}
+
+impl Related<super::koreader_document::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::KoreaderDocument.def()
+    }
+}
+
 impl Related<super::metadata_to_collection::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::MetadataToCollection.def()
@@ -153,6 +179,16 @@ impl Related<super::user::Entity> for Entity {
 
 #[async_trait]
 impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, _insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        if let ActiveValue::Set(title) = &self.title {
+            self.normalized_title = ActiveValue::Set(Some(normalize_text(title)));
+        }
+        Ok(self)
+    }
+
     async fn after_save<C>(model: Model, db: &C, _insert: bool) -> Result<Model, DbErr>
     where
         C: ConnectionTrait,
@@ -2,13 +2,20 @@
 
 pub mod prelude;
 
+pub mod announcement;
+pub mod audit_log;
 pub mod calendar_event;
 pub mod collection;
 pub mod creator;
 pub mod exercise;
 pub mod genre;
+pub mod highlight;
 pub mod import_report;
+pub mod instance_setting;
+pub mod integration_mapping;
+pub mod koreader_document;
 pub mod metadata;
+pub mod metadata_correction;
 pub mod metadata_group;
 pub mod metadata_to_collection;
 pub mod metadata_to_creator;
@@ -18,8 +25,15 @@ pub mod partial_metadata;
 pub mod partial_metadata_to_metadata_group;
 pub mod review;
 pub mod seen;
+pub mod seen_attachment;
+pub mod seen_companion;
+pub mod shared_workout;
+pub mod training_program;
 pub mod user;
 pub mod user_measurement;
+pub mod user_statistic;
 pub mod user_to_exercise;
 pub mod user_to_metadata;
 pub mod workout;
+pub mod workout_set;
+pub mod workout_template;
@@ -1,12 +1,19 @@
 //! `SeaORM` Entity. Generated by sea-orm-codegen 0.12.2
 
+pub use super::announcement::Entity as Announcement;
+pub use super::audit_log::Entity as AuditLog;
 pub use super::calendar_event::Entity as CalendarEvent;
 pub use super::collection::Entity as Collection;
 pub use super::creator::Entity as Creator;
 pub use super::exercise::Entity as Exercise;
 pub use super::genre::Entity as Genre;
+pub use super::highlight::Entity as Highlight;
 pub use super::import_report::Entity as ImportReport;
+pub use super::instance_setting::Entity as InstanceSetting;
+pub use super::integration_mapping::Entity as IntegrationMapping;
+pub use super::koreader_document::Entity as KoreaderDocument;
 pub use super::metadata::Entity as Metadata;
+pub use super::metadata_correction::Entity as MetadataCorrection;
 pub use super::metadata_group::Entity as MetadataGroup;
 pub use super::metadata_to_collection::Entity as MetadataToCollection;
 pub use super::metadata_to_creator::Entity as MetadataToCreator;
@@ -16,8 +23,15 @@ pub use super::partial_metadata::Entity as PartialMetadata;
 pub use super::partial_metadata_to_metadata_group::Entity as PartialMetadataToMetadataGroup;
 pub use super::review::Entity as Review;
 pub use super::seen::Entity as Seen;
+pub use super::seen_attachment::Entity as SeenAttachment;
+pub use super::seen_companion::Entity as SeenCompanion;
+pub use super::shared_workout::Entity as SharedWorkout;
+pub use super::training_program::Entity as TrainingProgram;
 pub use super::user::Entity as User;
 pub use super::user_measurement::Entity as UserMeasurement;
+pub use super::user_statistic::Entity as UserStatistic;
 pub use super::user_to_exercise::Entity as UserToExercise;
 pub use super::user_to_metadata::Entity as UserToMetadata;
 pub use super::workout::Entity as Workout;
+pub use super::workout_set::Entity as WorkoutSet;
+pub use super::workout_template::Entity as WorkoutTemplate;
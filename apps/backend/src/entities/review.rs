@@ -2,12 +2,12 @@
 
 use async_trait::async_trait;
 use rust_decimal::Decimal;
-use sea_orm::entity::prelude::*;
+use sea_orm::{entity::prelude::*, ActiveValue};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     models::media::{ReviewComments, SeenOrReviewOrCalendarEventExtraInformation, Visibility},
-    utils::associate_user_with_metadata,
+    utils::{associate_user_with_metadata, update_user_statistic, UserStatisticDelta},
 };
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
@@ -55,6 +55,34 @@ pub enum Relation {
     User,
 }
 
+impl Model {
+    /// Add (`sign` being `1`) or remove (`-1`) the review from the statistics of
+    /// the user, if it is of a media item.
+    async fn update_statistic<C>(&self, sign: i32, db: &C) -> Result<(), DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let metadata_id = match self.metadata_id {
+            Some(metadata_id) => metadata_id,
+            None => return Ok(()),
+        };
+        let delta = UserStatisticDelta {
+            reviews: sign,
+            rating_sum: self.rating.unwrap_or_default() * Decimal::from(sign),
+            ratings: if self.rating.is_some() { sign } else { 0 },
+            ..Default::default()
+        };
+        update_user_statistic(
+            self.user_id,
+            metadata_id,
+            self.posted_on.date_naive(),
+            delta,
+            db,
+        )
+        .await
+    }
+}
+
 impl Related<super::creator::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Creator.def()
@@ -75,6 +103,20 @@ impl Related<super::user::Entity> for Entity {
 
 #[async_trait]
 impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(self, db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        if !insert {
+            if let ActiveValue::Set(id) | ActiveValue::Unchanged(id) = &self.id {
+                if let Some(old) = Entity::find_by_id(*id).one(db).await? {
+                    old.update_statistic(-1, db).await?;
+                }
+            }
+        }
+        Ok(self)
+    }
+
     async fn after_save<C>(model: Model, db: &C, insert: bool) -> Result<Model, DbErr>
     where
         C: ConnectionTrait,
@@ -86,6 +128,19 @@ impl ActiveModelBehavior for ActiveModel {
                     .ok();
             }
         }
+        model.update_statistic(1, db).await?;
         Ok(model)
     }
+
+    async fn before_delete<C>(self, db: &C) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        if let ActiveValue::Set(id) | ActiveValue::Unchanged(id) = &self.id {
+            if let Some(old) = Entity::find_by_id(*id).one(db).await? {
+                old.update_statistic(-1, db).await?;
+            }
+        }
+        Ok(self)
+    }
 }
@@ -14,7 +14,7 @@ use crate::{
         SeenOrReviewOrCalendarEventExtraInformation, SeenPodcastExtraInformation,
         SeenShowExtraInformation,
     },
-    utils::associate_user_with_metadata,
+    utils::{associate_user_with_metadata, update_user_statistic, UserStatisticDelta},
 };
 
 // When updating a media item's progress, here are the things that should happen:
@@ -34,6 +34,16 @@ pub struct Model {
     pub metadata_id: i32,
     pub num_times_updated: Option<i32>,
     pub state: SeenState,
+    /// The rating given at the time the media was seen, in the review scale of
+    /// the user.
+    pub rating: Option<Decimal>,
+    /// Where or how the media was seen, eg: `Cinema` or `Ebook`.
+    pub context: Option<String>,
+    /// The seen item that this one is likely a duplicate of, as flagged by the
+    /// duplicate detection job.
+    pub duplicate_of: Option<i32>,
+    #[graphql(skip)]
+    pub duplicate_dismissed: bool,
     #[graphql(skip)]
     #[serde(skip)]
     pub extra_information: Option<SeenOrReviewOrCalendarEventExtraInformation>,
@@ -41,6 +51,12 @@ pub struct Model {
     pub show_information: Option<SeenShowExtraInformation>,
     #[sea_orm(ignore)]
     pub podcast_information: Option<SeenPodcastExtraInformation>,
+    #[sea_orm(ignore)]
+    #[serde(default)]
+    pub attachments: Vec<super::seen_attachment::Model>,
+    #[sea_orm(ignore)]
+    #[serde(default)]
+    pub companions: Vec<super::seen_companion::Model>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -53,6 +69,10 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     Metadata,
+    #[sea_orm(has_many = "super::seen_attachment::Entity")]
+    SeenAttachment,
+    #[sea_orm(has_many = "super::seen_companion::Entity")]
+    SeenCompanion,
     #[sea_orm(
         belongs_to = "super::user::Entity",
         from = "Column::UserId",
@@ -63,12 +83,60 @@ pub enum Relation {
     User,
 }
 
+/// The date that a seen item is counted in, for the statistics of the user.
+pub fn statistic_date(
+    finished_on: Option<NaiveDate>,
+    started_on: Option<NaiveDate>,
+    last_updated_on: DateTimeUtc,
+) -> NaiveDate {
+    finished_on
+        .or(started_on)
+        .unwrap_or_else(|| last_updated_on.date_naive())
+}
+
+impl Model {
+    /// Add (`sign` being `1`) or remove (`-1`) the seen item from the statistics
+    /// of the user, if it was completed.
+    async fn update_statistic<C>(&self, sign: i32, db: &C) -> Result<(), DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        if self.state != SeenState::Completed {
+            return Ok(());
+        }
+        let delta = UserStatisticDelta {
+            seen: sign,
+            ..Default::default()
+        };
+        update_user_statistic(
+            self.user_id,
+            self.metadata_id,
+            statistic_date(self.finished_on, self.started_on, self.last_updated_on),
+            delta,
+            db,
+        )
+        .await
+    }
+}
+
 impl Related<super::metadata::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Metadata.def()
     }
 }
 
+impl Related<super::seen_attachment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SeenAttachment.def()
+    }
+}
+
+impl Related<super::seen_companion::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SeenCompanion.def()
+    }
+}
+
 impl Related<super::user::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::User.def()
@@ -77,7 +145,7 @@ impl Related<super::user::Entity> for Entity {
 
 #[async_trait]
 impl ActiveModelBehavior for ActiveModel {
-    async fn before_save<C>(mut self, _db: &C, _insert: bool) -> Result<Self, DbErr>
+    async fn before_save<C>(mut self, db: &C, insert: bool) -> Result<Self, DbErr>
     where
         C: ConnectionTrait,
     {
@@ -86,6 +154,15 @@ impl ActiveModelBehavior for ActiveModel {
         if progress == 100 && state == SeenState::InProgress {
             self.state = ActiveValue::Set(SeenState::Completed);
         }
+        // DEV: The previous version is removed from the statistics here and the
+        // new one is added back after saving
+        if !insert {
+            if let ActiveValue::Set(id) | ActiveValue::Unchanged(id) = &self.id {
+                if let Some(old) = Entity::find_by_id(*id).one(db).await? {
+                    old.update_statistic(-1, db).await?;
+                }
+            }
+        }
         Ok(self)
     }
 
@@ -107,6 +184,19 @@ impl ActiveModelBehavior for ActiveModel {
             )
             .exec(db)
             .await?;
+        model.update_statistic(1, db).await?;
         Ok(model)
     }
+
+    async fn before_delete<C>(self, db: &C) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        if let ActiveValue::Set(id) | ActiveValue::Unchanged(id) = &self.id {
+            if let Some(old) = Entity::find_by_id(*id).one(db).await? {
+                old.update_statistic(-1, db).await?;
+            }
+        }
+        Ok(self)
+    }
 }
@@ -1,24 +1,15 @@
 //! `SeaORM` Entity. Generated by sea-orm-codegen 0.12.1
 
-use argon2::{
-    password_hash::{rand_core::OsRng, SaltString},
-    Argon2, PasswordHasher,
-};
 use async_graphql::SimpleObject;
-use async_trait::async_trait;
-use sea_orm::{entity::prelude::*, ActiveValue};
+use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     migrator::UserLot,
-    models::media::UserSummary,
+    models::{fitness::InProgressWorkout, media::UserSummary},
     users::{UserNotifications, UserPreferences, UserSinkIntegrations, UserYankIntegrations},
 };
 
-fn get_hasher() -> Argon2<'static> {
-    Argon2::default()
-}
-
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize, SimpleObject)]
 #[graphql(name = "User")]
 #[sea_orm(table_name = "user")]
@@ -40,6 +31,10 @@ pub struct Model {
     pub notifications: UserNotifications,
     #[graphql(skip)]
     pub summary: Option<UserSummary>,
+    #[graphql(skip)]
+    pub in_progress_workout: Option<InProgressWorkout>,
+    #[graphql(skip)]
+    pub email_verification_pending: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -52,14 +47,22 @@ pub enum Relation {
     Review,
     #[sea_orm(has_many = "super::seen::Entity")]
     Seen,
+    #[sea_orm(has_many = "super::training_program::Entity")]
+    TrainingProgram,
     #[sea_orm(has_many = "super::user_measurement::Entity")]
     UserMeasurement,
+    #[sea_orm(has_many = "super::user_statistic::Entity")]
+    UserStatistic,
     #[sea_orm(has_many = "super::user_to_exercise::Entity")]
     UserToExercise,
     #[sea_orm(has_many = "super::user_to_metadata::Entity")]
     UserToMetadata,
     #[sea_orm(has_many = "super::workout::Entity")]
     Workout,
+    #[sea_orm(has_many = "super::workout_set::Entity")]
+    WorkoutSet,
+    #[sea_orm(has_many = "super::workout_template::Entity")]
+    WorkoutTemplate,
 }
 
 impl Related<super::collection::Entity> for Entity {
@@ -86,12 +89,24 @@ impl Related<super::seen::Entity> for Entity {
     }
 }
 
+impl Related<super::training_program::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TrainingProgram.def()
+    }
+}
+
 impl Related<super::user_measurement::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::UserMeasurement.def()
     }
 }
 
+impl Related<super::user_statistic::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserStatistic.def()
+    }
+}
+
 impl Related<super::user_to_exercise::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::UserToExercise.def()
@@ -110,6 +125,18 @@ impl Related<super::workout::Entity> for Entity {
     }
 }
 
+impl Related<super::workout_set::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WorkoutSet.def()
+    }
+}
+
+impl Related<super::workout_template::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WorkoutTemplate.def()
+    }
+}
+
 impl Related<super::exercise::Entity> for Entity {
     fn to() -> RelationDef {
         super::user_to_exercise::Relation::Exercise.def()
@@ -128,21 +155,4 @@ impl Related<super::metadata::Entity> for Entity {
     }
 }
 
-#[async_trait]
-impl ActiveModelBehavior for ActiveModel {
-    async fn before_save<C>(mut self, _db: &C, _insert: bool) -> Result<Self, DbErr>
-    where
-        C: ConnectionTrait,
-    {
-        if self.password.is_set() {
-            let password = self.password.unwrap();
-            let salt = SaltString::generate(&mut OsRng);
-            let password_hash = get_hasher()
-                .hash_password(password.as_bytes(), &salt)
-                .map_err(|_| DbErr::Custom("Unable to hash password".to_owned()))?
-                .to_string();
-            self.password = ActiveValue::Set(password_hash);
-        }
-        Ok(self)
-    }
-}
+impl ActiveModelBehavior for ActiveModel {}
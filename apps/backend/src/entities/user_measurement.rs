@@ -5,7 +5,7 @@ use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 use specta::Type;
 
-use crate::models::fitness::UserMeasurementStats;
+use crate::models::fitness::{UserMeasurementPhotos, UserMeasurementStats};
 
 #[derive(
     Clone,
@@ -36,6 +36,10 @@ pub struct Model {
     pub comment: Option<String>,
     /// The contents of the actual measurement.
     pub stats: UserMeasurementStats,
+    /// The progress photos attached to this measurement.
+    #[graphql(skip)]
+    #[serde(skip)]
+    pub photos: Option<UserMeasurementPhotos>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
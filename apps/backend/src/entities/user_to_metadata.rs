@@ -17,6 +17,8 @@ pub struct Model {
     pub last_updated_on: DateTimeUtc,
     pub monitored: bool,
     pub reminder: Option<UserMediaReminder>,
+    /// The total time in minutes that the user played a game for.
+    pub playtime: Option<i32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
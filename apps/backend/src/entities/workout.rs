@@ -18,6 +18,9 @@ pub struct Model {
     pub information: WorkoutInformation,
     pub name: Option<String>,
     pub comment: Option<String>,
+    /// The identifier of this workout in the service it was synced from, eg:
+    /// `strava:1234`.
+    pub external_id: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -30,6 +33,8 @@ pub enum Relation {
         on_delete = "Cascade"
     )]
     User,
+    #[sea_orm(has_many = "super::workout_set::Entity")]
+    WorkoutSet,
 }
 
 impl Related<super::user::Entity> for Entity {
@@ -38,4 +43,10 @@ impl Related<super::user::Entity> for Entity {
     }
 }
 
+impl Related<super::workout_set::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::WorkoutSet.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -7,16 +7,27 @@ use uuid::Uuid;
 pub struct FileStorageService {
     s3_client: aws_sdk_s3::Client,
     bucket_name: String,
+    signed_url_expiry: Duration,
 }
 
 impl FileStorageService {
-    pub fn new(s3_client: aws_sdk_s3::Client, bucket_name: String) -> Self {
+    pub fn new(
+        s3_client: aws_sdk_s3::Client,
+        bucket_name: String,
+        signed_url_expiry_minutes: i64,
+    ) -> Self {
         Self {
             s3_client,
             bucket_name,
+            signed_url_expiry: Duration::minutes(signed_url_expiry_minutes),
         }
     }
 
+    /// Whether the key is of a file that was uploaded by the user.
+    pub fn is_user_upload(key: &str, user_id: i32) -> bool {
+        key.starts_with(&format!("uploads/{}/", user_id))
+    }
+
     pub async fn is_enabled(&self) -> bool {
         self.s3_client
             .head_bucket()
@@ -32,7 +43,7 @@ impl FileStorageService {
             .bucket(&self.bucket_name)
             .key(key)
             .presigned(
-                PresigningConfig::expires_in(Duration::minutes(90).to_std().unwrap()).unwrap(),
+                PresigningConfig::expires_in(self.signed_url_expiry.to_std().unwrap()).unwrap(),
             )
             .await
             .unwrap()
@@ -40,8 +51,8 @@ impl FileStorageService {
             .to_string()
     }
 
-    pub async fn get_presigned_put_url(&self, filename: String) -> (String, String) {
-        let key = format!("uploads/{}-{}", Uuid::new_v4(), filename);
+    pub async fn get_presigned_put_url(&self, filename: String, user_id: i32) -> (String, String) {
+        let key = format!("uploads/{}/{}-{}", user_id, Uuid::new_v4(), filename);
         let url = self
             .s3_client
             .put_object()
@@ -68,4 +79,15 @@ impl FileStorageService {
             .context("Could not upload file")
             .map(|_| ())
     }
+
+    pub async fn delete_object(&self, key: &str) -> Result<()> {
+        self.s3_client
+            .delete_object()
+            .bucket(&self.bucket_name)
+            .key(key)
+            .send()
+            .await
+            .context("Could not delete file")
+            .map(|_| ())
+    }
 }
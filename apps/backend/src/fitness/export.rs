@@ -0,0 +1,186 @@
+use anyhow::{anyhow, Result};
+use csv::Writer;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use strum::EnumString;
+
+use crate::{
+    entities::workout,
+    models::fitness::{SetLot, WorkoutSetRecord},
+    users::UserUnitSystem,
+};
+
+/// The CSV formats of other workout trackers that workouts can be exported to.
+#[derive(Debug, Clone, Copy, EnumString)]
+#[strum(ascii_case_insensitive, serialize_all = "snake_case")]
+pub enum WorkoutCsvFormat {
+    Strong,
+    Hevy,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StrongRow {
+    #[serde(rename = "Date")]
+    date: String,
+    #[serde(rename = "Workout Name")]
+    workout_name: String,
+    #[serde(rename = "Duration")]
+    duration: String,
+    #[serde(rename = "Exercise Name")]
+    exercise_name: String,
+    #[serde(rename = "Set Order")]
+    set_order: String,
+    #[serde(rename = "Weight")]
+    weight: Option<Decimal>,
+    #[serde(rename = "Reps")]
+    reps: Option<usize>,
+    #[serde(rename = "Distance")]
+    distance: Option<Decimal>,
+    #[serde(rename = "Seconds")]
+    seconds: Option<Decimal>,
+    #[serde(rename = "Notes")]
+    notes: String,
+    #[serde(rename = "Workout Notes")]
+    workout_notes: String,
+    #[serde(rename = "RPE")]
+    rpe: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct HevyRow {
+    title: String,
+    start_time: String,
+    end_time: String,
+    description: String,
+    exercise_title: String,
+    superset_id: Option<usize>,
+    exercise_notes: String,
+    set_index: usize,
+    set_type: String,
+    weight_kg: Option<Decimal>,
+    reps: Option<usize>,
+    distance_km: Option<Decimal>,
+    duration_seconds: Option<Decimal>,
+    rpe: Option<Decimal>,
+}
+
+fn workout_name(workout: &workout::Model) -> String {
+    workout.name.clone().unwrap_or_else(|| "Workout".to_owned())
+}
+
+fn duration_seconds(set: &WorkoutSetRecord) -> Option<Decimal> {
+    set.statistic.duration.map(|d| (d * dec!(60)).round())
+}
+
+/// Strong shows the weights and distances in the unit system of the user while
+/// Ryot always stores them in metric.
+fn strong_rows(workout: &workout::Model, unit_system: UserUnitSystem) -> Vec<StrongRow> {
+    let (weight_factor, distance_factor) = match unit_system {
+        UserUnitSystem::Metric => (Decimal::ONE, Decimal::ONE),
+        UserUnitSystem::Imperial => (dec!(0.45359), dec!(1.60934)),
+    };
+    let minutes = (workout.end_time - workout.start_time).num_minutes();
+    let duration = match minutes / 60 {
+        0 => format!("{}m", minutes % 60),
+        hours => format!("{}h {}m", hours, minutes % 60),
+    };
+    let mut rows = vec![];
+    for exercise in workout.information.exercises.iter() {
+        // DEV: Strong marks warm up sets with a `W` and does not count them in the
+        // order of the working sets
+        let mut set_order = 0;
+        for set in exercise.sets.iter() {
+            let set_order = match set.lot {
+                SetLot::WarmUp => "W".to_owned(),
+                _ => {
+                    set_order += 1;
+                    set_order.to_string()
+                }
+            };
+            rows.push(StrongRow {
+                date: workout.start_time.format("%Y-%m-%d %H:%M:%S").to_string(),
+                workout_name: workout_name(workout),
+                duration: duration.clone(),
+                exercise_name: exercise.exercise_name.clone(),
+                set_order,
+                weight: set
+                    .statistic
+                    .weight
+                    .map(|w| (w / weight_factor).round_dp(2)),
+                reps: set.statistic.reps,
+                distance: set
+                    .statistic
+                    .distance
+                    .map(|d| (d / distance_factor).round_dp(2)),
+                seconds: duration_seconds(set),
+                notes: exercise.notes.join("\n"),
+                workout_notes: workout.comment.clone().unwrap_or_default(),
+                rpe: None,
+            });
+        }
+    }
+    rows
+}
+
+fn hevy_rows(workout: &workout::Model) -> Vec<HevyRow> {
+    let mut rows = vec![];
+    for (exercise_idx, exercise) in workout.information.exercises.iter().enumerate() {
+        let superset_id = workout
+            .information
+            .groups
+            .iter()
+            .position(|g| g.exercises.iter().any(|e| usize::from(*e) == exercise_idx));
+        for (set_index, set) in exercise.sets.iter().enumerate() {
+            let set_type = match set.lot {
+                SetLot::Normal => "normal",
+                SetLot::WarmUp => "warmup",
+                SetLot::Drop => "dropset",
+                SetLot::Failure => "failure",
+            };
+            rows.push(HevyRow {
+                title: workout_name(workout),
+                start_time: workout.start_time.format("%d %b %Y, %H:%M").to_string(),
+                end_time: workout.end_time.format("%d %b %Y, %H:%M").to_string(),
+                description: workout.comment.clone().unwrap_or_default(),
+                exercise_title: exercise.exercise_name.clone(),
+                superset_id,
+                exercise_notes: exercise.notes.join("\n"),
+                set_index,
+                set_type: set_type.to_owned(),
+                weight_kg: set.statistic.weight,
+                reps: set.statistic.reps,
+                distance_km: set.statistic.distance,
+                duration_seconds: duration_seconds(set),
+                rpe: None,
+            });
+        }
+    }
+    rows
+}
+
+/// Convert the workouts to a CSV file that can be imported into another workout
+/// tracker. Every row is a single set.
+pub fn workouts_to_csv(
+    workouts: &[workout::Model],
+    format: WorkoutCsvFormat,
+    unit_system: UserUnitSystem,
+) -> Result<String> {
+    let mut writer = Writer::from_writer(vec![]);
+    for workout in workouts.iter() {
+        match format {
+            WorkoutCsvFormat::Strong => {
+                for row in strong_rows(workout, unit_system) {
+                    writer.serialize(row)?;
+                }
+            }
+            WorkoutCsvFormat::Hevy => {
+                for row in hevy_rows(workout) {
+                    writer.serialize(row)?;
+                }
+            }
+        }
+    }
+    let data = writer.into_inner().map_err(|e| anyhow!(e.to_string()))?;
+    Ok(String::from_utf8(data)?)
+}
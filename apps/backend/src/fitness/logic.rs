@@ -1,4 +1,4 @@
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::HashSet};
 
 use anyhow::{anyhow, Result};
 use async_graphql::InputObject;
@@ -14,12 +14,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     entities::{
-        prelude::{Exercise, UserToExercise},
-        user_to_exercise, workout,
+        prelude::{Exercise, UserToExercise, WorkoutSet},
+        user_to_exercise, workout, workout_set,
     },
-    migrator::ExerciseLot,
+    migrator::{ExerciseLot, ExerciseSource},
     models::fitness::{
-        ExerciseBestSetRecord, ProcessedExercise, SetLot, SetStatistic, TotalMeasurement,
+        ExerciseBestSetRecord, ExerciseGroup, ProcessedExercise, ProcessedExerciseGroup, SetLot,
+        SetStatistic, TotalMeasurement, UserToExerciseAssets,
         UserToExerciseBestSetExtraInformation, UserToExerciseExtraInformation,
         UserToExerciseHistoryExtraInformation, WorkoutInformation, WorkoutSetPersonalBest,
         WorkoutSetRecord, WorkoutSummary, WorkoutSummaryExercise,
@@ -64,6 +65,48 @@ fn get_index_of_highest_pb(
         .map(|(index, _)| index)
 }
 
+/// Check that the groups only refer to existing exercises and that every exercise
+/// is part of at most one group.
+pub fn validate_exercise_groups(groups: &[ExerciseGroup], num_exercises: usize) -> Result<()> {
+    let mut seen = HashSet::new();
+    for group in groups {
+        if group.exercises.len() < 2 {
+            return Err(anyhow!("A group needs at least two exercises"));
+        }
+        for idx in group.exercises.iter() {
+            if usize::from(*idx) >= num_exercises {
+                return Err(anyhow!("Group refers to an exercise that does not exist"));
+            }
+            if !seen.insert(*idx) {
+                return Err(anyhow!("An exercise can only be part of one group"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Point the groups at the new positions of their exercises once some of the
+/// exercises were removed. `kept` has the old positions of the remaining
+/// exercises in order, and the groups that are left with less than two
+/// exercises are dropped.
+pub fn remap_exercise_groups(groups: &[ExerciseGroup], kept: &[usize]) -> Vec<ExerciseGroup> {
+    groups
+        .iter()
+        .filter_map(|group| {
+            let exercises = group
+                .exercises
+                .iter()
+                .filter_map(|idx| kept.iter().position(|k| *k == usize::from(*idx)))
+                .filter_map(|idx| u16::try_from(idx).ok())
+                .collect::<Vec<_>>();
+            (exercises.len() >= 2).then(|| ExerciseGroup {
+                exercises,
+                ..group.clone()
+            })
+        })
+        .collect()
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, InputObject)]
 pub struct UserWorkoutSetRecord {
     pub statistic: SetStatistic,
@@ -82,6 +125,9 @@ impl UserWorkoutSetRecord {
                 if let Some(d) = du.statistic.distance.as_mut() {
                     *d *= dec!(1.60934);
                 }
+                if let Some(e) = du.statistic.elevation_gain.as_mut() {
+                    *e *= dec!(0.3048);
+                }
                 du
             }
         }
@@ -98,12 +144,17 @@ pub struct UserExerciseInput {
 
 #[derive(Clone, Debug, Deserialize, Serialize, InputObject)]
 pub struct UserWorkoutInput {
+    /// The template this workout was started from, if any.
+    pub template_id: Option<String>,
+    /// Set when the workout is synced from an external service.
+    #[graphql(skip)]
+    pub external_id: Option<String>,
     pub name: Option<String>,
     pub comment: Option<String>,
     pub start_time: DateTimeUtc,
     pub end_time: DateTimeUtc,
     pub exercises: Vec<UserExerciseInput>,
-    pub supersets: Vec<Vec<u16>>,
+    pub groups: Vec<ExerciseGroup>,
 }
 
 impl UserWorkoutInput {
@@ -114,13 +165,17 @@ impl UserWorkoutInput {
         db: &DatabaseConnection,
         id: String,
         preferences: UserExercisePreferences,
-    ) -> Result<String> {
+    ) -> Result<workout::Model> {
+        validate_exercise_groups(&self.groups, self.exercises.len())?;
         let mut exercises = vec![];
         let mut workout_totals = vec![];
         for (idx, ex) in self.exercises.into_iter().enumerate() {
             let db_ex = Exercise::find_by_id(ex.exercise_id)
                 .one(db)
                 .await?
+                .filter(|e| {
+                    e.source != ExerciseSource::Custom || e.created_by_user_id == Some(user_id)
+                })
                 .ok_or_else(|| anyhow!("No exercise found!"))?;
             let mut sets = vec![];
             let mut total = TotalMeasurement::default();
@@ -146,6 +201,8 @@ impl UserWorkoutInput {
                             history: vec![history_item],
                             lifetime_stats: TotalMeasurement::default(),
                             personal_bests: vec![],
+                            assets: UserToExerciseAssets::default(),
+                            default_rest_time: None,
                         }),
                     };
                     user_to_ex.insert(db).await.unwrap()
@@ -175,6 +232,9 @@ impl UserWorkoutInput {
                 if let Some(d) = set.statistic.distance {
                     total.distance += d;
                 }
+                if let Some(e) = set.statistic.elevation_gain {
+                    total.elevation_gain += e;
+                }
                 sets.push(WorkoutSetRecord {
                     statistic: set.statistic,
                     lot: set.lot,
@@ -191,6 +251,7 @@ impl UserWorkoutInput {
                     WorkoutSetPersonalBest::Weight,
                     WorkoutSetPersonalBest::OneRm,
                     WorkoutSetPersonalBest::Volume,
+                    WorkoutSetPersonalBest::Reps,
                 ],
             };
             for best_type in types_of_prs.iter() {
@@ -248,6 +309,20 @@ impl UserWorkoutInput {
                 total,
             });
         }
+        let groups = self
+            .groups
+            .into_iter()
+            .map(|g| ProcessedExerciseGroup {
+                total: g
+                    .exercises
+                    .iter()
+                    .map(|idx| workout_totals[usize::from(*idx)].clone())
+                    .sum(),
+                lot: g.lot,
+                exercises: g.exercises,
+                rest_time: g.rest_time,
+            })
+            .collect();
         let summary_total = workout_totals.into_iter().sum();
         let model = workout::Model {
             id,
@@ -256,6 +331,7 @@ impl UserWorkoutInput {
             user_id,
             name: self.name,
             comment: self.comment,
+            external_id: self.external_id,
             processed: true,
             summary: WorkoutSummary {
                 total: summary_total,
@@ -268,13 +344,19 @@ impl UserWorkoutInput {
                     })
                     .collect(),
             },
-            information: WorkoutInformation {
-                supersets: self.supersets,
-                exercises,
-            },
+            information: WorkoutInformation { groups, exercises },
         };
         let insert: workout::ActiveModel = model.into();
         let data = insert.insert(db).await?;
-        Ok(data.id)
+        let sets = workout_set::ActiveModel::from_workout(
+            &data.id,
+            user_id,
+            data.start_time,
+            &data.information.exercises,
+        );
+        if !sets.is_empty() {
+            WorkoutSet::insert_many(sets).exec(db).await?;
+        }
+        Ok(data)
     }
 }
@@ -1,3 +1,6 @@
+pub mod export;
 pub mod resolver;
 
 mod logic;
+mod strava;
+mod wger;
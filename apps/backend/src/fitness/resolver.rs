@@ -1,48 +1,80 @@
-use std::sync::Arc;
+use std::{cmp::Reverse, collections::HashMap, sync::Arc};
 
 use apalis::{prelude::Storage, sqlite::SqliteStorage};
-use async_graphql::{Context, Error, InputObject, Object, Result, SimpleObject};
+use async_graphql::{
+    Context, Error, InputObject, Object, OneofObject, Result, SimpleObject, Subscription,
+};
+use chrono::{Duration, NaiveDate, Utc};
+use futures::{stream, Stream};
 use itertools::Itertools;
+use nanoid::nanoid;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use sea_orm::{
     prelude::DateTimeUtc, ActiveModelTrait, ActiveValue, ColumnTrait, DatabaseConnection,
-    EntityTrait, ModelTrait, PaginatorTrait, QueryFilter, QueryOrder, QueryTrait,
+    EntityTrait, FromQueryResult, ModelTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    QueryTrait,
 };
 use sea_query::{Alias, Condition, Expr, Func};
 use serde::{Deserialize, Serialize};
 use sonyflake::Sonyflake;
 use strum::IntoEnumIterator;
+use tokio::sync::broadcast;
 use tracing::instrument;
 
 use crate::{
     background::ApplicationJob,
-    config::AppConfig,
+    config::{AppConfig, IsFeatureEnabled},
     entities::{
         exercise,
-        prelude::{Exercise, UserMeasurement, UserToExercise, Workout},
-        user_measurement, user_to_exercise, workout,
+        prelude::{
+            Exercise, SharedWorkout, TrainingProgram, User, UserMeasurement, UserToExercise,
+            Workout, WorkoutSet, WorkoutTemplate,
+        },
+        shared_workout, training_program, user, user_measurement, user_to_exercise, workout,
+        workout_set, workout_template,
     },
     file_storage::FileStorageService,
     migrator::{
         ExerciseEquipment, ExerciseForce, ExerciseLevel, ExerciseLot, ExerciseMechanic,
-        ExerciseMuscle,
+        ExerciseMuscle, ExerciseSource,
     },
     models::{
         fitness::{
-            Exercise as GithubExercise, ExerciseAttributes, ExerciseCategory, ExerciseMuscles,
-            GithubExerciseAttributes, WorkoutSetRecord,
+            Exercise as GithubExercise, ExerciseAttributes, ExerciseCategory, ExerciseGroup,
+            ExerciseLocalizedName, ExerciseMuscles, ExternalWorkout, GithubExerciseAttributes,
+            InProgressWorkout, SetLot, SetStatistic, TrainingProgramCompletion,
+            TrainingProgramCompletions, TrainingProgramInformation, TrainingProgramProgression,
+            UserMeasurementStats, UserToExerciseExtraInformation, WorkoutSetPersonalBest,
+            WorkoutSetRecord, WorkoutTemplateInformation, WorkoutTemplateSet,
         },
         SearchDetails, SearchResults, StoredUrl,
     },
     traits::AuthProvider,
-    utils::{get_case_insensitive_like_query, user_by_id},
+    users::{
+        UserExercisePreferences, UserUnitSystem, UserYankIntegration, UserYankIntegrationSetting,
+        UserYankIntegrations,
+    },
+    utils::{
+        get_case_insensitive_like_query, get_stored_asset, send_notifications_to_user_platforms,
+        user_by_id,
+    },
 };
 
-use super::logic::UserWorkoutInput;
+use super::{
+    export::{workouts_to_csv, WorkoutCsvFormat},
+    logic::{
+        remap_exercise_groups, validate_exercise_groups, UserExerciseInput, UserWorkoutInput,
+        UserWorkoutSetRecord,
+    },
+    strava,
+    wger::{self, WgerExercise},
+};
 
 static JSON_URL: &str =
     "https://raw.githubusercontent.com/yuhonas/free-exercise-db/main/dist/exercises.json";
 static IMAGES_PREFIX_URL: &str =
     "https://raw.githubusercontent.com/yuhonas/free-exercise-db/main/exercises";
+static SUBSTITUTIONS_LIMIT: usize = 10;
 
 #[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
 struct ExerciseListFilter {
@@ -62,6 +94,18 @@ struct ExercisesListInput {
     filter: Option<ExerciseListFilter>,
 }
 
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+struct ExerciseInput {
+    name: String,
+    lot: ExerciseLot,
+    level: ExerciseLevel,
+    force: Option<ExerciseForce>,
+    mechanic: Option<ExerciseMechanic>,
+    equipment: Option<ExerciseEquipment>,
+    muscles: Vec<ExerciseMuscle>,
+    instructions: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
 struct ExerciseParameters {
     /// All filters applicable to an exercises query.
@@ -86,6 +130,22 @@ struct UserMeasurementsListInput {
     end_time: Option<DateTimeUtc>,
 }
 
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+struct UserMeasurementPhotosInput {
+    /// The timestamp of the measurement to attach the photos to.
+    timestamp: DateTimeUtc,
+    /// The keys of the photos uploaded using `presignedPutUrl`.
+    photos: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
+struct ProgressPhotosTimelineItem {
+    timestamp: DateTimeUtc,
+    name: Option<String>,
+    stats: UserMeasurementStats,
+    photos: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
 struct UserExerciseHistoryInformation {
     workout_id: String,
@@ -98,6 +158,175 @@ struct UserExerciseHistoryInformation {
 struct UserExerciseInformation {
     details: user_to_exercise::Model,
     history: Vec<UserExerciseHistoryInformation>,
+    images: Vec<String>,
+    videos: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+struct PlateBreakdownInput {
+    /// The total weight to be lifted, including the bar.
+    target_weight: Decimal,
+    /// The bar to use. Defaults to the heaviest bar of the user that is not heavier
+    /// than the target weight.
+    bar_weight: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
+struct PlateBreakdown {
+    bar_weight: Decimal,
+    /// The plates to load on each side of the bar, heaviest first.
+    plates_per_side: Vec<Decimal>,
+    /// The weight that can be loaded with the available plates, which can be lower
+    /// than the target weight.
+    total_weight: Decimal,
+    /// The weight of the dumbbell closest to the target weight.
+    closest_dumbbell_weight: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
+struct PersonalRecord {
+    lot: WorkoutSetPersonalBest,
+    value: Decimal,
+    workout_id: String,
+    set_idx: usize,
+    set: WorkoutSetRecord,
+}
+
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
+struct ExercisePersonalRecords {
+    exercise_id: i32,
+    exercise_name: String,
+    records: Vec<PersonalRecord>,
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+struct ExerciseAnalyticsInput {
+    exercise_id: i32,
+    start_date: Option<NaiveDate>,
+    end_date: Option<NaiveDate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone, FromQueryResult)]
+struct ExerciseOneRmPoint {
+    date: NaiveDate,
+    /// The best estimated one rep max of the day.
+    one_rm: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone, FromQueryResult)]
+struct ExerciseWeeklyVolume {
+    /// The monday of the week.
+    week_start: NaiveDate,
+    volume: Option<Decimal>,
+    distance: Option<Decimal>,
+    duration: Option<Decimal>,
+    elevation_gain: Option<Decimal>,
+    num_sets: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone, FromQueryResult)]
+struct ExerciseCardioPoint {
+    date: NaiveDate,
+    distance: Option<Decimal>,
+    duration: Option<Decimal>,
+    elevation_gain: Option<Decimal>,
+    average_heart_rate: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone, FromQueryResult)]
+struct ExerciseRepCount {
+    reps: i32,
+    num_sets: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
+struct ExerciseAnalytics {
+    one_rm_history: Vec<ExerciseOneRmPoint>,
+    cardio_history: Vec<ExerciseCardioPoint>,
+    weekly_volume: Vec<ExerciseWeeklyVolume>,
+    rep_distribution: Vec<ExerciseRepCount>,
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+struct UserExerciseAssetsInput {
+    exercise_id: i32,
+    /// The keys of the images uploaded using `presignedPutUrl`.
+    images: Vec<String>,
+    /// The keys of the videos uploaded using `presignedPutUrl`.
+    videos: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+struct WorkoutTemplateInput {
+    name: String,
+    information: WorkoutTemplateInformation,
+}
+
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
+struct WorkoutFromTemplateExercise {
+    exercise_id: i32,
+    exercise_name: String,
+    lot: ExerciseLot,
+    sets: Vec<WorkoutTemplateSet>,
+    notes: Vec<String>,
+    rest_time: Option<u16>,
+}
+
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
+struct WorkoutFromTemplate {
+    name: String,
+    start_time: DateTimeUtc,
+    /// The supersets and circuits in this workout.
+    groups: Vec<ExerciseGroup>,
+    exercises: Vec<WorkoutFromTemplateExercise>,
+}
+
+#[derive(Debug, Serialize, Deserialize, OneofObject, Clone)]
+enum ShareWorkoutSource {
+    WorkoutId(String),
+    TemplateId(String),
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+struct ShareWorkoutInput {
+    source: ShareWorkoutSource,
+    /// The user to share the workout with. If not set, anyone with the token can
+    /// view and copy it.
+    username: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
+struct SharedWorkoutDetails {
+    token: String,
+    name: String,
+    /// The name of the user who shared the workout.
+    shared_by: String,
+    created_on: DateTimeUtc,
+    information: WorkoutTemplateInformation,
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+struct TrainingProgramInput {
+    name: String,
+    start_date: NaiveDate,
+    information: TrainingProgramInformation,
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+struct TrainingProgramScheduleInput {
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+}
+
+#[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
+struct ScheduledWorkout {
+    date: NaiveDate,
+    program_id: String,
+    program_name: String,
+    template_id: String,
+    template_name: Option<String>,
+    /// The workout that completed this scheduled workout, if any.
+    completed_workout_id: Option<String>,
 }
 
 #[derive(Default)]
@@ -118,7 +347,8 @@ impl ExerciseQuery {
         input: ExercisesListInput,
     ) -> Result<SearchResults<exercise::Model>> {
         let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
-        service.exercises_list(input).await
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.exercises_list(user_id, input).await
     }
 
     /// Get details about an exercise.
@@ -128,7 +358,20 @@ impl ExerciseQuery {
         exercise_id: i32,
     ) -> Result<exercise::Model> {
         let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
-        service.exercise_details(exercise_id).await
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.exercise_details(user_id, exercise_id).await
+    }
+
+    /// Get exercises that work the same muscles as an exercise and can be
+    /// performed with the equipment available to the user.
+    async fn exercise_substitutions(
+        &self,
+        gql_ctx: &Context<'_>,
+        exercise_id: i32,
+    ) -> Result<Vec<exercise::Model>> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.exercise_substitutions(user_id, exercise_id).await
     }
 
     /// Get information about an exercise for a user.
@@ -142,6 +385,93 @@ impl ExerciseQuery {
         service.user_exercise_details(exercise_id, user_id).await
     }
 
+    /// Get the progression of a user in an exercise over time.
+    async fn exercise_analytics(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: ExerciseAnalyticsInput,
+    ) -> Result<ExerciseAnalytics> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.exercise_analytics(user_id, input).await
+    }
+
+    /// Get the current personal records of the user, optionally only for a single
+    /// exercise.
+    async fn user_personal_records(
+        &self,
+        gql_ctx: &Context<'_>,
+        exercise_id: Option<i32>,
+    ) -> Result<Vec<ExercisePersonalRecords>> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.user_personal_records(user_id, exercise_id).await
+    }
+
+    /// Get all the workout templates created by the user.
+    async fn workout_templates(
+        &self,
+        gql_ctx: &Context<'_>,
+    ) -> Result<Vec<workout_template::Model>> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.workout_templates(user_id).await
+    }
+
+    /// Get a workout shared by another user. Shares without a recipient can be
+    /// viewed without logging in.
+    async fn shared_workout(
+        &self,
+        gql_ctx: &Context<'_>,
+        token: String,
+    ) -> Result<SharedWorkoutDetails> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await.ok();
+        service.shared_workout(user_id, token).await
+    }
+
+    /// Get all the workouts that other users have shared with the user.
+    async fn shared_workouts(&self, gql_ctx: &Context<'_>) -> Result<Vec<SharedWorkoutDetails>> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.shared_workouts(user_id).await
+    }
+
+    /// Get all the training programs created by the user.
+    async fn training_programs(
+        &self,
+        gql_ctx: &Context<'_>,
+    ) -> Result<Vec<training_program::Model>> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.training_programs(user_id).await
+    }
+
+    /// Get the workouts scheduled by the user's training programs in the given
+    /// date range.
+    async fn training_program_schedule(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: TrainingProgramScheduleInput,
+    ) -> Result<Vec<ScheduledWorkout>> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.training_program_schedule(user_id, input).await
+    }
+
+    /// Get the URL the user needs to visit to connect their Strava account. Strava
+    /// will redirect back to `redirect_uri` with a `code` that needs to be passed
+    /// to `connectStravaIntegration`.
+    async fn strava_authorization_url(
+        &self,
+        gql_ctx: &Context<'_>,
+        redirect_uri: String,
+    ) -> Result<String> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        service.user_id_from_ctx(gql_ctx).await?;
+        service.strava_authorization_url(redirect_uri).await
+    }
+
     /// Get all the measurements for a user.
     async fn user_measurements_list(
         &self,
@@ -152,6 +482,39 @@ impl ExerciseQuery {
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
         service.user_measurements_list(user_id, input).await
     }
+
+    /// Get all the measurements of the user that have progress photos attached.
+    async fn user_progress_photos(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: UserMeasurementsListInput,
+    ) -> Result<Vec<ProgressPhotosTimelineItem>> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.user_progress_photos(user_id, input).await
+    }
+
+    /// Get the plates to load on the bar for a weight, using the equipment in the
+    /// preferences of the user.
+    async fn plate_breakdown(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: PlateBreakdownInput,
+    ) -> Result<PlateBreakdown> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.plate_breakdown(user_id, input).await
+    }
+
+    /// Get the workout that the user is currently recording, if any.
+    async fn in_progress_workout(
+        &self,
+        gql_ctx: &Context<'_>,
+    ) -> Result<Option<InProgressWorkout>> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.in_progress_workout(user_id).await
+    }
 }
 
 #[derive(Default)]
@@ -165,6 +528,40 @@ impl ExerciseMutation {
         service.deploy_update_exercise_library_job().await
     }
 
+    /// Set the rest time (in seconds) to use by default after each set of an
+    /// exercise. Pass `null` to remove it.
+    async fn update_user_exercise_rest_time(
+        &self,
+        gql_ctx: &Context<'_>,
+        exercise_id: i32,
+        rest_time: Option<u16>,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .update_user_exercise_rest_time(user_id, exercise_id, rest_time)
+            .await
+    }
+
+    /// Save the workout that the user is currently recording, so that it can be
+    /// continued from another device.
+    async fn update_in_progress_workout(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: InProgressWorkout,
+    ) -> Result<DateTimeUtc> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.update_in_progress_workout(user_id, input).await
+    }
+
+    /// Discard the workout that the user is currently recording.
+    async fn delete_in_progress_workout(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.set_in_progress_workout(user_id, None).await
+    }
+
     /// Create a user measurement.
     async fn create_user_measurement(
         &self,
@@ -187,88 +584,440 @@ impl ExerciseMutation {
         service.delete_user_measurement(user_id, timestamp).await
     }
 
-    /// Take a user workout, process it and commit it to database.
-    async fn create_user_workout(
+    /// Attach uploaded progress photos to a user measurement.
+    async fn add_user_measurement_photos(
         &self,
         gql_ctx: &Context<'_>,
-        input: UserWorkoutInput,
-    ) -> Result<String> {
+        input: UserMeasurementPhotosInput,
+    ) -> Result<bool> {
         let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.create_user_workout(user_id, input).await
+        service.add_user_measurement_photos(user_id, input).await
     }
-}
-
-pub struct ExerciseService {
-    db: DatabaseConnection,
-    config: Arc<AppConfig>,
-    file_storage_service: Arc<FileStorageService>,
-    perform_application_job: SqliteStorage<ApplicationJob>,
-}
 
-impl AuthProvider for ExerciseService {}
+    /// Remove a progress photo from a user measurement.
+    async fn remove_user_measurement_photo(
+        &self,
+        gql_ctx: &Context<'_>,
+        timestamp: DateTimeUtc,
+        key: String,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .remove_user_measurement_photo(user_id, timestamp, key)
+            .await
+    }
 
-impl ExerciseService {
-    pub fn new(
-        db: &DatabaseConnection,
-        config: Arc<AppConfig>,
-        file_storage_service: Arc<FileStorageService>,
-        perform_application_job: &SqliteStorage<ApplicationJob>,
-    ) -> Self {
-        Self {
-            db: db.clone(),
-            config,
-            file_storage_service,
-            perform_application_job: perform_application_job.clone(),
-        }
+    /// Connect the Strava account of the user so that their activities are synced
+    /// as workouts.
+    async fn connect_strava_integration(
+        &self,
+        gql_ctx: &Context<'_>,
+        code: String,
+    ) -> Result<usize> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.connect_strava_integration(user_id, code).await
     }
-}
 
-impl ExerciseService {
-    async fn exercise_parameters(&self) -> Result<ExerciseParameters> {
-        let download_required = Exercise::find().count(&self.db).await? == 0;
-        Ok(ExerciseParameters {
-            filters: ExerciseFilters {
-                lot: ExerciseLot::iter().collect_vec(),
-                level: ExerciseLevel::iter().collect_vec(),
-                force: ExerciseForce::iter().collect_vec(),
-                mechanic: ExerciseMechanic::iter().collect_vec(),
-                equipment: ExerciseEquipment::iter().collect_vec(),
-                muscle: ExerciseMuscle::iter().collect_vec(),
-            },
-            download_required,
-        })
+    /// Create a custom exercise that is only visible to the user.
+    async fn create_custom_exercise(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: ExerciseInput,
+    ) -> Result<i32> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.create_custom_exercise(user_id, input).await
     }
 
-    async fn get_all_exercises_from_dataset(&self) -> Result<Vec<GithubExercise>> {
-        let data: Vec<GithubExercise> = surf::get(JSON_URL)
-            .send()
-            .await
-            .unwrap()
-            .body_json()
+    /// Edit a custom exercise created by the user.
+    async fn edit_custom_exercise(
+        &self,
+        gql_ctx: &Context<'_>,
+        exercise_id: i32,
+        input: ExerciseInput,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .edit_custom_exercise(user_id, exercise_id, input)
             .await
-            .unwrap();
-        Ok(data
-            .into_iter()
-            .map(|e| GithubExercise {
-                attributes: GithubExerciseAttributes {
-                    images: e
-                        .attributes
-                        .images
-                        .into_iter()
-                        .map(|i| format!("{}/{}", IMAGES_PREFIX_URL, i))
-                        .collect(),
-                    ..e.attributes
-                },
-                ..e
-            })
-            .collect())
     }
 
-    async fn exercise_details(&self, exercise_id: i32) -> Result<exercise::Model> {
-        let maybe_exercise = Exercise::find_by_id(exercise_id).one(&self.db).await?;
-        match maybe_exercise {
-            None => Err(Error::new("Exercise with the given ID could not be found.")),
+    /// Attach uploaded images and videos to an exercise. They are only visible to
+    /// the user.
+    async fn add_user_exercise_assets(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: UserExerciseAssetsInput,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.add_user_exercise_assets(user_id, input).await
+    }
+
+    /// Remove an image or video that the user attached to an exercise.
+    /// The association of the user with an exercise, which is created if the user
+    /// has not performed the exercise yet.
+    async fn user_exercise_association(
+        &self,
+        user_id: i32,
+        exercise_id: i32,
+    ) -> Result<user_to_exercise::Model> {
+        if Exercise::find_by_id(exercise_id)
+            .filter(exercise_visible_to_user(user_id))
+            .count(&self.db)
+            .await?
+            == 0
+        {
+            return Err(Error::new("Exercise with the given ID could not be found."));
+        }
+        if let Some(a) = UserToExercise::find_by_id((user_id, exercise_id))
+            .one(&self.db)
+            .await?
+        {
+            return Ok(a);
+        }
+        let user_to_ex = user_to_exercise::ActiveModel {
+            user_id: ActiveValue::Set(user_id),
+            exercise_id: ActiveValue::Set(exercise_id),
+            num_times_performed: ActiveValue::Set(0),
+            last_updated_on: ActiveValue::Set(Utc::now()),
+            extra_information: ActiveValue::Set(UserToExerciseExtraInformation::default()),
+        };
+        Ok(user_to_ex.insert(&self.db).await?)
+    }
+
+    async fn update_user_exercise_rest_time(
+        &self,
+        user_id: i32,
+        exercise_id: i32,
+        rest_time: Option<u16>,
+    ) -> Result<bool> {
+        let association = self.user_exercise_association(user_id, exercise_id).await?;
+        let mut extra_information = association.extra_information.clone();
+        extra_information.default_rest_time = rest_time;
+        let mut association: user_to_exercise::ActiveModel = association.into();
+        association.extra_information = ActiveValue::Set(extra_information);
+        association.update(&self.db).await?;
+        Ok(true)
+    }
+
+    async fn plate_breakdown(
+        &self,
+        user_id: i32,
+        input: PlateBreakdownInput,
+    ) -> Result<PlateBreakdown> {
+        if input.target_weight < Decimal::ZERO {
+            return Err(Error::new("The target weight can not be negative."));
+        }
+        let equipment = user_by_id(&self.db, user_id)
+            .await?
+            .preferences
+            .fitness
+            .equipment;
+        let bar_weight = input.bar_weight.unwrap_or_else(|| {
+            equipment
+                .bars
+                .iter()
+                .filter(|b| **b <= input.target_weight)
+                .max()
+                .or_else(|| equipment.bars.iter().min())
+                .copied()
+                .unwrap_or_default()
+        });
+        // DEV: Greedily loading the heaviest plate that fits is exact for the usual
+        // plate sets and uses the least number of plates
+        let mut remaining = (input.target_weight - bar_weight) / Decimal::TWO;
+        let mut plates_per_side = vec![];
+        for plate in equipment
+            .plates
+            .iter()
+            .filter(|p| **p > Decimal::ZERO)
+            .sorted()
+            .rev()
+        {
+            while remaining >= *plate {
+                plates_per_side.push(*plate);
+                remaining -= *plate;
+            }
+        }
+        let total_weight = bar_weight + plates_per_side.iter().sum::<Decimal>() * Decimal::TWO;
+        let closest_dumbbell_weight = match equipment.dumbbell_increment > Decimal::ZERO {
+            true => {
+                (input.target_weight / equipment.dumbbell_increment).round()
+                    * equipment.dumbbell_increment
+            }
+            false => input.target_weight,
+        };
+        Ok(PlateBreakdown {
+            bar_weight,
+            plates_per_side,
+            total_weight,
+            closest_dumbbell_weight,
+        })
+    }
+
+    async fn in_progress_workout(&self, user_id: i32) -> Result<Option<InProgressWorkout>> {
+        let user = user_by_id(&self.db, user_id).await?;
+        Ok(user.in_progress_workout)
+    }
+
+    async fn update_in_progress_workout(
+        &self,
+        user_id: i32,
+        mut input: InProgressWorkout,
+    ) -> Result<DateTimeUtc> {
+        input.updated_on = Utc::now();
+        let updated_on = input.updated_on;
+        self.set_in_progress_workout(user_id, Some(input)).await?;
+        Ok(updated_on)
+    }
+
+    /// Save the workout and notify the other devices of the user about it.
+    async fn set_in_progress_workout(
+        &self,
+        user_id: i32,
+        workout: Option<InProgressWorkout>,
+    ) -> Result<bool> {
+        let user = user_by_id(&self.db, user_id).await?;
+        let mut user: user::ActiveModel = user.into();
+        user.in_progress_workout = ActiveValue::Set(workout.clone());
+        user.update(&self.db).await?;
+        // DEV: Sending only fails when nobody is subscribed, which is fine
+        self.in_progress_workout_updates
+            .send((user_id, workout))
+            .ok();
+        Ok(true)
+    }
+
+    async fn remove_user_exercise_asset(
+        &self,
+        gql_ctx: &Context<'_>,
+        exercise_id: i32,
+        key: String,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .remove_user_exercise_asset(user_id, exercise_id, key)
+            .await
+    }
+
+    /// Create a workout template with the given exercises and target sets.
+    async fn create_workout_template(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: WorkoutTemplateInput,
+    ) -> Result<String> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.create_workout_template(user_id, input).await
+    }
+
+    /// Replace the contents of a workout template.
+    async fn edit_workout_template(
+        &self,
+        gql_ctx: &Context<'_>,
+        template_id: String,
+        input: WorkoutTemplateInput,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .edit_workout_template(user_id, template_id, input)
+            .await
+    }
+
+    /// Delete a workout template.
+    async fn delete_workout_template(
+        &self,
+        gql_ctx: &Context<'_>,
+        template_id: String,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.delete_workout_template(user_id, template_id).await
+    }
+
+    /// Share a workout or a template with another user, or with anyone who has
+    /// the returned token.
+    async fn share_workout(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: ShareWorkoutInput,
+    ) -> Result<String> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.share_workout(user_id, input).await
+    }
+
+    /// Stop sharing a workout.
+    async fn delete_shared_workout(&self, gql_ctx: &Context<'_>, token: String) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.delete_shared_workout(user_id, token).await
+    }
+
+    /// Copy a shared workout into the templates of the user and return the ID of
+    /// the new template.
+    async fn copy_shared_workout(&self, gql_ctx: &Context<'_>, token: String) -> Result<String> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.copy_shared_workout(user_id, token).await
+    }
+
+    /// Get a workout pre-populated with the exercises and target sets of a
+    /// template, ready to be recorded.
+    async fn start_workout_from_template(
+        &self,
+        gql_ctx: &Context<'_>,
+        template_id: String,
+    ) -> Result<WorkoutFromTemplate> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .start_workout_from_template(user_id, template_id)
+            .await
+    }
+
+    /// Create a training program that schedules workout templates onto days.
+    async fn create_training_program(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: TrainingProgramInput,
+    ) -> Result<String> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.create_training_program(user_id, input).await
+    }
+
+    /// Delete a training program.
+    async fn delete_training_program(
+        &self,
+        gql_ctx: &Context<'_>,
+        program_id: String,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.delete_training_program(user_id, program_id).await
+    }
+
+    /// Take a user workout, process it and commit it to database.
+    async fn create_user_workout(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: UserWorkoutInput,
+    ) -> Result<String> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.create_user_workout(user_id, input).await
+    }
+}
+
+#[derive(Default)]
+pub struct ExerciseSubscription;
+
+#[Subscription]
+impl ExerciseSubscription {
+    /// Get the workout that the user is currently recording whenever it changes.
+    /// `null` is sent once the workout is finished or discarded.
+    async fn in_progress_workout_updated(
+        &self,
+        gql_ctx: &Context<'_>,
+    ) -> Result<impl Stream<Item = Option<InProgressWorkout>>> {
+        let service = gql_ctx.data_unchecked::<Arc<ExerciseService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        let receiver = service.in_progress_workout_updates.subscribe();
+        Ok(stream::unfold(receiver, move |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok((id, workout)) if id == user_id => return Some((workout, receiver)),
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        }))
+    }
+}
+
+pub struct ExerciseService {
+    db: DatabaseConnection,
+    config: Arc<AppConfig>,
+    file_storage_service: Arc<FileStorageService>,
+    perform_application_job: SqliteStorage<ApplicationJob>,
+    in_progress_workout_updates: broadcast::Sender<(i32, Option<InProgressWorkout>)>,
+}
+
+impl AuthProvider for ExerciseService {}
+
+impl ExerciseService {
+    pub fn new(
+        db: &DatabaseConnection,
+        config: Arc<AppConfig>,
+        file_storage_service: Arc<FileStorageService>,
+        perform_application_job: &SqliteStorage<ApplicationJob>,
+    ) -> Self {
+        Self {
+            db: db.clone(),
+            config,
+            file_storage_service,
+            perform_application_job: perform_application_job.clone(),
+            in_progress_workout_updates: broadcast::channel(16).0,
+        }
+    }
+}
+
+impl ExerciseService {
+    async fn exercise_parameters(&self) -> Result<ExerciseParameters> {
+        let download_required = Exercise::find().count(&self.db).await? == 0;
+        Ok(ExerciseParameters {
+            filters: ExerciseFilters {
+                lot: ExerciseLot::iter().collect_vec(),
+                level: ExerciseLevel::iter().collect_vec(),
+                force: ExerciseForce::iter().collect_vec(),
+                mechanic: ExerciseMechanic::iter().collect_vec(),
+                equipment: ExerciseEquipment::iter().collect_vec(),
+                muscle: ExerciseMuscle::iter().collect_vec(),
+            },
+            download_required,
+        })
+    }
+
+    async fn get_all_exercises_from_dataset(&self) -> Result<Vec<GithubExercise>> {
+        let data: Vec<GithubExercise> = surf::get(JSON_URL)
+            .send()
+            .await
+            .unwrap()
+            .body_json()
+            .await
+            .unwrap();
+        Ok(data
+            .into_iter()
+            .map(|e| GithubExercise {
+                attributes: GithubExerciseAttributes {
+                    images: e
+                        .attributes
+                        .images
+                        .into_iter()
+                        .map(|i| format!("{}/{}", IMAGES_PREFIX_URL, i))
+                        .collect(),
+                    ..e.attributes
+                },
+                ..e
+            })
+            .collect())
+    }
+
+    async fn exercise_details(&self, user_id: i32, exercise_id: i32) -> Result<exercise::Model> {
+        let maybe_exercise = Exercise::find_by_id(exercise_id)
+            .filter(exercise_visible_to_user(user_id))
+            .one(&self.db)
+            .await?;
+        match maybe_exercise {
+            None => Err(Error::new("Exercise with the given ID could not be found.")),
             Some(e) => Ok(e.graphql_repr(&self.file_storage_service).await),
         }
     }
@@ -311,17 +1060,184 @@ impl ExerciseService {
                     }
                 })
                 .collect();
-            Ok(Some(UserExerciseInformation { details, history }))
+            let mut images = vec![];
+            for image in details.extra_information.assets.images.iter() {
+                images.push(get_stored_asset(image.clone(), &self.file_storage_service).await);
+            }
+            let mut videos = vec![];
+            for video in details.extra_information.assets.videos.iter() {
+                videos.push(get_stored_asset(video.clone(), &self.file_storage_service).await);
+            }
+            Ok(Some(UserExerciseInformation {
+                details,
+                history,
+                images,
+                videos,
+            }))
         } else {
             Ok(None)
         }
     }
 
+    async fn exercise_analytics(
+        &self,
+        user_id: i32,
+        input: ExerciseAnalyticsInput,
+    ) -> Result<ExerciseAnalytics> {
+        let sets = WorkoutSet::find()
+            .select_only()
+            .filter(workout_set::Column::UserId.eq(user_id))
+            .filter(workout_set::Column::ExerciseId.eq(input.exercise_id))
+            .apply_if(input.start_date, |query, v| {
+                query.filter(workout_set::Column::PerformedOn.gte(v))
+            })
+            .apply_if(input.end_date, |query, v| {
+                query.filter(workout_set::Column::PerformedOn.lte(v))
+            });
+        let one_rm_history = sets
+            .clone()
+            .column_as(workout_set::Column::PerformedOn, "date")
+            .column_as(
+                Expr::expr(Func::max(Expr::col(workout_set::Column::OneRm))),
+                "one_rm",
+            )
+            .filter(workout_set::Column::OneRm.is_not_null())
+            .group_by(workout_set::Column::PerformedOn)
+            .order_by_asc(workout_set::Column::PerformedOn)
+            .into_model::<ExerciseOneRmPoint>()
+            .all(&self.db)
+            .await?;
+        let cardio_history = sets
+            .clone()
+            .column_as(workout_set::Column::PerformedOn, "date")
+            .column_as(
+                Expr::expr(Func::sum(Expr::col(workout_set::Column::Distance))),
+                "distance",
+            )
+            .column_as(
+                Expr::expr(Func::sum(Expr::col(workout_set::Column::Duration))),
+                "duration",
+            )
+            .column_as(
+                Expr::expr(Func::sum(Expr::col(workout_set::Column::ElevationGain))),
+                "elevation_gain",
+            )
+            .column_as(
+                Expr::expr(Func::avg(Expr::col(workout_set::Column::AverageHeartRate))),
+                "average_heart_rate",
+            )
+            .filter(
+                Condition::any()
+                    .add(workout_set::Column::Distance.is_not_null())
+                    .add(workout_set::Column::Duration.is_not_null()),
+            )
+            .group_by(workout_set::Column::PerformedOn)
+            .order_by_asc(workout_set::Column::PerformedOn)
+            .into_model::<ExerciseCardioPoint>()
+            .all(&self.db)
+            .await?;
+        let weekly_volume = sets
+            .clone()
+            .column(workout_set::Column::WeekStart)
+            .column_as(
+                Expr::expr(Func::sum(Expr::col(workout_set::Column::Volume))),
+                "volume",
+            )
+            .column_as(
+                Expr::expr(Func::sum(Expr::col(workout_set::Column::Distance))),
+                "distance",
+            )
+            .column_as(
+                Expr::expr(Func::sum(Expr::col(workout_set::Column::Duration))),
+                "duration",
+            )
+            .column_as(
+                Expr::expr(Func::sum(Expr::col(workout_set::Column::ElevationGain))),
+                "elevation_gain",
+            )
+            .column_as(
+                Expr::expr(Func::count(Expr::col(workout_set::Column::Id))),
+                "num_sets",
+            )
+            .group_by(workout_set::Column::WeekStart)
+            .order_by_asc(workout_set::Column::WeekStart)
+            .into_model::<ExerciseWeeklyVolume>()
+            .all(&self.db)
+            .await?;
+        let rep_distribution = sets
+            .column(workout_set::Column::Reps)
+            .column_as(
+                Expr::expr(Func::count(Expr::col(workout_set::Column::Id))),
+                "num_sets",
+            )
+            .filter(workout_set::Column::Reps.is_not_null())
+            .group_by(workout_set::Column::Reps)
+            .order_by_asc(workout_set::Column::Reps)
+            .into_model::<ExerciseRepCount>()
+            .all(&self.db)
+            .await?;
+        Ok(ExerciseAnalytics {
+            one_rm_history,
+            cardio_history,
+            weekly_volume,
+            rep_distribution,
+        })
+    }
+
+    async fn user_personal_records(
+        &self,
+        user_id: i32,
+        exercise_id: Option<i32>,
+    ) -> Result<Vec<ExercisePersonalRecords>> {
+        let associations = UserToExercise::find()
+            .filter(user_to_exercise::Column::UserId.eq(user_id))
+            .apply_if(exercise_id, |query, v| {
+                query.filter(user_to_exercise::Column::ExerciseId.eq(v))
+            })
+            .find_also_related(Exercise)
+            .all(&self.db)
+            .await?;
+        let mut data = vec![];
+        for (association, exercise) in associations {
+            let exercise = match exercise {
+                Some(e) => e,
+                None => continue,
+            };
+            // DEV: the most recent record is always at the start of the list
+            let records = association
+                .extra_information
+                .personal_bests
+                .into_iter()
+                .filter_map(|pb| {
+                    let best = pb.sets.into_iter().next()?;
+                    Some(PersonalRecord {
+                        lot: pb.lot,
+                        value: best.data.get_personal_best(&pb.lot)?,
+                        workout_id: best.workout_id,
+                        set_idx: best.set_idx,
+                        set: best.data,
+                    })
+                })
+                .collect_vec();
+            if records.is_empty() {
+                continue;
+            }
+            data.push(ExercisePersonalRecords {
+                exercise_id: exercise.id,
+                exercise_name: exercise.name,
+                records,
+            });
+        }
+        Ok(data)
+    }
+
     async fn exercises_list(
         &self,
+        user_id: i32,
         input: ExercisesListInput,
     ) -> Result<SearchResults<exercise::Model>> {
         let query = Exercise::find()
+            .filter(exercise_visible_to_user(user_id))
             .apply_if(input.filter, |query, q| {
                 query
                     .apply_if(q.lot, |q, v| q.filter(exercise::Column::Lot.eq(v)))
@@ -378,6 +1294,73 @@ impl ExerciseService {
         })
     }
 
+    async fn exercise_substitutions(
+        &self,
+        user_id: i32,
+        exercise_id: i32,
+    ) -> Result<Vec<exercise::Model>> {
+        let original = self.exercise_details(user_id, exercise_id).await?;
+        if original.muscles.0.is_empty() {
+            return Ok(vec![]);
+        }
+        let available = user_by_id(&self.db, user_id)
+            .await?
+            .preferences
+            .fitness
+            .equipment
+            .available;
+        let mut muscles_condition = Condition::any();
+        for muscle in original.muscles.0.iter() {
+            muscles_condition = muscles_condition.add(get_case_insensitive_like_query(
+                Func::cast_as(Expr::col(exercise::Column::Muscles), Alias::new("text")),
+                &muscle.to_string(),
+            ));
+        }
+        let candidates = Exercise::find()
+            .filter(exercise_visible_to_user(user_id))
+            .filter(exercise::Column::Id.ne(original.id))
+            .filter(exercise::Column::Lot.eq(original.lot))
+            .filter(muscles_condition)
+            .all(&self.db)
+            .await?;
+        // DEV: Exercises without equipment or that only need the body can always
+        // be performed
+        let substitutions = candidates
+            .into_iter()
+            .filter(|ex| match ex.equipment {
+                None | Some(ExerciseEquipment::BodyOnly) => true,
+                Some(equipment) => available.is_empty() || available.contains(&equipment),
+            })
+            .map(|ex| {
+                let common = ex
+                    .muscles
+                    .0
+                    .iter()
+                    .filter(|m| original.muscles.0.contains(m))
+                    .count();
+                let missing = original.muscles.0.len() - common;
+                let extra = ex.muscles.0.len() - common;
+                let similar = [
+                    ex.mechanic == original.mechanic,
+                    ex.force == original.force,
+                    ex.level == original.level,
+                ]
+                .into_iter()
+                .filter(|s| *s)
+                .count();
+                ((missing + extra, Reverse(similar), ex.name.clone()), ex)
+            })
+            .sorted_by(|a, b| a.0.cmp(&b.0))
+            .take(SUBSTITUTIONS_LIMIT)
+            .map(|(_, ex)| ex)
+            .collect_vec();
+        let mut data = vec![];
+        for ex in substitutions {
+            data.push(ex.graphql_repr(&self.file_storage_service).await);
+        }
+        Ok(data)
+    }
+
     #[instrument(skip(self))]
     async fn deploy_update_exercise_library_job(&self) -> Result<i32> {
         let exercises = self.get_all_exercises_from_dataset().await?;
@@ -391,12 +1374,136 @@ impl ExerciseService {
             tracing::trace!(job_id = ?job, "Deployed job to update exercise library.");
             job_ids.push(job.to_string());
         }
+        if self.config.exercise.wger.is_enabled() {
+            let job = self
+                .perform_application_job
+                .clone()
+                .push(ApplicationJob::SyncWgerExercises)
+                .await?;
+            job_ids.push(job.to_string());
+        }
         Ok(job_ids.len().try_into().unwrap())
     }
 
+    /// Supplement the exercise library with the exercises of the configured wger
+    /// instance. Exercises are merged by their wger UUID, or by name with the ones
+    /// from the bundled dataset.
+    pub async fn sync_wger_exercises(&self) -> Result<()> {
+        let config = &self.config.exercise.wger;
+        if !config.is_enabled() {
+            return Ok(());
+        }
+        let languages = wger::languages(config)
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let exercises = wger::exercises(config)
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        tracing::debug!("Syncing {} exercises from wger", exercises.len());
+        for ex in exercises {
+            if let Err(err) = self.update_wger_exercise(&languages, ex).await {
+                tracing::error!("Could not sync wger exercise: {:?}", err);
+            }
+        }
+        Ok(())
+    }
+
+    async fn update_wger_exercise(
+        &self,
+        languages: &HashMap<i32, String>,
+        ex: WgerExercise,
+    ) -> Result<()> {
+        let mut localized_names = vec![];
+        let mut english = None;
+        for translation in ex.translations.iter() {
+            let language = match languages.get(&translation.language) {
+                Some(l) => l.clone(),
+                None => continue,
+            };
+            if language == "en" {
+                english = Some(translation);
+            } else {
+                localized_names.push(ExerciseLocalizedName {
+                    language,
+                    name: translation.name.trim().to_owned(),
+                });
+            }
+        }
+        // DEV: The exercise library is in english, so exercises without an english
+        // name can not be merged with it
+        let english = match english {
+            Some(e) => e,
+            None => return Ok(()),
+        };
+        let name = english.name.trim().to_owned();
+        let external_id = format!("wger:{}", ex.uuid);
+        let existing = match Exercise::find()
+            .filter(exercise::Column::ExternalId.eq(&external_id))
+            .one(&self.db)
+            .await?
+        {
+            Some(e) => Some(e),
+            None => {
+                Exercise::find()
+                    .filter(exercise::Column::Name.eq(&name))
+                    .filter(exercise::Column::Source.ne(ExerciseSource::Custom))
+                    .one(&self.db)
+                    .await?
+            }
+        };
+        if let Some(e) = existing {
+            let mut attributes = e.attributes.clone();
+            attributes.localized_names = localized_names;
+            let mut db_ex: exercise::ActiveModel = e.into();
+            db_ex.external_id = ActiveValue::Set(Some(external_id));
+            db_ex.attributes = ActiveValue::Set(attributes);
+            db_ex.update(&self.db).await?;
+            return Ok(());
+        }
+        self.ensure_exercise_name_available(&name, None).await?;
+        let lot = if ex.category.name == "Cardio" {
+            ExerciseLot::DistanceAndDuration
+        } else {
+            ExerciseLot::RepsAndWeight
+        };
+        let muscles = ex
+            .muscles
+            .iter()
+            .chain(ex.muscles_secondary.iter())
+            .filter_map(|m| m.muscle())
+            .unique()
+            .sorted_unstable()
+            .collect_vec();
+        let db_exercise = exercise::ActiveModel {
+            name: ActiveValue::Set(name),
+            identifier: ActiveValue::Set(format!("wger-{}", ex.uuid)),
+            muscles: ActiveValue::Set(ExerciseMuscles(muscles)),
+            attributes: ActiveValue::Set(ExerciseAttributes {
+                instructions: english.instructions(),
+                internal_images: ex
+                    .images
+                    .into_iter()
+                    .map(|i| StoredUrl::Url(i.image))
+                    .collect(),
+                images: vec![],
+                muscles: vec![],
+                localized_names,
+            }),
+            lot: ActiveValue::Set(lot),
+            level: ActiveValue::Set(ExerciseLevel::Beginner),
+            equipment: ActiveValue::Set(ex.equipment.iter().find_map(|e| e.equipment())),
+            source: ActiveValue::Set(ExerciseSource::Wger),
+            external_id: ActiveValue::Set(Some(external_id)),
+            ..Default::default()
+        };
+        let created_exercise = db_exercise.insert(&self.db).await?;
+        tracing::trace!("Created wger exercise with id: {}", created_exercise.id);
+        Ok(())
+    }
+
     #[instrument(skip(self, ex))]
     pub async fn update_exercise(&self, ex: GithubExercise) -> Result<()> {
-        let attributes = ExerciseAttributes {
+        let mut attributes = ExerciseAttributes {
             muscles: vec![],
             instructions: ex.attributes.instructions,
             internal_images: ex
@@ -406,6 +1513,7 @@ impl ExerciseService {
                 .map(StoredUrl::Url)
                 .collect(),
             images: vec![],
+            localized_names: vec![],
         };
         if let Some(e) = Exercise::find()
             .filter(exercise::Column::Identifier.eq(&ex.identifier))
@@ -416,7 +1524,23 @@ impl ExerciseService {
                 "Updating existing exercise with identifier: {}",
                 ex.identifier
             );
+            attributes.localized_names = e.attributes.localized_names.clone();
+            let mut db_ex: exercise::ActiveModel = e.into();
+            db_ex.attributes = ActiveValue::Set(attributes);
+            db_ex.update(&self.db).await?;
+        } else if let Some(e) = Exercise::find()
+            .filter(exercise::Column::Name.eq(&ex.name))
+            .filter(exercise::Column::Source.eq(ExerciseSource::Wger))
+            .one(&self.db)
+            .await?
+        {
+            // DEV: The exercise was added from wger before the bundled dataset was
+            // downloaded, so it is adopted instead of creating a duplicate
+            tracing::trace!("Adopting wger exercise with identifier: {}", ex.identifier);
+            attributes.localized_names = e.attributes.localized_names.clone();
             let mut db_ex: exercise::ActiveModel = e.into();
+            db_ex.identifier = ActiveValue::Set(ex.identifier);
+            db_ex.source = ActiveValue::Set(ExerciseSource::Github);
             db_ex.attributes = ActiveValue::Set(attributes);
             db_ex.update(&self.db).await?;
         } else {
@@ -450,6 +1574,175 @@ impl ExerciseService {
         Ok(())
     }
 
+    async fn create_custom_exercise(&self, user_id: i32, input: ExerciseInput) -> Result<i32> {
+        self.ensure_exercise_name_available(&input.name, None)
+            .await?;
+        let sf = Sonyflake::new().unwrap();
+        let identifier = format!("custom-{}", sf.next_id().unwrap());
+        let mut muscles = input.muscles.into_iter().unique().collect_vec();
+        muscles.sort_unstable();
+        let db_exercise = exercise::ActiveModel {
+            name: ActiveValue::Set(input.name),
+            identifier: ActiveValue::Set(identifier),
+            muscles: ActiveValue::Set(ExerciseMuscles(muscles)),
+            attributes: ActiveValue::Set(ExerciseAttributes {
+                instructions: input.instructions,
+                internal_images: vec![],
+                images: vec![],
+                muscles: vec![],
+                localized_names: vec![],
+            }),
+            lot: ActiveValue::Set(input.lot),
+            level: ActiveValue::Set(input.level),
+            force: ActiveValue::Set(input.force),
+            equipment: ActiveValue::Set(input.equipment),
+            mechanic: ActiveValue::Set(input.mechanic),
+            source: ActiveValue::Set(ExerciseSource::Custom),
+            created_by_user_id: ActiveValue::Set(Some(user_id)),
+            ..Default::default()
+        };
+        let created_exercise = db_exercise.insert(&self.db).await?;
+        tracing::trace!("Created custom exercise with id: {}", created_exercise.id);
+        Ok(created_exercise.id)
+    }
+
+    async fn edit_custom_exercise(
+        &self,
+        user_id: i32,
+        exercise_id: i32,
+        input: ExerciseInput,
+    ) -> Result<bool> {
+        let e = match Exercise::find_by_id(exercise_id)
+            .filter(exercise::Column::Source.eq(ExerciseSource::Custom))
+            .filter(exercise::Column::CreatedByUserId.eq(user_id))
+            .one(&self.db)
+            .await?
+        {
+            Some(e) => e,
+            None => return Err(Error::new("This exercise can not be edited.")),
+        };
+        self.ensure_exercise_name_available(&input.name, Some(exercise_id))
+            .await?;
+        let mut muscles = input.muscles.into_iter().unique().collect_vec();
+        muscles.sort_unstable();
+        let mut attributes = e.attributes.clone();
+        attributes.instructions = input.instructions;
+        let mut db_ex: exercise::ActiveModel = e.into();
+        db_ex.name = ActiveValue::Set(input.name);
+        db_ex.muscles = ActiveValue::Set(ExerciseMuscles(muscles));
+        db_ex.attributes = ActiveValue::Set(attributes);
+        db_ex.lot = ActiveValue::Set(input.lot);
+        db_ex.level = ActiveValue::Set(input.level);
+        db_ex.force = ActiveValue::Set(input.force);
+        db_ex.equipment = ActiveValue::Set(input.equipment);
+        db_ex.mechanic = ActiveValue::Set(input.mechanic);
+        db_ex.update(&self.db).await?;
+        Ok(true)
+    }
+
+    async fn add_user_exercise_assets(
+        &self,
+        user_id: i32,
+        input: UserExerciseAssetsInput,
+    ) -> Result<bool> {
+        if !self.config.file_storage.is_enabled() {
+            return Err(Error::new("File storage is not enabled."));
+        }
+        if input
+            .images
+            .iter()
+            .chain(input.videos.iter())
+            .any(|k| !FileStorageService::is_user_upload(k, user_id))
+        {
+            return Err(Error::new("This file was not uploaded by the user"));
+        }
+        let association = self
+            .user_exercise_association(user_id, input.exercise_id)
+            .await?;
+        let mut extra_information = association.extra_information.clone();
+        extra_information
+            .assets
+            .images
+            .extend(input.images.into_iter().map(StoredUrl::S3));
+        extra_information
+            .assets
+            .videos
+            .extend(input.videos.into_iter().map(StoredUrl::S3));
+        let mut association: user_to_exercise::ActiveModel = association.into();
+        association.extra_information = ActiveValue::Set(extra_information);
+        association.update(&self.db).await?;
+        Ok(true)
+    }
+
+    async fn remove_user_exercise_asset(
+        &self,
+        user_id: i32,
+        exercise_id: i32,
+        key: String,
+    ) -> Result<bool> {
+        let association = match UserToExercise::find_by_id((user_id, exercise_id))
+            .one(&self.db)
+            .await?
+        {
+            Some(a) => a,
+            None => return Ok(false),
+        };
+        let key = StoredUrl::S3(key);
+        let mut extra_information = association.extra_information.clone();
+        extra_information.assets.images.retain(|i| i != &key);
+        extra_information.assets.videos.retain(|v| v != &key);
+        if extra_information == association.extra_information {
+            return Ok(false);
+        }
+        let mut association: user_to_exercise::ActiveModel = association.into();
+        association.extra_information = ActiveValue::Set(extra_information);
+        association.update(&self.db).await?;
+        Ok(true)
+    }
+
+    /// Exercise names are unique across the built-in and custom exercises.
+    async fn ensure_exercise_name_available(
+        &self,
+        name: &str,
+        exclude_id: Option<i32>,
+    ) -> Result<()> {
+        let existing = Exercise::find()
+            .filter(exercise::Column::Name.eq(name))
+            .apply_if(exclude_id, |query, v| {
+                query.filter(exercise::Column::Id.ne(v))
+            })
+            .count(&self.db)
+            .await?;
+        if existing > 0 {
+            return Err(Error::new("An exercise with this name already exists."));
+        }
+        Ok(())
+    }
+
+    pub async fn export_workouts(&self, user_id: i32) -> Result<Vec<workout::Model>> {
+        let workouts = Workout::find()
+            .filter(workout::Column::UserId.eq(user_id))
+            .order_by_asc(workout::Column::StartTime)
+            .all(&self.db)
+            .await?;
+        Ok(workouts)
+    }
+
+    pub async fn export_workouts_csv(
+        &self,
+        user_id: i32,
+        format: WorkoutCsvFormat,
+    ) -> Result<String> {
+        let unit_system = user_by_id(&self.db, user_id)
+            .await?
+            .preferences
+            .fitness
+            .exercises
+            .unit_system;
+        let workouts = self.export_workouts(user_id).await?;
+        Ok(workouts_to_csv(&workouts, format, unit_system)?)
+    }
+
     pub async fn export_measurements(&self, user_id: i32) -> Result<Vec<user_measurement::Model>> {
         self.user_measurements_list(
             user_id,
@@ -480,7 +1773,7 @@ impl ExerciseService {
         Ok(resp)
     }
 
-    async fn create_user_measurement(
+    pub async fn create_user_measurement(
         &self,
         user_id: i32,
         mut input: user_measurement::Model,
@@ -497,21 +1790,993 @@ impl ExerciseService {
             .await?
         {
             m.delete(&self.db).await?;
+            self.perform_application_job
+                .clone()
+                .push(ApplicationJob::RecalculateUserSummary(user_id))
+                .await?;
             Ok(true)
         } else {
             Ok(false)
         }
     }
 
+    async fn user_progress_photos(
+        &self,
+        user_id: i32,
+        input: UserMeasurementsListInput,
+    ) -> Result<Vec<ProgressPhotosTimelineItem>> {
+        let measurements = UserMeasurement::find()
+            .filter(user_measurement::Column::UserId.eq(user_id))
+            .filter(user_measurement::Column::Photos.is_not_null())
+            .apply_if(input.start_time, |query, v| {
+                query.filter(user_measurement::Column::Timestamp.gte(v))
+            })
+            .apply_if(input.end_time, |query, v| {
+                query.filter(user_measurement::Column::Timestamp.lte(v))
+            })
+            .order_by_asc(user_measurement::Column::Timestamp)
+            .all(&self.db)
+            .await?;
+        let mut timeline = vec![];
+        for measurement in measurements {
+            let stored = measurement.photos.unwrap_or_default().0;
+            if stored.is_empty() {
+                continue;
+            }
+            let mut photos = vec![];
+            for photo in stored {
+                photos.push(get_stored_asset(photo, &self.file_storage_service).await);
+            }
+            timeline.push(ProgressPhotosTimelineItem {
+                timestamp: measurement.timestamp,
+                name: measurement.name,
+                stats: measurement.stats,
+                photos,
+            });
+        }
+        Ok(timeline)
+    }
+
+    async fn add_user_measurement_photos(
+        &self,
+        user_id: i32,
+        input: UserMeasurementPhotosInput,
+    ) -> Result<bool> {
+        if !self.config.file_storage.is_enabled() {
+            return Err(Error::new("File storage is not enabled."));
+        }
+        if input
+            .photos
+            .iter()
+            .any(|k| !FileStorageService::is_user_upload(k, user_id))
+        {
+            return Err(Error::new("This file was not uploaded by the user"));
+        }
+        let measurement = match UserMeasurement::find_by_id((input.timestamp, user_id))
+            .one(&self.db)
+            .await?
+        {
+            Some(m) => m,
+            None => {
+                return Err(Error::new(
+                    "Measurement with the given timestamp not found.",
+                ))
+            }
+        };
+        // DEV: The uploads belong to the user, so a photo can only have been
+        // attached to one of their own measurements already
+        let claimed = UserMeasurement::find()
+            .filter(user_measurement::Column::UserId.eq(user_id))
+            .filter(user_measurement::Column::Photos.is_not_null())
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .flat_map(|m| m.photos.map(|p| p.0).unwrap_or_default())
+            .any(|p| matches!(p, StoredUrl::S3(k) if input.photos.contains(&k)));
+        if claimed {
+            return Err(Error::new("This photo has already been attached."));
+        }
+        let mut photos = measurement.photos.clone().unwrap_or_default();
+        photos.0.extend(input.photos.into_iter().map(StoredUrl::S3));
+        let mut measurement: user_measurement::ActiveModel = measurement.into();
+        measurement.photos = ActiveValue::Set(Some(photos));
+        measurement.update(&self.db).await?;
+        Ok(true)
+    }
+
+    async fn remove_user_measurement_photo(
+        &self,
+        user_id: i32,
+        timestamp: DateTimeUtc,
+        key: String,
+    ) -> Result<bool> {
+        let measurement = match UserMeasurement::find_by_id((timestamp, user_id))
+            .one(&self.db)
+            .await?
+        {
+            Some(m) => m,
+            None => return Ok(false),
+        };
+        let key = StoredUrl::S3(key);
+        let mut photos = measurement.photos.clone().unwrap_or_default();
+        if !photos.0.contains(&key) {
+            return Ok(false);
+        }
+        photos.0.retain(|p| p != &key);
+        let mut measurement: user_measurement::ActiveModel = measurement.into();
+        measurement.photos = ActiveValue::Set(Some(photos));
+        measurement.update(&self.db).await?;
+        Ok(true)
+    }
+
+    async fn workout_templates(&self, user_id: i32) -> Result<Vec<workout_template::Model>> {
+        let templates = WorkoutTemplate::find()
+            .filter(workout_template::Column::UserId.eq(user_id))
+            .order_by_asc(workout_template::Column::Name)
+            .all(&self.db)
+            .await?;
+        Ok(templates)
+    }
+
+    async fn workout_template_by_id(
+        &self,
+        user_id: i32,
+        template_id: String,
+    ) -> Result<workout_template::Model> {
+        WorkoutTemplate::find_by_id(template_id)
+            .filter(workout_template::Column::UserId.eq(user_id))
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| Error::new("Workout template with the given ID could not be found."))
+    }
+
+    async fn validate_workout_template(
+        &self,
+        user_id: i32,
+        information: &WorkoutTemplateInformation,
+    ) -> Result<()> {
+        let exercise_ids = information
+            .exercises
+            .iter()
+            .map(|e| e.exercise_id)
+            .unique()
+            .collect_vec();
+        let found = Exercise::find()
+            .filter(exercise::Column::Id.is_in(exercise_ids.clone()))
+            .filter(exercise_visible_to_user(user_id))
+            .count(&self.db)
+            .await?;
+        if found != exercise_ids.len() as u64 {
+            return Err(Error::new("Some of the exercises could not be found."));
+        }
+        validate_exercise_groups(&information.groups, information.exercises.len())
+            .map_err(|e| Error::new(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn create_workout_template(
+        &self,
+        user_id: i32,
+        input: WorkoutTemplateInput,
+    ) -> Result<String> {
+        self.validate_workout_template(user_id, &input.information)
+            .await?;
+        let sf = Sonyflake::new().unwrap();
+        let id = sf.next_id().unwrap().to_string();
+        let template = workout_template::ActiveModel {
+            id: ActiveValue::Set(id),
+            user_id: ActiveValue::Set(user_id),
+            name: ActiveValue::Set(input.name),
+            information: ActiveValue::Set(input.information),
+            ..Default::default()
+        };
+        let template = template.insert(&self.db).await?;
+        Ok(template.id)
+    }
+
+    async fn edit_workout_template(
+        &self,
+        user_id: i32,
+        template_id: String,
+        input: WorkoutTemplateInput,
+    ) -> Result<bool> {
+        let template = self.workout_template_by_id(user_id, template_id).await?;
+        self.validate_workout_template(user_id, &input.information)
+            .await?;
+        let mut template: workout_template::ActiveModel = template.into();
+        template.name = ActiveValue::Set(input.name);
+        template.information = ActiveValue::Set(input.information);
+        template.update(&self.db).await?;
+        Ok(true)
+    }
+
+    async fn delete_workout_template(&self, user_id: i32, template_id: String) -> Result<bool> {
+        let template = self.workout_template_by_id(user_id, template_id).await?;
+        template.delete(&self.db).await?;
+        Ok(true)
+    }
+
+    async fn start_workout_from_template(
+        &self,
+        user_id: i32,
+        template_id: String,
+    ) -> Result<WorkoutFromTemplate> {
+        let template = self.workout_template_by_id(user_id, template_id).await?;
+        let mut exercises = vec![];
+        let mut kept = vec![];
+        for (idx, ex) in template.information.exercises.iter().enumerate() {
+            // DEV: Exercises that were deleted since the template was created are
+            // dropped, the user can add a replacement in the live workout.
+            if let Some(db_ex) = Exercise::find_by_id(ex.exercise_id)
+                .filter(exercise_visible_to_user(user_id))
+                .one(&self.db)
+                .await?
+            {
+                kept.push(idx);
+                exercises.push(WorkoutFromTemplateExercise {
+                    exercise_id: db_ex.id,
+                    exercise_name: db_ex.name,
+                    lot: db_ex.lot,
+                    sets: ex.sets.clone(),
+                    notes: ex.notes.clone(),
+                    rest_time: ex.rest_time,
+                });
+            }
+        }
+        let workout = WorkoutFromTemplate {
+            name: template.name.clone(),
+            start_time: Utc::now(),
+            groups: remap_exercise_groups(&template.information.groups, &kept),
+            exercises,
+        };
+        let mut template: workout_template::ActiveModel = template.into();
+        template.last_used_on = ActiveValue::Set(Some(workout.start_time));
+        template.update(&self.db).await?;
+        Ok(workout)
+    }
+
+    async fn share_workout(&self, user_id: i32, input: ShareWorkoutInput) -> Result<String> {
+        let (name, information) = match input.source {
+            ShareWorkoutSource::WorkoutId(workout_id) => {
+                let workout = Workout::find_by_id(workout_id)
+                    .filter(workout::Column::UserId.eq(user_id))
+                    .one(&self.db)
+                    .await?
+                    .ok_or_else(|| Error::new("Workout with the given ID could not be found."))?;
+                let name = workout
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| workout.start_time.format("%Y-%m-%d").to_string());
+                (name, workout.information.to_template())
+            }
+            ShareWorkoutSource::TemplateId(template_id) => {
+                let template = self.workout_template_by_id(user_id, template_id).await?;
+                (template.name, template.information)
+            }
+        };
+        // DEV: Custom exercises are only visible to the user who created them, so
+        // the recipient would not be able to use the copy
+        let exercise_ids = information
+            .exercises
+            .iter()
+            .map(|e| e.exercise_id)
+            .unique()
+            .collect_vec();
+        let custom = Exercise::find()
+            .filter(exercise::Column::Id.is_in(exercise_ids))
+            .filter(exercise::Column::Source.eq(ExerciseSource::Custom))
+            .count(&self.db)
+            .await?;
+        if custom > 0 {
+            return Err(Error::new(
+                "Workouts with custom exercises can not be shared.",
+            ));
+        }
+        let shared_with_user_id = match input.username {
+            Some(username) => {
+                let recipient = User::find()
+                    .filter(user::Column::Name.eq(username))
+                    .one(&self.db)
+                    .await?
+                    .ok_or_else(|| Error::new("User with the given name could not be found."))?;
+                if recipient.id == user_id {
+                    return Err(Error::new("A workout can not be shared with yourself."));
+                }
+                Some(recipient.id)
+            }
+            None => None,
+        };
+        let shared = shared_workout::ActiveModel {
+            id: ActiveValue::Set(nanoid!(20)),
+            user_id: ActiveValue::Set(user_id),
+            shared_with_user_id: ActiveValue::Set(shared_with_user_id),
+            name: ActiveValue::Set(name),
+            information: ActiveValue::Set(information),
+            ..Default::default()
+        };
+        let shared = shared.insert(&self.db).await?;
+        Ok(shared.id)
+    }
+
+    async fn shared_workout_details(
+        &self,
+        shared: shared_workout::Model,
+    ) -> Result<SharedWorkoutDetails> {
+        let shared_by = user_by_id(&self.db, shared.user_id).await?.name;
+        Ok(SharedWorkoutDetails {
+            token: shared.id,
+            name: shared.name,
+            shared_by,
+            created_on: shared.created_on,
+            information: shared.information,
+        })
+    }
+
+    async fn shared_workout_by_token(
+        &self,
+        user_id: Option<i32>,
+        token: String,
+    ) -> Result<shared_workout::Model> {
+        let shared = SharedWorkout::find_by_id(token).one(&self.db).await?;
+        match shared {
+            Some(s)
+                if s.shared_with_user_id.is_none()
+                    || s.shared_with_user_id == user_id
+                    || Some(s.user_id) == user_id =>
+            {
+                Ok(s)
+            }
+            _ => Err(Error::new(
+                "Shared workout with the given token could not be found.",
+            )),
+        }
+    }
+
+    async fn shared_workout(
+        &self,
+        user_id: Option<i32>,
+        token: String,
+    ) -> Result<SharedWorkoutDetails> {
+        let shared = self.shared_workout_by_token(user_id, token).await?;
+        self.shared_workout_details(shared).await
+    }
+
+    async fn shared_workouts(&self, user_id: i32) -> Result<Vec<SharedWorkoutDetails>> {
+        let shared = SharedWorkout::find()
+            .filter(shared_workout::Column::SharedWithUserId.eq(user_id))
+            .order_by_desc(shared_workout::Column::CreatedOn)
+            .all(&self.db)
+            .await?;
+        let mut data = vec![];
+        for s in shared {
+            data.push(self.shared_workout_details(s).await?);
+        }
+        Ok(data)
+    }
+
+    async fn delete_shared_workout(&self, user_id: i32, token: String) -> Result<bool> {
+        let deleted = SharedWorkout::delete_many()
+            .filter(shared_workout::Column::Id.eq(token))
+            .filter(shared_workout::Column::UserId.eq(user_id))
+            .exec(&self.db)
+            .await?;
+        Ok(deleted.rows_affected > 0)
+    }
+
+    async fn copy_shared_workout(&self, user_id: i32, token: String) -> Result<String> {
+        let shared = self.shared_workout_by_token(Some(user_id), token).await?;
+        self.create_workout_template(
+            user_id,
+            WorkoutTemplateInput {
+                name: shared.name,
+                information: shared.information,
+            },
+        )
+        .await
+    }
+
+    async fn training_programs(&self, user_id: i32) -> Result<Vec<training_program::Model>> {
+        let programs = TrainingProgram::find()
+            .filter(training_program::Column::UserId.eq(user_id))
+            .order_by_desc(training_program::Column::StartDate)
+            .all(&self.db)
+            .await?;
+        Ok(programs)
+    }
+
+    async fn training_program_schedule(
+        &self,
+        user_id: i32,
+        input: TrainingProgramScheduleInput,
+    ) -> Result<Vec<ScheduledWorkout>> {
+        let templates = self.workout_templates(user_id).await?;
+        let mut scheduled = vec![];
+        for program in self.training_programs(user_id).await? {
+            for (date, template_id) in program.scheduled_between(input.start_date, input.end_date) {
+                let completed_workout_id = program
+                    .completions
+                    .0
+                    .iter()
+                    .find(|c| c.date == date && c.template_id == template_id)
+                    .map(|c| c.workout_id.clone());
+                scheduled.push(ScheduledWorkout {
+                    date,
+                    program_id: program.id.clone(),
+                    program_name: program.name.clone(),
+                    template_name: templates
+                        .iter()
+                        .find(|t| t.id == template_id)
+                        .map(|t| t.name.clone()),
+                    template_id,
+                    completed_workout_id,
+                });
+            }
+        }
+        scheduled.sort_by_key(|s| s.date);
+        Ok(scheduled)
+    }
+
+    async fn create_training_program(
+        &self,
+        user_id: i32,
+        input: TrainingProgramInput,
+    ) -> Result<String> {
+        let information = input.information;
+        if information.cycle_length == 0 || information.num_cycles == 0 {
+            return Err(Error::new(
+                "A training program needs at least one cycle of one day.",
+            ));
+        }
+        if information
+            .schedule
+            .iter()
+            .any(|s| s.day >= information.cycle_length)
+        {
+            return Err(Error::new(
+                "Scheduled days must be within the length of a cycle.",
+            ));
+        }
+        let templates = self.workout_templates(user_id).await?;
+        if !information
+            .schedule
+            .iter()
+            .all(|s| templates.iter().any(|t| t.id == s.template_id))
+        {
+            return Err(Error::new(
+                "Some of the workout templates could not be found.",
+            ));
+        }
+        let sf = Sonyflake::new().unwrap();
+        let id = sf.next_id().unwrap().to_string();
+        let program = training_program::ActiveModel {
+            id: ActiveValue::Set(id),
+            user_id: ActiveValue::Set(user_id),
+            name: ActiveValue::Set(input.name),
+            start_date: ActiveValue::Set(input.start_date),
+            information: ActiveValue::Set(information),
+            completions: ActiveValue::Set(TrainingProgramCompletions::default()),
+            ..Default::default()
+        };
+        let program = program.insert(&self.db).await?;
+        Ok(program.id)
+    }
+
+    async fn delete_training_program(&self, user_id: i32, program_id: String) -> Result<bool> {
+        let program = TrainingProgram::find_by_id(program_id)
+            .filter(training_program::Column::UserId.eq(user_id))
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| Error::new("Training program with the given ID could not be found."))?;
+        program.delete(&self.db).await?;
+        Ok(true)
+    }
+
+    /// Mark the workout scheduled for the template as completed in the active
+    /// program of the user and progress the targets of the template. When more
+    /// than one active program has the template, the one that was started last
+    /// is advanced. A workout is only credited once.
+    async fn workout_template_completed(
+        &self,
+        user_id: i32,
+        template_id: String,
+        workout_id: String,
+        unit_system: UserUnitSystem,
+    ) -> Result<()> {
+        let today = Utc::now().date_naive();
+        // DEV: The programs are sorted by their start date, latest first
+        let program = self
+            .training_programs(user_id)
+            .await?
+            .into_iter()
+            .find(|program| {
+                today >= program.start_date
+                    && today < program.end_date()
+                    && program
+                        .information
+                        .schedule
+                        .iter()
+                        .any(|s| s.template_id == template_id)
+            });
+        let program = match program {
+            Some(p) => p,
+            None => return Ok(()),
+        };
+        if program
+            .completions
+            .0
+            .iter()
+            .any(|c| c.workout_id == workout_id)
+        {
+            return Ok(());
+        }
+        // DEV: Credit the most recent scheduled workout that is not completed
+        // yet, so that a session done a day late still counts.
+        let date = program
+            .scheduled_between(program.start_date, today)
+            .into_iter()
+            .rev()
+            .find(|(d, t)| {
+                t == &template_id
+                    && !program
+                        .completions
+                        .0
+                        .iter()
+                        .any(|c| c.date == *d && &c.template_id == t)
+            })
+            .map(|(d, _)| d)
+            .unwrap_or(today);
+        let mut completions = program.completions.clone();
+        completions.0.push(TrainingProgramCompletion {
+            date,
+            template_id: template_id.clone(),
+            workout_id: workout_id.clone(),
+        });
+        let progressions = program.information.progressions.clone();
+        let mut program: training_program::ActiveModel = program.into();
+        program.completions = ActiveValue::Set(completions);
+        program.update(&self.db).await?;
+        self.progress_workout_template(
+            user_id,
+            template_id,
+            &workout_id,
+            &progressions,
+            unit_system,
+        )
+        .await
+    }
+
+    /// Increase the target weights of the exercises in the template whose target
+    /// sets were all achieved in the workout.
+    async fn progress_workout_template(
+        &self,
+        user_id: i32,
+        template_id: String,
+        workout_id: &str,
+        progressions: &[TrainingProgramProgression],
+        unit_system: UserUnitSystem,
+    ) -> Result<()> {
+        let template = self.workout_template_by_id(user_id, template_id).await?;
+        let workout = match Workout::find_by_id(workout_id.to_owned())
+            .one(&self.db)
+            .await?
+        {
+            Some(w) => w,
+            None => return Ok(()),
+        };
+        let mut information = template.information.clone();
+        let mut progressed = false;
+        for ex in information.exercises.iter_mut() {
+            let increment = match progressions
+                .iter()
+                .find(|p| p.exercise_id == ex.exercise_id)
+            {
+                Some(p) => p.weight_increment,
+                None => continue,
+            };
+            let performed = match workout
+                .information
+                .exercises
+                .iter()
+                .find(|e| e.exercise_id == ex.exercise_id)
+            {
+                Some(p) => p,
+                None => continue,
+            };
+            let all_achieved = ex.sets.len() <= performed.sets.len()
+                && ex
+                    .sets
+                    .iter()
+                    .zip(performed.sets.iter())
+                    .all(|(set, done)| {
+                        // DEV: Targets are in the unit system of the user but workouts are
+                        // stored in metric.
+                        let target = UserWorkoutSetRecord {
+                            statistic: set.target.clone(),
+                            lot: set.lot,
+                        }
+                        .translate_units(unit_system)
+                        .statistic;
+                        target
+                            .reps
+                            .map_or(true, |r| done.statistic.reps.unwrap_or_default() >= r)
+                            && target
+                                .weight
+                                .map_or(true, |w| done.statistic.weight.unwrap_or_default() >= w)
+                    });
+            if all_achieved {
+                for set in ex.sets.iter_mut() {
+                    if let Some(w) = set.target.weight.as_mut() {
+                        *w += increment;
+                    }
+                }
+                progressed = true;
+            }
+        }
+        if progressed {
+            tracing::trace!("Progressing targets of workout template {}", template.id);
+            let mut template: workout_template::ActiveModel = template.into();
+            template.information = ActiveValue::Set(information);
+            template.update(&self.db).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn send_due_workout_reminders(&self) -> Result<()> {
+        let today = Utc::now().date_naive();
+        for program in TrainingProgram::find().all(&self.db).await? {
+            for (date, template_id) in program.scheduled_between(today, today) {
+                if program
+                    .completions
+                    .0
+                    .iter()
+                    .any(|c| c.date == date && c.template_id == template_id)
+                {
+                    continue;
+                }
+                let template = match WorkoutTemplate::find_by_id(template_id)
+                    .one(&self.db)
+                    .await?
+                {
+                    Some(t) => t,
+                    None => continue,
+                };
+                let msg = format!(
+                    "Your {:?} workout from the {:?} program is due today.",
+                    template.name, program.name
+                );
+                send_notifications_to_user_platforms(&self.db, program.user_id, &msg)
+                    .await
+                    .ok();
+            }
+        }
+        Ok(())
+    }
+
+    async fn strava_authorization_url(&self, redirect_uri: String) -> Result<String> {
+        if !self.config.exercise.strava.is_enabled() {
+            return Err(Error::new("Strava integration is not enabled."));
+        }
+        Ok(strava::authorization_url(
+            &self.config.exercise.strava,
+            &redirect_uri,
+        ))
+    }
+
+    async fn connect_strava_integration(&self, user_id: i32, code: String) -> Result<usize> {
+        if !self.config.exercise.strava.is_enabled() {
+            return Err(Error::new("Strava integration is not enabled."));
+        }
+        let token = strava::exchange_code(&self.config.exercise.strava, &code)
+            .await
+            .map_err(|e| Error::new(e.to_string()))?;
+        let athlete = token
+            .athlete
+            .map(|a| {
+                [a.firstname, a.lastname]
+                    .into_iter()
+                    .flatten()
+                    .collect_vec()
+                    .join(" ")
+            })
+            .unwrap_or_default();
+        let user = user_by_id(&self.db, user_id).await?;
+        let mut integrations = user
+            .yank_integrations
+            .clone()
+            .map(|i| i.0)
+            .unwrap_or_default();
+        let new_integration_id = integrations.len() + 1;
+        integrations.insert(
+            0,
+            UserYankIntegration {
+                id: new_integration_id,
+                timestamp: Utc::now(),
+                settings: UserYankIntegrationSetting::Strava {
+                    athlete,
+                    access_token: token.access_token,
+                    refresh_token: token.refresh_token,
+                    expires_at: token.expires_at,
+                    last_synced_on: None,
+                },
+            },
+        );
+        let mut user: user::ActiveModel = user.into();
+        user.yank_integrations = ActiveValue::Set(Some(UserYankIntegrations(integrations)));
+        user.update(&self.db).await?;
+        Ok(new_integration_id)
+    }
+
+    pub async fn sync_strava_activities(&self) -> Result<()> {
+        if !self.config.exercise.strava.is_enabled() {
+            return Ok(());
+        }
+        let users_with_integrations = User::find()
+            .filter(user::Column::YankIntegrations.is_not_null())
+            .select_only()
+            .column(user::Column::Id)
+            .into_tuple::<i32>()
+            .all(&self.db)
+            .await?;
+        for user_id in users_with_integrations {
+            if let Err(err) = self.sync_strava_activities_for_user(user_id).await {
+                tracing::error!("Could not sync Strava activities: {:?}", err);
+            }
+        }
+        Ok(())
+    }
+
+    async fn sync_strava_activities_for_user(&self, user_id: i32) -> Result<usize> {
+        let user = user_by_id(&self.db, user_id).await?;
+        let mut integrations = match user.yank_integrations.clone() {
+            Some(i) => i.0,
+            None => return Ok(0),
+        };
+        let mut synced = 0;
+        for idx in 0..integrations.len() {
+            let token = match &integrations[idx].settings {
+                UserYankIntegrationSetting::Strava {
+                    refresh_token,
+                    expires_at,
+                    ..
+                } if *expires_at <= Utc::now().timestamp() + 60 => Some(
+                    strava::refresh_token(&self.config.exercise.strava, refresh_token.as_str())
+                        .await
+                        .map_err(|e| Error::new(e.to_string()))?,
+                ),
+                UserYankIntegrationSetting::Strava { .. } => None,
+                _ => continue,
+            };
+            if let Some(token) = token {
+                if let UserYankIntegrationSetting::Strava {
+                    access_token,
+                    refresh_token,
+                    expires_at,
+                    ..
+                } = &mut integrations[idx].settings
+                {
+                    *access_token = token.access_token;
+                    *refresh_token = token.refresh_token;
+                    *expires_at = token.expires_at;
+                }
+                // DEV: Strava revokes the previous refresh token, so the new one has
+                // to be saved even if the sync fails later on
+                let user_model = user::ActiveModel {
+                    id: ActiveValue::Unchanged(user_id),
+                    yank_integrations: ActiveValue::Set(Some(UserYankIntegrations(
+                        integrations.clone(),
+                    ))),
+                    ..Default::default()
+                };
+                user_model.update(&self.db).await?;
+            }
+            let (access_token, last_synced_on) = match &mut integrations[idx].settings {
+                UserYankIntegrationSetting::Strava {
+                    access_token,
+                    last_synced_on,
+                    ..
+                } => (access_token, last_synced_on),
+                _ => continue,
+            };
+            let activities = strava::activities(access_token.as_str(), *last_synced_on)
+                .await
+                .map_err(|e| Error::new(e.to_string()))?;
+            for activity in activities {
+                let start_time = activity.start_date;
+                let workout = ExternalWorkout {
+                    source: "Strava",
+                    identifier: activity.id.to_string(),
+                    name: activity.name,
+                    sport_type: activity.sport_type,
+                    start_time,
+                    end_time: start_time + Duration::seconds(activity.elapsed_time),
+                    duration: Decimal::from(activity.moving_time) / Decimal::from(60),
+                    distance: Some(activity.distance / Decimal::from(1000)),
+                    elevation_gain: activity.total_elevation_gain,
+                    average_heart_rate: activity.average_heartrate.and_then(|h| h.round().to_u16()),
+                };
+                if !self.create_external_workout(&user, workout).await? {
+                    continue;
+                }
+                if last_synced_on.map_or(true, |l| l < start_time) {
+                    *last_synced_on = Some(start_time);
+                }
+                synced += 1;
+            }
+        }
+        let mut user: user::ActiveModel = user.into();
+        user.yank_integrations = ActiveValue::Set(Some(UserYankIntegrations(integrations)));
+        user.update(&self.db).await?;
+        Ok(synced)
+    }
+
+    /// Record an activity from an external service as a workout with a single set.
+    /// Returns `false` if the activity has already been recorded.
+    pub async fn create_external_workout(
+        &self,
+        user: &user::Model,
+        workout: ExternalWorkout,
+    ) -> Result<bool> {
+        let external_id = format!("{}:{}", workout.source_slug(), workout.identifier);
+        if Workout::find()
+            .filter(workout::Column::UserId.eq(user.id))
+            .filter(workout::Column::ExternalId.eq(&external_id))
+            .count(&self.db)
+            .await?
+            > 0
+        {
+            return Ok(false);
+        }
+        let distance = workout.distance.filter(|d| *d > Decimal::ZERO);
+        let lot = if distance.is_some() {
+            ExerciseLot::DistanceAndDuration
+        } else {
+            ExerciseLot::Duration
+        };
+        let exercise_id = self.external_exercise_id(user, &workout, lot).await?;
+        let statistic = SetStatistic {
+            duration: Some(workout.duration),
+            distance,
+            reps: None,
+            weight: None,
+            average_heart_rate: workout.average_heart_rate,
+            elevation_gain: workout.elevation_gain,
+        };
+        let input = UserWorkoutInput {
+            template_id: None,
+            external_id: Some(external_id),
+            name: Some(workout.name),
+            comment: None,
+            start_time: workout.start_time,
+            end_time: workout.end_time,
+            exercises: vec![UserExerciseInput {
+                exercise_id,
+                sets: vec![UserWorkoutSetRecord {
+                    statistic,
+                    lot: SetLot::Normal,
+                }],
+                notes: vec![],
+                rest_time: None,
+            }],
+            groups: vec![],
+        };
+        // DEV: External workouts are always in metric units
+        let preferences = UserExercisePreferences {
+            unit_system: UserUnitSystem::Metric,
+            ..user.preferences.fitness.exercises.clone()
+        };
+        let sf = Sonyflake::new().unwrap();
+        let id = sf.next_id().unwrap().to_string();
+        input
+            .calculate_and_commit(user.id, &self.db, id, preferences)
+            .await?;
+        Ok(true)
+    }
+
+    /// Activities of the same sport type from a service are all recorded against
+    /// one custom exercise of the user, which is created the first time it is needed.
+    async fn external_exercise_id(
+        &self,
+        user: &user::Model,
+        workout: &ExternalWorkout,
+        lot: ExerciseLot,
+    ) -> Result<i32> {
+        let identifier = format!(
+            "{}-{}-{}",
+            workout.source_slug(),
+            user.id,
+            workout.sport_type.to_lowercase()
+        );
+        if let Some(ex) = Exercise::find()
+            .filter(exercise::Column::Identifier.eq(&identifier))
+            .one(&self.db)
+            .await?
+        {
+            return Ok(ex.id);
+        }
+        let mut name = format!("{} ({})", workout.sport_type, workout.source);
+        if self
+            .ensure_exercise_name_available(&name, None)
+            .await
+            .is_err()
+        {
+            name = format!("{} ({}, {})", workout.sport_type, workout.source, user.name);
+        }
+        let db_exercise = exercise::ActiveModel {
+            name: ActiveValue::Set(name),
+            identifier: ActiveValue::Set(identifier),
+            muscles: ActiveValue::Set(ExerciseMuscles(vec![])),
+            attributes: ActiveValue::Set(ExerciseAttributes {
+                instructions: vec![],
+                internal_images: vec![],
+                images: vec![],
+                muscles: vec![],
+                localized_names: vec![],
+            }),
+            lot: ActiveValue::Set(lot),
+            level: ActiveValue::Set(ExerciseLevel::Beginner),
+            source: ActiveValue::Set(ExerciseSource::Custom),
+            created_by_user_id: ActiveValue::Set(Some(user.id)),
+            ..Default::default()
+        };
+        let created_exercise = db_exercise.insert(&self.db).await?;
+        Ok(created_exercise.id)
+    }
+
     #[instrument(skip(self))]
     async fn create_user_workout(&self, user_id: i32, input: UserWorkoutInput) -> Result<String> {
         let user = user_by_id(&self.db, user_id).await?;
         let sf = Sonyflake::new().unwrap();
         let id = sf.next_id().unwrap().to_string();
         tracing::trace!("Creating new workout with id: {}", id);
-        let identifier = input
+        let template_id = input.template_id.clone();
+        let unit_system = user.preferences.fitness.exercises.unit_system;
+        let workout = input
             .calculate_and_commit(user_id, &self.db, id, user.preferences.fitness.exercises)
             .await?;
-        Ok(identifier)
+        if user.in_progress_workout.is_some() {
+            self.set_in_progress_workout(user_id, None).await?;
+        }
+        if user.preferences.notifications.personal_best_achieved {
+            self.notify_personal_bests(user_id, &workout).await.ok();
+        }
+        if let Some(template_id) = template_id {
+            self.workout_template_completed(user_id, template_id, workout.id.clone(), unit_system)
+                .await?;
+        }
+        Ok(workout.id)
+    }
+
+    async fn notify_personal_bests(&self, user_id: i32, workout: &workout::Model) -> Result<()> {
+        let achieved = workout
+            .information
+            .exercises
+            .iter()
+            .filter_map(|e| {
+                let bests = e
+                    .sets
+                    .iter()
+                    .flat_map(|s| s.personal_bests.iter())
+                    .unique()
+                    .map(|pb| pb.to_string())
+                    .collect_vec();
+                match bests.is_empty() {
+                    true => None,
+                    false => Some(format!("{} ({})", e.exercise_name, bests.join(", "))),
+                }
+            })
+            .collect_vec();
+        if achieved.is_empty() {
+            return Ok(());
+        }
+        let msg = format!("New personal bests achieved: {}.", achieved.join(", "));
+        send_notifications_to_user_platforms(&self.db, user_id, &msg).await?;
+        Ok(())
     }
 }
+
+/// Library exercises are visible to everyone, custom ones only to their creator.
+fn exercise_visible_to_user(user_id: i32) -> Condition {
+    Condition::any()
+        .add(exercise::Column::Source.ne(ExerciseSource::Custom))
+        .add(exercise::Column::CreatedByUserId.eq(user_id))
+}
@@ -0,0 +1,139 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use http_types::mime;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use surf::{
+    http::headers::{ACCEPT, AUTHORIZATION},
+    Url,
+};
+
+use crate::{config::StravaConfig, utils::get_base_http_client};
+
+static AUTHORIZE_URL: &str = "https://www.strava.com/oauth/authorize";
+static URL: &str = "https://www.strava.com/";
+/// The number of activities requested at a time.
+static PAGE_SIZE: usize = 100;
+/// The most pages of activities that are requested in a single sync, the rest
+/// are synced the next time.
+static MAX_PAGES: usize = 20;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StravaAthlete {
+    pub firstname: Option<String>,
+    pub lastname: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StravaToken {
+    pub access_token: String,
+    pub refresh_token: String,
+    /// The unix timestamp after which the access token needs to be refreshed.
+    pub expires_at: i64,
+    pub athlete: Option<StravaAthlete>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StravaActivity {
+    pub id: i64,
+    pub name: String,
+    pub sport_type: String,
+    pub start_date: DateTime<Utc>,
+    /// In seconds.
+    pub elapsed_time: i64,
+    /// In seconds.
+    pub moving_time: i64,
+    /// In meters.
+    pub distance: Decimal,
+    /// In meters.
+    pub total_elevation_gain: Option<Decimal>,
+    pub average_heartrate: Option<Decimal>,
+}
+
+/// The URL the user needs to visit to allow Ryot to read their activities.
+pub fn authorization_url(config: &StravaConfig, redirect_uri: &str) -> String {
+    Url::parse_with_params(
+        AUTHORIZE_URL,
+        &[
+            ("client_id", config.client_id.as_str()),
+            ("response_type", "code"),
+            ("approval_prompt", "auto"),
+            ("scope", "activity:read_all"),
+            ("redirect_uri", redirect_uri),
+        ],
+    )
+    .unwrap()
+    .to_string()
+}
+
+async fn token(config: &StravaConfig, mut body: serde_json::Value) -> Result<StravaToken> {
+    let client = get_base_http_client(URL, vec![(ACCEPT, mime::JSON.to_string())], None);
+    body["client_id"] = json!(config.client_id);
+    body["client_secret"] = json!(config.client_secret);
+    let mut rsp = client
+        .post("oauth/token")
+        .body_json(&body)
+        .unwrap()
+        .await
+        .map_err(|e| anyhow!(e))?;
+    if !rsp.status().is_success() {
+        return Err(anyhow!("Strava rejected the token request"));
+    }
+    rsp.body_json().await.map_err(|e| anyhow!(e))
+}
+
+/// Exchange the code received after the user authorized the application.
+pub async fn exchange_code(config: &StravaConfig, code: &str) -> Result<StravaToken> {
+    token(
+        config,
+        json!({ "code": code, "grant_type": "authorization_code" }),
+    )
+    .await
+}
+
+pub async fn refresh_token(config: &StravaConfig, refresh_token: &str) -> Result<StravaToken> {
+    token(
+        config,
+        json!({ "refresh_token": refresh_token, "grant_type": "refresh_token" }),
+    )
+    .await
+}
+
+/// Get all the activities of the athlete that started after the given time.
+pub async fn activities(
+    access_token: &str,
+    after: Option<DateTime<Utc>>,
+) -> Result<Vec<StravaActivity>> {
+    let client = get_base_http_client(
+        &format!("{}api/v3/", URL),
+        vec![(AUTHORIZATION, format!("Bearer {}", access_token))],
+        None,
+    );
+    let mut all = vec![];
+    for page in 1..=MAX_PAGES {
+        let mut rsp = client
+            .get("athlete/activities")
+            .query(&json!({
+                "after": after.map(|a| a.timestamp()).unwrap_or(0),
+                "page": page,
+                "per_page": PAGE_SIZE,
+            }))
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        if !rsp.status().is_success() {
+            return Err(anyhow!(
+                "Strava rejected the activities request with status {}",
+                rsp.status()
+            ));
+        }
+        let activities: Vec<StravaActivity> = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+        let last_page = activities.len() < PAGE_SIZE;
+        all.extend(activities);
+        if last_page {
+            break;
+        }
+    }
+    Ok(all)
+}
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use http_types::mime;
+use scraper::Html;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::json;
+use surf::{http::headers::ACCEPT, Client};
+
+use crate::{
+    config::WgerConfig,
+    migrator::{ExerciseEquipment, ExerciseMuscle},
+    utils::get_base_http_client,
+};
+
+static PAGE_SIZE: usize = 100;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct WgerPage<T> {
+    next: Option<String>,
+    results: Vec<T>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct WgerLanguage {
+    id: i32,
+    short_name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WgerNamed {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WgerMuscle {
+    pub name: String,
+    #[serde(default)]
+    pub name_en: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WgerImage {
+    pub image: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WgerTranslation {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// The id of the language, see `languages`.
+    pub language: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WgerExercise {
+    pub uuid: String,
+    pub category: WgerNamed,
+    pub muscles: Vec<WgerMuscle>,
+    pub muscles_secondary: Vec<WgerMuscle>,
+    pub equipment: Vec<WgerNamed>,
+    pub images: Vec<WgerImage>,
+    // DEV: Older versions of wger call these `exercises`
+    #[serde(alias = "exercises")]
+    pub translations: Vec<WgerTranslation>,
+}
+
+impl WgerNamed {
+    pub fn equipment(&self) -> Option<ExerciseEquipment> {
+        let equipment = match self.name.to_lowercase().as_str() {
+            "barbell" => ExerciseEquipment::Barbell,
+            "sz-bar" => ExerciseEquipment::EZCurlBar,
+            "dumbbell" => ExerciseEquipment::Dumbbell,
+            "kettlebell" => ExerciseEquipment::Kettlebells,
+            "swiss ball" => ExerciseEquipment::ExerciseBall,
+            "resistance band" => ExerciseEquipment::Bands,
+            "none (bodyweight exercise)" | "pull-up bar" | "gym mat" => ExerciseEquipment::BodyOnly,
+            _ => return None,
+        };
+        Some(equipment)
+    }
+}
+
+impl WgerMuscle {
+    pub fn muscle(&self) -> Option<ExerciseMuscle> {
+        let name = format!("{} {}", self.name_en, self.name).to_lowercase();
+        // DEV: Ordered so that the more specific names are matched first
+        let muscle = if name.contains("biceps femoris") || name.contains("hamstring") {
+            ExerciseMuscle::Hamstrings
+        } else if name.contains("biceps") || name.contains("brachialis") {
+            ExerciseMuscle::Biceps
+        } else if name.contains("triceps") {
+            ExerciseMuscle::Triceps
+        } else if name.contains("abs") || name.contains("obliquus") {
+            ExerciseMuscle::Abdominals
+        } else if name.contains("calves") || name.contains("soleus") {
+            ExerciseMuscle::Calves
+        } else if name.contains("chest") || name.contains("pectoralis") {
+            ExerciseMuscle::Chest
+        } else if name.contains("glute") {
+            ExerciseMuscle::Glutes
+        } else if name.contains("lats") || name.contains("latissimus") {
+            ExerciseMuscle::Lats
+        } else if name.contains("quad") {
+            ExerciseMuscle::Quadriceps
+        } else if name.contains("shoulder") || name.contains("deltoid") {
+            ExerciseMuscle::Shoulders
+        } else if name.contains("trapezius") {
+            ExerciseMuscle::Traps
+        } else if name.contains("erector spinae") {
+            ExerciseMuscle::LowerBack
+        } else {
+            return None;
+        };
+        Some(muscle)
+    }
+}
+
+impl WgerTranslation {
+    /// The description is HTML, so only the text of every paragraph is kept.
+    pub fn instructions(&self) -> Vec<String> {
+        Html::parse_fragment(&self.description)
+            .root_element()
+            .text()
+            .map(|t| t.trim().to_owned())
+            .filter(|t| !t.is_empty())
+            .collect()
+    }
+}
+
+fn client(config: &WgerConfig) -> Client {
+    get_base_http_client(
+        &format!("{}/api/v2/", config.url.trim_end_matches('/')),
+        vec![(ACCEPT, mime::JSON.to_string())],
+        None,
+    )
+}
+
+async fn all_pages<T: DeserializeOwned>(client: &Client, path: &str) -> Result<Vec<T>> {
+    let mut all = vec![];
+    let mut offset = 0;
+    loop {
+        let mut rsp = client
+            .get(path)
+            .query(&json!({ "limit": PAGE_SIZE, "offset": offset }))
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let page: WgerPage<T> = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+        all.extend(page.results);
+        if page.next.is_none() {
+            break;
+        }
+        offset += PAGE_SIZE;
+    }
+    Ok(all)
+}
+
+/// The language codes (eg: `en`, `de`) keyed by their id.
+pub async fn languages(config: &WgerConfig) -> Result<HashMap<i32, String>> {
+    let languages: Vec<WgerLanguage> = all_pages(&client(config), "language/").await?;
+    Ok(languages
+        .into_iter()
+        .map(|l| (l.id, l.short_name))
+        .collect())
+}
+
+/// All the exercises of the instance along with their translations.
+pub async fn exercises(config: &WgerConfig) -> Result<Vec<WgerExercise>> {
+    all_pages(&client(config), "exerciseinfo/").await
+}
@@ -1,10 +1,17 @@
-use async_graphql::{EmptySubscription, MergedObject, Schema};
+use std::{
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use async_graphql::{MergedObject, MergedSubscription, Schema};
+use chrono::Duration as ChronoDuration;
+use retainer::Cache;
 
 use crate::{
-    fitness::resolver::{ExerciseMutation, ExerciseQuery},
-    importer::{ImporterMutation, ImporterQuery},
+    fitness::resolver::{ExerciseMutation, ExerciseQuery, ExerciseSubscription},
+    importer::{ImporterMutation, ImporterQuery, ImporterSubscription},
     miscellaneous::resolver::{MiscellaneousMutation, MiscellaneousQuery},
-    utils::AppServices,
+    utils::{AppServices, AuthContext},
 };
 
 #[derive(MergedObject, Default)]
@@ -13,16 +20,95 @@ pub struct QueryRoot(MiscellaneousQuery, ImporterQuery, ExerciseQuery);
 #[derive(MergedObject, Default)]
 pub struct MutationRoot(MiscellaneousMutation, ImporterMutation, ExerciseMutation);
 
-pub type GraphqlSchema = Schema<QueryRoot, MutationRoot, EmptySubscription>;
+#[derive(MergedSubscription, Default)]
+pub struct SubscriptionRoot(ExerciseSubscription, ImporterSubscription);
+
+pub type GraphqlSchema = Schema<QueryRoot, MutationRoot, SubscriptionRoot>;
 
 pub async fn get_schema(app_services: &AppServices) -> GraphqlSchema {
-    Schema::build(
+    let limits = &app_services.config.server.graphql;
+    let mut schema = Schema::build(
         QueryRoot::default(),
         MutationRoot::default(),
-        EmptySubscription,
+        SubscriptionRoot::default(),
     )
     .data(app_services.media_service.clone())
     .data(app_services.importer_service.clone())
-    .data(app_services.exercise_service.clone())
-    .finish()
+    .data(app_services.exercise_service.clone());
+    if limits.max_depth > 0 {
+        schema = schema.limit_depth(limits.max_depth);
+    }
+    if limits.max_complexity > 0 {
+        schema = schema.limit_complexity(limits.max_complexity);
+    }
+    schema.finish()
+}
+
+/// Counts the requests made in the current minute for each user, or IP address if
+/// the request is not authenticated. Messages sent over the websocket of the
+/// subscriptions are counted as requests too.
+pub struct GraphqlRateLimiter {
+    cache: Arc<Cache<String, u32>>,
+    max_requests_per_minute: u32,
+}
+
+impl GraphqlRateLimiter {
+    pub fn new(max_requests_per_minute: u32) -> Self {
+        let cache = Arc::new(Cache::new());
+        let cache_clone = cache.clone();
+
+        tokio::spawn(async move {
+            cache_clone
+                .monitor(4, 0.25, ChronoDuration::minutes(3).to_std().unwrap())
+                .await
+        });
+
+        Self {
+            cache,
+            max_requests_per_minute,
+        }
+    }
+
+    /// The client that the requests of a context are counted for. Only a token
+    /// that was verified identifies a user, since a client could otherwise get a
+    /// new limit by sending a different token with every request.
+    pub fn client(ctx: &AuthContext) -> Option<String> {
+        match (ctx.user_id, ctx.ip) {
+            (Some(user_id), _) => Some(format!("user:{}", user_id)),
+            (None, Some(ip)) => Some(format!("ip:{}", ip)),
+            (None, None) => None,
+        }
+    }
+
+    /// Record a request and return whether it is within the limit.
+    pub async fn check(&self, ctx: &AuthContext) -> bool {
+        self.check_client(Self::client(ctx).as_deref()).await
+    }
+
+    /// Record a request of a client and return whether it is within the limit.
+    pub async fn check_client(&self, client: Option<&str>) -> bool {
+        if self.max_requests_per_minute == 0 {
+            return true;
+        }
+        let client = match client {
+            Some(c) => c,
+            None => return true,
+        };
+        let minute = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            / 60;
+        // DEV: The minute is part of the key, so the counts of the previous
+        // minutes are never read again and just expire
+        let key = format!("{}:{}", minute, client);
+        let requests = self.cache.get(&key).await.map_or(0, |r| *r) + 1;
+        self.cache
+            .insert(key, requests, ChronoDuration::minutes(1).to_std().unwrap())
+            .await;
+        if requests == self.max_requests_per_minute + 1 {
+            tracing::warn!("Rate limiting the GraphQL requests of {}", client);
+        }
+        requests <= self.max_requests_per_minute
+    }
 }
@@ -0,0 +1,186 @@
+// An in-memory cache of the responses of the providers, shared by all the clients
+// of the providers created using `utils::get_base_http_client`. Clients that are
+// not created for a provider, eg: the ones of the integrations and importers, do
+// not use it. It also records the responses of the providers when they are being
+// tested.
+
+use std::{
+    cell::RefCell,
+    collections::hash_map::DefaultHasher,
+    future::Future,
+    hash::{Hash, Hasher},
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
+
+use chrono::Duration as ChronoDuration;
+use http_types::{
+    headers::{HeaderName, HeaderValues},
+    Method, StatusCode,
+};
+use retainer::Cache;
+use surf::{
+    middleware::{Middleware, Next},
+    Client, Request, Response,
+};
+
+static CACHE: OnceLock<HttpCache> = OnceLock::new();
+
+/// The headers that a provider can identify the caller of a request with.
+static AUTH_HEADERS: [&str; 6] = [
+    "authorization",
+    "client-id",
+    "cookie",
+    "x-api-key",
+    "x-auth-key",
+    "x-mal-client-id",
+];
+
+tokio::task_local! {
+    static REFRESHING: bool;
+    static PROBE: RefCell<Option<ProbedResponse>>;
+}
+
+/// The response that a provider sent last while it was being probed.
+#[derive(Debug, Clone)]
+pub struct ProbedResponse {
+    pub status: u16,
+    /// The headers that describe the rate limits of the provider.
+    pub rate_limit_headers: Vec<(String, String)>,
+}
+
+impl ProbedResponse {
+    fn from_response(response: &Response) -> Self {
+        let rate_limit_headers = response
+            .iter()
+            .filter(|(name, _)| {
+                let name = name.as_str().to_lowercase();
+                name.contains("ratelimit") || name.contains("rate-limit") || name == "retry-after"
+            })
+            .map(|(name, values)| (name.to_string(), values.to_string()))
+            .collect();
+        Self {
+            status: response.status().into(),
+            rate_limit_headers,
+        }
+    }
+}
+
+struct HttpCache {
+    responses: Arc<Cache<String, CachedResponse>>,
+    ttl: Duration,
+}
+
+#[derive(Debug, Clone)]
+struct CachedResponse {
+    status: StatusCode,
+    headers: Vec<(HeaderName, HeaderValues)>,
+    body: Vec<u8>,
+}
+
+impl CachedResponse {
+    fn to_response(&self) -> Response {
+        let mut response = http_types::Response::new(self.status);
+        response.set_body(self.body.clone());
+        for (name, values) in self.headers.iter() {
+            response.remove_header(name);
+            for value in values.iter() {
+                response.append_header(name, value.clone());
+            }
+        }
+        Response::from(response)
+    }
+}
+
+/// Enable the cache, keeping the responses for the given number of minutes. The
+/// cache is disabled if this is never called or the duration is `0`.
+pub fn init(ttl_minutes: i64) {
+    if ttl_minutes <= 0 {
+        return;
+    }
+    let responses = Arc::new(Cache::new());
+    let cache_clone = responses.clone();
+
+    tokio::spawn(async move {
+        cache_clone
+            .monitor(4, 0.25, ChronoDuration::minutes(3).to_std().unwrap())
+            .await
+    });
+
+    CACHE
+        .set(HttpCache {
+            responses,
+            ttl: ChronoDuration::minutes(ttl_minutes).to_std().unwrap(),
+        })
+        .ok();
+}
+
+/// Run the future without reading from the cache, so that the providers return
+/// their latest details. The responses are still cached for the next requests.
+pub async fn refreshing<F: Future>(future: F) -> F::Output {
+    REFRESHING.scope(true, future).await
+}
+
+/// Run the future without reading from the cache and return the last response
+/// that a provider sent while it was running, if any.
+pub async fn probe<F: Future>(future: F) -> (F::Output, Option<ProbedResponse>) {
+    PROBE
+        .scope(RefCell::new(None), async {
+            let output = refreshing(future).await;
+            (output, PROBE.with(|p| p.take()))
+        })
+        .await
+}
+
+/// The key of the response to a request: its URL and a hash of the headers that
+/// identify the caller, since the response can differ between them.
+fn cache_key(req: &Request) -> String {
+    let mut hasher = DefaultHasher::new();
+    for name in AUTH_HEADERS {
+        if let Some(values) = req.header(name) {
+            name.hash(&mut hasher);
+            for value in values.iter() {
+                value.as_str().hash(&mut hasher);
+            }
+        }
+    }
+    format!("{}#{:x}", req.url(), hasher.finish())
+}
+
+/// Only successful `GET` requests are cached, keyed by their URL and the headers
+/// that identify the caller.
+pub struct HttpCacheMiddleware;
+
+#[surf::utils::async_trait]
+impl Middleware for HttpCacheMiddleware {
+    async fn handle(&self, req: Request, client: Client, next: Next<'_>) -> surf::Result<Response> {
+        let cache = CACHE.get().filter(|_| req.method() == Method::Get);
+        let key = cache_key(&req);
+        if let Some(cache) = cache {
+            if !REFRESHING.try_with(|r| *r).unwrap_or(false) {
+                if let Some(cached) = cache.responses.get(&key).await {
+                    return Ok(cached.to_response());
+                }
+            }
+        }
+        let mut response = next.run(req, client).await?;
+        PROBE
+            .try_with(|p| p.replace(Some(ProbedResponse::from_response(&response))))
+            .ok();
+        let cache = match cache {
+            Some(cache) if response.status().is_success() => cache,
+            _ => return Ok(response),
+        };
+        let cached = CachedResponse {
+            status: response.status(),
+            headers: response
+                .iter()
+                .map(|(name, values)| (name.clone(), values.clone()))
+                .collect(),
+            body: response.body_bytes().await?,
+        };
+        let response = cached.to_response();
+        cache.responses.insert(key, cached, cache.ttl).await;
+        Ok(response)
+    }
+}
@@ -0,0 +1,166 @@
+use std::fs;
+
+use async_graphql::Result;
+use chrono::{Duration, NaiveDateTime, TimeZone, Utc};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use rust_decimal_macros::dec;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    entities::user_measurement,
+    importer::{DeployGarminConnectImportInput, ExerciseImportResult},
+    models::fitness::{ExternalWorkout, UserMeasurementStats},
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Activity {
+    activity_id: i64,
+    name: Option<String>,
+    activity_type: Option<String>,
+    /// Unix timestamp in milliseconds.
+    start_time_gmt: f64,
+    /// In milliseconds.
+    duration: Option<f64>,
+    /// In milliseconds.
+    moving_duration: Option<f64>,
+    /// In centimeters.
+    distance: Option<f64>,
+    /// In centimeters.
+    elevation_gain: Option<f64>,
+    avg_hr: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ActivitiesExport {
+    summarized_activities_export: Vec<Activity>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BioMetricsWeight {
+    /// In grams.
+    weight: Option<f64>,
+    bmi: Option<f64>,
+    body_fat: Option<f64>,
+    body_water: Option<f64>,
+    /// In grams.
+    bone_mass: Option<f64>,
+    /// In grams.
+    muscle_mass: Option<f64>,
+    /// Unix timestamp in milliseconds.
+    #[serde(rename = "timestampGMT")]
+    timestamp_gmt: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BioMetricsMetaData {
+    calendar_date: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BioMetrics {
+    meta_data: Option<BioMetricsMetaData>,
+    weight: Option<BioMetricsWeight>,
+}
+
+fn from_millis(ms: f64) -> Option<DateTimeUtc> {
+    Utc.timestamp_millis_opt(ms.to_i64()?).single()
+}
+
+fn to_decimal(value: Option<f64>, divisor: Decimal) -> Option<Decimal> {
+    value
+        .and_then(Decimal::from_f64)
+        .map(|v| (v / divisor).round_dp(2))
+}
+
+/// Garmin uses snake case activity types, eg: `lap_swimming`.
+fn sport_type(activity_type: Option<String>) -> String {
+    let activity_type = activity_type.unwrap_or_else(|| "other".to_owned());
+    let mut chars = activity_type.replace('_', " ").chars().collect::<Vec<_>>();
+    if let Some(c) = chars.first_mut() {
+        *c = c.to_ascii_uppercase();
+    }
+    chars.into_iter().collect()
+}
+
+fn convert_activity(activity: Activity) -> Option<ExternalWorkout> {
+    let start_time = from_millis(activity.start_time_gmt)?;
+    let elapsed = activity.duration.unwrap_or_default();
+    let moving = activity.moving_duration.unwrap_or(elapsed);
+    let sport_type = sport_type(activity.activity_type);
+    Some(ExternalWorkout {
+        source: "Garmin Connect",
+        identifier: activity.activity_id.to_string(),
+        name: activity.name.unwrap_or_else(|| sport_type.clone()),
+        sport_type,
+        start_time,
+        end_time: start_time + Duration::milliseconds(elapsed.to_i64()?),
+        duration: to_decimal(Some(moving), dec!(60000))?,
+        distance: to_decimal(activity.distance, dec!(100000)),
+        elevation_gain: to_decimal(activity.elevation_gain, dec!(100)),
+        average_heart_rate: activity.avg_hr.and_then(|h| h.round().to_u16()),
+    })
+}
+
+fn convert_bio_metrics(bio_metrics: BioMetrics) -> Option<user_measurement::Model> {
+    let weight = bio_metrics.weight?;
+    // DEV: Entries without a weight only contain device information
+    weight.weight?;
+    let timestamp = match weight.timestamp_gmt.and_then(from_millis) {
+        Some(t) => t,
+        None => {
+            let date = bio_metrics.meta_data?.calendar_date?;
+            let date = NaiveDateTime::parse_from_str(&date, "%Y-%m-%dT%H:%M:%S%.f").ok()?;
+            Utc.from_utc_datetime(&date)
+        }
+    };
+    let stats = UserMeasurementStats {
+        weight: to_decimal(weight.weight, dec!(1000)),
+        body_mass_index: to_decimal(weight.bmi, Decimal::ONE),
+        body_fat: to_decimal(weight.body_fat, Decimal::ONE),
+        total_body_water: to_decimal(weight.body_water, Decimal::ONE),
+        bone_mass: to_decimal(weight.bone_mass, dec!(1000)),
+        muscle: to_decimal(weight.muscle_mass, dec!(1000)),
+        ..Default::default()
+    };
+    Some(user_measurement::Model {
+        timestamp,
+        user_id: 0,
+        name: Some("Garmin Connect".to_owned()),
+        comment: None,
+        stats,
+        photos: None,
+    })
+}
+
+pub async fn import(input: DeployGarminConnectImportInput) -> Result<ExerciseImportResult> {
+    let exports: Vec<ActivitiesExport> =
+        serde_json::from_str(&fs::read_to_string(&input.activities_path)?)?;
+    let workouts = exports
+        .into_iter()
+        .flat_map(|e| e.summarized_activities_export)
+        .filter_map(convert_activity)
+        .collect();
+    let measurements = match input.bio_metrics_path {
+        Some(path) => {
+            let bio_metrics: Vec<BioMetrics> = serde_json::from_str(&fs::read_to_string(path)?)?;
+            bio_metrics
+                .into_iter()
+                .filter_map(convert_bio_metrics)
+                .collect()
+        }
+        None => vec![],
+    };
+    Ok(ExerciseImportResult {
+        workouts,
+        measurements,
+    })
+}
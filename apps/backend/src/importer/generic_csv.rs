@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use async_graphql::Result;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use csv::Reader;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use sea_orm::prelude::DateTimeUtc;
+
+use crate::{
+    importer::{
+        DeployGenericCsvImportInput, GenericCsvIdentifierType, ImportFailStep, ImportFailedItem,
+        ImportOrExportItemIdentifier, ImportOrExportMediaItem, ImportResult,
+    },
+    migrator::MetadataSource,
+    models::media::{
+        ExternalIdentifierType, ImportOrExportItemRating, ImportOrExportMediaItemSeen,
+        MediaSearchFilters,
+    },
+    traits::MediaProvider,
+    utils::convert_naive_to_utc,
+};
+
+/// The formats that dates are tried in when the input does not specify one.
+static DATE_TIME_FORMATS: [&str; 2] = ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S"];
+static DATE_FORMATS: [&str; 2] = ["%Y-%m-%d", "%Y/%m/%d"];
+
+#[derive(Debug, Default)]
+struct Item {
+    seen_history: Vec<ImportOrExportMediaItemSeen>,
+    reviews: Vec<ImportOrExportItemRating>,
+}
+
+fn parse_date(value: &str, format: Option<&str>) -> Option<DateTimeUtc> {
+    if let Ok(d) = DateTime::parse_from_rfc3339(value) {
+        return Some(d.with_timezone(&Utc));
+    }
+    let (date_time_formats, date_formats) = match format {
+        Some(f) => (vec![f], vec![f]),
+        None => (DATE_TIME_FORMATS.to_vec(), DATE_FORMATS.to_vec()),
+    };
+    date_time_formats
+        .into_iter()
+        .find_map(|f| NaiveDateTime::parse_from_str(value, f).ok())
+        .map(|d| DateTime::from_naive_utc_and_offset(d, Utc))
+        .or_else(|| {
+            date_formats
+                .into_iter()
+                .find_map(|f| NaiveDate::parse_from_str(value, f).ok())
+                .map(convert_naive_to_utc)
+        })
+}
+
+/// Find the item using the identifier if there is one, falling back to a search
+/// for its title.
+async fn find_item(
+    title: &str,
+    identifier: Option<&str>,
+    identifier_type: GenericCsvIdentifierType,
+    provider: &(dyn MediaProvider + Send + Sync),
+) -> Option<String> {
+    if let Some(identifier) = identifier {
+        let external_type = match identifier_type {
+            GenericCsvIdentifierType::Provider => return Some(identifier.to_owned()),
+            GenericCsvIdentifierType::Isbn => ExternalIdentifierType::Isbn,
+            GenericCsvIdentifierType::Imdb => ExternalIdentifierType::Imdb,
+        };
+        if let Ok(Some(found)) = provider.id_from_external(external_type, identifier).await {
+            return Some(found);
+        }
+    }
+    // DEV: Picking the closest result would silently import the wrong media, so
+    // a title that does not match exactly is reported as failed instead
+    provider
+        .search(title, None, true, &MediaSearchFilters::default())
+        .await
+        .ok()?
+        .items
+        .into_iter()
+        .find(|i| i.title.eq_ignore_ascii_case(title))
+        .map(|i| i.identifier)
+}
+
+pub async fn import(
+    input: DeployGenericCsvImportInput,
+    source: MetadataSource,
+    provider: &(dyn MediaProvider + Send + Sync),
+) -> Result<ImportResult> {
+    let lot = input.lot;
+    let mut media = vec![];
+    let mut failed_items = vec![];
+    let mut reader = Reader::from_path(&input.csv_path)?;
+    let headers = reader.headers()?.clone();
+    let column = |name: &Option<String>| -> Result<Option<usize>> {
+        match name {
+            Some(n) => match headers.iter().position(|h| h.trim() == n.trim()) {
+                Some(idx) => Ok(Some(idx)),
+                None => Err(format!("The file does not have a column named {:?}", n).into()),
+            },
+            None => Ok(None),
+        }
+    };
+    let title_column = column(&Some(input.title_column.clone()))?.unwrap();
+    let identifier_column = column(&input.identifier_column)?;
+    let date_column = column(&input.date_column)?;
+    let rating_column = column(&input.rating_column)?;
+    let rating_scale = input.rating_scale.unwrap_or(dec!(100));
+    // DEV: Items can be in multiple rows of the file, eg: when they were
+    // consumed more than once
+    let mut keys = vec![];
+    let mut items: HashMap<(String, Option<String>), Item> = HashMap::new();
+    for (idx, result) in reader.records().enumerate() {
+        let record = match result {
+            Ok(r) => r,
+            Err(e) => {
+                failed_items.push(ImportFailedItem {
+                    lot,
+                    step: ImportFailStep::InputTransformation,
+                    index: None,
+                    identifier: idx.to_string(),
+                    error: Some(e.to_string()),
+                });
+                continue;
+            }
+        };
+        let value = |column: Option<usize>| {
+            column
+                .and_then(|c| record.get(c))
+                .map(|v| v.trim())
+                .filter(|v| !v.is_empty())
+        };
+        let title = match value(Some(title_column)) {
+            Some(t) => t.to_owned(),
+            None => {
+                failed_items.push(ImportFailedItem {
+                    lot,
+                    step: ImportFailStep::InputTransformation,
+                    index: None,
+                    identifier: idx.to_string(),
+                    error: Some("The row does not have a title".to_owned()),
+                });
+                continue;
+            }
+        };
+        let key = (title, value(identifier_column).map(String::from));
+        if !items.contains_key(&key) {
+            keys.push(key.clone());
+        }
+        let item = items.entry(key).or_default();
+        item.seen_history.push(ImportOrExportMediaItemSeen {
+            ended_on: value(date_column).and_then(|d| parse_date(d, input.date_format.as_deref())),
+            ..Default::default()
+        });
+        if let Some(rating) = value(rating_column).and_then(|r| r.parse::<Decimal>().ok()) {
+            if !rating.is_zero() && !rating_scale.is_zero() {
+                item.reviews.push(ImportOrExportItemRating {
+                    rating: Some(rating.saturating_mul(dec!(100)) / rating_scale),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+    let total = keys.len();
+    let identifier_type = input.identifier_type.unwrap_or_default();
+    for (idx, key) in keys.into_iter().enumerate() {
+        let item = items.remove(&key).unwrap();
+        let (title, identifier) = key;
+        tracing::debug!("Getting details for {title:?} ({idx}/{total})");
+        let found = find_item(&title, identifier.as_deref(), identifier_type, provider).await;
+        let found = match found {
+            Some(f) => f,
+            None => {
+                failed_items.push(ImportFailedItem {
+                    lot,
+                    step: ImportFailStep::InputTransformation,
+                    index: None,
+                    identifier: title,
+                    error: Some(format!("Could not find the item in {:?}", source)),
+                });
+                continue;
+            }
+        };
+        media.push(ImportOrExportMediaItem {
+            source_id: identifier.unwrap_or_else(|| title.clone()),
+            title: Some(title),
+            lot,
+            source,
+            identifier: ImportOrExportItemIdentifier::NeedsDetails(found),
+            seen_history: item.seen_history,
+            reviews: item.reviews,
+            collections: vec![],
+            highlights: vec![],
+        });
+    }
+    Ok(ImportResult {
+        collections: vec![],
+        media,
+        failed_items,
+    })
+}
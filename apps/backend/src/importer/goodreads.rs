@@ -106,12 +106,14 @@ pub async fn import(input: DeployGoodreadsImportInput) -> Result<ImportResult> {
 
                 ImportOrExportMediaItem {
                     source_id: d.book_id.to_string(),
+                    title: None,
                     source: MetadataSource::Custom,
                     lot: MetadataLot::Book,
                     identifier: ImportOrExportItemIdentifier::AlreadyFilled(Box::new(
                         MediaDetails {
                             identifier: d.book_id.to_string(),
                             title: d.title,
+                            alternative_titles: vec![],
                             description: Some(d.book_description),
                             production_status: "Released".to_owned(),
                             lot: MetadataLot::Book,
@@ -140,6 +142,7 @@ pub async fn import(input: DeployGoodreadsImportInput) -> Result<ImportResult> {
                     )),
                     seen_history,
                     collections: default_collections,
+                    highlights: vec![],
                     reviews,
                 }
             })
@@ -0,0 +1,210 @@
+use async_graphql::Result;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use convert_case::{Case, Casing};
+use csv::Reader;
+use itertools::Itertools;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    importer::{
+        DeployGoodreadsCsvImportInput, ImportFailStep, ImportFailedItem,
+        ImportOrExportItemIdentifier, ImportOrExportMediaItem, ImportResult,
+    },
+    migrator::{MetadataLot, MetadataSource},
+    miscellaneous::DefaultCollection,
+    models::media::{
+        ExternalIdentifierType, ImportOrExportItemRating, ImportOrExportItemReview,
+        ImportOrExportMediaItemSeen,
+    },
+    traits::MediaProvider,
+};
+
+/// The shelves that every book is on exactly one of.
+static EXCLUSIVE_SHELVES: [&str; 3] = ["read", "currently-reading", "to-read"];
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Book {
+    #[serde(rename = "Book Id")]
+    book_id: String,
+    #[serde(rename = "Title")]
+    title: String,
+    #[serde(rename = "ISBN")]
+    isbn: String,
+    #[serde(rename = "ISBN13")]
+    isbn13: String,
+    #[serde(rename = "My Rating")]
+    rating: Option<Decimal>,
+    #[serde(rename = "Date Read")]
+    date_read: String,
+    #[serde(rename = "Bookshelves")]
+    bookshelves: String,
+    #[serde(rename = "Exclusive Shelf")]
+    exclusive_shelf: String,
+    #[serde(rename = "My Review")]
+    review: String,
+    #[serde(rename = "Spoiler")]
+    spoiler: String,
+    #[serde(rename = "Read Count")]
+    read_count: Option<usize>,
+}
+
+impl Book {
+    /// The ISBNs are exported as formulas (eg: `="0439023483"`) so that
+    /// spreadsheets do not treat them as numbers.
+    fn isbns(&self) -> Vec<String> {
+        [&self.isbn13, &self.isbn]
+            .into_iter()
+            .map(|i| i.trim_start_matches('=').trim_matches('"').to_owned())
+            .filter(|i| !i.is_empty())
+            .collect()
+    }
+}
+
+/// Try the ISBNs of the book with Openlibrary first and then with Google Books.
+async fn find_book(
+    isbns: &[String],
+    openlibrary: &(dyn MediaProvider + Send + Sync),
+    google_books: &(dyn MediaProvider + Send + Sync),
+) -> Option<(MetadataSource, String)> {
+    for (source, provider) in [
+        (MetadataSource::Openlibrary, openlibrary),
+        (MetadataSource::GoogleBooks, google_books),
+    ] {
+        for isbn in isbns {
+            if let Ok(Some(identifier)) = provider
+                .id_from_external(ExternalIdentifierType::Isbn, isbn)
+                .await
+            {
+                return Some((source, identifier));
+            }
+        }
+    }
+    None
+}
+
+pub async fn import(
+    input: DeployGoodreadsCsvImportInput,
+    openlibrary: &(dyn MediaProvider + Send + Sync),
+    google_books: &(dyn MediaProvider + Send + Sync),
+) -> Result<ImportResult> {
+    let lot = MetadataLot::Book;
+    let mut media = vec![];
+    let mut failed_items = vec![];
+    let books_reader = Reader::from_reader(input.export.as_bytes())
+        .deserialize()
+        .collect_vec();
+    let total = books_reader.len();
+    for (idx, result) in books_reader.into_iter().enumerate() {
+        let record: Book = match result {
+            Ok(r) => r,
+            Err(e) => {
+                failed_items.push(ImportFailedItem {
+                    lot,
+                    step: ImportFailStep::InputTransformation,
+                    index: None,
+                    identifier: idx.to_string(),
+                    error: Some(e.to_string()),
+                });
+                continue;
+            }
+        };
+        tracing::debug!(
+            "Getting details for {title:?} ({idx}/{total})",
+            title = record.title
+        );
+        let isbns = record.isbns();
+        if isbns.is_empty() {
+            failed_items.push(ImportFailedItem {
+                lot,
+                step: ImportFailStep::InputTransformation,
+                index: None,
+                identifier: record.title,
+                error: Some("No ISBN found".to_owned()),
+            });
+            continue;
+        }
+        let (source, identifier) = match find_book(&isbns, openlibrary, google_books).await {
+            Some(found) => found,
+            None => {
+                failed_items.push(ImportFailedItem {
+                    lot,
+                    step: ImportFailStep::InputTransformation,
+                    index: None,
+                    identifier: record.title,
+                    error: Some(format!(
+                        "Could not find ISBN: {} in Openlibrary or Google Books",
+                        isbns.join(", ")
+                    )),
+                });
+                continue;
+            }
+        };
+        let mut seen_history = vec![];
+        if record.exclusive_shelf == "read" {
+            seen_history = vec![
+                ImportOrExportMediaItemSeen::default();
+                record.read_count.unwrap_or_default().max(1)
+            ];
+            if let Ok(date) = NaiveDate::parse_from_str(&record.date_read, "%Y/%m/%d") {
+                seen_history.first_mut().unwrap().ended_on =
+                    Some(DateTime::from_naive_utc_and_offset(
+                        NaiveDateTime::new(date, NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+                        Utc,
+                    ));
+            }
+        }
+        let mut collections = vec![];
+        match record.exclusive_shelf.as_str() {
+            "to-read" => collections.push(DefaultCollection::Watchlist.to_string()),
+            "currently-reading" => collections.push(DefaultCollection::InProgress.to_string()),
+            _ => {}
+        }
+        collections.extend(
+            record
+                .bookshelves
+                .split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty() && !EXCLUSIVE_SHELVES.contains(s))
+                .map(|s| s.to_case(Case::Title)),
+        );
+        let mut reviews = vec![];
+        let rating = record
+            .rating
+            .filter(|r| !r.is_zero())
+            // DEV: Rates items out of 5
+            .map(|r| r.saturating_mul(dec!(20)));
+        let review = match record.review.is_empty() {
+            true => None,
+            false => Some(ImportOrExportItemReview {
+                date: None,
+                spoiler: Some(record.spoiler == "true"),
+                text: Some(record.review),
+            }),
+        };
+        if rating.is_some() || review.is_some() {
+            reviews.push(ImportOrExportItemRating {
+                rating,
+                review,
+                ..Default::default()
+            });
+        }
+        media.push(ImportOrExportMediaItem {
+            source_id: record.book_id,
+            title: Some(record.title),
+            lot,
+            source,
+            identifier: ImportOrExportItemIdentifier::NeedsDetails(identifier),
+            seen_history,
+            reviews,
+            collections,
+            highlights: vec![],
+        });
+    }
+    Ok(ImportResult {
+        collections: vec![],
+        media,
+        failed_items,
+    })
+}
@@ -0,0 +1,266 @@
+// Responsible for importing the highlights and notes from the `My Clippings.txt`
+// file of a Kindle.
+
+use std::sync::OnceLock;
+
+use async_graphql::Result;
+use regex::Regex;
+
+use crate::{
+    importer::{
+        DeployKindleImportInput, ImportFailStep, ImportFailedItem, ImportOrExportItemIdentifier,
+        ImportOrExportMediaItem, ImportResult,
+    },
+    migrator::{MetadataLot, MetadataSource},
+    models::media::{ImportOrExportItemHighlight, MediaSearchFilters},
+    traits::MediaProvider,
+};
+
+static SEPARATOR: &str = "==========";
+
+#[derive(Debug, PartialEq)]
+enum ClippingLot {
+    Highlight,
+    Note,
+    Bookmark,
+}
+
+#[derive(Debug)]
+struct Clipping {
+    title: String,
+    author: Option<String>,
+    lot: ClippingLot,
+    page: Option<i32>,
+    /// The first and last location of the clipping in the book.
+    location: Option<(i32, i32)>,
+    text: String,
+}
+
+#[derive(Debug)]
+struct Book {
+    title: String,
+    author: Option<String>,
+    highlights: Vec<(Option<(i32, i32)>, ImportOrExportItemHighlight)>,
+}
+
+/// The title line looks like `The Great Gatsby (F. Scott Fitzgerald)`, where the
+/// author is the last parenthesized part.
+fn parse_title(line: &str) -> (String, Option<String>) {
+    let line = line.trim_start_matches('\u{feff}').trim();
+    if line.ends_with(')') {
+        if let Some(idx) = line.rfind(" (") {
+            let author = line[idx + 2..line.len() - 1].trim().to_owned();
+            return (line[..idx].trim().to_owned(), Some(author));
+        }
+    }
+    (line.to_owned(), None)
+}
+
+/// The metadata line looks like `- Your Highlight on page 12 | Location 170-172 |
+/// Added on Sunday, 3 September 2023 10:11:12`. Older devices use `Loc.` instead
+/// of `Location` and shorten the end of the range, eg: `Loc. 170-72`.
+fn parse_clipping(entry: &str) -> Option<Clipping> {
+    static PAGE: OnceLock<Regex> = OnceLock::new();
+    static LOCATION: OnceLock<Regex> = OnceLock::new();
+    let page = PAGE.get_or_init(|| Regex::new(r"(?i)\bpage (\d+)").unwrap());
+    let location =
+        LOCATION.get_or_init(|| Regex::new(r"(?i)\bloc(?:ation|\.)? (\d+)(?:-(\d+))?").unwrap());
+    let mut lines = entry
+        .lines()
+        .map(|l| l.trim_start_matches('\u{feff}').trim())
+        .skip_while(|l| l.is_empty());
+    let (title, author) = parse_title(lines.next()?);
+    let details = lines.next()?.to_lowercase();
+    let lot = if details.contains("highlight") {
+        ClippingLot::Highlight
+    } else if details.contains("note") {
+        ClippingLot::Note
+    } else if details.contains("bookmark") {
+        ClippingLot::Bookmark
+    } else {
+        return None;
+    };
+    let page = page
+        .captures(&details)
+        .and_then(|c| c.get(1))
+        .and_then(|p| p.as_str().parse().ok());
+    let location = location.captures(&details).and_then(|c| {
+        let start = c.get(1)?.as_str();
+        let end = c.get(2).map(|e| e.as_str()).unwrap_or(start);
+        // DEV: Expand shortened ranges like `170-72` to `170-172`
+        let end = match end.len() < start.len() {
+            true => format!("{}{}", &start[..start.len() - end.len()], end),
+            false => end.to_owned(),
+        };
+        Some((start.parse().ok()?, end.parse().ok()?))
+    });
+    let text = lines.collect::<Vec<_>>().join("\n").trim().to_owned();
+    Some(Clipping {
+        title,
+        author,
+        lot,
+        page,
+        location,
+        text,
+    })
+}
+
+pub async fn import(
+    input: DeployKindleImportInput,
+    provider: &(dyn MediaProvider + Send + Sync),
+) -> Result<ImportResult> {
+    let lot = MetadataLot::Book;
+    let source = MetadataSource::Openlibrary;
+    let mut failed_items = vec![];
+    let mut books: Vec<Book> = vec![];
+    for (idx, entry) in input.clippings.split(SEPARATOR).enumerate() {
+        if entry.trim().is_empty() {
+            continue;
+        }
+        let clipping = match parse_clipping(entry) {
+            Some(c) => c,
+            None => {
+                failed_items.push(ImportFailedItem {
+                    lot,
+                    step: ImportFailStep::InputTransformation,
+                    index: None,
+                    identifier: idx.to_string(),
+                    error: Some("Could not parse this clipping".to_owned()),
+                });
+                continue;
+            }
+        };
+        if clipping.lot == ClippingLot::Bookmark || clipping.text.is_empty() {
+            continue;
+        }
+        let book = match books
+            .iter()
+            .position(|b| b.title == clipping.title && b.author == clipping.author)
+        {
+            Some(p) => &mut books[p],
+            None => {
+                books.push(Book {
+                    title: clipping.title.clone(),
+                    author: clipping.author.clone(),
+                    highlights: vec![],
+                });
+                books.last_mut().unwrap()
+            }
+        };
+        match clipping.lot {
+            ClippingLot::Highlight => {
+                // DEV: Changing a highlight on the device adds a new clipping
+                // without removing the old one, so overlapping highlights where
+                // one contains the other are treated as the same passage
+                let existing = book.highlights.iter().position(|(l, h)| {
+                    h.text == clipping.text
+                        || match (l, clipping.location) {
+                            (Some((s, e)), Some((cs, ce))) => {
+                                *s <= ce
+                                    && cs <= *e
+                                    && (h.text.contains(&clipping.text)
+                                        || clipping.text.contains(&h.text))
+                            }
+                            _ => false,
+                        }
+                });
+                let highlight = ImportOrExportItemHighlight {
+                    text: clipping.text,
+                    page: clipping.page,
+                    chapter: None,
+                    note: None,
+                };
+                match existing {
+                    Some(e) => {
+                        let note = book.highlights[e].1.note.take();
+                        book.highlights[e] = (clipping.location, highlight);
+                        book.highlights[e].1.note = note;
+                    }
+                    None => book.highlights.push((clipping.location, highlight)),
+                }
+            }
+            ClippingLot::Note => {
+                // DEV: Notes are separate clippings that are placed at the last
+                // location of the highlight they were written for
+                let highlight = match clipping.location {
+                    Some((n, _)) => book
+                        .highlights
+                        .iter_mut()
+                        .rev()
+                        .find(|(l, _)| matches!(l, Some((s, e)) if *s <= n && n <= *e)),
+                    None => None,
+                };
+                match highlight {
+                    Some((_, h)) => {
+                        h.note = Some(match h.note.take() {
+                            Some(n) => format!("{}\n\n{}", n, clipping.text),
+                            None => clipping.text,
+                        })
+                    }
+                    None => failed_items.push(ImportFailedItem {
+                        lot,
+                        step: ImportFailStep::InputTransformation,
+                        index: None,
+                        identifier: clipping.title,
+                        error: Some(format!(
+                            "Could not find the highlight for the note: {}",
+                            clipping.text
+                        )),
+                    }),
+                }
+            }
+            ClippingLot::Bookmark => unreachable!(),
+        }
+    }
+    let mut media = vec![];
+    let total = books.len();
+    for (idx, book) in books.into_iter().enumerate() {
+        tracing::debug!(
+            "Getting details for {title:?} ({idx}/{total})",
+            title = book.title
+        );
+        // DEV: Kindle titles usually include the subtitle and series, which make
+        // the search less accurate
+        let title = book
+            .title
+            .split([':', '(', '['])
+            .next()
+            .unwrap_or(&book.title)
+            .trim();
+        let query = match &book.author {
+            Some(a) => format!("{} {}", title, a),
+            None => title.to_owned(),
+        };
+        let identifier = provider
+            .search(&query, None, false, &MediaSearchFilters::default())
+            .await
+            .ok()
+            .and_then(|r| r.items.into_iter().next())
+            .map(|i| i.identifier);
+        match identifier {
+            Some(identifier) => media.push(ImportOrExportMediaItem {
+                source_id: book.title.clone(),
+                title: Some(book.title),
+                lot,
+                source,
+                identifier: ImportOrExportItemIdentifier::NeedsDetails(identifier),
+                seen_history: vec![],
+                reviews: vec![],
+                collections: vec![],
+                highlights: book.highlights.into_iter().map(|(_, h)| h).collect(),
+            }),
+            None => failed_items.push(ImportFailedItem {
+                lot,
+                step: ImportFailStep::MediaDetailsFromProvider,
+                index: None,
+                identifier: book.title,
+                error: Some("Could not find this book on Openlibrary".to_owned()),
+            }),
+        }
+    }
+    Ok(ImportResult {
+        collections: vec![],
+        media,
+        failed_items,
+    })
+}
@@ -0,0 +1,249 @@
+use std::{collections::HashMap, fs::File};
+
+use async_graphql::Result;
+use csv::Reader;
+use itertools::Itertools;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use zip::{result::ZipError, ZipArchive};
+
+use crate::{
+    importer::{
+        DeployLetterboxdImportInput, ImportFailStep, ImportFailedItem,
+        ImportOrExportItemIdentifier, ImportOrExportMediaItem, ImportResult,
+    },
+    migrator::{MetadataLot, MetadataSource},
+    miscellaneous::DefaultCollection,
+    models::media::{
+        ImportOrExportItemRating, ImportOrExportItemReview, ImportOrExportMediaItemSeen,
+        MediaSearchFilters,
+    },
+    traits::MediaProvider,
+    utils::{convert_naive_to_utc, convert_string_to_date},
+};
+
+/// A row of any of the CSV files of the export. They all share the first
+/// columns and only some of them have the rest.
+#[derive(Debug, Serialize, Deserialize)]
+struct Entry {
+    #[serde(rename = "Date")]
+    date: String,
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "Year")]
+    year: Option<i32>,
+    #[serde(rename = "Rating")]
+    rating: Option<Decimal>,
+    #[serde(rename = "Review")]
+    review: Option<String>,
+    #[serde(rename = "Watched Date")]
+    watched_date: Option<String>,
+}
+
+impl Entry {
+    /// The date the film was watched on, falling back to the date it was logged.
+    fn watched_on(&self) -> Option<String> {
+        self.watched_date
+            .clone()
+            .filter(|d| !d.is_empty())
+            .or_else(|| Some(self.date.clone()).filter(|d| !d.is_empty()))
+    }
+
+    fn rating(&self) -> Option<Decimal> {
+        self.rating
+            .filter(|r| !r.is_zero())
+            // DEV: Rates items out of 5, allowing halves
+            .map(|r| r.saturating_mul(dec!(20)))
+    }
+}
+
+/// The films are not identified by anything but their name and year across the
+/// files, since the URIs of the diary and reviews point to the entries.
+type FilmKey = (String, Option<i32>);
+
+#[derive(Debug, Default)]
+struct Film {
+    watched: bool,
+    in_watchlist: bool,
+    rating: Option<Decimal>,
+    seen_history: Vec<ImportOrExportMediaItemSeen>,
+    reviews: Vec<ImportOrExportItemRating>,
+}
+
+#[derive(Debug, Default)]
+struct Export {
+    watched: Vec<csv::Result<Entry>>,
+    watchlist: Vec<csv::Result<Entry>>,
+    ratings: Vec<csv::Result<Entry>>,
+    diary: Vec<csv::Result<Entry>>,
+    reviews: Vec<csv::Result<Entry>>,
+}
+
+/// Files that are not in the archive are treated as empty, since Letterboxd
+/// leaves out some of them for accounts without any such data.
+fn read_csv(archive: &mut ZipArchive<File>, name: &str) -> Result<Vec<csv::Result<Entry>>> {
+    let file = match archive.by_name(name) {
+        Ok(f) => f,
+        Err(ZipError::FileNotFound) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(Reader::from_reader(file).deserialize().collect_vec())
+}
+
+fn read_export(path: &str) -> Result<Export> {
+    let mut archive = ZipArchive::new(File::open(path)?)?;
+    Ok(Export {
+        watched: read_csv(&mut archive, "watched.csv")?,
+        watchlist: read_csv(&mut archive, "watchlist.csv")?,
+        ratings: read_csv(&mut archive, "ratings.csv")?,
+        diary: read_csv(&mut archive, "diary.csv")?,
+        reviews: read_csv(&mut archive, "reviews.csv")?,
+    })
+}
+
+/// Search TMDB for the film, preferring a result with the exact title that was
+/// released in the same year.
+async fn find_film(
+    name: &str,
+    year: Option<i32>,
+    provider: &(dyn MediaProvider + Send + Sync),
+) -> Option<String> {
+    let filters = MediaSearchFilters {
+        year_from: year,
+        year_to: year,
+        ..Default::default()
+    };
+    let items = provider
+        .search(name, None, true, &filters)
+        .await
+        .ok()?
+        .items;
+    let same_year = |publish_year: Option<i32>| year.is_none() || publish_year == year;
+    items
+        .iter()
+        .find(|i| i.title.eq_ignore_ascii_case(name) && same_year(i.publish_year))
+        .or_else(|| items.iter().find(|i| same_year(i.publish_year)))
+        .map(|i| i.identifier.clone())
+}
+
+pub async fn import(
+    input: DeployLetterboxdImportInput,
+    provider: &(dyn MediaProvider + Send + Sync),
+) -> Result<ImportResult> {
+    let lot = MetadataLot::Movie;
+    let source = MetadataSource::Tmdb;
+    let mut media = vec![];
+    let mut failed_items = vec![];
+    let export = read_export(&input.export_path)?;
+    let mut films: HashMap<FilmKey, Film> = HashMap::new();
+    let files = [
+        ("watched.csv", export.watched),
+        ("watchlist.csv", export.watchlist),
+        ("ratings.csv", export.ratings),
+        ("diary.csv", export.diary),
+        ("reviews.csv", export.reviews),
+    ];
+    for (file, entries) in files {
+        for (idx, result) in entries.into_iter().enumerate() {
+            let entry = match result {
+                Ok(e) => e,
+                Err(e) => {
+                    failed_items.push(ImportFailedItem {
+                        lot,
+                        step: ImportFailStep::InputTransformation,
+                        index: None,
+                        identifier: format!("{}:{}", file, idx),
+                        error: Some(e.to_string()),
+                    });
+                    continue;
+                }
+            };
+            let watched_on = entry
+                .watched_on()
+                .as_deref()
+                .and_then(convert_string_to_date)
+                .map(convert_naive_to_utc);
+            let rating = entry.rating();
+            let film = films.entry((entry.name, entry.year)).or_default();
+            match file {
+                "watched.csv" => film.watched = true,
+                "watchlist.csv" => film.in_watchlist = true,
+                "ratings.csv" => film.rating = rating,
+                "diary.csv" => film.seen_history.push(ImportOrExportMediaItemSeen {
+                    ended_on: watched_on,
+                    ..Default::default()
+                }),
+                _ => {
+                    let review = entry.review.filter(|r| !r.is_empty()).map(|text| {
+                        ImportOrExportItemReview {
+                            date: watched_on,
+                            spoiler: Some(false),
+                            text: Some(text),
+                        }
+                    });
+                    film.reviews.push(ImportOrExportItemRating {
+                        rating,
+                        review,
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+    }
+    let total = films.len();
+    for (idx, ((name, year), mut film)) in films.into_iter().enumerate() {
+        tracing::debug!("Getting details for {name:?} ({idx}/{total})");
+        let identifier = match find_film(&name, year, provider).await {
+            Some(identifier) => identifier,
+            None => {
+                failed_items.push(ImportFailedItem {
+                    lot,
+                    step: ImportFailStep::InputTransformation,
+                    index: None,
+                    identifier: name,
+                    error: Some(format!(
+                        "Could not find a film released in {} in TMDB",
+                        year.map_or_else(|| "any year".to_owned(), |y| y.to_string())
+                    )),
+                });
+                continue;
+            }
+        };
+        // DEV: Films that are marked as watched without being logged in the
+        // diary do not have a date
+        if film.seen_history.is_empty() && film.watched {
+            film.seen_history
+                .push(ImportOrExportMediaItemSeen::default());
+        }
+        if film.rating.is_some() && film.reviews.iter().all(|r| r.rating.is_none()) {
+            film.reviews.push(ImportOrExportItemRating {
+                rating: film.rating,
+                ..Default::default()
+            });
+        }
+        let mut collections = vec![];
+        if film.in_watchlist {
+            collections.push(DefaultCollection::Watchlist.to_string());
+        }
+        media.push(ImportOrExportMediaItem {
+            source_id: match year {
+                Some(y) => format!("{} ({})", name, y),
+                None => name.clone(),
+            },
+            title: Some(name),
+            lot,
+            source,
+            identifier: ImportOrExportItemIdentifier::NeedsDetails(identifier),
+            seen_history: film.seen_history,
+            reviews: film.reviews,
+            collections,
+            highlights: vec![],
+        });
+    }
+    Ok(ImportResult {
+        collections: vec![],
+        media,
+        failed_items,
+    })
+}
@@ -11,12 +11,14 @@ use sea_orm::prelude::DateTimeUtc;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 use crate::{
-    importer::{DeployMalImportInput, ImportResult},
-    migrator::{MetadataLot, MetadataSource},
+    importer::{DeployMalImportInput, ImportFailStep, ImportFailedItem, ImportResult},
+    migrator::{MetadataLot, MetadataSource, SeenState},
+    miscellaneous::DefaultCollection,
     models::media::{
-        ImportOrExportItemIdentifier, ImportOrExportItemRating, ImportOrExportMediaItem,
-        ImportOrExportMediaItemSeen,
+        ExternalIdentifierType, ImportOrExportItemIdentifier, ImportOrExportItemRating,
+        ImportOrExportMediaItem, ImportOrExportMediaItemSeen,
     },
+    traits::MediaProvider,
     utils::{convert_naive_to_utc, convert_string_to_date},
 };
 
@@ -40,54 +42,117 @@ fn get_date(date: String) -> Option<DateTimeUtc> {
     }
 }
 
-fn convert_to_format(
+/// Where the MAL ids are mapped to when the MAL provider is not configured.
+type Fallback<'a> = Option<&'a (dyn MediaProvider + Send + Sync)>;
+
+async fn convert_to_format(
     item: Item,
     lot: MetadataLot,
-) -> ImportOrExportMediaItem<ImportOrExportItemIdentifier> {
-    let progress = if item.done != 0 && item.total != 0 {
-        Some(item.done / item.total)
-    } else {
-        None
-    };
-    let seen_item = ImportOrExportMediaItemSeen {
-        started_on: get_date(item.my_start_date),
-        ended_on: get_date(item.my_finish_date),
-        progress,
-        ..Default::default()
-    };
-    let review_item = ImportOrExportItemRating {
-        review: None,
-        rating: if item.my_score == 0 {
-            None
-        } else {
-            Some(Decimal::from_u32(item.my_score).unwrap() * dec!(10))
+    fallback: Fallback<'_>,
+) -> Result<ImportOrExportMediaItem<ImportOrExportItemIdentifier>, ImportFailedItem> {
+    let (source, identifier) = match fallback {
+        None => (MetadataSource::Mal, item.identifier.to_string()),
+        Some(provider) => match provider
+            .id_from_external(ExternalIdentifierType::Mal, &item.identifier.to_string())
+            .await
+        {
+            Ok(Some(identifier)) => (MetadataSource::Anilist, identifier),
+            _ => {
+                return Err(ImportFailedItem {
+                    lot,
+                    step: ImportFailStep::InputTransformation,
+                    index: None,
+                    identifier: item.title,
+                    error: Some(format!(
+                        "Could not find MAL id: {} in Anilist",
+                        item.identifier
+                    )),
+                })
+            }
         },
-        ..Default::default()
     };
-    ImportOrExportMediaItem {
-        source_id: item.title,
-        lot,
-        source: MetadataSource::Mal,
-        identifier: ImportOrExportItemIdentifier::NeedsDetails(item.identifier.to_string()),
-        seen_history: vec![seen_item],
-        reviews: vec![review_item],
-        collections: vec![],
+    let mut seen_history = vec![];
+    let mut collections = vec![];
+    match item.my_status.as_str() {
+        "Plan to Watch" | "Plan to Read" => {
+            collections.push(DefaultCollection::Watchlist.to_string())
+        }
+        "Completed" => {
+            seen_history = vec![
+                ImportOrExportMediaItemSeen::default();
+                usize::try_from(item.times).unwrap_or_default() + 1
+            ];
+            let last = seen_history.last_mut().unwrap();
+            last.started_on = get_date(item.my_start_date);
+            last.ended_on = get_date(item.my_finish_date);
+        }
+        status => {
+            // DEV: The total is `0` while the media is still being released
+            let progress = match item.total > 0 {
+                true => (item.done * 100 / item.total).min(99),
+                false => 0,
+            };
+            seen_history.push(ImportOrExportMediaItemSeen {
+                started_on: get_date(item.my_start_date),
+                progress: Some(progress),
+                state: match status {
+                    "Dropped" => Some(SeenState::Dropped),
+                    "On-Hold" => Some(SeenState::OnAHold),
+                    _ => None,
+                },
+                ..Default::default()
+            });
+        }
+    }
+    let mut reviews = vec![];
+    if item.my_score > 0 {
+        reviews.push(ImportOrExportItemRating {
+            // DEV: Rates items out of 10
+            rating: Some(Decimal::from_u32(item.my_score).unwrap() * dec!(10)),
+            ..Default::default()
+        });
     }
+    Ok(ImportOrExportMediaItem {
+        source_id: item.title.clone(),
+        title: Some(item.title),
+        lot,
+        source,
+        identifier: ImportOrExportItemIdentifier::NeedsDetails(identifier),
+        seen_history,
+        reviews,
+        collections,
+        highlights: vec![],
+    })
 }
 
-pub async fn import(input: DeployMalImportInput) -> Result<ImportResult> {
+pub async fn import(
+    input: DeployMalImportInput,
+    anime_fallback: Fallback<'_>,
+    manga_fallback: Fallback<'_>,
+) -> Result<ImportResult> {
     let anime_data = decode_data::<DataRoot>(&input.anime_path)?;
     let manga_data = decode_data::<DataRoot>(&input.manga_path)?;
     let mut media = vec![];
-    for item in anime_data.items.into_iter() {
-        media.push(convert_to_format(item, MetadataLot::Anime));
-    }
-    for item in manga_data.items.into_iter() {
-        media.push(convert_to_format(item, MetadataLot::Manga));
+    let mut failed_items = vec![];
+    let items = anime_data
+        .items
+        .into_iter()
+        .map(|i| (i, MetadataLot::Anime, anime_fallback))
+        .chain(
+            manga_data
+                .items
+                .into_iter()
+                .map(|i| (i, MetadataLot::Manga, manga_fallback)),
+        );
+    for (item, lot, fallback) in items {
+        match convert_to_format(item, lot, fallback).await {
+            Ok(m) => media.push(m),
+            Err(f) => failed_items.push(f),
+        }
     }
     Ok(ImportResult {
         collections: vec![],
-        failed_items: vec![],
+        failed_items,
         media,
     })
 }
@@ -111,4 +176,8 @@ struct Item {
     my_start_date: String,
     my_finish_date: String,
     my_score: u32,
+    my_status: String,
+    /// The number of times the media was completed again after the first time.
+    #[serde(default, alias = "my_times_watched", alias = "my_times_read")]
+    times: u32,
 }
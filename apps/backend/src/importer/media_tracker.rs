@@ -172,6 +172,7 @@ pub async fn import(input: DeployMediaTrackerImportInput) -> Result<ImportResult
                 ListPrivacy::Public => Visibility::Public,
             }),
             update_id: None,
+            actions: None,
         })
         .collect();
     for list in lists.iter_mut() {
@@ -219,6 +220,7 @@ pub async fn import(input: DeployMediaTrackerImportInput) -> Result<ImportResult
                 failed_items.push(ImportFailedItem {
                     lot,
                     step: ImportFailStep::ItemDetailsFromSource,
+                    index: None,
                     identifier: d.id.to_string(),
                     error: Some(e.to_string()),
                 });
@@ -266,13 +268,16 @@ pub async fn import(input: DeployMediaTrackerImportInput) -> Result<ImportResult
 
         let item = ImportOrExportMediaItem {
             source_id: d.id.to_string(),
+            title: Some(details.title.clone()),
             source,
             lot,
             collections,
+            highlights: vec![],
             identifier: match need_details {
                 false => ImportOrExportItemIdentifier::AlreadyFilled(Box::new(MediaDetails {
                     identifier,
                     title: details.title,
+                    alternative_titles: vec![],
                     description: details.overview,
                     production_status: "Released".to_owned(),
                     lot,
@@ -1,38 +1,69 @@
-use std::sync::Arc;
+use std::{collections::HashMap, panic::AssertUnwindSafe, sync::Arc};
 
 use apalis::prelude::Storage;
-use async_graphql::{Context, Enum, InputObject, Object, Result, SimpleObject};
+use async_graphql::{
+    Context, Enum, Error, InputObject, Object, Result, SimpleObject, Subscription,
+};
 use chrono::{Duration, Utc};
+use futures::{stream, FutureExt, Stream};
 use itertools::Itertools;
+use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use sea_orm::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, FromJsonQueryResult, QueryFilter,
-    QueryOrder,
+    prelude::DateTimeUtc, ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait,
+    FromJsonQueryResult, ModelTrait, PaginatorTrait, QueryFilter, QueryOrder,
 };
+use sea_query::Expr;
 use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
 use tracing::instrument;
 
 use crate::{
     background::ApplicationJob,
-    entities::{import_report, prelude::ImportReport},
-    migrator::{ImportSource, MetadataLot},
+    entities::{
+        collection, highlight, import_report,
+        prelude::{Collection, Highlight, ImportReport, Review, Seen},
+        review, seen, user_measurement,
+    },
+    fitness::resolver::ExerciseService,
+    migrator::{ImportSource, MetadataLot, MetadataSource, SeenState},
     miscellaneous::resolver::MiscellaneousService,
-    models::media::{
-        AddMediaToCollection, CreateOrUpdateCollectionInput, ImportOrExportItemIdentifier,
-        ImportOrExportMediaItem, PostReviewInput, ProgressUpdateInput,
+    models::{
+        fitness::ExternalWorkout,
+        media::{
+            AddMediaToCollection, CreateOrUpdateCollectionInput, CreateOrUpdateHighlightInput,
+            ImportOrExportItemIdentifier, ImportOrExportMediaItem, PostReviewInput,
+            ProgressUpdateInput,
+        },
     },
     traits::AuthProvider,
     users::UserReviewScale,
     utils::user_by_id,
 };
 
+mod garmin_connect;
+mod generic_csv;
 mod goodreads;
+mod goodreads_csv;
+mod kindle;
+mod letterboxd;
 mod mal;
 mod media_json;
 mod media_tracker;
 mod movary;
+mod playstation;
+mod ryot;
 mod story_graph;
 mod trakt;
+mod tv_time;
+mod xbox;
+
+/// The number of days that the input of an import is kept for resuming it or
+/// retrying its failed items.
+static IMPORT_INPUT_RETENTION_DAYS: i64 = 7;
+/// The number of minutes after which a media import that has not saved a
+/// checkpoint is considered to have stopped, eg: because the server restarted.
+static IMPORT_STALE_MINUTES: i64 = 60;
 
 #[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
 pub struct DeployMediaTrackerImportInput {
@@ -48,10 +79,18 @@ pub struct DeployGoodreadsImportInput {
     rss_url: String,
 }
 
+#[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
+pub struct DeployGoodreadsCsvImportInput {
+    /// The CSV contents of the library export.
+    export: String,
+}
+
 #[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
 pub struct DeployTraktImportInput {
     // The public username in Trakt.
     username: String,
+    /// An OAuth access token of the user, needed to import a private profile.
+    access_token: Option<String>,
 }
 
 #[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
@@ -72,6 +111,54 @@ pub struct DeployMalImportInput {
     manga_path: String,
 }
 
+#[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
+pub struct DeployLetterboxdImportInput {
+    /// The ZIP data export file path (uploaded via temporary upload).
+    export_path: String,
+}
+
+#[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
+pub struct DeployTvTimeImportInput {
+    /// The ZIP data export file path (uploaded via temporary upload).
+    export_path: String,
+}
+
+/// What the values of the identifier column of a CSV file are.
+#[derive(Debug, Enum, Serialize, Deserialize, Clone, Eq, PartialEq, Copy, Default)]
+pub enum GenericCsvIdentifierType {
+    /// The identifier of the item in the default provider of the type of media.
+    #[default]
+    Provider,
+    Isbn,
+    Imdb,
+}
+
+/// A CSV file from any service. The columns are referred to by their headers.
+#[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
+pub struct DeployGenericCsvImportInput {
+    /// The CSV file path (uploaded via temporary upload).
+    csv_path: String,
+    /// The type of media in the file. The items are looked up in the default
+    /// provider of this type.
+    lot: MetadataLot,
+    title_column: String,
+    /// The column that identifies the items, used before searching for their
+    /// titles.
+    identifier_column: Option<String>,
+    /// Defaults to the identifier of the provider.
+    identifier_type: Option<GenericCsvIdentifierType>,
+    /// The column with the date that the item was consumed on. Every row is
+    /// imported as consumed, even when it does not have a date.
+    date_column: Option<String>,
+    /// The format of the dates (eg: `%d/%m/%Y`). By default, dates like
+    /// `2023-10-19` and `2023-10-19 20:30:00` are recognized.
+    date_format: Option<String>,
+    rating_column: Option<String>,
+    /// The highest rating possible in the file (eg: `5` or `10`). Defaults to
+    /// `100`.
+    rating_scale: Option<Decimal>,
+}
+
 #[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
 pub struct DeployStoryGraphImportInput {
     // The CSV contents of the export file.
@@ -84,27 +171,113 @@ pub struct DeployMediaJsonImportInput {
     export: String,
 }
 
+#[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
+pub struct DeployGarminConnectImportInput {
+    /// The `summarizedActivities.json` file path from the export (uploaded via
+    /// temporary upload).
+    activities_path: String,
+    /// The `userBioMetrics.json` file path from the export (uploaded via
+    /// temporary upload).
+    bio_metrics_path: Option<String>,
+}
+
+#[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
+pub struct DeployKindleImportInput {
+    /// The contents of the `My Clippings.txt` file from the Kindle.
+    clippings: String,
+}
+
+#[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
+pub struct DeployPlaystationImportInput {
+    /// The JSON list of played titles from the PlayStation Network.
+    export: String,
+}
+
+#[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
+pub struct DeployXboxImportInput {
+    /// The JSON title history from the Xbox network.
+    export: String,
+}
+
+#[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
+pub struct DeployRyotImportInput {
+    /// The base url of the other instance.
+    url: String,
+    /// The username of the user on the other instance.
+    username: String,
+    /// The password of the user on the other instance. It is only used to login
+    /// when the import is deployed, and is never saved.
+    #[graphql(secret)]
+    #[serde(skip_serializing, default)]
+    password: String,
+    /// The token that the import authenticates with, from logging in to the other
+    /// instance when the import is deployed.
+    #[graphql(skip)]
+    #[serde(default)]
+    api_key: Option<String>,
+}
+
 #[derive(Debug, Enum, Serialize, Deserialize, Clone, Eq, PartialEq, Copy)]
 pub enum ImportLot {
     Media,
     Exercise,
 }
 
+/// How the data of a media item that the user already has data for is handled.
+/// Seen history, reviews and highlights are each handled separately, and only
+/// when the import has data of that kind for the item.
+#[derive(Debug, Enum, Serialize, Deserialize, Clone, Eq, PartialEq, Copy, Default)]
+pub enum ImportConflictPolicy {
+    /// Keep the existing data and ignore the imported data.
+    Skip,
+    /// Remove the existing data and replace it with the imported data.
+    Overwrite,
+    /// Add the imported data to the existing data.
+    #[default]
+    Append,
+}
+
+/// The kinds of data of a media item that the conflict policy is applied to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ImportConflictData {
+    SeenHistory,
+    Reviews,
+    Highlights,
+}
+
 #[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
 pub struct DeployImportJobInput {
     pub lot: ImportLot,
     pub source: ImportSource,
+    /// How to handle media that the user already has data for. Defaults to
+    /// appending. Fitness data is never imported twice, so this only applies to
+    /// media.
+    pub conflict_policy: Option<ImportConflictPolicy>,
     pub media_tracker: Option<DeployMediaTrackerImportInput>,
     pub goodreads: Option<DeployGoodreadsImportInput>,
+    pub goodreads_csv: Option<DeployGoodreadsCsvImportInput>,
     pub trakt: Option<DeployTraktImportInput>,
     pub movary: Option<DeployMovaryImportInput>,
     pub mal: Option<DeployMalImportInput>,
+    pub letterboxd: Option<DeployLetterboxdImportInput>,
+    pub tv_time: Option<DeployTvTimeImportInput>,
+    pub generic_csv: Option<DeployGenericCsvImportInput>,
     pub story_graph: Option<DeployStoryGraphImportInput>,
     pub media_json: Option<DeployMediaJsonImportInput>,
+    pub garmin_connect: Option<DeployGarminConnectImportInput>,
+    pub ryot: Option<DeployRyotImportInput>,
+    pub kindle: Option<DeployKindleImportInput>,
+    pub playstation: Option<DeployPlaystationImportInput>,
+    pub xbox: Option<DeployXboxImportInput>,
+    /// Find the media items without importing anything, and save what the
+    /// import would have done in its report instead. Only supported for media.
+    #[graphql(default)]
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 /// The various steps in which media importing can fail
-#[derive(Debug, Enum, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Enum, PartialEq, Eq, Copy, Clone, Serialize, Deserialize, Hash)]
 pub enum ImportFailStep {
     /// Failed to get details from the source itself (for eg: MediaTracker, Goodreads etc.)
     ItemDetailsFromSource,
@@ -116,6 +289,8 @@ pub enum ImportFailStep {
     SeenHistoryConversion,
     /// Failed to save a review/rating item
     ReviewConversion,
+    /// Failed to save a highlight
+    HighlightConversion,
 }
 
 #[derive(
@@ -124,10 +299,39 @@ pub enum ImportFailStep {
 pub struct ImportFailedItem {
     lot: MetadataLot,
     step: ImportFailStep,
+    /// The position of the seen history item, review or highlight of the item
+    /// that could not be saved, for the steps that save them.
+    #[serde(default)]
+    index: Option<usize>,
     identifier: String,
     error: Option<String>,
 }
 
+/// A different identifier to look up an item that failed to import with when it
+/// is retried.
+#[derive(Debug, InputObject, Serialize, Deserialize, Clone)]
+pub struct ImportFailedItemOverride {
+    pub lot: MetadataLot,
+    /// The identifier of the failed item in the report.
+    pub identifier: String,
+    /// The identifier of the media item with the provider that the import uses.
+    pub provider_identifier: String,
+}
+
+/// How far a media import got, so that it can be resumed from there if it fails.
+#[derive(
+    Debug, SimpleObject, Serialize, Deserialize, FromJsonQueryResult, Eq, PartialEq, Clone,
+)]
+pub struct ImportCheckpoint {
+    /// The number of media items that were imported.
+    pub processed: usize,
+    /// The identifier of the media item that was imported last.
+    pub last_item: String,
+    /// When the checkpoint was saved.
+    #[serde(default)]
+    pub saved_on: Option<DateTimeUtc>,
+}
+
 #[derive(Debug, SimpleObject, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct ImportDetails {
     pub total: usize,
@@ -140,12 +344,78 @@ pub struct ImportResult {
     failed_items: Vec<ImportFailedItem>,
 }
 
+#[derive(Debug)]
+pub struct ExerciseImportResult {
+    workouts: Vec<ExternalWorkout>,
+    measurements: Vec<user_measurement::Model>,
+}
+
 #[derive(
     Debug, SimpleObject, Serialize, Deserialize, FromJsonQueryResult, Eq, PartialEq, Clone,
 )]
 pub struct ImportResultResponse {
     pub import: ImportDetails,
     pub failed_items: Vec<ImportFailedItem>,
+    /// What the import would have done, if it was a dry run.
+    #[serde(default)]
+    pub preview: Option<ImportPreview>,
+}
+
+/// A media item of an import that was a dry run.
+#[derive(Debug, SimpleObject, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ImportPreviewItem {
+    pub lot: MetadataLot,
+    pub source: MetadataSource,
+    /// The identifier of the item in the source of the import.
+    pub identifier: String,
+    /// Whether the item is not in the database yet and would be created.
+    pub is_new: bool,
+}
+
+/// What a media import would have done if it was not a dry run.
+#[derive(Debug, SimpleObject, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+pub struct ImportPreview {
+    /// The items that were found, either in the database or with their provider.
+    pub matched: Vec<ImportPreviewItem>,
+    /// The items that could not be found. The reasons are in the failed items.
+    pub unmatched: Vec<ImportPreviewItem>,
+    /// The collections that would be created for the user.
+    pub collections: Vec<String>,
+    /// The number of seen history items that would be added.
+    pub seen_history: usize,
+    /// The number of reviews that would be added.
+    pub reviews: usize,
+    /// The number of highlights that would be added.
+    pub highlights: usize,
+}
+
+/// The progress of an import that is running. It is sent every time an item is
+/// processed, and once more when the import is finished.
+#[derive(Debug, SimpleObject, Clone, Default)]
+pub struct ImportProgress {
+    /// The id of the report that will contain the results of the import.
+    pub report_id: i32,
+    /// The item that was processed last.
+    pub current_item: Option<String>,
+    pub processed: usize,
+    pub total: usize,
+    /// The number of items that were found and imported.
+    pub matched: usize,
+    /// The number of items that could not be found or imported.
+    pub unmatched: usize,
+    pub finished: bool,
+}
+
+impl ImportProgress {
+    fn record(&mut self, item: &str, count: usize, matched: bool) {
+        self.current_item = Some(item.to_owned());
+        self.processed += count;
+        if matched {
+            self.matched += count;
+        } else {
+            self.unmatched += count;
+        }
+    }
 }
 
 #[derive(Default)]
@@ -176,17 +446,76 @@ impl ImporterMutation {
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
         service.deploy_import_job(user_id, input).await
     }
+
+    /// Resume a media import that failed from the item after the last one that
+    /// was imported.
+    async fn resume_import_job(&self, gql_ctx: &Context<'_>, report_id: i32) -> Result<String> {
+        let service = gql_ctx.data_unchecked::<Arc<ImporterService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.resume_import_job(user_id, report_id).await
+    }
+
+    /// Import the items that failed in a media import again, optionally looking
+    /// some of them up with a different identifier. The results are saved in the
+    /// same report.
+    async fn retry_import_failed_items(
+        &self,
+        gql_ctx: &Context<'_>,
+        report_id: i32,
+        overrides: Option<Vec<ImportFailedItemOverride>>,
+    ) -> Result<String> {
+        let service = gql_ctx.data_unchecked::<Arc<ImporterService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .retry_import_failed_items(user_id, report_id, overrides.unwrap_or_default())
+            .await
+    }
+}
+
+#[derive(Default)]
+pub struct ImporterSubscription;
+
+#[Subscription]
+impl ImporterSubscription {
+    /// Get the progress of the imports of the user whenever one of them makes
+    /// progress.
+    async fn import_progress_updated(
+        &self,
+        gql_ctx: &Context<'_>,
+    ) -> Result<impl Stream<Item = ImportProgress>> {
+        let service = gql_ctx.data_unchecked::<Arc<ImporterService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        let receiver = service.import_progress_updates.subscribe();
+        Ok(stream::unfold(receiver, move |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok((id, progress)) if id == user_id => return Some((progress, receiver)),
+                    Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        }))
+    }
 }
 
 pub struct ImporterService {
     media_service: Arc<MiscellaneousService>,
+    exercise_service: Arc<ExerciseService>,
+    import_progress_updates: broadcast::Sender<(i32, ImportProgress)>,
 }
 
 impl AuthProvider for ImporterService {}
 
 impl ImporterService {
-    pub fn new(media_service: Arc<MiscellaneousService>) -> Self {
-        Self { media_service }
+    pub fn new(
+        media_service: Arc<MiscellaneousService>,
+        exercise_service: Arc<ExerciseService>,
+    ) -> Self {
+        Self {
+            media_service,
+            exercise_service,
+            import_progress_updates: broadcast::channel(16).0,
+        }
     }
 
     pub async fn deploy_import_job(
@@ -197,6 +526,15 @@ impl ImporterService {
         if let Some(s) = input.media_tracker.as_mut() {
             s.api_url = s.api_url.trim_end_matches('/').to_owned()
         }
+        if let Some(s) = input.ryot.as_mut() {
+            s.url = s.url.trim_end_matches('/').to_owned();
+            // DEV: The password would otherwise be saved in the job and the report
+            s.api_key = Some(ryot::login(s).await?);
+            s.password = String::new();
+        }
+        if input.dry_run && input.lot != ImportLot::Media {
+            return Err(Error::new("Only media imports can be a dry run"));
+        }
         let job = self
             .media_service
             .perform_application_job
@@ -207,6 +545,67 @@ impl ImporterService {
         Ok(job.to_string())
     }
 
+    pub async fn resume_import_job(&self, user_id: i32, report_id: i32) -> Result<String> {
+        let report = ImportReport::find_by_id(report_id)
+            .filter(import_report::Column::UserId.eq(user_id))
+            .one(&self.media_service.db)
+            .await?
+            .ok_or_else(|| Error::new("Import report does not exist"))?;
+        let last_activity = report
+            .checkpoint
+            .as_ref()
+            .and_then(|c| c.saved_on)
+            .unwrap_or(report.started_on);
+        let is_stale = report.success.is_none()
+            && Utc::now() - last_activity > Duration::minutes(IMPORT_STALE_MINUTES);
+        if !(report.success == Some(false) || is_stale) || report.input.is_none() {
+            return Err(Error::new(
+                "Only media imports that failed or stopped can be resumed",
+            ));
+        }
+        let job = self
+            .media_service
+            .perform_application_job
+            .clone()
+            .push(ApplicationJob::ResumeImportMedia(user_id, report_id))
+            .await
+            .unwrap();
+        Ok(job.to_string())
+    }
+
+    pub async fn retry_import_failed_items(
+        &self,
+        user_id: i32,
+        report_id: i32,
+        overrides: Vec<ImportFailedItemOverride>,
+    ) -> Result<String> {
+        let report = ImportReport::find_by_id(report_id)
+            .filter(import_report::Column::UserId.eq(user_id))
+            .one(&self.media_service.db)
+            .await?
+            .ok_or_else(|| Error::new("Import report does not exist"))?;
+        let has_failed_items = report
+            .details
+            .as_ref()
+            .map(|d| !d.failed_items.is_empty())
+            .unwrap_or_default();
+        if report.success != Some(true) || report.input.is_none() || !has_failed_items {
+            return Err(Error::new(
+                "Only media imports that finished with failed items can be retried",
+            ));
+        }
+        let job = self
+            .media_service
+            .perform_application_job
+            .clone()
+            .push(ApplicationJob::RetryImportFailedItems(
+                user_id, report_id, overrides,
+            ))
+            .await
+            .unwrap();
+        Ok(job.to_string())
+    }
+
     pub async fn invalidate_import_jobs(&self) -> Result<()> {
         let all_jobs = ImportReport::find()
             .filter(import_report::Column::Success.is_null())
@@ -220,6 +619,20 @@ impl ImporterService {
                 job.save(&self.media_service.db).await?;
             }
         }
+        // DEV: The input can contain credentials of the source, so it is not
+        // kept forever for imports that are never resumed or retried
+        ImportReport::update_many()
+            .col_expr(
+                import_report::Column::Input,
+                Expr::value(Option::<serde_json::Value>::None),
+            )
+            .filter(import_report::Column::Input.is_not_null())
+            .filter(
+                import_report::Column::StartedOn
+                    .lt(Utc::now() - Duration::days(IMPORT_INPUT_RETENTION_DAYS)),
+            )
+            .exec(&self.media_service.db)
+            .await?;
         Ok(())
     }
 
@@ -236,61 +649,548 @@ impl ImporterService {
     pub async fn import_from_lot(&self, user_id: i32, input: DeployImportJobInput) -> Result<()> {
         match input.lot {
             ImportLot::Media => self.import_media(user_id, input).await,
-            ImportLot::Exercise => todo!(),
+            ImportLot::Exercise => self.import_exercise(user_id, input).await,
+        }
+    }
+
+    #[instrument(skip(self, input))]
+    async fn import_exercise(&self, user_id: i32, input: DeployImportJobInput) -> Result<()> {
+        if !matches!(
+            input.source,
+            ImportSource::GarminConnect | ImportSource::Ryot
+        ) {
+            return Err(Error::new("Source can not import exercises"));
+        }
+        let db_import_job = self.start_import_job(user_id, input.source, None).await?;
+        let job_id = db_import_job.id;
+        let result = AssertUnwindSafe(self.import_exercise_items(user_id, db_import_job, input))
+            .catch_unwind()
+            .await
+            .unwrap_or_else(|_| Err(Error::new("The import stopped unexpectedly")));
+        if let Err(e) = &result {
+            self.fail_import_job(job_id, e).await?;
+        }
+        result
+    }
+
+    async fn import_exercise_items(
+        &self,
+        user_id: i32,
+        db_import_job: import_report::Model,
+        input: DeployImportJobInput,
+    ) -> Result<()> {
+        let import = match (input.source, input.garmin_connect, input.ryot) {
+            (ImportSource::GarminConnect, Some(i), _) => garmin_connect::import(i).await?,
+            (ImportSource::Ryot, _, Some(i)) => ryot::import_exercise(i).await?,
+            _ => return Err(Error::new("The input for the source is missing")),
+        };
+        let user = user_by_id(&self.media_service.db, user_id).await?;
+        let mut progress = ImportProgress {
+            report_id: db_import_job.id,
+            total: import.workouts.len() + import.measurements.len(),
+            ..Default::default()
+        };
+        self.send_import_progress(user_id, &progress);
+        let mut total = 0;
+        for workout in import.workouts {
+            tracing::debug!(
+                "Importing workout with identifier = {iden}",
+                iden = workout.identifier
+            );
+            let identifier = workout.identifier.clone();
+            let created = self
+                .exercise_service
+                .create_external_workout(&user, workout)
+                .await?;
+            if created {
+                total += 1;
+            }
+            progress.record(&identifier, 1, created);
+            self.send_import_progress(user_id, &progress);
+        }
+        for measurement in import.measurements {
+            let timestamp = measurement.timestamp.to_string();
+            // DEV: Measurements are keyed by timestamp, so ones that were already
+            // imported will fail to be inserted again
+            let created = self
+                .exercise_service
+                .create_user_measurement(user_id, measurement)
+                .await
+                .is_ok();
+            if created {
+                total += 1;
+            }
+            progress.record(&timestamp, 1, created);
+            self.send_import_progress(user_id, &progress);
         }
+        tracing::debug!(
+            "Imported {total} fitness items from {source}",
+            source = db_import_job.source
+        );
+        let details = ImportResultResponse {
+            import: ImportDetails { total },
+            failed_items: vec![],
+            preview: None,
+        };
+        self.finish_import_job(db_import_job, details).await?;
+        progress.finished = true;
+        self.send_import_progress(user_id, &progress);
+        Ok(())
     }
 
     #[instrument(skip(self, input))]
     async fn import_media(&self, user_id: i32, input: DeployImportJobInput) -> Result<()> {
-        let db_import_job = self.start_import_job(user_id, input.source).await?;
-        let mut import = match input.source {
+        let db_import_job = self
+            .start_import_job(user_id, input.source, (!input.dry_run).then_some(&input))
+            .await?;
+        self.run_media_import(user_id, db_import_job, input).await
+    }
+
+    #[instrument(skip(self))]
+    pub async fn resume_media_import(&self, user_id: i32, report_id: i32) -> Result<()> {
+        let db_import_job = ImportReport::find_by_id(report_id)
+            .one(&self.media_service.db)
+            .await?
+            .ok_or_else(|| Error::new("Import report does not exist"))?;
+        let input = match db_import_job.input.clone() {
+            Some(i) => serde_json::from_value::<DeployImportJobInput>(i)?,
+            None => return Err(Error::new("The input of the import was not saved")),
+        };
+        tracing::debug!(
+            "Resuming import job with id = {id} from {checkpoint:?}",
+            id = db_import_job.id,
+            checkpoint = db_import_job.checkpoint
+        );
+        let mut model: import_report::ActiveModel = db_import_job.into();
+        model.finished_on = ActiveValue::Set(None);
+        model.success = ActiveValue::Set(None);
+        let db_import_job = model.update(&self.media_service.db).await?;
+        self.run_media_import(user_id, db_import_job, input).await
+    }
+
+    /// Import the items of a media import that failed again. Items whose media
+    /// could not be found are imported as a whole, and for the others only the
+    /// entries that could not be saved are imported again.
+    #[instrument(skip(self, overrides))]
+    pub async fn retry_failed_import_items(
+        &self,
+        user_id: i32,
+        report_id: i32,
+        overrides: Vec<ImportFailedItemOverride>,
+    ) -> Result<()> {
+        let db_import_job = ImportReport::find_by_id(report_id)
+            .one(&self.media_service.db)
+            .await?
+            .ok_or_else(|| Error::new("Import report does not exist"))?;
+        let (input, previous) = match (db_import_job.input.clone(), db_import_job.details.clone()) {
+            (Some(i), Some(d)) => (serde_json::from_value::<DeployImportJobInput>(i)?, d),
+            _ => return Err(Error::new("The import can not be retried")),
+        };
+        let conflict_policy = input.conflict_policy.unwrap_or_default();
+        let mut import = self.fetch_media_import(input).await?;
+        let mut retried = vec![];
+        let mut positions = HashMap::new();
+        import.media = import
+            .media
+            .into_iter()
+            .filter_map(|mut item| {
+                let failed = previous
+                    .failed_items
+                    .iter()
+                    .filter(|f| f.lot == item.lot && f.identifier == item.source_id)
+                    .collect_vec();
+                if !failed
+                    .iter()
+                    .any(|f| f.step == ImportFailStep::MediaDetailsFromProvider)
+                {
+                    let mut retain = |step, len| {
+                        let kept = failed_positions(&failed, step, len);
+                        positions.insert((item.lot, item.source_id.clone(), step), kept.clone());
+                        kept
+                    };
+                    let kept = retain(
+                        ImportFailStep::SeenHistoryConversion,
+                        item.seen_history.len(),
+                    );
+                    item.seen_history = retain_positions(item.seen_history, &kept);
+                    let kept = retain(ImportFailStep::ReviewConversion, item.reviews.len());
+                    item.reviews = retain_positions(item.reviews, &kept);
+                    let kept = retain(ImportFailStep::HighlightConversion, item.highlights.len());
+                    item.highlights = retain_positions(item.highlights, &kept);
+                    item.collections.clear();
+                    if item.seen_history.is_empty()
+                        && item.reviews.is_empty()
+                        && item.highlights.is_empty()
+                    {
+                        return None;
+                    }
+                }
+                let item_override = overrides
+                    .iter()
+                    .find(|o| o.lot == item.lot && o.identifier == item.source_id);
+                if let Some(o) = item_override {
+                    item.identifier =
+                        ImportOrExportItemIdentifier::NeedsDetails(o.provider_identifier.clone());
+                }
+                retried.push((item.lot, item.source_id.clone()));
+                Some(item)
+            })
+            .collect_vec();
+        // DEV: The collections and the items that the source itself could not
+        // handle were dealt with when the import was first run
+        import.collections = vec![];
+        import.failed_items = vec![];
+        tracing::debug!(
+            "Retrying {count} failed items of import job with id = {id}",
+            count = import.media.len(),
+            id = db_import_job.id
+        );
+        let mut model: import_report::ActiveModel = db_import_job.clone().into();
+        model.finished_on = ActiveValue::Set(None);
+        model.success = ActiveValue::Set(None);
+        let running_import_job = model.update(&self.media_service.db).await?;
+        let result = self
+            .import_media_result(user_id, &running_import_job, import, conflict_policy, false)
+            .await;
+        let (mut details, progress) = match result {
+            Ok(r) => r,
+            Err(e) => {
+                // DEV: The report is left the way it was before the retry
+                let mut model: import_report::ActiveModel = running_import_job.into();
+                model.finished_on = ActiveValue::Set(db_import_job.finished_on);
+                model.success = ActiveValue::Set(db_import_job.success);
+                model.update(&self.media_service.db).await?;
+                return Err(e);
+            }
+        };
+        // DEV: The entries that were retried are at different positions than
+        // in the source, which is what the report has to refer to
+        for failed in details.failed_items.iter_mut() {
+            if let (Some(idx), Some(kept)) = (
+                failed.index,
+                positions.get(&(failed.lot, failed.identifier.clone(), failed.step)),
+            ) {
+                failed.index = kept.get(idx).copied();
+            }
+        }
+        let mut failed_items = previous
+            .failed_items
+            .into_iter()
+            .filter(|f| !retried.contains(&(f.lot, f.identifier.clone())))
+            .collect_vec();
+        failed_items.extend(details.failed_items);
+        details.failed_items = failed_items;
+        details.import.total = previous.import.total;
+        self.finish_media_import(user_id, running_import_job, details, progress)
+            .await
+    }
+
+    /// Run a media import, marking its report as failed if it does not finish, or
+    /// panics, so that it can be resumed.
+    async fn run_media_import(
+        &self,
+        user_id: i32,
+        db_import_job: import_report::Model,
+        input: DeployImportJobInput,
+    ) -> Result<()> {
+        let job_id = db_import_job.id;
+        // DEV: A panic would otherwise leave the report running forever
+        let result = AssertUnwindSafe(self.import_media_items(user_id, db_import_job, input))
+            .catch_unwind()
+            .await
+            .unwrap_or_else(|_| Err(Error::new("The import stopped unexpectedly")));
+        if let Err(e) = &result {
+            self.fail_import_job(job_id, e).await?;
+        }
+        result
+    }
+
+    async fn import_media_items(
+        &self,
+        user_id: i32,
+        db_import_job: import_report::Model,
+        input: DeployImportJobInput,
+    ) -> Result<()> {
+        let conflict_policy = input.conflict_policy.unwrap_or_default();
+        let dry_run = input.dry_run;
+        let import = self.fetch_media_import(input).await?;
+        if dry_run {
+            return self
+                .preview_media_import(user_id, db_import_job, import, conflict_policy)
+                .await;
+        }
+        let (details, progress) = self
+            .import_media_result(user_id, &db_import_job, import, conflict_policy, true)
+            .await?;
+        self.finish_media_import(user_id, db_import_job, details, progress)
+            .await
+    }
+
+    /// Get the media items of an import from its source.
+    async fn fetch_media_import(&self, input: DeployImportJobInput) -> Result<ImportResult> {
+        let import = match input.source {
             ImportSource::MediaTracker => {
                 media_tracker::import(input.media_tracker.unwrap()).await?
             }
             ImportSource::MediaJson => media_json::import(input.media_json.unwrap()).await?,
-            ImportSource::Mal => mal::import(input.mal.unwrap()).await?,
+            ImportSource::Mal => {
+                // DEV: The ids are mapped to Anilist for the types of media that
+                // MAL is not configured for
+                let mut fallbacks = vec![];
+                for lot in [MetadataLot::Anime, MetadataLot::Manga] {
+                    let configured = self
+                        .media_service
+                        .config
+                        .missing_provider_settings(lot, MetadataSource::Mal)
+                        .is_empty();
+                    fallbacks.push(match configured {
+                        true => None,
+                        false => Some(
+                            self.media_service
+                                .get_provider(lot, MetadataSource::Anilist, None)
+                                .await?,
+                        ),
+                    });
+                }
+                mal::import(
+                    input.mal.unwrap(),
+                    fallbacks[0].as_deref(),
+                    fallbacks[1].as_deref(),
+                )
+                .await?
+            }
             ImportSource::Goodreads => goodreads::import(input.goodreads.unwrap()).await?,
-            ImportSource::Trakt => trakt::import(input.trakt.unwrap()).await?,
+            ImportSource::GoodreadsCsv => {
+                let openlibrary = self
+                    .media_service
+                    .get_provider(MetadataLot::Book, MetadataSource::Openlibrary, None)
+                    .await?;
+                let google_books = self
+                    .media_service
+                    .get_provider(MetadataLot::Book, MetadataSource::GoogleBooks, None)
+                    .await?;
+                goodreads_csv::import(input.goodreads_csv.unwrap(), &*openlibrary, &*google_books)
+                    .await?
+            }
+            ImportSource::Trakt => {
+                let movie_provider = self
+                    .media_service
+                    .get_provider(MetadataLot::Movie, MetadataSource::Tmdb, None)
+                    .await?;
+                let show_provider = self
+                    .media_service
+                    .get_provider(MetadataLot::Show, MetadataSource::Tmdb, None)
+                    .await?;
+                trakt::import(input.trakt.unwrap(), &*movie_provider, &*show_provider).await?
+            }
             ImportSource::Movary => movary::import(input.movary.unwrap()).await?,
+            ImportSource::Letterboxd => {
+                let provider = self
+                    .media_service
+                    .get_provider(MetadataLot::Movie, MetadataSource::Tmdb, None)
+                    .await?;
+                letterboxd::import(input.letterboxd.unwrap(), &*provider).await?
+            }
+            ImportSource::TvTime => {
+                let provider = self
+                    .media_service
+                    .get_provider(MetadataLot::Show, MetadataSource::Tmdb, None)
+                    .await?;
+                tv_time::import(input.tv_time.unwrap(), &*provider).await?
+            }
+            ImportSource::GenericCsv => {
+                let input = input.generic_csv.unwrap();
+                let source = self
+                    .media_service
+                    .media_sources_for_lot(input.lot)
+                    .await
+                    .remove(0);
+                let provider = self
+                    .media_service
+                    .get_provider(input.lot, source, None)
+                    .await?;
+                generic_csv::import(input, source, &*provider).await?
+            }
+            ImportSource::Ryot => ryot::import(input.ryot.unwrap()).await?,
             ImportSource::StoryGraph => {
-                story_graph::import(
-                    input.story_graph.unwrap(),
-                    &self.media_service.get_openlibrary_service().await?,
-                )
-                .await?
+                let provider = self
+                    .media_service
+                    .get_provider(MetadataLot::Book, MetadataSource::Openlibrary, None)
+                    .await?;
+                story_graph::import(input.story_graph.unwrap(), &*provider).await?
+            }
+            ImportSource::Kindle => {
+                let provider = self
+                    .media_service
+                    .get_provider(MetadataLot::Book, MetadataSource::Openlibrary, None)
+                    .await?;
+                kindle::import(input.kindle.unwrap(), &*provider).await?
+            }
+            ImportSource::Playstation => {
+                let provider = self
+                    .media_service
+                    .get_provider(MetadataLot::VideoGame, MetadataSource::Igdb, None)
+                    .await?;
+                playstation::import(input.playstation.unwrap(), &*provider).await?
             }
+            ImportSource::Xbox => {
+                let provider = self
+                    .media_service
+                    .get_provider(MetadataLot::VideoGame, MetadataSource::Igdb, None)
+                    .await?;
+                xbox::import(input.xbox.unwrap(), &*provider).await?
+            }
+            _ => return Err(Error::new("Source can not import media")),
         };
+        Ok(import)
+    }
+
+    /// Import the media items that were fetched from the source of an import, and
+    /// return the details that should be saved in its report. The import is
+    /// resumed from the checkpoint of the report if it has one.
+    async fn import_media_result(
+        &self,
+        user_id: i32,
+        db_import_job: &import_report::Model,
+        mut import: ImportResult,
+        conflict_policy: ImportConflictPolicy,
+        save_checkpoints: bool,
+    ) -> Result<(ImportResultResponse, ImportProgress)> {
         let preferences = user_by_id(&self.media_service.db, user_id)
             .await?
             .preferences;
+        // DEV: The order has to be the same every time the import is run for the
+        // checkpoint to point at the same item when it is resumed
+        let weight = |m: &ImportOrExportMediaItem<ImportOrExportItemIdentifier>| {
+            m.seen_history.len() + m.reviews.len() + m.collections.len() + m.highlights.len()
+        };
         import.media = import
             .media
             .into_iter()
-            .sorted_unstable_by_key(|m| {
-                m.seen_history.len() + m.reviews.len() + m.collections.len()
+            .sorted_by(|a, b| {
+                weight(b)
+                    .cmp(&weight(a))
+                    .then_with(|| a.lot.to_string().cmp(&b.lot.to_string()))
+                    .then_with(|| a.source_id.cmp(&b.source_id))
             })
-            .rev()
             .collect_vec();
+        let resume_from = match &db_import_job.checkpoint {
+            Some(checkpoint) => resume_position(&import.media, checkpoint)?,
+            None => 0,
+        };
+        // DEV: The items that failed before the import was resumed are kept, the
+        // ones from the source itself are found again
+        let resumed_details = db_import_job
+            .details
+            .as_ref()
+            .filter(|_| db_import_job.checkpoint.is_some());
+        if let Some(details) = resumed_details {
+            for item in details.failed_items.iter() {
+                if !import.failed_items.contains(item) {
+                    import.failed_items.push(item.clone());
+                }
+            }
+        }
+        let mut saved_failed_items = import.failed_items.len();
         for col_details in import.collections.into_iter() {
             self.media_service
                 .create_or_update_collection(user_id, col_details)
                 .await?;
         }
-        for (idx, item) in import.media.iter().enumerate() {
+        let mut progress = ImportProgress {
+            report_id: db_import_job.id,
+            total: import.media.len() - resume_from,
+            ..Default::default()
+        };
+        self.send_import_progress(user_id, &progress);
+        // DEV: Several items of an import can resolve to the same media, and the
+        // policy must not remove the data that an earlier one just imported
+        let mut conflict_decisions = HashMap::new();
+        let to_commit = import
+            .media
+            .iter()
+            .skip(resume_from)
+            .filter_map(|item| match &item.identifier {
+                ImportOrExportItemIdentifier::NeedsDetails(i) => Some((
+                    item.lot,
+                    item.source,
+                    i.clone(),
+                    preferences.languages.for_source(item.source),
+                )),
+                ImportOrExportItemIdentifier::AlreadyFilled(_) => None,
+            })
+            .collect_vec();
+        // DEV: An item is only committed once, but it counts for every time that
+        // it is present in the import
+        let counts = to_commit
+            .iter()
+            .map(|(lot, source, identifier, _)| (*lot, *source, identifier.clone()))
+            .counts();
+        let committed = self
+            .media_service
+            .commit_media_bulk(to_commit, |key, matched| {
+                progress.record(&key.2, counts[key], matched);
+                self.send_import_progress(user_id, &progress);
+            })
+            .await;
+        for (idx, item) in import.media.iter().enumerate().skip(resume_from) {
+            // DEV: The checkpoint is saved before an item is imported since the
+            // previous one could have been skipped at any point
+            if save_checkpoints && idx > resume_from {
+                let failed_items = (import.failed_items.len() != saved_failed_items).then(|| {
+                    saved_failed_items = import.failed_items.len();
+                    ImportResultResponse {
+                        import: ImportDetails {
+                            total: import.media.len(),
+                        },
+                        failed_items: import.failed_items.clone(),
+                        preview: None,
+                    }
+                });
+                let checkpoint = ImportCheckpoint {
+                    processed: idx,
+                    last_item: import.media[idx - 1].source_id.clone(),
+                    saved_on: Some(Utc::now()),
+                };
+                self.save_import_checkpoint(db_import_job.id, checkpoint, failed_items)
+                    .await?;
+            }
             tracing::debug!(
                 "Importing media with identifier = {iden}",
                 iden = item.source_id
             );
             let data = match &item.identifier {
                 ImportOrExportItemIdentifier::NeedsDetails(i) => {
-                    self.media_service
-                        .commit_media(item.lot, item.source, i)
-                        .await
+                    committed[&(item.lot, item.source, i.clone())].clone()
                 }
                 ImportOrExportItemIdentifier::AlreadyFilled(a) => {
-                    self.media_service.commit_media_internal(*a.clone()).await
+                    self.media_service
+                        .commit_media_internal(*a.clone(), None)
+                        .await
                 }
             };
+            let found = data.is_ok();
+            // DEV: If the provider could not find the item, it might already be in
+            // the database under one of its other names
+            let data = match (data, media_title(item)) {
+                (Err(e), Some(title)) => self
+                    .media_service
+                    .media_exists_with_title(item.lot, title)
+                    .await
+                    .ok()
+                    .flatten()
+                    .ok_or(e),
+                (d, _) => d,
+            };
+            if let ImportOrExportItemIdentifier::AlreadyFilled(_) = item.identifier {
+                progress.record(&item.source_id, 1, data.is_ok());
+                self.send_import_progress(user_id, &progress);
+            } else if !found && data.is_ok() {
+                // DEV: It was counted as unmatched when the provider failed
+                progress.unmatched -= 1;
+                progress.matched += 1;
+                self.send_import_progress(user_id, &progress);
+            }
             let metadata = match data {
                 Ok(r) => r,
                 Err(e) => {
@@ -298,13 +1198,49 @@ impl ImporterService {
                     import.failed_items.push(ImportFailedItem {
                         lot: item.lot,
                         step: ImportFailStep::MediaDetailsFromProvider,
+                        index: None,
                         identifier: item.source_id.to_owned(),
                         error: Some(e.message),
                     });
                     continue;
                 }
             };
-            for seen in item.seen_history.iter() {
+            let import_seen_history = !item.seen_history.is_empty()
+                && self
+                    .apply_conflict_policy(
+                        conflict_policy,
+                        user_id,
+                        metadata.id,
+                        ImportConflictData::SeenHistory,
+                        &mut conflict_decisions,
+                    )
+                    .await?;
+            let import_reviews = !item.reviews.is_empty()
+                && self
+                    .apply_conflict_policy(
+                        conflict_policy,
+                        user_id,
+                        metadata.id,
+                        ImportConflictData::Reviews,
+                        &mut conflict_decisions,
+                    )
+                    .await?;
+            let import_highlights = !item.highlights.is_empty()
+                && self
+                    .apply_conflict_policy(
+                        conflict_policy,
+                        user_id,
+                        metadata.id,
+                        ImportConflictData::Highlights,
+                        &mut conflict_decisions,
+                    )
+                    .await?;
+            for (idx, seen) in item
+                .seen_history
+                .iter()
+                .enumerate()
+                .filter(|_| import_seen_history)
+            {
                 let progress = if seen.progress.is_some() {
                     seen.progress
                 } else {
@@ -321,21 +1257,57 @@ impl ImporterService {
                             show_episode_number: seen.show_episode_number,
                             podcast_episode_number: seen.podcast_episode_number,
                             change_state: None,
+                            context: None,
                         },
                         user_id,
                     )
                     .await
                 {
                     Ok(_) => {}
-                    Err(e) => import.failed_items.push(ImportFailedItem {
+                    Err(e) => {
+                        import.failed_items.push(ImportFailedItem {
+                            lot: item.lot,
+                            step: ImportFailStep::SeenHistoryConversion,
+                            index: Some(idx),
+                            identifier: item.source_id.to_owned(),
+                            error: Some(e.message),
+                        });
+                        continue;
+                    }
+                };
+                // DEV: Media that was not finished is left in progress unless
+                // the source says otherwise
+                let state = match seen.state {
+                    Some(s @ (SeenState::Dropped | SeenState::OnAHold)) => s,
+                    _ => continue,
+                };
+                if let Err(e) = self
+                    .media_service
+                    .progress_update(
+                        ProgressUpdateInput {
+                            metadata_id: metadata.id,
+                            progress: None,
+                            date: None,
+                            show_season_number: None,
+                            show_episode_number: None,
+                            podcast_episode_number: None,
+                            change_state: Some(state),
+                            context: None,
+                        },
+                        user_id,
+                    )
+                    .await
+                {
+                    import.failed_items.push(ImportFailedItem {
                         lot: item.lot,
                         step: ImportFailStep::SeenHistoryConversion,
+                        index: Some(idx),
                         identifier: item.source_id.to_owned(),
                         error: Some(e.message),
-                    }),
-                };
+                    });
+                }
             }
-            for review in item.reviews.iter() {
+            for (idx, review) in item.reviews.iter().enumerate().filter(|_| import_reviews) {
                 if review.review.is_none() && review.rating.is_none() {
                     tracing::debug!("Skipping review since it has no content");
                     continue;
@@ -371,6 +1343,38 @@ impl ImporterService {
                     Err(e) => import.failed_items.push(ImportFailedItem {
                         lot: item.lot,
                         step: ImportFailStep::ReviewConversion,
+                        index: Some(idx),
+                        identifier: item.source_id.to_owned(),
+                        error: Some(e.message),
+                    }),
+                };
+            }
+            for (idx, highlight) in item
+                .highlights
+                .iter()
+                .enumerate()
+                .filter(|_| import_highlights)
+            {
+                match self
+                    .media_service
+                    .create_or_update_highlight(
+                        user_id,
+                        CreateOrUpdateHighlightInput {
+                            metadata_id: metadata.id,
+                            text: highlight.text.clone(),
+                            page: highlight.page,
+                            chapter: highlight.chapter.clone(),
+                            note: highlight.note.clone(),
+                            ..Default::default()
+                        },
+                    )
+                    .await
+                {
+                    Ok(_) => {}
+                    Err(e) => import.failed_items.push(ImportFailedItem {
+                        lot: item.lot,
+                        step: ImportFailStep::HighlightConversion,
+                        index: Some(idx),
                         identifier: item.source_id.to_owned(),
                         error: Some(e.message),
                     }),
@@ -398,15 +1402,20 @@ impl ImporterService {
                     .ok();
             }
             tracing::debug!(
-                "Imported item: {idx}/{total}, lot: {lot}, history count: {hist}, review count: {rev}, collection count: {col}",
+                "Imported item: {idx}/{total}, lot: {lot}, history count: {hist}, review count: {rev}, collection count: {col}, highlight count: {hig}",
                 idx = idx + 1,
                 total = import.media.len(),
                 lot = item.lot,
                 hist = item.seen_history.len(),
                 rev = item.reviews.len(),
                 col = item.collections.len(),
+                hig = item.highlights.len(),
             );
         }
+        self.media_service
+            .detect_duplicate_seen_history_for_user(user_id)
+            .await
+            .ok();
         self.media_service
             .deploy_recalculate_summary_job(user_id)
             .await
@@ -421,19 +1430,311 @@ impl ImporterService {
                 total: import.media.len(),
             },
             failed_items: import.failed_items,
+            preview: None,
         };
+        Ok((details, progress))
+    }
+
+    async fn finish_media_import(
+        &self,
+        user_id: i32,
+        db_import_job: import_report::Model,
+        details: ImportResultResponse,
+        mut progress: ImportProgress,
+    ) -> Result<()> {
         self.finish_import_job(db_import_job, details).await?;
+        progress.finished = true;
+        self.send_import_progress(user_id, &progress);
         Ok(())
     }
 
+    /// Find the media items of an import and save what importing them would do in
+    /// the report, without writing anything else.
+    async fn preview_media_import(
+        &self,
+        user_id: i32,
+        db_import_job: import_report::Model,
+        mut import: ImportResult,
+        conflict_policy: ImportConflictPolicy,
+    ) -> Result<()> {
+        let preferences = user_by_id(&self.media_service.db, user_id)
+            .await?
+            .preferences;
+        let mut progress = ImportProgress {
+            report_id: db_import_job.id,
+            total: import.media.len(),
+            ..Default::default()
+        };
+        self.send_import_progress(user_id, &progress);
+        let to_match = import
+            .media
+            .iter()
+            .filter_map(|item| match &item.identifier {
+                ImportOrExportItemIdentifier::NeedsDetails(i) => Some((
+                    item.lot,
+                    item.source,
+                    i.clone(),
+                    preferences.languages.for_source(item.source),
+                )),
+                ImportOrExportItemIdentifier::AlreadyFilled(_) => None,
+            })
+            .collect_vec();
+        let counts = to_match
+            .iter()
+            .map(|(lot, source, identifier, _)| (*lot, *source, identifier.clone()))
+            .counts();
+        let matched = self
+            .media_service
+            .match_media_bulk(to_match, |key, matched| {
+                progress.record(&key.2, counts[key], matched);
+                self.send_import_progress(user_id, &progress);
+            })
+            .await;
+        let mut preview = ImportPreview::default();
+        for item in import.media.iter() {
+            let data = match &item.identifier {
+                ImportOrExportItemIdentifier::NeedsDetails(i) => {
+                    matched[&(item.lot, item.source, i.clone())].clone()
+                }
+                ImportOrExportItemIdentifier::AlreadyFilled(a) => {
+                    self.media_service
+                        .media_exists_in_database(a.lot, a.source, &a.identifier)
+                        .await
+                }
+            };
+            let found = data.is_ok();
+            let data = match (data, media_title(item)) {
+                (Err(e), Some(title)) => self
+                    .media_service
+                    .media_exists_with_title(item.lot, title)
+                    .await
+                    .ok()
+                    .flatten()
+                    .map(Some)
+                    .ok_or(e),
+                (d, _) => d,
+            };
+            if let ImportOrExportItemIdentifier::AlreadyFilled(_) = item.identifier {
+                progress.record(&item.source_id, 1, data.is_ok());
+                self.send_import_progress(user_id, &progress);
+            } else if !found && data.is_ok() {
+                progress.unmatched -= 1;
+                progress.matched += 1;
+                self.send_import_progress(user_id, &progress);
+            }
+            let mut preview_item = ImportPreviewItem {
+                lot: item.lot,
+                source: item.source,
+                identifier: item.source_id.clone(),
+                is_new: false,
+            };
+            let metadata_id = match data {
+                Ok(m) => m.map(|m| m.id),
+                Err(e) => {
+                    import.failed_items.push(ImportFailedItem {
+                        lot: item.lot,
+                        step: ImportFailStep::MediaDetailsFromProvider,
+                        index: None,
+                        identifier: item.source_id.to_owned(),
+                        error: Some(e.message),
+                    });
+                    preview.unmatched.push(preview_item);
+                    continue;
+                }
+            };
+            preview_item.is_new = metadata_id.is_none();
+            preview.matched.push(preview_item);
+            // DEV: Only skipping changes what is added, overwriting removes the
+            // existing data before adding all of it
+            let skipped = |data| async move {
+                match metadata_id {
+                    Some(id) if conflict_policy == ImportConflictPolicy::Skip => self
+                        .existing_data_count(user_id, id, data)
+                        .await
+                        .map(|c| c > 0),
+                    _ => Ok(false),
+                }
+            };
+            if !skipped(ImportConflictData::SeenHistory).await? {
+                preview.seen_history += item.seen_history.len();
+            }
+            if !skipped(ImportConflictData::Reviews).await? {
+                preview.reviews += item
+                    .reviews
+                    .iter()
+                    .filter(|r| r.review.is_some() || r.rating.is_some())
+                    .count();
+            }
+            if !skipped(ImportConflictData::Highlights).await? {
+                preview.highlights += item.highlights.len();
+            }
+        }
+        let existing_collections = Collection::find()
+            .filter(collection::Column::UserId.eq(user_id))
+            .all(&self.media_service.db)
+            .await?
+            .into_iter()
+            .map(|c| c.name)
+            .collect_vec();
+        preview.collections = import
+            .collections
+            .iter()
+            .map(|c| c.name.clone())
+            .chain(
+                import
+                    .media
+                    .iter()
+                    .flat_map(|m| m.collections.iter().map(|c| c.to_string())),
+            )
+            .unique()
+            .filter(|c| !existing_collections.contains(c))
+            .collect();
+        tracing::debug!(
+            "Previewed {total} media items from {source}",
+            total = import.media.len(),
+            source = db_import_job.source
+        );
+        let details = ImportResultResponse {
+            import: ImportDetails {
+                total: import.media.len(),
+            },
+            failed_items: import.failed_items,
+            preview: Some(preview),
+        };
+        self.finish_import_job(db_import_job, details).await?;
+        progress.finished = true;
+        self.send_import_progress(user_id, &progress);
+        Ok(())
+    }
+
+    /// The number of items of a kind of data that the user has for a media item.
+    async fn existing_data_count(
+        &self,
+        user_id: i32,
+        metadata_id: i32,
+        data: ImportConflictData,
+    ) -> Result<u64> {
+        let db = &self.media_service.db;
+        let count = match data {
+            ImportConflictData::SeenHistory => {
+                Seen::find()
+                    .filter(seen::Column::UserId.eq(user_id))
+                    .filter(seen::Column::MetadataId.eq(metadata_id))
+                    .count(db)
+                    .await?
+            }
+            ImportConflictData::Reviews => {
+                Review::find()
+                    .filter(review::Column::UserId.eq(user_id))
+                    .filter(review::Column::MetadataId.eq(metadata_id))
+                    .count(db)
+                    .await?
+            }
+            ImportConflictData::Highlights => {
+                Highlight::find()
+                    .filter(highlight::Column::UserId.eq(user_id))
+                    .filter(highlight::Column::MetadataId.eq(metadata_id))
+                    .count(db)
+                    .await?
+            }
+        };
+        Ok(count)
+    }
+
+    /// Handle the existing data of a kind for a media item according to the
+    /// conflict policy. Returns whether the imported data should be added.
+    ///
+    /// The policy is applied once per media item in an import, and the items
+    /// after that reuse the decision that was made for the first one.
+    async fn apply_conflict_policy(
+        &self,
+        policy: ImportConflictPolicy,
+        user_id: i32,
+        metadata_id: i32,
+        data: ImportConflictData,
+        decisions: &mut HashMap<(i32, ImportConflictData), bool>,
+    ) -> Result<bool> {
+        if policy == ImportConflictPolicy::Append {
+            return Ok(true);
+        }
+        if let Some(decision) = decisions.get(&(metadata_id, data)) {
+            return Ok(*decision);
+        }
+        let decision = self
+            .resolve_conflict_policy(policy, user_id, metadata_id, data)
+            .await?;
+        decisions.insert((metadata_id, data), decision);
+        Ok(decision)
+    }
+
+    async fn resolve_conflict_policy(
+        &self,
+        policy: ImportConflictPolicy,
+        user_id: i32,
+        metadata_id: i32,
+        data: ImportConflictData,
+    ) -> Result<bool> {
+        let existing = self.existing_data_count(user_id, metadata_id, data).await?;
+        if existing == 0 {
+            return Ok(true);
+        }
+        if policy == ImportConflictPolicy::Skip {
+            tracing::debug!("Skipping {data:?} of metadata = {metadata_id} since it exists");
+            return Ok(false);
+        }
+        tracing::debug!("Overwriting {data:?} of metadata = {metadata_id}");
+        let db = &self.media_service.db;
+        match data {
+            // DEV: Deleted one at a time so that they are removed from the
+            // statistics of the user
+            ImportConflictData::SeenHistory => {
+                let seen_items = Seen::find()
+                    .filter(seen::Column::UserId.eq(user_id))
+                    .filter(seen::Column::MetadataId.eq(metadata_id))
+                    .all(db)
+                    .await?;
+                for seen in seen_items {
+                    seen.delete(db).await?;
+                }
+            }
+            ImportConflictData::Reviews => {
+                let reviews = Review::find()
+                    .filter(review::Column::UserId.eq(user_id))
+                    .filter(review::Column::MetadataId.eq(metadata_id))
+                    .all(db)
+                    .await?;
+                for review in reviews {
+                    review.delete(db).await?;
+                }
+            }
+            ImportConflictData::Highlights => {
+                Highlight::delete_many()
+                    .filter(highlight::Column::UserId.eq(user_id))
+                    .filter(highlight::Column::MetadataId.eq(metadata_id))
+                    .exec(db)
+                    .await?;
+            }
+        }
+        Ok(true)
+    }
+
+    fn send_import_progress(&self, user_id: i32, progress: &ImportProgress) {
+        // DEV: Sending fails when nobody is subscribed, which is not a problem
+        self.import_progress_updates
+            .send((user_id, progress.clone()))
+            .ok();
+    }
+
     async fn start_import_job(
         &self,
         user_id: i32,
         source: ImportSource,
+        input: Option<&DeployImportJobInput>,
     ) -> Result<import_report::Model> {
         let model = import_report::ActiveModel {
             user_id: ActiveValue::Set(user_id),
             source: ActiveValue::Set(source),
+            input: ActiveValue::Set(input.map(serde_json::to_value).transpose()?),
             ..Default::default()
         };
         let model = model.insert(&self.media_service.db).await.unwrap();
@@ -448,9 +1749,105 @@ impl ImporterService {
     ) -> Result<import_report::Model> {
         let mut model: import_report::ActiveModel = job.into();
         model.finished_on = ActiveValue::Set(Some(Utc::now()));
+        // DEV: The input can contain credentials, so it is only kept while the
+        // items that failed can still be retried
+        if details.failed_items.is_empty() {
+            model.input = ActiveValue::Set(None);
+        }
         model.details = ActiveValue::Set(Some(details));
         model.success = ActiveValue::Set(Some(true));
+        model.checkpoint = ActiveValue::Set(None);
         let model = model.update(&self.media_service.db).await.unwrap();
         Ok(model)
     }
+
+    async fn fail_import_job(&self, job_id: i32, error: &Error) -> Result<()> {
+        tracing::error!("Import job with id = {job_id} failed: {error:?}");
+        let model = import_report::ActiveModel {
+            id: ActiveValue::Unchanged(job_id),
+            finished_on: ActiveValue::Set(Some(Utc::now())),
+            success: ActiveValue::Set(Some(false)),
+            ..Default::default()
+        };
+        model.update(&self.media_service.db).await?;
+        Ok(())
+    }
+
+    async fn save_import_checkpoint(
+        &self,
+        job_id: i32,
+        checkpoint: ImportCheckpoint,
+        details: Option<ImportResultResponse>,
+    ) -> Result<()> {
+        let mut model = import_report::ActiveModel {
+            id: ActiveValue::Unchanged(job_id),
+            checkpoint: ActiveValue::Set(Some(checkpoint)),
+            ..Default::default()
+        };
+        if let Some(details) = details {
+            model.details = ActiveValue::Set(Some(details));
+        }
+        model.update(&self.media_service.db).await?;
+        Ok(())
+    }
+}
+
+/// The position in the media items of an import that it should be resumed from.
+/// The items are looked for by their identifier in case the source returned a
+/// different number of them this time.
+fn resume_position(
+    media: &[ImportOrExportMediaItem<ImportOrExportItemIdentifier>],
+    checkpoint: &ImportCheckpoint,
+) -> Result<usize> {
+    let last_item = checkpoint
+        .processed
+        .checked_sub(1)
+        .and_then(|idx| media.get(idx));
+    if let Some(item) = last_item {
+        if item.source_id == checkpoint.last_item {
+            return Ok(checkpoint.processed);
+        }
+    }
+    media
+        .iter()
+        .position(|m| m.source_id == checkpoint.last_item)
+        .map(|idx| idx + 1)
+        .ok_or_else(|| {
+            Error::new("The last imported item is not in the source anymore, deploy a new import")
+        })
+}
+
+/// The title that the media of an item has in the source, if it is known.
+fn media_title(item: &ImportOrExportMediaItem<ImportOrExportItemIdentifier>) -> Option<&str> {
+    match &item.identifier {
+        ImportOrExportItemIdentifier::AlreadyFilled(a) => Some(&a.title),
+        ImportOrExportItemIdentifier::NeedsDetails(_) => item.title.as_deref(),
+    }
+}
+
+/// The positions of the entries of an item that could not be saved in a step,
+/// which are the ones that are imported again when it is retried. Reports from
+/// before the positions were saved only say that the step failed, so all the
+/// entries are imported again for them.
+fn failed_positions(failed: &[&ImportFailedItem], step: ImportFailStep, len: usize) -> Vec<usize> {
+    let failed = failed.iter().filter(|f| f.step == step).collect_vec();
+    if failed.iter().any(|f| f.index.is_none()) {
+        return (0..len).collect();
+    }
+    failed
+        .iter()
+        .filter_map(|f| f.index)
+        .filter(|idx| *idx < len)
+        .unique()
+        .sorted()
+        .collect()
+}
+
+fn retain_positions<T>(entries: Vec<T>, positions: &[usize]) -> Vec<T> {
+    entries
+        .into_iter()
+        .enumerate()
+        .filter(|(idx, _)| positions.contains(idx))
+        .map(|(_, e)| e)
+        .collect()
 }
@@ -55,6 +55,7 @@ pub async fn import(input: DeployMovaryImportInput) -> Result<ImportResult> {
                 failed_items.push(ImportFailedItem {
                     lot,
                     step: ImportFailStep::InputTransformation,
+                    index: None,
                     identifier: idx.to_string(),
                     error: Some(format!("Ratings file: {:#?}", e)),
                 });
@@ -62,7 +63,8 @@ pub async fn import(input: DeployMovaryImportInput) -> Result<ImportResult> {
             }
         };
         media.push(ImportOrExportMediaItem {
-            source_id: record.common.title,
+            source_id: record.common.title.clone(),
+            title: Some(record.common.title),
             lot,
             source,
             identifier: ImportOrExportItemIdentifier::NeedsDetails(
@@ -75,6 +77,7 @@ pub async fn import(input: DeployMovaryImportInput) -> Result<ImportResult> {
                 ..Default::default()
             }],
             collections: vec![],
+            highlights: vec![],
         })
     }
     let mut watchlist_reader = Reader::from_reader(input.watchlist.as_bytes());
@@ -85,6 +88,7 @@ pub async fn import(input: DeployMovaryImportInput) -> Result<ImportResult> {
                 failed_items.push(ImportFailedItem {
                     lot,
                     step: ImportFailStep::InputTransformation,
+                    index: None,
                     identifier: idx.to_string(),
                     error: Some(format!("Watchlist file: {:#?}", e)),
                 });
@@ -92,13 +96,15 @@ pub async fn import(input: DeployMovaryImportInput) -> Result<ImportResult> {
             }
         };
         media.push(ImportOrExportMediaItem {
-            source_id: record.title,
+            source_id: record.title.clone(),
+            title: Some(record.title),
             lot,
             source,
             identifier: ImportOrExportItemIdentifier::NeedsDetails(record.tmdb_id.to_string()),
             seen_history: vec![],
             reviews: vec![],
             collections: vec![DefaultCollection::Watchlist.to_string()],
+            highlights: vec![],
         })
     }
     let mut history_reader = Reader::from_reader(input.history.as_bytes());
@@ -109,6 +115,7 @@ pub async fn import(input: DeployMovaryImportInput) -> Result<ImportResult> {
                 failed_items.push(ImportFailedItem {
                     lot,
                     step: ImportFailStep::InputTransformation,
+                    index: None,
                     identifier: idx.to_string(),
                     error: Some(format!("History file: {:#?}", e)),
                 });
@@ -150,7 +157,8 @@ pub async fn import(input: DeployMovaryImportInput) -> Result<ImportResult> {
                 })
             }
             media.push(ImportOrExportMediaItem {
-                source_id: record.common.title,
+                source_id: record.common.title.clone(),
+                title: Some(record.common.title),
                 lot,
                 source,
                 identifier: ImportOrExportItemIdentifier::NeedsDetails(
@@ -159,6 +167,7 @@ pub async fn import(input: DeployMovaryImportInput) -> Result<ImportResult> {
                 seen_history: vec![seen_item],
                 reviews,
                 collections: vec![],
+                highlights: vec![],
             })
         }
     }
@@ -0,0 +1,108 @@
+// Responsible for importing the games that were played on a PlayStation, using
+// the list of played titles from the PlayStation Network.
+
+use std::collections::HashSet;
+
+use async_graphql::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    importer::{
+        DeployPlaystationImportInput, ImportFailStep, ImportFailedItem,
+        ImportOrExportItemIdentifier, ImportOrExportMediaItem, ImportResult,
+    },
+    migrator::{MetadataLot, MetadataSource},
+    models::media::{CreateOrUpdateCollectionInput, MediaSearchFilters},
+    traits::MediaProvider,
+};
+
+static COLLECTION: &str = "PlayStation";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Title {
+    name: String,
+    category: Option<String>,
+}
+
+/// The played titles can either be the response of the API or only the list of
+/// titles in it, depending on the tool that was used to download them.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Export {
+    Response { titles: Vec<Title> },
+    Titles(Vec<Title>),
+}
+
+pub async fn import(
+    input: DeployPlaystationImportInput,
+    provider: &(dyn MediaProvider + Send + Sync),
+) -> Result<ImportResult> {
+    let lot = MetadataLot::VideoGame;
+    let source = MetadataSource::Igdb;
+    let titles = match serde_json::from_str::<Export>(&input.export)? {
+        Export::Response { titles } => titles,
+        Export::Titles(titles) => titles,
+    };
+    let mut media = vec![];
+    let mut failed_items = vec![];
+    let mut identifiers = HashSet::new();
+    let total = titles.len();
+    for (idx, title) in titles.into_iter().enumerate() {
+        // DEV: Media apps like Netflix are listed along with the games
+        if title.category.as_deref() == Some("unknown") {
+            continue;
+        }
+        tracing::debug!(
+            "Getting details for {title:?} ({idx}/{total})",
+            title = title.name
+        );
+        let name = title.name.replace(['™', '®'], "");
+        let filters = MediaSearchFilters {
+            platform: Some("PlayStation".to_owned()),
+            ..Default::default()
+        };
+        let identifier = provider
+            .search(name.trim(), None, false, &filters)
+            .await
+            .ok()
+            .and_then(|r| r.items.into_iter().next())
+            .map(|i| i.identifier);
+        let identifier = match identifier {
+            Some(i) => i,
+            None => {
+                failed_items.push(ImportFailedItem {
+                    lot,
+                    step: ImportFailStep::MediaDetailsFromProvider,
+                    index: None,
+                    identifier: title.name,
+                    error: Some("Could not find this game on IGDB".to_owned()),
+                });
+                continue;
+            }
+        };
+        // DEV: The same game is listed once for every platform it was played on
+        if !identifiers.insert(identifier.clone()) {
+            continue;
+        }
+        media.push(ImportOrExportMediaItem {
+            source_id: title.name.clone(),
+            title: Some(title.name),
+            lot,
+            source,
+            identifier: ImportOrExportItemIdentifier::NeedsDetails(identifier),
+            seen_history: vec![],
+            reviews: vec![],
+            collections: vec![COLLECTION.to_owned()],
+            highlights: vec![],
+        });
+    }
+    Ok(ImportResult {
+        collections: vec![CreateOrUpdateCollectionInput {
+            name: COLLECTION.to_owned(),
+            description: Some("Games that I have played on PlayStation.".to_owned()),
+            ..Default::default()
+        }],
+        media,
+        failed_items,
+    })
+}
@@ -0,0 +1,198 @@
+// Responsible for importing from another instance of Ryot.
+
+use async_graphql::{Error, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use surf::{
+    http::headers::{AUTHORIZATION, USER_AGENT},
+    Client, Config, Url,
+};
+
+use crate::{
+    entities::user_measurement,
+    importer::{DeployRyotImportInput, ExerciseImportResult, ImportResult},
+    models::media::{
+        CreateOrUpdateCollectionInput, ImportOrExportItemIdentifier, ImportOrExportMediaItem,
+        Visibility,
+    },
+    utils::USER_AGENT_STR,
+};
+
+static LOGIN_QUERY: &str = r#"
+mutation LoginUser($input: UserInput!) {
+  loginUser(input: $input) {
+    ... on LoginError {
+      error
+    }
+    ... on LoginResponse {
+      apiKey
+    }
+  }
+}
+"#;
+
+static COLLECTIONS_QUERY: &str = r#"
+query Collections {
+  collections {
+    name
+    description
+    visibility
+  }
+}
+"#;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GraphqlResponse<T> {
+    data: T,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LoginResult {
+    api_key: Option<String>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LoginData {
+    login_user: LoginResult,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Collection {
+    name: String,
+    description: Option<String>,
+    visibility: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CollectionsData {
+    collections: Vec<Collection>,
+}
+
+fn base_url(input: &DeployRyotImportInput) -> Result<Url> {
+    Url::parse(&format!("{}/", input.url))
+        .map_err(|_| Error::new("The URL of the instance is not valid"))
+}
+
+/// Login to the other instance and return the token of the user.
+pub async fn login(input: &DeployRyotImportInput) -> Result<String> {
+    let client: Client = Config::new()
+        .add_header(USER_AGENT, USER_AGENT_STR)
+        .unwrap()
+        .set_base_url(base_url(input)?)
+        .try_into()
+        .unwrap();
+    let login: GraphqlResponse<LoginData> = client
+        .post("graphql")
+        .body_json(&json!({
+            "query": LOGIN_QUERY,
+            "variables": {
+                "input": { "username": input.username, "password": input.password }
+            }
+        }))
+        .unwrap()
+        .await
+        .map_err(|e| Error::new(e.to_string()))?
+        .body_json()
+        .await
+        .map_err(|e| Error::new(e.to_string()))?;
+    match login.data.login_user {
+        LoginResult {
+            api_key: Some(k), ..
+        } => Ok(k),
+        LoginResult { error, .. } => Err(Error::new(format!(
+            "Could not login to the instance: {}",
+            error.unwrap_or_default()
+        ))),
+    }
+}
+
+/// Return a client that is authenticated as the user, logging in if the import
+/// was deployed before the token was saved.
+async fn get_client(input: &DeployRyotImportInput) -> Result<Client> {
+    let api_key = match &input.api_key {
+        Some(k) => k.clone(),
+        None => login(input).await?,
+    };
+    let client: Client = Config::new()
+        .add_header(USER_AGENT, USER_AGENT_STR)
+        .unwrap()
+        .add_header(AUTHORIZATION, format!("Bearer {}", api_key))
+        .unwrap()
+        .set_base_url(base_url(input)?)
+        .try_into()
+        .unwrap();
+    Ok(client)
+}
+
+pub async fn import(input: DeployRyotImportInput) -> Result<ImportResult> {
+    let client = get_client(&input).await?;
+    let collections: GraphqlResponse<CollectionsData> = client
+        .post("graphql")
+        .body_json(&json!({ "query": COLLECTIONS_QUERY }))
+        .unwrap()
+        .await
+        .map_err(|e| Error::new(e.to_string()))?
+        .body_json()
+        .await
+        .map_err(|e| Error::new(e.to_string()))?;
+    let collections = collections
+        .data
+        .collections
+        .into_iter()
+        .map(|c| CreateOrUpdateCollectionInput {
+            name: c.name,
+            description: c.description,
+            visibility: Some(match c.visibility.as_str() {
+                "PUBLIC" => Visibility::Public,
+                _ => Visibility::Private,
+            }),
+            ..Default::default()
+        })
+        .collect();
+    // DEV: The media export has the same format as the JSON import, so the items
+    // only need the details to be fetched again on this instance
+    let media: Vec<ImportOrExportMediaItem<String>> = client
+        .get("export/media")
+        .await
+        .map_err(|e| Error::new(e.to_string()))?
+        .body_json()
+        .await
+        .map_err(|e| Error::new(e.to_string()))?;
+    let media = media
+        .into_iter()
+        .map(|m| ImportOrExportMediaItem {
+            source_id: m.source_id,
+            title: m.title,
+            lot: m.lot,
+            source: m.source,
+            identifier: ImportOrExportItemIdentifier::NeedsDetails(m.identifier),
+            seen_history: m.seen_history,
+            reviews: m.reviews,
+            collections: m.collections,
+            highlights: m.highlights,
+        })
+        .collect();
+    Ok(ImportResult {
+        collections,
+        media,
+        failed_items: vec![],
+    })
+}
+
+pub async fn import_exercise(input: DeployRyotImportInput) -> Result<ExerciseImportResult> {
+    let client = get_client(&input).await?;
+    let measurements: Vec<user_measurement::Model> = client
+        .get("export/measurements")
+        .await
+        .map_err(|e| Error::new(e.to_string()))?
+        .body_json()
+        .await
+        .map_err(|e| Error::new(e.to_string()))?;
+    Ok(ExerciseImportResult {
+        workouts: vec![],
+        measurements,
+    })
+}
@@ -14,9 +14,10 @@ use crate::{
     },
     migrator::{MetadataLot, MetadataSource},
     models::media::{
-        ImportOrExportItemRating, ImportOrExportItemReview, ImportOrExportMediaItemSeen,
+        ExternalIdentifierType, ImportOrExportItemRating, ImportOrExportItemReview,
+        ImportOrExportMediaItemSeen,
     },
-    providers::openlibrary::OpenlibraryService,
+    traits::MediaProvider,
 };
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -51,7 +52,7 @@ struct History {
 
 pub async fn import(
     input: DeployStoryGraphImportInput,
-    openlibrary_service: &OpenlibraryService,
+    provider: &(dyn MediaProvider + Send + Sync),
 ) -> Result<ImportResult> {
     let lot = MetadataLot::Book;
     let source = MetadataSource::Openlibrary;
@@ -68,6 +69,7 @@ pub async fn import(
                 failed_items.push(ImportFailedItem {
                     lot,
                     step: ImportFailStep::InputTransformation,
+                    index: None,
                     identifier: idx.to_string(),
                     error: Some(e.to_string()),
                 });
@@ -79,7 +81,10 @@ pub async fn import(
             title = record.title
         );
         if let Some(isbn) = record.isbn {
-            if let Some(identifier) = openlibrary_service.id_from_isbn(&isbn).await {
+            if let Ok(Some(identifier)) = provider
+                .id_from_external(ExternalIdentifierType::Isbn, &isbn)
+                .await
+            {
                 let mut seen_history = vec![
                     ImportOrExportMediaItemSeen {
                         started_on: None,
@@ -106,7 +111,8 @@ pub async fn import(
                     collections.extend(t.split(", ").map(|d| d.to_case(Case::Title)))
                 }
                 media.push(ImportOrExportMediaItem {
-                    source_id: record.title,
+                    source_id: record.title.clone(),
+                    title: Some(record.title),
                     lot,
                     source,
                     identifier: ImportOrExportItemIdentifier::NeedsDetails(identifier),
@@ -124,11 +130,13 @@ pub async fn import(
                         ..Default::default()
                     }],
                     collections,
+                    highlights: vec![],
                 })
             } else {
                 failed_items.push(ImportFailedItem {
                     lot,
                     step: ImportFailStep::InputTransformation,
+                    index: None,
                     identifier: record.title,
                     error: Some(format!(
                         "Could not convert ISBN: {} to Openlibrary ID",
@@ -140,6 +148,7 @@ pub async fn import(
             failed_items.push(ImportFailedItem {
                 lot,
                 step: ImportFailStep::InputTransformation,
+                index: None,
                 identifier: record.title,
                 error: Some("No ISBN found".to_owned()),
             })
@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use async_graphql::Result;
 use convert_case::{Case, Casing};
 use http_types::mime;
@@ -6,7 +8,7 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use sea_orm::prelude::DateTimeUtc;
 use serde::{Deserialize, Serialize};
-use surf::http::headers::CONTENT_TYPE;
+use surf::http::headers::{AUTHORIZATION, CONTENT_TYPE};
 
 use crate::{
     importer::{
@@ -15,9 +17,10 @@ use crate::{
     },
     migrator::{MetadataLot, MetadataSource},
     models::media::{
-        CreateOrUpdateCollectionInput, ImportOrExportItemRating, ImportOrExportItemReview,
-        ImportOrExportMediaItemSeen,
+        CreateOrUpdateCollectionInput, ExternalIdentifierType, ImportOrExportItemRating,
+        ImportOrExportItemReview, ImportOrExportMediaItemSeen,
     },
+    traits::MediaProvider,
     utils::get_base_http_client,
 };
 
@@ -29,6 +32,8 @@ const API_VERSION: &str = "2";
 struct Id {
     trakt: u64,
     tmdb: Option<u64>,
+    tvdb: Option<u64>,
+    imdb: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -58,17 +63,83 @@ struct ListResponse {
     items: Vec<ListItemResponse>,
 }
 
-pub async fn import(input: DeployTraktImportInput) -> Result<ImportResult> {
+/// The key of an item in the identifiers that were looked up, since movies and
+/// shows have separate Trakt identifiers.
+type ItemKey = (MetadataLot, u64);
+
+impl ListItemResponse {
+    fn key_and_ids(&self) -> Option<(ItemKey, &Id)> {
+        if let Some(d) = self.movie.as_ref() {
+            Some(((MetadataLot::Movie, d.ids.trakt), &d.ids))
+        } else {
+            self.show
+                .as_ref()
+                .map(|d| ((MetadataLot::Show, d.ids.trakt), &d.ids))
+        }
+    }
+}
+
+/// Find the TMDB identifiers of the items that Trakt does not know them for using
+/// their TVDB or IMDb identifiers instead.
+async fn find_missing_tmdb_ids(
+    items: Vec<&ListItemResponse>,
+    movie_provider: &(dyn MediaProvider + Send + Sync),
+    show_provider: &(dyn MediaProvider + Send + Sync),
+) -> HashMap<ItemKey, String> {
+    let mut found = HashMap::new();
+    let mut checked = HashSet::new();
+    for item in items {
+        let (key, ids) = match item.key_and_ids() {
+            Some(k) if k.1.tmdb.is_none() => k,
+            _ => continue,
+        };
+        if !checked.insert(key) {
+            continue;
+        }
+        let provider = match key.0 {
+            MetadataLot::Show => show_provider,
+            _ => movie_provider,
+        };
+        let external_ids = [
+            (
+                ExternalIdentifierType::Tvdb,
+                ids.tvdb.map(|i| i.to_string()),
+            ),
+            (ExternalIdentifierType::Imdb, ids.imdb.clone()),
+        ];
+        for (id_type, value) in external_ids {
+            if let Some(value) = value {
+                if let Ok(Some(identifier)) = provider.id_from_external(id_type, &value).await {
+                    found.insert(key, identifier);
+                    break;
+                }
+            }
+        }
+    }
+    found
+}
+
+pub async fn import(
+    input: DeployTraktImportInput,
+    movie_provider: &(dyn MediaProvider + Send + Sync),
+    show_provider: &(dyn MediaProvider + Send + Sync),
+) -> Result<ImportResult> {
     let mut media_items = vec![];
     let mut failed_items = vec![];
 
+    let mut headers = vec![
+        (CONTENT_TYPE, mime::JSON.to_string()),
+        ("trakt-api-key".into(), CLIENT_ID.to_owned()),
+        ("trakt-api-version".into(), API_VERSION.to_owned()),
+    ];
+    // DEV: Private profiles can only be read by their owner
+    if let Some(token) = input.access_token {
+        headers.push((AUTHORIZATION, format!("Bearer {}", token)));
+    }
     let client = get_base_http_client(
         &format!("{}/users/{}/", API_URL, input.username),
-        vec![
-            (CONTENT_TYPE, mime::JSON.to_string().as_str()),
-            ("trakt-api-key".into(), CLIENT_ID),
-            ("trakt-api-version".into(), API_VERSION),
-        ],
+        headers,
+        None,
     );
     let mut rsp = client.get("lists").await.unwrap();
     let mut lists: Vec<ListResponse> = rsp.body_json().await.unwrap();
@@ -90,14 +161,61 @@ pub async fn import(input: DeployTraktImportInput) -> Result<ImportResult> {
             ids: Id {
                 trakt: 0,
                 tmdb: None,
+                tvdb: None,
+                imdb: None,
             },
             items,
         });
     }
 
+    let mut ratings = vec![];
+    for typ in ["movies", "shows"] {
+        let mut rsp = client.get(format!("ratings/{}", typ)).await.unwrap();
+        let items: Vec<ListItemResponse> = rsp.body_json().await.unwrap();
+        ratings.extend(items);
+    }
+
+    let mut histories = vec![];
+    let rsp = client
+        .head("history")
+        .query(&serde_json::json!({ "limit": 1000 }))
+        .unwrap()
+        .await
+        .unwrap();
+    let total_history = rsp
+        .header("x-pagination-page-count")
+        .expect("pagination to be present")
+        .last()
+        .as_str()
+        .parse::<usize>()
+        .unwrap();
+    for page in 1..total_history + 1 {
+        tracing::trace!("Fetching user history {page:?}/{total_history:?}");
+        let mut rsp = client
+            .get("history")
+            .query(&serde_json::json!({ "page": page, "limit": 1000 }))
+            .unwrap()
+            .await
+            .unwrap();
+        let history: Vec<ListItemResponse> = rsp.body_json().await.unwrap();
+        histories.extend(history);
+    }
+
+    let tmdb_ids = find_missing_tmdb_ids(
+        lists
+            .iter()
+            .flat_map(|l| l.items.iter())
+            .chain(ratings.iter())
+            .chain(histories.iter())
+            .collect(),
+        movie_provider,
+        show_provider,
+    )
+    .await;
+
     for l in lists.iter() {
         for i in l.items.iter() {
-            match process_item(i) {
+            match process_item(i, &tmdb_ids) {
                 Ok(mut d) => {
                     d.collections.push(l.name.to_case(Case::Title));
                     media_items.push(d)
@@ -122,63 +240,33 @@ pub async fn import(input: DeployTraktImportInput) -> Result<ImportResult> {
         })
         .collect_vec();
 
-    for typ in ["movies", "shows"] {
-        let mut rsp = client.get(format!("ratings/{}", typ)).await.unwrap();
-        let ratings: Vec<ListItemResponse> = rsp.body_json().await.unwrap();
-        for item in ratings.iter() {
-            match process_item(item) {
-                Ok(mut d) => {
-                    d.reviews.push(ImportOrExportItemRating {
-                        rating: item
-                            .rating
-                            // DEV: Rates items out of 10
-                            .map(|e| e * dec!(10)),
-                        review: Some(ImportOrExportItemReview {
-                            spoiler: Some(false),
-                            text: Some("".to_owned()),
-                            date: item.rated_at,
-                        }),
-                        ..Default::default()
-                    });
-                    if let Some(a) = media_items.iter_mut().find(|i| i.source_id == d.source_id) {
-                        a.reviews = d.reviews;
-                    } else {
-                        media_items.push(d)
-                    }
+    for item in ratings.iter() {
+        match process_item(item, &tmdb_ids) {
+            Ok(mut d) => {
+                d.reviews.push(ImportOrExportItemRating {
+                    rating: item
+                        .rating
+                        // DEV: Rates items out of 10
+                        .map(|e| e * dec!(10)),
+                    review: Some(ImportOrExportItemReview {
+                        spoiler: Some(false),
+                        text: Some("".to_owned()),
+                        date: item.rated_at,
+                    }),
+                    ..Default::default()
+                });
+                if let Some(a) = media_items.iter_mut().find(|i| i.source_id == d.source_id) {
+                    a.reviews = d.reviews;
+                } else {
+                    media_items.push(d)
                 }
-                Err(d) => failed_items.push(d),
             }
+            Err(d) => failed_items.push(d),
         }
     }
 
-    let mut histories = vec![];
-    let rsp = client
-        .head("history")
-        .query(&serde_json::json!({ "limit": 1000 }))
-        .unwrap()
-        .await
-        .unwrap();
-    let total_history = rsp
-        .header("x-pagination-page-count")
-        .expect("pagination to be present")
-        .last()
-        .as_str()
-        .parse::<usize>()
-        .unwrap();
-    for page in 1..total_history + 1 {
-        tracing::trace!("Fetching user history {page:?}/{total_history:?}");
-        let mut rsp = client
-            .get("history")
-            .query(&serde_json::json!({ "page": page, "limit": 1000 }))
-            .unwrap()
-            .await
-            .unwrap();
-        let history: Vec<ListItemResponse> = rsp.body_json().await.unwrap();
-        histories.extend(history);
-    }
-
     for item in histories.iter() {
-        match process_item(item) {
+        match process_item(item, &tmdb_ids) {
             Ok(mut d) => {
                 let (show_season_number, show_episode_number) =
                     if let Some(e) = item.episode.as_ref() {
@@ -192,6 +280,7 @@ pub async fn import(input: DeployTraktImportInput) -> Result<ImportResult> {
                     failed_items.push(ImportFailedItem {
                         lot: d.lot,
                         step: ImportFailStep::ItemDetailsFromSource,
+                        index: None,
                         identifier: "".to_owned(),
                         error: Some(
                             "Item is a show but does not have a season or episode number"
@@ -224,34 +313,49 @@ pub async fn import(input: DeployTraktImportInput) -> Result<ImportResult> {
 
 fn process_item(
     i: &ListItemResponse,
+    tmdb_ids: &HashMap<ItemKey, String>,
 ) -> std::result::Result<ImportOrExportMediaItem<ImportOrExportItemIdentifier>, ImportFailedItem> {
-    let (source_id, identifier, lot) = if let Some(d) = i.movie.as_ref() {
-        (d.ids.trakt, d.ids.tmdb, MetadataLot::Movie)
-    } else if let Some(d) = i.show.as_ref() {
-        (d.ids.trakt, d.ids.tmdb, MetadataLot::Show)
-    } else {
-        return Err(ImportFailedItem {
-            lot: MetadataLot::VideoGame,
-            step: ImportFailStep::ItemDetailsFromSource,
-            identifier: "".to_owned(),
-            error: Some("Item is neither a movie or a show".to_owned()),
-        });
+    let ((lot, source_id), ids) = match i.key_and_ids() {
+        Some(k) => k,
+        None => {
+            return Err(ImportFailedItem {
+                lot: MetadataLot::VideoGame,
+                step: ImportFailStep::ItemDetailsFromSource,
+                index: None,
+                identifier: "".to_owned(),
+                error: Some("Item is neither a movie or a show".to_owned()),
+            })
+        }
     };
+    let identifier = ids
+        .tmdb
+        .map(|i| i.to_string())
+        .or_else(|| tmdb_ids.get(&(lot, source_id)).cloned());
+    let title = i
+        .movie
+        .as_ref()
+        .or(i.show.as_ref())
+        .map(|d| d.title.clone());
     match identifier {
         Some(i) => Ok(ImportOrExportMediaItem {
             source_id: source_id.to_string(),
+            title,
             lot,
-            identifier: ImportOrExportItemIdentifier::NeedsDetails(i.to_string()),
+            identifier: ImportOrExportItemIdentifier::NeedsDetails(i),
             source: MetadataSource::Tmdb,
             seen_history: vec![],
             reviews: vec![],
             collections: vec![],
+            highlights: vec![],
         }),
         None => Err(ImportFailedItem {
-            lot: MetadataLot::Book,
+            lot,
             step: ImportFailStep::ItemDetailsFromSource,
-            identifier: "".to_owned(),
-            error: Some("Item does not have an associated TMDB id".to_owned()),
+            index: None,
+            identifier: source_id.to_string(),
+            error: Some(
+                "Item could not be found on TMDB using its TMDB, TVDB or IMDb id".to_owned(),
+            ),
         }),
     }
 }
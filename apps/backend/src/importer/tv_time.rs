@@ -0,0 +1,178 @@
+use std::{collections::HashMap, fs::File};
+
+use async_graphql::Result;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use csv::Reader;
+use itertools::Itertools;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use zip::{result::ZipError, ZipArchive};
+
+use crate::{
+    importer::{
+        DeployTvTimeImportInput, ImportFailStep, ImportFailedItem, ImportOrExportItemIdentifier,
+        ImportOrExportMediaItem, ImportResult,
+    },
+    migrator::{MetadataLot, MetadataSource},
+    miscellaneous::DefaultCollection,
+    models::media::{ExternalIdentifierType, ImportOrExportMediaItemSeen, MediaSearchFilters},
+    traits::MediaProvider,
+};
+
+/// A row of `followed_tv_show.csv`. The show ID is the one used by TVDB.
+#[derive(Debug, Serialize, Deserialize)]
+struct FollowedShow {
+    tv_show_id: Option<String>,
+    tv_show_name: String,
+}
+
+/// A row of `seen_episode.csv`. Older exports do not have the show ID.
+#[derive(Debug, Serialize, Deserialize)]
+struct SeenEpisode {
+    #[serde(default)]
+    tv_show_id: Option<String>,
+    tv_show_name: String,
+    episode_season_number: i32,
+    episode_number: i32,
+    created_at: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct Show {
+    tvdb_id: Option<String>,
+    followed: bool,
+    seen_history: Vec<ImportOrExportMediaItemSeen>,
+}
+
+/// Files that are not in the archive are treated as empty, since TV Time leaves
+/// out the ones that the account does not have any data for.
+fn read_csv<T: DeserializeOwned>(
+    archive: &mut ZipArchive<File>,
+    name: &str,
+) -> Result<Vec<csv::Result<T>>> {
+    let file = match archive.by_name(name) {
+        Ok(f) => f,
+        Err(ZipError::FileNotFound) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(Reader::from_reader(file).deserialize().collect_vec())
+}
+
+fn parse_date(date: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .map(|d| DateTime::from_naive_utc_and_offset(d, Utc))
+}
+
+/// Find the show using its TVDB ID, falling back to a search for its name.
+async fn find_show(
+    name: &str,
+    tvdb_id: Option<&str>,
+    provider: &(dyn MediaProvider + Send + Sync),
+) -> Option<String> {
+    if let Some(id) = tvdb_id.filter(|i| !i.is_empty()) {
+        if let Ok(Some(identifier)) = provider
+            .id_from_external(ExternalIdentifierType::Tvdb, id)
+            .await
+        {
+            return Some(identifier);
+        }
+    }
+    provider
+        .search(name, None, true, &MediaSearchFilters::default())
+        .await
+        .ok()?
+        .items
+        .into_iter()
+        .find(|i| i.title.eq_ignore_ascii_case(name))
+        .map(|i| i.identifier)
+}
+
+pub async fn import(
+    input: DeployTvTimeImportInput,
+    provider: &(dyn MediaProvider + Send + Sync),
+) -> Result<ImportResult> {
+    let lot = MetadataLot::Show;
+    let source = MetadataSource::Tmdb;
+    let mut media = vec![];
+    let mut failed_items = vec![];
+    let mut archive = ZipArchive::new(File::open(&input.export_path)?)?;
+    let followed = read_csv::<FollowedShow>(&mut archive, "followed_tv_show.csv")?;
+    let seen = read_csv::<SeenEpisode>(&mut archive, "seen_episode.csv")?;
+    let mut shows: HashMap<String, Show> = HashMap::new();
+    for (idx, result) in followed.into_iter().enumerate() {
+        match result {
+            Ok(f) => {
+                let show = shows.entry(f.tv_show_name).or_default();
+                show.followed = true;
+                show.tvdb_id = show.tvdb_id.take().or(f.tv_show_id);
+            }
+            Err(e) => failed_items.push(ImportFailedItem {
+                lot,
+                step: ImportFailStep::InputTransformation,
+                index: None,
+                identifier: format!("followed_tv_show.csv:{}", idx),
+                error: Some(e.to_string()),
+            }),
+        }
+    }
+    for (idx, result) in seen.into_iter().enumerate() {
+        match result {
+            Ok(e) => {
+                let show = shows.entry(e.tv_show_name).or_default();
+                show.tvdb_id = show.tvdb_id.take().or(e.tv_show_id);
+                show.seen_history.push(ImportOrExportMediaItemSeen {
+                    ended_on: e.created_at.as_deref().and_then(parse_date),
+                    show_season_number: Some(e.episode_season_number),
+                    show_episode_number: Some(e.episode_number),
+                    ..Default::default()
+                });
+            }
+            Err(e) => failed_items.push(ImportFailedItem {
+                lot,
+                step: ImportFailStep::InputTransformation,
+                index: None,
+                identifier: format!("seen_episode.csv:{}", idx),
+                error: Some(e.to_string()),
+            }),
+        }
+    }
+    let total = shows.len();
+    for (idx, (name, show)) in shows.into_iter().enumerate() {
+        tracing::debug!("Getting details for {name:?} ({idx}/{total})");
+        let identifier = match find_show(&name, show.tvdb_id.as_deref(), provider).await {
+            Some(identifier) => identifier,
+            None => {
+                failed_items.push(ImportFailedItem {
+                    lot,
+                    step: ImportFailStep::InputTransformation,
+                    index: None,
+                    identifier: name,
+                    error: Some("Could not find the show in TMDB".to_owned()),
+                });
+                continue;
+            }
+        };
+        let mut collections = vec![];
+        // DEV: Shows are followed as soon as they are added, so the ones without
+        // any watched episodes are yet to be started
+        if show.followed && show.seen_history.is_empty() {
+            collections.push(DefaultCollection::Watchlist.to_string());
+        }
+        media.push(ImportOrExportMediaItem {
+            source_id: name.clone(),
+            title: Some(name),
+            lot,
+            source,
+            identifier: ImportOrExportItemIdentifier::NeedsDetails(identifier),
+            seen_history: show.seen_history,
+            reviews: vec![],
+            collections,
+            highlights: vec![],
+        });
+    }
+    Ok(ImportResult {
+        collections: vec![],
+        media,
+        failed_items,
+    })
+}
@@ -0,0 +1,139 @@
+// Responsible for importing the games that were played on an Xbox, using the
+// title history of the Xbox network.
+
+use std::collections::HashSet;
+
+use async_graphql::Result;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    importer::{
+        DeployXboxImportInput, ImportFailStep, ImportFailedItem, ImportOrExportItemIdentifier,
+        ImportOrExportMediaItem, ImportResult,
+    },
+    migrator::{MetadataLot, MetadataSource},
+    models::media::{
+        CreateOrUpdateCollectionInput, ImportOrExportMediaItemSeen, MediaSearchFilters,
+    },
+    traits::MediaProvider,
+};
+
+static COLLECTION: &str = "Xbox";
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Achievement {
+    current_achievements: i32,
+    total_achievements: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TitleHistory {
+    last_time_played: Option<DateTimeUtc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Title {
+    name: String,
+    #[serde(rename = "type")]
+    lot: Option<String>,
+    achievement: Option<Achievement>,
+    title_history: Option<TitleHistory>,
+}
+
+/// The title history can either be the response of the API or only the list of
+/// titles in it, depending on the tool that was used to download it.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Export {
+    Response { titles: Vec<Title> },
+    Titles(Vec<Title>),
+}
+
+pub async fn import(
+    input: DeployXboxImportInput,
+    provider: &(dyn MediaProvider + Send + Sync),
+) -> Result<ImportResult> {
+    let lot = MetadataLot::VideoGame;
+    let source = MetadataSource::Igdb;
+    let titles = match serde_json::from_str::<Export>(&input.export)? {
+        Export::Response { titles } => titles,
+        Export::Titles(titles) => titles,
+    };
+    let mut media = vec![];
+    let mut failed_items = vec![];
+    let mut identifiers = HashSet::new();
+    let total = titles.len();
+    for (idx, title) in titles.into_iter().enumerate() {
+        // DEV: Apps like Netflix are listed along with the games
+        if title.lot.as_deref().map_or(false, |l| l != "Game") {
+            continue;
+        }
+        tracing::debug!(
+            "Getting details for {title:?} ({idx}/{total})",
+            title = title.name
+        );
+        let name = title.name.replace(['™', '®'], "");
+        let filters = MediaSearchFilters {
+            platform: Some("Xbox".to_owned()),
+            ..Default::default()
+        };
+        let identifier = provider
+            .search(name.trim(), None, false, &filters)
+            .await
+            .ok()
+            .and_then(|r| r.items.into_iter().next())
+            .map(|i| i.identifier);
+        let identifier = match identifier {
+            Some(i) => i,
+            None => {
+                failed_items.push(ImportFailedItem {
+                    lot,
+                    step: ImportFailStep::MediaDetailsFromProvider,
+                    index: None,
+                    identifier: title.name,
+                    error: Some("Could not find this game on IGDB".to_owned()),
+                });
+                continue;
+            }
+        };
+        if !identifiers.insert(identifier.clone()) {
+            continue;
+        }
+        // DEV: Unlocking every achievement is the closest thing to finishing a
+        // game that the title history has
+        let completed = title.achievement.map_or(false, |a| {
+            a.total_achievements > 0 && a.current_achievements >= a.total_achievements
+        });
+        let seen_history = match completed {
+            true => vec![ImportOrExportMediaItemSeen {
+                ended_on: title.title_history.and_then(|h| h.last_time_played),
+                ..Default::default()
+            }],
+            false => vec![],
+        };
+        media.push(ImportOrExportMediaItem {
+            source_id: title.name.clone(),
+            title: Some(title.name),
+            lot,
+            source,
+            identifier: ImportOrExportItemIdentifier::NeedsDetails(identifier),
+            seen_history,
+            reviews: vec![],
+            collections: vec![COLLECTION.to_owned()],
+            highlights: vec![],
+        });
+    }
+    Ok(ImportResult {
+        collections: vec![CreateOrUpdateCollectionInput {
+            name: COLLECTION.to_owned(),
+            description: Some("Games that I have played on Xbox.".to_owned()),
+            ..Default::default()
+        }],
+        media,
+        failed_items,
+    })
+}
@@ -0,0 +1,140 @@
+use anyhow::{bail, Result};
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    integrations::{find_tmdb_id, IntegrationMedia, IntegrationService},
+    migrator::{MetadataLot, MetadataSource},
+    models::media::ExternalIdentifierType,
+    traits::MediaProvider,
+};
+
+mod models {
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "PascalCase")]
+    pub struct JellyfinWebhookSessionPlayStatePayload {
+        pub position_ticks: Option<Decimal>,
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "PascalCase")]
+    pub struct JellyfinWebhookSessionPayload {
+        pub play_state: JellyfinWebhookSessionPlayStatePayload,
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[serde(rename_all = "PascalCase")]
+    pub struct JellyfinWebhookItemProviderIdsPayload {
+        pub tmdb: Option<String>,
+        pub imdb: Option<String>,
+        pub tvdb: Option<String>,
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "PascalCase")]
+    pub struct JellyfinWebhookItemPayload {
+        pub name: Option<String>,
+        pub run_time_ticks: Option<Decimal>,
+        #[serde(rename = "Type")]
+        pub item_type: String,
+        #[serde(default)]
+        pub provider_ids: JellyfinWebhookItemProviderIdsPayload,
+        #[serde(rename = "ParentIndexNumber")]
+        pub season_number: Option<i32>,
+        #[serde(rename = "IndexNumber")]
+        pub episode_number: Option<i32>,
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "PascalCase")]
+    pub struct JellyfinWebhookUserPayload {
+        pub name: String,
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[serde(rename_all = "PascalCase")]
+    pub struct JellyfinWebhookPayload {
+        pub event: Option<String>,
+        pub user: Option<JellyfinWebhookUserPayload>,
+        pub item: JellyfinWebhookItemPayload,
+        pub series: Option<JellyfinWebhookItemPayload>,
+        pub session: Option<JellyfinWebhookSessionPayload>,
+    }
+}
+
+/// Find the TMDb ID of a movie or show using the other IDs that Jellyfin has
+/// for it.
+async fn jellyfin_tmdb_id(
+    provider_ids: &models::JellyfinWebhookItemProviderIdsPayload,
+    lot: MetadataLot,
+    provider: &(dyn MediaProvider + Send + Sync),
+) -> Option<String> {
+    let mut external_ids = vec![];
+    if let Some(id) = &provider_ids.imdb {
+        external_ids.push((ExternalIdentifierType::Imdb, id.as_str()));
+    }
+    if let (MetadataLot::Show, Some(id)) = (lot, &provider_ids.tvdb) {
+        external_ids.push((ExternalIdentifierType::Tvdb, id.as_str()));
+    }
+    find_tmdb_id(external_ids, provider).await
+}
+
+impl IntegrationService {
+    /// Get the progress of the movie or episode that a payload of the Jellyfin
+    /// webhook plugin is about. Episodes are matched by the IDs of their show.
+    /// Media is matched by its TMDb ID, falling back to its IMDb ID and, for
+    /// shows, its TVDB ID.
+    pub async fn jellyfin_progress(
+        &self,
+        payload: &str,
+        movie_provider: &(dyn MediaProvider + Send + Sync),
+        show_provider: &(dyn MediaProvider + Send + Sync),
+    ) -> Result<IntegrationMedia> {
+        let payload = serde_json::from_str::<models::JellyfinWebhookPayload>(payload)?;
+        let (lot, item, provider) = match payload.item.item_type.as_str() {
+            "Episode" => match &payload.series {
+                Some(series) => (MetadataLot::Show, series, show_provider),
+                None => bail!("No show associated with this episode"),
+            },
+            "Movie" => (MetadataLot::Movie, &payload.item, movie_provider),
+            _ => bail!("Only movies and shows supported"),
+        };
+        let position_ticks = payload
+            .session
+            .as_ref()
+            .and_then(|s| s.play_state.position_ticks);
+        let progress = match payload.event.as_deref() {
+            Some("MarkPlayed") => 100,
+            Some("MarkUnplayed") => bail!("Ignoring event type MarkUnplayed"),
+            _ => {
+                let runtime = match payload.item.run_time_ticks {
+                    Some(r) if !r.is_zero() => r,
+                    _ => bail!("No run time associated with this media"),
+                };
+                let position = match position_ticks {
+                    Some(p) => p,
+                    None => bail!("No position associated with this media"),
+                };
+                (position / runtime * dec!(100))
+                    .to_i32()
+                    .unwrap_or_default()
+            }
+        };
+        let identifier = match item.provider_ids.tmdb.clone() {
+            Some(id) => Some(id),
+            None => jellyfin_tmdb_id(&item.provider_ids, lot, provider).await,
+        };
+        Ok(IntegrationMedia {
+            identifier,
+            external_id: item.name.clone(),
+            lot,
+            source: MetadataSource::Tmdb,
+            progress,
+            podcast_episode_number: None,
+            show_season_number: payload.item.season_number,
+            show_episode_number: payload.item.episode_number,
+            // DEV: Jellyfin counts time in ticks of 100 nanoseconds
+            position: position_ticks.and_then(|p| (p / dec!(10_000_000)).to_i32()),
+            paused: payload.event.as_deref() == Some("Pause"),
+            server_user: payload.user.map(|u| u.name),
+        })
+    }
+}
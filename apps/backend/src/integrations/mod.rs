@@ -1,21 +1,122 @@
 use anyhow::{anyhow, bail, Result};
-use regex::Regex;
+use chrono::NaiveDateTime;
+use hmac::{Hmac, Mac};
 use rust_decimal::{prelude::ToPrimitive, Decimal};
 use rust_decimal_macros::dec;
-use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
-use sea_query::{Alias, Expr, Func};
+use sea_orm::prelude::DateTimeUtc;
 use serde::{Deserialize, Serialize};
-use surf::{http::headers::AUTHORIZATION, Client};
+use serde_with::{serde_as, DisplayFromStr, PickFirst};
+use sha2::Sha256;
+use surf::{
+    http::{
+        headers::{HeaderName, ACCEPT, AUTHORIZATION},
+        Method,
+    },
+    Client, Request, Url,
+};
 
 use crate::{
-    entities::{metadata, prelude::Metadata},
     migrator::{MetadataLot, MetadataSource},
-    utils::{get_base_http_client, get_case_insensitive_like_query},
+    models::media::{ExternalIdentifierType, MediaSearchFilters},
+    traits::MediaProvider,
+    utils::{constant_time_eq, get_base_http_client},
 };
 
+mod jellyfin;
+mod plex;
+
+/// The store API that Kobo devices talk to. Requests that Ryot does not handle
+/// itself are passed through to it.
+pub static KOBO_STORE_URL: &str = "https://storeapi.kobo.com";
+/// The header that contains the signature of a webhook payload. It is the hex
+/// encoded HMAC-SHA256 of the payload with the secret of the integration,
+/// optionally prefixed with `sha256=`.
+pub static WEBHOOK_SIGNATURE_HEADER: &str = "x-ryot-signature";
+/// The header that contains the secret of the integration itself, for media
+/// servers that can add headers to their webhooks but can not sign them.
+pub static WEBHOOK_SECRET_HEADER: &str = "x-ryot-secret";
+/// The query parameter that contains the secret of the integration, for media
+/// servers that can only be given the URL of the webhook.
+pub static WEBHOOK_SECRET_QUERY: &str = "secret";
+static STEAM_API_URL: &str = "https://api.steampowered.com/";
+static RETRO_ACHIEVEMENTS_API_URL: &str = "https://retroachievements.org/API/";
+
+/// A game that the user owns on Steam.
+#[derive(Debug, Clone)]
+pub struct SteamGame {
+    pub app_id: String,
+    pub name: String,
+    /// The total playtime in minutes.
+    pub playtime: i32,
+    pub last_played_on: Option<DateTimeUtc>,
+}
+
+/// A game that the user has recently played on RetroAchievements.
+#[derive(Debug, Clone)]
+pub struct RetroAchievementsGame {
+    pub game_id: String,
+    pub title: String,
+    pub console: String,
+    pub last_played_on: DateTimeUtc,
+    /// The number of achievements that the game has.
+    pub achievements: i32,
+    /// The number of achievements that the user has unlocked.
+    pub achieved: i32,
+}
+
+/// The credentials that a request to the webhook of an integration came with.
+#[derive(Debug, Clone, Default)]
+pub struct WebhookCredentials {
+    pub signature: Option<String>,
+    pub secret: Option<String>,
+}
+
+impl WebhookCredentials {
+    /// Whether the payload was signed with the secret, or the secret itself was
+    /// sent along with it.
+    pub fn verify(&self, secret: &str, payload: &str) -> bool {
+        if let Some(signature) = &self.signature {
+            let signature = signature.trim();
+            let signature = signature.strip_prefix("sha256=").unwrap_or(signature);
+            let signature = match hex::decode(signature) {
+                Ok(s) => s,
+                Err(_) => return false,
+            };
+            let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+                .expect("HMAC can take a key of any size");
+            mac.update(payload.as_bytes());
+            return mac.verify_slice(&signature).is_ok();
+        }
+        match &self.secret {
+            Some(s) => constant_time_eq(s.as_bytes(), secret.as_bytes()),
+            None => false,
+        }
+    }
+}
+
+/// Find the TMDb ID of an item using the first of its other IDs that TMDb can
+/// convert.
+async fn find_tmdb_id(
+    external_ids: Vec<(ExternalIdentifierType, &str)>,
+    provider: &(dyn MediaProvider + Send + Sync),
+) -> Option<String> {
+    for (id_type, id) in external_ids {
+        if let Ok(Some(identifier)) = provider.id_from_external(id_type, id).await {
+            return Some(identifier);
+        }
+    }
+    None
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IntegrationMedia {
-    pub identifier: String,
+    /// The identifier of the item with its provider, if it could be found.
+    pub identifier: Option<String>,
+    /// The title or the ID that the integration identified the item with. It is
+    /// used to look the item up in the mappings of the user, falling back to the
+    /// identifier.
+    #[serde(default)]
+    pub external_id: Option<String>,
     pub lot: MetadataLot,
     #[serde(default)]
     pub source: MetadataSource,
@@ -23,6 +124,32 @@ pub struct IntegrationMedia {
     pub show_season_number: Option<i32>,
     pub show_episode_number: Option<i32>,
     pub podcast_episode_number: Option<i32>,
+    /// The playback position in the media in seconds, if the integration sends
+    /// it. This is how far into the media the user is, not how long they played
+    /// it for.
+    #[serde(default)]
+    pub position: Option<i32>,
+    /// Whether the progress was sent because the media was paused.
+    #[serde(default)]
+    pub paused: bool,
+    /// The profile on the media server that the media was played by.
+    #[serde(default)]
+    pub server_user: Option<String>,
+}
+
+/// The position in a document that KOReader sends to and expects from a sync
+/// server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KoreaderProgress {
+    pub document: String,
+    pub progress: String,
+    /// The fraction of the document that has been read, between 0 and 1.
+    pub percentage: f64,
+    pub device: String,
+    pub device_id: String,
+    /// The unix timestamp of the last update, only sent back to KOReader.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<i64>,
 }
 
 #[derive(Debug)]
@@ -33,207 +160,6 @@ impl IntegrationService {
         Self
     }
 
-    pub async fn jellyfin_progress(&self, payload: &str) -> Result<IntegrationMedia> {
-        mod models {
-            use super::*;
-
-            #[derive(Serialize, Deserialize, Debug, Clone)]
-            #[serde(rename_all = "PascalCase")]
-            pub struct JellyfinWebhookSessionPlayStatePayload {
-                pub position_ticks: Option<Decimal>,
-            }
-            #[derive(Serialize, Deserialize, Debug, Clone)]
-            #[serde(rename_all = "PascalCase")]
-            pub struct JellyfinWebhookSessionPayload {
-                pub play_state: JellyfinWebhookSessionPlayStatePayload,
-            }
-            #[derive(Serialize, Deserialize, Debug, Clone)]
-            #[serde(rename_all = "PascalCase")]
-            pub struct JellyfinWebhookItemProviderIdsPayload {
-                pub tmdb: Option<String>,
-            }
-            #[derive(Serialize, Deserialize, Debug, Clone)]
-            #[serde(rename_all = "PascalCase")]
-            pub struct JellyfinWebhookItemPayload {
-                pub run_time_ticks: Option<Decimal>,
-                #[serde(rename = "Type")]
-                pub item_type: String,
-                pub provider_ids: JellyfinWebhookItemProviderIdsPayload,
-                #[serde(rename = "ParentIndexNumber")]
-                pub season_number: Option<i32>,
-                #[serde(rename = "IndexNumber")]
-                pub episode_number: Option<i32>,
-            }
-            #[derive(Serialize, Deserialize, Debug, Clone)]
-            #[serde(rename_all = "PascalCase")]
-            pub struct JellyfinWebhookPayload {
-                pub event: Option<String>,
-                pub item: JellyfinWebhookItemPayload,
-                pub series: Option<JellyfinWebhookItemPayload>,
-                pub session: JellyfinWebhookSessionPayload,
-            }
-        }
-
-        let payload = serde_json::from_str::<models::JellyfinWebhookPayload>(payload)?;
-        let identifier = if let Some(id) = payload.item.provider_ids.tmdb.as_ref() {
-            Some(id.clone())
-        } else {
-            payload
-                .series
-                .as_ref()
-                .and_then(|s| s.provider_ids.tmdb.clone())
-        };
-        if identifier.is_none() {
-            bail!("No TMDb ID associated with this media")
-        }
-        if payload.item.run_time_ticks.is_none() {
-            bail!("No run time associated with this media")
-        }
-        if payload.session.play_state.position_ticks.is_none() {
-            bail!("No position associated with this media")
-        }
-        let identifier = identifier.unwrap();
-        let runtime = payload.item.run_time_ticks.unwrap();
-        let position = payload.session.play_state.position_ticks.unwrap();
-        let lot = match payload.item.item_type.as_str() {
-            "Episode" => MetadataLot::Show,
-            "Movie" => MetadataLot::Movie,
-            _ => bail!("Only movies and shows supported"),
-        };
-        Ok(IntegrationMedia {
-            identifier,
-            lot,
-            source: MetadataSource::Tmdb,
-            progress: (position / runtime * dec!(100)).to_i32().unwrap(),
-            podcast_episode_number: None,
-            show_season_number: payload.item.season_number,
-            show_episode_number: payload.item.episode_number,
-        })
-    }
-
-    pub async fn plex_progress(
-        &self,
-        payload: &str,
-        plex_user: Option<String>,
-        db: &DatabaseConnection,
-    ) -> Result<IntegrationMedia> {
-        mod models {
-            use super::*;
-
-            #[derive(Serialize, Deserialize, Debug, Clone)]
-            pub struct PlexWebhookMetadataGuid {
-                pub id: String,
-            }
-            #[derive(Serialize, Deserialize, Debug, Clone)]
-            pub struct PlexWebhookMetadataPayload {
-                #[serde(rename = "type")]
-                pub item_type: String,
-                #[serde(rename = "viewOffset")]
-                pub view_offset: Option<Decimal>,
-                pub duration: Decimal,
-                #[serde(rename = "grandparentTitle")]
-                pub show_name: Option<String>,
-                #[serde(rename = "parentIndex")]
-                pub season_number: Option<i32>,
-                #[serde(rename = "index")]
-                pub episode_number: Option<i32>,
-                #[serde(rename = "Guid")]
-                pub guids: Vec<PlexWebhookMetadataGuid>,
-            }
-            #[derive(Serialize, Deserialize, Debug, Clone)]
-            pub struct PlexWebhookAccount {
-                #[serde(rename = "title")]
-                pub plex_user: String,
-            }
-            #[derive(Serialize, Deserialize, Debug, Clone)]
-            pub struct PlexWebhookPayload {
-                #[serde(rename = "event")]
-                pub event_type: String,
-                pub user: bool,
-                pub owner: bool,
-                #[serde(rename = "Metadata")]
-                pub metadata: PlexWebhookMetadataPayload,
-                #[serde(rename = "Account")]
-                pub account: PlexWebhookAccount,
-            }
-        }
-
-        let payload_regex = Regex::new(r#"\{.*\}"#).unwrap();
-        let json_payload = payload_regex
-            .find(payload)
-            .map(|x| x.as_str())
-            .unwrap_or("");
-        let payload = match serde_json::from_str::<models::PlexWebhookPayload>(json_payload) {
-            Result::Ok(val) => val,
-            Result::Err(err) => bail!("Error during JSON payload deserialization {:#}", err),
-        };
-        if let Some(plex_user) = plex_user {
-            if plex_user != payload.account.plex_user {
-                bail!(
-                    "Ignoring non matching user {:#?}",
-                    payload.account.plex_user
-                );
-            }
-        }
-        match payload.event_type.as_str() {
-            "media.scrobble" | "media.play" | "media.pause" | "media.resume" | "media.stop" => {}
-            _ => bail!("Ignoring event type {:#?}", payload.event_type),
-        };
-
-        let tmdb_guid = payload
-            .metadata
-            .guids
-            .into_iter()
-            .find(|g| g.id.starts_with("tmdb://"));
-
-        if tmdb_guid.is_none() {
-            bail!("No TMDb ID associated with this media")
-        }
-        let tmdb_guid = tmdb_guid.unwrap();
-        let identifier = &tmdb_guid.id[7..];
-        let (identifier, lot) = match payload.metadata.item_type.as_str() {
-            "movie" => (identifier.to_owned(), MetadataLot::Movie),
-            "episode" => {
-                // DEV: Since Plex and Ryot both use TMDb, we can safely assume that the
-                // TMDB ID sent by Plex (which is actually the episode ID) is also present
-                // in the media specifics we have in DB.
-                let db_show = Metadata::find()
-                    .filter(metadata::Column::Lot.eq(MetadataLot::Show))
-                    .filter(metadata::Column::Source.eq(MetadataSource::Tmdb))
-                    .filter(get_case_insensitive_like_query(
-                        Func::cast_as(Expr::col(metadata::Column::Specifics), Alias::new("text")),
-                        identifier,
-                    ))
-                    .one(db)
-                    .await?;
-                if db_show.is_none() {
-                    bail!("No show found with TMDb ID {}", identifier);
-                }
-                (db_show.unwrap().identifier, MetadataLot::Show)
-            }
-            _ => bail!("Only movies and shows supported"),
-        };
-        let progress = match payload.metadata.view_offset {
-            Some(offset) => (offset / payload.metadata.duration * dec!(100))
-                .to_i32()
-                .unwrap(),
-            None => match payload.event_type.as_str() {
-                "media.scrobble" => 100,
-                _ => bail!("No position associated with this media"),
-            }
-        };
-
-        Ok(IntegrationMedia {
-            identifier,
-            lot,
-            source: MetadataSource::Tmdb,
-            progress,
-            podcast_episode_number: None,
-            show_season_number: payload.metadata.season_number,
-            show_episode_number: payload.metadata.episode_number,
-        })
-    }
-
     pub async fn kodi_progress(&self, payload: &str) -> Result<IntegrationMedia> {
         let mut payload = match serde_json::from_str::<IntegrationMedia>(payload) {
             Result::Ok(val) => val,
@@ -257,6 +183,7 @@ impl IntegrationService {
             }
             #[derive(Debug, Serialize, Deserialize)]
             pub struct ItemMetadata {
+                pub title: Option<String>,
                 pub asin: Option<String>,
             }
             #[derive(Debug, Serialize, Deserialize)]
@@ -278,6 +205,7 @@ impl IntegrationService {
         let client: Client = get_base_http_client(
             &format!("{}/api/", base_url),
             vec![(AUTHORIZATION, format!("Bearer {access_token}"))],
+            None,
         );
         let resp: models::Response = client
             .get("me/items-in-progress")
@@ -288,7 +216,9 @@ impl IntegrationService {
             .unwrap();
         let mut media_items = vec![];
         for item in resp.library_items.iter() {
-            if let Some(asin) = item.media.metadata.asin.clone() {
+            let metadata = &item.media.metadata;
+            // DEV: Items without an ASIN can only be matched by a mapping
+            if metadata.asin.is_some() || metadata.title.is_some() {
                 let resp: models::ItemProgress = client
                     .get(format!("me/progress/{}", item.id))
                     .await
@@ -297,16 +227,297 @@ impl IntegrationService {
                     .await
                     .unwrap();
                 media_items.push(IntegrationMedia {
-                    identifier: asin,
+                    identifier: metadata.asin.clone(),
+                    external_id: metadata.title.clone(),
                     lot: MetadataLot::AudioBook,
                     source: MetadataSource::Audible,
                     progress: (resp.progress * dec!(100)).to_i32().unwrap(),
                     show_season_number: None,
                     show_episode_number: None,
                     podcast_episode_number: None,
+                    position: None,
+                    paused: false,
+                    server_user: None,
                 });
             }
         }
         Ok(media_items)
     }
+
+    /// Send a request that a Kobo device made to the Kobo store, and return the
+    /// status, content type and body of the response.
+    pub async fn kobo_store_request(
+        &self,
+        method: &str,
+        path: &str,
+        headers: &[(String, String)],
+        body: Vec<u8>,
+    ) -> Result<(u16, Option<String>, Vec<u8>)> {
+        let method = method.parse::<Method>().map_err(|e| anyhow!(e))?;
+        let url = Url::parse(&format!("{}{}", KOBO_STORE_URL, path))?;
+        let mut request = Request::new(method, url);
+        for (name, value) in headers.iter() {
+            let name = name.parse::<HeaderName>().map_err(|e| anyhow!(e))?;
+            request.insert_header(name, value.as_str());
+        }
+        request.set_body(body);
+        let mut response = surf::client().send(request).await.map_err(|e| anyhow!(e))?;
+        let content_type = response.content_type().map(|c| c.to_string());
+        let body = response.body_bytes().await.map_err(|e| anyhow!(e))?;
+        Ok((response.status().into(), content_type, body))
+    }
+
+    pub async fn kobo_progress(
+        &self,
+        payload: &str,
+        headers: &[(String, String)],
+        provider: &(dyn MediaProvider + Send + Sync),
+    ) -> Result<Vec<IntegrationMedia>> {
+        mod models {
+            use super::*;
+
+            #[derive(Serialize, Deserialize, Debug, Clone)]
+            #[serde(rename_all = "PascalCase")]
+            pub struct KoboStatusInfo {
+                pub status: String,
+            }
+            #[derive(Serialize, Deserialize, Debug, Clone)]
+            #[serde(rename_all = "PascalCase")]
+            pub struct KoboBookmark {
+                pub progress_percent: Option<Decimal>,
+            }
+            #[derive(Serialize, Deserialize, Debug, Clone)]
+            #[serde(rename_all = "PascalCase")]
+            pub struct KoboReadingState {
+                pub entitlement_id: String,
+                pub status_info: Option<KoboStatusInfo>,
+                pub current_bookmark: Option<KoboBookmark>,
+            }
+            #[derive(Serialize, Deserialize, Debug, Clone)]
+            #[serde(rename_all = "PascalCase")]
+            pub struct KoboReadingStatesPayload {
+                pub reading_states: Vec<KoboReadingState>,
+            }
+            #[derive(Serialize, Deserialize, Debug, Clone)]
+            #[serde(rename_all = "PascalCase")]
+            pub struct KoboBookMetadata {
+                pub title: String,
+                pub isbn: Option<String>,
+            }
+        }
+
+        let payload = serde_json::from_str::<models::KoboReadingStatesPayload>(payload)?;
+        let mut media_items = vec![];
+        for state in payload.reading_states {
+            let status = state.status_info.map(|s| s.status).unwrap_or_default();
+            let percent = state.current_bookmark.and_then(|b| b.progress_percent);
+            let progress = match (status.as_str(), percent) {
+                ("Finished", _) => 100,
+                (_, Some(p)) => p.to_i32().unwrap_or_default(),
+                _ => continue,
+            };
+            // DEV: The device only sends the id of the book in the store, so the
+            // details need to be fetched to find the book on Openlibrary
+            let (status, _, body) = self
+                .kobo_store_request(
+                    "GET",
+                    &format!("/v1/library/{}/metadata", state.entitlement_id),
+                    headers,
+                    vec![],
+                )
+                .await?;
+            if status != 200 {
+                tracing::debug!("Could not get the details of {}", state.entitlement_id);
+                continue;
+            }
+            let book = match serde_json::from_slice::<Vec<models::KoboBookMetadata>>(&body)?
+                .into_iter()
+                .next()
+            {
+                Some(b) => b,
+                None => continue,
+            };
+            let query = book
+                .isbn
+                .filter(|i| !i.is_empty())
+                .unwrap_or(book.title.clone());
+            let identifier = provider
+                .search(&query, None, false, &MediaSearchFilters::default())
+                .await
+                .ok()
+                .and_then(|r| r.items.into_iter().next())
+                .map(|i| i.identifier);
+            if identifier.is_none() {
+                tracing::debug!("Could not find {:?} on Openlibrary", book.title);
+            }
+            media_items.push(IntegrationMedia {
+                identifier,
+                external_id: Some(book.title),
+                lot: MetadataLot::Book,
+                source: MetadataSource::Openlibrary,
+                progress,
+                show_season_number: None,
+                show_episode_number: None,
+                podcast_episode_number: None,
+                position: None,
+                paused: false,
+                server_user: None,
+            });
+        }
+        Ok(media_items)
+    }
+
+    /// Get the id of a Steam account from the link to its profile. The link can
+    /// either contain the id itself or the custom name chosen by the user.
+    pub async fn steam_id(&self, api_key: &str, profile: &str) -> Result<String> {
+        mod models {
+            use super::*;
+
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct VanityUrl {
+                pub steamid: Option<String>,
+            }
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct Response {
+                pub response: VanityUrl,
+            }
+        }
+
+        let profile = profile.trim().trim_end_matches('/');
+        let name = profile.rsplit('/').next().unwrap_or(profile);
+        if !profile.contains("/id/") && name.chars().all(|c| c.is_ascii_digit()) {
+            return Ok(name.to_owned());
+        }
+        let client: Client =
+            get_base_http_client(STEAM_API_URL, vec![(ACCEPT, "application/json")], None);
+        let resp: models::Response = client
+            .get("ISteamUser/ResolveVanityURL/v1/")
+            .query(&serde_json::json!({ "key": api_key, "vanityurl": name }))
+            .map_err(|e| anyhow!(e))?
+            .await
+            .map_err(|e| anyhow!(e))?
+            .body_json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        resp.response
+            .steamid
+            .ok_or_else(|| anyhow!("Could not find the Steam profile {}", profile))
+    }
+
+    pub async fn steam_owned_games(&self, api_key: &str, steam_id: &str) -> Result<Vec<SteamGame>> {
+        mod models {
+            use super::*;
+
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct Game {
+                pub appid: u32,
+                pub name: Option<String>,
+                pub playtime_forever: i32,
+                pub rtime_last_played: Option<i64>,
+            }
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct OwnedGames {
+                pub games: Option<Vec<Game>>,
+            }
+            #[derive(Debug, Serialize, Deserialize)]
+            pub struct Response {
+                pub response: OwnedGames,
+            }
+        }
+
+        let client: Client =
+            get_base_http_client(STEAM_API_URL, vec![(ACCEPT, "application/json")], None);
+        let resp: models::Response = client
+            .get("IPlayerService/GetOwnedGames/v1/")
+            .query(&serde_json::json!({
+                "key": api_key,
+                "steamid": steam_id,
+                "include_appinfo": 1,
+                "include_played_free_games": 1
+            }))
+            .map_err(|e| anyhow!(e))?
+            .await
+            .map_err(|e| anyhow!(e))?
+            .body_json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        // DEV: Steam returns an empty response instead of an error when the game
+        // details of the profile are private
+        let games = resp
+            .response
+            .games
+            .ok_or_else(|| anyhow!("The game details of this Steam profile are not public"))?;
+        Ok(games
+            .into_iter()
+            .map(|g| SteamGame {
+                app_id: g.appid.to_string(),
+                name: g.name.unwrap_or_default(),
+                playtime: g.playtime_forever,
+                last_played_on: g
+                    .rtime_last_played
+                    .filter(|t| *t > 0)
+                    .and_then(|t| DateTimeUtc::from_timestamp(t, 0)),
+            })
+            .collect())
+    }
+
+    pub async fn retro_achievements_recent_games(
+        &self,
+        username: &str,
+        api_key: &str,
+    ) -> Result<Vec<RetroAchievementsGame>> {
+        mod models {
+            use super::*;
+
+            // DEV: Older games return the numbers as strings
+            #[serde_as]
+            #[derive(Debug, Serialize, Deserialize)]
+            #[serde(rename_all = "PascalCase")]
+            pub struct Game {
+                #[serde(rename = "GameID")]
+                #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+                pub game_id: i64,
+                pub title: String,
+                pub console_name: String,
+                pub last_played: String,
+                #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+                pub num_possible_achievements: i32,
+                #[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+                pub num_achieved: i32,
+            }
+        }
+
+        let client: Client = get_base_http_client(
+            RETRO_ACHIEVEMENTS_API_URL,
+            vec![(ACCEPT, "application/json")],
+            None,
+        );
+        let games: Vec<models::Game> = client
+            .get("API_GetUserRecentlyPlayedGames.php")
+            .query(&serde_json::json!({ "z": username, "y": api_key, "u": username, "c": 50 }))
+            .map_err(|e| anyhow!(e))?
+            .await
+            .map_err(|e| anyhow!(e))?
+            .body_json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        Ok(games
+            .into_iter()
+            .filter_map(|g| {
+                // DEV: The time of the last session is in UTC
+                let last_played_on =
+                    NaiveDateTime::parse_from_str(&g.last_played, "%Y-%m-%d %H:%M:%S")
+                        .ok()?
+                        .and_utc();
+                Some(RetroAchievementsGame {
+                    game_id: g.game_id.to_string(),
+                    title: g.title,
+                    console: g.console_name,
+                    last_played_on,
+                    achievements: g.num_possible_achievements,
+                    achieved: g.num_achieved,
+                })
+            })
+            .collect())
+    }
 }
@@ -0,0 +1,219 @@
+use anyhow::{bail, Result};
+use regex::Regex;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use rust_decimal_macros::dec;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+use sea_query::{Alias, Expr, Func};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    entities::{metadata, prelude::Metadata},
+    integrations::{find_tmdb_id, IntegrationMedia, IntegrationService},
+    migrator::{MetadataLot, MetadataSource},
+    models::media::ExternalIdentifierType,
+    traits::MediaProvider,
+    utils::get_case_insensitive_like_query,
+};
+
+mod models {
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    pub struct PlexWebhookMetadataGuid {
+        pub id: String,
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    pub struct PlexWebhookMetadataPayload {
+        #[serde(rename = "type")]
+        pub item_type: String,
+        #[serde(rename = "viewOffset")]
+        pub view_offset: Option<Decimal>,
+        pub duration: Option<Decimal>,
+        pub title: Option<String>,
+        #[serde(rename = "grandparentTitle")]
+        pub show_name: Option<String>,
+        #[serde(rename = "parentIndex")]
+        pub season_number: Option<i32>,
+        #[serde(rename = "index")]
+        pub episode_number: Option<i32>,
+        /// The ID of the item with the agent of the library. Only the legacy
+        /// agents put the ID of a provider here.
+        pub guid: Option<String>,
+        #[serde(rename = "Guid", default)]
+        pub guids: Vec<PlexWebhookMetadataGuid>,
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    pub struct PlexWebhookAccount {
+        #[serde(rename = "title")]
+        pub plex_user: String,
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    pub struct PlexWebhookPayload {
+        #[serde(rename = "event")]
+        pub event_type: String,
+        pub user: bool,
+        pub owner: bool,
+        #[serde(rename = "Metadata")]
+        pub metadata: PlexWebhookMetadataPayload,
+        #[serde(rename = "Account")]
+        pub account: PlexWebhookAccount,
+    }
+}
+
+/// The IDs of providers that Plex has for an item.
+#[derive(Debug, Default)]
+struct PlexIds {
+    tmdb: Option<String>,
+    imdb: Option<String>,
+    tvdb: Option<String>,
+}
+
+impl PlexIds {
+    /// Collect the IDs from GUIDs like `tmdb://603` that the Plex agents use, or
+    /// `com.plexapp.agents.themoviedb://1396/1/1?lang=en` that the legacy agents
+    /// use. GUIDs of other agents are skipped.
+    fn from_guids<'a>(guids: impl Iterator<Item = &'a str>) -> Self {
+        let mut ids = Self::default();
+        for guid in guids {
+            let (scheme, rest) = match guid.split_once("://") {
+                Some(parts) => parts,
+                None => continue,
+            };
+            let id = rest.split(['/', '?']).next().unwrap_or_default();
+            if id.is_empty() {
+                continue;
+            }
+            let slot = match scheme.rsplit('.').next().unwrap_or_default() {
+                "tmdb" | "themoviedb" => &mut ids.tmdb,
+                "imdb" => &mut ids.imdb,
+                "tvdb" | "thetvdb" => &mut ids.tvdb,
+                _ => continue,
+            };
+            slot.get_or_insert_with(|| id.to_owned());
+        }
+        ids
+    }
+
+    async fn tmdb_id(
+        &self,
+        lot: MetadataLot,
+        provider: &(dyn MediaProvider + Send + Sync),
+    ) -> Option<String> {
+        if let Some(id) = &self.tmdb {
+            return Some(id.clone());
+        }
+        let mut external_ids = vec![];
+        if let Some(id) = &self.imdb {
+            external_ids.push((ExternalIdentifierType::Imdb, id.as_str()));
+        }
+        if let (MetadataLot::Show, Some(id)) = (lot, &self.tvdb) {
+            external_ids.push((ExternalIdentifierType::Tvdb, id.as_str()));
+        }
+        find_tmdb_id(external_ids, provider).await
+    }
+}
+
+/// Find a show in the database that has an episode with this TMDb ID.
+async fn find_show_by_episode(episode_id: &str, db: &DatabaseConnection) -> Result<Option<String>> {
+    // DEV: Since Plex and Ryot both use TMDb, we can safely assume that the
+    // TMDB ID sent by Plex (which is actually the episode ID) is also present
+    // in the media specifics we have in DB.
+    let db_show = Metadata::find()
+        .filter(metadata::Column::Lot.eq(MetadataLot::Show))
+        .filter(metadata::Column::Source.eq(MetadataSource::Tmdb))
+        .filter(get_case_insensitive_like_query(
+            Func::cast_as(Expr::col(metadata::Column::Specifics), Alias::new("text")),
+            episode_id,
+        ))
+        .one(db)
+        .await?;
+    Ok(db_show.map(|s| s.identifier))
+}
+
+impl IntegrationService {
+    /// Get the progress of the movie or episode that a payload of the Plex
+    /// webhook is about, along with the account that it was played by.
+    ///
+    /// Plex identifies episodes with the IDs of the episode itself, so they are
+    /// matched to their show using the episodes of the shows in the database,
+    /// falling back to a lookup of the IMDb or TVDB ID of the episode on TMDb.
+    pub async fn plex_progress(
+        &self,
+        payload: &str,
+        db: &DatabaseConnection,
+        movie_provider: &(dyn MediaProvider + Send + Sync),
+        show_provider: &(dyn MediaProvider + Send + Sync),
+    ) -> Result<IntegrationMedia> {
+        let payload_regex = Regex::new(r#"(?s)\{.*\}"#).unwrap();
+        let json_payload = payload_regex
+            .find(payload)
+            .map(|x| x.as_str())
+            .unwrap_or("");
+        let payload = match serde_json::from_str::<models::PlexWebhookPayload>(json_payload) {
+            Result::Ok(val) => val,
+            Result::Err(err) => bail!("Error during JSON payload deserialization {:#}", err),
+        };
+        match payload.event_type.as_str() {
+            "media.scrobble" | "media.play" | "media.pause" | "media.resume" | "media.stop" => {}
+            _ => bail!("Ignoring event type {:#?}", payload.event_type),
+        };
+        let metadata = payload.metadata;
+        let legacy_ids = PlexIds::from_guids(metadata.guid.iter().map(String::as_str));
+        let item_ids = PlexIds::from_guids(metadata.guids.iter().map(|g| g.id.as_str()));
+
+        let (identifier, external_id, lot) = match metadata.item_type.as_str() {
+            "movie" => {
+                let identifier = match legacy_ids.tmdb_id(MetadataLot::Movie, movie_provider).await
+                {
+                    Some(id) => Some(id),
+                    None => item_ids.tmdb_id(MetadataLot::Movie, movie_provider).await,
+                };
+                (identifier, metadata.title.clone(), MetadataLot::Movie)
+            }
+            "episode" => {
+                // DEV: The legacy agents use the IDs of the show for its episodes
+                let mut identifier = legacy_ids.tmdb_id(MetadataLot::Show, show_provider).await;
+                if identifier.is_none() {
+                    if let Some(episode_id) = &item_ids.tmdb {
+                        identifier = find_show_by_episode(episode_id, db).await?;
+                    }
+                }
+                if identifier.is_none() {
+                    let episode_ids = PlexIds {
+                        tmdb: None,
+                        ..item_ids
+                    };
+                    identifier = episode_ids.tmdb_id(MetadataLot::Show, show_provider).await;
+                }
+                (identifier, metadata.show_name.clone(), MetadataLot::Show)
+            }
+            _ => bail!("Only movies and shows supported"),
+        };
+        let position = metadata.view_offset.and_then(|o| (o / dec!(1000)).to_i32());
+        let progress = match payload.event_type.as_str() {
+            // DEV: Plex sends this event once the item has been watched past its
+            // threshold, so it is marked as seen regardless of the position
+            "media.scrobble" => 100,
+            _ => match (metadata.view_offset, metadata.duration) {
+                (Some(offset), Some(duration)) if !duration.is_zero() => {
+                    (offset / duration * dec!(100)).to_i32().unwrap_or_default()
+                }
+                _ => bail!("No position associated with this media"),
+            },
+        };
+
+        Ok(IntegrationMedia {
+            identifier,
+            external_id,
+            lot,
+            source: MetadataSource::Tmdb,
+            progress,
+            podcast_episode_number: None,
+            show_season_number: metadata.season_number,
+            show_episode_number: metadata.episode_number,
+            position,
+            paused: payload.event_type == "media.pause",
+            server_user: Some(payload.account.plex_user),
+        })
+    }
+}
@@ -2,6 +2,7 @@ use anyhow::Result;
 use chrono::{Duration, Utc};
 use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation};
 use serde::{Deserialize, Serialize};
+use strum::Display;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Claims {
@@ -41,3 +42,51 @@ pub fn verify(token: &str, jwt_secret: &str) -> Result<Claims> {
     .map(|data| data.claims)?;
     Ok(claims)
 }
+
+/// The different kinds of tokens that are sent to the users by email.
+#[derive(Debug, Clone, Copy, Display)]
+pub enum EmailTokenPurpose {
+    EmailVerification,
+    PasswordReset,
+}
+
+/// The tokens sent by email are signed with a different key than the auth
+/// tokens so that they can not be used to login. The key also includes the
+/// state that the token is tied to (eg: the current password hash) so that it
+/// stops working once that state changes.
+fn email_token_key(jwt_secret: &str, purpose: EmailTokenPurpose, state: &str) -> String {
+    format!("{}:{}:{}", jwt_secret, purpose, state)
+}
+
+pub fn sign_email_token(
+    id: i32,
+    jwt_secret: &str,
+    purpose: EmailTokenPurpose,
+    state: &str,
+    token_valid_for_days: i64,
+) -> Result<String> {
+    sign(
+        id,
+        &email_token_key(jwt_secret, purpose, state),
+        token_valid_for_days,
+    )
+}
+
+/// The user that an email token was issued for. The token is not verified, this
+/// is only used to find the state that it needs to be verified against.
+pub fn email_token_user_id(token: &str) -> Result<i32> {
+    let mut validation = Validation::default();
+    validation.insecure_disable_signature_validation();
+    let claims: Claims =
+        jsonwebtoken::decode(token, &DecodingKey::from_secret(&[]), &validation)?.claims;
+    Ok(claims.sub.parse()?)
+}
+
+pub fn verify_email_token(
+    token: &str,
+    jwt_secret: &str,
+    purpose: EmailTokenPurpose,
+    state: &str,
+) -> Result<Claims> {
+    verify(token, &email_token_key(jwt_secret, purpose, state))
+}
@@ -23,7 +23,7 @@ use aws_sdk_s3::config::Region;
 use axum::{
     extract::DefaultBodyLimit,
     http::{header, Method},
-    routing::{get, post, Router},
+    routing::{any, get, post, put, Router},
     Extension, Server,
 };
 use itertools::Itertools;
@@ -39,14 +39,20 @@ use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{fmt, layer::SubscriberExt};
 
 use crate::{
-    background::{media_jobs, perform_application_job, user_jobs, yank_integrations_data},
+    background::{
+        fitness_jobs, maintenance_jobs, media_jobs, perform_application_job, user_jobs,
+        yank_integrations_data,
+    },
     config::load_app_config,
     config::AppConfig,
-    graphql::get_schema,
+    graphql::{get_schema, GraphqlRateLimiter},
     migrator::Migrator,
     routes::{
-        config_handler, graphql_handler, graphql_playground, integration_webhook, json_export,
-        static_handler, upload_file,
+        config_handler, graphql_handler, graphql_playground, graphql_sdl_handler,
+        graphql_subscription_handler, highlights_export, integration_webhook, json_export,
+        kobo_initialization, kobo_proxy, kobo_reading_state, koreader_auth, koreader_create_user,
+        koreader_get_progress, koreader_update_progress, letterboxd_export, mal_export,
+        media_csv_export, openapi_handler, static_handler, upload_file, workouts_csv_export,
     },
     utils::{create_app_services, BASE_DIR, PROJECT_NAME, VERSION},
 };
@@ -57,6 +63,7 @@ mod entities;
 mod file_storage;
 mod fitness;
 mod graphql;
+mod http_cache;
 mod importer;
 mod integrations;
 mod jwt;
@@ -64,7 +71,10 @@ mod migrator;
 mod miscellaneous;
 mod models;
 mod notification;
+mod openapi;
+mod password;
 mod providers;
+mod rate_limit;
 mod routes;
 mod traits;
 mod users;
@@ -91,6 +101,7 @@ async fn main() -> Result<()> {
         .collect_vec();
     let rate_limit_num = config.scheduler.rate_limit_num;
     let user_cleanup_every = config.scheduler.user_cleanup_every;
+    let maintenance_every = config.scheduler.maintenance_every;
     let pull_every = config.integration.pull_every;
     let max_file_size = config.server.max_file_size;
     fs::write(
@@ -116,6 +127,8 @@ async fn main() -> Result<()> {
         ));
     }
     let aws_conf = aws_conf.build();
+    http_cache::init(config.media.provider_cache_minutes);
+    rate_limit::init(config.media.rate_limits.clone());
     let s3_client = aws_sdk_s3::Client::from_conf(aws_conf);
 
     let opt = ConnectOptions::new(config.database.url.clone())
@@ -191,6 +204,9 @@ async fn main() -> Result<()> {
     }
 
     let schema = get_schema(&app_services).await;
+    let rate_limiter = Arc::new(GraphqlRateLimiter::new(
+        app_services.config.server.graphql.max_requests_per_minute,
+    ));
 
     let cors = TowerCorsLayer::new()
         .allow_methods([Method::GET, Method::POST])
@@ -198,22 +214,45 @@ async fn main() -> Result<()> {
         .allow_origin(cors_origins)
         .allow_credentials(true);
 
-    let webhook_routes = Router::new().route(
-        "/integrations/:integration/:user_hash_id",
-        post(integration_webhook),
-    );
+    let koreader_routes = Router::new()
+        .route("/users/auth", get(koreader_auth))
+        .route("/users/create", post(koreader_create_user))
+        .route("/syncs/progress", put(koreader_update_progress))
+        .route("/syncs/progress/:document", get(koreader_get_progress));
+
+    let kobo_routes = Router::new()
+        .route("/v1/initialization", get(kobo_initialization))
+        .route("/v1/library/:book_id/state", put(kobo_reading_state))
+        .route("/*path", any(kobo_proxy));
+
+    let webhook_routes = Router::new()
+        .route(
+            "/integrations/:integration/:user_hash_id",
+            post(integration_webhook),
+        )
+        .nest("/integrations/koreader/:user_hash_id", koreader_routes)
+        .nest("/integrations/kobo/:user_hash_id/:secret", kobo_routes);
 
     let app_routes = Router::new()
         .route("/config", get(config_handler))
         .route("/graphql", get(graphql_playground).post(graphql_handler))
+        .route("/graphql/ws", get(graphql_subscription_handler))
+        .route("/graphql/schema.graphql", get(graphql_sdl_handler))
+        .route("/openapi.json", get(openapi_handler))
         .nest("/webhooks", webhook_routes)
         .route("/export/:export_type", get(json_export))
+        .route("/export/workouts/:format", get(workouts_csv_export))
+        .route("/export/csv/:lot", get(media_csv_export))
+        .route("/export/letterboxd", get(letterboxd_export))
+        .route("/export/mal/:lot", get(mal_export))
+        .route("/export/highlights", get(highlights_export))
         .route("/upload", post(upload_file))
         .fallback(static_handler)
         .layer(Extension(app_services.config.clone()))
         .layer(Extension(app_services.media_service.clone()))
         .layer(Extension(app_services.exercise_service.clone()))
         .layer(Extension(schema))
+        .layer(Extension(rate_limiter))
         .layer(TowerTraceLayer::new_for_http())
         .layer(TowerCatchPanicLayer::new())
         .layer(DefaultBodyLimit::max(1024 * 1024 * max_file_size))
@@ -232,10 +271,13 @@ async fn main() -> Result<()> {
     let media_service_2 = app_services.media_service.clone();
     let media_service_3 = app_services.media_service.clone();
     let media_service_4 = app_services.media_service.clone();
+    let media_service_5 = app_services.media_service.clone();
     let exercise_service_1 = app_services.exercise_service.clone();
+    let exercise_service_2 = app_services.exercise_service.clone();
+    let exercise_service_3 = app_services.exercise_service.clone();
 
     let monitor = async {
-        let mn = Monitor::new()
+        let mut mn = Monitor::new()
             // cron jobs
             .register_with_count(1, move |c| {
                 WorkerBuilder::new(format!("general_user_cleanup-{c}"))
@@ -264,6 +306,18 @@ async fn main() -> Result<()> {
                     .layer(ApalisExtension(media_service_2.clone()))
                     .build_fn(media_jobs)
             })
+            .register_with_count(1, move |c| {
+                WorkerBuilder::new(format!("general_fitness_job-{c}"))
+                    .stream(
+                        // every day
+                        CronStream::new(Schedule::from_str("0 0 0 * * *").unwrap())
+                            .timer(SleepTimer)
+                            .to_stream(),
+                    )
+                    .layer(ApalisTraceLayer::new())
+                    .layer(ApalisExtension(exercise_service_2.clone()))
+                    .build_fn(fitness_jobs)
+            })
             .register_with_count(1, move |c| {
                 WorkerBuilder::new(format!("yank_integrations_data-{c}"))
                     .stream(
@@ -275,6 +329,7 @@ async fn main() -> Result<()> {
                     )
                     .layer(ApalisTraceLayer::new())
                     .layer(ApalisExtension(media_service_3.clone()))
+                    .layer(ApalisExtension(exercise_service_3.clone()))
                     .build_fn(yank_integrations_data)
             })
             // application jobs
@@ -290,15 +345,30 @@ async fn main() -> Result<()> {
                     .layer(ApalisExtension(exercise_service_1.clone()))
                     .with_storage(perform_application_job_storage.clone())
                     .build_fn(perform_application_job)
-            })
-            .run()
-            .await;
+            });
+        if maintenance_every > 0 {
+            mn = mn.register_with_count(1, move |c| {
+                WorkerBuilder::new(format!("database_maintenance-{c}"))
+                    .stream(
+                        CronStream::new(
+                            Schedule::from_str(&format!("0 0 */{} ? * *", maintenance_every))
+                                .unwrap(),
+                        )
+                        .timer(SleepTimer)
+                        .to_stream(),
+                    )
+                    .layer(ApalisTraceLayer::new())
+                    .layer(ApalisExtension(media_service_5.clone()))
+                    .build_fn(maintenance_jobs)
+            });
+        }
+        let mn = mn.run().await;
         Ok(mn)
     };
 
     let http = async {
         Server::bind(&addr)
-            .serve(app_routes.into_make_service())
+            .serve(app_routes.into_make_service_with_connect_info::<SocketAddr>())
             .await
             .map_err(|e| IoError::new(IoErrorKind::Interrupted, e))
     };
@@ -3,7 +3,7 @@ use sea_orm::{DeriveActiveEnum, EnumIter};
 use sea_orm_migration::prelude::*;
 use serde::{Deserialize, Serialize};
 use specta::Type;
-use strum::Display;
+use strum::{Display, EnumString};
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -27,14 +27,20 @@ pub static METADATA_UNIQUE_INDEX: &str = "metadata-identifier-source-lot__unique
     Default,
     Type,
     Hash,
+    EnumString,
 )]
 #[sea_orm(rs_type = "String", db_type = "String(None)")]
+#[strum(ascii_case_insensitive)]
 pub enum MetadataSource {
     #[sea_orm(string_value = "AN")]
     Anilist,
     #[default]
     #[sea_orm(string_value = "AU")]
     Audible,
+    #[sea_orm(string_value = "BG")]
+    BoardGameGeek,
+    #[sea_orm(string_value = "CV")]
+    ComicVine,
     #[sea_orm(string_value = "CU")]
     Custom,
     #[sea_orm(string_value = "GO")]
@@ -45,16 +51,24 @@ pub enum MetadataSource {
     Itunes,
     #[sea_orm(string_value = "LI")]
     Listennotes,
+    #[sea_orm(string_value = "MD")]
+    MangaDex,
     #[sea_orm(string_value = "MU")]
     MangaUpdates,
     #[sea_orm(string_value = "MY")]
     Mal,
+    #[sea_orm(string_value = "MB")]
+    MusicBrainz,
     #[sea_orm(string_value = "OL")]
     Openlibrary,
+    #[sea_orm(string_value = "PI")]
+    PodcastIndex,
     #[sea_orm(string_value = "TM")]
     Tmdb,
     #[sea_orm(string_value = "VN")]
     Vndb,
+    #[sea_orm(string_value = "WE")]
+    Web,
 }
 
 // The different types of media that can be stored
@@ -76,19 +90,27 @@ pub enum MetadataSource {
 )]
 #[sea_orm(rs_type = "String", db_type = "String(None)")]
 pub enum MetadataLot {
+    #[sea_orm(string_value = "AR")]
+    Article,
     #[sea_orm(string_value = "AB")]
     AudioBook,
     #[sea_orm(string_value = "AN")]
     Anime,
+    #[sea_orm(string_value = "BG")]
+    BoardGame,
     #[default]
     #[sea_orm(string_value = "BO")]
     Book,
+    #[sea_orm(string_value = "CO")]
+    Comic,
     #[sea_orm(string_value = "PO")]
     Podcast,
     #[sea_orm(string_value = "MA")]
     Manga,
     #[sea_orm(string_value = "MO")]
     Movie,
+    #[sea_orm(string_value = "MU")]
+    Music,
     #[sea_orm(string_value = "SH")]
     Show,
     #[sea_orm(string_value = "VG")]
@@ -108,6 +130,14 @@ pub enum Metadata {
     // updated using jobs
     LastUpdatedOn,
     Title,
+    // the title with all diacritics removed, used for searching
+    NormalizedTitle,
+    // other names this media is known by, eg: translated or romanized titles
+    AlternativeTitles,
+    // the language that the provider was asked to return details in
+    Language,
+    // the title and description in the other languages that users prefer
+    Localizations,
     Description,
     // the year this media item was released
     PublishYear,
@@ -129,6 +159,10 @@ pub enum Metadata {
     Specifics,
     // whether it is not safe for work
     IsNsfw,
+    // whether the provider has deleted this item, needs to be resolved by the user
+    IsDeletedOnProvider,
+    // the awards and nominations this media has received, fetched from wikidata
+    Awards,
     // Time when this item has been processed by the calendar indexer
     LastProcessedOnForCalendar,
 }
@@ -161,6 +195,10 @@ impl MigrationTrait for Migration {
                             .default(Expr::current_timestamp()),
                     )
                     .col(ColumnDef::new(Metadata::Title).string().not_null())
+                    .col(ColumnDef::new(Metadata::NormalizedTitle).string())
+                    .col(ColumnDef::new(Metadata::AlternativeTitles).json())
+                    .col(ColumnDef::new(Metadata::Language).string())
+                    .col(ColumnDef::new(Metadata::Localizations).json())
                     .col(ColumnDef::new(Metadata::Description).text())
                     .col(
                         ColumnDef::new(Metadata::ProductionStatus)
@@ -177,6 +215,13 @@ impl MigrationTrait for Migration {
                     .col(ColumnDef::new(Metadata::Images).json())
                     .col(ColumnDef::new(Metadata::Videos).json())
                     .col(ColumnDef::new(Metadata::IsNsfw).boolean().default(false))
+                    .col(
+                        ColumnDef::new(Metadata::IsDeletedOnProvider)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(ColumnDef::new(Metadata::Awards).json())
                     .col(ColumnDef::new(Metadata::Identifier).string().not_null())
                     .col(ColumnDef::new(Metadata::Source).string_len(2).not_null())
                     .col(ColumnDef::new(Metadata::Specifics).json().not_null())
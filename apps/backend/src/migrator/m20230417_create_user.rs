@@ -23,6 +23,8 @@ pub enum UserToMetadata {
     LastUpdatedOn,
     Monitored,
     Reminder,
+    /// The total time in minutes that the user played a game for.
+    Playtime,
 }
 
 #[derive(
@@ -51,6 +53,10 @@ pub enum User {
     SinkIntegrations,
     Notifications,
     Summary,
+    // The workout that is currently being recorded by the user, if any
+    InProgressWorkout,
+    // Whether the user has to verify their email before they can login
+    EmailVerificationPending,
 }
 
 #[async_trait::async_trait]
@@ -76,6 +82,7 @@ impl MigrationTrait for Migration {
                     .col(ColumnDef::new(User::SinkIntegrations).json())
                     .col(ColumnDef::new(User::Notifications).json())
                     .col(ColumnDef::new(User::Summary).json())
+                    .col(ColumnDef::new(User::InProgressWorkout).json())
                     .to_owned(),
             )
             .await?;
@@ -2,6 +2,7 @@ use async_graphql::Enum;
 use sea_orm::{DeriveActiveEnum, EnumIter};
 use sea_orm_migration::prelude::*;
 use serde::{Deserialize, Serialize};
+use specta::Type;
 
 use crate::migrator::{m20230417_create_user::User, Metadata};
 
@@ -10,7 +11,17 @@ pub struct Migration;
 
 // The different possible states of a seen item.
 #[derive(
-    Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize, Enum,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    Deserialize,
+    Serialize,
+    Enum,
+    Type,
 )]
 #[sea_orm(rs_type = "String", db_type = "String(None)")]
 pub enum SeenState {
@@ -38,6 +49,14 @@ pub enum Seen {
     // for the time being this stores the `season` and `episode` numbers
     ExtraInformation,
     NumTimesUpdated,
+    /// The rating given at the time the media was seen, out of 100.
+    Rating,
+    /// Where or how the media was seen, eg: `Cinema` or `Ebook`.
+    Context,
+    /// The seen item that this one is likely a duplicate of.
+    DuplicateOf,
+    /// Whether the user marked this item as not being a duplicate.
+    DuplicateDismissed,
 }
 
 #[async_trait::async_trait]
@@ -77,6 +96,7 @@ impl MigrationTrait for Migration {
                             .not_null(),
                     )
                     .col(ColumnDef::new(Seen::ExtraInformation).json())
+                    .col(ColumnDef::new(Seen::Rating).decimal())
                     .foreign_key(
                         ForeignKey::create()
                             .name("user_to_seen_foreign_key")
@@ -24,6 +24,8 @@ pub enum Collection {
     UserId,
     Description,
     Visibility,
+    /// The actions that are run when a media is added to this collection.
+    Actions,
 }
 
 #[async_trait::async_trait]
@@ -55,6 +57,7 @@ impl MigrationTrait for Migration {
                             .not_null()
                             .default(Visibility::Private),
                     )
+                    .col(ColumnDef::new(Collection::Actions).json())
                     .foreign_key(
                         ForeignKey::create()
                             .name("collection_to_user_foreign_key")
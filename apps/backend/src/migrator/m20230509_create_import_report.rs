@@ -32,12 +32,30 @@ pub enum ImportSource {
     MediaTracker,
     #[sea_orm(string_value = "GO")]
     Goodreads,
+    #[sea_orm(string_value = "GX")]
+    GoodreadsCsv,
     #[sea_orm(string_value = "TR")]
     Trakt,
     #[sea_orm(string_value = "MO")]
     Movary,
+    #[sea_orm(string_value = "LB")]
+    Letterboxd,
+    #[sea_orm(string_value = "TV")]
+    TvTime,
+    #[sea_orm(string_value = "CS")]
+    GenericCsv,
     #[sea_orm(string_value = "ST")]
     StoryGraph,
+    #[sea_orm(string_value = "GC")]
+    GarminConnect,
+    #[sea_orm(string_value = "RY")]
+    Ryot,
+    #[sea_orm(string_value = "KI")]
+    Kindle,
+    #[sea_orm(string_value = "PS")]
+    Playstation,
+    #[sea_orm(string_value = "XB")]
+    Xbox,
 }
 
 #[derive(Iden)]
@@ -50,6 +68,8 @@ pub enum ImportReport {
     Source,
     Details,
     Success,
+    Input,
+    Checkpoint,
 }
 
 #[async_trait::async_trait]
@@ -137,6 +137,30 @@ pub enum ExerciseLot {
     RepsAndWeight,
 }
 
+#[derive(
+    Clone,
+    Debug,
+    Deserialize,
+    Serialize,
+    DeriveActiveEnum,
+    Eq,
+    PartialEq,
+    Enum,
+    Copy,
+    EnumIter,
+    Default,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
+pub enum ExerciseSource {
+    #[sea_orm(string_value = "CU")]
+    Custom,
+    #[default]
+    #[sea_orm(string_value = "GH")]
+    Github,
+    #[sea_orm(string_value = "WG")]
+    Wger,
+}
+
 #[derive(Iden)]
 pub enum Exercise {
     Table,
@@ -150,6 +174,12 @@ pub enum Exercise {
     Muscles,
     Identifier,
     Attributes,
+    // where this exercise came from, either the bundled dataset or a user
+    Source,
+    // the user who created this exercise, only set for custom exercises
+    CreatedByUserId,
+    // the identifier of this exercise in an external exercise database
+    ExternalId,
 }
 
 #[async_trait::async_trait]
@@ -185,6 +215,14 @@ impl MigrationTrait for Migration {
                             .not_null(),
                     )
                     .col(ColumnDef::new(Exercise::Attributes).json().not_null())
+                    .col(
+                        ColumnDef::new(Exercise::Source)
+                            .string_len(2)
+                            .not_null()
+                            .default("GH"),
+                    )
+                    .col(ColumnDef::new(Exercise::CreatedByUserId).integer().null())
+                    .col(ColumnDef::new(Exercise::ExternalId).string().null())
                     .to_owned(),
             )
             .await?;
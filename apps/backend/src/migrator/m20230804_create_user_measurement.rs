@@ -15,6 +15,8 @@ pub enum UserMeasurement {
     Name,
     Comment,
     Stats,
+    /// The keys of the progress photos uploaded to the file storage.
+    Photos,
 }
 
 #[async_trait::async_trait]
@@ -39,6 +41,7 @@ impl MigrationTrait for Migration {
                             .col(UserMeasurement::Timestamp),
                     )
                     .col(ColumnDef::new(UserMeasurement::Stats).json().not_null())
+                    .col(ColumnDef::new(UserMeasurement::Photos).json())
                     .foreign_key(
                         ForeignKey::create()
                             .name("fk-user_measurement-user_id")
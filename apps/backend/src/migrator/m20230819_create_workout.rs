@@ -20,6 +20,8 @@ pub enum Workout {
     EndTime,
     /// General information like total weights lifted, number of records etc.
     Summary,
+    /// The identifier of the workout in the service it was synced from.
+    ExternalId,
     /// Actual exercises performed, supersets, etc.
     Information,
 }
@@ -66,6 +68,7 @@ impl MigrationTrait for Migration {
                     .col(ColumnDef::new(Workout::Information).json().not_null())
                     .col(ColumnDef::new(Workout::Name).string().null())
                     .col(ColumnDef::new(Workout::Comment).string().null())
+                    .col(ColumnDef::new(Workout::ExternalId).string().null())
                     .to_owned(),
             )
             .await?;
@@ -0,0 +1,51 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveValue, DeriveEntityModel, EntityTrait};
+use sea_orm_migration::prelude::*;
+
+use super::Metadata;
+use crate::utils::normalize_text;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "metadata")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: i32,
+    title: String,
+    normalized_title: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !manager.has_column("metadata", "normalized_title").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Metadata::Table)
+                        .add_column(ColumnDef::new(Metadata::NormalizedTitle).string())
+                        .to_owned(),
+                )
+                .await?;
+            let db = manager.get_connection();
+            for metadata in Entity::find().all(db).await? {
+                let normalized_title = normalize_text(&metadata.title);
+                let mut metadata: ActiveModel = metadata.into();
+                metadata.normalized_title = ActiveValue::Set(Some(normalized_title));
+                metadata.update(db).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveValue, DeriveEntityModel, EntityTrait};
+use sea_orm_migration::prelude::*;
+use serde_json::json;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "user")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    id: i32,
+    preferences: Json,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        for user in Entity::find().all(db).await? {
+            let mut preferences = user.preferences.clone();
+            let display_nsfw = preferences
+                .get_mut("general")
+                .and_then(|g| g.as_object_mut())
+                .and_then(|g| g.remove("display_nsfw"));
+            if let Some(display_nsfw) = display_nsfw {
+                let nsfw_display = match display_nsfw.as_bool() {
+                    Some(true) => "Show",
+                    _ => "Hide",
+                };
+                preferences["general"]["nsfw_display"] = json!(nsfw_display);
+                let mut user: ActiveModel = user.into();
+                user.preferences = ActiveValue::Set(preferences);
+                user.update(db).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20230622_create_exercise::Exercise;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !manager.has_column("exercise", "source").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Exercise::Table)
+                        .add_column(
+                            ColumnDef::new(Exercise::Source)
+                                .string_len(2)
+                                .not_null()
+                                .default("GH"),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+        }
+        if !manager.has_column("exercise", "created_by_user_id").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Exercise::Table)
+                        .add_column(ColumnDef::new(Exercise::CreatedByUserId).integer().null())
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
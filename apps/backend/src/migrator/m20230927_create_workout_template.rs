@@ -0,0 +1,66 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20230417_create_user::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum WorkoutTemplate {
+    Table,
+    /// Generated using sonyflake, same as workouts.
+    Id,
+    UserId,
+    Name,
+    CreatedOn,
+    /// The last time a workout was started from this template.
+    LastUsedOn,
+    /// The exercises with their target sets, supersets, etc.
+    Information,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WorkoutTemplate::Table)
+                    .col(
+                        ColumnDef::new(WorkoutTemplate::Id)
+                            .primary_key()
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(WorkoutTemplate::UserId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("workout_template_to_user_foreign_key")
+                            .from(WorkoutTemplate::Table, WorkoutTemplate::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(WorkoutTemplate::Name).string().not_null())
+                    .col(
+                        ColumnDef::new(WorkoutTemplate::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(WorkoutTemplate::LastUsedOn).timestamp_with_time_zone())
+                    .col(
+                        ColumnDef::new(WorkoutTemplate::Information)
+                            .json()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
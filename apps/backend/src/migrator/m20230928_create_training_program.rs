@@ -0,0 +1,73 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20230417_create_user::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum TrainingProgram {
+    Table,
+    /// Generated using sonyflake, same as workouts.
+    Id,
+    UserId,
+    Name,
+    /// The day on which the first cycle of the program starts.
+    StartDate,
+    CreatedOn,
+    /// The templates scheduled on each day of a cycle and the progression rules.
+    Information,
+    /// The scheduled workouts that have been completed.
+    Completions,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TrainingProgram::Table)
+                    .col(
+                        ColumnDef::new(TrainingProgram::Id)
+                            .primary_key()
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(TrainingProgram::UserId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("training_program_to_user_foreign_key")
+                            .from(TrainingProgram::Table, TrainingProgram::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(TrainingProgram::Name).string().not_null())
+                    .col(ColumnDef::new(TrainingProgram::StartDate).date().not_null())
+                    .col(
+                        ColumnDef::new(TrainingProgram::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(TrainingProgram::Information)
+                            .json()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(TrainingProgram::Completions)
+                            .json()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use itertools::Itertools;
+use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveValue, DeriveEntityModel, EntityTrait};
+use sea_orm_migration::prelude::*;
+
+use crate::models::fitness::{ExerciseGroupLot, ProcessedExerciseGroup, TotalMeasurement};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "workout")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    id: String,
+    information: Json,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        for workout in Entity::find().all(db).await? {
+            let mut information = workout.information.clone();
+            let supersets: Vec<Vec<u16>> = match information.get("supersets") {
+                Some(s) => serde_json::from_value(s.clone()).unwrap_or_default(),
+                None => continue,
+            };
+            let totals: Vec<TotalMeasurement> = information
+                .get("exercises")
+                .and_then(|e| e.as_array())
+                .map(|exercises| {
+                    exercises
+                        .iter()
+                        .map(|e| serde_json::from_value(e["total"].clone()).unwrap_or_default())
+                        .collect()
+                })
+                .unwrap_or_default();
+            let groups = supersets
+                .into_iter()
+                .map(|exercises| ProcessedExerciseGroup {
+                    total: exercises
+                        .iter()
+                        .filter_map(|idx| totals.get(usize::from(*idx)).cloned())
+                        .sum(),
+                    lot: ExerciseGroupLot::Superset,
+                    exercises,
+                    rest_time: None,
+                })
+                .collect_vec();
+            if let Some(obj) = information.as_object_mut() {
+                obj.remove("supersets");
+                obj.insert("groups".to_owned(), serde_json::to_value(groups).unwrap());
+            }
+            let mut workout: ActiveModel = workout.into();
+            workout.information = ActiveValue::Set(information);
+            workout.update(db).await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use sea_orm::entity::prelude::*;
+use sea_orm::{DeriveEntityModel, EntityTrait};
+use sea_orm_migration::prelude::*;
+
+use super::{
+    m20230417_create_user::User, m20230622_create_exercise::Exercise,
+    m20230819_create_workout::Workout,
+};
+use crate::{entities::workout_set, models::fitness::ProcessedExercise};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum WorkoutSet {
+    Table,
+    Id,
+    WorkoutId,
+    UserId,
+    ExerciseId,
+    /// The index of the exercise in the workout.
+    ExerciseIdx,
+    SetIdx,
+    /// The date the workout was started on.
+    PerformedOn,
+    /// The monday of the week the workout was performed in, used to group
+    /// sets by week without needing database specific date functions.
+    WeekStart,
+    Reps,
+    Weight,
+    OneRm,
+    Volume,
+    Duration,
+    Distance,
+    AverageHeartRate,
+    ElevationGain,
+}
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "workout")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    id: String,
+    user_id: i32,
+    start_time: DateTimeUtc,
+    information: Json,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(WorkoutSet::Table)
+                    .col(
+                        ColumnDef::new(WorkoutSet::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(WorkoutSet::WorkoutId).string().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("workout_set_to_workout_foreign_key")
+                            .from(WorkoutSet::Table, WorkoutSet::WorkoutId)
+                            .to(Workout::Table, Workout::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(WorkoutSet::UserId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("workout_set_to_user_foreign_key")
+                            .from(WorkoutSet::Table, WorkoutSet::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(WorkoutSet::ExerciseId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("workout_set_to_exercise_foreign_key")
+                            .from(WorkoutSet::Table, WorkoutSet::ExerciseId)
+                            .to(Exercise::Table, Exercise::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(WorkoutSet::ExerciseIdx).integer().not_null())
+                    .col(ColumnDef::new(WorkoutSet::SetIdx).integer().not_null())
+                    .col(ColumnDef::new(WorkoutSet::PerformedOn).date().not_null())
+                    .col(ColumnDef::new(WorkoutSet::WeekStart).date().not_null())
+                    .col(ColumnDef::new(WorkoutSet::Reps).integer())
+                    .col(ColumnDef::new(WorkoutSet::Weight).decimal())
+                    .col(ColumnDef::new(WorkoutSet::OneRm).decimal())
+                    .col(ColumnDef::new(WorkoutSet::Volume).decimal())
+                    .col(ColumnDef::new(WorkoutSet::Duration).decimal())
+                    .col(ColumnDef::new(WorkoutSet::Distance).decimal())
+                    .col(ColumnDef::new(WorkoutSet::AverageHeartRate).integer())
+                    .col(ColumnDef::new(WorkoutSet::ElevationGain).decimal())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("workout_set__user_id__exercise_id__index")
+                    .table(WorkoutSet::Table)
+                    .col(WorkoutSet::UserId)
+                    .col(WorkoutSet::ExerciseId)
+                    .to_owned(),
+            )
+            .await?;
+        let db = manager.get_connection();
+        for workout in Entity::find().all(db).await? {
+            let exercises: Vec<ProcessedExercise> =
+                match serde_json::from_value(workout.information["exercises"].clone()) {
+                    Ok(e) => e,
+                    Err(_) => continue,
+                };
+            let rows = workout_set::ActiveModel::from_workout(
+                &workout.id,
+                workout.user_id,
+                workout.start_time,
+                &exercises,
+            );
+            if !rows.is_empty() {
+                workout_set::Entity::insert_many(rows).exec(db).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20230804_create_user_measurement::UserMeasurement;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !manager.has_column("user_measurement", "photos").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(UserMeasurement::Table)
+                        .add_column(ColumnDef::new(UserMeasurement::Photos).json())
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
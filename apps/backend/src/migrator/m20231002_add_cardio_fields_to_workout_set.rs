@@ -0,0 +1,40 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20230930_create_workout_set::WorkoutSet;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !manager
+            .has_column("workout_set", "average_heart_rate")
+            .await?
+        {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(WorkoutSet::Table)
+                        .add_column(ColumnDef::new(WorkoutSet::AverageHeartRate).integer())
+                        .to_owned(),
+                )
+                .await?;
+        }
+        if !manager.has_column("workout_set", "elevation_gain").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(WorkoutSet::Table)
+                        .add_column(ColumnDef::new(WorkoutSet::ElevationGain).decimal())
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
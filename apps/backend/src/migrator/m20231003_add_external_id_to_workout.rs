@@ -0,0 +1,27 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20230819_create_workout::Workout;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !manager.has_column("workout", "external_id").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Workout::Table)
+                        .add_column(ColumnDef::new(Workout::ExternalId).string().null())
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20230417_create_user::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum SharedWorkout {
+    Table,
+    /// The random token used to access the share.
+    Id,
+    /// The user who shared the workout.
+    UserId,
+    /// If set, only this user can access the share. Otherwise anyone with the
+    /// token can.
+    SharedWithUserId,
+    Name,
+    CreatedOn,
+    /// A copy of the exercises at the time the workout was shared.
+    Information,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SharedWorkout::Table)
+                    .col(
+                        ColumnDef::new(SharedWorkout::Id)
+                            .primary_key()
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SharedWorkout::UserId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("shared_workout_to_user_foreign_key")
+                            .from(SharedWorkout::Table, SharedWorkout::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(SharedWorkout::SharedWithUserId).integer())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("shared_workout_to_shared_with_user_foreign_key")
+                            .from(SharedWorkout::Table, SharedWorkout::SharedWithUserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(SharedWorkout::Name).string().not_null())
+                    .col(
+                        ColumnDef::new(SharedWorkout::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SharedWorkout::Information).json().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20230507_create_collection::Collection;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !manager.has_column("collection", "actions").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Collection::Table)
+                        .add_column(ColumnDef::new(Collection::Actions).json().null())
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20230419_create_seen::Seen;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum SeenAttachment {
+    Table,
+    Id,
+    SeenId,
+    /// The key of the file in the file storage.
+    Key,
+    CreatedOn,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SeenAttachment::Table)
+                    .col(
+                        ColumnDef::new(SeenAttachment::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SeenAttachment::SeenId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("seen_attachment_to_seen_foreign_key")
+                            .from(SeenAttachment::Table, SeenAttachment::SeenId)
+                            .to(Seen::Table, Seen::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(SeenAttachment::Key).string().not_null())
+                    .col(
+                        ColumnDef::new(SeenAttachment::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use sea_orm_migration::prelude::*;
+
+use super::{m20230417_create_user::User, m20230419_create_seen::Seen};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum SeenCompanion {
+    Table,
+    Id,
+    SeenId,
+    /// The name shown for the companion. The username if they are a user of
+    /// this instance.
+    Name,
+    /// The user of this instance that was tagged, if any.
+    UserId,
+    /// The copy of the seen item that was created for the tagged user once they
+    /// confirmed it.
+    MirroredSeenId,
+    CreatedOn,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SeenCompanion::Table)
+                    .col(
+                        ColumnDef::new(SeenCompanion::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SeenCompanion::SeenId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("seen_companion_to_seen_foreign_key")
+                            .from(SeenCompanion::Table, SeenCompanion::SeenId)
+                            .to(Seen::Table, Seen::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(SeenCompanion::Name).string().not_null())
+                    .col(ColumnDef::new(SeenCompanion::UserId).integer())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("seen_companion_to_user_foreign_key")
+                            .from(SeenCompanion::Table, SeenCompanion::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(SeenCompanion::MirroredSeenId).integer())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("seen_companion_to_mirrored_seen_foreign_key")
+                            .from(SeenCompanion::Table, SeenCompanion::MirroredSeenId)
+                            .to(Seen::Table, Seen::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(
+                        ColumnDef::new(SeenCompanion::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
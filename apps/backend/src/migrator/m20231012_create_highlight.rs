@@ -0,0 +1,84 @@
+use sea_orm_migration::prelude::*;
+
+use super::{m20230410_create_metadata::Metadata, m20230417_create_user::User};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum Highlight {
+    Table,
+    Id,
+    UserId,
+    MetadataId,
+    /// The passage that was highlighted.
+    Text,
+    Page,
+    Chapter,
+    /// The note that the user wrote about the passage.
+    Note,
+    CreatedOn,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Highlight::Table)
+                    .col(
+                        ColumnDef::new(Highlight::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Highlight::UserId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("highlight_to_user_foreign_key")
+                            .from(Highlight::Table, Highlight::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Highlight::MetadataId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("highlight_to_metadata_foreign_key")
+                            .from(Highlight::Table, Highlight::MetadataId)
+                            .to(Metadata::Table, Metadata::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(Highlight::Text).text().not_null())
+                    .col(ColumnDef::new(Highlight::Page).integer())
+                    .col(ColumnDef::new(Highlight::Chapter).string())
+                    .col(ColumnDef::new(Highlight::Note).text())
+                    .col(
+                        ColumnDef::new(Highlight::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("highlight__user_id__metadata_id__index")
+                    .table(Highlight::Table)
+                    .col(Highlight::UserId)
+                    .col(Highlight::MetadataId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
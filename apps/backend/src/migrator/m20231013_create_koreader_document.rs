@@ -0,0 +1,108 @@
+use sea_orm_migration::prelude::*;
+
+use super::{m20230410_create_metadata::Metadata, m20230417_create_user::User};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum KoreaderDocument {
+    Table,
+    Id,
+    UserId,
+    /// The hash that KOReader uses to identify the document.
+    Document,
+    /// The book that the document is linked to, if it has been matched yet.
+    MetadataId,
+    /// The position in the document, in the format used by KOReader.
+    Progress,
+    Percentage,
+    Device,
+    DeviceId,
+    UpdatedOn,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(KoreaderDocument::Table)
+                    .col(
+                        ColumnDef::new(KoreaderDocument::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(KoreaderDocument::UserId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("koreader_document_to_user_foreign_key")
+                            .from(KoreaderDocument::Table, KoreaderDocument::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(
+                        ColumnDef::new(KoreaderDocument::Document)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(KoreaderDocument::MetadataId).integer())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("koreader_document_to_metadata_foreign_key")
+                            .from(KoreaderDocument::Table, KoreaderDocument::MetadataId)
+                            .to(Metadata::Table, Metadata::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(
+                        ColumnDef::new(KoreaderDocument::Progress)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(KoreaderDocument::Percentage)
+                            .decimal()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(KoreaderDocument::Device).string().not_null())
+                    .col(
+                        ColumnDef::new(KoreaderDocument::DeviceId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(KoreaderDocument::UpdatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .unique()
+                    .name("koreader_document__user_id__document__index")
+                    .table(KoreaderDocument::Table)
+                    .col(KoreaderDocument::UserId)
+                    .col(KoreaderDocument::Document)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
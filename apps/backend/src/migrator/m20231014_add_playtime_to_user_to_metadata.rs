@@ -0,0 +1,27 @@
+use sea_orm_migration::prelude::*;
+
+use super::UserToMetadata;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !manager.has_column("user_to_metadata", "playtime").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(UserToMetadata::Table)
+                        .add_column(ColumnDef::new(UserToMetadata::Playtime).integer())
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use async_graphql::Enum;
+use sea_orm::{DeriveActiveEnum, EnumIter};
+use sea_orm_migration::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{m20230410_create_metadata::Metadata, m20230417_create_user::User};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// The details of a media item that can be corrected by the users.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize, Enum,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
+pub enum MetadataCorrectionField {
+    #[sea_orm(string_value = "DE")]
+    Description,
+    /// An episode of a show that is missing or has the wrong details.
+    #[sea_orm(string_value = "EP")]
+    Episode,
+    #[sea_orm(string_value = "IM")]
+    Image,
+    #[sea_orm(string_value = "PD")]
+    PublishDate,
+    #[sea_orm(string_value = "PY")]
+    PublishYear,
+    #[sea_orm(string_value = "TI")]
+    Title,
+}
+
+// The different states of a correction that was submitted by a user.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize, Enum,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
+pub enum MetadataCorrectionState {
+    #[sea_orm(string_value = "AP")]
+    Approved,
+    #[sea_orm(string_value = "PE")]
+    Pending,
+    #[sea_orm(string_value = "RE")]
+    Rejected,
+}
+
+#[derive(Iden)]
+pub enum MetadataCorrection {
+    Table,
+    Id,
+    UserId,
+    MetadataId,
+    Field,
+    /// The corrected value, `None` to clear the field.
+    Value,
+    /// Why the user thinks the details are wrong.
+    Comment,
+    State,
+    CreatedOn,
+    ReviewedOn,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(MetadataCorrection::Table)
+                    .col(
+                        ColumnDef::new(MetadataCorrection::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(MetadataCorrection::UserId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("metadata_correction_to_user_foreign_key")
+                            .from(MetadataCorrection::Table, MetadataCorrection::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(
+                        ColumnDef::new(MetadataCorrection::MetadataId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("metadata_correction_to_metadata_foreign_key")
+                            .from(MetadataCorrection::Table, MetadataCorrection::MetadataId)
+                            .to(Metadata::Table, Metadata::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(
+                        ColumnDef::new(MetadataCorrection::Field)
+                            .string_len(2)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(MetadataCorrection::Value).text())
+                    .col(ColumnDef::new(MetadataCorrection::Comment).text())
+                    .col(
+                        ColumnDef::new(MetadataCorrection::State)
+                            .string_len(2)
+                            .not_null()
+                            .default(MetadataCorrectionState::Pending),
+                    )
+                    .col(
+                        ColumnDef::new(MetadataCorrection::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(MetadataCorrection::ReviewedOn).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
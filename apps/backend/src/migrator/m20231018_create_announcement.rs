@@ -0,0 +1,72 @@
+use async_graphql::Enum;
+use sea_orm::{DeriveActiveEnum, EnumIter};
+use sea_orm_migration::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// How prominently an announcement is displayed to the users.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize, Enum,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
+pub enum AnnouncementSeverity {
+    #[sea_orm(string_value = "CR")]
+    Critical,
+    #[sea_orm(string_value = "IN")]
+    Info,
+    #[sea_orm(string_value = "WA")]
+    Warning,
+}
+
+#[derive(Iden)]
+pub enum Announcement {
+    Table,
+    Id,
+    Message,
+    Severity,
+    CreatedOn,
+    /// The time after which the announcement is no longer displayed, `None` to
+    /// display it until it is deleted.
+    ExpiresOn,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Announcement::Table)
+                    .col(
+                        ColumnDef::new(Announcement::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Announcement::Message).text().not_null())
+                    .col(
+                        ColumnDef::new(Announcement::Severity)
+                            .string_len(2)
+                            .not_null()
+                            .default(AnnouncementSeverity::Info),
+                    )
+                    .col(
+                        ColumnDef::new(Announcement::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(Announcement::ExpiresOn).timestamp_with_time_zone())
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
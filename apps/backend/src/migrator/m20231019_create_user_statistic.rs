@@ -0,0 +1,97 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20230417_create_user::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// The number of media seen and reviews posted by a user in a month, kept up to
+/// date as they are written so that the statistics do not have to be computed
+/// from all the seen items. The rows are rebuilt whenever the summary of the
+/// user is calculated.
+#[derive(Iden)]
+pub enum UserStatistic {
+    Table,
+    Id,
+    UserId,
+    Lot,
+    /// The first day of the month.
+    Month,
+    SeenCount,
+    ReviewCount,
+    /// The sum of the ratings of the reviews, out of 100.
+    RatingSum,
+    RatingCount,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserStatistic::Table)
+                    .col(
+                        ColumnDef::new(UserStatistic::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(UserStatistic::UserId).integer().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("user_statistic_to_user_foreign_key")
+                            .from(UserStatistic::Table, UserStatistic::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(UserStatistic::Lot).string_len(2).not_null())
+                    .col(ColumnDef::new(UserStatistic::Month).date().not_null())
+                    .col(
+                        ColumnDef::new(UserStatistic::SeenCount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(UserStatistic::ReviewCount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(UserStatistic::RatingSum)
+                            .decimal()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(UserStatistic::RatingCount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .unique()
+                    .name("user_statistic__user_id__lot__month__index")
+                    .table(UserStatistic::Table)
+                    .col(UserStatistic::UserId)
+                    .col(UserStatistic::Lot)
+                    .col(UserStatistic::Month)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// The settings of the instance that are changed at runtime. The table has at
+/// most one row.
+#[derive(Iden)]
+pub enum InstanceSetting {
+    Table,
+    Id,
+    /// The preferences that newly created users start with.
+    UserPreferencesTemplate,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(InstanceSetting::Table)
+                    .col(
+                        ColumnDef::new(InstanceSetting::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(InstanceSetting::UserPreferencesTemplate).json())
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
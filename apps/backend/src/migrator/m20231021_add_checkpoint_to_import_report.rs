@@ -0,0 +1,37 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20230509_create_import_report::ImportReport;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !manager.has_column("import_report", "input").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(ImportReport::Table)
+                        .add_column(ColumnDef::new(ImportReport::Input).json())
+                        .to_owned(),
+                )
+                .await?;
+        }
+        if !manager.has_column("import_report", "checkpoint").await? {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(ImportReport::Table)
+                        .add_column(ColumnDef::new(ImportReport::Checkpoint).json())
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
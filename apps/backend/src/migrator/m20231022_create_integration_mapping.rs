@@ -0,0 +1,97 @@
+use sea_orm_migration::prelude::*;
+
+use super::{m20230410_create_metadata::Metadata, m20230417_create_user::User};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[derive(Iden)]
+pub enum IntegrationMapping {
+    Table,
+    Id,
+    UserId,
+    Lot,
+    /// The title or the ID that the integration identified the item with.
+    ExternalId,
+    /// The media that the item is mapped to, if it has been mapped yet.
+    MetadataId,
+    /// Why the item could not be matched the last time it was received.
+    Error,
+    UpdatedOn,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(IntegrationMapping::Table)
+                    .col(
+                        ColumnDef::new(IntegrationMapping::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(IntegrationMapping::UserId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("integration_mapping_to_user_foreign_key")
+                            .from(IntegrationMapping::Table, IntegrationMapping::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(
+                        ColumnDef::new(IntegrationMapping::Lot)
+                            .string_len(2)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(IntegrationMapping::ExternalId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(IntegrationMapping::MetadataId).integer())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("integration_mapping_to_metadata_foreign_key")
+                            .from(IntegrationMapping::Table, IntegrationMapping::MetadataId)
+                            .to(Metadata::Table, Metadata::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(IntegrationMapping::Error).text())
+                    .col(
+                        ColumnDef::new(IntegrationMapping::UpdatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .unique()
+                    .name("integration_mapping__user_id__lot__external_id__index")
+                    .table(IntegrationMapping::Table)
+                    .col(IntegrationMapping::UserId)
+                    .col(IntegrationMapping::Lot)
+                    .col(IntegrationMapping::ExternalId)
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use async_graphql::Enum;
+use sea_orm::{DeriveActiveEnum, EnumIter};
+use sea_orm_migration::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::m20230417_create_user::User;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// What happened in an entry of the audit log.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize, Enum,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(None)")]
+pub enum AuditLogEvent {
+    #[sea_orm(string_value = "FL")]
+    FailedLogin,
+    #[sea_orm(string_value = "LO")]
+    Lockout,
+    #[sea_orm(string_value = "ND")]
+    NsfwDisplayChanged,
+}
+
+#[derive(Iden)]
+pub enum AuditLog {
+    Table,
+    Id,
+    Event,
+    /// The user that the event is about, if it is about one that exists.
+    UserId,
+    /// The IP address that the request came from.
+    Ip,
+    /// What the event is about, eg: the username of a failed login.
+    Details,
+    CreatedOn,
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuditLog::Table)
+                    .col(
+                        ColumnDef::new(AuditLog::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AuditLog::Event).string_len(2).not_null())
+                    .col(ColumnDef::new(AuditLog::UserId).integer())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("audit_log_to_user_foreign_key")
+                            .from(AuditLog::Table, AuditLog::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .col(ColumnDef::new(AuditLog::Ip).string())
+                    .col(ColumnDef::new(AuditLog::Details).text())
+                    .col(
+                        ColumnDef::new(AuditLog::CreatedOn)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        Ok(())
+    }
+}
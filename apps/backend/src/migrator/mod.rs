@@ -28,6 +28,41 @@ mod m20230912_add_last_processed_for_calendar_to_metadata;
 mod m20230912_create_calendar_event;
 mod m20230919_add_num_times_updated_field_to_seen;
 mod m20230919_change_foreign_keys;
+mod m20230920_add_normalized_title_to_metadata;
+mod m20230921_add_alternative_titles_to_metadata;
+mod m20230922_add_language_to_metadata;
+mod m20230923_change_display_nsfw_preference;
+mod m20230924_add_is_deleted_on_provider_to_metadata;
+mod m20230925_add_awards_to_metadata;
+mod m20230926_add_custom_fields_to_exercise;
+mod m20230927_create_workout_template;
+mod m20230928_create_training_program;
+mod m20230929_change_supersets_to_exercise_groups;
+mod m20230930_create_workout_set;
+mod m20231001_add_photos_to_user_measurement;
+mod m20231002_add_cardio_fields_to_workout_set;
+mod m20231003_add_external_id_to_workout;
+mod m20231004_add_external_id_to_exercise;
+mod m20231005_add_in_progress_workout_to_user;
+mod m20231006_create_shared_workout;
+mod m20231007_add_rating_to_seen;
+mod m20231008_add_actions_to_collection;
+mod m20231009_create_seen_attachment;
+mod m20231010_add_context_to_seen;
+mod m20231011_create_seen_companion;
+mod m20231012_create_highlight;
+mod m20231013_create_koreader_document;
+mod m20231014_add_playtime_to_user_to_metadata;
+mod m20231015_create_metadata_correction;
+mod m20231016_add_duplicate_of_to_seen;
+mod m20231017_add_email_verification_pending_to_user;
+mod m20231018_create_announcement;
+mod m20231019_create_user_statistic;
+mod m20231020_create_instance_setting;
+mod m20231021_add_checkpoint_to_import_report;
+mod m20231022_create_integration_mapping;
+mod m20231023_create_audit_log;
+mod m20231024_add_localizations_to_metadata;
 
 pub use m20230410_create_metadata::{Metadata, MetadataLot, MetadataSource};
 pub use m20230417_create_user::{UserLot, UserToMetadata};
@@ -36,8 +71,12 @@ pub use m20230505_create_review::Review;
 pub use m20230509_create_import_report::ImportSource;
 pub use m20230622_create_exercise::{
     ExerciseEquipment, ExerciseForce, ExerciseLevel, ExerciseLot, ExerciseMechanic, ExerciseMuscle,
+    ExerciseSource,
 };
 pub use m20230901_create_partial_metadata::MetadataToPartialMetadataRelation;
+pub use m20231015_create_metadata_correction::{MetadataCorrectionField, MetadataCorrectionState};
+pub use m20231018_create_announcement::AnnouncementSeverity;
+pub use m20231023_create_audit_log::AuditLogEvent;
 
 pub struct Migrator;
 
@@ -72,6 +111,41 @@ impl MigratorTrait for Migrator {
             Box::new(m20230912_create_calendar_event::Migration),
             Box::new(m20230919_add_num_times_updated_field_to_seen::Migration),
             Box::new(m20230919_change_foreign_keys::Migration),
+            Box::new(m20230920_add_normalized_title_to_metadata::Migration),
+            Box::new(m20230921_add_alternative_titles_to_metadata::Migration),
+            Box::new(m20230922_add_language_to_metadata::Migration),
+            Box::new(m20230923_change_display_nsfw_preference::Migration),
+            Box::new(m20230924_add_is_deleted_on_provider_to_metadata::Migration),
+            Box::new(m20230925_add_awards_to_metadata::Migration),
+            Box::new(m20230926_add_custom_fields_to_exercise::Migration),
+            Box::new(m20230927_create_workout_template::Migration),
+            Box::new(m20230928_create_training_program::Migration),
+            Box::new(m20230929_change_supersets_to_exercise_groups::Migration),
+            Box::new(m20230930_create_workout_set::Migration),
+            Box::new(m20231001_add_photos_to_user_measurement::Migration),
+            Box::new(m20231002_add_cardio_fields_to_workout_set::Migration),
+            Box::new(m20231003_add_external_id_to_workout::Migration),
+            Box::new(m20231004_add_external_id_to_exercise::Migration),
+            Box::new(m20231005_add_in_progress_workout_to_user::Migration),
+            Box::new(m20231006_create_shared_workout::Migration),
+            Box::new(m20231007_add_rating_to_seen::Migration),
+            Box::new(m20231008_add_actions_to_collection::Migration),
+            Box::new(m20231009_create_seen_attachment::Migration),
+            Box::new(m20231010_add_context_to_seen::Migration),
+            Box::new(m20231011_create_seen_companion::Migration),
+            Box::new(m20231012_create_highlight::Migration),
+            Box::new(m20231013_create_koreader_document::Migration),
+            Box::new(m20231014_add_playtime_to_user_to_metadata::Migration),
+            Box::new(m20231015_create_metadata_correction::Migration),
+            Box::new(m20231016_add_duplicate_of_to_seen::Migration),
+            Box::new(m20231017_add_email_verification_pending_to_user::Migration),
+            Box::new(m20231018_create_announcement::Migration),
+            Box::new(m20231019_create_user_statistic::Migration),
+            Box::new(m20231020_create_instance_setting::Migration),
+            Box::new(m20231021_add_checkpoint_to_import_report::Migration),
+            Box::new(m20231022_create_integration_mapping::Migration),
+            Box::new(m20231023_create_audit_log::Migration),
+            Box::new(m20231024_add_localizations_to_metadata::Migration),
         ]
     }
 }
@@ -0,0 +1,304 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use chrono::NaiveDate;
+use csv::Writer;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::Serialize;
+
+use crate::{
+    migrator::{MetadataLot, MetadataSource, SeenState},
+    models::media::ImportOrExportItemHighlight,
+};
+
+/// A single seen entry of a media along with the review of the user.
+#[derive(Debug)]
+pub struct MediaCsvRow {
+    pub title: String,
+    pub publish_year: Option<i32>,
+    pub source: MetadataSource,
+    pub identifier: String,
+    pub started_on: Option<NaiveDate>,
+    pub finished_on: Option<NaiveDate>,
+    pub progress: i32,
+    pub show_season_number: Option<i32>,
+    pub show_episode_number: Option<i32>,
+    pub podcast_episode_number: Option<i32>,
+    /// The rating out of 100.
+    pub rating: Option<Decimal>,
+    pub review: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct LetterboxdRow {
+    #[serde(rename = "Title")]
+    title: String,
+    #[serde(rename = "Year")]
+    year: Option<i32>,
+    #[serde(rename = "tmdbID")]
+    tmdb_id: Option<String>,
+    #[serde(rename = "WatchedDate")]
+    watched_date: Option<NaiveDate>,
+    #[serde(rename = "Rating")]
+    rating: Option<Decimal>,
+    #[serde(rename = "Review")]
+    review: Option<String>,
+    #[serde(rename = "Rewatch")]
+    rewatch: bool,
+}
+
+/// The latest state of an anime or manga that was seen by the user.
+#[derive(Debug)]
+pub struct MalXmlItem {
+    pub identifier: String,
+    pub title: String,
+    /// The number of episodes or chapters of the media, if known.
+    pub total: Option<i32>,
+    /// The progress of the latest seen entry, in percent.
+    pub progress: i32,
+    /// The latest state, `None` if the media was never started.
+    pub state: Option<SeenState>,
+    pub started_on: Option<NaiveDate>,
+    pub finished_on: Option<NaiveDate>,
+    /// The number of times the media was completed.
+    pub times_completed: usize,
+    /// The rating out of 100.
+    pub rating: Option<Decimal>,
+}
+
+/// The highlights that the user made in a single book.
+#[derive(Debug)]
+pub struct HighlightsMarkdownItem {
+    pub title: String,
+    pub highlights: Vec<ImportOrExportItemHighlight>,
+}
+
+#[derive(Debug, Serialize)]
+struct MalInfo {
+    user_export_type: u8,
+}
+
+#[derive(Debug, Serialize)]
+struct MalAnime {
+    series_animedb_id: String,
+    series_title: String,
+    series_episodes: i32,
+    my_watched_episodes: i32,
+    my_start_date: String,
+    my_finish_date: String,
+    my_score: Decimal,
+    my_status: String,
+    my_times_watched: usize,
+    update_on_import: u8,
+}
+
+#[derive(Debug, Serialize)]
+struct MalManga {
+    manga_mangadb_id: String,
+    manga_title: String,
+    manga_chapters: i32,
+    my_read_chapters: i32,
+    my_read_volumes: i32,
+    my_start_date: String,
+    my_finish_date: String,
+    my_score: Decimal,
+    my_status: String,
+    my_times_read: usize,
+    update_on_import: u8,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename = "myanimelist")]
+struct MalExport {
+    myinfo: MalInfo,
+    anime: Vec<MalAnime>,
+    manga: Vec<MalManga>,
+}
+
+fn rating_out_of_five(rating: Option<Decimal>) -> Option<Decimal> {
+    // DEV: Letterboxd and Goodreads rate out of 5, allowing halves
+    rating.map(|r| (r / dec!(10)).round() / dec!(2))
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// Convert the seen entries of a single type of media to a CSV file. The column
+/// names are the ones that Goodreads (for books) and Letterboxd or Trakt (for
+/// everything else) recognize when importing, so the file can be uploaded there
+/// as well as opened in a spreadsheet.
+pub fn media_to_csv(lot: MetadataLot, rows: Vec<MediaCsvRow>) -> Result<String> {
+    let (finished_header, rating_header, review_header) = match lot {
+        MetadataLot::Book | MetadataLot::AudioBook => ("Date Read", "My Rating", "My Review"),
+        _ => ("WatchedDate", "Rating", "Review"),
+    };
+    let mut writer = Writer::from_writer(vec![]);
+    writer.write_record([
+        "Title",
+        "Year",
+        "Source",
+        "Identifier",
+        "Date Started",
+        finished_header,
+        "Progress",
+        "Season",
+        "Episode",
+        rating_header,
+        "Rating10",
+        review_header,
+    ])?;
+    for row in rows {
+        let rating = rating_out_of_five(row.rating);
+        let rating_10 = row.rating.map(|r| (r / dec!(10)).round());
+        let episode = row.show_episode_number.or(row.podcast_episode_number);
+        writer.write_record([
+            row.title,
+            optional(row.publish_year),
+            format!("{:?}", row.source),
+            row.identifier,
+            optional(row.started_on),
+            optional(row.finished_on),
+            row.progress.to_string(),
+            optional(row.show_season_number),
+            optional(episode),
+            optional(rating),
+            optional(rating_10),
+            row.review.unwrap_or_default(),
+        ])?;
+    }
+    let data = writer.into_inner().map_err(|e| anyhow!(e.to_string()))?;
+    Ok(String::from_utf8(data)?)
+}
+
+/// Convert the movies of a user to the import format of Letterboxd. Rows with a
+/// finish date are diary entries and the rest are only marked as watched or
+/// rated.
+pub fn media_to_letterboxd_csv(rows: Vec<MediaCsvRow>) -> Result<String> {
+    let mut writer = Writer::from_writer(vec![]);
+    let mut watched = HashSet::new();
+    for row in rows {
+        let rewatch = row.finished_on.is_some() && !watched.insert(row.identifier.clone());
+        writer.serialize(LetterboxdRow {
+            title: row.title,
+            year: row.publish_year,
+            tmdb_id: match row.source {
+                MetadataSource::Tmdb => Some(row.identifier),
+                _ => None,
+            },
+            watched_date: row.finished_on,
+            rating: rating_out_of_five(row.rating),
+            review: row.review,
+            rewatch,
+        })?;
+    }
+    let data = writer.into_inner().map_err(|e| anyhow!(e.to_string()))?;
+    Ok(String::from_utf8(data)?)
+}
+
+/// Convert the anime or manga of a user to the XML format that MyAnimeList
+/// exports and accepts as an import. MyAnimeList only matches items by its own
+/// ids, so the items should be from that source.
+pub fn media_to_mal_xml(lot: MetadataLot, items: Vec<MalXmlItem>) -> Result<String> {
+    let mut export = MalExport {
+        myinfo: MalInfo {
+            user_export_type: match lot {
+                MetadataLot::Anime => 1,
+                MetadataLot::Manga => 2,
+                _ => return Err(anyhow!("Only anime and manga can be exported to MAL")),
+            },
+        },
+        anime: vec![],
+        manga: vec![],
+    };
+    for item in items {
+        let total = item.total.unwrap_or_default();
+        let done = match item.state {
+            Some(SeenState::Completed) => total,
+            _ => total * item.progress / 100,
+        };
+        let status = match (item.state, lot) {
+            (Some(SeenState::Completed), _) => "Completed",
+            (Some(SeenState::Dropped), _) => "Dropped",
+            (Some(SeenState::OnAHold), _) => "On-Hold",
+            (Some(SeenState::InProgress), MetadataLot::Anime) => "Watching",
+            (Some(SeenState::InProgress), _) => "Reading",
+            (None, MetadataLot::Anime) => "Plan to Watch",
+            (None, _) => "Plan to Read",
+        };
+        // DEV: MAL uses `0000-00-00` for missing dates and `0` for no score
+        let date = |d: Option<NaiveDate>| {
+            d.map(|d| d.to_string())
+                .unwrap_or_else(|| "0000-00-00".to_owned())
+        };
+        let score = item
+            .rating
+            .map(|r| (r / dec!(10)).round())
+            .unwrap_or_default();
+        let times = item.times_completed.saturating_sub(1);
+        match lot {
+            MetadataLot::Anime => export.anime.push(MalAnime {
+                series_animedb_id: item.identifier,
+                series_title: item.title,
+                series_episodes: total,
+                my_watched_episodes: done,
+                my_start_date: date(item.started_on),
+                my_finish_date: date(item.finished_on),
+                my_score: score,
+                my_status: status.to_owned(),
+                my_times_watched: times,
+                update_on_import: 1,
+            }),
+            _ => export.manga.push(MalManga {
+                manga_mangadb_id: item.identifier,
+                manga_title: item.title,
+                manga_chapters: total,
+                my_read_chapters: done,
+                my_read_volumes: 0,
+                my_start_date: date(item.started_on),
+                my_finish_date: date(item.finished_on),
+                my_score: score,
+                my_status: status.to_owned(),
+                my_times_read: times,
+                update_on_import: 1,
+            }),
+        }
+    }
+    let xml = quick_xml::se::to_string(&export)?;
+    Ok(format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\" ?>\n{}",
+        xml
+    ))
+}
+
+/// Convert the highlights of a user to a Markdown document with a section for
+/// every book. Each passage is a quote followed by where it is in the book and
+/// the note of the user.
+pub fn highlights_to_markdown(items: Vec<HighlightsMarkdownItem>) -> String {
+    let mut document = String::new();
+    for item in items {
+        document.push_str(&format!("# {}\n\n", item.title));
+        for highlight in item.highlights {
+            for line in highlight.text.lines() {
+                document.push_str(format!("> {}", line).trim_end());
+                document.push('\n');
+            }
+            let position = [
+                highlight.page.map(|p| format!("Page {}", p)),
+                highlight.chapter,
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+            if !position.is_empty() {
+                document.push_str(&format!("\n_{}_\n", position.join(", ")));
+            }
+            if let Some(note) = highlight.note {
+                document.push_str(&format!("\n{}\n", note));
+            }
+            document.push('\n');
+        }
+    }
+    document
+}
@@ -3,11 +3,13 @@ use strum::{Display, EnumIter};
 
 use crate::traits::MediaProviderLanguages;
 
+pub mod export;
 pub mod resolver;
 
 #[derive(Display, EnumIter)]
 pub enum DefaultCollection {
     Custom,
+    Favorites,
     #[strum(serialize = "In Progress")]
     InProgress,
     Watchlist,
@@ -16,6 +18,7 @@ pub enum DefaultCollection {
 meta! {
     DefaultCollection, &'static str;
     Custom, "Items that I have created manually.";
+    Favorites, "Media items that I love.";
     InProgress, "Media items that I am currently watching.";
     Watchlist, "Things I want to watch in the future.";
 }
@@ -1,33 +1,37 @@
 use std::{
     collections::{HashMap, HashSet},
     iter::zip,
+    net::IpAddr,
     str::FromStr,
-    sync::{Arc, OnceLock},
+    sync::{Arc, Mutex, OnceLock},
+    time::Instant,
 };
 
 use anyhow::anyhow;
 use apalis::{prelude::Storage as ApalisStorage, sqlite::SqliteStorage};
-use argon2::{Argon2, PasswordHash, PasswordVerifier};
 use async_graphql::{
-    Context, Enum, Error, InputObject, Object, OneofObject, Result, SimpleObject, Union,
+    Context, Enum, Error, InputObject, InputType, Object, OneofObject, Result, SimpleObject, Union,
+    Value,
 };
-use chrono::{Days, Duration as ChronoDuration, NaiveDate, Utc};
+use chrono::{Datelike, Days, Duration as ChronoDuration, NaiveDate, Utc};
 use cookie::{
     time::{Duration as CookieDuration, OffsetDateTime},
     Cookie, SameSite,
 };
 use enum_meta::Meta;
-use futures::{future::join_all, TryStreamExt};
+use futures::{future::join_all, stream, Stream, StreamExt, TryStreamExt};
 use harsh::Harsh;
 use http::header::SET_COOKIE;
 use itertools::Itertools;
+use kinded::Kinded;
 use markdown::{
     to_html as markdown_to_html, to_html_with_options as markdown_to_html_opts, CompileOptions,
     Options,
 };
+use md5::{Digest, Md5};
 use nanoid::nanoid;
 use retainer::Cache;
-use rust_decimal::Decimal;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
 use rust_decimal_macros::dec;
 use sea_orm::{
     prelude::DateTimeUtc, ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait,
@@ -36,8 +40,9 @@ use sea_orm::{
     QueryTrait, RelationTrait, Statement,
 };
 use sea_query::{
-    Alias, Asterisk, Cond, Condition, Expr, Func, Keyword, MySqlQueryBuilder, NullOrdering,
-    PostgresQueryBuilder, Query, SelectStatement, SqliteQueryBuilder, UnionType, Values,
+    Alias, Asterisk, Cond, Condition, Expr, Func, Keyword, LikeExpr, MySqlQueryBuilder,
+    NullOrdering, OnConflict, PostgresQueryBuilder, Query, SelectStatement, SqliteQueryBuilder,
+    UnionType, Values,
 };
 use semver::Version;
 use serde::{Deserialize, Serialize};
@@ -47,75 +52,113 @@ use uuid::Uuid;
 
 use crate::{
     background::ApplicationJob,
-    config::AppConfig,
+    config::{import_app_config, is_provider_setting, save_app_config_settings, AppConfig},
     entities::{
-        calendar_event, collection, creator, genre, metadata, metadata_group,
+        announcement, audit_log, calendar_event, collection, creator, genre, highlight,
+        instance_setting::{self, INSTANCE_SETTING_ID},
+        integration_mapping, koreader_document, metadata, metadata_correction, metadata_group,
         metadata_to_collection, metadata_to_creator, metadata_to_genre,
         metadata_to_partial_metadata, partial_metadata, partial_metadata_to_metadata_group,
         prelude::{
-            CalendarEvent, Collection, Creator, Genre, Metadata, MetadataGroup,
-            MetadataToCollection, MetadataToCreator, MetadataToGenre, MetadataToPartialMetadata,
-            PartialMetadata as PartialMetadataModel, PartialMetadataToMetadataGroup, Review, Seen,
-            User, UserMeasurement, UserToMetadata, Workout,
+            Announcement, AuditLog, CalendarEvent, Collection, Creator, Genre, Highlight,
+            InstanceSetting, IntegrationMapping, KoreaderDocument, Metadata, MetadataCorrection,
+            MetadataGroup, MetadataToCollection, MetadataToCreator, MetadataToGenre,
+            MetadataToPartialMetadata, PartialMetadata as PartialMetadataModel,
+            PartialMetadataToMetadataGroup, Review, Seen, SeenAttachment, SeenCompanion, User,
+            UserMeasurement, UserStatistic, UserToMetadata, Workout, WorkoutSet,
         },
-        review, seen, user, user_measurement, user_to_metadata, workout,
+        review, seen, seen_attachment, seen_companion, user, user_measurement, user_statistic,
+        user_to_metadata, workout, workout_set,
     },
     file_storage::FileStorageService,
-    integrations::{IntegrationMedia, IntegrationService},
-    jwt,
+    http_cache,
+    integrations::{IntegrationMedia, IntegrationService, KoreaderProgress, WebhookCredentials},
+    jwt::{self, EmailTokenPurpose},
     migrator::{
-        Metadata as TempMetadata, MetadataLot, MetadataSource, MetadataToPartialMetadataRelation,
+        AnnouncementSeverity, AuditLogEvent, Metadata as TempMetadata, MetadataCorrectionField,
+        MetadataCorrectionState, MetadataLot, MetadataSource, MetadataToPartialMetadataRelation,
         Review as TempReview, Seen as TempSeen, SeenState, UserLot,
         UserToMetadata as TempUserToMetadata,
     },
-    miscellaneous::{CustomService, DefaultCollection},
+    miscellaneous::{
+        export::{
+            highlights_to_markdown, media_to_csv, media_to_letterboxd_csv, media_to_mal_xml,
+            HighlightsMarkdownItem, MalXmlItem, MediaCsvRow,
+        },
+        CustomService, DefaultCollection,
+    },
     models::{
         media::{
-            AddMediaToCollection, AnimeSpecifics, AudioBookSpecifics, BookSpecifics,
-            CreateOrUpdateCollectionInput, CreatorExtraInformation, ImportOrExportItemRating,
-            ImportOrExportItemReview, ImportOrExportItemReviewComment, ImportOrExportMediaItem,
-            ImportOrExportMediaItemSeen, ImportOrExportPersonItem, MangaSpecifics,
-            MediaCreatorSearchItem, MediaDetails, MediaListItem, MediaSearchItem,
-            MediaSearchItemResponse, MediaSearchItemWithLot, MediaSpecifics, MetadataCreator,
-            MetadataGroupListItem, MetadataImage, MetadataImageLot, MetadataImages, MetadataVideo,
-            MetadataVideoSource, MetadataVideos, MovieSpecifics, PartialMetadata, PodcastSpecifics,
-            PostReviewInput, ProgressUpdateError, ProgressUpdateErrorVariant, ProgressUpdateInput,
-            ProgressUpdateResultUnion, ReviewCommentUser, ReviewComments,
+            AddMediaToCollection, AnimeSeason, AnimeSpecifics, ArticleSpecifics,
+            AudioBookSpecifics, BoardGameSpecifics, BookSpecifics, CollectionAction,
+            CollectionActionLot, CollectionActions, ComicSpecifics, CreateOrUpdateCollectionInput,
+            CreateOrUpdateHighlightInput, CreatorExtraInformation, ImportOrExportItemHighlight,
+            ImportOrExportItemRating, ImportOrExportItemReview, ImportOrExportItemReviewComment,
+            ImportOrExportMediaItem, ImportOrExportMediaItemSeen, ImportOrExportPersonItem,
+            MangaSpecifics, MediaCreatorSearchItem, MediaDeletedOnProvider, MediaDetails,
+            MediaListItem, MediaSearchFilters, MediaSearchItem, MediaSearchItemResponse,
+            MediaSearchItemWithLot, MediaSpecifics, MediaUpcomingItem, MetadataAlternativeTitles,
+            MetadataAward, MetadataAwards, MetadataCreator, MetadataGroupListItem, MetadataImage,
+            MetadataImageLot, MetadataImages, MetadataLocalization, MetadataLocalizations,
+            MetadataVideo, MetadataVideoSource, MetadataVideos, MovieSpecifics, MusicSpecifics,
+            PartialMetadata, PodcastSpecifics, PostReviewInput, ProgressUpdateError,
+            ProgressUpdateErrorVariant, ProgressUpdateInput, ProgressUpdateResultUnion,
+            ReviewCommentUser, ReviewComments, SeasonalAnimeItem,
             SeenOrReviewOrCalendarEventExtraInformation, SeenPodcastExtraInformation,
-            SeenShowExtraInformation, ShowSpecifics, UserMediaReminder, UserSummary,
-            VideoGameSpecifics, Visibility, VisualNovelSpecifics,
+            SeenShowExtraInformation, ShowEpisode, ShowSeason, ShowSpecifics, UserMediaReminder,
+            UserSummary, VideoGameSpecifics, Visibility, VisualNovelSpecifics,
         },
         IdObject, SearchDetails, SearchInput, SearchResults, StoredUrl,
     },
+    notification::send_email,
+    password,
     providers::{
         anilist::{AnilistAnimeService, AnilistMangaService, AnilistService},
         audible::AudibleService,
+        bgg::BggService,
+        comic_vine::ComicVineService,
         google_books::GoogleBooksService,
         igdb::IgdbService,
         itunes::ITunesService,
         listennotes::ListennotesService,
         mal::{MalAnimeService, MalMangaService, MalService},
+        manga_dex::MangaDexService,
         manga_updates::MangaUpdatesService,
+        musicbrainz::MusicBrainzService,
         openlibrary::OpenlibraryService,
+        podcast_index::PodcastIndexService,
         tmdb::{TmdbMovieService, TmdbService, TmdbShowService},
+        upcitemdb::UpcItemDbService,
         vndb::VndbService,
+        web::WebService,
+        wikidata::WikidataService,
     },
     traits::{AuthProvider, IsFeatureEnabled, MediaProvider, MediaProviderLanguages},
     users::{
-        UserNotification, UserNotificationSetting, UserNotificationSettingKind, UserNotifications,
-        UserPreferences, UserReviewScale, UserSinkIntegration, UserSinkIntegrationSetting,
+        DashboardElementLot, IntegrationProgressSettings, IntegrationUserMapping,
+        UserDashboardSection, UserNotification, UserNotificationSetting,
+        UserNotificationSettingKind, UserNotifications, UserNsfwDisplay, UserPreferences,
+        UserReviewScale, UserSinkIntegration, UserSinkIntegrationSetting,
         UserSinkIntegrationSettingKind, UserSinkIntegrations, UserUnitSystem, UserYankIntegration,
         UserYankIntegrationSetting, UserYankIntegrationSettingKind, UserYankIntegrations,
     },
     utils::{
-        associate_user_with_metadata, convert_naive_to_utc, get_case_insensitive_like_query,
-        get_first_and_last_day_of_month, get_stored_asset, get_user_and_metadata_association,
-        user_by_id, user_id_from_token, AUTHOR, COOKIE_NAME, USER_AGENT_STR, VERSION,
+        associate_user_with_metadata, constant_time_eq, convert_naive_to_utc,
+        get_case_insensitive_like_query, get_first_and_last_day_of_month, get_stored_asset,
+        get_user_and_metadata_association, normalize_text, send_notifications_to_user_platforms,
+        user_by_id, user_id_from_token, AuthContext, UserStatisticDelta, AUTHOR, COOKIE_NAME,
+        USER_AGENT_STR, VERSION,
     },
 };
 
 type Provider = Box<(dyn MediaProvider + Send + Sync)>;
 
+static DASHBOARD_UPCOMING_DAYS: u64 = 7;
+/// How long the upcoming releases that are recommended to a user are reused
+/// before they are requested from the providers again.
+static COMING_SOON_CACHE_HOURS: i64 = 6;
+static AUDIT_LOG_RETENTION_DAYS: i64 = 90;
+
 #[derive(Debug)]
 pub enum MediaStateChanged {
     StatusChanged,
@@ -146,6 +189,10 @@ struct CreateCustomMediaInput {
     manga_specifics: Option<MangaSpecifics>,
     anime_specifics: Option<AnimeSpecifics>,
     visual_novel_specifics: Option<VisualNovelSpecifics>,
+    article_specifics: Option<ArticleSpecifics>,
+    music_specifics: Option<MusicSpecifics>,
+    board_game_specifics: Option<BoardGameSpecifics>,
+    comic_specifics: Option<ComicSpecifics>,
 }
 
 #[derive(Enum, Serialize, Deserialize, Clone, Debug, Copy, PartialEq, Eq)]
@@ -161,6 +208,11 @@ struct GraphqlUserIntegration {
     timestamp: DateTimeUtc,
     lot: UserIntegrationLot,
     slug: Option<String>,
+    /// The secret that requests made by the integration have to be authenticated
+    /// with.
+    secret: Option<String>,
+    progress_settings: IntegrationProgressSettings,
+    user_mappings: Vec<IntegrationUserMapping>,
 }
 
 #[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
@@ -169,6 +221,7 @@ struct CreateUserYankIntegrationInput {
     base_url: String,
     #[graphql(secret)]
     token: String,
+    progress_settings: Option<IntegrationProgressSettings>,
 }
 
 #[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
@@ -191,6 +244,7 @@ struct CreateUserNotificationPlatformInput {
 struct CreateUserSinkIntegrationInput {
     lot: UserSinkIntegrationSettingKind,
     username: Option<String>,
+    progress_settings: Option<IntegrationProgressSettings>,
 }
 
 #[derive(Enum, Clone, Debug, Copy, PartialEq, Eq)]
@@ -237,12 +291,61 @@ struct UserInput {
     username: String,
     #[graphql(secret)]
     password: String,
+    /// Only used when registering. Required if the instance needs new users to
+    /// verify their email.
+    email: Option<String>,
+}
+
+#[derive(Debug, SimpleObject)]
+struct SetupProviderStatus {
+    lot: MetadataLot,
+    source: MetadataSource,
+    /// The settings that need to be set before this provider can be used.
+    missing_settings: Vec<String>,
+}
+
+#[derive(Debug, SimpleObject)]
+struct SetupStatus {
+    /// Whether an admin account has been created.
+    admin_exists: bool,
+    providers: Vec<SetupProviderStatus>,
+}
+
+#[derive(Debug, SimpleObject)]
+struct ProviderResponseHeader {
+    name: String,
+    value: String,
+}
+
+#[derive(Debug, SimpleObject)]
+struct ProviderTestResult {
+    lot: MetadataLot,
+    source: MetadataSource,
+    success: bool,
+    /// The error returned by the provider if the request failed.
+    error: Option<String>,
+    latency_ms: i64,
+    /// The status code of the last response of the provider, if a request was
+    /// made.
+    status: Option<i32>,
+    /// The headers of the last response that describe the rate limits of the
+    /// provider.
+    rate_limit_headers: Vec<ProviderResponseHeader>,
+}
+
+#[derive(Debug, InputObject)]
+struct SetupConfigSettingInput {
+    /// The dotted path of the setting (eg: `video_games.twitch.client_id`).
+    key: String,
+    #[graphql(secret)]
+    value: String,
 }
 
 #[derive(Enum, Clone, Debug, Copy, PartialEq, Eq)]
 enum RegisterErrorVariant {
     UsernameAlreadyExists,
     Disabled,
+    EmailRequired,
 }
 
 #[derive(Debug, SimpleObject)]
@@ -260,6 +363,8 @@ enum RegisterResult {
 enum LoginErrorVariant {
     UsernameDoesNotExist,
     CredentialsMismatch,
+    EmailNotVerified,
+    TooManyAttempts,
 }
 
 #[derive(Debug, SimpleObject)]
@@ -286,6 +391,150 @@ struct UpdateUserInput {
     password: Option<String>,
 }
 
+#[derive(Debug, InputObject)]
+struct ResetUserPasswordInput {
+    /// The token that was sent to the email of the user.
+    token: String,
+    #[graphql(secret)]
+    password: String,
+}
+
+#[derive(Debug, InputObject)]
+struct UpdateSeenItemRatingInput {
+    seen_id: i32,
+    /// The rating in the review scale of the user. If not set, the rating is
+    /// removed.
+    rating: Option<Decimal>,
+}
+
+#[derive(Debug, InputObject)]
+struct AddSeenAttachmentInput {
+    seen_id: i32,
+    /// The key of the file that was uploaded using a presigned URL.
+    key: String,
+}
+
+#[derive(Debug, InputObject)]
+struct AddSeenCompanionInput {
+    seen_id: i32,
+    /// The name of someone who does not use this instance.
+    name: Option<String>,
+    /// The username of a user of this instance. They get a copy of the seen
+    /// item once they confirm it.
+    username: Option<String>,
+}
+
+#[derive(Debug, InputObject)]
+struct CreateAnnouncementInput {
+    message: String,
+    severity: AnnouncementSeverity,
+    /// The time after which the announcement is no longer displayed, `None` to
+    /// display it until it is deleted.
+    expires_on: Option<DateTimeUtc>,
+}
+
+#[derive(Debug, InputObject)]
+struct CreateMetadataCorrectionInput {
+    metadata_id: i32,
+    field: MetadataCorrectionField,
+    /// The corrected value, `None` to clear the field. The image is the URL of
+    /// the new poster, and the episode is a JSON object like `{"seasonNumber":
+    /// 1, "episodeNumber": 5, "name": "Pilot", "publishDate": "2023-10-01"}`
+    /// that adds or replaces an episode of a show. Only shows have episodes that
+    /// can be corrected.
+    value: Option<String>,
+    /// Why the details are wrong, to help the admins review it.
+    comment: Option<String>,
+}
+
+/// The value of a correction to an episode of a show.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EpisodeCorrection {
+    season_number: i32,
+    episode_number: i32,
+    name: String,
+    publish_date: Option<NaiveDate>,
+}
+
+impl EpisodeCorrection {
+    /// Add the episode to the show, replacing the one with the same number.
+    fn apply(self, show: &mut ShowSpecifics) {
+        let season = match show
+            .seasons
+            .iter_mut()
+            .position(|s| s.season_number == self.season_number)
+        {
+            Some(idx) => &mut show.seasons[idx],
+            None => {
+                show.seasons.push(ShowSeason {
+                    season_number: self.season_number,
+                    name: format!("Season {}", self.season_number),
+                    ..Default::default()
+                });
+                show.seasons.sort_by_key(|s| s.season_number);
+                show.seasons
+                    .iter_mut()
+                    .find(|s| s.season_number == self.season_number)
+                    .unwrap()
+            }
+        };
+        let existing = season
+            .episodes
+            .iter()
+            .position(|e| e.episode_number == self.episode_number)
+            .map(|idx| season.episodes.remove(idx))
+            .unwrap_or_default();
+        season.episodes.push(ShowEpisode {
+            episode_number: self.episode_number,
+            name: self.name,
+            publish_date: self.publish_date,
+            ..existing
+        });
+        season.episodes.sort_by_key(|e| e.episode_number);
+    }
+}
+
+/// A seen item of another user that the current user was tagged in and has not
+/// responded to yet.
+#[derive(Debug, SimpleObject)]
+struct PendingSeenCompanion {
+    companion_id: i32,
+    /// The username of the user that tagged them.
+    tagged_by: String,
+    metadata_id: i32,
+    metadata_title: String,
+    seen: seen::Model,
+}
+
+/// A seen item along with the items that were flagged as likely duplicates of
+/// it.
+#[derive(Debug, SimpleObject)]
+struct DuplicateSeenHistory {
+    metadata_id: i32,
+    metadata_title: String,
+    original: seen::Model,
+    duplicates: Vec<seen::Model>,
+}
+
+#[derive(Enum, Clone, Debug, Copy, PartialEq, Eq)]
+enum DuplicateSeenHistoryAction {
+    /// Copy the details that the original is missing from the duplicate, then
+    /// delete the duplicate.
+    Merge,
+    /// Delete the duplicate.
+    Delete,
+    /// Keep the duplicate and do not flag it again.
+    Dismiss,
+}
+
+#[derive(Debug, InputObject)]
+struct ResolveDuplicateSeenHistoryInput {
+    /// The seen items that were flagged as duplicates.
+    seen_ids: Vec<i32>,
+    action: DuplicateSeenHistoryAction,
+}
+
 #[derive(Debug, InputObject)]
 struct UpdateUserPreferenceInput {
     property: String,
@@ -334,12 +583,22 @@ struct CollectionItem {
     num_items: u64,
     description: Option<String>,
     visibility: Visibility,
+    actions: Vec<CollectionAction>,
+}
+
+/// What the failed logins are counted by.
+#[derive(Debug, Ord, PartialEq, Eq, PartialOrd, Clone)]
+enum LoginAttemptsCache {
+    Username(String),
+    Ip(IpAddr),
 }
 
 #[derive(SimpleObject)]
 struct GeneralFeatures {
     file_storage: bool,
     signup_allowed: bool,
+    password_reset_allowed: bool,
+    email_verification_required: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
@@ -401,8 +660,11 @@ struct GraphqlMediaAssets {
 struct GraphqlMediaDetails {
     id: i32,
     title: String,
+    alternative_titles: Vec<String>,
     identifier: String,
     is_nsfw: bool,
+    is_deleted_on_provider: bool,
+    awards: Vec<MetadataAward>,
     description: Option<String>,
     provider_rating: Option<Decimal>,
     production_status: String,
@@ -422,6 +684,10 @@ struct GraphqlMediaDetails {
     podcast_specifics: Option<PodcastSpecifics>,
     manga_specifics: Option<MangaSpecifics>,
     anime_specifics: Option<AnimeSpecifics>,
+    article_specifics: Option<ArticleSpecifics>,
+    music_specifics: Option<MusicSpecifics>,
+    board_game_specifics: Option<BoardGameSpecifics>,
+    comic_specifics: Option<ComicSpecifics>,
     source_url: Option<String>,
     suggestions: Vec<partial_metadata::Model>,
     group: Option<GraphqlMediaGroup>,
@@ -451,6 +717,9 @@ enum MediaSortBy {
     LastSeen,
     LastUpdated,
     Rating,
+    /// The favorites of the user first, each group ordered by the rating given by
+    /// the user.
+    TopFavorites,
 }
 
 #[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
@@ -472,12 +741,23 @@ enum MediaGeneralFilter {
     Completed,
     Unseen,
     ExplicitlyMonitored,
+    DeletedOnProvider,
+}
+
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+struct MediaAwardFilter {
+    /// Part of the name of the award, eg: `Best Picture`.
+    name: String,
+    /// Only include media that won the award, not the ones that were nominated.
+    #[graphql(default)]
+    won: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
 struct MediaFilter {
     general: Option<MediaGeneralFilter>,
     collection: Option<i32>,
+    award: Option<MediaAwardFilter>,
 }
 
 #[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
@@ -552,12 +832,20 @@ struct UserMediaDetails {
     next_episode: Option<UserMediaNextEpisode>,
     /// Whether the user is monitoring this media.
     is_monitored: bool,
+    /// Whether the user has marked this media as a favorite.
+    is_favorite: bool,
     /// The reminder that the user has set for this media.
     reminder: Option<UserMediaReminder>,
+    /// The total time in minutes that the user played this game for, as synced
+    /// by an integration.
+    playtime: Option<i32>,
     /// The number of users who have seen this media.
     seen_by: i32,
     /// The average rating of this media in this service.
     average_rating: Option<Decimal>,
+    /// The average of the ratings given when this media was seen by the users of
+    /// this service.
+    average_seen_rating: Option<Decimal>,
 }
 
 #[derive(SimpleObject)]
@@ -623,6 +911,105 @@ enum UserUpcomingCalendarEventInput {
     NextDays(u64),
 }
 
+#[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
+struct MediaFromBarcodeInput {
+    /// The UPC/EAN code printed on the box.
+    barcode: String,
+    lot: MetadataLot,
+}
+
+#[derive(Debug, SimpleObject, Clone)]
+struct ComingSoonItem {
+    lot: MetadataLot,
+    source: MetadataSource,
+    release_date: NaiveDate,
+    item: MediaSearchItem,
+    /// Whether the item is NSFW and needs to be clicked through before it is shown.
+    blur: bool,
+}
+
+#[derive(Debug, SimpleObject)]
+struct UserDashboard {
+    /// The media that the user is currently consuming.
+    in_progress: Vec<MediaSearchItemWithLot>,
+    /// The calendar events of the next few days.
+    upcoming: Vec<GraphqlCalendarEvent>,
+    /// The media that the user has pinned, in the order they were pinned.
+    pinned_media: Vec<MediaSearchItemWithLot>,
+    /// The collections that the user has pinned, in the order they were pinned.
+    pinned_collections: Vec<CollectionItem>,
+    /// Upcoming releases that the user might be interested in. They are requested
+    /// from the providers in the background, so this is empty until that is done.
+    recommendations: Vec<ComingSoonItem>,
+}
+
+#[derive(Debug, SimpleObject)]
+struct InProgressMediaItem {
+    details: MediaSearchItemWithLot,
+    /// The latest open seen entry of the media.
+    seen: seen::Model,
+}
+
+/// The number of times media of a type was completed in a context.
+#[derive(Debug, SimpleObject)]
+struct SeenContextStatistic {
+    lot: MetadataLot,
+    /// `None` for the media that was seen without a context.
+    context: Option<String>,
+    count: usize,
+}
+
+#[derive(Debug, InputObject)]
+struct UserMediaStatisticsInput {
+    lot: Option<MetadataLot>,
+    /// Only include the months on or after the one this date is in.
+    start_date: Option<NaiveDate>,
+    /// Only include the months on or before the one this date is in.
+    end_date: Option<NaiveDate>,
+}
+
+/// The media of a type that was completed and reviewed in a month.
+#[derive(Debug, SimpleObject)]
+struct UserMediaStatistic {
+    lot: MetadataLot,
+    /// The first day of the month.
+    month: NaiveDate,
+    seen: i32,
+    reviews: i32,
+    /// The average rating of the reviews, in the review scale of the user.
+    average_rating: Option<Decimal>,
+}
+
+/// The outcome of the last run of the database maintenance job.
+#[derive(Debug, SimpleObject, Clone)]
+struct DatabaseMaintenanceReport {
+    started_on: DateTimeUtc,
+    finished_on: DateTimeUtc,
+    /// The size of the database in bytes before the maintenance, if it could
+    /// be determined.
+    size_before: Option<i64>,
+    size_after: Option<i64>,
+    /// The number of bytes freed by the maintenance.
+    reclaimed: Option<i64>,
+    /// The number of media items removed because no user was associated with them.
+    metadata_removed: u64,
+}
+
+#[derive(Debug, SimpleObject)]
+struct SearchAllResult {
+    lot: MetadataLot,
+    source: MetadataSource,
+    results: SearchResults<MediaSearchItemResponse>,
+}
+
+#[derive(Debug, SimpleObject)]
+struct MediaFromBarcodeResponse {
+    /// The product title that the barcode resolved to.
+    product_title: String,
+    source: MetadataSource,
+    results: SearchResults<MediaSearchItemResponse>,
+}
+
 fn create_cookie(
     ctx: &Context<'_>,
     api_key: &str,
@@ -646,14 +1033,68 @@ fn create_cookie(
     Ok(())
 }
 
-fn get_password_hasher() -> Argon2<'static> {
-    Argon2::default()
-}
-
 fn get_id_hasher(salt: &str) -> Harsh {
     Harsh::builder().length(10).salt(salt).build().unwrap()
 }
 
+/// The secret that requests made by the integration have to be authenticated
+/// with.
+fn integration_secret(integration: &UserSinkIntegration) -> Result<&str> {
+    // DEV: Integrations from before requests were authenticated have to be added
+    // again, since anyone who knows their slug could send progress otherwise
+    integration.secret.as_deref().ok_or_else(|| {
+        Error::new("This integration does not have a secret, delete it and add it again")
+    })
+}
+
+/// The user that the progress sent to the webhook of an integration is saved
+/// to. Profiles on the media server that are mapped to another user are saved to
+/// that user, and the rest to the owner of the integration.
+fn integration_webhook_user_id(
+    owner_id: i32,
+    integration: &UserSinkIntegration,
+    pu: &IntegrationMedia,
+) -> Result<i32> {
+    let server_user = match &pu.server_user {
+        Some(u) => u,
+        None => return Ok(owner_id),
+    };
+    if let Some(mapping) = integration
+        .user_mappings
+        .iter()
+        .find(|m| &m.server_username == server_user)
+    {
+        return Ok(mapping.user_id);
+    }
+    if let UserSinkIntegrationSetting::Plex {
+        user: Some(plex_user),
+        ..
+    } = &integration.settings
+    {
+        if plex_user != server_user {
+            return Err(Error::new(format!(
+                "Ignoring non matching user {:#?}",
+                server_user
+            )));
+        }
+    }
+    Ok(owner_id)
+}
+
+fn validate_integration_progress_settings(settings: &IntegrationProgressSettings) -> Result<()> {
+    if let Some(threshold) = settings.completion_threshold {
+        if !(0..=100).contains(&threshold) {
+            return Err(Error::new(
+                "The completion threshold must be between 0 and 100",
+            ));
+        }
+    }
+    if settings.minimum_duration.is_some_and(|d| d < 0) {
+        return Err(Error::new("The minimum duration can not be negative"));
+    }
+    Ok(())
+}
+
 #[derive(Default)]
 pub struct MiscellaneousQuery;
 
@@ -665,6 +1106,28 @@ impl MiscellaneousQuery {
         service.core_details().await
     }
 
+    /// Get the details needed to set up the instance, including the settings
+    /// that are missing for each provider. Open to everyone with the setup token
+    /// until the first user is created, and only to admins after that.
+    async fn setup_status(
+        &self,
+        gql_ctx: &Context<'_>,
+        setup_token: Option<String>,
+    ) -> Result<SetupStatus> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        service.setup_guard(gql_ctx, setup_token).await?;
+        service.setup_status().await
+    }
+
+    /// Make a search request to every provider that is configured and get how
+    /// they responded.
+    async fn test_providers(&self, gql_ctx: &Context<'_>) -> Result<Vec<ProviderTestResult>> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.admin_account_guard(user_id).await?;
+        Ok(service.test_providers().await)
+    }
+
     /// Get a review by its ID.
     async fn review(&self, gql_ctx: &Context<'_>, review_id: i32) -> Result<ReviewItem> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
@@ -701,7 +1164,8 @@ impl MiscellaneousQuery {
         metadata_id: i32,
     ) -> Result<GraphqlMediaDetails> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        service.media_details(metadata_id).await
+        let user_id = service.user_id_from_ctx(gql_ctx).await.ok();
+        service.media_details(user_id, metadata_id).await
     }
 
     /// Get details about a creator present in the database.
@@ -711,7 +1175,8 @@ impl MiscellaneousQuery {
         creator_id: i32,
     ) -> Result<CreatorDetails> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        service.creator_details(creator_id).await
+        let user_id = service.user_id_from_ctx(gql_ctx).await.ok();
+        service.creator_details(user_id, creator_id).await
     }
 
     /// Get details about a metadata group present in the database.
@@ -721,7 +1186,10 @@ impl MiscellaneousQuery {
         metadata_group_id: i32,
     ) -> Result<MetadataGroupDetails> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        service.metadata_group_details(metadata_group_id).await
+        let user_id = service.user_id_from_ctx(gql_ctx).await.ok();
+        service
+            .metadata_group_details(user_id, metadata_group_id)
+            .await
     }
 
     /// Get all the media items related to a user for a specific media type.
@@ -735,10 +1203,14 @@ impl MiscellaneousQuery {
         service.media_list(user_id, input).await
     }
 
-    /// Get a presigned URL (valid for 90 minutes) for a given key.
-    async fn get_presigned_url(&self, gql_ctx: &Context<'_>, key: String) -> String {
+    /// Get a short-lived presigned URL for a file uploaded by the user.
+    async fn get_presigned_url(&self, gql_ctx: &Context<'_>, key: String) -> Result<String> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        service.file_storage_service.get_presigned_url(key).await
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        if !FileStorageService::is_user_upload(&key, user_id) {
+            return Err(Error::new("This file was not uploaded by the user"));
+        }
+        Ok(service.file_storage_service.get_presigned_url(key).await)
     }
 
     /// Get all the features that are enabled for the service
@@ -761,10 +1233,64 @@ impl MiscellaneousQuery {
         lot: MetadataLot,
         source: MetadataSource,
         input: SearchInput,
+        filters: Option<MediaSearchFilters>,
+    ) -> Result<SearchResults<MediaSearchItemResponse>> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .media_search(lot, source, input, filters.unwrap_or_default(), user_id)
+            .await
+    }
+
+    /// Search for a query in all the media types that the user has enabled, using
+    /// the provider that the user prefers for each of them.
+    async fn search_all(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: SearchInput,
+    ) -> Result<Vec<SearchAllResult>> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.search_all(input, user_id).await
+    }
+
+    /// Get the media that are currently trending on the provider that the user
+    /// prefers for a lot.
+    async fn discover_trending(
+        &self,
+        gql_ctx: &Context<'_>,
+        lot: MetadataLot,
+        page: Option<i32>,
     ) -> Result<SearchResults<MediaSearchItemResponse>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.media_search(lot, source, input, user_id).await
+        service.discover_trending(lot, page, user_id).await
+    }
+
+    /// Get the anime that started airing in a season, along with what the user
+    /// has already done with each of them.
+    async fn seasonal_anime(
+        &self,
+        gql_ctx: &Context<'_>,
+        year: i32,
+        season: AnimeSeason,
+        page: Option<i32>,
+    ) -> Result<SearchResults<SeasonalAnimeItem>> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.seasonal_anime(year, season, page, user_id).await
+    }
+
+    /// Resolve a barcode from a physical movie/game box to search results from
+    /// the provider for that lot. Returns `null` if the barcode is not known.
+    async fn media_from_barcode(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: MediaFromBarcodeInput,
+    ) -> Result<Option<MediaFromBarcodeResponse>> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.media_from_barcode(user_id, input).await
     }
 
     /// Get all the metadata sources possible for a lot.
@@ -861,1238 +1387,2441 @@ impl MiscellaneousQuery {
         service.user_calendar_events(user_id, input).await
     }
 
-    /// Get upcoming calendar events for the given filter.
-    async fn user_upcoming_calendar_events(
-        &self,
-        gql_ctx: &Context<'_>,
-        input: UserUpcomingCalendarEventInput,
-    ) -> Result<Vec<GraphqlCalendarEvent>> {
+    /// Get media that will be released soon and that the user has not added
+    /// yet, filtered by the genres and creators they follow.
+    async fn user_coming_soon(&self, gql_ctx: &Context<'_>) -> Result<Vec<ComingSoonItem>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.user_upcoming_calendar_events(user_id, input).await
+        service.user_coming_soon(user_id).await
     }
 
-    /// Get paginated list of creators.
-    async fn creators_list(
-        &self,
-        gql_ctx: &Context<'_>,
-        input: SearchInput,
-    ) -> Result<SearchResults<MediaCreatorSearchItem>> {
+    /// Get everything that is shown on the dashboard of the user in one request.
+    async fn dashboard(&self, gql_ctx: &Context<'_>) -> Result<UserDashboard> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        service.creators_list(input).await
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.user_dashboard(user_id).await
     }
 
-    /// Get paginated list of metadata groups.
-    async fn metadata_groups_list(
-        &self,
-        gql_ctx: &Context<'_>,
-        input: SearchInput,
-    ) -> Result<SearchResults<MetadataGroupListItem>> {
+    /// Get everything that the user is currently consuming across all types of
+    /// media, with the most recently updated first.
+    async fn in_progress(&self, gql_ctx: &Context<'_>) -> Result<Vec<InProgressMediaItem>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        service.metadata_groups_list(input).await
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.in_progress(user_id).await
     }
-}
-
-#[derive(Default)]
-pub struct MiscellaneousMutation;
 
-#[Object]
-impl MiscellaneousMutation {
-    /// Create or update a review.
-    async fn post_review(&self, gql_ctx: &Context<'_>, input: PostReviewInput) -> Result<IdObject> {
+    /// Get the seen items of other users that the currently logged in user was
+    /// tagged in and has not confirmed or declined yet.
+    async fn pending_seen_companions(
+        &self,
+        gql_ctx: &Context<'_>,
+    ) -> Result<Vec<PendingSeenCompanion>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.post_review(user_id, input).await
+        service.pending_seen_companions(user_id).await
     }
 
-    /// Delete a review if it belongs to the currently logged in user.
-    async fn delete_review(&self, gql_ctx: &Context<'_>, review_id: i32) -> Result<bool> {
+    /// Get the announcements made by the admins that have not expired yet, the
+    /// most recent first.
+    async fn announcements(&self, gql_ctx: &Context<'_>) -> Result<Vec<announcement::Model>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.delete_review(user_id, review_id).await
+        service.announcements().await
     }
 
-    /// Create a new collection for the logged in user or edit details of an existing one.
-    async fn create_or_update_collection(
+    /// Get the entries of the audit log, eg: failed logins and lockouts, the most
+    /// recent first. User must be an `Admin`.
+    async fn audit_log(
         &self,
         gql_ctx: &Context<'_>,
-        input: CreateOrUpdateCollectionInput,
-    ) -> Result<IdObject> {
+        page: Option<i32>,
+    ) -> Result<SearchResults<audit_log::Model>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.create_or_update_collection(user_id, input).await
+        service.admin_account_guard(user_id).await?;
+        service.audit_log(page).await
     }
 
-    /// Add a media item to a collection if it is not there, otherwise do nothing.
-    async fn add_media_to_collection(
+    /// Get the metadata corrections that were submitted. Admins get the ones of
+    /// all users, while everyone else gets only their own.
+    async fn metadata_corrections(
         &self,
         gql_ctx: &Context<'_>,
-        input: AddMediaToCollection,
-    ) -> Result<bool> {
+        state: Option<MetadataCorrectionState>,
+    ) -> Result<Vec<metadata_correction::Model>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.add_media_to_collection(user_id, input).await
+        service.metadata_corrections(user_id, state).await
     }
 
-    /// Remove a media item from a collection if it is not there, otherwise do nothing.
-    async fn remove_media_from_collection(
+    /// Get the seen items of the user that were flagged as likely duplicates,
+    /// grouped by the item that they duplicate.
+    async fn duplicate_seen_history(
         &self,
         gql_ctx: &Context<'_>,
-        metadata_id: i32,
-        collection_name: String,
-    ) -> Result<IdObject> {
+    ) -> Result<Vec<DuplicateSeenHistory>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service
-            .remove_media_from_collection(user_id, &metadata_id, &collection_name)
-            .await
+        service.duplicate_seen_history(user_id).await
     }
 
-    /// Delete a collection.
-    async fn delete_collection(
+    /// Get the number of times the user completed media, broken down by the
+    /// type of media and the context it was seen in.
+    async fn seen_context_statistics(
         &self,
         gql_ctx: &Context<'_>,
-        collection_name: String,
-    ) -> Result<bool> {
+        lot: Option<MetadataLot>,
+    ) -> Result<Vec<SeenContextStatistic>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.delete_collection(user_id, &collection_name).await
+        service.seen_context_statistics(user_id, lot).await
     }
 
-    /// Delete a seen item from a user's history.
-    async fn delete_seen_item(&self, gql_ctx: &Context<'_>, seen_id: i32) -> Result<IdObject> {
+    /// Get the number of media the user completed and reviewed in each month,
+    /// broken down by the type of media.
+    async fn user_media_statistics(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: UserMediaStatisticsInput,
+    ) -> Result<Vec<UserMediaStatistic>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.delete_seen_item(seen_id, user_id).await
+        service.user_media_statistics(user_id, input).await
     }
 
-    /// Deploy jobs to update all media item's metadata.
-    async fn update_all_metadata(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+    /// Get the preferences that newly created users start with. User must be an
+    /// `Admin`.
+    async fn user_preferences_template(&self, gql_ctx: &Context<'_>) -> Result<UserPreferences> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        service.update_all_metadata().await
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.admin_account_guard(user_id).await?;
+        service.user_preferences_template().await
     }
 
-    /// Create a custom media item.
-    async fn create_custom_media(
+    /// Get the report of the last database maintenance that was performed since
+    /// the server started. User must be an `Admin`.
+    async fn database_maintenance_report(
         &self,
         gql_ctx: &Context<'_>,
-        input: CreateCustomMediaInput,
-    ) -> Result<CreateCustomMediaResult> {
+    ) -> Result<Option<DatabaseMaintenanceReport>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.create_custom_media(input, user_id).await
+        service.admin_account_guard(user_id).await?;
+        Ok(service.last_maintenance_report.lock().unwrap().clone())
     }
 
-    /// Mark a user's progress on a specific media item.
-    async fn progress_update(
+    /// Get the highlights that the currently logged in user made, optionally
+    /// only the ones in a specific book.
+    async fn highlights(
         &self,
         gql_ctx: &Context<'_>,
-        input: ProgressUpdateInput,
-    ) -> Result<ProgressUpdateResultUnion> {
+        metadata_id: Option<i32>,
+    ) -> Result<Vec<highlight::Model>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.progress_update(input, user_id).await
+        service.highlights(user_id, metadata_id).await
     }
 
-    /// Update progress in bulk.
-    async fn bulk_progress_update(
+    /// Get the documents that KOReader synced the progress of for the currently
+    /// logged in user.
+    async fn koreader_documents(
         &self,
         gql_ctx: &Context<'_>,
-        input: Vec<ProgressUpdateInput>,
-    ) -> Result<bool> {
+    ) -> Result<Vec<koreader_document::Model>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.bulk_progress_update(user_id, input).await
+        service.koreader_documents(user_id).await
     }
 
-    /// Deploy a job to update a media item's metadata.
-    async fn deploy_update_metadata_job(
+    /// Get the titles and IDs that the integrations of the currently logged in
+    /// user sent, along with the media that they are mapped to.
+    async fn integration_mappings(
         &self,
         gql_ctx: &Context<'_>,
-        metadata_id: i32,
-    ) -> Result<String> {
+    ) -> Result<Vec<integration_mapping::Model>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        service.deploy_update_metadata_job(metadata_id).await
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.integration_mappings(user_id).await
     }
 
-    /// Merge a media item into another. This will move all `seen`, `collection`
-    /// and `review` associations with the new user and then delete the old media
-    /// item completely.
-    async fn merge_metadata(
+    /// Get upcoming calendar events for the given filter.
+    async fn user_upcoming_calendar_events(
         &self,
         gql_ctx: &Context<'_>,
-        merge_from: i32,
-        merge_into: i32,
-    ) -> Result<bool> {
+        input: UserUpcomingCalendarEventInput,
+    ) -> Result<Vec<GraphqlCalendarEvent>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        service.merge_metadata(merge_from, merge_into).await
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.user_upcoming_calendar_events(user_id, input).await
     }
 
-    /// Fetch details about a media and create a media item in the database.
-    async fn commit_media(
+    /// Get paginated list of creators.
+    async fn creators_list(
         &self,
         gql_ctx: &Context<'_>,
-        lot: MetadataLot,
-        source: MetadataSource,
-        identifier: String,
-    ) -> Result<IdObject> {
+        input: SearchInput,
+    ) -> Result<SearchResults<MediaCreatorSearchItem>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        service.commit_media(lot, source, &identifier).await
+        service.creators_list(input).await
     }
 
-    /// Create a new user for the service. Also set their `lot` as admin if
-    /// they are the first user.
-    async fn register_user(
+    /// Get paginated list of metadata groups.
+    async fn metadata_groups_list(
         &self,
         gql_ctx: &Context<'_>,
-        input: UserInput,
-    ) -> Result<RegisterResult> {
+        input: SearchInput,
+    ) -> Result<SearchResults<MetadataGroupListItem>> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        service
-            .register_user(&input.username, &input.password)
-            .await
+        service.metadata_groups_list(input).await
     }
+}
 
-    /// Login a user using their username and password and return an auth token.
-    async fn login_user(&self, gql_ctx: &Context<'_>, input: UserInput) -> Result<LoginResult> {
+#[derive(Default)]
+pub struct MiscellaneousMutation;
+
+#[Object]
+impl MiscellaneousMutation {
+    /// Create or update a review.
+    async fn post_review(&self, gql_ctx: &Context<'_>, input: PostReviewInput) -> Result<IdObject> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        service
-            .login_user(&input.username, &input.password, gql_ctx)
-            .await
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.post_review(user_id, input).await
     }
 
-    /// Logout a user from the server and delete their login token.
-    async fn logout_user(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+    /// Delete a review if it belongs to the currently logged in user.
+    async fn delete_review(&self, gql_ctx: &Context<'_>, review_id: i32) -> Result<bool> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        service.logout_user(gql_ctx).await
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.delete_review(user_id, review_id).await
     }
 
-    /// Update a user's profile details.
-    async fn update_user(&self, gql_ctx: &Context<'_>, input: UpdateUserInput) -> Result<IdObject> {
+    /// Create a new collection for the logged in user or edit details of an existing one.
+    async fn create_or_update_collection(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: CreateOrUpdateCollectionInput,
+    ) -> Result<IdObject> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.update_user(user_id, input).await
+        service.create_or_update_collection(user_id, input).await
     }
 
-    /// Delete all summaries for the currently logged in user and then generate one from scratch.
-    pub async fn regenerate_user_summary(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+    /// Add a media item to a collection if it is not there, otherwise do nothing.
+    async fn add_media_to_collection(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: AddMediaToCollection,
+    ) -> Result<bool> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.deploy_recalculate_summary_job(user_id).await.ok();
-        Ok(true)
+        service.add_media_to_collection(user_id, input).await
     }
 
-    /// Change a user's preferences.
-    async fn update_user_preference(
+    /// Remove a media item from a collection if it is not there, otherwise do nothing.
+    async fn remove_media_from_collection(
         &self,
         gql_ctx: &Context<'_>,
-        input: UpdateUserPreferenceInput,
+        metadata_id: i32,
+        collection_name: String,
+    ) -> Result<IdObject> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .remove_media_from_collection(user_id, &metadata_id, &collection_name)
+            .await
+    }
+
+    /// Delete a collection.
+    async fn delete_collection(
+        &self,
+        gql_ctx: &Context<'_>,
+        collection_name: String,
     ) -> Result<bool> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.update_user_preference(input, user_id).await
+        service.delete_collection(user_id, &collection_name).await
     }
 
-    /// Create a sink based integrations for the currently logged in user.
-    async fn create_user_sink_integration(
+    /// Delete a seen item from a user's history.
+    async fn delete_seen_item(&self, gql_ctx: &Context<'_>, seen_id: i32) -> Result<IdObject> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.delete_seen_item(seen_id, user_id).await
+    }
+
+    /// Merge, delete or dismiss seen items that were flagged as duplicates in
+    /// bulk. Returns the number of items that were resolved.
+    async fn resolve_duplicate_seen_history(
         &self,
         gql_ctx: &Context<'_>,
-        input: CreateUserSinkIntegrationInput,
+        input: ResolveDuplicateSeenHistoryInput,
     ) -> Result<usize> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.create_user_sink_integration(user_id, input).await
+        service.resolve_duplicate_seen_history(user_id, input).await
     }
 
-    /// Create a yank based integrations for the currently logged in user.
-    async fn create_user_yank_integration(
+    /// Set the rating given at the time a seen item was recorded. This is separate
+    /// from the rating in the reviews, so that rewatches can record how the opinion
+    /// of the user changed.
+    async fn update_seen_item_rating(
         &self,
         gql_ctx: &Context<'_>,
-        input: CreateUserYankIntegrationInput,
-    ) -> Result<usize> {
+        input: UpdateSeenItemRatingInput,
+    ) -> Result<IdObject> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.create_user_yank_integration(user_id, input).await
+        service.update_seen_item_rating(user_id, input).await
     }
 
-    /// Delete an integration for the currently logged in user.
-    async fn delete_user_integration(
+    /// Attach an uploaded file, like a ticket stub or a screenshot, to a seen item.
+    async fn add_seen_attachment(
         &self,
         gql_ctx: &Context<'_>,
-        integration_id: usize,
-        integration_lot: UserIntegrationLot,
-    ) -> Result<bool> {
+        input: AddSeenAttachmentInput,
+    ) -> Result<IdObject> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service
-            .delete_user_integration(user_id, integration_id, integration_lot)
-            .await
+        service.add_seen_attachment(user_id, input).await
     }
 
-    /// Add a notification platform for the currently logged in user.
-    async fn create_user_notification_platform(
+    /// Tag someone as having seen the media along with the user. If they are a
+    /// user of this instance, they are asked to confirm it.
+    async fn add_seen_companion(
         &self,
         gql_ctx: &Context<'_>,
-        input: CreateUserNotificationPlatformInput,
-    ) -> Result<usize> {
+        input: AddSeenCompanionInput,
+    ) -> Result<IdObject> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service
-            .create_user_notification_platform(user_id, input)
-            .await
+        service.add_seen_companion(user_id, input).await
     }
 
-    /// Test all notification platforms for the currently logged in user.
-    async fn test_user_notification_platforms(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+    /// Remove a companion from a seen item. The copy of the seen item that they
+    /// confirmed, if any, is not removed.
+    async fn delete_seen_companion(
+        &self,
+        gql_ctx: &Context<'_>,
+        companion_id: i32,
+    ) -> Result<bool> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service
-            .send_notifications_to_user_platforms(user_id, "Test notification message triggered.")
-            .await
+        service.delete_seen_companion(user_id, companion_id).await
     }
 
-    /// Delete a notification platform for the currently logged in user.
-    async fn delete_user_notification_platform(
+    /// Confirm or decline being tagged in the seen item of another user.
+    /// Confirming adds a copy of it to the history of the user.
+    async fn respond_to_seen_companion(
         &self,
         gql_ctx: &Context<'_>,
-        notification_id: usize,
+        companion_id: i32,
+        accept: bool,
     ) -> Result<bool> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
         service
-            .delete_user_notification_platform(user_id, notification_id)
+            .respond_to_seen_companion(user_id, companion_id, accept)
             .await
     }
 
-    /// Yank data from all integrations for the currently logged in user.
-    async fn yank_integration_data(&self, gql_ctx: &Context<'_>) -> Result<usize> {
+    /// Make an announcement that is displayed to all the users, eg: to warn them
+    /// about a maintenance. User must be an `Admin`.
+    async fn create_announcement(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: CreateAnnouncementInput,
+    ) -> Result<IdObject> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.yank_integrations_data_for_user(user_id).await
+        service.admin_account_guard(user_id).await?;
+        service.create_announcement(input).await
     }
 
-    /// Delete a user. The account making the user must an `Admin`.
-    async fn delete_user(&self, gql_ctx: &Context<'_>, to_delete_user_id: i32) -> Result<bool> {
+    /// Delete an announcement. User must be an `Admin`.
+    async fn delete_announcement(
+        &self,
+        gql_ctx: &Context<'_>,
+        announcement_id: i32,
+    ) -> Result<bool> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
         service.admin_account_guard(user_id).await?;
-        service.delete_user(to_delete_user_id).await
+        service.delete_announcement(announcement_id).await
     }
 
-    /// Toggle the monitor on a media for a user.
-    async fn toggle_media_monitor(
+    /// Propose a correction to the details of a media item. It is applied once
+    /// an admin approves it.
+    async fn create_metadata_correction(
         &self,
         gql_ctx: &Context<'_>,
-        to_monitor_metadata_id: i32,
+        input: CreateMetadataCorrectionInput,
+    ) -> Result<IdObject> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.create_metadata_correction(user_id, input).await
+    }
+
+    /// Approve or reject a pending metadata correction. Approved corrections
+    /// override the details from the provider. User must be an `Admin`.
+    async fn review_metadata_correction(
+        &self,
+        gql_ctx: &Context<'_>,
+        correction_id: i32,
+        approve: bool,
     ) -> Result<bool> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.admin_account_guard(user_id).await?;
         service
-            .toggle_media_monitor(user_id, to_monitor_metadata_id)
+            .review_metadata_correction(correction_id, approve)
             .await
     }
 
-    /// Create or update a reminder on a media for a user.
-    async fn create_media_reminder(
+    /// Delete a metadata correction. Users can delete their own pending ones,
+    /// while admins can delete any of them. The details from the provider are
+    /// restored the next time the media is updated.
+    async fn delete_metadata_correction(
         &self,
         gql_ctx: &Context<'_>,
-        input: CreateMediaReminderInput,
+        correction_id: i32,
     ) -> Result<bool> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.create_media_reminder(user_id, input).await
+        service
+            .delete_metadata_correction(user_id, correction_id)
+            .await
     }
 
-    /// Delete a reminder on a media for a user if it exists.
-    async fn delete_media_reminder(&self, gql_ctx: &Context<'_>, metadata_id: i32) -> Result<bool> {
+    /// Remove an attachment from a seen item.
+    async fn delete_seen_attachment(
+        &self,
+        gql_ctx: &Context<'_>,
+        attachment_id: i32,
+    ) -> Result<bool> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.delete_media_reminder(user_id, metadata_id).await
+        service.delete_seen_attachment(user_id, attachment_id).await
     }
 
-    /// Get a presigned URL (valid for 10 minutes) for a given file name.
-    async fn presigned_put_url(
+    /// Save a passage of a book that the user highlighted, or update an existing
+    /// one.
+    async fn create_or_update_highlight(
         &self,
         gql_ctx: &Context<'_>,
-        file_name: String,
-    ) -> Result<PresignedPutUrlResponse> {
+        input: CreateOrUpdateHighlightInput,
+    ) -> Result<IdObject> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
-        let (key, upload_url) = service
-            .file_storage_service
-            .get_presigned_put_url(file_name)
-            .await;
-        Ok(PresignedPutUrlResponse { upload_url, key })
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.create_or_update_highlight(user_id, input).await
     }
 
-    /// Generate an auth token without any expiry.
-    async fn generate_auth_token(&self, gql_ctx: &Context<'_>) -> Result<String> {
+    /// Delete a highlight of the user.
+    async fn delete_highlight(&self, gql_ctx: &Context<'_>, highlight_id: i32) -> Result<bool> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.generate_auth_token(user_id).await
+        service.delete_highlight(user_id, highlight_id).await
     }
 
-    /// Create, like or delete a comment on a review.
-    async fn create_review_comment(
+    /// Link a document synced by KOReader to a book so that its progress is
+    /// updated when the document is read.
+    async fn link_koreader_document(
         &self,
         gql_ctx: &Context<'_>,
-        input: CreateReviewCommentInput,
+        document_id: i32,
+        metadata_id: i32,
     ) -> Result<bool> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.create_review_comment(user_id, input).await
+        service
+            .link_koreader_document(user_id, document_id, metadata_id)
+            .await
     }
 
-    /// Recalculate all calendar events. User must an `Admin`.
-    async fn deploy_recalculate_calendar_events_job(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+    /// Map a title or ID sent by an integration to a media item, so that it is
+    /// used the next time the integration sends it.
+    async fn link_integration_mapping(
+        &self,
+        gql_ctx: &Context<'_>,
+        mapping_id: i32,
+        metadata_id: i32,
+    ) -> Result<bool> {
         let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
         let user_id = service.user_id_from_ctx(gql_ctx).await?;
-        service.admin_account_guard(user_id).await?;
-        service.deploy_recalculate_calendar_events_job().await
+        service
+            .link_integration_mapping(user_id, mapping_id, metadata_id)
+            .await
     }
-}
-
-pub struct MiscellaneousService {
-    pub db: DatabaseConnection,
-    file_storage_service: Arc<FileStorageService>,
-    pub perform_application_job: SqliteStorage<ApplicationJob>,
-    seen_progress_cache: Arc<Cache<ProgressUpdateCache, ()>>,
-    config: Arc<AppConfig>,
-}
-
-impl AuthProvider for MiscellaneousService {}
 
-impl MiscellaneousService {
-    #[allow(clippy::too_many_arguments)]
-    pub async fn new(
-        db: &DatabaseConnection,
-        config: Arc<AppConfig>,
-        file_storage_service: Arc<FileStorageService>,
-        perform_application_job: &SqliteStorage<ApplicationJob>,
-    ) -> Self {
-        let seen_progress_cache = Arc::new(Cache::new());
-        let cache_clone = seen_progress_cache.clone();
+    /// Deploy jobs to update all media item's metadata.
+    async fn update_all_metadata(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        service.update_all_metadata().await
+    }
 
-        tokio::spawn(async move {
-            cache_clone
-                .monitor(4, 0.25, ChronoDuration::minutes(3).to_std().unwrap())
-                .await
-        });
+    /// Create a custom media item.
+    async fn create_custom_media(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: CreateCustomMediaInput,
+    ) -> Result<CreateCustomMediaResult> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.create_custom_media(input, user_id).await
+    }
 
-        Self {
-            db: db.clone(),
-            config,
-            file_storage_service,
-            seen_progress_cache,
-            perform_application_job: perform_application_job.clone(),
-        }
+    /// Mark a user's progress on a specific media item.
+    async fn progress_update(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: ProgressUpdateInput,
+    ) -> Result<ProgressUpdateResultUnion> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.progress_update(input, user_id).await
     }
-}
 
-impl MiscellaneousService {
-    async fn core_details(&self) -> Result<CoreDetails> {
-        static LATEST_VERSION: OnceLock<String> = OnceLock::new();
-        let tag = if let Some(tag) = LATEST_VERSION.get() {
-            tag.clone()
-        } else {
-            #[derive(Serialize, Deserialize, Debug)]
-            struct GithubResponse {
-                tag_name: String,
-            }
-            let github_response =
-                surf::get("https://api.github.com/repos/ignisda/ryot/releases/latest")
-                    .header(USER_AGENT, USER_AGENT_STR)
-                    .await
-                    .map_err(|e| anyhow!(e))?
-                    .body_json::<GithubResponse>()
-                    .await
-                    .map_err(|e| anyhow!(e))?;
-            let tag = github_response
-                .tag_name
-                .strip_prefix('v')
-                .unwrap()
-                .to_owned();
-            LATEST_VERSION.set(tag.clone()).ok();
-            tag
-        };
-        let latest_version = Version::parse(&tag).unwrap();
-        let current_version = Version::parse(VERSION).unwrap();
-        let upgrade = if latest_version > current_version {
-            Some(if latest_version.major > current_version.major {
-                UpgradeType::Major
-            } else {
-                UpgradeType::Minor
-            })
-        } else {
-            None
-        };
-        Ok(CoreDetails {
-            upgrade,
-            version: VERSION.to_owned(),
-            author_name: AUTHOR.to_owned(),
-            docs_link: "https://ignisda.github.io/ryot".to_owned(),
-            repository_link: "https://github.com/ignisda/ryot".to_owned(),
-            page_limit: self.config.frontend.page_size,
-            videos_disabled: self.config.server.videos_disabled,
-            reviews_disabled: self.config.users.reviews_disabled,
-            default_credentials: self.config.server.default_credentials,
-            item_details_height: self.config.frontend.item_details_height,
-            username_change_allowed: self.config.users.allow_changing_username,
-            password_change_allowed: self.config.users.allow_changing_password,
-            preferences_change_allowed: self.config.users.allow_changing_preferences,
-            deploy_admin_jobs_allowed: self.config.server.deploy_admin_jobs_allowed,
-        })
+    /// Update progress in bulk.
+    async fn bulk_progress_update(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: Vec<ProgressUpdateInput>,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.bulk_progress_update(user_id, input).await
     }
 
-    fn get_integration_service(&self) -> IntegrationService {
-        IntegrationService::new()
+    /// Deploy a job to update a media item's metadata.
+    async fn deploy_update_metadata_job(
+        &self,
+        gql_ctx: &Context<'_>,
+        metadata_id: i32,
+    ) -> Result<String> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        service.deploy_update_metadata_job(metadata_id).await
     }
 
-    async fn metadata_assets(&self, meta: &metadata::Model) -> Result<GraphqlMediaAssets> {
-        let mut images = vec![];
-        let mut videos = vec![];
-        if let Some(imgs) = &meta.images {
-            for i in imgs.0.clone() {
-                images.push(get_stored_asset(i.url, &self.file_storage_service).await);
-            }
-        }
-        if let Some(vids) = &meta.videos {
-            for v in vids.0.clone() {
-                let url = get_stored_asset(v.identifier, &self.file_storage_service).await;
-                videos.push(GraphqlVideoAsset {
-                    source: v.source,
-                    video_id: url,
-                })
-            }
-        }
-        Ok(GraphqlMediaAssets { images, videos })
+    /// Merge a media item into another. This will move all `seen`, `collection`
+    /// and `review` associations with the new user and then delete the old media
+    /// item completely.
+    async fn merge_metadata(
+        &self,
+        gql_ctx: &Context<'_>,
+        merge_from: i32,
+        merge_into: i32,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        service.merge_metadata(merge_from, merge_into).await
     }
 
-    async fn generic_metadata(&self, metadata_id: i32) -> Result<MediaBaseData> {
-        let mut meta = match Metadata::find_by_id(metadata_id)
-            .one(&self.db)
+    /// Fetch details about a media and create a media item in the database.
+    async fn commit_media(
+        &self,
+        gql_ctx: &Context<'_>,
+        lot: MetadataLot,
+        source: MetadataSource,
+        identifier: String,
+    ) -> Result<IdObject> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .commit_media_for_user(user_id, lot, source, &identifier)
             .await
-            .unwrap()
-        {
-            Some(m) => m,
-            None => return Err(Error::new("The record does not exist".to_owned())),
-        };
-        let genres = meta
-            .find_related(Genre)
-            .all(&self.db)
+    }
+
+    /// Create a new user for the service. Also set their `lot` as admin if
+    /// they are the first user.
+    async fn register_user(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: UserInput,
+    ) -> Result<RegisterResult> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        service
+            .register_user(&input.username, &input.password, input.email)
             .await
-            .unwrap()
-            .into_iter()
-            .map(|g| g.name)
-            .collect();
-        #[derive(Debug, FromQueryResult)]
-        struct PartialCreator {
-            id: i32,
-            name: String,
-            image: Option<String>,
-            extra_information: CreatorExtraInformation,
-            role: String,
-        }
-        let crts = MetadataToCreator::find()
-            .expr(Expr::col(Asterisk))
-            .filter(metadata_to_creator::Column::MetadataId.eq(meta.id))
-            .join(
-                JoinType::Join,
-                metadata_to_creator::Relation::Creator
-                    .def()
-                    .on_condition(|left, right| {
-                        Condition::all().add(
-                            Expr::col((left, metadata_to_creator::Column::CreatorId))
-                                .equals((right, creator::Column::Id)),
-                        )
-                    }),
-            )
-            .order_by_asc(metadata_to_creator::Column::Index)
-            .into_model::<PartialCreator>()
-            .all(&self.db)
-            .await?;
-        let mut creators: HashMap<String, Vec<creator::Model>> = HashMap::new();
-        for cr in crts {
-            let creator = creator::Model {
-                id: cr.id,
-                name: cr.name,
-                image: cr.image,
-                extra_information: cr.extra_information,
-            };
-            creators
-                .entry(cr.role)
-                .and_modify(|e| {
-                    e.push(creator.clone());
-                })
-                .or_insert(vec![creator.clone()]);
-        }
-        if let Some(ref mut d) = meta.description {
-            *d = markdown_to_html_opts(
-                d,
-                &Options {
-                    compile: CompileOptions {
-                        allow_dangerous_html: true,
-                        allow_dangerous_protocol: true,
-                        ..CompileOptions::default()
-                    },
-                    ..Options::default()
-                },
-            )
-            .unwrap();
-        }
-        let creators = creators
-            .into_iter()
-            .map(|(name, items)| MetadataCreatorGroupedByRole { name, items })
-            .collect_vec();
+    }
 
-        let partial_metadata_ids = MetadataToPartialMetadata::find()
-            .select_only()
-            .column(metadata_to_partial_metadata::Column::PartialMetadataId)
-            .filter(metadata_to_partial_metadata::Column::MetadataId.eq(meta.id))
-            .filter(
-                metadata_to_partial_metadata::Column::Relation
-                    .eq(MetadataToPartialMetadataRelation::Suggestion),
-            )
-            .into_tuple::<i32>()
-            .all(&self.db)
-            .await?;
-        let suggestions = PartialMetadataModel::find()
-            .filter(partial_metadata::Column::Id.is_in(partial_metadata_ids))
-            .all(&self.db)
-            .await?;
-        let assets = self.metadata_assets(&meta).await.unwrap();
-        Ok(MediaBaseData {
-            model: meta,
-            creators,
-            assets,
-            genres,
-            suggestions,
-        })
+    /// Create the admin account while setting up the instance. Only possible
+    /// with the setup token when no users exist, even if registration is
+    /// disabled.
+    async fn setup_admin_user(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: UserInput,
+        setup_token: String,
+    ) -> Result<RegisterResult> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        service
+            .setup_admin_user(&input.username, &input.password, input.email, &setup_token)
+            .await
     }
 
-    async fn media_details(&self, metadata_id: i32) -> Result<GraphqlMediaDetails> {
-        let MediaBaseData {
-            model,
-            creators,
-            assets,
-            genres,
-            suggestions,
-        } = self.generic_metadata(metadata_id).await?;
-        let slug = slug::slugify(&model.title);
-        let identifier = &model.identifier;
-        let source_url = match model.source {
-            MetadataSource::Custom => None,
-            // DEV: This is updated by the specifics
-            MetadataSource::MangaUpdates => None,
-            MetadataSource::Itunes => Some(format!(
-                "https://podcasts.apple.com/us/podcast/{slug}/id{identifier}"
-            )),
-            MetadataSource::GoogleBooks => Some(format!(
-                "https://www.google.co.in/books/edition/{slug}/{identifier}"
-            )),
-            MetadataSource::Audible => {
-                Some(format!("https://www.audible.com/pd/{slug}/{identifier}"))
-            }
-            MetadataSource::Openlibrary => {
-                Some(format!("https://openlibrary.org/works/{identifier}/{slug}"))
-            }
-            MetadataSource::Tmdb => {
-                let bw = match model.lot {
-                    MetadataLot::Movie => "movie",
-                    MetadataLot::Show => "tv",
-                    _ => unreachable!(),
-                };
-                Some(format!(
-                    "https://www.themoviedb.org/{bw}/{identifier}-{slug}"
-                ))
-            }
-            MetadataSource::Listennotes => Some(format!(
-                "https://www.listennotes.com/podcasts/{slug}-{identifier}"
-            )),
-            MetadataSource::Igdb => Some(format!("https://www.igdb.com/games/{slug}")),
-            MetadataSource::Anilist => {
-                let bw = match model.lot {
-                    MetadataLot::Anime => "anime",
-                    MetadataLot::Manga => "manga",
-                    _ => unreachable!(),
-                };
-                Some(format!("https://anilist.co/{bw}/{identifier}/{slug}"))
-            }
-            MetadataSource::Mal => {
-                let bw = match model.lot {
-                    MetadataLot::Anime => "anime",
-                    MetadataLot::Manga => "manga",
-                    _ => unreachable!(),
-                };
-                Some(format!("https://myanimelist.net/{bw}/{identifier}/{slug}"))
-            }
-            MetadataSource::Vndb => Some(format!("https://vndb.org/{identifier}")),
-        };
+    /// Make a search request to a provider to check that it is configured
+    /// correctly while setting up the instance.
+    async fn setup_test_provider(
+        &self,
+        gql_ctx: &Context<'_>,
+        lot: MetadataLot,
+        source: MetadataSource,
+        setup_token: Option<String>,
+    ) -> Result<ProviderTestResult> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        service.setup_guard(gql_ctx, setup_token).await?;
+        Ok(service.test_provider(lot, source).await)
+    }
 
-        let group = {
-            let existing_partial_metadata = PartialMetadataModel::find()
-                .filter(partial_metadata::Column::Identifier.eq(&model.identifier))
-                .filter(partial_metadata::Column::Lot.eq(model.lot))
-                .filter(partial_metadata::Column::Source.eq(model.source))
-                .one(&self.db)
-                .await?;
-            match existing_partial_metadata {
-                None => None,
-                Some(epm) => {
-                    let association = PartialMetadataToMetadataGroup::find()
-                        .filter(
-                            partial_metadata_to_metadata_group::Column::PartialMetadataId
-                                .eq(epm.id),
-                        )
-                        .one(&self.db)
-                        .await?;
-                    match association {
-                        None => None,
-                        Some(a) => {
-                            let grp = a.find_related(MetadataGroup).one(&self.db).await?.unwrap();
-                            Some(GraphqlMediaGroup {
-                                id: grp.id,
-                                name: grp.title,
-                                part: a.part,
-                            })
-                        }
-                    }
-                }
-            }
-        };
+    /// Save the settings of providers to the config file while setting up the
+    /// instance. The server needs to be restarted for them to be used.
+    async fn setup_save_config(
+        &self,
+        gql_ctx: &Context<'_>,
+        settings: Vec<SetupConfigSettingInput>,
+        setup_token: Option<String>,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        service.setup_guard(gql_ctx, setup_token).await?;
+        service.setup_save_config(settings).await
+    }
 
-        let mut resp = GraphqlMediaDetails {
-            id: model.id,
-            lot: model.lot,
-            title: model.title,
-            source: model.source,
-            is_nsfw: model.is_nsfw,
-            identifier: model.identifier,
-            description: model.description,
-            publish_date: model.publish_date,
-            publish_year: model.publish_year,
-            provider_rating: model.provider_rating,
-            production_status: model.production_status,
-            book_specifics: None,
-            show_specifics: None,
-            movie_specifics: None,
-            manga_specifics: None,
-            anime_specifics: None,
-            podcast_specifics: None,
-            video_game_specifics: None,
-            audio_book_specifics: None,
-            visual_novel_specifics: None,
-            group,
-            genres,
-            creators,
-            source_url,
-            suggestions,
-            assets,
-        };
-        match model.specifics {
-            MediaSpecifics::AudioBook(a) => {
-                resp.audio_book_specifics = Some(a);
-            }
-            MediaSpecifics::Book(a) => {
-                resp.book_specifics = Some(a);
-            }
-            MediaSpecifics::Movie(a) => {
-                resp.movie_specifics = Some(a);
-            }
-            MediaSpecifics::Podcast(a) => {
-                resp.podcast_specifics = Some(a);
-            }
-            MediaSpecifics::Show(a) => {
-                resp.show_specifics = Some(a);
-            }
-            MediaSpecifics::VideoGame(a) => {
-                resp.video_game_specifics = Some(a);
-            }
-            MediaSpecifics::VisualNovel(a) => {
-                resp.visual_novel_specifics = Some(a);
-            }
-            MediaSpecifics::Anime(a) => {
-                resp.anime_specifics = Some(a);
-            }
-            MediaSpecifics::Manga(a) => {
-                if a.url.is_some() {
-                    resp.source_url = a.url.clone();
-                }
-                resp.manga_specifics = Some(a);
+    /// Login a user using their username and password and return an auth token.
+    async fn login_user(&self, gql_ctx: &Context<'_>, input: UserInput) -> Result<LoginResult> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        service
+            .login_user(&input.username, &input.password, gql_ctx)
+            .await
+    }
+
+    /// Logout a user from the server and delete their login token.
+    async fn logout_user(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        service.logout_user(gql_ctx).await
+    }
+
+    /// Update a user's profile details.
+    async fn update_user(&self, gql_ctx: &Context<'_>, input: UpdateUserInput) -> Result<IdObject> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.update_user(user_id, input).await
+    }
+
+    /// Verify the email of a user using the token that was sent to it.
+    async fn verify_user_email(&self, gql_ctx: &Context<'_>, token: String) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        service.verify_user_email(&token).await
+    }
+
+    /// Send the email to verify the email of a user again. Does not reveal
+    /// whether a user with this username exists.
+    async fn request_user_email_verification(
+        &self,
+        gql_ctx: &Context<'_>,
+        username: String,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        service.request_user_email_verification(&username).await
+    }
+
+    /// Send an email with a link to reset the password of a user. Does not
+    /// reveal whether a user with this username exists.
+    async fn request_user_password_reset(
+        &self,
+        gql_ctx: &Context<'_>,
+        username: String,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        service.request_user_password_reset(&username).await
+    }
+
+    /// Reset the password of a user using the token that was sent to their email.
+    async fn reset_user_password(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: ResetUserPasswordInput,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        service.reset_user_password(input).await
+    }
+
+    /// Recalculate the summary of the currently logged in user from scratch in
+    /// the background. Admins can also recalculate the summary of another user.
+    pub async fn regenerate_user_summary(
+        &self,
+        gql_ctx: &Context<'_>,
+        user_id: Option<i32>,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let current_user_id = service.user_id_from_ctx(gql_ctx).await?;
+        let user_id = match user_id {
+            Some(u) if u != current_user_id => {
+                service.admin_account_guard(current_user_id).await?;
+                user_by_id(&service.db, u).await?.id
             }
-            MediaSpecifics::Unknown => {}
+            _ => current_user_id,
         };
-        Ok(resp)
+        service.deploy_recalculate_summary_job(user_id).await.ok();
+        Ok(true)
     }
 
-    async fn user_media_details(&self, user_id: i32, metadata_id: i32) -> Result<UserMediaDetails> {
-        let media_details = self.media_details(metadata_id).await?;
-        let collections = self.media_in_collections(user_id, metadata_id).await?;
-        let reviews = self.item_reviews(user_id, Some(metadata_id), None).await?;
-        let history = self.seen_history(user_id, metadata_id).await?;
-        let in_progress = history
-            .iter()
-            .find(|h| h.state == SeenState::InProgress || h.state == SeenState::OnAHold)
-            .cloned();
-        let next_episode = history.first().and_then(|h| {
-            if let Some(s) = &media_details.show_specifics {
-                let current = s.get_episode(
-                    h.show_information.as_ref().unwrap().season,
-                    h.show_information.as_ref().unwrap().episode,
-                );
-                current.map(|(s, e)| UserMediaNextEpisode {
-                    season_number: Some(s.season_number),
-                    episode_number: Some(e.episode_number),
-                })
-            } else if let Some(p) = &media_details.podcast_specifics {
-                let current = p.get_episode(h.podcast_information.as_ref().unwrap().episode);
-                current.map(|i| UserMediaNextEpisode {
-                    season_number: None,
-                    episode_number: Some(i.number),
-                })
-            } else {
-                None
-            }
-        });
-        let next_episode = next_episode.filter(|ne| ne.episode_number.is_some());
-        let is_monitored = self.get_monitored_status(user_id, metadata_id).await?;
-        let metadata_alias = Alias::new("m");
-        let seen_alias = Alias::new("s");
-        let seen_select = Query::select()
-            .expr_as(
-                Expr::col((metadata_alias.clone(), TempMetadata::Id)),
-                Alias::new("metadata_id"),
-            )
-            .expr_as(
-                Func::count(Expr::col((seen_alias.clone(), TempSeen::MetadataId))),
-                Alias::new("num_times_seen"),
-            )
-            .from_as(TempMetadata::Table, metadata_alias.clone())
-            .join_as(
-                JoinType::LeftJoin,
-                TempSeen::Table,
-                seen_alias.clone(),
-                Expr::col((metadata_alias.clone(), TempMetadata::Id))
-                    .equals((seen_alias.clone(), TempSeen::MetadataId)),
-            )
-            .and_where(Expr::col((metadata_alias.clone(), TempMetadata::Id)).eq(metadata_id))
-            .group_by_col((metadata_alias.clone(), TempMetadata::Id))
-            .to_owned();
-        let stmt = self.get_db_stmt(seen_select);
-        let seen_by = self
-            .db
-            .query_one(stmt)
-            .await?
-            .map(|qr| qr.try_get_by_index::<i64>(1).unwrap())
-            .unwrap();
-        let seen_by: i32 = seen_by.try_into().unwrap();
-        let reminder = UserToMetadata::find()
-            .filter(user_to_metadata::Column::UserId.eq(user_id))
-            .filter(user_to_metadata::Column::MetadataId.eq(metadata_id))
-            .one(&self.db)
-            .await?
-            .and_then(|n| n.reminder);
-
-        let average_rating = if reviews.is_empty() {
-            None
-        } else {
-            Some(
-                reviews.iter().flat_map(|r| r.rating).sum::<Decimal>()
-                    / Decimal::from(reviews.len()),
-            )
-        };
-
-        Ok(UserMediaDetails {
-            collections,
-            reviews,
-            history,
-            in_progress,
-            next_episode,
-            is_monitored,
-            seen_by,
-            reminder,
-            average_rating,
-        })
-    }
-
-    async fn user_creator_details(
+    /// Change a user's preferences.
+    async fn update_user_preference(
         &self,
-        user_id: i32,
-        creator_id: i32,
-    ) -> Result<UserCreatorDetails> {
-        let reviews = self.item_reviews(user_id, None, Some(creator_id)).await?;
-        Ok(UserCreatorDetails { reviews })
+        gql_ctx: &Context<'_>,
+        input: UpdateUserPreferenceInput,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.update_user_preference(input, user_id).await
     }
 
-    async fn get_calendar_events(
+    /// Change the preferences that newly created users start with. User must be
+    /// an `Admin`.
+    async fn update_user_preferences_template(
         &self,
-        user_id: i32,
-        start_date: Option<NaiveDate>,
-        end_date: Option<NaiveDate>,
-        media_limit: Option<u64>,
-    ) -> Result<Vec<GraphqlCalendarEvent>> {
-        #[derive(Debug, FromQueryResult, Clone)]
-        struct CalEvent {
-            id: i32,
-            date: NaiveDate,
-            metadata_extra_information: Option<String>,
-            metadata_id: i32,
-            m_title: String,
-            m_images: Option<MetadataImages>,
-            m_lot: MetadataLot,
-            m_specifics: MediaSpecifics,
-        }
-        let all_events = CalendarEvent::find()
-            .column_as(
-                Expr::col((TempMetadata::Table, metadata::Column::Lot)),
-                "m_lot",
-            )
-            .column_as(
-                Expr::col((TempMetadata::Table, metadata::Column::Title)),
-                "m_title",
-            )
-            .column_as(
-                Expr::col((TempMetadata::Table, metadata::Column::Images)),
-                "m_images",
-            )
-            .column_as(
-                Expr::col((TempMetadata::Table, metadata::Column::Specifics)),
-                "m_specifics",
-            )
-            .filter(
-                Expr::col((TempUserToMetadata::Table, user_to_metadata::Column::UserId))
-                    .eq(user_id),
-            )
-            .join_rev(
-                JoinType::Join,
-                UserToMetadata::belongs_to(CalendarEvent)
-                    .from(user_to_metadata::Column::MetadataId)
-                    .to(calendar_event::Column::MetadataId)
-                    .into(),
-            )
-            .join_rev(
-                JoinType::Join,
-                Metadata::belongs_to(CalendarEvent)
-                    .from(metadata::Column::Id)
-                    .to(calendar_event::Column::MetadataId)
-                    .into(),
-            )
-            .order_by_asc(calendar_event::Column::Date)
-            .apply_if(end_date, |q, v| {
-                q.filter(calendar_event::Column::Date.gte(v))
-            })
-            .apply_if(start_date, |q, v| {
-                q.filter(calendar_event::Column::Date.lte(v))
-            })
-            .limit(media_limit)
-            .into_model::<CalEvent>()
-            .all(&self.db)
-            .await?;
-        let mut events = vec![];
-        for evt in all_events {
-            let mut calc = GraphqlCalendarEvent {
-                calendar_event_id: evt.id,
-                date: evt.date,
-                metadata_id: evt.metadata_id,
-                metadata_title: evt.m_title,
-                metadata_lot: evt.m_lot,
-                ..Default::default()
-            };
-            let mut image = None;
-            if let Some(ex) = &evt.metadata_extra_information {
-                let extra_info =
-                    serde_json::from_str::<SeenOrReviewOrCalendarEventExtraInformation>(ex)
-                        .unwrap();
-                match extra_info {
-                    SeenOrReviewOrCalendarEventExtraInformation::Show(s) => {
-                        calc.show_season_number = Some(s.season);
-                        calc.show_episode_number = Some(s.episode);
-                        if let MediaSpecifics::Show(sh) = evt.m_specifics {
-                            if let Some((_, ep)) = sh.get_episode(s.season, s.episode) {
-                                image = ep.poster_images.first().cloned();
-                            }
-                        }
-                    }
-                    SeenOrReviewOrCalendarEventExtraInformation::Podcast(p) => {
-                        calc.podcast_episode_number = Some(p.episode);
-                        if let MediaSpecifics::Podcast(po) = evt.m_specifics {
-                            if let Some(ep) = po.get_episode(p.episode) {
-                                image = ep.thumbnail.clone();
-                            }
-                        };
-                    }
-                }
-            }
-            if image.is_none() {
-                if let Some(images) = evt.m_images {
-                    if let Some(i) = images.0.first().cloned() {
-                        image = Some(get_stored_asset(i.url, &self.file_storage_service).await);
-                    }
-                }
-            }
-            calc.metadata_image = image;
-            events.push(calc);
-        }
-        Ok(events)
+        gql_ctx: &Context<'_>,
+        input: UpdateUserPreferenceInput,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.admin_account_guard(user_id).await?;
+        service.update_user_preferences_template(Some(input)).await
     }
 
-    async fn user_calendar_events(
-        &self,
-        user_id: i32,
-        input: UserCalendarEventInput,
-    ) -> Result<Vec<GroupedCalendarEvent>> {
-        let (end_date, start_date) = get_first_and_last_day_of_month(input.year, input.month);
-        let events = self
-            .get_calendar_events(user_id, Some(start_date), Some(end_date), None)
-            .await?;
-        let grouped_events = events
-            .into_iter()
-            .group_by(|event| event.date)
-            .into_iter()
-            .map(|(date, events)| GroupedCalendarEvent {
-                date,
-                events: events.collect(),
-            })
-            .collect();
-        Ok(grouped_events)
+    /// Make newly created users start with the default preferences again. User
+    /// must be an `Admin`.
+    async fn reset_user_preferences_template(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.admin_account_guard(user_id).await?;
+        service.update_user_preferences_template(None).await
     }
 
-    async fn user_upcoming_calendar_events(
+    /// Create a sink based integrations for the currently logged in user.
+    async fn create_user_sink_integration(
         &self,
-        user_id: i32,
-        input: UserUpcomingCalendarEventInput,
-    ) -> Result<Vec<GraphqlCalendarEvent>> {
-        let from_date = Utc::now().date_naive();
-        let (media_limit, to_date) = match input {
-            UserUpcomingCalendarEventInput::NextMedia(l) => (Some(l), None),
-            UserUpcomingCalendarEventInput::NextDays(d) => {
-                (None, from_date.checked_add_days(Days::new(d)))
-            }
-        };
-        let events = self
-            .get_calendar_events(user_id, to_date, Some(from_date), media_limit)
-            .await?;
-        Ok(events)
+        gql_ctx: &Context<'_>,
+        input: CreateUserSinkIntegrationInput,
+    ) -> Result<usize> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.create_user_sink_integration(user_id, input).await
     }
 
-    async fn seen_history(&self, user_id: i32, metadata_id: i32) -> Result<Vec<seen::Model>> {
-        let mut seen = Seen::find()
-            .filter(seen::Column::UserId.eq(user_id))
-            .filter(seen::Column::MetadataId.eq(metadata_id))
-            .order_by_desc(seen::Column::LastUpdatedOn)
-            .all(&self.db)
-            .await
-            .unwrap();
-        modify_seen_elements(&mut seen);
-        Ok(seen)
+    /// Create a yank based integrations for the currently logged in user.
+    async fn create_user_yank_integration(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: CreateUserYankIntegrationInput,
+    ) -> Result<usize> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.create_user_yank_integration(user_id, input).await
     }
 
-    async fn media_list(
+    /// Delete an integration for the currently logged in user.
+    async fn delete_user_integration(
         &self,
-        user_id: i32,
-        input: MediaListInput,
-    ) -> Result<SearchResults<MediaListItem>> {
-        let preferences = user_by_id(&self.db, user_id).await?.preferences;
-        let meta = UserToMetadata::find()
-            .filter(user_to_metadata::Column::UserId.eq(user_id))
-            .apply_if(
-                match input.filter.as_ref().and_then(|f| f.general) {
-                    Some(MediaGeneralFilter::ExplicitlyMonitored) => Some(true),
-                    _ => None,
-                },
-                |query, v| query.filter(user_to_metadata::Column::Monitored.eq(v)),
+        gql_ctx: &Context<'_>,
+        integration_id: usize,
+        integration_lot: UserIntegrationLot,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .delete_user_integration(user_id, integration_id, integration_lot)
+            .await
+    }
+
+    /// Change how the progress that an integration of the currently logged in
+    /// user sends is applied.
+    async fn update_user_integration_progress_settings(
+        &self,
+        gql_ctx: &Context<'_>,
+        integration_id: usize,
+        integration_lot: UserIntegrationLot,
+        progress_settings: IntegrationProgressSettings,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .update_user_integration_progress_settings(
+                user_id,
+                integration_id,
+                integration_lot,
+                progress_settings,
             )
-            .all(&self.db)
             .await
-            .unwrap();
-        let distinct_meta_ids = meta.into_iter().map(|m| m.metadata_id).collect_vec();
+    }
 
-        let metadata_alias = Alias::new("m");
-        let seen_alias = Alias::new("s");
-        let review_alias = Alias::new("r");
-        let mtu_alias = Alias::new("mtu");
+    /// Save the progress of profiles on the media server of a Jellyfin or Plex
+    /// integration of the currently logged in user to other users. Only admins
+    /// can map profiles, since the progress is saved to the accounts of others.
+    async fn update_user_integration_user_mappings(
+        &self,
+        gql_ctx: &Context<'_>,
+        integration_id: usize,
+        user_mappings: Vec<IntegrationUserMapping>,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.admin_account_guard(user_id).await?;
+        service
+            .update_user_integration_user_mappings(user_id, integration_id, user_mappings)
+            .await
+    }
 
-        let mut main_select = Query::select()
-            .expr(Expr::col((metadata_alias.clone(), Asterisk)))
-            .from_as(TempMetadata::Table, metadata_alias.clone())
-            .and_where_option(match preferences.general.display_nsfw {
-                true => None,
-                false => Some(Expr::col((metadata_alias.clone(), TempMetadata::IsNsfw)).eq(false)),
-            })
-            .and_where(Expr::col((metadata_alias.clone(), TempMetadata::Lot)).eq(input.lot))
-            .and_where(
-                Expr::col((metadata_alias.clone(), TempMetadata::Id))
-                    .is_in(distinct_meta_ids.clone()),
-            )
-            .to_owned();
+    /// Add a notification platform for the currently logged in user.
+    async fn create_user_notification_platform(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: CreateUserNotificationPlatformInput,
+    ) -> Result<usize> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .create_user_notification_platform(user_id, input)
+            .await
+    }
 
-        if let Some(v) = input.query {
-            let get_contains_expr = |col: metadata::Column| {
-                get_case_insensitive_like_query(
-                    Func::cast_as(Expr::col((metadata_alias.clone(), col)), Alias::new("text")),
-                    &v,
-                )
-            };
-            main_select = main_select
-                .cond_where(
-                    Cond::any()
-                        .add(get_contains_expr(metadata::Column::Title))
-                        .add(get_contains_expr(metadata::Column::Description)),
-                )
-                .to_owned();
-        };
+    /// Test all notification platforms for the currently logged in user.
+    async fn test_user_notification_platforms(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .send_notifications_to_user_platforms(user_id, "Test notification message triggered.")
+            .await
+    }
 
-        let order_by = input
-            .sort
-            .as_ref()
-            .map(|a| Order::from(a.order))
-            .unwrap_or(Order::Asc);
+    /// Delete a notification platform for the currently logged in user.
+    async fn delete_user_notification_platform(
+        &self,
+        gql_ctx: &Context<'_>,
+        notification_id: usize,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .delete_user_notification_platform(user_id, notification_id)
+            .await
+    }
 
-        match input.sort {
-            None => {
-                main_select = main_select
-                    .order_by((metadata_alias.clone(), metadata::Column::Title), order_by)
-                    .to_owned();
-            }
-            Some(s) => {
-                match s.by {
-                    MediaSortBy::Title => {
-                        main_select = main_select
-                            .order_by((metadata_alias.clone(), metadata::Column::Title), order_by)
-                            .to_owned();
-                    }
-                    MediaSortBy::ReleaseDate => {
-                        main_select = main_select
-                            .order_by_with_nulls(
-                                (metadata_alias.clone(), metadata::Column::PublishYear),
-                                order_by,
-                                NullOrdering::Last,
-                            )
-                            .to_owned();
-                    }
-                    MediaSortBy::LastSeen => {
-                        let last_seen = Alias::new("last_seen");
-                        let sub_select = Query::select()
-                            .column(TempSeen::MetadataId)
-                            .expr_as(
-                                Func::max(Expr::col(TempSeen::FinishedOn)),
-                                last_seen.clone(),
-                            )
-                            .from(TempSeen::Table)
-                            .and_where(Expr::col(TempSeen::UserId).eq(user_id))
-                            .group_by_col(TempSeen::MetadataId)
-                            .to_owned();
-                        main_select = main_select
-                            .join_subquery(
-                                JoinType::LeftJoin,
-                                sub_select,
-                                seen_alias.clone(),
-                                Expr::col((metadata_alias.clone(), TempMetadata::Id))
-                                    .equals((seen_alias.clone(), TempSeen::MetadataId)),
-                            )
-                            .order_by_with_nulls(
-                                (seen_alias.clone(), last_seen),
-                                order_by,
-                                NullOrdering::Last,
-                            )
-                            .to_owned();
-                    }
-                    MediaSortBy::LastUpdated => {
-                        main_select = main_select
-                            .join_as(
-                                JoinType::LeftJoin,
-                                TempUserToMetadata::Table,
-                                mtu_alias.clone(),
-                                Expr::col((metadata_alias.clone(), TempMetadata::Id))
-                                    .equals((mtu_alias.clone(), TempUserToMetadata::MetadataId))
-                                    .and(
-                                        Expr::col((mtu_alias.clone(), TempUserToMetadata::UserId))
-                                            .eq(user_id),
-                                    ),
-                            )
-                            .order_by(
-                                (mtu_alias.clone(), TempUserToMetadata::LastUpdatedOn),
-                                order_by,
-                            )
-                            .to_owned();
-                    }
-                    MediaSortBy::Rating => {
-                        let alias_name = "average_rating";
-                        main_select = main_select
-                            .expr_as(
-                                Func::avg(Expr::col((review_alias.clone(), TempReview::Rating))),
-                                Alias::new(alias_name),
-                            )
-                            .join_as(
-                                JoinType::LeftJoin,
-                                TempReview::Table,
-                                review_alias.clone(),
-                                Expr::col((metadata_alias.clone(), TempMetadata::Id))
-                                    .equals((review_alias.clone(), TempReview::MetadataId))
-                                    .and(
-                                        Expr::col((review_alias.clone(), TempReview::UserId))
-                                            .eq(user_id),
-                                    ),
-                            )
-                            .group_by_col((metadata_alias.clone(), TempMetadata::Id))
-                            .order_by_expr_with_nulls(
-                                Expr::cust(alias_name),
-                                order_by,
-                                NullOrdering::Last,
-                            )
-                            .to_owned();
-                    }
-                };
-            }
-        };
+    /// Yank data from all integrations for the currently logged in user.
+    async fn yank_integration_data(&self, gql_ctx: &Context<'_>) -> Result<usize> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.yank_integrations_data_for_user(user_id).await
+    }
 
-        if let Some(f) = input.filter {
-            if let Some(s) = f.collection {
-                let all_media = MetadataToCollection::find()
-                    .filter(metadata_to_collection::Column::CollectionId.eq(s))
-                    .all(&self.db)
-                    .await?;
-                let collections = all_media.into_iter().map(|m| m.metadata_id).collect_vec();
-                main_select = main_select
-                    .and_where(
-                        Expr::col((metadata_alias.clone(), TempMetadata::Id)).is_in(collections),
-                    )
-                    .to_owned();
-            }
-            if let Some(s) = f.general {
-                let reviews = if matches!(s, MediaGeneralFilter::All) {
-                    vec![]
-                } else {
-                    Review::find()
-                        .filter(review::Column::UserId.eq(user_id))
-                        .all(&self.db)
-                        .await?
-                        .into_iter()
-                        .map(|r| r.metadata_id)
-                        .collect_vec()
-                };
-                match s {
-                    MediaGeneralFilter::ExplicitlyMonitored => {}
-                    MediaGeneralFilter::All => {}
-                    MediaGeneralFilter::Rated => {
-                        main_select = main_select
-                            .and_where(
-                                Expr::col((metadata_alias.clone(), TempMetadata::Id))
-                                    .is_in(reviews),
-                            )
-                            .to_owned();
-                    }
-                    MediaGeneralFilter::Unrated => {
-                        main_select = main_select
-                            .and_where(
-                                Expr::col((metadata_alias.clone(), TempMetadata::Id))
-                                    .is_not_in(reviews),
-                            )
-                            .to_owned();
-                    }
-                    MediaGeneralFilter::Dropped
-                    | MediaGeneralFilter::InProgress
-                    | MediaGeneralFilter::Completed
-                    | MediaGeneralFilter::OnAHold => {
+    /// Delete a user. The account making the user must an `Admin`.
+    async fn delete_user(&self, gql_ctx: &Context<'_>, to_delete_user_id: i32) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.admin_account_guard(user_id).await?;
+        service.delete_user(to_delete_user_id).await
+    }
+
+    /// Toggle whether a media is a favorite of the user. Returns whether it is
+    /// now a favorite.
+    async fn toggle_media_favorite(&self, gql_ctx: &Context<'_>, metadata_id: i32) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.toggle_media_favorite(user_id, metadata_id).await
+    }
+
+    /// Toggle the monitor on a media for a user.
+    async fn toggle_media_monitor(
+        &self,
+        gql_ctx: &Context<'_>,
+        to_monitor_metadata_id: i32,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service
+            .toggle_media_monitor(user_id, to_monitor_metadata_id)
+            .await
+    }
+
+    /// Create or update a reminder on a media for a user.
+    async fn create_media_reminder(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: CreateMediaReminderInput,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.create_media_reminder(user_id, input).await
+    }
+
+    /// Delete a reminder on a media for a user if it exists.
+    async fn delete_media_reminder(&self, gql_ctx: &Context<'_>, metadata_id: i32) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.delete_media_reminder(user_id, metadata_id).await
+    }
+
+    /// Get a presigned URL (valid for 10 minutes) for a given file name.
+    async fn presigned_put_url(
+        &self,
+        gql_ctx: &Context<'_>,
+        file_name: String,
+    ) -> Result<PresignedPutUrlResponse> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        let (key, upload_url) = service
+            .file_storage_service
+            .get_presigned_put_url(file_name, user_id)
+            .await;
+        Ok(PresignedPutUrlResponse { upload_url, key })
+    }
+
+    /// Generate an auth token without any expiry.
+    async fn generate_auth_token(&self, gql_ctx: &Context<'_>) -> Result<String> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.generate_auth_token(user_id).await
+    }
+
+    /// Create, like or delete a comment on a review.
+    async fn create_review_comment(
+        &self,
+        gql_ctx: &Context<'_>,
+        input: CreateReviewCommentInput,
+    ) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.create_review_comment(user_id, input).await
+    }
+
+    /// Recalculate all calendar events. User must an `Admin`.
+    async fn deploy_recalculate_calendar_events_job(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.admin_account_guard(user_id).await?;
+        service.deploy_recalculate_calendar_events_job().await
+    }
+
+    /// Analyze the database, rebuild the indexes of the searched tables and
+    /// remove media not associated with any user. User must be an `Admin`.
+    async fn deploy_database_maintenance_job(&self, gql_ctx: &Context<'_>) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.admin_account_guard(user_id).await?;
+        service.deploy_database_maintenance_job().await
+    }
+
+    /// Export the configuration of the instance as JSON, leaving out the secrets
+    /// and other masked settings. User must be an `Admin`.
+    async fn export_instance_config(&self, gql_ctx: &Context<'_>) -> Result<String> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.admin_account_guard(user_id).await?;
+        service.export_instance_config().await
+    }
+
+    /// Save a configuration exported from another instance to the config file.
+    /// The server needs to be restarted for it to be used. User must be an
+    /// `Admin`.
+    async fn import_instance_config(&self, gql_ctx: &Context<'_>, config: String) -> Result<bool> {
+        let service = gql_ctx.data_unchecked::<Arc<MiscellaneousService>>();
+        let user_id = service.user_id_from_ctx(gql_ctx).await?;
+        service.admin_account_guard(user_id).await?;
+        service.import_instance_config(config).await
+    }
+}
+
+pub struct MiscellaneousService {
+    pub db: DatabaseConnection,
+    file_storage_service: Arc<FileStorageService>,
+    pub perform_application_job: SqliteStorage<ApplicationJob>,
+    seen_progress_cache: Arc<Cache<ProgressUpdateCache, ()>>,
+    login_attempts_cache: Arc<Cache<LoginAttemptsCache, u32>>,
+    /// The upcoming releases that were last recommended to each user.
+    coming_soon_cache: Arc<Cache<i32, Vec<ComingSoonItem>>>,
+    /// The users whose summary is queued to be recalculated.
+    pending_summary_recalculations: Arc<Mutex<HashSet<i32>>>,
+    last_maintenance_report: Arc<Mutex<Option<DatabaseMaintenanceReport>>>,
+    /// The token that has to be sent to set up the instance before the first
+    /// user is created. It is printed to the log on startup.
+    setup_token: String,
+    /// Held while a user is created, so that only one can become the first
+    /// admin.
+    user_creation_lock: Arc<tokio::sync::Mutex<()>>,
+    pub config: Arc<AppConfig>,
+}
+
+impl AuthProvider for MiscellaneousService {}
+
+impl MiscellaneousService {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn new(
+        db: &DatabaseConnection,
+        config: Arc<AppConfig>,
+        file_storage_service: Arc<FileStorageService>,
+        perform_application_job: &SqliteStorage<ApplicationJob>,
+    ) -> Self {
+        let seen_progress_cache = Arc::new(Cache::new());
+        let cache_clone = seen_progress_cache.clone();
+
+        tokio::spawn(async move {
+            cache_clone
+                .monitor(4, 0.25, ChronoDuration::minutes(3).to_std().unwrap())
+                .await
+        });
+
+        let login_attempts_cache = Arc::new(Cache::new());
+        let cache_clone = login_attempts_cache.clone();
+
+        tokio::spawn(async move {
+            cache_clone
+                .monitor(4, 0.25, ChronoDuration::minutes(3).to_std().unwrap())
+                .await
+        });
+
+        let coming_soon_cache = Arc::new(Cache::new());
+        let cache_clone = coming_soon_cache.clone();
+
+        tokio::spawn(async move {
+            cache_clone
+                .monitor(4, 0.25, ChronoDuration::minutes(3).to_std().unwrap())
+                .await
+        });
+
+        let setup_token = nanoid!(32);
+        if User::find().count(db).await.unwrap_or_default() == 0 {
+            tracing::info!("Use this token to set up the instance: {}", setup_token);
+        }
+
+        Self {
+            db: db.clone(),
+            config,
+            file_storage_service,
+            seen_progress_cache,
+            login_attempts_cache,
+            coming_soon_cache,
+            pending_summary_recalculations: Arc::new(Mutex::new(HashSet::new())),
+            last_maintenance_report: Arc::new(Mutex::new(None)),
+            setup_token,
+            user_creation_lock: Arc::new(tokio::sync::Mutex::new(())),
+            perform_application_job: perform_application_job.clone(),
+        }
+    }
+}
+
+impl MiscellaneousService {
+    async fn core_details(&self) -> Result<CoreDetails> {
+        static LATEST_VERSION: OnceLock<String> = OnceLock::new();
+        let tag = if let Some(tag) = LATEST_VERSION.get() {
+            tag.clone()
+        } else {
+            #[derive(Serialize, Deserialize, Debug)]
+            struct GithubResponse {
+                tag_name: String,
+            }
+            let github_response =
+                surf::get("https://api.github.com/repos/ignisda/ryot/releases/latest")
+                    .header(USER_AGENT, USER_AGENT_STR)
+                    .await
+                    .map_err(|e| anyhow!(e))?
+                    .body_json::<GithubResponse>()
+                    .await
+                    .map_err(|e| anyhow!(e))?;
+            let tag = github_response
+                .tag_name
+                .strip_prefix('v')
+                .unwrap()
+                .to_owned();
+            LATEST_VERSION.set(tag.clone()).ok();
+            tag
+        };
+        let latest_version = Version::parse(&tag).unwrap();
+        let current_version = Version::parse(VERSION).unwrap();
+        let upgrade = if latest_version > current_version {
+            Some(if latest_version.major > current_version.major {
+                UpgradeType::Major
+            } else {
+                UpgradeType::Minor
+            })
+        } else {
+            None
+        };
+        Ok(CoreDetails {
+            upgrade,
+            version: VERSION.to_owned(),
+            author_name: AUTHOR.to_owned(),
+            docs_link: "https://ignisda.github.io/ryot".to_owned(),
+            repository_link: "https://github.com/ignisda/ryot".to_owned(),
+            page_limit: self.config.frontend.page_size,
+            videos_disabled: self.config.server.videos_disabled,
+            reviews_disabled: self.config.users.reviews_disabled,
+            default_credentials: self.config.server.default_credentials,
+            item_details_height: self.config.frontend.item_details_height,
+            username_change_allowed: self.config.users.allow_changing_username,
+            password_change_allowed: self.config.users.allow_changing_password,
+            preferences_change_allowed: self.config.users.allow_changing_preferences,
+            deploy_admin_jobs_allowed: self.config.server.deploy_admin_jobs_allowed,
+        })
+    }
+
+    fn get_integration_service(&self) -> IntegrationService {
+        IntegrationService::new()
+    }
+
+    async fn metadata_assets(&self, meta: &metadata::Model) -> Result<GraphqlMediaAssets> {
+        let mut images = vec![];
+        let mut videos = vec![];
+        if let Some(imgs) = &meta.images {
+            for i in imgs.0.clone() {
+                images.push(get_stored_asset(i.url, &self.file_storage_service).await);
+            }
+        }
+        if let Some(vids) = &meta.videos {
+            for v in vids.0.clone() {
+                let url = get_stored_asset(v.identifier, &self.file_storage_service).await;
+                videos.push(GraphqlVideoAsset {
+                    source: v.source,
+                    video_id: url,
+                })
+            }
+        }
+        Ok(GraphqlMediaAssets { images, videos })
+    }
+
+    async fn generic_metadata(&self, metadata_id: i32) -> Result<MediaBaseData> {
+        let mut meta = match Metadata::find_by_id(metadata_id)
+            .one(&self.db)
+            .await
+            .unwrap()
+        {
+            Some(m) => m,
+            None => return Err(Error::new("The record does not exist".to_owned())),
+        };
+        let genres = meta
+            .find_related(Genre)
+            .all(&self.db)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|g| g.name)
+            .collect();
+        #[derive(Debug, FromQueryResult)]
+        struct PartialCreator {
+            id: i32,
+            name: String,
+            image: Option<String>,
+            extra_information: CreatorExtraInformation,
+            role: String,
+        }
+        let crts = MetadataToCreator::find()
+            .expr(Expr::col(Asterisk))
+            .filter(metadata_to_creator::Column::MetadataId.eq(meta.id))
+            .join(
+                JoinType::Join,
+                metadata_to_creator::Relation::Creator
+                    .def()
+                    .on_condition(|left, right| {
+                        Condition::all().add(
+                            Expr::col((left, metadata_to_creator::Column::CreatorId))
+                                .equals((right, creator::Column::Id)),
+                        )
+                    }),
+            )
+            .order_by_asc(metadata_to_creator::Column::Index)
+            .into_model::<PartialCreator>()
+            .all(&self.db)
+            .await?;
+        let mut creators: HashMap<String, Vec<creator::Model>> = HashMap::new();
+        for cr in crts {
+            let creator = creator::Model {
+                id: cr.id,
+                name: cr.name,
+                image: cr.image,
+                extra_information: cr.extra_information,
+            };
+            creators
+                .entry(cr.role)
+                .and_modify(|e| {
+                    e.push(creator.clone());
+                })
+                .or_insert(vec![creator.clone()]);
+        }
+        if let Some(ref mut d) = meta.description {
+            *d = markdown_to_html_opts(
+                d,
+                &Options {
+                    compile: CompileOptions {
+                        allow_dangerous_html: true,
+                        allow_dangerous_protocol: true,
+                        ..CompileOptions::default()
+                    },
+                    ..Options::default()
+                },
+            )
+            .unwrap();
+        }
+        let creators = creators
+            .into_iter()
+            .map(|(name, items)| MetadataCreatorGroupedByRole { name, items })
+            .collect_vec();
+
+        let partial_metadata_ids = MetadataToPartialMetadata::find()
+            .select_only()
+            .column(metadata_to_partial_metadata::Column::PartialMetadataId)
+            .filter(metadata_to_partial_metadata::Column::MetadataId.eq(meta.id))
+            .filter(
+                metadata_to_partial_metadata::Column::Relation
+                    .eq(MetadataToPartialMetadataRelation::Suggestion),
+            )
+            .into_tuple::<i32>()
+            .all(&self.db)
+            .await?;
+        let suggestions = PartialMetadataModel::find()
+            .filter(partial_metadata::Column::Id.is_in(partial_metadata_ids))
+            .all(&self.db)
+            .await?;
+        let assets = self.metadata_assets(&meta).await.unwrap();
+        Ok(MediaBaseData {
+            model: meta,
+            creators,
+            assets,
+            genres,
+            suggestions,
+        })
+    }
+
+    async fn media_details(
+        &self,
+        user_id: Option<i32>,
+        metadata_id: i32,
+    ) -> Result<GraphqlMediaDetails> {
+        let MediaBaseData {
+            model,
+            creators,
+            assets,
+            genres,
+            mut suggestions,
+        } = self.generic_metadata(metadata_id).await?;
+        if self.user_nsfw_display(user_id).await? == UserNsfwDisplay::Hide {
+            let nsfw_identifiers: Vec<String> = Metadata::find()
+                .select_only()
+                .column(metadata::Column::Identifier)
+                .filter(metadata::Column::IsNsfw.eq(true))
+                .filter(metadata::Column::Lot.eq(model.lot))
+                .filter(
+                    metadata::Column::Identifier
+                        .is_in(suggestions.iter().map(|s| s.identifier.clone())),
+                )
+                .into_tuple()
+                .all(&self.db)
+                .await?;
+            suggestions.retain(|s| !nsfw_identifiers.contains(&s.identifier));
+        }
+        let localization = match user_id {
+            Some(user_id) => user_by_id(&self.db, user_id)
+                .await?
+                .preferences
+                .languages
+                .for_source(model.source)
+                .and_then(|l| model.localizations.clone()?.0.remove(&l)),
+            None => None,
+        };
+        let slug = slug::slugify(&model.title);
+        let identifier = &model.identifier;
+        let source_url = match model.source {
+            MetadataSource::Custom => None,
+            // DEV: This is updated by the specifics
+            MetadataSource::MangaUpdates => None,
+            MetadataSource::Itunes => Some(format!(
+                "https://podcasts.apple.com/us/podcast/{slug}/id{identifier}"
+            )),
+            MetadataSource::GoogleBooks => Some(format!(
+                "https://www.google.co.in/books/edition/{slug}/{identifier}"
+            )),
+            MetadataSource::Audible => {
+                Some(format!("https://www.audible.com/pd/{slug}/{identifier}"))
+            }
+            MetadataSource::Openlibrary => {
+                Some(format!("https://openlibrary.org/works/{identifier}/{slug}"))
+            }
+            MetadataSource::Tmdb => {
+                let bw = match model.lot {
+                    MetadataLot::Movie => "movie",
+                    MetadataLot::Show => "tv",
+                    _ => unreachable!(),
+                };
+                Some(format!(
+                    "https://www.themoviedb.org/{bw}/{identifier}-{slug}"
+                ))
+            }
+            MetadataSource::Listennotes => Some(format!(
+                "https://www.listennotes.com/podcasts/{slug}-{identifier}"
+            )),
+            MetadataSource::PodcastIndex => {
+                Some(format!("https://podcastindex.org/podcast/{identifier}"))
+            }
+            MetadataSource::Igdb => Some(format!("https://www.igdb.com/games/{slug}")),
+            MetadataSource::Anilist => {
+                let bw = match model.lot {
+                    MetadataLot::Anime => "anime",
+                    MetadataLot::Manga => "manga",
+                    _ => unreachable!(),
+                };
+                Some(format!("https://anilist.co/{bw}/{identifier}/{slug}"))
+            }
+            MetadataSource::Mal => {
+                let bw = match model.lot {
+                    MetadataLot::Anime => "anime",
+                    MetadataLot::Manga => "manga",
+                    _ => unreachable!(),
+                };
+                Some(format!("https://myanimelist.net/{bw}/{identifier}/{slug}"))
+            }
+            MetadataSource::Vndb => Some(format!("https://vndb.org/{identifier}")),
+            MetadataSource::MangaDex => Some(format!("https://mangadex.org/title/{identifier}")),
+            MetadataSource::Web => Some(identifier.clone()),
+            MetadataSource::MusicBrainz => Some(format!(
+                "https://musicbrainz.org/release-group/{identifier}"
+            )),
+            MetadataSource::BoardGameGeek => {
+                Some(format!("https://boardgamegeek.com/boardgame/{identifier}"))
+            }
+            MetadataSource::ComicVine => Some(format!(
+                "https://comicvine.gamespot.com/volume/4050-{identifier}"
+            )),
+        };
+
+        let group = {
+            let existing_partial_metadata = PartialMetadataModel::find()
+                .filter(partial_metadata::Column::Identifier.eq(&model.identifier))
+                .filter(partial_metadata::Column::Lot.eq(model.lot))
+                .filter(partial_metadata::Column::Source.eq(model.source))
+                .one(&self.db)
+                .await?;
+            match existing_partial_metadata {
+                None => None,
+                Some(epm) => {
+                    let association = PartialMetadataToMetadataGroup::find()
+                        .filter(
+                            partial_metadata_to_metadata_group::Column::PartialMetadataId
+                                .eq(epm.id),
+                        )
+                        .one(&self.db)
+                        .await?;
+                    match association {
+                        None => None,
+                        Some(a) => {
+                            let grp = a.find_related(MetadataGroup).one(&self.db).await?.unwrap();
+                            Some(GraphqlMediaGroup {
+                                id: grp.id,
+                                name: grp.title,
+                                part: a.part,
+                            })
+                        }
+                    }
+                }
+            }
+        };
+
+        let (title, description) = match localization {
+            Some(l) => (l.title, l.description.or(model.description)),
+            None => (model.title, model.description),
+        };
+        let mut resp = GraphqlMediaDetails {
+            id: model.id,
+            lot: model.lot,
+            title,
+            alternative_titles: model.alternative_titles.unwrap_or_default().0,
+            source: model.source,
+            is_nsfw: model.is_nsfw,
+            is_deleted_on_provider: model.is_deleted_on_provider,
+            awards: model.awards.unwrap_or_default().0,
+            identifier: model.identifier,
+            description,
+            publish_date: model.publish_date,
+            publish_year: model.publish_year,
+            provider_rating: model.provider_rating,
+            production_status: model.production_status,
+            book_specifics: None,
+            show_specifics: None,
+            movie_specifics: None,
+            manga_specifics: None,
+            anime_specifics: None,
+            podcast_specifics: None,
+            video_game_specifics: None,
+            audio_book_specifics: None,
+            visual_novel_specifics: None,
+            article_specifics: None,
+            music_specifics: None,
+            board_game_specifics: None,
+            comic_specifics: None,
+            group,
+            genres,
+            creators,
+            source_url,
+            suggestions,
+            assets,
+        };
+        match model.specifics {
+            MediaSpecifics::AudioBook(a) => {
+                resp.audio_book_specifics = Some(a);
+            }
+            MediaSpecifics::Book(a) => {
+                resp.book_specifics = Some(a);
+            }
+            MediaSpecifics::Movie(a) => {
+                resp.movie_specifics = Some(a);
+            }
+            MediaSpecifics::Podcast(a) => {
+                resp.podcast_specifics = Some(a);
+            }
+            MediaSpecifics::Show(a) => {
+                resp.show_specifics = Some(a);
+            }
+            MediaSpecifics::VideoGame(a) => {
+                resp.video_game_specifics = Some(a);
+            }
+            MediaSpecifics::VisualNovel(a) => {
+                resp.visual_novel_specifics = Some(a);
+            }
+            MediaSpecifics::Anime(a) => {
+                resp.anime_specifics = Some(a);
+            }
+            MediaSpecifics::Manga(a) => {
+                if a.url.is_some() {
+                    resp.source_url = a.url.clone();
+                }
+                resp.manga_specifics = Some(a);
+            }
+            MediaSpecifics::Article(a) => {
+                resp.article_specifics = Some(a);
+            }
+            MediaSpecifics::Music(a) => {
+                resp.music_specifics = Some(a);
+            }
+            MediaSpecifics::BoardGame(a) => {
+                resp.board_game_specifics = Some(a);
+            }
+            MediaSpecifics::Comic(a) => {
+                resp.comic_specifics = Some(a);
+            }
+            MediaSpecifics::Unknown => {}
+        };
+        Ok(resp)
+    }
+
+    async fn user_media_details(&self, user_id: i32, metadata_id: i32) -> Result<UserMediaDetails> {
+        let media_details = self.media_details(Some(user_id), metadata_id).await?;
+        let collections = self.media_in_collections(user_id, metadata_id).await?;
+        let reviews = self.item_reviews(user_id, Some(metadata_id), None).await?;
+        let history = self.seen_history(user_id, metadata_id).await?;
+        let in_progress = history
+            .iter()
+            .find(|h| h.state == SeenState::InProgress || h.state == SeenState::OnAHold)
+            .cloned();
+        let next_episode = history.first().and_then(|h| {
+            if let Some(s) = &media_details.show_specifics {
+                let current = s.get_episode(
+                    h.show_information.as_ref().unwrap().season,
+                    h.show_information.as_ref().unwrap().episode,
+                );
+                current.map(|(s, e)| UserMediaNextEpisode {
+                    season_number: Some(s.season_number),
+                    episode_number: Some(e.episode_number),
+                })
+            } else if let Some(p) = &media_details.podcast_specifics {
+                let current = p.get_episode(h.podcast_information.as_ref().unwrap().episode);
+                current.map(|i| UserMediaNextEpisode {
+                    season_number: None,
+                    episode_number: Some(i.number),
+                })
+            } else {
+                None
+            }
+        });
+        let next_episode = next_episode.filter(|ne| ne.episode_number.is_some());
+        let is_monitored = self.get_monitored_status(user_id, metadata_id).await?;
+        let is_favorite = self
+            .favorite_metadata_ids(user_id)
+            .await?
+            .contains(&metadata_id);
+        let metadata_alias = Alias::new("m");
+        let seen_alias = Alias::new("s");
+        let seen_select = Query::select()
+            .expr_as(
+                Expr::col((metadata_alias.clone(), TempMetadata::Id)),
+                Alias::new("metadata_id"),
+            )
+            .expr_as(
+                Func::count(Expr::col((seen_alias.clone(), TempSeen::MetadataId))),
+                Alias::new("num_times_seen"),
+            )
+            .from_as(TempMetadata::Table, metadata_alias.clone())
+            .join_as(
+                JoinType::LeftJoin,
+                TempSeen::Table,
+                seen_alias.clone(),
+                Expr::col((metadata_alias.clone(), TempMetadata::Id))
+                    .equals((seen_alias.clone(), TempSeen::MetadataId)),
+            )
+            .and_where(Expr::col((metadata_alias.clone(), TempMetadata::Id)).eq(metadata_id))
+            .group_by_col((metadata_alias.clone(), TempMetadata::Id))
+            .to_owned();
+        let stmt = self.get_db_stmt(seen_select);
+        let seen_by = self
+            .db
+            .query_one(stmt)
+            .await?
+            .map(|qr| qr.try_get_by_index::<i64>(1).unwrap())
+            .unwrap();
+        let seen_by: i32 = seen_by.try_into().unwrap();
+        let user_to_meta = UserToMetadata::find()
+            .filter(user_to_metadata::Column::UserId.eq(user_id))
+            .filter(user_to_metadata::Column::MetadataId.eq(metadata_id))
+            .one(&self.db)
+            .await?;
+        let reminder = user_to_meta.as_ref().and_then(|n| n.reminder.clone());
+        let playtime = user_to_meta.and_then(|n| n.playtime);
+
+        let average_rating = if reviews.is_empty() {
+            None
+        } else {
+            Some(
+                reviews.iter().flat_map(|r| r.rating).sum::<Decimal>()
+                    / Decimal::from(reviews.len()),
+            )
+        };
+        let seen_ratings: Vec<Option<Decimal>> = Seen::find()
+            .select_only()
+            .column(seen::Column::Rating)
+            .filter(seen::Column::MetadataId.eq(metadata_id))
+            .filter(seen::Column::Rating.is_not_null())
+            .into_tuple()
+            .all(&self.db)
+            .await?;
+        let average_seen_rating = if seen_ratings.is_empty() {
+            None
+        } else {
+            let review_scale = user_by_id(&self.db, user_id)
+                .await?
+                .preferences
+                .general
+                .review_scale;
+            Some(rating_in_review_scale(
+                seen_ratings.iter().flatten().sum::<Decimal>() / Decimal::from(seen_ratings.len()),
+                review_scale,
+            ))
+        };
+
+        Ok(UserMediaDetails {
+            collections,
+            reviews,
+            history,
+            in_progress,
+            next_episode,
+            is_monitored,
+            is_favorite,
+            seen_by,
+            reminder,
+            playtime,
+            average_rating,
+            average_seen_rating,
+        })
+    }
+
+    async fn user_creator_details(
+        &self,
+        user_id: i32,
+        creator_id: i32,
+    ) -> Result<UserCreatorDetails> {
+        let reviews = self.item_reviews(user_id, None, Some(creator_id)).await?;
+        Ok(UserCreatorDetails { reviews })
+    }
+
+    async fn get_calendar_events(
+        &self,
+        user_id: i32,
+        start_date: Option<NaiveDate>,
+        end_date: Option<NaiveDate>,
+        media_limit: Option<u64>,
+    ) -> Result<Vec<GraphqlCalendarEvent>> {
+        #[derive(Debug, FromQueryResult, Clone)]
+        struct CalEvent {
+            id: i32,
+            date: NaiveDate,
+            metadata_extra_information: Option<String>,
+            metadata_id: i32,
+            m_title: String,
+            m_images: Option<MetadataImages>,
+            m_lot: MetadataLot,
+            m_specifics: MediaSpecifics,
+        }
+        let all_events = CalendarEvent::find()
+            .column_as(
+                Expr::col((TempMetadata::Table, metadata::Column::Lot)),
+                "m_lot",
+            )
+            .column_as(
+                Expr::col((TempMetadata::Table, metadata::Column::Title)),
+                "m_title",
+            )
+            .column_as(
+                Expr::col((TempMetadata::Table, metadata::Column::Images)),
+                "m_images",
+            )
+            .column_as(
+                Expr::col((TempMetadata::Table, metadata::Column::Specifics)),
+                "m_specifics",
+            )
+            .filter(
+                Expr::col((TempUserToMetadata::Table, user_to_metadata::Column::UserId))
+                    .eq(user_id),
+            )
+            .join_rev(
+                JoinType::Join,
+                UserToMetadata::belongs_to(CalendarEvent)
+                    .from(user_to_metadata::Column::MetadataId)
+                    .to(calendar_event::Column::MetadataId)
+                    .into(),
+            )
+            .join_rev(
+                JoinType::Join,
+                Metadata::belongs_to(CalendarEvent)
+                    .from(metadata::Column::Id)
+                    .to(calendar_event::Column::MetadataId)
+                    .into(),
+            )
+            .order_by_asc(calendar_event::Column::Date)
+            .apply_if(end_date, |q, v| {
+                q.filter(calendar_event::Column::Date.gte(v))
+            })
+            .apply_if(start_date, |q, v| {
+                q.filter(calendar_event::Column::Date.lte(v))
+            })
+            .limit(media_limit)
+            .into_model::<CalEvent>()
+            .all(&self.db)
+            .await?;
+        let mut events = vec![];
+        for evt in all_events {
+            let mut calc = GraphqlCalendarEvent {
+                calendar_event_id: evt.id,
+                date: evt.date,
+                metadata_id: evt.metadata_id,
+                metadata_title: evt.m_title,
+                metadata_lot: evt.m_lot,
+                ..Default::default()
+            };
+            let mut image = None;
+            if let Some(ex) = &evt.metadata_extra_information {
+                let extra_info =
+                    serde_json::from_str::<SeenOrReviewOrCalendarEventExtraInformation>(ex)
+                        .unwrap();
+                match extra_info {
+                    SeenOrReviewOrCalendarEventExtraInformation::Show(s) => {
+                        calc.show_season_number = Some(s.season);
+                        calc.show_episode_number = Some(s.episode);
+                        if let MediaSpecifics::Show(sh) = evt.m_specifics {
+                            if let Some((_, ep)) = sh.get_episode(s.season, s.episode) {
+                                image = ep.poster_images.first().cloned();
+                            }
+                        }
+                    }
+                    SeenOrReviewOrCalendarEventExtraInformation::Podcast(p) => {
+                        calc.podcast_episode_number = Some(p.episode);
+                        if let MediaSpecifics::Podcast(po) = evt.m_specifics {
+                            if let Some(ep) = po.get_episode(p.episode) {
+                                image = ep.thumbnail.clone();
+                            }
+                        };
+                    }
+                }
+            }
+            if image.is_none() {
+                if let Some(images) = evt.m_images {
+                    if let Some(i) = images.0.first().cloned() {
+                        image = Some(get_stored_asset(i.url, &self.file_storage_service).await);
+                    }
+                }
+            }
+            calc.metadata_image = image;
+            events.push(calc);
+        }
+        Ok(events)
+    }
+
+    async fn user_coming_soon(&self, user_id: i32) -> Result<Vec<ComingSoonItem>> {
+        let items = self.fetch_user_coming_soon(user_id).await?;
+        self.coming_soon_cache
+            .insert(
+                user_id,
+                items.clone(),
+                ChronoDuration::hours(COMING_SOON_CACHE_HOURS)
+                    .to_std()
+                    .unwrap(),
+            )
+            .await;
+        Ok(items)
+    }
+
+    async fn fetch_user_coming_soon(&self, user_id: i32) -> Result<Vec<ComingSoonItem>> {
+        let preferences = self.user_preferences(user_id).await?;
+        let media = &preferences.features_enabled.media;
+        if !media.enabled {
+            return Ok(vec![]);
+        }
+        let nsfw_display = self.user_nsfw_display(Some(user_id)).await?;
+        let following = &preferences.following;
+        let is_followed = |item: &MediaUpcomingItem| {
+            if following.genres.is_empty() && following.creators.is_empty() {
+                return true;
+            }
+            let matches = |followed: &Vec<String>, values: &Vec<String>| {
+                values
+                    .iter()
+                    .any(|v| followed.iter().any(|f| f.eq_ignore_ascii_case(v)))
+            };
+            matches(&following.genres, &item.genres) || matches(&following.creators, &item.creators)
+        };
+        let providers = [
+            (MetadataLot::Movie, MetadataSource::Tmdb, media.movie),
+            (
+                MetadataLot::VideoGame,
+                MetadataSource::Igdb,
+                media.video_game,
+            ),
+            (MetadataLot::Book, MetadataSource::Openlibrary, media.book),
+        ];
+        let mut items = vec![];
+        for (lot, source, enabled) in providers {
+            if !enabled {
+                continue;
+            }
+            let provider = self
+                .get_provider(lot, source, preferences.languages.for_source(source))
+                .await?;
+            let upcoming = match provider.upcoming(&following.creators).await {
+                Ok(upcoming) => upcoming,
+                Err(e) => {
+                    tracing::error!("Error while getting upcoming {:?}: {:?}", lot, e);
+                    continue;
+                }
+            };
+            let existing: Vec<String> = UserToMetadata::find()
+                .filter(user_to_metadata::Column::UserId.eq(user_id))
+                .inner_join(Metadata)
+                .filter(metadata::Column::Lot.eq(lot))
+                .filter(metadata::Column::Source.eq(source))
+                .select_only()
+                .column(metadata::Column::Identifier)
+                .into_tuple()
+                .all(&self.db)
+                .await?;
+            // DEV: Not all providers say which items are NSFW, so also use what we know
+            let nsfw: Vec<String> = Metadata::find()
+                .filter(metadata::Column::Lot.eq(lot))
+                .filter(metadata::Column::Source.eq(source))
+                .filter(metadata::Column::IsNsfw.eq(true))
+                .filter(
+                    metadata::Column::Identifier
+                        .is_in(upcoming.iter().map(|u| u.item.identifier.clone())),
+                )
+                .select_only()
+                .column(metadata::Column::Identifier)
+                .into_tuple()
+                .all(&self.db)
+                .await?;
+            items.extend(upcoming.into_iter().filter_map(|u| {
+                if existing.contains(&u.item.identifier) || !is_followed(&u) {
+                    return None;
+                }
+                let is_nsfw = u.is_nsfw.unwrap_or_default() || nsfw.contains(&u.item.identifier);
+                if is_nsfw && nsfw_display == UserNsfwDisplay::Hide {
+                    return None;
+                }
+                Some(ComingSoonItem {
+                    lot,
+                    source,
+                    release_date: u.release_date,
+                    item: u.item,
+                    blur: is_nsfw && nsfw_display == UserNsfwDisplay::Blur,
+                })
+            }));
+        }
+        items.sort_by_key(|i| i.release_date);
+        Ok(items)
+    }
+
+    /// The upcoming releases that were last recommended to the user. They are
+    /// requested from the providers in the background when there are none, since
+    /// that needs a request for every creator that the user follows.
+    async fn cached_user_coming_soon(&self, user_id: i32) -> Result<Vec<ComingSoonItem>> {
+        if let Some(items) = self.coming_soon_cache.get(&user_id).await {
+            return Ok(items.to_vec());
+        }
+        self.perform_application_job
+            .clone()
+            .push(ApplicationJob::RefreshUserComingSoon(user_id))
+            .await?;
+        Ok(vec![])
+    }
+
+    pub async fn refresh_user_coming_soon(&self, user_id: i32) -> Result<()> {
+        self.user_coming_soon(user_id).await?;
+        Ok(())
+    }
+
+    async fn user_calendar_events(
+        &self,
+        user_id: i32,
+        input: UserCalendarEventInput,
+    ) -> Result<Vec<GroupedCalendarEvent>> {
+        let (end_date, start_date) = get_first_and_last_day_of_month(input.year, input.month);
+        let events = self
+            .get_calendar_events(user_id, Some(start_date), Some(end_date), None)
+            .await?;
+        let grouped_events = events
+            .into_iter()
+            .group_by(|event| event.date)
+            .into_iter()
+            .map(|(date, events)| GroupedCalendarEvent {
+                date,
+                events: events.collect(),
+            })
+            .collect();
+        Ok(grouped_events)
+    }
+
+    async fn user_upcoming_calendar_events(
+        &self,
+        user_id: i32,
+        input: UserUpcomingCalendarEventInput,
+    ) -> Result<Vec<GraphqlCalendarEvent>> {
+        let from_date = Utc::now().date_naive();
+        let (media_limit, to_date) = match input {
+            UserUpcomingCalendarEventInput::NextMedia(l) => (Some(l), None),
+            UserUpcomingCalendarEventInput::NextDays(d) => {
+                (None, from_date.checked_add_days(Days::new(d)))
+            }
+        };
+        let events = self
+            .get_calendar_events(user_id, to_date, Some(from_date), media_limit)
+            .await?;
+        Ok(events)
+    }
+
+    async fn seen_context_statistics(
+        &self,
+        user_id: i32,
+        lot: Option<MetadataLot>,
+    ) -> Result<Vec<SeenContextStatistic>> {
+        let seen = Seen::find()
+            .filter(seen::Column::UserId.eq(user_id))
+            .filter(seen::Column::State.eq(SeenState::Completed))
+            .find_also_related(Metadata)
+            .apply_if(lot, |query, v| query.filter(metadata::Column::Lot.eq(v)))
+            .all(&self.db)
+            .await?;
+        let mut counts: HashMap<(MetadataLot, Option<String>), usize> = HashMap::new();
+        for (s, m) in seen {
+            let lot = m.unwrap().lot;
+            *counts.entry((lot, s.context)).or_default() += 1;
+        }
+        let statistics = counts
+            .into_iter()
+            .map(|((lot, context), count)| SeenContextStatistic {
+                lot,
+                context,
+                count,
+            })
+            .sorted_by(|a, b| {
+                a.lot
+                    .to_string()
+                    .cmp(&b.lot.to_string())
+                    .then(b.count.cmp(&a.count))
+            })
+            .collect();
+        Ok(statistics)
+    }
+
+    async fn user_media_statistics(
+        &self,
+        user_id: i32,
+        input: UserMediaStatisticsInput,
+    ) -> Result<Vec<UserMediaStatistic>> {
+        let review_scale = user_by_id(&self.db, user_id)
+            .await?
+            .preferences
+            .general
+            .review_scale;
+        let statistics = UserStatistic::find()
+            .filter(user_statistic::Column::UserId.eq(user_id))
+            .apply_if(input.lot, |query, v| {
+                query.filter(user_statistic::Column::Lot.eq(v))
+            })
+            .apply_if(input.start_date, |query, v| {
+                query.filter(user_statistic::Column::Month.gte(v.with_day(1).unwrap()))
+            })
+            .apply_if(input.end_date, |query, v| {
+                query.filter(user_statistic::Column::Month.lte(v))
+            })
+            .order_by_asc(user_statistic::Column::Month)
+            .order_by_asc(user_statistic::Column::Lot)
+            .all(&self.db)
+            .await?;
+        let statistics = statistics
+            .into_iter()
+            .filter(|s| s.seen_count > 0 || s.review_count > 0)
+            .map(|s| UserMediaStatistic {
+                lot: s.lot,
+                month: s.month,
+                seen: s.seen_count,
+                reviews: s.review_count,
+                average_rating: match s.rating_count > 0 {
+                    true => Some(rating_in_review_scale(
+                        s.rating_sum / Decimal::from(s.rating_count),
+                        review_scale,
+                    )),
+                    false => None,
+                },
+            })
+            .collect();
+        Ok(statistics)
+    }
+
+    /// Rebuild the statistics of the user from all the seen items and reviews,
+    /// which corrects the ones that were changed without being counted (eg: by
+    /// deleting media). This is done when the summaries are regenerated.
+    async fn recalculate_user_statistics(&self, user_id: i32) -> Result<()> {
+        let mut deltas: HashMap<(MetadataLot, NaiveDate), UserStatisticDelta> = HashMap::new();
+        let seen = Seen::find()
+            .select_only()
+            .column(seen::Column::FinishedOn)
+            .column(seen::Column::StartedOn)
+            .column(seen::Column::LastUpdatedOn)
+            .column(metadata::Column::Lot)
+            .inner_join(Metadata)
+            .filter(seen::Column::UserId.eq(user_id))
+            .filter(seen::Column::State.eq(SeenState::Completed))
+            .into_tuple::<(
+                Option<NaiveDate>,
+                Option<NaiveDate>,
+                DateTimeUtc,
+                MetadataLot,
+            )>()
+            .all(&self.db)
+            .await?;
+        for (finished_on, started_on, last_updated_on, lot) in seen {
+            let date = seen::statistic_date(finished_on, started_on, last_updated_on);
+            deltas
+                .entry((lot, date.with_day(1).unwrap()))
+                .or_default()
+                .seen += 1;
+        }
+        let reviews = Review::find()
+            .select_only()
+            .column(review::Column::PostedOn)
+            .column(review::Column::Rating)
+            .column(metadata::Column::Lot)
+            .inner_join(Metadata)
+            .filter(review::Column::UserId.eq(user_id))
+            .into_tuple::<(DateTimeUtc, Option<Decimal>, MetadataLot)>()
+            .all(&self.db)
+            .await?;
+        for (posted_on, rating, lot) in reviews {
+            let delta = deltas
+                .entry((lot, posted_on.date_naive().with_day(1).unwrap()))
+                .or_default();
+            delta.reviews += 1;
+            if let Some(rating) = rating {
+                delta.rating_sum += rating;
+                delta.ratings += 1;
+            }
+        }
+        UserStatistic::delete_many()
+            .filter(user_statistic::Column::UserId.eq(user_id))
+            .exec(&self.db)
+            .await?;
+        if deltas.is_empty() {
+            return Ok(());
+        }
+        let statistics =
+            deltas
+                .into_iter()
+                .map(|((lot, month), delta)| user_statistic::ActiveModel {
+                    user_id: ActiveValue::Set(user_id),
+                    lot: ActiveValue::Set(lot),
+                    month: ActiveValue::Set(month),
+                    seen_count: ActiveValue::Set(delta.seen),
+                    review_count: ActiveValue::Set(delta.reviews),
+                    rating_sum: ActiveValue::Set(delta.rating_sum),
+                    rating_count: ActiveValue::Set(delta.ratings),
+                    ..Default::default()
+                });
+        // DEV: Rows that were counted while rebuilding are overwritten
+        UserStatistic::insert_many(statistics)
+            .on_conflict(
+                OnConflict::columns([
+                    user_statistic::Column::UserId,
+                    user_statistic::Column::Lot,
+                    user_statistic::Column::Month,
+                ])
+                .update_columns([
+                    user_statistic::Column::SeenCount,
+                    user_statistic::Column::ReviewCount,
+                    user_statistic::Column::RatingSum,
+                    user_statistic::Column::RatingCount,
+                ])
+                .to_owned(),
+            )
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn in_progress(&self, user_id: i32) -> Result<Vec<InProgressMediaItem>> {
+        let mut seen = Seen::find()
+            .filter(seen::Column::UserId.eq(user_id))
+            .filter(seen::Column::State.is_in([SeenState::InProgress, SeenState::OnAHold]))
+            .find_also_related(Metadata)
+            .order_by_desc(seen::Column::LastUpdatedOn)
+            .all(&self.db)
+            .await?;
+        let nsfw_display = self.user_nsfw_display(Some(user_id)).await?;
+        let review_scale = self.user_preferences(user_id).await?.general.review_scale;
+        let mut items = vec![];
+        let mut added = HashSet::new();
+        for (s, m) in seen.iter_mut() {
+            let m = m.as_ref().unwrap();
+            // DEV: Only the latest open entry is returned for shows and podcasts
+            // that have one per episode
+            if !added.insert(m.id) || (m.is_nsfw && nsfw_display == UserNsfwDisplay::Hide) {
+                continue;
+            }
+            modify_seen_elements(std::slice::from_mut(s));
+            s.rating = s.rating.map(|r| rating_in_review_scale(r, review_scale));
+            items.push(InProgressMediaItem {
+                details: MediaSearchItemWithLot {
+                    details: MediaSearchItem {
+                        identifier: m.id.to_string(),
+                        image: self.metadata_assets(m).await?.images.first().cloned(),
+                        title: m.title.clone(),
+                        publish_year: m.publish_year,
+                    },
+                    lot: m.lot,
+                    blur: m.is_nsfw && nsfw_display == UserNsfwDisplay::Blur,
+                },
+                seen: s.clone(),
+            });
+        }
+        Ok(items)
+    }
+
+    async fn user_dashboard(&self, user_id: i32) -> Result<UserDashboard> {
+        let preferences = self.user_preferences(user_id).await?;
+        // DEV: Hidden sections are not computed since the client will not render them
+        let is_shown = |lot: DashboardElementLot| {
+            preferences
+                .dashboard
+                .sections
+                .iter()
+                .any(|s| s.section == lot && !s.hidden)
+        };
+        let in_progress_collection = Collection::find()
+            .filter(collection::Column::UserId.eq(user_id))
+            .filter(collection::Column::Name.eq(DefaultCollection::InProgress.to_string()))
+            .one(&self.db)
+            .await?;
+        let in_progress = match in_progress_collection {
+            Some(c) if is_shown(DashboardElementLot::InProgress) => {
+                self.collection_contents(
+                    Some(user_id),
+                    CollectionContentsInput {
+                        collection_id: c.id,
+                        page: None,
+                        take: None,
+                    },
+                )
+                .await?
+                .results
+                .items
+            }
+            _ => vec![],
+        };
+        let upcoming = match is_shown(DashboardElementLot::Upcoming) {
+            true => {
+                self.user_upcoming_calendar_events(
+                    user_id,
+                    UserUpcomingCalendarEventInput::NextDays(DASHBOARD_UPCOMING_DAYS),
+                )
+                .await?
+            }
+            false => vec![],
+        };
+        let nsfw_display = self.user_nsfw_display(Some(user_id)).await?;
+        let pinned = &preferences.dashboard;
+        // DEV: Pinned items that have been deleted since are silently skipped
+        let metas = Metadata::find()
+            .filter(metadata::Column::Id.is_in(pinned.pinned_media.clone()))
+            .all(&self.db)
+            .await?;
+        let mut pinned_media = vec![];
+        for m in metas
+            .into_iter()
+            .sorted_by_key(|m| pinned.pinned_media.iter().position(|p| *p == m.id))
+        {
+            if m.is_nsfw && nsfw_display == UserNsfwDisplay::Hide {
+                continue;
+            }
+            pinned_media.push(MediaSearchItemWithLot {
+                details: MediaSearchItem {
+                    identifier: m.id.to_string(),
+                    image: self.metadata_assets(&m).await?.images.first().cloned(),
+                    title: m.title.clone(),
+                    publish_year: m.publish_year,
+                },
+                lot: m.lot,
+                blur: m.is_nsfw && nsfw_display == UserNsfwDisplay::Blur,
+            });
+        }
+        let pinned_collections = self
+            .collections(user_id, None)
+            .await?
+            .into_iter()
+            .filter(|c| pinned.pinned_collections.contains(&c.id))
+            .sorted_by_key(|c| pinned.pinned_collections.iter().position(|p| *p == c.id))
+            .collect();
+        let recommendations = match is_shown(DashboardElementLot::Recommendations) {
+            true => self.cached_user_coming_soon(user_id).await?,
+            false => vec![],
+        };
+        Ok(UserDashboard {
+            in_progress,
+            upcoming,
+            pinned_media,
+            pinned_collections,
+            recommendations,
+        })
+    }
+
+    async fn seen_history(&self, user_id: i32, metadata_id: i32) -> Result<Vec<seen::Model>> {
+        let mut seen = Seen::find()
+            .filter(seen::Column::UserId.eq(user_id))
+            .filter(seen::Column::MetadataId.eq(metadata_id))
+            .order_by_desc(seen::Column::LastUpdatedOn)
+            .all(&self.db)
+            .await
+            .unwrap();
+        modify_seen_elements(&mut seen);
+        let review_scale = user_by_id(&self.db, user_id)
+            .await?
+            .preferences
+            .general
+            .review_scale;
+        seen.iter_mut().for_each(|s| {
+            s.rating = s.rating.map(|r| rating_in_review_scale(r, review_scale));
+        });
+        let attachments = SeenAttachment::find()
+            .filter(seen_attachment::Column::SeenId.is_in(seen.iter().map(|s| s.id)))
+            .order_by_asc(seen_attachment::Column::CreatedOn)
+            .all(&self.db)
+            .await?;
+        for mut attachment in attachments {
+            attachment.url = self
+                .file_storage_service
+                .get_presigned_url(attachment.key.clone())
+                .await;
+            if let Some(s) = seen.iter_mut().find(|s| s.id == attachment.seen_id) {
+                s.attachments.push(attachment);
+            }
+        }
+        let companions = SeenCompanion::find()
+            .filter(seen_companion::Column::SeenId.is_in(seen.iter().map(|s| s.id)))
+            .order_by_asc(seen_companion::Column::CreatedOn)
+            .all(&self.db)
+            .await?;
+        for companion in companions {
+            if let Some(s) = seen.iter_mut().find(|s| s.id == companion.seen_id) {
+                s.companions.push(companion);
+            }
+        }
+        Ok(seen)
+    }
+
+    async fn media_list(
+        &self,
+        user_id: i32,
+        input: MediaListInput,
+    ) -> Result<SearchResults<MediaListItem>> {
+        let preferences = user_by_id(&self.db, user_id).await?.preferences;
+        let nsfw_display = self.user_nsfw_display(Some(user_id)).await?;
+        let meta = UserToMetadata::find()
+            .filter(user_to_metadata::Column::UserId.eq(user_id))
+            .apply_if(
+                match input.filter.as_ref().and_then(|f| f.general) {
+                    Some(MediaGeneralFilter::ExplicitlyMonitored) => Some(true),
+                    _ => None,
+                },
+                |query, v| query.filter(user_to_metadata::Column::Monitored.eq(v)),
+            )
+            .all(&self.db)
+            .await
+            .unwrap();
+        let distinct_meta_ids = meta.into_iter().map(|m| m.metadata_id).collect_vec();
+
+        let metadata_alias = Alias::new("m");
+        let seen_alias = Alias::new("s");
+        let review_alias = Alias::new("r");
+        let mtu_alias = Alias::new("mtu");
+
+        let mut main_select = Query::select()
+            .expr(Expr::col((metadata_alias.clone(), Asterisk)))
+            .from_as(TempMetadata::Table, metadata_alias.clone())
+            .and_where_option(match nsfw_display {
+                UserNsfwDisplay::Hide => {
+                    Some(Expr::col((metadata_alias.clone(), TempMetadata::IsNsfw)).eq(false))
+                }
+                _ => None,
+            })
+            .and_where(Expr::col((metadata_alias.clone(), TempMetadata::Lot)).eq(input.lot))
+            .and_where(
+                Expr::col((metadata_alias.clone(), TempMetadata::Id))
+                    .is_in(distinct_meta_ids.clone()),
+            )
+            .to_owned();
+
+        if let Some(v) = input.query {
+            let get_contains_expr = |col: metadata::Column, v: &str| {
+                get_case_insensitive_like_query(
+                    Func::cast_as(Expr::col((metadata_alias.clone(), col)), Alias::new("text")),
+                    v,
+                )
+            };
+            main_select = main_select
+                .cond_where(
+                    Cond::any()
+                        .add(get_contains_expr(metadata::Column::Title, &v))
+                        .add(get_contains_expr(
+                            metadata::Column::NormalizedTitle,
+                            &normalize_text(&v),
+                        ))
+                        .add(get_contains_expr(metadata::Column::AlternativeTitles, &v))
+                        .add(get_contains_expr(metadata::Column::Description, &v)),
+                )
+                .to_owned();
+        };
+
+        let order_by = input
+            .sort
+            .as_ref()
+            .map(|a| Order::from(a.order))
+            .unwrap_or(Order::Asc);
+
+        match input.sort {
+            None => {
+                main_select = main_select
+                    .order_by((metadata_alias.clone(), metadata::Column::Title), order_by)
+                    .to_owned();
+            }
+            Some(s) => {
+                match s.by {
+                    MediaSortBy::Title => {
+                        main_select = main_select
+                            .order_by((metadata_alias.clone(), metadata::Column::Title), order_by)
+                            .to_owned();
+                    }
+                    MediaSortBy::ReleaseDate => {
+                        main_select = main_select
+                            .order_by_with_nulls(
+                                (metadata_alias.clone(), metadata::Column::PublishYear),
+                                order_by,
+                                NullOrdering::Last,
+                            )
+                            .to_owned();
+                    }
+                    MediaSortBy::LastSeen => {
+                        let last_seen = Alias::new("last_seen");
+                        let sub_select = Query::select()
+                            .column(TempSeen::MetadataId)
+                            .expr_as(
+                                Func::max(Expr::col(TempSeen::FinishedOn)),
+                                last_seen.clone(),
+                            )
+                            .from(TempSeen::Table)
+                            .and_where(Expr::col(TempSeen::UserId).eq(user_id))
+                            .group_by_col(TempSeen::MetadataId)
+                            .to_owned();
+                        main_select = main_select
+                            .join_subquery(
+                                JoinType::LeftJoin,
+                                sub_select,
+                                seen_alias.clone(),
+                                Expr::col((metadata_alias.clone(), TempMetadata::Id))
+                                    .equals((seen_alias.clone(), TempSeen::MetadataId)),
+                            )
+                            .order_by_with_nulls(
+                                (seen_alias.clone(), last_seen),
+                                order_by,
+                                NullOrdering::Last,
+                            )
+                            .to_owned();
+                    }
+                    MediaSortBy::LastUpdated => {
+                        main_select = main_select
+                            .join_as(
+                                JoinType::LeftJoin,
+                                TempUserToMetadata::Table,
+                                mtu_alias.clone(),
+                                Expr::col((metadata_alias.clone(), TempMetadata::Id))
+                                    .equals((mtu_alias.clone(), TempUserToMetadata::MetadataId))
+                                    .and(
+                                        Expr::col((mtu_alias.clone(), TempUserToMetadata::UserId))
+                                            .eq(user_id),
+                                    ),
+                            )
+                            .order_by(
+                                (mtu_alias.clone(), TempUserToMetadata::LastUpdatedOn),
+                                order_by,
+                            )
+                            .to_owned();
+                    }
+                    MediaSortBy::Rating => {
+                        let alias_name = "average_rating";
+                        main_select = main_select
+                            .expr_as(
+                                Func::avg(Expr::col((review_alias.clone(), TempReview::Rating))),
+                                Alias::new(alias_name),
+                            )
+                            .join_as(
+                                JoinType::LeftJoin,
+                                TempReview::Table,
+                                review_alias.clone(),
+                                Expr::col((metadata_alias.clone(), TempMetadata::Id))
+                                    .equals((review_alias.clone(), TempReview::MetadataId))
+                                    .and(
+                                        Expr::col((review_alias.clone(), TempReview::UserId))
+                                            .eq(user_id),
+                                    ),
+                            )
+                            .group_by_col((metadata_alias.clone(), TempMetadata::Id))
+                            .order_by_expr_with_nulls(
+                                Expr::cust(alias_name),
+                                order_by,
+                                NullOrdering::Last,
+                            )
+                            .to_owned();
+                    }
+                    MediaSortBy::TopFavorites => {
+                        let favorites = self.favorite_metadata_ids(user_id).await?;
+                        let alias_name = "average_rating";
+                        main_select = main_select
+                            .expr_as(
+                                Func::avg(Expr::col((review_alias.clone(), TempReview::Rating))),
+                                Alias::new(alias_name),
+                            )
+                            .join_as(
+                                JoinType::LeftJoin,
+                                TempReview::Table,
+                                review_alias.clone(),
+                                Expr::col((metadata_alias.clone(), TempMetadata::Id))
+                                    .equals((review_alias.clone(), TempReview::MetadataId))
+                                    .and(
+                                        Expr::col((review_alias.clone(), TempReview::UserId))
+                                            .eq(user_id),
+                                    ),
+                            )
+                            .group_by_col((metadata_alias.clone(), TempMetadata::Id))
+                            .order_by_expr(
+                                Expr::col((metadata_alias.clone(), TempMetadata::Id))
+                                    .is_in(favorites),
+                                order_by.clone(),
+                            )
+                            .order_by_expr_with_nulls(
+                                Expr::cust(alias_name),
+                                order_by,
+                                NullOrdering::Last,
+                            )
+                            .to_owned();
+                    }
+                };
+            }
+        };
+
+        if let Some(f) = input.filter {
+            if let Some(s) = f.collection {
+                let all_media = MetadataToCollection::find()
+                    .filter(metadata_to_collection::Column::CollectionId.eq(s))
+                    .all(&self.db)
+                    .await?;
+                let collections = all_media.into_iter().map(|m| m.metadata_id).collect_vec();
+                main_select = main_select
+                    .and_where(
+                        Expr::col((metadata_alias.clone(), TempMetadata::Id)).is_in(collections),
+                    )
+                    .to_owned();
+            }
+            if let Some(a) = f.award {
+                let awarded = Metadata::find()
+                    .filter(metadata::Column::Id.is_in(distinct_meta_ids.clone()))
+                    .filter(metadata::Column::Awards.is_not_null())
+                    .all(&self.db)
+                    .await?
+                    .into_iter()
+                    .filter(|m| {
+                        m.awards
+                            .as_ref()
+                            .map(|aw| aw.contains(&a.name, a.won))
+                            .unwrap_or_default()
+                    })
+                    .map(|m| m.id)
+                    .collect_vec();
+                main_select = main_select
+                    .and_where(Expr::col((metadata_alias.clone(), TempMetadata::Id)).is_in(awarded))
+                    .to_owned();
+            }
+            if let Some(s) = f.general {
+                let reviews = if matches!(s, MediaGeneralFilter::All) {
+                    vec![]
+                } else {
+                    Review::find()
+                        .filter(review::Column::UserId.eq(user_id))
+                        .all(&self.db)
+                        .await?
+                        .into_iter()
+                        .map(|r| r.metadata_id)
+                        .collect_vec()
+                };
+                match s {
+                    MediaGeneralFilter::ExplicitlyMonitored => {}
+                    MediaGeneralFilter::All => {}
+                    MediaGeneralFilter::DeletedOnProvider => {
+                        main_select = main_select
+                            .and_where(
+                                Expr::col((
+                                    metadata_alias.clone(),
+                                    TempMetadata::IsDeletedOnProvider,
+                                ))
+                                .eq(true),
+                            )
+                            .to_owned();
+                    }
+                    MediaGeneralFilter::Rated => {
+                        main_select = main_select
+                            .and_where(
+                                Expr::col((metadata_alias.clone(), TempMetadata::Id))
+                                    .is_in(reviews),
+                            )
+                            .to_owned();
+                    }
+                    MediaGeneralFilter::Unrated => {
+                        main_select = main_select
+                            .and_where(
+                                Expr::col((metadata_alias.clone(), TempMetadata::Id))
+                                    .is_not_in(reviews),
+                            )
+                            .to_owned();
+                    }
+                    MediaGeneralFilter::Dropped
+                    | MediaGeneralFilter::InProgress
+                    | MediaGeneralFilter::Completed
+                    | MediaGeneralFilter::OnAHold => {
                         let state = match s {
                             MediaGeneralFilter::Dropped => SeenState::Dropped,
                             MediaGeneralFilter::InProgress => SeenState::InProgress,
@@ -2115,1625 +3844,3140 @@ impl MiscellaneousService {
                             )
                             .to_owned();
                     }
-                    MediaGeneralFilter::Unseen => {
-                        main_select = main_select
-                            .join_as(
-                                JoinType::LeftJoin,
-                                TempReview::Table,
-                                review_alias.clone(),
-                                Expr::col((metadata_alias.clone(), TempMetadata::Id))
-                                    .equals((seen_alias.clone(), TempSeen::MetadataId)),
-                            )
-                            .and_where(
-                                Expr::col((seen_alias.clone(), TempSeen::MetadataId)).is_null(),
-                            )
-                            .to_owned();
+                    MediaGeneralFilter::Unseen => {
+                        // DEV: Not joining on seen since the alias is already used when sorting
+                        // by last seen.
+                        let seen_ids = Query::select()
+                            .column(TempSeen::MetadataId)
+                            .from(TempSeen::Table)
+                            .and_where(Expr::col(TempSeen::UserId).eq(user_id))
+                            .to_owned();
+                        main_select = main_select
+                            .and_where(
+                                Expr::col((metadata_alias.clone(), TempMetadata::Id))
+                                    .not_in_subquery(seen_ids),
+                            )
+                            .to_owned();
+                    }
+                };
+            }
+        };
+
+        #[derive(Debug, FromQueryResult)]
+        struct InnerMediaSearchItem {
+            id: i32,
+            title: String,
+            source: MetadataSource,
+            localizations: Option<serde_json::Value>,
+            publish_year: Option<i32>,
+            images: serde_json::Value,
+            is_nsfw: bool,
+        }
+
+        let count_select = Query::select()
+            .expr(Func::count(Expr::col(Asterisk)))
+            .from_subquery(main_select.clone(), Alias::new("subquery"))
+            .to_owned();
+        let stmt = self.get_db_stmt(count_select);
+        let total = self
+            .db
+            .query_one(stmt)
+            .await?
+            .map(|qr| qr.try_get_by_index::<i64>(0).unwrap())
+            .unwrap();
+        let total: i32 = total.try_into().unwrap();
+
+        let main_select = main_select
+            .limit(self.config.frontend.page_size as u64)
+            .offset(((input.page - 1) * self.config.frontend.page_size) as u64)
+            .to_owned();
+        let stmt = self.get_db_stmt(main_select);
+        let metadata_items = InnerMediaSearchItem::find_by_statement(stmt)
+            .all(&self.db)
+            .await?;
+        let mut items = vec![];
+
+        for met in metadata_items {
+            let avg_select = Query::select()
+                .expr(Func::round_with_precision(
+                    Func::avg(Expr::col((TempReview::Table, TempReview::Rating)).div(
+                        match preferences.general.review_scale {
+                            UserReviewScale::OutOfFive => 20,
+                            UserReviewScale::OutOfHundred => 1,
+                        },
+                    )),
+                    match preferences.general.review_scale {
+                        UserReviewScale::OutOfFive => 1,
+                        UserReviewScale::OutOfHundred => 0,
+                    },
+                ))
+                .from(TempReview::Table)
+                .cond_where(
+                    Cond::all()
+                        .add(Expr::col((TempReview::Table, TempReview::UserId)).eq(user_id))
+                        .add(Expr::col((TempReview::Table, TempReview::MetadataId)).eq(met.id)),
+                )
+                .to_owned();
+            let stmt = self.get_db_stmt(avg_select);
+            let avg = self
+                .db
+                .query_one(stmt)
+                .await?
+                .map(|qr| qr.try_get_by_index::<Decimal>(0).ok())
+                .unwrap();
+            let images = serde_json::from_value(met.images).unwrap();
+            let assets = self
+                .metadata_assets(&metadata::Model {
+                    images,
+                    ..Default::default()
+                })
+                .await?;
+            let title = preferences
+                .languages
+                .for_source(met.source)
+                .and_then(|l| {
+                    serde_json::from_value::<MetadataLocalizations>(met.localizations?)
+                        .ok()?
+                        .0
+                        .remove(&l)
+                })
+                .map(|l| l.title)
+                .unwrap_or(met.title);
+            let m_small = MediaListItem {
+                data: MediaSearchItem {
+                    identifier: met.id.to_string(),
+                    title,
+                    image: assets.images.get(0).cloned(),
+                    publish_year: met.publish_year,
+                },
+                average_rating: avg,
+                blur: met.is_nsfw && nsfw_display == UserNsfwDisplay::Blur,
+            };
+            items.push(m_small);
+        }
+        let next_page = if total - ((input.page) * self.config.frontend.page_size) > 0 {
+            Some(input.page + 1)
+        } else {
+            None
+        };
+        Ok(SearchResults {
+            details: SearchDetails { next_page, total },
+            items,
+        })
+    }
+
+    // DEV: First we update progress only if media has not been consumed for
+    // this user in the last `n` duration.
+    pub async fn progress_update(
+        &self,
+        input: ProgressUpdateInput,
+        user_id: i32,
+    ) -> Result<ProgressUpdateResultUnion> {
+        let cache = ProgressUpdateCache {
+            user_id,
+            metadata_id: input.metadata_id,
+            show_season_number: input.show_season_number,
+            show_episode_number: input.show_episode_number,
+            podcast_episode_number: input.podcast_episode_number,
+        };
+
+        if self.seen_progress_cache.get(&cache).await.is_some() {
+            return Ok(ProgressUpdateResultUnion::Error(ProgressUpdateError {
+                error: ProgressUpdateErrorVariant::AlreadySeen,
+            }));
+        }
+
+        if let Some(context) = &input.context {
+            let meta = Metadata::find_by_id(input.metadata_id)
+                .one(&self.db)
+                .await?
+                .ok_or_else(|| Error::new("The media does not exist"))?;
+            let contexts = user_by_id(&self.db, user_id)
+                .await?
+                .preferences
+                .seen_contexts;
+            if !contexts.for_lot(meta.lot).contains(context) {
+                return Ok(ProgressUpdateResultUnion::Error(ProgressUpdateError {
+                    error: ProgressUpdateErrorVariant::InvalidUpdate,
+                }));
+            }
+        }
+
+        let prev_seen = Seen::find()
+            .filter(seen::Column::Progress.lt(100))
+            .filter(seen::Column::UserId.eq(user_id))
+            .filter(seen::Column::State.ne(SeenState::Dropped))
+            .filter(seen::Column::MetadataId.eq(input.metadata_id))
+            .order_by_desc(seen::Column::LastUpdatedOn)
+            .all(&self.db)
+            .await
+            .unwrap();
+        #[derive(Debug, Serialize, Deserialize, Enum, Clone, PartialEq, Eq, Copy)]
+        pub enum ProgressUpdateAction {
+            Update,
+            Now,
+            InThePast,
+            JustStarted,
+            ChangeState,
+        }
+        let action = match input.change_state {
+            None => match input.progress {
+                None => ProgressUpdateAction::ChangeState,
+                Some(p) => {
+                    if p == 100 {
+                        match input.date {
+                            None => ProgressUpdateAction::InThePast,
+                            Some(u) => {
+                                if Utc::now().date_naive() == u {
+                                    if prev_seen.is_empty() {
+                                        ProgressUpdateAction::Now
+                                    } else {
+                                        ProgressUpdateAction::Update
+                                    }
+                                } else {
+                                    ProgressUpdateAction::InThePast
+                                }
+                            }
+                        }
+                    } else if prev_seen.is_empty() {
+                        ProgressUpdateAction::JustStarted
+                    } else {
+                        ProgressUpdateAction::Update
+                    }
+                }
+            },
+            Some(_) => ProgressUpdateAction::ChangeState,
+        };
+        let err = || {
+            Ok(ProgressUpdateResultUnion::Error(ProgressUpdateError {
+                error: ProgressUpdateErrorVariant::NoSeenInProgress,
+            }))
+        };
+        let seen = match action {
+            ProgressUpdateAction::Update => {
+                let progress = input.progress.unwrap();
+                let num_times_seen = prev_seen[0].num_times_updated;
+                let mut last_seen: seen::ActiveModel = prev_seen[0].clone().into();
+                last_seen.state = ActiveValue::Set(SeenState::InProgress);
+                last_seen.progress = ActiveValue::Set(progress);
+                last_seen.num_times_updated =
+                    ActiveValue::Set(Some(num_times_seen.unwrap_or_default() + 1));
+                last_seen.last_updated_on = ActiveValue::Set(Utc::now());
+                if input.context.is_some() {
+                    last_seen.context = ActiveValue::Set(input.context.clone());
+                }
+                if progress == 100 {
+                    last_seen.finished_on = ActiveValue::Set(Some(Utc::now().date_naive()));
+                }
+                last_seen.update(&self.db).await.unwrap()
+            }
+            ProgressUpdateAction::ChangeState => {
+                let new_state = input.change_state.unwrap_or(SeenState::Dropped);
+                let last_seen = Seen::find()
+                    .filter(seen::Column::UserId.eq(user_id))
+                    .filter(seen::Column::MetadataId.eq(input.metadata_id))
+                    .order_by_desc(seen::Column::LastUpdatedOn)
+                    .one(&self.db)
+                    .await
+                    .unwrap();
+                match last_seen {
+                    Some(ls) => {
+                        let num_times_seen = ls.num_times_updated;
+                        let mut last_seen: seen::ActiveModel = ls.into();
+                        last_seen.state = ActiveValue::Set(new_state);
+                        last_seen.num_times_updated =
+                            ActiveValue::Set(Some(num_times_seen.unwrap_or_default() + 1));
+                        last_seen.last_updated_on = ActiveValue::Set(Utc::now());
+                        last_seen.update(&self.db).await.unwrap()
+                    }
+                    None => {
+                        return err();
+                    }
+                }
+            }
+            ProgressUpdateAction::Now
+            | ProgressUpdateAction::InThePast
+            | ProgressUpdateAction::JustStarted => {
+                let meta = Metadata::find_by_id(input.metadata_id)
+                    .one(&self.db)
+                    .await
+                    .unwrap()
+                    .unwrap();
+                let extra_infomation = match meta.lot {
+                    MetadataLot::Show => {
+                        if let (Some(season), Some(episode), MediaSpecifics::Show(spec)) = (
+                            input.show_season_number,
+                            input.show_episode_number,
+                            meta.specifics,
+                        ) {
+                            let is_there = spec.get_episode(season, episode).is_some();
+                            if !is_there {
+                                return Ok(ProgressUpdateResultUnion::Error(ProgressUpdateError {
+                                    error: ProgressUpdateErrorVariant::InvalidUpdate,
+                                }));
+                            }
+                            Some(SeenOrReviewOrCalendarEventExtraInformation::Show(
+                                SeenShowExtraInformation { season, episode },
+                            ))
+                        } else {
+                            return Ok(ProgressUpdateResultUnion::Error(ProgressUpdateError {
+                                error: ProgressUpdateErrorVariant::InvalidUpdate,
+                            }));
+                        }
+                    }
+                    MetadataLot::Podcast => {
+                        if let (Some(episode), MediaSpecifics::Podcast(spec)) =
+                            (input.podcast_episode_number, meta.specifics)
+                        {
+                            let is_there = spec.get_episode(episode).is_some();
+                            if !is_there {
+                                return Ok(ProgressUpdateResultUnion::Error(ProgressUpdateError {
+                                    error: ProgressUpdateErrorVariant::InvalidUpdate,
+                                }));
+                            }
+                            Some(SeenOrReviewOrCalendarEventExtraInformation::Podcast(
+                                SeenPodcastExtraInformation { episode },
+                            ))
+                        } else {
+                            return Ok(ProgressUpdateResultUnion::Error(ProgressUpdateError {
+                                error: ProgressUpdateErrorVariant::InvalidUpdate,
+                            }));
+                        }
+                    }
+                    _ => None,
+                };
+                let finished_on = if action == ProgressUpdateAction::JustStarted {
+                    None
+                } else {
+                    input.date
+                };
+                let (progress, started_on) = if matches!(action, ProgressUpdateAction::JustStarted)
+                {
+                    (0, Some(Utc::now().date_naive()))
+                } else {
+                    (100, None)
+                };
+                let seen_insert = seen::ActiveModel {
+                    progress: ActiveValue::Set(progress),
+                    user_id: ActiveValue::Set(user_id),
+                    metadata_id: ActiveValue::Set(input.metadata_id),
+                    started_on: ActiveValue::Set(started_on),
+                    finished_on: ActiveValue::Set(finished_on),
+                    last_updated_on: ActiveValue::Set(Utc::now()),
+                    extra_information: ActiveValue::Set(extra_infomation),
+                    state: ActiveValue::Set(SeenState::InProgress),
+                    context: ActiveValue::Set(input.context.clone()),
+                    ..Default::default()
+                };
+                seen_insert.insert(&self.db).await.unwrap()
+            }
+        };
+        let id = seen.id;
+        if seen.state == SeenState::Completed {
+            self.seen_progress_cache
+                .insert(
+                    cache,
+                    (),
+                    ChronoDuration::hours(self.config.server.progress_update_threshold)
+                        .to_std()
+                        .unwrap(),
+                )
+                .await;
+        }
+        self.perform_application_job
+            .clone()
+            .push(ApplicationJob::AfterMediaSeen(seen))
+            .await?;
+        Ok(ProgressUpdateResultUnion::Ok(IdObject { id }))
+    }
+
+    pub async fn bulk_progress_update(
+        &self,
+        user_id: i32,
+        input: Vec<ProgressUpdateInput>,
+    ) -> Result<bool> {
+        if input.len() < 2 {
+            return Err(Error::new("Atleast two bulk update elements are required"));
+        }
+        // DEV: We have to do this sorcery because the `associate_user_with_metadata` operation
+        // fails if we do all of them together. So we perform one update and then the rest together.
+        let mut updates = input
+            .into_iter()
+            .map(|i| self.progress_update(i, user_id))
+            .collect_vec();
+        updates.drain(..1).collect_vec().pop().unwrap().await?;
+        join_all(updates).await;
+        Ok(true)
+    }
+
+    /// Queue the recalculation of the summary of the user. Nothing is queued if
+    /// a recalculation has already been queued and not started yet, since it
+    /// will include the latest changes anyway.
+    pub async fn deploy_recalculate_summary_job(&self, user_id: i32) -> Result<()> {
+        if !self
+            .pending_summary_recalculations
+            .lock()
+            .unwrap()
+            .insert(user_id)
+        {
+            return Ok(());
+        }
+        if let Err(e) = self
+            .perform_application_job
+            .clone()
+            .push(ApplicationJob::RecalculateUserSummary(user_id))
+            .await
+        {
+            self.pending_summary_recalculations
+                .lock()
+                .unwrap()
+                .remove(&user_id);
+            return Err(e.into());
+        }
+        Ok(())
+    }
+
+    pub async fn cleanup_user_and_metadata_association(&self) -> Result<()> {
+        let user_to_metadatas = UserToMetadata::find().all(&self.db).await.unwrap();
+        for u in user_to_metadatas {
+            // check if there is any seen item
+            let seen_count = Seen::find()
+                .filter(seen::Column::UserId.eq(u.user_id))
+                .filter(seen::Column::MetadataId.eq(u.metadata_id))
+                .count(&self.db)
+                .await
+                .unwrap();
+            // check if it has been reviewed
+            let reviewed_count = Review::find()
+                .filter(review::Column::UserId.eq(u.user_id))
+                .filter(review::Column::MetadataId.eq(u.metadata_id))
+                .count(&self.db)
+                .await
+                .unwrap();
+            // check if it is part of any collection
+            let collection_ids: Vec<i32> = Collection::find()
+                .select_only()
+                .column(collection::Column::Id)
+                .filter(collection::Column::UserId.eq(u.user_id))
+                .into_tuple()
+                .all(&self.db)
+                .await
+                .unwrap();
+            let meta_ids: Vec<i32> = MetadataToCollection::find()
+                .select_only()
+                .column(metadata_to_collection::Column::MetadataId)
+                .filter(metadata_to_collection::Column::CollectionId.is_in(collection_ids))
+                .into_tuple()
+                .all(&self.db)
+                .await
+                .unwrap();
+            let is_in_collection = meta_ids.contains(&u.metadata_id);
+            // if the metadata is monitored
+            let is_monitored = u.monitored;
+            // if user has set a reminder
+            let is_reminder_active = u.reminder.is_some();
+            if seen_count + reviewed_count == 0
+                && !is_in_collection
+                && !is_monitored
+                && !is_reminder_active
+            {
+                tracing::debug!(
+                    "Removing user_to_metadata = {id:?}",
+                    id = (u.user_id, u.metadata_id)
+                );
+                u.delete(&self.db).await.ok();
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_media(
+        &self,
+        metadata_id: i32,
+        title: String,
+        alternative_titles: Vec<String>,
+        is_nsfw: Option<bool>,
+        description: Option<String>,
+        provider_rating: Option<Decimal>,
+        images: Vec<MetadataImage>,
+        videos: Vec<MetadataVideo>,
+        specifics: MediaSpecifics,
+        creators: Vec<MetadataCreator>,
+        genres: Vec<String>,
+        production_status: String,
+        publish_year: Option<i32>,
+        publish_date: Option<NaiveDate>,
+        suggestions: Vec<PartialMetadata>,
+        groups: Vec<(metadata_group::Model, Vec<PartialMetadata>)>,
+        language: Option<String>,
+    ) -> Result<Vec<(String, MediaStateChanged)>> {
+        let mut notifications = vec![];
+
+        let meta = Metadata::find_by_id(metadata_id)
+            .one(&self.db)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // compare diff using serde diff
+
+        if meta.production_status != production_status {
+            notifications.push((
+                format!(
+                    "Status changed from {:#?} to {:#?}",
+                    meta.production_status, production_status
+                ),
+                MediaStateChanged::StatusChanged,
+            ));
+        }
+
+        if let (Some(p1), Some(p2)) = (meta.publish_year, publish_year) {
+            if p1 != p2 {
+                notifications.push((
+                    format!("Publish year from {:#?} to {:#?}", p1, p2),
+                    MediaStateChanged::ReleaseDateChanged,
+                ));
+            }
+        }
+
+        match (&meta.specifics, &specifics) {
+            (MediaSpecifics::Show(s1), MediaSpecifics::Show(s2)) => {
+                if s1.seasons.len() != s2.seasons.len() {
+                    notifications.push((
+                        format!(
+                            "Number of seasons changed from {:#?} to {:#?}",
+                            s1.seasons.len(),
+                            s2.seasons.len()
+                        ),
+                        MediaStateChanged::NumberOfSeasonsChanged,
+                    ));
+                } else {
+                    for (s1, s2) in zip(s1.seasons.iter(), s2.seasons.iter()) {
+                        if s1.episodes.len() != s2.episodes.len() {
+                            notifications.push((
+                                format!(
+                                    "Number of episodes changed from {:#?} to {:#?} (Season {})",
+                                    s1.episodes.len(),
+                                    s2.episodes.len(),
+                                    s1.season_number
+                                ),
+                                MediaStateChanged::EpisodeReleased,
+                            ));
+                        } else {
+                            for (before_episode, after_episode) in
+                                zip(s1.episodes.iter(), s2.episodes.iter())
+                            {
+                                if before_episode.name != after_episode.name {
+                                    notifications.push((
+                                        format!(
+                                            "Episode name changed from {:#?} to {:#?} (S{}E{})",
+                                            before_episode.name,
+                                            after_episode.name,
+                                            s1.season_number,
+                                            before_episode.episode_number
+                                        ),
+                                        MediaStateChanged::EpisodeNameChanged,
+                                    ));
+                                }
+                                if let (Some(pd1), Some(pd2)) =
+                                    (before_episode.publish_date, after_episode.publish_date)
+                                {
+                                    if pd1 != pd2 {
+                                        notifications.push((
+                                            format!(
+                                                "Episode release date changed from {:?} to {:?} (S{}E{})",
+                                                pd1,
+                                                pd2,
+                                                s1.season_number,
+                                                before_episode.episode_number
+                                            ),
+                                            MediaStateChanged::ReleaseDateChanged,
+                                        ));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            (MediaSpecifics::Anime(a1), MediaSpecifics::Anime(a2)) => {
+                if let (Some(e1), Some(e2)) = (a1.episodes, a2.episodes) {
+                    if e1 != e2 {
+                        notifications.push((
+                            format!("Number of episodes changed from {:#?} to {:#?}", e1, e2),
+                            MediaStateChanged::ChaptersOrEpisodesChanged,
+                        ));
                     }
-                };
+                }
             }
-        };
-
-        #[derive(Debug, FromQueryResult)]
-        struct InnerMediaSearchItem {
-            id: i32,
-            title: String,
-            publish_year: Option<i32>,
-            images: serde_json::Value,
+            (MediaSpecifics::Manga(p1), MediaSpecifics::Manga(m2)) => {
+                if let (Some(c1), Some(c2)) = (p1.chapters, m2.chapters) {
+                    if c1 != c2 {
+                        notifications.push((
+                            format!("Number of chapters changed from {:#?} to {:#?}", c1, c2),
+                            MediaStateChanged::ChaptersOrEpisodesChanged,
+                        ));
+                    }
+                }
+            }
+            (MediaSpecifics::Podcast(p1), MediaSpecifics::Podcast(p2)) => {
+                if p1.episodes.len() != p2.episodes.len() {
+                    notifications.push((
+                        format!(
+                            "Number of episodes changed from {:#?} to {:#?}",
+                            p1.episodes.len(),
+                            p2.episodes.len()
+                        ),
+                        MediaStateChanged::EpisodeReleased,
+                    ));
+                } else {
+                    for (before_episode, after_episode) in
+                        zip(p1.episodes.iter(), p2.episodes.iter())
+                    {
+                        if before_episode.title != after_episode.title {
+                            notifications.push((
+                                format!(
+                                    "Episode name changed from {:#?} to {:#?} (EP{})",
+                                    before_episode.title,
+                                    after_episode.title,
+                                    before_episode.number
+                                ),
+                                MediaStateChanged::EpisodeNameChanged,
+                            ));
+                        }
+                    }
+                }
+            }
+            _ => {}
         }
 
-        let count_select = Query::select()
-            .expr(Func::count(Expr::col(Asterisk)))
-            .from_subquery(main_select.clone(), Alias::new("subquery"))
-            .to_owned();
-        let stmt = self.get_db_stmt(count_select);
-        let total = self
-            .db
-            .query_one(stmt)
-            .await?
-            .map(|qr| qr.try_get_by_index::<i64>(0).unwrap())
-            .unwrap();
-        let total: i32 = total.try_into().unwrap();
+        let notifications = notifications
+            .into_iter()
+            .map(|n| (format!("{} for {:?}.", n.0, meta.title), n.1))
+            .collect_vec();
 
-        let main_select = main_select
-            .limit(self.config.frontend.page_size as u64)
-            .offset(((input.page - 1) * self.config.frontend.page_size) as u64)
-            .to_owned();
-        let stmt = self.get_db_stmt(main_select);
-        let metadata_items = InnerMediaSearchItem::find_by_statement(stmt)
-            .all(&self.db)
-            .await?;
-        let mut items = vec![];
+        // DEV: The providers do not know about achievements, so the ones synced by
+        // an integration are kept
+        let specifics = match (&meta.specifics, specifics) {
+            (MediaSpecifics::VideoGame(v1), MediaSpecifics::VideoGame(mut v2)) => {
+                v2.achievements = v2.achievements.or(v1.achievements);
+                MediaSpecifics::VideoGame(v2)
+            }
+            (_, s) => s,
+        };
 
-        for met in metadata_items {
-            let avg_select = Query::select()
-                .expr(Func::round_with_precision(
-                    Func::avg(Expr::col((TempReview::Table, TempReview::Rating)).div(
-                        match preferences.general.review_scale {
-                            UserReviewScale::OutOfFive => 20,
-                            UserReviewScale::OutOfHundred => 1,
-                        },
-                    )),
-                    match preferences.general.review_scale {
-                        UserReviewScale::OutOfFive => 1,
-                        UserReviewScale::OutOfHundred => 0,
-                    },
-                ))
-                .from(TempReview::Table)
-                .cond_where(
-                    Cond::all()
-                        .add(Expr::col((TempReview::Table, TempReview::UserId)).eq(user_id))
-                        .add(Expr::col((TempReview::Table, TempReview::MetadataId)).eq(met.id)),
-                )
-                .to_owned();
-            let stmt = self.get_db_stmt(avg_select);
-            let avg = self
-                .db
-                .query_one(stmt)
-                .await?
-                .map(|qr| qr.try_get_by_index::<Decimal>(0).ok())
-                .unwrap();
-            let images = serde_json::from_value(met.images).unwrap();
-            let assets = self
-                .metadata_assets(&metadata::Model {
-                    images,
-                    ..Default::default()
-                })
-                .await?;
-            let m_small = MediaListItem {
-                data: MediaSearchItem {
-                    identifier: met.id.to_string(),
-                    title: met.title,
-                    image: assets.images.get(0).cloned(),
-                    publish_year: met.publish_year,
-                },
-                average_rating: avg,
-            };
-            items.push(m_small);
-        }
-        let next_page = if total - ((input.page) * self.config.frontend.page_size) > 0 {
-            Some(input.page + 1)
+        let mut meta: metadata::ActiveModel = meta.into();
+        meta.last_updated_on = ActiveValue::Set(Utc::now());
+        meta.alternative_titles = ActiveValue::Set(Some(MetadataAlternativeTitles::new(
+            &title,
+            alternative_titles,
+        )));
+        meta.title = ActiveValue::Set(title);
+        meta.language = ActiveValue::Set(language);
+        meta.is_nsfw = match is_nsfw {
+            None => ActiveValue::NotSet,
+            Some(n) => ActiveValue::Set(n),
+        };
+        meta.provider_rating = ActiveValue::Set(provider_rating);
+        meta.description = ActiveValue::Set(description);
+        meta.images = ActiveValue::Set(Some(MetadataImages(images)));
+        meta.videos = ActiveValue::Set(Some(MetadataVideos(videos)));
+        meta.production_status = ActiveValue::Set(production_status);
+        meta.publish_year = ActiveValue::Set(publish_year);
+        meta.publish_date = ActiveValue::Set(publish_date);
+        meta.specifics = ActiveValue::Set(specifics);
+        meta.last_processed_on_for_calendar = ActiveValue::Set(None);
+        meta.is_deleted_on_provider = ActiveValue::Set(false);
+        let metadata = meta.update(&self.db).await.unwrap();
+        // DEV: The details from the provider replace everything, so the approved
+        // corrections need to be applied again
+        self.apply_metadata_corrections(metadata.id).await?;
+
+        self.change_metadata_associations(
+            metadata.id,
+            metadata.lot,
+            metadata.source,
+            creators,
+            genres,
+            suggestions,
+            groups,
+        )
+        .await?;
+        Ok(notifications)
+    }
+
+    async fn associate_creator_with_metadata(
+        &self,
+        metadata_id: i32,
+        creator: MetadataCreator,
+        index: usize,
+    ) -> Result<()> {
+        let db_creator = if let Some(db_creator) = Creator::find()
+            .filter(creator::Column::Name.eq(&creator.name))
+            .one(&self.db)
+            .await
+            .unwrap()
+        {
+            if db_creator.image.is_none() {
+                let mut new: creator::ActiveModel = db_creator.clone().into();
+                new.image = ActiveValue::Set(creator.image);
+                new.update(&self.db).await?;
+            }
+            db_creator
         } else {
-            None
+            let c = creator::ActiveModel {
+                name: ActiveValue::Set(creator.name),
+                image: ActiveValue::Set(creator.image),
+                extra_information: ActiveValue::Set(CreatorExtraInformation { active: true }),
+                ..Default::default()
+            };
+            c.insert(&self.db).await.unwrap()
         };
-        Ok(SearchResults {
-            details: SearchDetails { next_page, total },
-            items,
-        })
+        let intermediate = metadata_to_creator::ActiveModel {
+            metadata_id: ActiveValue::Set(metadata_id),
+            creator_id: ActiveValue::Set(db_creator.id),
+            role: ActiveValue::Set(creator.role),
+            index: ActiveValue::Set(index.try_into().unwrap()),
+        };
+        intermediate.insert(&self.db).await.ok();
+        Ok(())
+    }
+
+    pub async fn associate_group_with_metadata(
+        &self,
+        lot: MetadataLot,
+        source: MetadataSource,
+        (group, associated_items): (metadata_group::Model, Vec<PartialMetadata>),
+    ) -> Result<()> {
+        let existing_group = MetadataGroup::find()
+            .filter(metadata_group::Column::Identifier.eq(&group.identifier))
+            .filter(metadata_group::Column::Lot.eq(lot))
+            .filter(metadata_group::Column::Source.eq(source))
+            .one(&self.db)
+            .await?;
+        let group_id = match existing_group {
+            Some(eg) => {
+                if eg.title != group.title
+                    || eg.description != group.description
+                    || eg.images != group.images
+                {
+                    let title = group.title.clone();
+                    let description = group.description.clone();
+                    let images = group.images.clone();
+                    let mut db_group: metadata_group::ActiveModel = group.into();
+                    db_group.title = ActiveValue::Set(title);
+                    db_group.description = ActiveValue::Set(description);
+                    db_group.images = ActiveValue::Set(images);
+                    db_group.update(&self.db).await?;
+                }
+                eg.id
+            }
+            None => {
+                let mut db_group: metadata_group::ActiveModel = group.into();
+                db_group.id = ActiveValue::NotSet;
+                let new_group = db_group.insert(&self.db).await?;
+                new_group.id
+            }
+        };
+        for (idx, media) in associated_items.into_iter().enumerate() {
+            let db_partial_metadata = self.create_partial_metadata(media).await?;
+            let intermediate = partial_metadata_to_metadata_group::ActiveModel {
+                metadata_group_id: ActiveValue::Set(group_id),
+                partial_metadata_id: ActiveValue::Set(db_partial_metadata.id),
+                part: ActiveValue::Set(idx.try_into().unwrap()),
+            };
+            intermediate.insert(&self.db).await.ok();
+        }
+        Ok(())
     }
 
-    // DEV: First we update progress only if media has not been consumed for
-    // this user in the last `n` duration.
-    pub async fn progress_update(
+    async fn associate_suggestion_with_metadata(
         &self,
-        input: ProgressUpdateInput,
-        user_id: i32,
-    ) -> Result<ProgressUpdateResultUnion> {
-        let cache = ProgressUpdateCache {
-            user_id,
-            metadata_id: input.metadata_id,
-            show_season_number: input.show_season_number,
-            show_episode_number: input.show_episode_number,
-            podcast_episode_number: input.podcast_episode_number,
+        data: PartialMetadata,
+        metadata_id: i32,
+    ) -> Result<()> {
+        let db_partial_metadata = self.create_partial_metadata(data).await?;
+        let intermediate = metadata_to_partial_metadata::ActiveModel {
+            metadata_id: ActiveValue::Set(metadata_id),
+            partial_metadata_id: ActiveValue::Set(db_partial_metadata.id),
+            relation: ActiveValue::Set(MetadataToPartialMetadataRelation::Suggestion),
         };
+        intermediate.insert(&self.db).await.ok();
+        Ok(())
+    }
 
-        if self.seen_progress_cache.get(&cache).await.is_some() {
-            return Ok(ProgressUpdateResultUnion::Error(ProgressUpdateError {
-                error: ProgressUpdateErrorVariant::AlreadySeen,
-            }));
-        }
-
-        let prev_seen = Seen::find()
-            .filter(seen::Column::Progress.lt(100))
-            .filter(seen::Column::UserId.eq(user_id))
-            .filter(seen::Column::State.ne(SeenState::Dropped))
-            .filter(seen::Column::MetadataId.eq(input.metadata_id))
-            .order_by_desc(seen::Column::LastUpdatedOn)
-            .all(&self.db)
+    async fn create_partial_metadata(
+        &self,
+        data: PartialMetadata,
+    ) -> Result<partial_metadata::Model> {
+        let model = if let Some(c) = PartialMetadataModel::find()
+            .filter(partial_metadata::Column::Identifier.eq(&data.identifier))
+            .filter(partial_metadata::Column::Lot.eq(data.lot))
+            .filter(partial_metadata::Column::Source.eq(data.source))
+            .one(&self.db)
             .await
-            .unwrap();
-        #[derive(Debug, Serialize, Deserialize, Enum, Clone, PartialEq, Eq, Copy)]
-        pub enum ProgressUpdateAction {
-            Update,
-            Now,
-            InThePast,
-            JustStarted,
-            ChangeState,
-        }
-        let action = match input.change_state {
-            None => match input.progress {
-                None => ProgressUpdateAction::ChangeState,
-                Some(p) => {
-                    if p == 100 {
-                        match input.date {
-                            None => ProgressUpdateAction::InThePast,
-                            Some(u) => {
-                                if Utc::now().date_naive() == u {
-                                    if prev_seen.is_empty() {
-                                        ProgressUpdateAction::Now
-                                    } else {
-                                        ProgressUpdateAction::Update
-                                    }
-                                } else {
-                                    ProgressUpdateAction::InThePast
-                                }
-                            }
-                        }
-                    } else if prev_seen.is_empty() {
-                        ProgressUpdateAction::JustStarted
-                    } else {
-                        ProgressUpdateAction::Update
-                    }
-                }
-            },
-            Some(_) => ProgressUpdateAction::ChangeState,
-        };
-        let err = || {
-            Ok(ProgressUpdateResultUnion::Error(ProgressUpdateError {
-                error: ProgressUpdateErrorVariant::NoSeenInProgress,
-            }))
+            .unwrap()
+        {
+            c
+        } else {
+            let c = partial_metadata::ActiveModel {
+                title: ActiveValue::Set(data.title),
+                identifier: ActiveValue::Set(data.identifier),
+                lot: ActiveValue::Set(data.lot),
+                source: ActiveValue::Set(data.source),
+                image: ActiveValue::Set(data.image),
+                ..Default::default()
+            };
+            c.insert(&self.db).await.unwrap()
         };
-        let seen = match action {
-            ProgressUpdateAction::Update => {
-                let progress = input.progress.unwrap();
-                let num_times_seen = prev_seen[0].num_times_updated;
-                let mut last_seen: seen::ActiveModel = prev_seen[0].clone().into();
-                last_seen.state = ActiveValue::Set(SeenState::InProgress);
-                last_seen.progress = ActiveValue::Set(progress);
-                last_seen.num_times_updated =
-                    ActiveValue::Set(Some(num_times_seen.unwrap_or_default() + 1));
-                last_seen.last_updated_on = ActiveValue::Set(Utc::now());
-                if progress == 100 {
-                    last_seen.finished_on = ActiveValue::Set(Some(Utc::now().date_naive()));
-                }
-                last_seen.update(&self.db).await.unwrap()
-            }
-            ProgressUpdateAction::ChangeState => {
-                let new_state = input.change_state.unwrap_or(SeenState::Dropped);
-                let last_seen = Seen::find()
-                    .filter(seen::Column::UserId.eq(user_id))
-                    .filter(seen::Column::MetadataId.eq(input.metadata_id))
-                    .order_by_desc(seen::Column::LastUpdatedOn)
-                    .one(&self.db)
-                    .await
-                    .unwrap();
-                match last_seen {
-                    Some(ls) => {
-                        let num_times_seen = ls.num_times_updated;
-                        let mut last_seen: seen::ActiveModel = ls.into();
-                        last_seen.state = ActiveValue::Set(new_state);
-                        last_seen.num_times_updated =
-                            ActiveValue::Set(Some(num_times_seen.unwrap_or_default() + 1));
-                        last_seen.last_updated_on = ActiveValue::Set(Utc::now());
-                        last_seen.update(&self.db).await.unwrap()
-                    }
-                    None => {
-                        return err();
-                    }
-                }
-            }
-            ProgressUpdateAction::Now
-            | ProgressUpdateAction::InThePast
-            | ProgressUpdateAction::JustStarted => {
-                let meta = Metadata::find_by_id(input.metadata_id)
-                    .one(&self.db)
-                    .await
-                    .unwrap()
-                    .unwrap();
-                let extra_infomation = match meta.lot {
-                    MetadataLot::Show => {
-                        if let (Some(season), Some(episode), MediaSpecifics::Show(spec)) = (
-                            input.show_season_number,
-                            input.show_episode_number,
-                            meta.specifics,
-                        ) {
-                            let is_there = spec.get_episode(season, episode).is_some();
-                            if !is_there {
-                                return Ok(ProgressUpdateResultUnion::Error(ProgressUpdateError {
-                                    error: ProgressUpdateErrorVariant::InvalidUpdate,
-                                }));
-                            }
-                            Some(SeenOrReviewOrCalendarEventExtraInformation::Show(
-                                SeenShowExtraInformation { season, episode },
-                            ))
-                        } else {
-                            return Ok(ProgressUpdateResultUnion::Error(ProgressUpdateError {
-                                error: ProgressUpdateErrorVariant::InvalidUpdate,
-                            }));
-                        }
-                    }
-                    MetadataLot::Podcast => {
-                        if let (Some(episode), MediaSpecifics::Podcast(spec)) =
-                            (input.podcast_episode_number, meta.specifics)
-                        {
-                            let is_there = spec.get_episode(episode).is_some();
-                            if !is_there {
-                                return Ok(ProgressUpdateResultUnion::Error(ProgressUpdateError {
-                                    error: ProgressUpdateErrorVariant::InvalidUpdate,
-                                }));
-                            }
-                            Some(SeenOrReviewOrCalendarEventExtraInformation::Podcast(
-                                SeenPodcastExtraInformation { episode },
-                            ))
-                        } else {
-                            return Ok(ProgressUpdateResultUnion::Error(ProgressUpdateError {
-                                error: ProgressUpdateErrorVariant::InvalidUpdate,
-                            }));
-                        }
-                    }
-                    _ => None,
-                };
-                let finished_on = if action == ProgressUpdateAction::JustStarted {
-                    None
-                } else {
-                    input.date
-                };
-                let (progress, started_on) = if matches!(action, ProgressUpdateAction::JustStarted)
-                {
-                    (0, Some(Utc::now().date_naive()))
-                } else {
-                    (100, None)
-                };
-                let seen_insert = seen::ActiveModel {
-                    progress: ActiveValue::Set(progress),
-                    user_id: ActiveValue::Set(user_id),
-                    metadata_id: ActiveValue::Set(input.metadata_id),
-                    started_on: ActiveValue::Set(started_on),
-                    finished_on: ActiveValue::Set(finished_on),
-                    last_updated_on: ActiveValue::Set(Utc::now()),
-                    extra_information: ActiveValue::Set(extra_infomation),
-                    state: ActiveValue::Set(SeenState::InProgress),
-                    ..Default::default()
-                };
-                seen_insert.insert(&self.db).await.unwrap()
-            }
+        Ok(model)
+    }
+
+    async fn associate_genre_with_metadata(&self, name: String, metadata_id: i32) -> Result<()> {
+        let db_genre = if let Some(c) = Genre::find()
+            .filter(genre::Column::Name.eq(&name))
+            .one(&self.db)
+            .await
+            .unwrap()
+        {
+            c
+        } else {
+            let c = genre::ActiveModel {
+                name: ActiveValue::Set(name),
+                ..Default::default()
+            };
+            c.insert(&self.db).await.unwrap()
         };
-        let id = seen.id;
-        if seen.state == SeenState::Completed {
-            self.seen_progress_cache
-                .insert(
-                    cache,
-                    (),
-                    ChronoDuration::hours(self.config.server.progress_update_threshold)
-                        .to_std()
-                        .unwrap(),
-                )
-                .await;
-        }
-        self.perform_application_job
-            .clone()
-            .push(ApplicationJob::AfterMediaSeen(seen))
-            .await?;
-        Ok(ProgressUpdateResultUnion::Ok(IdObject { id }))
+        let intermediate = metadata_to_genre::ActiveModel {
+            metadata_id: ActiveValue::Set(metadata_id),
+            genre_id: ActiveValue::Set(db_genre.id),
+        };
+        intermediate.insert(&self.db).await.ok();
+        Ok(())
     }
 
-    pub async fn bulk_progress_update(
+    pub async fn commit_media_internal(
         &self,
-        user_id: i32,
-        input: Vec<ProgressUpdateInput>,
-    ) -> Result<bool> {
-        if input.len() < 2 {
-            return Err(Error::new("Atleast two bulk update elements are required"));
-        }
-        // DEV: We have to do this sorcery because the `associate_user_with_metadata` operation
-        // fails if we do all of them together. So we perform one update and then the rest together.
-        let mut updates = input
-            .into_iter()
-            .map(|i| self.progress_update(i, user_id))
-            .collect_vec();
-        updates.drain(..1).collect_vec().pop().unwrap().await?;
-        join_all(updates).await;
-        Ok(true)
+        details: MediaDetails,
+        language: Option<String>,
+    ) -> Result<IdObject> {
+        let metadata = metadata::ActiveModel {
+            lot: ActiveValue::Set(details.lot),
+            source: ActiveValue::Set(details.source),
+            alternative_titles: ActiveValue::Set(Some(MetadataAlternativeTitles::new(
+                &details.title,
+                details.alternative_titles,
+            ))),
+            title: ActiveValue::Set(details.title),
+            language: ActiveValue::Set(language),
+            description: ActiveValue::Set(details.description),
+            publish_year: ActiveValue::Set(details.publish_year),
+            publish_date: ActiveValue::Set(details.publish_date),
+            images: ActiveValue::Set(Some(MetadataImages(details.images))),
+            videos: ActiveValue::Set(Some(MetadataVideos(details.videos))),
+            identifier: ActiveValue::Set(details.identifier),
+            specifics: ActiveValue::Set(details.specifics),
+            production_status: ActiveValue::Set(details.production_status),
+            provider_rating: ActiveValue::Set(details.provider_rating),
+            is_nsfw: match details.is_nsfw {
+                None => ActiveValue::NotSet,
+                Some(n) => ActiveValue::Set(n),
+            },
+            ..Default::default()
+        };
+        let metadata = metadata.insert(&self.db).await?;
+
+        self.change_metadata_associations(
+            metadata.id,
+            metadata.lot,
+            metadata.source,
+            details.creators,
+            details.genres,
+            details.suggestions,
+            details.groups,
+        )
+        .await?;
+        self.deploy_enrich_metadata_awards_job(metadata.id).await?;
+        Ok(IdObject { id: metadata.id })
     }
 
-    pub async fn deploy_recalculate_summary_job(&self, user_id: i32) -> Result<()> {
-        self.perform_application_job
-            .clone()
-            .push(ApplicationJob::RecalculateUserSummary(user_id))
+    #[allow(clippy::too_many_arguments)]
+    async fn change_metadata_associations(
+        &self,
+        metadata_id: i32,
+        lot: MetadataLot,
+        source: MetadataSource,
+        creators: Vec<MetadataCreator>,
+        genres: Vec<String>,
+        suggestions: Vec<PartialMetadata>,
+        groups: Vec<(metadata_group::Model, Vec<PartialMetadata>)>,
+    ) -> Result<()> {
+        MetadataToCreator::delete_many()
+            .filter(metadata_to_creator::Column::MetadataId.eq(metadata_id))
+            .exec(&self.db)
+            .await?;
+        MetadataToGenre::delete_many()
+            .filter(metadata_to_genre::Column::MetadataId.eq(metadata_id))
+            .exec(&self.db)
+            .await?;
+        // suggestions
+        MetadataToPartialMetadata::delete_many()
+            .filter(metadata_to_partial_metadata::Column::MetadataId.eq(metadata_id))
+            .exec(&self.db)
             .await?;
+        for (idx, creator) in creators.into_iter().enumerate() {
+            self.associate_creator_with_metadata(metadata_id, creator, idx)
+                .await
+                .ok();
+        }
+        for genre in genres {
+            self.associate_genre_with_metadata(genre, metadata_id)
+                .await
+                .ok();
+        }
+        for suggestion in suggestions {
+            self.associate_suggestion_with_metadata(suggestion, metadata_id)
+                .await
+                .ok();
+        }
+        // DEV: Ideally, we should remove partial_metadata to metadata_group association but does not really matter
+        for group in groups {
+            self.associate_group_with_metadata(lot, source, group)
+                .await
+                .ok();
+        }
         Ok(())
     }
 
-    pub async fn cleanup_user_and_metadata_association(&self) -> Result<()> {
-        let user_to_metadatas = UserToMetadata::find().all(&self.db).await.unwrap();
-        for u in user_to_metadatas {
-            // check if there is any seen item
-            let seen_count = Seen::find()
-                .filter(seen::Column::UserId.eq(u.user_id))
-                .filter(seen::Column::MetadataId.eq(u.metadata_id))
+    pub async fn cleanup_data_without_associated_user_activities(&self) -> Result<()> {
+        tracing::trace!("Cleaning up media items without associated user activities");
+        let retained_since =
+            Utc::now() - ChronoDuration::days(self.config.media.orphaned_metadata_retention_days);
+        let mut all_metadata = Metadata::find()
+            .filter(metadata::Column::CreatedOn.lte(retained_since))
+            .stream(&self.db)
+            .await?;
+        while let Some(metadata) = all_metadata.try_next().await? {
+            let num_user_associations = UserToMetadata::find()
+                .filter(user_to_metadata::Column::MetadataId.eq(metadata.id))
                 .count(&self.db)
                 .await
                 .unwrap();
-            // check if it has been reviewed
-            let reviewed_count = Review::find()
-                .filter(review::Column::UserId.eq(u.user_id))
-                .filter(review::Column::MetadataId.eq(u.metadata_id))
+            if num_user_associations == 0 {
+                let images = metadata.images.clone().unwrap_or_default().0;
+                if metadata.delete(&self.db).await.is_ok() {
+                    for image in images {
+                        if let StoredUrl::S3(key) = image.url {
+                            self.file_storage_service.delete_object(&key).await.ok();
+                        }
+                    }
+                }
+            }
+        }
+        tracing::trace!("Cleaning up genres without associated metadata");
+        let mut all_genre = Genre::find().stream(&self.db).await?;
+        while let Some(genre) = all_genre.try_next().await? {
+            let num_associations = MetadataToGenre::find()
+                .filter(metadata_to_genre::Column::GenreId.eq(genre.id))
                 .count(&self.db)
                 .await
                 .unwrap();
-            // check if it is part of any collection
-            let collection_ids: Vec<i32> = Collection::find()
-                .select_only()
-                .column(collection::Column::Id)
-                .filter(collection::Column::UserId.eq(u.user_id))
-                .into_tuple()
-                .all(&self.db)
+            if num_associations == 0 {
+                genre.delete(&self.db).await.ok();
+            }
+        }
+        tracing::trace!("Cleaning up creators without associated metadata");
+        let mut all_creators = Creator::find().stream(&self.db).await?;
+        while let Some(creator) = all_creators.try_next().await? {
+            let num_associations = MetadataToCreator::find()
+                .filter(metadata_to_creator::Column::CreatorId.eq(creator.id))
+                .count(&self.db)
                 .await
                 .unwrap();
-            let meta_ids: Vec<i32> = MetadataToCollection::find()
-                .select_only()
-                .column(metadata_to_collection::Column::MetadataId)
-                .filter(metadata_to_collection::Column::CollectionId.is_in(collection_ids))
-                .into_tuple()
-                .all(&self.db)
+            let num_reviews = Review::find()
+                .filter(review::Column::CreatorId.eq(creator.id))
+                .count(&self.db)
                 .await
                 .unwrap();
-            let is_in_collection = meta_ids.contains(&u.metadata_id);
-            // if the metadata is monitored
-            let is_monitored = u.monitored;
-            // if user has set a reminder
-            let is_reminder_active = u.reminder.is_some();
-            if seen_count + reviewed_count == 0
-                && !is_in_collection
-                && !is_monitored
-                && !is_reminder_active
-            {
-                tracing::debug!(
-                    "Removing user_to_metadata = {id:?}",
-                    id = (u.user_id, u.metadata_id)
-                );
-                u.delete(&self.db).await.ok();
+            if num_associations + num_reviews == 0 {
+                creator.delete(&self.db).await.ok();
+            }
+        }
+        tracing::trace!("Cleaning up partial metadata without associated metadata");
+        let mut all_partial_metadata = PartialMetadataModel::find().stream(&self.db).await?;
+        while let Some(partial_metadata) = all_partial_metadata.try_next().await? {
+            let num_associations = MetadataToPartialMetadata::find()
+                .filter(
+                    metadata_to_partial_metadata::Column::PartialMetadataId.eq(partial_metadata.id),
+                )
+                .count(&self.db)
+                .await
+                .unwrap();
+            let num_group_associations = PartialMetadataToMetadataGroup::find()
+                .filter(
+                    partial_metadata_to_metadata_group::Column::PartialMetadataId
+                        .eq(partial_metadata.id),
+                )
+                .count(&self.db)
+                .await
+                .unwrap();
+            if num_associations + num_group_associations == 0 {
+                partial_metadata.delete(&self.db).await.ok();
             }
         }
         Ok(())
     }
 
-    #[allow(clippy::too_many_arguments)]
-    pub async fn update_media(
-        &self,
-        metadata_id: i32,
-        title: String,
-        is_nsfw: Option<bool>,
-        description: Option<String>,
-        provider_rating: Option<Decimal>,
-        images: Vec<MetadataImage>,
-        videos: Vec<MetadataVideo>,
-        specifics: MediaSpecifics,
-        creators: Vec<MetadataCreator>,
-        genres: Vec<String>,
-        production_status: String,
-        publish_year: Option<i32>,
-        publish_date: Option<NaiveDate>,
-        suggestions: Vec<PartialMetadata>,
-        groups: Vec<(metadata_group::Model, Vec<PartialMetadata>)>,
-    ) -> Result<Vec<(String, MediaStateChanged)>> {
-        let mut notifications = vec![];
-
-        let meta = Metadata::find_by_id(metadata_id)
+    pub async fn deploy_update_metadata_job(&self, metadata_id: i32) -> Result<String> {
+        let metadata = Metadata::find_by_id(metadata_id)
             .one(&self.db)
             .await
             .unwrap()
-            .unwrap();
+            .unwrap();
+        let job_id = self
+            .perform_application_job
+            .clone()
+            .push(ApplicationJob::UpdateMetadata(metadata))
+            .await?;
+        Ok(job_id.to_string())
+    }
+
+    async fn deploy_enrich_metadata_awards_job(&self, metadata_id: i32) -> Result<()> {
+        if self.config.media.wikidata.enabled {
+            self.perform_application_job
+                .clone()
+                .push(ApplicationJob::EnrichMetadataAwards(metadata_id))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Tag the media item with the awards and nominations it has received,
+    /// according to Wikidata.
+    pub async fn enrich_metadata_awards(&self, metadata_id: i32) -> Result<()> {
+        let meta = match Metadata::find_by_id(metadata_id).one(&self.db).await? {
+            Some(m) => m,
+            None => return Ok(()),
+        };
+        let service = WikidataService::new().await;
+        let awards = service
+            .awards(meta.lot, meta.source, &meta.identifier)
+            .await?;
+        tracing::trace!(
+            "Found {} awards for metadata = {:?}",
+            awards.len(),
+            metadata_id
+        );
+        let mut meta: metadata::ActiveModel = meta.into();
+        meta.awards = ActiveValue::Set(Some(MetadataAwards(awards)));
+        meta.update(&self.db).await?;
+        Ok(())
+    }
+
+    /// Fetch the title and description of the media item in a language other
+    /// than the one that it was fetched in, for the users that prefer it.
+    pub async fn localize_metadata(&self, metadata_id: i32, language: String) -> Result<()> {
+        let meta = match Metadata::find_by_id(metadata_id).one(&self.db).await? {
+            Some(m) => m,
+            None => return Ok(()),
+        };
+        let provider = self
+            .get_provider(meta.lot, meta.source, Some(language.clone()))
+            .await?;
+        let details = provider.details(&meta.identifier).await?;
+        let mut localizations = meta.localizations.clone().unwrap_or_default();
+        localizations.0.insert(
+            language,
+            MetadataLocalization {
+                title: details.title,
+                description: details.description,
+            },
+        );
+        let mut meta: metadata::ActiveModel = meta.into();
+        meta.localizations = ActiveValue::Set(Some(localizations));
+        meta.update(&self.db).await?;
+        Ok(())
+    }
+
+    pub async fn merge_metadata(&self, merge_from: i32, merge_into: i32) -> Result<bool> {
+        let mut affected_users = HashSet::new();
+        for old_seen in Seen::find()
+            .filter(seen::Column::MetadataId.eq(merge_from))
+            .all(&self.db)
+            .await
+            .unwrap()
+        {
+            affected_users.insert(old_seen.user_id);
+            let old_seen_active: seen::ActiveModel = old_seen.clone().into();
+            let new_seen = seen::ActiveModel {
+                id: ActiveValue::NotSet,
+                metadata_id: ActiveValue::Set(merge_into),
+                ..old_seen_active
+            };
+            new_seen.insert(&self.db).await?;
+            old_seen.delete(&self.db).await?;
+        }
+        for old_review in Review::find()
+            .filter(review::Column::MetadataId.eq(merge_from))
+            .all(&self.db)
+            .await
+            .unwrap()
+        {
+            affected_users.insert(old_review.user_id);
+            let old_review_active: review::ActiveModel = old_review.clone().into();
+            let new_review = review::ActiveModel {
+                id: ActiveValue::NotSet,
+                metadata_id: ActiveValue::Set(Some(merge_into)),
+                ..old_review_active
+            };
+            new_review.insert(&self.db).await?;
+            old_review.delete(&self.db).await?;
+        }
+        MetadataToCollection::update_many()
+            .filter(metadata_to_collection::Column::MetadataId.eq(merge_from))
+            .set(metadata_to_collection::ActiveModel {
+                metadata_id: ActiveValue::Set(merge_into),
+                ..Default::default()
+            })
+            .exec(&self.db)
+            .await?;
+        Metadata::delete_by_id(merge_from).exec(&self.db).await?;
+        // DEV: The two items can have different details, eg: the number of pages
+        for user_id in affected_users {
+            self.deploy_recalculate_summary_job(user_id).await?;
+        }
+        Ok(true)
+    }
 
-        // compare diff using serde diff
+    async fn user_preferences(&self, user_id: i32) -> Result<UserPreferences> {
+        let mut preferences = user_by_id(&self.db, user_id).await?.preferences;
+        preferences.features_enabled.media.anime =
+            self.config.anime.is_enabled() && preferences.features_enabled.media.anime;
+        preferences.features_enabled.media.article =
+            self.config.articles.is_enabled() && preferences.features_enabled.media.article;
+        preferences.features_enabled.media.audio_book =
+            self.config.audio_books.is_enabled() && preferences.features_enabled.media.audio_book;
+        preferences.features_enabled.media.board_game =
+            self.config.board_games.is_enabled() && preferences.features_enabled.media.board_game;
+        preferences.features_enabled.media.comic =
+            self.config.comics.is_enabled() && preferences.features_enabled.media.comic;
+        preferences.features_enabled.media.book =
+            self.config.books.is_enabled() && preferences.features_enabled.media.book;
+        preferences.features_enabled.media.show =
+            self.config.shows.is_enabled() && preferences.features_enabled.media.show;
+        preferences.features_enabled.media.manga =
+            self.config.manga.is_enabled() && preferences.features_enabled.media.manga;
+        preferences.features_enabled.media.movie =
+            self.config.movies.is_enabled() && preferences.features_enabled.media.movie;
+        preferences.features_enabled.media.music =
+            self.config.music.is_enabled() && preferences.features_enabled.media.music;
+        preferences.features_enabled.media.podcast =
+            self.config.podcasts.is_enabled() && preferences.features_enabled.media.podcast;
+        preferences.features_enabled.media.video_game =
+            self.config.video_games.is_enabled() && preferences.features_enabled.media.video_game;
+        Ok(preferences)
+    }
 
-        if meta.production_status != production_status {
-            notifications.push((
-                format!(
-                    "Status changed from {:#?} to {:#?}",
-                    meta.production_status, production_status
-                ),
-                MediaStateChanged::StatusChanged,
-            ));
+    async fn core_enabled_features(&self) -> Result<GeneralFeatures> {
+        let mut files_enabled = self.config.file_storage.is_enabled();
+        if files_enabled && !self.file_storage_service.is_enabled().await {
+            files_enabled = false;
         }
+        let general = GeneralFeatures {
+            file_storage: files_enabled,
+            signup_allowed: self.config.users.allow_registration,
+            password_reset_allowed: self.password_reset_allowed(),
+            email_verification_required: self.email_verification_required(),
+        };
+        Ok(general)
+    }
 
-        if let (Some(p1), Some(p2)) = (meta.publish_year, publish_year) {
-            if p1 != p2 {
-                notifications.push((
-                    format!("Publish year from {:#?} to {:#?}", p1, p2),
-                    MediaStateChanged::ReleaseDateChanged,
-                ));
+    /// The way NSFW media should be displayed to this user, taking the instance
+    /// wide floor into account. Anonymous users get the default setting.
+    async fn user_nsfw_display(&self, user_id: Option<i32>) -> Result<UserNsfwDisplay> {
+        let nsfw_display = match user_id {
+            Some(user_id) => {
+                user_by_id(&self.db, user_id)
+                    .await?
+                    .preferences
+                    .general
+                    .nsfw_display
+            }
+            None => UserNsfwDisplay::default(),
+        };
+        Ok(nsfw_display.with_floor(&self.config.users.nsfw_display_floor))
+    }
+
+    async fn media_search(
+        &self,
+        lot: MetadataLot,
+        source: MetadataSource,
+        input: SearchInput,
+        filters: MediaSearchFilters,
+        user_id: i32,
+    ) -> Result<SearchResults<MediaSearchItemResponse>> {
+        if let Some(q) = input.query {
+            if q.is_empty() {
+                return Ok(SearchResults {
+                    details: SearchDetails {
+                        total: 0,
+                        next_page: None,
+                    },
+                    items: vec![],
+                });
             }
+            let preferences = user_by_id(&self.db, user_id).await?.preferences;
+            let nsfw_display = self.user_nsfw_display(Some(user_id)).await?;
+            let provider = self
+                .get_provider(lot, source, preferences.languages.for_source(source))
+                .await?;
+            let results = provider
+                .search(
+                    &q,
+                    input.page,
+                    nsfw_display != UserNsfwDisplay::Hide,
+                    &filters,
+                )
+                .await?;
+            self.provider_results_to_response(lot, source, results, nsfw_display)
+                .await
+        } else {
+            Err(Error::new("Can not search without a query"))
         }
+    }
 
-        match (&meta.specifics, &specifics) {
-            (MediaSpecifics::Show(s1), MediaSpecifics::Show(s2)) => {
-                if s1.seasons.len() != s2.seasons.len() {
-                    notifications.push((
-                        format!(
-                            "Number of seasons changed from {:#?} to {:#?}",
-                            s1.seasons.len(),
-                            s2.seasons.len()
-                        ),
-                        MediaStateChanged::NumberOfSeasonsChanged,
-                    ));
-                } else {
-                    for (s1, s2) in zip(s1.seasons.iter(), s2.seasons.iter()) {
-                        if s1.episodes.len() != s2.episodes.len() {
-                            notifications.push((
-                                format!(
-                                    "Number of episodes changed from {:#?} to {:#?} (Season {})",
-                                    s1.episodes.len(),
-                                    s2.episodes.len(),
-                                    s1.season_number
-                                ),
-                                MediaStateChanged::EpisodeReleased,
-                            ));
-                        } else {
-                            for (before_episode, after_episode) in
-                                zip(s1.episodes.iter(), s2.episodes.iter())
-                            {
-                                if before_episode.name != after_episode.name {
-                                    notifications.push((
-                                        format!(
-                                            "Episode name changed from {:#?} to {:#?} (S{}E{})",
-                                            before_episode.name,
-                                            after_episode.name,
-                                            s1.season_number,
-                                            before_episode.episode_number
-                                        ),
-                                        MediaStateChanged::EpisodeNameChanged,
-                                    ));
-                                }
-                                if let (Some(pd1), Some(pd2)) =
-                                    (before_episode.publish_date, after_episode.publish_date)
-                                {
-                                    if pd1 != pd2 {
-                                        notifications.push((
-                                            format!(
-                                                "Episode release date changed from {:?} to {:?} (S{}E{})",
-                                                pd1,
-                                                pd2,
-                                                s1.season_number,
-                                                before_episode.episode_number
-                                            ),
-                                            MediaStateChanged::ReleaseDateChanged,
-                                        ));
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
+    /// Attach the database IDs of the items that are already present to the
+    /// results returned by a provider and handle the user's NSFW preference.
+    async fn provider_results_to_response(
+        &self,
+        lot: MetadataLot,
+        source: MetadataSource,
+        results: SearchResults<MediaSearchItem>,
+        nsfw_display: UserNsfwDisplay,
+    ) -> Result<SearchResults<MediaSearchItemResponse>> {
+        let mut all_idens = results
+            .items
+            .iter()
+            .map(|i| i.identifier.to_owned())
+            .collect_vec();
+        let data = if all_idens.is_empty() {
+            vec![]
+        } else {
+            #[derive(Iden)]
+            #[iden = "identifiers"]
+            enum TempIdentifiers {
+                #[iden = "identifiers"]
+                Alias,
+                Identifier,
             }
-            (MediaSpecifics::Anime(a1), MediaSpecifics::Anime(a2)) => {
-                if let (Some(e1), Some(e2)) = (a1.episodes, a2.episodes) {
-                    if e1 != e2 {
-                        notifications.push((
-                            format!("Number of episodes changed from {:#?} to {:#?}", e1, e2),
-                            MediaStateChanged::ChaptersOrEpisodesChanged,
-                        ));
-                    }
-                }
+            let metadata_alias = Alias::new("m");
+            // This can be done with `select id from metadata where identifier = '...'
+            // and lot = '...'` in a loop. But, I wanted to write a performant query.
+            let first_iden = all_idens.drain(..1).collect_vec().pop().unwrap();
+            let mut subquery = Query::select()
+                .expr_as(Expr::val(first_iden), TempIdentifiers::Identifier)
+                .to_owned();
+            for identifier in all_idens {
+                subquery = subquery
+                    .union(
+                        UnionType::All,
+                        Query::select().expr(Expr::val(identifier)).to_owned(),
+                    )
+                    .to_owned();
             }
-            (MediaSpecifics::Manga(p1), MediaSpecifics::Manga(m2)) => {
-                if let (Some(c1), Some(c2)) = (p1.chapters, m2.chapters) {
-                    if c1 != c2 {
-                        notifications.push((
-                            format!("Number of chapters changed from {:#?} to {:#?}", c1, c2),
-                            MediaStateChanged::ChaptersOrEpisodesChanged,
-                        ));
-                    }
-                }
+            let identifiers_query = Query::select()
+                .expr(Expr::col((
+                    TempIdentifiers::Alias,
+                    TempIdentifiers::Identifier,
+                )))
+                .expr_as(
+                    Expr::case(
+                        Expr::col((metadata_alias.clone(), TempMetadata::Id)).is_not_null(),
+                        Expr::col((metadata_alias.clone(), TempMetadata::Id)),
+                    )
+                    .finally(Keyword::Null),
+                    TempMetadata::Id,
+                )
+                .expr_as(
+                    Expr::col((metadata_alias.clone(), TempMetadata::IsNsfw)),
+                    TempMetadata::IsNsfw,
+                )
+                .from_subquery(subquery, TempIdentifiers::Alias)
+                .join_as(
+                    JoinType::LeftJoin,
+                    TempMetadata::Table,
+                    metadata_alias.clone(),
+                    Expr::col((TempIdentifiers::Alias, TempIdentifiers::Identifier))
+                        .equals((metadata_alias.clone(), TempMetadata::Identifier)),
+                )
+                .and_where(
+                    Expr::col((metadata_alias.clone(), TempMetadata::Lot))
+                        .eq(lot)
+                        .and(Expr::col((metadata_alias.clone(), TempMetadata::Source)).eq(source))
+                        .or(Expr::col((metadata_alias.clone(), TempMetadata::Lot)).is_null()),
+                )
+                .to_owned();
+            let stmt = self.get_db_stmt(identifiers_query);
+            #[derive(Debug, FromQueryResult)]
+            struct DbResponse {
+                identifier: String,
+                id: Option<i32>,
+                is_nsfw: Option<bool>,
             }
-            (MediaSpecifics::Podcast(p1), MediaSpecifics::Podcast(p2)) => {
-                if p1.episodes.len() != p2.episodes.len() {
-                    notifications.push((
-                        format!(
-                            "Number of episodes changed from {:#?} to {:#?}",
-                            p1.episodes.len(),
-                            p2.episodes.len()
-                        ),
-                        MediaStateChanged::EpisodeReleased,
-                    ));
-                } else {
-                    for (before_episode, after_episode) in
-                        zip(p1.episodes.iter(), p2.episodes.iter())
-                    {
-                        if before_episode.title != after_episode.title {
-                            notifications.push((
-                                format!(
-                                    "Episode name changed from {:#?} to {:#?} (EP{})",
-                                    before_episode.title,
-                                    after_episode.title,
-                                    before_episode.number
-                                ),
-                                MediaStateChanged::EpisodeNameChanged,
-                            ));
-                        }
+            let identifiers = DbResponse::find_by_statement(stmt).all(&self.db).await?;
+            results
+                .items
+                .into_iter()
+                .filter_map(|i| {
+                    let db_item = identifiers.iter().find(|&f| f.identifier == i.identifier);
+                    // DEV: Not all providers filter NSFW items, so also use what we know
+                    let is_nsfw = db_item.and_then(|d| d.is_nsfw).unwrap_or_default();
+                    if is_nsfw && nsfw_display == UserNsfwDisplay::Hide {
+                        return None;
                     }
-                }
+                    Some(MediaSearchItemResponse {
+                        database_id: db_item.and_then(|d| d.id),
+                        blur: is_nsfw && nsfw_display == UserNsfwDisplay::Blur,
+                        item: i,
+                    })
+                })
+                .collect()
+        };
+        Ok(SearchResults {
+            details: results.details,
+            items: data,
+        })
+    }
+
+    async fn discover_trending(
+        &self,
+        lot: MetadataLot,
+        page: Option<i32>,
+        user_id: i32,
+    ) -> Result<SearchResults<MediaSearchItemResponse>> {
+        let preferences = user_by_id(&self.db, user_id).await?.preferences;
+        let nsfw_display = self.user_nsfw_display(Some(user_id)).await?;
+        let source = self.preferred_source_for_lot(lot, &preferences).await;
+        let provider = self
+            .get_provider(lot, source, preferences.languages.for_source(source))
+            .await?;
+        let results = provider.trending(page).await?;
+        self.provider_results_to_response(lot, source, results, nsfw_display)
+            .await
+    }
+
+    async fn seasonal_anime(
+        &self,
+        year: i32,
+        season: AnimeSeason,
+        page: Option<i32>,
+        user_id: i32,
+    ) -> Result<SearchResults<SeasonalAnimeItem>> {
+        let lot = MetadataLot::Anime;
+        let source = MetadataSource::Mal;
+        let nsfw_display = self.user_nsfw_display(Some(user_id)).await?;
+        let provider = self.get_provider(lot, source, None).await?;
+        let results = provider.seasonal(year, season, page).await?;
+        let results = self
+            .provider_results_to_response(lot, source, results, nsfw_display)
+            .await?;
+        let metadata_ids = results
+            .items
+            .iter()
+            .filter_map(|i| i.database_id)
+            .collect_vec();
+        // DEV: Later entries overwrite earlier ones, so each anime ends up with the
+        // state of its latest seen entry
+        let states: HashMap<i32, SeenState> = Seen::find()
+            .filter(seen::Column::UserId.eq(user_id))
+            .filter(seen::Column::MetadataId.is_in(metadata_ids.clone()))
+            .order_by_asc(seen::Column::LastUpdatedOn)
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|s| (s.metadata_id, s.state))
+            .collect();
+        let watchlist = Collection::find()
+            .filter(collection::Column::UserId.eq(user_id))
+            .filter(collection::Column::Name.eq(DefaultCollection::Watchlist.to_string()))
+            .one(&self.db)
+            .await?;
+        let in_watchlist: HashSet<i32> = match watchlist {
+            Some(c) => MetadataToCollection::find()
+                .filter(metadata_to_collection::Column::CollectionId.eq(c.id))
+                .filter(metadata_to_collection::Column::MetadataId.is_in(metadata_ids))
+                .all(&self.db)
+                .await?
+                .into_iter()
+                .map(|m| m.metadata_id)
+                .collect(),
+            None => HashSet::new(),
+        };
+        Ok(SearchResults {
+            details: results.details,
+            items: results
+                .items
+                .into_iter()
+                .map(|i| SeasonalAnimeItem {
+                    state: i.database_id.and_then(|d| states.get(&d).copied()),
+                    in_watchlist: i.database_id.map_or(false, |d| in_watchlist.contains(&d)),
+                    details: i,
+                })
+                .collect(),
+        })
+    }
+
+    async fn search_all(&self, input: SearchInput, user_id: i32) -> Result<Vec<SearchAllResult>> {
+        let preferences = self.user_preferences(user_id).await?;
+        let media = preferences.features_enabled.media.clone();
+        if !media.enabled {
+            return Ok(vec![]);
+        }
+        let lots = MetadataLot::iter().filter(|lot| match lot {
+            MetadataLot::Anime => media.anime,
+            MetadataLot::Article => media.article,
+            MetadataLot::AudioBook => media.audio_book,
+            MetadataLot::BoardGame => media.board_game,
+            MetadataLot::Comic => media.comic,
+            MetadataLot::Book => media.book,
+            MetadataLot::Manga => media.manga,
+            MetadataLot::Movie => media.movie,
+            MetadataLot::Music => media.music,
+            MetadataLot::Podcast => media.podcast,
+            MetadataLot::Show => media.show,
+            MetadataLot::VideoGame => media.video_game,
+            MetadataLot::VisualNovel => media.visual_novel,
+        });
+        let searches = lots.map(|lot| {
+            let input = SearchInput {
+                query: input.query.clone(),
+                page: input.page,
+            };
+            let preferences = &preferences;
+            async move {
+                let source = self.preferred_source_for_lot(lot, preferences).await;
+                let results = self
+                    .media_search(lot, source, input, MediaSearchFilters::default(), user_id)
+                    .await;
+                (lot, source, results)
+            }
+        });
+        let mut all_results = vec![];
+        for (lot, source, results) in join_all(searches).await {
+            match results {
+                Ok(results) => all_results.push(SearchAllResult {
+                    lot,
+                    source,
+                    results,
+                }),
+                Err(e) => tracing::error!("Error while searching for {:?}: {:?}", lot, e),
             }
-            _ => {}
         }
+        Ok(all_results)
+    }
 
-        let notifications = notifications
-            .into_iter()
-            .map(|n| (format!("{} for {:?}.", n.0, meta.title), n.1))
-            .collect_vec();
-
-        let mut meta: metadata::ActiveModel = meta.into();
-        meta.last_updated_on = ActiveValue::Set(Utc::now());
-        meta.title = ActiveValue::Set(title);
-        meta.is_nsfw = match is_nsfw {
-            None => ActiveValue::NotSet,
-            Some(n) => ActiveValue::Set(n),
+    async fn media_from_barcode(
+        &self,
+        user_id: i32,
+        input: MediaFromBarcodeInput,
+    ) -> Result<Option<MediaFromBarcodeResponse>> {
+        let source = match input.lot {
+            MetadataLot::Movie | MetadataLot::Show => MetadataSource::Tmdb,
+            MetadataLot::VideoGame => MetadataSource::Igdb,
+            _ => {
+                return Err(Error::new(
+                    "Barcode lookups are only supported for movies, shows and video games",
+                ))
+            }
         };
-        meta.provider_rating = ActiveValue::Set(provider_rating);
-        meta.description = ActiveValue::Set(description);
-        meta.images = ActiveValue::Set(Some(MetadataImages(images)));
-        meta.videos = ActiveValue::Set(Some(MetadataVideos(videos)));
-        meta.production_status = ActiveValue::Set(production_status);
-        meta.publish_year = ActiveValue::Set(publish_year);
-        meta.publish_date = ActiveValue::Set(publish_date);
-        meta.specifics = ActiveValue::Set(specifics);
-        meta.last_processed_on_for_calendar = ActiveValue::Set(None);
-        let metadata = meta.update(&self.db).await.unwrap();
-
-        self.change_metadata_associations(
-            metadata.id,
-            metadata.lot,
-            metadata.source,
-            creators,
-            genres,
-            suggestions,
-            groups,
-        )
-        .await?;
-        Ok(notifications)
+        let service = UpcItemDbService::new(&self.config.media.upcitemdb).await;
+        let product_title = match service.title_from_barcode(&input.barcode).await? {
+            Some(t) => t,
+            None => return Ok(None),
+        };
+        let results = self
+            .media_search(
+                input.lot,
+                source,
+                SearchInput {
+                    query: Some(product_title.clone()),
+                    page: None,
+                },
+                MediaSearchFilters::default(),
+                user_id,
+            )
+            .await?;
+        Ok(Some(MediaFromBarcodeResponse {
+            product_title,
+            source,
+            results,
+        }))
     }
 
-    async fn associate_creator_with_metadata(
+    /// Fetch the details in the language that the media was fetched in the first
+    /// time. Returns the language that was used.
+    async fn details_from_provider_for_existing_media(
         &self,
         metadata_id: i32,
-        creator: MetadataCreator,
-        index: usize,
-    ) -> Result<()> {
-        let db_creator = if let Some(db_creator) = Creator::find()
-            .filter(creator::Column::Name.eq(&creator.name))
+    ) -> anyhow::Result<(MediaDetails, Option<String>)> {
+        let metadata = Metadata::find_by_id(metadata_id)
             .one(&self.db)
             .await
             .unwrap()
-        {
-            if db_creator.image.is_none() {
-                let mut new: creator::ActiveModel = db_creator.clone().into();
-                new.image = ActiveValue::Set(creator.image);
-                new.update(&self.db).await?;
-            }
-            db_creator
-        } else {
-            let c = creator::ActiveModel {
-                name: ActiveValue::Set(creator.name),
-                image: ActiveValue::Set(creator.image),
-                extra_information: ActiveValue::Set(CreatorExtraInformation { active: true }),
-                ..Default::default()
-            };
-            c.insert(&self.db).await.unwrap()
-        };
-        let intermediate = metadata_to_creator::ActiveModel {
-            metadata_id: ActiveValue::Set(metadata_id),
-            creator_id: ActiveValue::Set(db_creator.id),
-            role: ActiveValue::Set(creator.role),
-            index: ActiveValue::Set(index.try_into().unwrap()),
+            .unwrap();
+        let language = metadata.language;
+        // DEV: Not using `details_from_provider` since its errors lose their type,
+        // which is needed to know whether the media was deleted on the provider.
+        let provider = self
+            .get_provider(metadata.lot, metadata.source, language.clone())
+            .await
+            .map_err(|e| anyhow!(e.message))?;
+        let mut results = provider.details(&metadata.identifier).await?;
+        self.fill_details_from_fallbacks(&mut results).await;
+        Ok((results, language))
+    }
+
+    pub async fn get_openlibrary_service(&self) -> Result<OpenlibraryService> {
+        Ok(OpenlibraryService::new(
+            &self.config.books.openlibrary,
+            self.config.frontend.page_size,
+        )
+        .await)
+    }
+
+    pub async fn get_provider(
+        &self,
+        lot: MetadataLot,
+        source: MetadataSource,
+        language: Option<String>,
+    ) -> Result<Provider> {
+        let err = || Err(Error::new("This source is not supported".to_owned()));
+        let service: Provider = match source {
+            MetadataSource::Vndb => Box::new(
+                VndbService::new(&self.config.visual_novels, self.config.frontend.page_size).await,
+            ),
+            MetadataSource::Openlibrary => Box::new(self.get_openlibrary_service().await?),
+            MetadataSource::Itunes => Box::new(
+                ITunesService::new(&self.config.podcasts.itunes, self.config.frontend.page_size)
+                    .await,
+            ),
+            MetadataSource::GoogleBooks => Box::new(
+                GoogleBooksService::new(
+                    &self.config.books.google_books,
+                    self.config.frontend.page_size,
+                )
+                .await,
+            ),
+            MetadataSource::Audible => Box::new(
+                AudibleService::new(
+                    &self.config.audio_books.audible,
+                    self.config.frontend.page_size,
+                )
+                .await,
+            ),
+            MetadataSource::Listennotes => Box::new(
+                ListennotesService::new(&self.config.podcasts, self.config.frontend.page_size)
+                    .await,
+            ),
+            MetadataSource::PodcastIndex => Box::new(
+                PodcastIndexService::new(
+                    &self.config.podcasts.podcast_index,
+                    self.config.frontend.page_size,
+                )
+                .await,
+            ),
+            MetadataSource::Tmdb => match lot {
+                MetadataLot::Show => Box::new(
+                    TmdbShowService::new(&self.config.shows.tmdb, self.config.frontend.page_size)
+                        .await
+                        .with_language(language),
+                ),
+                MetadataLot::Movie => Box::new(
+                    TmdbMovieService::new(&self.config.movies.tmdb, self.config.frontend.page_size)
+                        .await
+                        .with_language(language),
+                ),
+                _ => return err(),
+            },
+            MetadataSource::Anilist => match lot {
+                MetadataLot::Anime => Box::new(
+                    AnilistAnimeService::new(
+                        &self.config.anime.anilist,
+                        self.config.frontend.page_size,
+                    )
+                    .await
+                    .with_language(language),
+                ),
+                MetadataLot::Manga => Box::new(
+                    AnilistMangaService::new(
+                        &self.config.manga.anilist,
+                        self.config.frontend.page_size,
+                    )
+                    .await
+                    .with_language(language),
+                ),
+                _ => return err(),
+            },
+            MetadataSource::Mal => match lot {
+                MetadataLot::Anime => Box::new(
+                    MalAnimeService::new(&self.config.anime.mal, self.config.frontend.page_size)
+                        .await,
+                ),
+                MetadataLot::Manga => Box::new(
+                    MalMangaService::new(&self.config.manga.mal, self.config.frontend.page_size)
+                        .await,
+                ),
+                _ => return err(),
+            },
+            MetadataSource::Igdb => Box::new(
+                IgdbService::new(&self.config.video_games, self.config.frontend.page_size).await,
+            ),
+            MetadataSource::MangaDex => Box::new(
+                MangaDexService::new(&self.config.manga.manga_dex, self.config.frontend.page_size)
+                    .await,
+            ),
+            MetadataSource::MangaUpdates => Box::new(
+                MangaUpdatesService::new(
+                    &self.config.manga.manga_updates,
+                    self.config.frontend.page_size,
+                )
+                .await,
+            ),
+            MetadataSource::Web => Box::new(WebService::new(&self.config.articles).await),
+            MetadataSource::MusicBrainz => Box::new(
+                MusicBrainzService::new(&self.config.music, self.config.frontend.page_size).await,
+            ),
+            MetadataSource::BoardGameGeek => Box::new(
+                BggService::new(&self.config.board_games, self.config.frontend.page_size).await,
+            ),
+            MetadataSource::ComicVine => Box::new(
+                ComicVineService::new(&self.config.comics, self.config.frontend.page_size).await,
+            ),
+            MetadataSource::Custom => return err(),
         };
-        intermediate.insert(&self.db).await.ok();
-        Ok(())
+        Ok(service)
     }
 
-    pub async fn associate_group_with_metadata(
+    async fn details_from_provider(
         &self,
         lot: MetadataLot,
         source: MetadataSource,
-        (group, associated_items): (metadata_group::Model, Vec<PartialMetadata>),
-    ) -> Result<()> {
-        let existing_group = MetadataGroup::find()
-            .filter(metadata_group::Column::Identifier.eq(&group.identifier))
-            .filter(metadata_group::Column::Lot.eq(lot))
-            .filter(metadata_group::Column::Source.eq(source))
-            .one(&self.db)
-            .await?;
-        let group_id = match existing_group {
-            Some(eg) => {
-                if eg.title != group.title
-                    || eg.description != group.description
-                    || eg.images != group.images
-                {
-                    let title = group.title.clone();
-                    let description = group.description.clone();
-                    let images = group.images.clone();
-                    let mut db_group: metadata_group::ActiveModel = group.into();
-                    db_group.title = ActiveValue::Set(title);
-                    db_group.description = ActiveValue::Set(description);
-                    db_group.images = ActiveValue::Set(images);
-                    db_group.update(&self.db).await?;
-                }
-                eg.id
-            }
-            None => {
-                let mut db_group: metadata_group::ActiveModel = group.into();
-                db_group.id = ActiveValue::NotSet;
-                let new_group = db_group.insert(&self.db).await?;
-                new_group.id
+        identifier: &str,
+        language: Option<String>,
+    ) -> Result<MediaDetails> {
+        let provider = self.get_provider(lot, source, language).await?;
+        let mut results = provider.details(identifier).await?;
+        self.fill_details_from_fallbacks(&mut results).await;
+        Ok(results)
+    }
+
+    /// Try the fallback sources configured for the lot in order, until none of
+    /// the details are missing anymore. Failures of the fallback sources are
+    /// only logged since the details of the main source are still usable.
+    async fn fill_details_from_fallbacks(&self, details: &mut MediaDetails) {
+        let sources = self.media_sources_for_lot(details.lot).await;
+        for name in self.config.media.fallbacks.for_lot(details.lot) {
+            if !details.is_incomplete() {
+                break;
             }
-        };
-        for (idx, media) in associated_items.into_iter().enumerate() {
-            let db_partial_metadata = self.create_partial_metadata(media).await?;
-            let intermediate = partial_metadata_to_metadata_group::ActiveModel {
-                metadata_group_id: ActiveValue::Set(group_id),
-                partial_metadata_id: ActiveValue::Set(db_partial_metadata.id),
-                part: ActiveValue::Set(idx.try_into().unwrap()),
+            let source = match MetadataSource::from_str(name) {
+                Ok(source) if sources.contains(&source) => source,
+                _ => {
+                    tracing::warn!(
+                        "Ignoring fallback source {:?} since it does not support {}",
+                        name,
+                        details.lot
+                    );
+                    continue;
+                }
             };
-            intermediate.insert(&self.db).await.ok();
+            if source == details.source {
+                continue;
+            }
+            match self.details_from_fallback(details, source).await {
+                Ok(Some(other)) => details.merge_missing(other),
+                Ok(None) => {}
+                Err(e) => tracing::warn!(
+                    "Could not get details of {:?} from fallback source {:?}: {:?}",
+                    details.title,
+                    source,
+                    e
+                ),
+            }
         }
-        Ok(())
     }
 
-    async fn associate_suggestion_with_metadata(
+    /// The details of the same media item on another source, found by searching
+    /// for its title. Returns `None` if no search result matches the title and
+    /// the release year.
+    async fn details_from_fallback(
         &self,
-        data: PartialMetadata,
-        metadata_id: i32,
-    ) -> Result<()> {
-        let db_partial_metadata = self.create_partial_metadata(data).await?;
-        let intermediate = metadata_to_partial_metadata::ActiveModel {
-            metadata_id: ActiveValue::Set(metadata_id),
-            partial_metadata_id: ActiveValue::Set(db_partial_metadata.id),
-            relation: ActiveValue::Set(MetadataToPartialMetadataRelation::Suggestion),
-        };
-        intermediate.insert(&self.db).await.ok();
-        Ok(())
+        details: &MediaDetails,
+        source: MetadataSource,
+    ) -> Result<Option<MediaDetails>> {
+        let provider = self.get_provider(details.lot, source, None).await?;
+        let search = provider
+            .search(
+                &details.title,
+                None,
+                details.is_nsfw.unwrap_or_default(),
+                &MediaSearchFilters::default(),
+            )
+            .await?;
+        let title = normalize_text(&details.title);
+        let item = search.items.into_iter().find(|i| {
+            normalize_text(&i.title) == title
+                && match (i.publish_year, details.publish_year) {
+                    (Some(a), Some(b)) => a == b,
+                    _ => true,
+                }
+        });
+        match item {
+            Some(item) => Ok(Some(provider.details(&item.identifier).await?)),
+            None => Ok(None),
+        }
     }
 
-    async fn create_partial_metadata(
+    pub async fn commit_media(
         &self,
-        data: PartialMetadata,
-    ) -> Result<partial_metadata::Model> {
-        let model = if let Some(c) = PartialMetadataModel::find()
-            .filter(partial_metadata::Column::Identifier.eq(&data.identifier))
-            .filter(partial_metadata::Column::Lot.eq(data.lot))
-            .filter(partial_metadata::Column::Source.eq(data.source))
-            .one(&self.db)
-            .await
-            .unwrap()
+        lot: MetadataLot,
+        source: MetadataSource,
+        identifier: &str,
+        language: Option<String>,
+    ) -> Result<IdObject> {
+        if let Some(m) = self
+            .media_exists_in_database(lot, source, identifier)
+            .await?
         {
-            c
+            if let Some(language) = language {
+                let metadata = Metadata::find_by_id(m.id).one(&self.db).await?.unwrap();
+                // DEV: Other users depend on the details in the language that the
+                // media was fetched in, so the other languages are stored separately
+                let localized = metadata.language.as_ref() == Some(&language)
+                    || metadata
+                        .localizations
+                        .map(|l| l.0.contains_key(&language))
+                        .unwrap_or_default();
+                if !localized {
+                    self.perform_application_job
+                        .clone()
+                        .push(ApplicationJob::LocalizeMetadata(m.id, language))
+                        .await?;
+                }
+            }
+            Ok(m)
         } else {
-            let c = partial_metadata::ActiveModel {
-                title: ActiveValue::Set(data.title),
-                identifier: ActiveValue::Set(data.identifier),
-                lot: ActiveValue::Set(data.lot),
-                source: ActiveValue::Set(data.source),
-                image: ActiveValue::Set(data.image),
-                ..Default::default()
-            };
-            c.insert(&self.db).await.unwrap()
+            let details = self
+                .details_from_provider(lot, source, identifier, language.clone())
+                .await?;
+            let media_id = self.commit_media_internal(details, language).await?;
+            Ok(media_id)
+        }
+    }
+
+    /// The number of details that can be fetched from a provider at the same time
+    /// without running into its rate limits.
+    fn provider_concurrency(&self, source: MetadataSource) -> usize {
+        let concurrency = match source {
+            MetadataSource::BoardGameGeek
+            | MetadataSource::ComicVine
+            | MetadataSource::MusicBrainz
+            | MetadataSource::Openlibrary
+            | MetadataSource::Vndb => 1,
+            MetadataSource::Anilist
+            | MetadataSource::Mal
+            | MetadataSource::MangaDex
+            | MetadataSource::MangaUpdates => 2,
+            MetadataSource::Igdb => 4,
+            _ => usize::MAX,
         };
-        Ok(model)
+        concurrency
+            .min(self.config.scheduler.import_concurrency)
+            .max(1)
     }
 
-    async fn associate_genre_with_metadata(&self, name: String, metadata_id: i32) -> Result<()> {
-        let db_genre = if let Some(c) = Genre::find()
-            .filter(genre::Column::Name.eq(&name))
-            .one(&self.db)
-            .await
-            .unwrap()
+    /// Commit many media items at once. The details of the items that are not in
+    /// the database yet are fetched concurrently, with every provider limited to
+    /// its own concurrency. An item that is requested more than once is only
+    /// committed once. `on_committed` is called with the result of every item as
+    /// soon as it is committed.
+    pub async fn commit_media_bulk(
+        &self,
+        items: Vec<(MetadataLot, MetadataSource, String, Option<String>)>,
+        mut on_committed: impl FnMut(&(MetadataLot, MetadataSource, String), bool),
+    ) -> HashMap<(MetadataLot, MetadataSource, String), Result<IdObject>> {
+        let mut committed = HashMap::new();
+        let mut to_fetch = vec![];
+        for (lot, source, identifier, language) in items
+            .into_iter()
+            .unique_by(|(lot, source, identifier, _)| (*lot, *source, identifier.clone()))
         {
-            c
-        } else {
-            let c = genre::ActiveModel {
-                name: ActiveValue::Set(name),
-                ..Default::default()
+            let exists = self
+                .media_exists_in_database(lot, source, &identifier)
+                .await
+                .ok()
+                .flatten()
+                .is_some();
+            if exists {
+                let result = self.commit_media(lot, source, &identifier, language).await;
+                let key = (lot, source, identifier);
+                on_committed(&key, result.is_ok());
+                committed.insert(key, result);
+            } else {
+                to_fetch.push((lot, source, identifier, language));
+            }
+        }
+        let mut fetched = self.details_from_provider_bulk(to_fetch);
+        while let Some((key, details, language)) = fetched.next().await {
+            let result = match details {
+                Ok(details) => self.commit_media_internal(details, language).await,
+                Err(e) => Err(e),
             };
-            c.insert(&self.db).await.unwrap()
-        };
-        let intermediate = metadata_to_genre::ActiveModel {
-            metadata_id: ActiveValue::Set(metadata_id),
-            genre_id: ActiveValue::Set(db_genre.id),
-        };
-        intermediate.insert(&self.db).await.ok();
-        Ok(())
+            on_committed(&key, result.is_ok());
+            committed.insert(key, result);
+        }
+        committed
     }
 
-    pub async fn commit_media_internal(&self, details: MediaDetails) -> Result<IdObject> {
-        let metadata = metadata::ActiveModel {
-            lot: ActiveValue::Set(details.lot),
-            source: ActiveValue::Set(details.source),
-            title: ActiveValue::Set(details.title),
-            description: ActiveValue::Set(details.description),
-            publish_year: ActiveValue::Set(details.publish_year),
-            publish_date: ActiveValue::Set(details.publish_date),
-            images: ActiveValue::Set(Some(MetadataImages(details.images))),
-            videos: ActiveValue::Set(Some(MetadataVideos(details.videos))),
-            identifier: ActiveValue::Set(details.identifier),
-            specifics: ActiveValue::Set(details.specifics),
-            production_status: ActiveValue::Set(details.production_status),
-            provider_rating: ActiveValue::Set(details.provider_rating),
-            is_nsfw: match details.is_nsfw {
-                None => ActiveValue::NotSet,
-                Some(n) => ActiveValue::Set(n),
-            },
-            ..Default::default()
-        };
-        let metadata = metadata.insert(&self.db).await?;
+    /// Find many media items the same way that `commit_media_bulk` does, but
+    /// without writing anything. The result of an item is its ID if it is in the
+    /// database already, and nothing if it was found with its provider.
+    pub async fn match_media_bulk(
+        &self,
+        items: Vec<(MetadataLot, MetadataSource, String, Option<String>)>,
+        mut on_matched: impl FnMut(&(MetadataLot, MetadataSource, String), bool),
+    ) -> HashMap<(MetadataLot, MetadataSource, String), Result<Option<IdObject>>> {
+        let mut matched = HashMap::new();
+        let mut to_fetch = vec![];
+        for (lot, source, identifier, language) in items
+            .into_iter()
+            .unique_by(|(lot, source, identifier, _)| (*lot, *source, identifier.clone()))
+        {
+            match self
+                .media_exists_in_database(lot, source, &identifier)
+                .await
+            {
+                Ok(Some(m)) => {
+                    let key = (lot, source, identifier);
+                    on_matched(&key, true);
+                    matched.insert(key, Ok(Some(m)));
+                }
+                _ => to_fetch.push((lot, source, identifier, language)),
+            }
+        }
+        let mut fetched = self.details_from_provider_bulk(to_fetch);
+        while let Some((key, details, _)) = fetched.next().await {
+            on_matched(&key, details.is_ok());
+            matched.insert(key, details.map(|_| None));
+        }
+        matched
+    }
 
-        self.change_metadata_associations(
-            metadata.id,
-            metadata.lot,
-            metadata.source,
-            details.creators,
-            details.genres,
-            details.suggestions,
-            details.groups,
+    /// Fetch the details of many media items from their providers, with every
+    /// provider limited to its own concurrency.
+    fn details_from_provider_bulk(
+        &self,
+        items: Vec<(MetadataLot, MetadataSource, String, Option<String>)>,
+    ) -> impl Stream<
+        Item = (
+            (MetadataLot, MetadataSource, String),
+            Result<MediaDetails>,
+            Option<String>,
+        ),
+    > + '_ {
+        let total = items.len();
+        tracing::debug!("Fetching details of {total} media items from providers");
+        let fetches = items
+            .into_iter()
+            .into_group_map_by(|(_, source, _, _)| *source)
+            .into_iter()
+            .map(|(source, items)| {
+                let fetches = stream::iter(items)
+                    .map(move |(lot, source, identifier, language)| async move {
+                        let details = self
+                            .details_from_provider(lot, source, &identifier, language.clone())
+                            .await;
+                        ((lot, source, identifier), details, language)
+                    })
+                    .buffer_unordered(self.provider_concurrency(source));
+                Box::pin(fetches)
+            });
+        stream::select_all(fetches)
+    }
+
+    /// Commit media in the language that the user prefers for this source.
+    async fn commit_media_for_user(
+        &self,
+        user_id: i32,
+        lot: MetadataLot,
+        source: MetadataSource,
+        identifier: &str,
+    ) -> Result<IdObject> {
+        let preferences = user_by_id(&self.db, user_id).await?.preferences;
+        self.commit_media(
+            lot,
+            source,
+            identifier,
+            preferences.languages.for_source(source),
         )
-        .await?;
-        Ok(IdObject { id: metadata.id })
+        .await
+    }
+
+    async fn review_by_id(&self, review_id: i32, user_id: i32) -> Result<ReviewItem> {
+        let preferences = user_by_id(&self.db, user_id).await?.preferences;
+        let review = Review::find_by_id(review_id).one(&self.db).await?;
+        match review {
+            Some(r) => {
+                let user = r.find_related(User).one(&self.db).await.unwrap().unwrap();
+                let (show_se, show_ep, podcast_ep) = match r.extra_information {
+                    Some(s) => match s {
+                        SeenOrReviewOrCalendarEventExtraInformation::Show(d) => {
+                            (Some(d.season), Some(d.episode), None)
+                        }
+                        SeenOrReviewOrCalendarEventExtraInformation::Podcast(d) => {
+                            (None, None, Some(d.episode))
+                        }
+                    },
+                    None => (None, None, None),
+                };
+                Ok(ReviewItem {
+                    id: r.id,
+                    posted_on: r.posted_on,
+                    rating: r.rating.map(|s| {
+                        s.checked_div(match preferences.general.review_scale {
+                            UserReviewScale::OutOfFive => dec!(20),
+                            UserReviewScale::OutOfHundred => dec!(1),
+                        })
+                        .unwrap()
+                        .round_dp(1)
+                    }),
+                    spoiler: r.spoiler,
+                    text: r.text,
+                    visibility: r.visibility,
+                    show_season: show_se,
+                    show_episode: show_ep,
+                    podcast_episode: podcast_ep,
+                    posted_by: ReviewPostedBy {
+                        id: user.id,
+                        name: user.name,
+                    },
+                    comments: r.comments.0,
+                })
+            }
+            None => Err(Error::new("Unable to find review".to_owned())),
+        }
+    }
+
+    async fn item_reviews(
+        &self,
+        user_id: i32,
+        metadata_id: Option<i32>,
+        creator_id: Option<i32>,
+    ) -> Result<Vec<ReviewItem>> {
+        let all_reviews = Review::find()
+            .select_only()
+            .column(review::Column::Id)
+            .order_by_desc(review::Column::PostedOn)
+            .apply_if(metadata_id, |query, v| {
+                query.filter(review::Column::MetadataId.eq(v))
+            })
+            .apply_if(creator_id, |query, v| {
+                query.filter(review::Column::CreatorId.eq(v))
+            })
+            .into_tuple::<i32>()
+            .all(&self.db)
+            .await
+            .unwrap();
+        let mut reviews = vec![];
+        for r_id in all_reviews {
+            reviews.push(self.review_by_id(r_id, user_id).await?);
+        }
+        let all_reviews = reviews
+            .into_iter()
+            .filter(|r| match r.visibility {
+                Visibility::Private => r.posted_by.id == user_id,
+                _ => true,
+            })
+            .map(|r| ReviewItem {
+                text: r.text.map(|t| markdown_to_html(&t)),
+                ..r
+            })
+            .collect();
+        Ok(all_reviews)
+    }
+
+    async fn collections(
+        &self,
+        user_id: i32,
+        input: Option<CollectionInput>,
+    ) -> Result<Vec<CollectionItem>> {
+        let collections = Collection::find()
+            .filter(collection::Column::UserId.eq(user_id))
+            .apply_if(input.clone().and_then(|i| i.name), |query, v| {
+                query.filter(collection::Column::Name.eq(v))
+            })
+            .order_by_asc(collection::Column::CreatedOn)
+            .all(&self.db)
+            .await
+            .unwrap();
+        let mut data = vec![];
+        for collection in collections.into_iter() {
+            let num_items = collection.find_related(Metadata).count(&self.db).await?;
+            data.push(CollectionItem {
+                id: collection.id,
+                name: collection.name,
+                description: collection.description,
+                visibility: collection.visibility,
+                num_items,
+                actions: collection.actions.unwrap_or_default().0,
+            });
+        }
+        Ok(data)
     }
 
-    #[allow(clippy::too_many_arguments)]
-    async fn change_metadata_associations(
+    async fn media_in_collections(
         &self,
+        user_id: i32,
         metadata_id: i32,
-        lot: MetadataLot,
-        source: MetadataSource,
-        creators: Vec<MetadataCreator>,
-        genres: Vec<String>,
-        suggestions: Vec<PartialMetadata>,
-        groups: Vec<(metadata_group::Model, Vec<PartialMetadata>)>,
-    ) -> Result<()> {
-        MetadataToCreator::delete_many()
-            .filter(metadata_to_creator::Column::MetadataId.eq(metadata_id))
-            .exec(&self.db)
-            .await?;
-        MetadataToGenre::delete_many()
-            .filter(metadata_to_genre::Column::MetadataId.eq(metadata_id))
-            .exec(&self.db)
-            .await?;
-        // suggestions
-        MetadataToPartialMetadata::delete_many()
-            .filter(metadata_to_partial_metadata::Column::MetadataId.eq(metadata_id))
-            .exec(&self.db)
-            .await?;
-        for (idx, creator) in creators.into_iter().enumerate() {
-            self.associate_creator_with_metadata(metadata_id, creator, idx)
-                .await
-                .ok();
-        }
-        for genre in genres {
-            self.associate_genre_with_metadata(genre, metadata_id)
-                .await
-                .ok();
-        }
-        for suggestion in suggestions {
-            self.associate_suggestion_with_metadata(suggestion, metadata_id)
-                .await
-                .ok();
+    ) -> Result<Vec<collection::Model>> {
+        let user_collections = Collection::find()
+            .filter(collection::Column::UserId.eq(user_id))
+            .all(&self.db)
+            .await
+            .unwrap();
+        let mtc = MetadataToCollection::find()
+            .filter(metadata_to_collection::Column::MetadataId.eq(metadata_id))
+            .filter(
+                metadata_to_collection::Column::CollectionId
+                    .is_in(user_collections.into_iter().map(|c| c.id).collect_vec()),
+            )
+            .find_also_related(Collection)
+            .all(&self.db)
+            .await
+            .unwrap();
+        let mut resp = vec![];
+        mtc.into_iter().for_each(|(_, b)| {
+            if let Some(m) = b {
+                resp.push(m);
+            }
+        });
+        Ok(resp)
+    }
+
+    async fn collection_contents(
+        &self,
+        user_id: Option<i32>,
+        input: CollectionContentsInput,
+    ) -> Result<CollectionContents> {
+        let page = input.page.unwrap_or(1);
+        let collection = Collection::find_by_id(input.collection_id)
+            .one(&self.db)
+            .await
+            .unwrap()
+            .unwrap();
+        if collection.visibility != Visibility::Public {
+            match user_id {
+                None => {
+                    return Err(Error::new(
+                        "Need to be logged in to view a private collection".to_owned(),
+                    ));
+                }
+                Some(u) => {
+                    if u != collection.user_id {
+                        return Err(Error::new("This collection is not public".to_owned()));
+                    }
+                }
+            }
         }
-        // DEV: Ideally, we should remove partial_metadata to metadata_group association but does not really matter
-        for group in groups {
-            self.associate_group_with_metadata(lot, source, group)
-                .await
-                .ok();
+
+        let nsfw_display = self.user_nsfw_display(user_id).await?;
+        let all_metadata_items = Metadata::find()
+            .apply_if(
+                match nsfw_display {
+                    UserNsfwDisplay::Hide => Some(false),
+                    _ => None,
+                },
+                |query, v| query.filter(metadata::Column::IsNsfw.eq(v)),
+            )
+            .join(
+                JoinType::Join,
+                user_to_metadata::Relation::Metadata.def().rev(),
+            )
+            .join(
+                JoinType::Join,
+                metadata_to_collection::Relation::Metadata.def().rev(),
+            )
+            .filter(metadata_to_collection::Column::CollectionId.eq(collection.id))
+            .order_by_desc(user_to_metadata::Column::LastUpdatedOn)
+            .paginate(
+                &self.db,
+                input
+                    .take
+                    .unwrap_or_else(|| self.config.frontend.page_size.try_into().unwrap()),
+            );
+
+        let ItemsAndPagesNumber {
+            number_of_items,
+            number_of_pages,
+        } = all_metadata_items.num_items_and_pages().await?;
+        let mut meta_data = vec![];
+        for m in all_metadata_items.fetch_page(page - 1).await? {
+            meta_data.push((
+                MediaSearchItem {
+                    identifier: m.id.to_string(),
+                    image: self.metadata_assets(&m).await?.images.first().cloned(),
+                    title: m.title,
+                    publish_year: m.publish_year,
+                },
+                m.lot,
+                m.is_nsfw && nsfw_display == UserNsfwDisplay::Blur,
+            ));
         }
-        Ok(())
+        let items = meta_data
+            .into_iter()
+            .map(|a| MediaSearchItemWithLot {
+                details: a.0,
+                lot: a.1,
+                blur: a.2,
+            })
+            .collect();
+        let user = collection.find_related(User).one(&self.db).await?.unwrap();
+        Ok(CollectionContents {
+            details: collection,
+            results: SearchResults {
+                details: SearchDetails {
+                    total: number_of_items.try_into().unwrap(),
+                    next_page: if page < number_of_pages {
+                        Some((page + 1).try_into().unwrap())
+                    } else {
+                        None
+                    },
+                },
+                items,
+            },
+            user,
+        })
     }
 
-    pub async fn cleanup_data_without_associated_user_activities(&self) -> Result<()> {
-        tracing::trace!("Cleaning up media items without associated user activities");
-        let mut all_metadata = Metadata::find().stream(&self.db).await?;
-        while let Some(metadata) = all_metadata.try_next().await? {
-            let num_user_associations = UserToMetadata::find()
-                .filter(user_to_metadata::Column::MetadataId.eq(metadata.id))
-                .count(&self.db)
-                .await
-                .unwrap();
-            if num_user_associations == 0 {
-                metadata.delete(&self.db).await.ok();
-            }
+    pub async fn post_review(&self, user_id: i32, input: PostReviewInput) -> Result<IdObject> {
+        if self.config.users.reviews_disabled {
+            return Err(Error::new("Posting reviews on this instance is disabled"));
         }
-        tracing::trace!("Cleaning up genres without associated metadata");
-        let mut all_genre = Genre::find().stream(&self.db).await?;
-        while let Some(genre) = all_genre.try_next().await? {
-            let num_associations = MetadataToGenre::find()
-                .filter(metadata_to_genre::Column::GenreId.eq(genre.id))
-                .count(&self.db)
-                .await
-                .unwrap();
-            if num_associations == 0 {
-                genre.delete(&self.db).await.ok();
-            }
+        let review_id = match input.review_id {
+            Some(i) => ActiveValue::Set(i),
+            None => ActiveValue::NotSet,
+        };
+        let extra_information = if let (Some(season), Some(episode)) =
+            (input.show_season_number, input.show_episode_number)
+        {
+            Some(SeenOrReviewOrCalendarEventExtraInformation::Show(
+                SeenShowExtraInformation { season, episode },
+            ))
+        } else {
+            input.podcast_episode_number.map(|episode| {
+                SeenOrReviewOrCalendarEventExtraInformation::Podcast(SeenPodcastExtraInformation {
+                    episode,
+                })
+            })
+        };
+        if input.rating.is_none() && input.text.is_none() {
+            return Err(Error::new("At-least one of rating or review is required."));
         }
-        tracing::trace!("Cleaning up creators without associated metadata");
-        let mut all_creators = Creator::find().stream(&self.db).await?;
-        while let Some(creator) = all_creators.try_next().await? {
-            let num_associations = MetadataToCreator::find()
-                .filter(metadata_to_creator::Column::CreatorId.eq(creator.id))
-                .count(&self.db)
-                .await
-                .unwrap();
-            let num_reviews = Review::find()
-                .filter(review::Column::CreatorId.eq(creator.id))
-                .count(&self.db)
-                .await
-                .unwrap();
-            if num_associations + num_reviews == 0 {
-                creator.delete(&self.db).await.ok();
-            }
+
+        let preferences = user_by_id(&self.db, user_id).await?.preferences;
+        let mut review_obj = review::ActiveModel {
+            id: review_id,
+            rating: ActiveValue::Set(input.rating.map(
+                |r| match preferences.general.review_scale {
+                    UserReviewScale::OutOfFive => r * dec!(20),
+                    UserReviewScale::OutOfHundred => r,
+                },
+            )),
+            text: ActiveValue::Set(input.text),
+            user_id: ActiveValue::Set(user_id.to_owned()),
+            metadata_id: ActiveValue::Set(input.metadata_id),
+            creator_id: ActiveValue::Set(input.creator_id),
+            extra_information: ActiveValue::Set(extra_information),
+            comments: ActiveValue::Set(ReviewComments(vec![])),
+            ..Default::default()
+        };
+        if let Some(s) = input.spoiler {
+            review_obj.spoiler = ActiveValue::Set(s);
         }
-        tracing::trace!("Cleaning up partial metadata without associated metadata");
-        let mut all_partial_metadata = PartialMetadataModel::find().stream(&self.db).await?;
-        while let Some(partial_metadata) = all_partial_metadata.try_next().await? {
-            let num_associations = MetadataToPartialMetadata::find()
-                .filter(
-                    metadata_to_partial_metadata::Column::PartialMetadataId.eq(partial_metadata.id),
-                )
-                .count(&self.db)
-                .await
-                .unwrap();
-            let num_group_associations = PartialMetadataToMetadataGroup::find()
-                .filter(
-                    partial_metadata_to_metadata_group::Column::PartialMetadataId
-                        .eq(partial_metadata.id),
-                )
-                .count(&self.db)
-                .await
-                .unwrap();
-            if num_associations + num_group_associations == 0 {
-                partial_metadata.delete(&self.db).await.ok();
-            }
+        if let Some(v) = input.visibility {
+            review_obj.visibility = ActiveValue::Set(v);
         }
-        Ok(())
+        if let Some(d) = input.date {
+            review_obj.posted_on = ActiveValue::Set(d);
+        }
+        let insert = review_obj.save(&self.db).await.unwrap();
+        Ok(IdObject {
+            id: insert.id.unwrap(),
+        })
     }
 
-    pub async fn deploy_update_metadata_job(&self, metadata_id: i32) -> Result<String> {
-        let metadata = Metadata::find_by_id(metadata_id)
+    pub async fn delete_review(&self, user_id: i32, review_id: i32) -> Result<bool> {
+        let review = Review::find()
+            .filter(review::Column::Id.eq(review_id))
             .one(&self.db)
             .await
-            .unwrap()
             .unwrap();
-        let job_id = self
-            .perform_application_job
-            .clone()
-            .push(ApplicationJob::UpdateMetadata(metadata))
-            .await?;
-        Ok(job_id.to_string())
+        match review {
+            Some(r) => {
+                if r.user_id == user_id {
+                    r.delete(&self.db).await?;
+                    self.deploy_recalculate_summary_job(user_id).await?;
+                    Ok(true)
+                } else {
+                    Err(Error::new("This review does not belong to you".to_owned()))
+                }
+            }
+            None => Ok(false),
+        }
     }
 
-    pub async fn merge_metadata(&self, merge_from: i32, merge_into: i32) -> Result<bool> {
-        for old_seen in Seen::find()
-            .filter(seen::Column::MetadataId.eq(merge_from))
-            .all(&self.db)
+    pub async fn create_or_update_collection(
+        &self,
+        user_id: i32,
+        input: CreateOrUpdateCollectionInput,
+    ) -> Result<IdObject> {
+        let meta = Collection::find()
+            .filter(collection::Column::Name.eq(input.name.clone()))
+            .filter(collection::Column::UserId.eq(user_id.to_owned()))
+            .one(&self.db)
             .await
-            .unwrap()
-        {
-            let old_seen_active: seen::ActiveModel = old_seen.clone().into();
-            let new_seen = seen::ActiveModel {
-                id: ActiveValue::NotSet,
-                metadata_id: ActiveValue::Set(merge_into),
-                ..old_seen_active
-            };
-            new_seen.insert(&self.db).await?;
-            old_seen.delete(&self.db).await?;
+            .unwrap();
+        match meta {
+            Some(m) if input.update_id.is_none() => Ok(IdObject { id: m.id }),
+            _ => {
+                if let Some(actions) = &input.actions {
+                    if actions.iter().any(|a| {
+                        a.lot == CollectionActionLot::AddToCollection && a.collection_name.is_none()
+                    }) {
+                        return Err(Error::new(
+                            "The collection to add the media to is required".to_owned(),
+                        ));
+                    }
+                }
+                let col = collection::ActiveModel {
+                    id: match input.update_id {
+                        Some(i) => ActiveValue::Unchanged(i),
+                        None => ActiveValue::NotSet,
+                    },
+                    name: ActiveValue::Set(input.name),
+                    user_id: ActiveValue::Set(user_id.to_owned()),
+                    description: ActiveValue::Set(input.description),
+                    visibility: match input.visibility {
+                        None => ActiveValue::NotSet,
+                        Some(v) => ActiveValue::Set(v),
+                    },
+                    actions: match input.actions {
+                        None => ActiveValue::NotSet,
+                        Some(a) => ActiveValue::Set(Some(CollectionActions(a))),
+                    },
+                    ..Default::default()
+                };
+                let inserted = col.save(&self.db).await.map_err(|_| {
+                    Error::new("There was an error creating the collection".to_owned())
+                })?;
+                Ok(IdObject {
+                    id: inserted.id.unwrap(),
+                })
+            }
         }
-        for old_review in Review::find()
-            .filter(review::Column::MetadataId.eq(merge_from))
-            .all(&self.db)
-            .await
-            .unwrap()
-        {
-            let old_review_active: review::ActiveModel = old_review.clone().into();
-            let new_review = review::ActiveModel {
-                id: ActiveValue::NotSet,
-                metadata_id: ActiveValue::Set(Some(merge_into)),
-                ..old_review_active
-            };
-            new_review.insert(&self.db).await?;
-            old_review.delete(&self.db).await?;
+    }
+
+    pub async fn delete_collection(&self, user_id: i32, name: &str) -> Result<bool> {
+        if DefaultCollection::iter().any(|col_name| col_name.to_string() == name) {
+            return Err(Error::new("Can not delete a default collection".to_owned()));
         }
-        MetadataToCollection::update_many()
-            .filter(metadata_to_collection::Column::MetadataId.eq(merge_from))
-            .set(metadata_to_collection::ActiveModel {
-                metadata_id: ActiveValue::Set(merge_into),
-                ..Default::default()
-            })
-            .exec(&self.db)
+        let collection = Collection::find()
+            .filter(collection::Column::Name.eq(name))
+            .filter(collection::Column::UserId.eq(user_id.to_owned()))
+            .one(&self.db)
             .await?;
-        Metadata::delete_by_id(merge_from).exec(&self.db).await?;
-        Ok(true)
+        let resp = if let Some(c) = collection {
+            Collection::delete_by_id(c.id).exec(&self.db).await.is_ok()
+        } else {
+            false
+        };
+        Ok(resp)
     }
 
-    async fn user_preferences(&self, user_id: i32) -> Result<UserPreferences> {
-        let mut preferences = user_by_id(&self.db, user_id).await?.preferences;
-        preferences.features_enabled.media.anime =
-            self.config.anime.is_enabled() && preferences.features_enabled.media.anime;
-        preferences.features_enabled.media.audio_book =
-            self.config.audio_books.is_enabled() && preferences.features_enabled.media.audio_book;
-        preferences.features_enabled.media.book =
-            self.config.books.is_enabled() && preferences.features_enabled.media.book;
-        preferences.features_enabled.media.show =
-            self.config.shows.is_enabled() && preferences.features_enabled.media.show;
-        preferences.features_enabled.media.manga =
-            self.config.manga.is_enabled() && preferences.features_enabled.media.manga;
-        preferences.features_enabled.media.movie =
-            self.config.movies.is_enabled() && preferences.features_enabled.media.movie;
-        preferences.features_enabled.media.podcast =
-            self.config.podcasts.is_enabled() && preferences.features_enabled.media.podcast;
-        preferences.features_enabled.media.video_game =
-            self.config.video_games.is_enabled() && preferences.features_enabled.media.video_game;
-        Ok(preferences)
+    pub async fn remove_media_from_collection(
+        &self,
+        user_id: i32,
+        metadata_id: &i32,
+        collection_name: &str,
+    ) -> Result<IdObject> {
+        let collect = Collection::find()
+            .filter(collection::Column::Name.eq(collection_name.to_owned()))
+            .filter(collection::Column::UserId.eq(user_id.to_owned()))
+            .one(&self.db)
+            .await
+            .unwrap()
+            .unwrap();
+        let col = metadata_to_collection::ActiveModel {
+            metadata_id: ActiveValue::Set(metadata_id.to_owned()),
+            collection_id: ActiveValue::Set(collect.id),
+        };
+        let id = col.collection_id.clone().unwrap();
+        col.delete(&self.db).await.ok();
+        Ok(IdObject { id })
     }
 
-    async fn core_enabled_features(&self) -> Result<GeneralFeatures> {
-        let mut files_enabled = self.config.file_storage.is_enabled();
-        if files_enabled && !self.file_storage_service.is_enabled().await {
-            files_enabled = false;
-        }
-        let general = GeneralFeatures {
-            file_storage: files_enabled,
-            signup_allowed: self.config.users.allow_registration,
+    pub async fn add_media_to_collection(
+        &self,
+        user_id: i32,
+        input: AddMediaToCollection,
+    ) -> Result<bool> {
+        let collection = Collection::find()
+            .filter(collection::Column::UserId.eq(user_id.to_owned()))
+            .filter(collection::Column::Name.eq(input.collection_name))
+            .one(&self.db)
+            .await
+            .unwrap()
+            .unwrap();
+        let col = metadata_to_collection::ActiveModel {
+            metadata_id: ActiveValue::Set(input.media_id),
+            collection_id: ActiveValue::Set(collection.id),
         };
-        Ok(general)
+        let inserted = col.clone().insert(&self.db).await.is_ok();
+        if inserted {
+            self.run_collection_actions(
+                user_id,
+                input.media_id,
+                collection.actions.unwrap_or_default().0,
+            )
+            .await?;
+        }
+        Ok(inserted)
     }
 
-    async fn media_search(
+    /// Run the actions configured on a collection for a media that was just added
+    /// to it.
+    async fn run_collection_actions(
         &self,
-        lot: MetadataLot,
-        source: MetadataSource,
-        input: SearchInput,
         user_id: i32,
-    ) -> Result<SearchResults<MediaSearchItemResponse>> {
-        if let Some(q) = input.query {
-            if q.is_empty() {
-                return Ok(SearchResults {
-                    details: SearchDetails {
-                        total: 0,
-                        next_page: None,
-                    },
-                    items: vec![],
-                });
-            }
-            let preferences = user_by_id(&self.db, user_id).await?.preferences;
-            let provider = self.get_provider(lot, source).await?;
-            let results = provider
-                .search(&q, input.page, preferences.general.display_nsfw)
-                .await?;
-            let mut all_idens = results
-                .items
-                .iter()
-                .map(|i| i.identifier.to_owned())
-                .collect_vec();
-            let data = if all_idens.is_empty() {
-                vec![]
-            } else {
-                #[derive(Iden)]
-                #[iden = "identifiers"]
-                enum TempIdentifiers {
-                    #[iden = "identifiers"]
-                    Alias,
-                    Identifier,
+        metadata_id: i32,
+        actions: Vec<CollectionAction>,
+    ) -> Result<()> {
+        for action in actions {
+            match action.lot {
+                CollectionActionLot::Monitor => {
+                    let utm =
+                        associate_user_with_metadata(&user_id, &metadata_id, &self.db).await?;
+                    if !utm.monitored {
+                        let mut utm: user_to_metadata::ActiveModel = utm.into();
+                        utm.monitored = ActiveValue::Set(true);
+                        utm.update(&self.db).await?;
+                    }
                 }
-                let metadata_alias = Alias::new("m");
-                // This can be done with `select id from metadata where identifier = '...'
-                // and lot = '...'` in a loop. But, I wanted to write a performant query.
-                let first_iden = all_idens.drain(..1).collect_vec().pop().unwrap();
-                let mut subquery = Query::select()
-                    .expr_as(Expr::val(first_iden), TempIdentifiers::Identifier)
-                    .to_owned();
-                for identifier in all_idens {
-                    subquery = subquery
-                        .union(
-                            UnionType::All,
-                            Query::select().expr(Expr::val(identifier)).to_owned(),
+                CollectionActionLot::RemindOnRelease => {
+                    let utm =
+                        associate_user_with_metadata(&user_id, &metadata_id, &self.db).await?;
+                    // DEV: A reminder that the user has set manually is not overwritten
+                    if utm.reminder.is_some() {
+                        continue;
+                    }
+                    let meta = Metadata::find_by_id(metadata_id)
+                        .one(&self.db)
+                        .await?
+                        .unwrap();
+                    if let Some(remind_on) = meta.publish_date {
+                        self.create_media_reminder(
+                            user_id,
+                            CreateMediaReminderInput {
+                                metadata_id,
+                                remind_on,
+                                message: format!("{} has been released", meta.title),
+                            },
                         )
-                        .to_owned();
+                        .await?;
+                    }
                 }
-                let identifiers_query = Query::select()
-                    .expr(Expr::col((
-                        TempIdentifiers::Alias,
-                        TempIdentifiers::Identifier,
-                    )))
-                    .expr_as(
-                        Expr::case(
-                            Expr::col((metadata_alias.clone(), TempMetadata::Id)).is_not_null(),
-                            Expr::col((metadata_alias.clone(), TempMetadata::Id)),
-                        )
-                        .finally(Keyword::Null),
-                        TempMetadata::Id,
-                    )
-                    .from_subquery(subquery, TempIdentifiers::Alias)
-                    .join_as(
-                        JoinType::LeftJoin,
-                        TempMetadata::Table,
-                        metadata_alias.clone(),
-                        Expr::col((TempIdentifiers::Alias, TempIdentifiers::Identifier))
-                            .equals((metadata_alias.clone(), TempMetadata::Identifier)),
-                    )
-                    .and_where(
-                        Expr::col((metadata_alias.clone(), TempMetadata::Lot))
-                            .eq(lot)
-                            .and(
-                                Expr::col((metadata_alias.clone(), TempMetadata::Source))
-                                    .eq(source),
-                            )
-                            .or(Expr::col((metadata_alias.clone(), TempMetadata::Lot)).is_null()),
-                    )
-                    .to_owned();
-                let stmt = self.get_db_stmt(identifiers_query);
-                #[derive(Debug, FromQueryResult)]
-                struct DbResponse {
-                    identifier: String,
-                    id: Option<i32>,
+                CollectionActionLot::AddToCollection => {
+                    let other = Collection::find()
+                        .filter(collection::Column::UserId.eq(user_id))
+                        .filter(collection::Column::Name.eq(action.collection_name))
+                        .one(&self.db)
+                        .await?;
+                    // DEV: The actions of the other collection are not run so that
+                    // collections referring to each other do not loop
+                    if let Some(other) = other {
+                        let col = metadata_to_collection::ActiveModel {
+                            metadata_id: ActiveValue::Set(metadata_id),
+                            collection_id: ActiveValue::Set(other.id),
+                        };
+                        col.insert(&self.db).await.ok();
+                    }
                 }
-                let identifiers = DbResponse::find_by_statement(stmt).all(&self.db).await?;
-                results
-                    .items
-                    .into_iter()
-                    .map(|i| MediaSearchItemResponse {
-                        database_id: identifiers
-                            .iter()
-                            .find(|&f| f.identifier == i.identifier)
-                            .and_then(|i| i.id),
-                        item: i,
-                    })
-                    .collect()
-            };
-            let results = SearchResults {
-                details: results.details,
-                items: data,
-            };
-            Ok(results)
-        } else {
-            Err(Error::new("Can not search without a query"))
+            }
+        }
+        Ok(())
+    }
+
+    async fn update_seen_item_rating(
+        &self,
+        user_id: i32,
+        input: UpdateSeenItemRatingInput,
+    ) -> Result<IdObject> {
+        let seen_item = Seen::find_by_id(input.seen_id)
+            .filter(seen::Column::UserId.eq(user_id))
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| Error::new("This seen item does not belong to this user"))?;
+        let preferences = user_by_id(&self.db, user_id).await?.preferences;
+        let multiplier = match preferences.general.review_scale {
+            UserReviewScale::OutOfFive => dec!(20),
+            UserReviewScale::OutOfHundred => dec!(1),
+        };
+        let mut seen_item: seen::ActiveModel = seen_item.into();
+        seen_item.rating = ActiveValue::Set(input.rating.map(|r| r * multiplier));
+        let seen_item = seen_item.update(&self.db).await?;
+        Ok(IdObject { id: seen_item.id })
+    }
+
+    async fn add_seen_attachment(
+        &self,
+        user_id: i32,
+        input: AddSeenAttachmentInput,
+    ) -> Result<IdObject> {
+        if !self.config.file_storage.is_enabled() {
+            return Err(Error::new("File storage is not enabled on this instance"));
+        }
+        if !FileStorageService::is_user_upload(&input.key, user_id) {
+            return Err(Error::new("This file was not uploaded by the user"));
+        }
+        let seen_item = Seen::find_by_id(input.seen_id)
+            .filter(seen::Column::UserId.eq(user_id))
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| Error::new("This seen item does not belong to this user"))?;
+        let attachment = seen_attachment::ActiveModel {
+            seen_id: ActiveValue::Set(seen_item.id),
+            key: ActiveValue::Set(input.key),
+            ..Default::default()
         }
+        .insert(&self.db)
+        .await?;
+        Ok(IdObject { id: attachment.id })
     }
 
-    async fn details_from_provider_for_existing_media(
-        &self,
-        metadata_id: i32,
-    ) -> Result<MediaDetails> {
-        let metadata = Metadata::find_by_id(metadata_id)
+    async fn delete_seen_attachment(&self, user_id: i32, attachment_id: i32) -> Result<bool> {
+        let attachment = SeenAttachment::find_by_id(attachment_id)
+            .find_also_related(Seen)
+            .filter(seen::Column::UserId.eq(user_id))
             .one(&self.db)
-            .await
-            .unwrap()
-            .unwrap();
-        let results = self
-            .details_from_provider(metadata.lot, metadata.source, &metadata.identifier)
-            .await?;
-        Ok(results)
+            .await?
+            .ok_or_else(|| Error::new("This attachment does not belong to this user"))?;
+        attachment.0.delete(&self.db).await?;
+        Ok(true)
     }
 
-    pub async fn get_openlibrary_service(&self) -> Result<OpenlibraryService> {
-        Ok(OpenlibraryService::new(
-            &self.config.books.openlibrary,
-            self.config.frontend.page_size,
-        )
-        .await)
+    async fn highlights(
+        &self,
+        user_id: i32,
+        metadata_id: Option<i32>,
+    ) -> Result<Vec<highlight::Model>> {
+        let mut query = Highlight::find().filter(highlight::Column::UserId.eq(user_id));
+        if let Some(metadata_id) = metadata_id {
+            query = query.filter(highlight::Column::MetadataId.eq(metadata_id));
+        }
+        let highlights = query
+            .order_by_asc(highlight::Column::MetadataId)
+            .order_by_asc(highlight::Column::Page)
+            .order_by_asc(highlight::Column::Id)
+            .all(&self.db)
+            .await?;
+        Ok(highlights)
     }
 
-    async fn get_provider(&self, lot: MetadataLot, source: MetadataSource) -> Result<Provider> {
-        let err = || Err(Error::new("This source is not supported".to_owned()));
-        let service: Provider = match source {
-            MetadataSource::Vndb => Box::new(
-                VndbService::new(&self.config.visual_novels, self.config.frontend.page_size).await,
-            ),
-            MetadataSource::Openlibrary => Box::new(self.get_openlibrary_service().await?),
-            MetadataSource::Itunes => Box::new(
-                ITunesService::new(&self.config.podcasts.itunes, self.config.frontend.page_size)
-                    .await,
-            ),
-            MetadataSource::GoogleBooks => Box::new(
-                GoogleBooksService::new(
-                    &self.config.books.google_books,
-                    self.config.frontend.page_size,
-                )
-                .await,
-            ),
-            MetadataSource::Audible => Box::new(
-                AudibleService::new(
-                    &self.config.audio_books.audible,
-                    self.config.frontend.page_size,
-                )
-                .await,
-            ),
-            MetadataSource::Listennotes => Box::new(
-                ListennotesService::new(&self.config.podcasts, self.config.frontend.page_size)
-                    .await,
-            ),
-            MetadataSource::Tmdb => match lot {
-                MetadataLot::Show => Box::new(
-                    TmdbShowService::new(&self.config.shows.tmdb, self.config.frontend.page_size)
-                        .await,
-                ),
-                MetadataLot::Movie => Box::new(
-                    TmdbMovieService::new(&self.config.movies.tmdb, self.config.frontend.page_size)
-                        .await,
-                ),
-                _ => return err(),
-            },
-            MetadataSource::Anilist => match lot {
-                MetadataLot::Anime => Box::new(
-                    AnilistAnimeService::new(
-                        &self.config.anime.anilist,
-                        self.config.frontend.page_size,
-                    )
-                    .await,
-                ),
-                MetadataLot::Manga => Box::new(
-                    AnilistMangaService::new(
-                        &self.config.manga.anilist,
-                        self.config.frontend.page_size,
-                    )
-                    .await,
-                ),
-                _ => return err(),
-            },
-            MetadataSource::Mal => match lot {
-                MetadataLot::Anime => Box::new(
-                    MalAnimeService::new(&self.config.anime.mal, self.config.frontend.page_size)
-                        .await,
-                ),
-                MetadataLot::Manga => Box::new(
-                    MalMangaService::new(&self.config.manga.mal, self.config.frontend.page_size)
-                        .await,
-                ),
-                _ => return err(),
-            },
-            MetadataSource::Igdb => Box::new(
-                IgdbService::new(&self.config.video_games, self.config.frontend.page_size).await,
-            ),
-            MetadataSource::MangaUpdates => Box::new(
-                MangaUpdatesService::new(
-                    &self.config.manga.manga_updates,
-                    self.config.frontend.page_size,
-                )
-                .await,
-            ),
-            MetadataSource::Custom => return err(),
+    pub async fn create_or_update_highlight(
+        &self,
+        user_id: i32,
+        input: CreateOrUpdateHighlightInput,
+    ) -> Result<IdObject> {
+        let text = input.text.trim().to_owned();
+        if text.is_empty() {
+            return Err(Error::new("The text of a highlight can not be empty"));
+        }
+        let chapter = input
+            .chapter
+            .map(|c| c.trim().to_owned())
+            .filter(|c| !c.is_empty());
+        let note = input
+            .note
+            .map(|n| n.trim().to_owned())
+            .filter(|n| !n.is_empty());
+        let highlight = match input.highlight_id {
+            Some(highlight_id) => {
+                let highlight = Highlight::find_by_id(highlight_id)
+                    .filter(highlight::Column::UserId.eq(user_id))
+                    .one(&self.db)
+                    .await?
+                    .ok_or_else(|| Error::new("This highlight does not belong to this user"))?;
+                let mut highlight: highlight::ActiveModel = highlight.into();
+                highlight.text = ActiveValue::Set(text);
+                highlight.page = ActiveValue::Set(input.page);
+                highlight.chapter = ActiveValue::Set(chapter);
+                highlight.note = ActiveValue::Set(note);
+                highlight.update(&self.db).await?
+            }
+            None => {
+                let metadata = Metadata::find_by_id(input.metadata_id)
+                    .one(&self.db)
+                    .await?
+                    .ok_or_else(|| Error::new("The media does not exist"))?;
+                if metadata.lot != MetadataLot::Book {
+                    return Err(Error::new("Highlights can only be added to books"));
+                }
+                // DEV: Importing the same clippings again should not duplicate the
+                // highlights
+                if let Some(existing) = Highlight::find()
+                    .filter(highlight::Column::UserId.eq(user_id))
+                    .filter(highlight::Column::MetadataId.eq(metadata.id))
+                    .filter(highlight::Column::Text.eq(text.clone()))
+                    .one(&self.db)
+                    .await?
+                {
+                    return Ok(IdObject { id: existing.id });
+                }
+                associate_user_with_metadata(&user_id, &metadata.id, &self.db).await?;
+                highlight::ActiveModel {
+                    user_id: ActiveValue::Set(user_id),
+                    metadata_id: ActiveValue::Set(metadata.id),
+                    text: ActiveValue::Set(text),
+                    page: ActiveValue::Set(input.page),
+                    chapter: ActiveValue::Set(chapter),
+                    note: ActiveValue::Set(note),
+                    ..Default::default()
+                }
+                .insert(&self.db)
+                .await?
+            }
         };
-        Ok(service)
+        Ok(IdObject { id: highlight.id })
     }
 
-    async fn details_from_provider(
-        &self,
-        lot: MetadataLot,
-        source: MetadataSource,
-        identifier: &str,
-    ) -> Result<MediaDetails> {
-        let provider = self.get_provider(lot, source).await?;
-        let results = provider.details(identifier).await?;
-        Ok(results)
+    async fn delete_highlight(&self, user_id: i32, highlight_id: i32) -> Result<bool> {
+        let highlight = Highlight::find_by_id(highlight_id)
+            .filter(highlight::Column::UserId.eq(user_id))
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| Error::new("This highlight does not belong to this user"))?;
+        highlight.delete(&self.db).await?;
+        Ok(true)
     }
 
-    pub async fn commit_media(
+    async fn add_seen_companion(
         &self,
-        lot: MetadataLot,
-        source: MetadataSource,
-        identifier: &str,
+        user_id: i32,
+        input: AddSeenCompanionInput,
     ) -> Result<IdObject> {
-        if let Some(m) = self
-            .media_exists_in_database(lot, source, identifier)
+        let seen_item = Seen::find_by_id(input.seen_id)
+            .filter(seen::Column::UserId.eq(user_id))
+            .one(&self.db)
             .await?
-        {
-            Ok(m)
-        } else {
-            let details = self.details_from_provider(lot, source, identifier).await?;
-            let media_id = self.commit_media_internal(details).await?;
-            Ok(media_id)
+            .ok_or_else(|| Error::new("This seen item does not belong to this user"))?;
+        let (name, companion_user_id) = match (input.name, input.username) {
+            (None, Some(username)) => {
+                let companion = User::find()
+                    .filter(user::Column::Name.eq(username))
+                    .one(&self.db)
+                    .await?
+                    .ok_or_else(|| Error::new("There is no user with this username"))?;
+                if companion.id == user_id {
+                    return Err(Error::new("You can not tag yourself"));
+                }
+                (companion.name, Some(companion.id))
+            }
+            (Some(name), None) if !name.trim().is_empty() => (name.trim().to_owned(), None),
+            _ => {
+                return Err(Error::new(
+                    "Exactly one of the name or the username must be provided",
+                ))
+            }
+        };
+        let already_tagged = SeenCompanion::find()
+            .filter(seen_companion::Column::SeenId.eq(seen_item.id))
+            .filter(seen_companion::Column::Name.eq(&name))
+            .count(&self.db)
+            .await?;
+        if already_tagged > 0 {
+            return Err(Error::new("This companion is already tagged"));
+        }
+        let companion = seen_companion::ActiveModel {
+            seen_id: ActiveValue::Set(seen_item.id),
+            name: ActiveValue::Set(name),
+            user_id: ActiveValue::Set(companion_user_id),
+            ..Default::default()
+        }
+        .insert(&self.db)
+        .await?;
+        if let Some(companion_user_id) = companion_user_id {
+            let tagged_by = user_by_id(&self.db, user_id).await?.name;
+            let meta = Metadata::find_by_id(seen_item.metadata_id)
+                .one(&self.db)
+                .await?
+                .unwrap();
+            self.send_notifications_to_user_platforms(
+                companion_user_id,
+                &format!(
+                    "{} tagged you in {}. Confirm it to add it to your history.",
+                    tagged_by, meta.title
+                ),
+            )
+            .await
+            .ok();
         }
+        Ok(IdObject { id: companion.id })
     }
 
-    async fn review_by_id(&self, review_id: i32, user_id: i32) -> Result<ReviewItem> {
-        let preferences = user_by_id(&self.db, user_id).await?.preferences;
-        let review = Review::find_by_id(review_id).one(&self.db).await?;
-        match review {
-            Some(r) => {
-                let user = r.find_related(User).one(&self.db).await.unwrap().unwrap();
-                let (show_se, show_ep, podcast_ep) = match r.extra_information {
-                    Some(s) => match s {
-                        SeenOrReviewOrCalendarEventExtraInformation::Show(d) => {
-                            (Some(d.season), Some(d.episode), None)
-                        }
-                        SeenOrReviewOrCalendarEventExtraInformation::Podcast(d) => {
-                            (None, None, Some(d.episode))
-                        }
-                    },
-                    None => (None, None, None),
-                };
-                Ok(ReviewItem {
-                    id: r.id,
-                    posted_on: r.posted_on,
-                    rating: r.rating.map(|s| {
-                        s.checked_div(match preferences.general.review_scale {
-                            UserReviewScale::OutOfFive => dec!(20),
-                            UserReviewScale::OutOfHundred => dec!(1),
-                        })
-                        .unwrap()
-                        .round_dp(1)
-                    }),
-                    spoiler: r.spoiler,
-                    text: r.text,
-                    visibility: r.visibility,
-                    show_season: show_se,
-                    show_episode: show_ep,
-                    podcast_episode: podcast_ep,
-                    posted_by: ReviewPostedBy {
-                        id: user.id,
-                        name: user.name,
-                    },
-                    comments: r.comments.0,
-                })
-            }
-            None => Err(Error::new("Unable to find review".to_owned())),
-        }
+    async fn delete_seen_companion(&self, user_id: i32, companion_id: i32) -> Result<bool> {
+        let companion = SeenCompanion::find_by_id(companion_id)
+            .find_also_related(Seen)
+            .filter(seen::Column::UserId.eq(user_id))
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| Error::new("This companion does not belong to this user"))?;
+        companion.0.delete(&self.db).await?;
+        Ok(true)
     }
 
-    async fn item_reviews(
+    async fn respond_to_seen_companion(
         &self,
         user_id: i32,
-        metadata_id: Option<i32>,
-        creator_id: Option<i32>,
-    ) -> Result<Vec<ReviewItem>> {
-        let all_reviews = Review::find()
-            .select_only()
-            .column(review::Column::Id)
-            .order_by_desc(review::Column::PostedOn)
-            .apply_if(metadata_id, |query, v| {
-                query.filter(review::Column::MetadataId.eq(v))
-            })
-            .apply_if(creator_id, |query, v| {
-                query.filter(review::Column::CreatorId.eq(v))
-            })
+        companion_id: i32,
+        accept: bool,
+    ) -> Result<bool> {
+        let (companion, seen_item) = SeenCompanion::find_by_id(companion_id)
+            .filter(seen_companion::Column::UserId.eq(user_id))
+            .filter(seen_companion::Column::MirroredSeenId.is_null())
+            .find_also_related(Seen)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| Error::new("There is no pending request with this id"))?;
+        let seen_item = seen_item.unwrap();
+        let mut companion: seen_companion::ActiveModel = companion.into();
+        // DEV: A declined request is kept as a plain name so that the history of
+        // the user that tagged them does not change
+        if !accept {
+            companion.user_id = ActiveValue::Set(None);
+            companion.update(&self.db).await?;
+            return Ok(true);
+        }
+        let mirrored_seen = seen::ActiveModel {
+            progress: ActiveValue::Set(seen_item.progress),
+            user_id: ActiveValue::Set(user_id),
+            metadata_id: ActiveValue::Set(seen_item.metadata_id),
+            started_on: ActiveValue::Set(seen_item.started_on),
+            finished_on: ActiveValue::Set(seen_item.finished_on),
+            last_updated_on: ActiveValue::Set(Utc::now()),
+            extra_information: ActiveValue::Set(seen_item.extra_information),
+            state: ActiveValue::Set(seen_item.state),
+            context: ActiveValue::Set(seen_item.context),
+            ..Default::default()
+        }
+        .insert(&self.db)
+        .await?;
+        companion.mirrored_seen_id = ActiveValue::Set(Some(mirrored_seen.id));
+        companion.update(&self.db).await?;
+        self.perform_application_job
+            .clone()
+            .push(ApplicationJob::AfterMediaSeen(mirrored_seen))
+            .await?;
+        Ok(true)
+    }
+
+    async fn pending_seen_companions(&self, user_id: i32) -> Result<Vec<PendingSeenCompanion>> {
+        let companions = SeenCompanion::find()
+            .filter(seen_companion::Column::UserId.eq(user_id))
+            .filter(seen_companion::Column::MirroredSeenId.is_null())
+            .find_also_related(Seen)
+            .order_by_desc(seen_companion::Column::CreatedOn)
+            .all(&self.db)
+            .await?;
+        let mut items = vec![];
+        for (companion, seen_item) in companions {
+            let mut seen_item = seen_item.unwrap();
+            let tagged_by = user_by_id(&self.db, seen_item.user_id).await?.name;
+            let meta = Metadata::find_by_id(seen_item.metadata_id)
+                .one(&self.db)
+                .await?
+                .unwrap();
+            // DEV: The rating is personal to the user that tagged them
+            seen_item.rating = None;
+            modify_seen_elements(std::slice::from_mut(&mut seen_item));
+            items.push(PendingSeenCompanion {
+                companion_id: companion.id,
+                tagged_by,
+                metadata_id: meta.id,
+                metadata_title: meta.title,
+                seen: seen_item,
+            });
+        }
+        Ok(items)
+    }
+
+    /// Flag the seen items of all users that are likely duplicates.
+    pub async fn detect_duplicate_seen_history(&self) -> Result<()> {
+        let all_users = User::find()
+            .select_only()
+            .column(user::Column::Id)
             .into_tuple::<i32>()
             .all(&self.db)
-            .await
-            .unwrap();
-        let mut reviews = vec![];
-        for r_id in all_reviews {
-            reviews.push(self.review_by_id(r_id, user_id).await?);
+            .await?;
+        for user_id in all_users {
+            self.detect_duplicate_seen_history_for_user(user_id).await?;
         }
-        let all_reviews = reviews
-            .into_iter()
-            .filter(|r| match r.visibility {
-                Visibility::Private => r.posted_by.id == user_id,
-                _ => true,
-            })
-            .map(|r| ReviewItem {
-                text: r.text.map(|t| markdown_to_html(&t)),
-                ..r
-            })
-            .collect();
-        Ok(all_reviews)
+        Ok(())
     }
 
-    async fn collections(
-        &self,
-        user_id: i32,
-        input: Option<CollectionInput>,
-    ) -> Result<Vec<CollectionItem>> {
-        let collections = Collection::find()
-            .filter(collection::Column::UserId.eq(user_id))
-            .apply_if(input.clone().and_then(|i| i.name), |query, v| {
-                query.filter(collection::Column::Name.eq(v))
-            })
-            .order_by_asc(collection::Column::CreatedOn)
+    /// Seen items of the same media (and episode) that were finished on the same
+    /// date are likely duplicates, usually created by importing from more than
+    /// one source. The oldest one is kept as the original and the rest are
+    /// flagged so that the user can review them.
+    pub async fn detect_duplicate_seen_history_for_user(&self, user_id: i32) -> Result<usize> {
+        let seen_items = Seen::find()
+            .filter(seen::Column::UserId.eq(user_id))
+            .filter(seen::Column::DuplicateDismissed.eq(false))
+            .order_by_asc(seen::Column::Id)
             .all(&self.db)
-            .await
-            .unwrap();
-        let mut data = vec![];
-        for collection in collections.into_iter() {
-            let num_items = collection.find_related(Metadata).count(&self.db).await?;
-            data.push(CollectionItem {
-                id: collection.id,
-                name: collection.name,
-                description: collection.description,
-                visibility: collection.visibility,
-                num_items,
-            });
+            .await?;
+        let mut originals = HashMap::new();
+        let mut flagged = 0;
+        for item in seen_items {
+            let duplicate_of = match item.finished_on {
+                Some(finished_on) => {
+                    let key = (
+                        item.metadata_id,
+                        finished_on,
+                        serde_json::to_string(&item.extra_information)?,
+                    );
+                    match originals.get(&key) {
+                        Some(original) => Some(*original),
+                        None => {
+                            originals.insert(key, item.id);
+                            None
+                        }
+                    }
+                }
+                None => None,
+            };
+            if duplicate_of.is_some() {
+                flagged += 1;
+            }
+            // DEV: Updating the model directly would mark the media as updated
+            // for the user
+            if item.duplicate_of != duplicate_of {
+                Seen::update_many()
+                    .col_expr(seen::Column::DuplicateOf, Expr::value(duplicate_of))
+                    .filter(seen::Column::Id.eq(item.id))
+                    .exec(&self.db)
+                    .await?;
+            }
         }
-        Ok(data)
+        if flagged > 0 {
+            tracing::debug!(
+                "Flagged {} seen items of user = {} as duplicates",
+                flagged,
+                user_id
+            );
+        }
+        Ok(flagged)
     }
 
-    async fn media_in_collections(
-        &self,
-        user_id: i32,
-        metadata_id: i32,
-    ) -> Result<Vec<collection::Model>> {
-        let user_collections = Collection::find()
-            .filter(collection::Column::UserId.eq(user_id))
+    async fn duplicate_seen_history(&self, user_id: i32) -> Result<Vec<DuplicateSeenHistory>> {
+        let duplicates = Seen::find()
+            .filter(seen::Column::UserId.eq(user_id))
+            .filter(seen::Column::DuplicateOf.is_not_null())
+            .order_by_asc(seen::Column::Id)
             .all(&self.db)
-            .await
-            .unwrap();
-        let mtc = MetadataToCollection::find()
-            .filter(metadata_to_collection::Column::MetadataId.eq(metadata_id))
-            .filter(
-                metadata_to_collection::Column::CollectionId
-                    .is_in(user_collections.into_iter().map(|c| c.id).collect_vec()),
-            )
-            .find_also_related(Collection)
+            .await?;
+        let originals = Seen::find()
+            .filter(seen::Column::UserId.eq(user_id))
+            .filter(seen::Column::Id.is_in(duplicates.iter().filter_map(|d| d.duplicate_of)))
+            .order_by_desc(seen::Column::FinishedOn)
             .all(&self.db)
-            .await
-            .unwrap();
-        let mut resp = vec![];
-        mtc.into_iter().for_each(|(_, b)| {
-            if let Some(m) = b {
-                resp.push(m);
-            }
-        });
-        Ok(resp)
+            .await?;
+        let review_scale = user_by_id(&self.db, user_id)
+            .await?
+            .preferences
+            .general
+            .review_scale;
+        let mut duplicates = duplicates
+            .into_iter()
+            .into_group_map_by(|d| d.duplicate_of.unwrap());
+        let mut items = vec![];
+        for original in originals {
+            let duplicates = match duplicates.remove(&original.id) {
+                Some(d) => d,
+                None => continue,
+            };
+            let meta = Metadata::find_by_id(original.metadata_id)
+                .one(&self.db)
+                .await?
+                .unwrap();
+            let mut all_seen = vec![original];
+            all_seen.extend(duplicates);
+            modify_seen_elements(&mut all_seen);
+            all_seen.iter_mut().for_each(|s| {
+                s.rating = s.rating.map(|r| rating_in_review_scale(r, review_scale));
+            });
+            let original = all_seen.remove(0);
+            items.push(DuplicateSeenHistory {
+                metadata_id: meta.id,
+                metadata_title: meta.title,
+                original,
+                duplicates: all_seen,
+            });
+        }
+        Ok(items)
     }
 
-    async fn collection_contents(
+    async fn resolve_duplicate_seen_history(
         &self,
-        user_id: Option<i32>,
-        input: CollectionContentsInput,
-    ) -> Result<CollectionContents> {
-        let page = input.page.unwrap_or(1);
-        let collection = Collection::find_by_id(input.collection_id)
-            .one(&self.db)
-            .await
-            .unwrap()
-            .unwrap();
-        if collection.visibility != Visibility::Public {
-            match user_id {
-                None => {
-                    return Err(Error::new(
-                        "Need to be logged in to view a private collection".to_owned(),
-                    ));
+        user_id: i32,
+        input: ResolveDuplicateSeenHistoryInput,
+    ) -> Result<usize> {
+        let duplicates = Seen::find()
+            .filter(seen::Column::UserId.eq(user_id))
+            .filter(seen::Column::Id.is_in(input.seen_ids))
+            .filter(seen::Column::DuplicateOf.is_not_null())
+            .all(&self.db)
+            .await?;
+        let resolved = duplicates.len();
+        for duplicate in duplicates {
+            let original = match input.action {
+                DuplicateSeenHistoryAction::Merge => {
+                    Seen::find_by_id(duplicate.duplicate_of.unwrap())
+                        .one(&self.db)
+                        .await?
                 }
-                Some(u) => {
-                    if u != collection.user_id {
-                        return Err(Error::new("This collection is not public".to_owned()));
-                    }
+                _ => None,
+            };
+            match (input.action, original) {
+                (DuplicateSeenHistoryAction::Dismiss, _)
+                | (DuplicateSeenHistoryAction::Merge, None) => {
+                    Seen::update_many()
+                        .col_expr(seen::Column::DuplicateOf, Expr::value(Option::<i32>::None))
+                        .col_expr(
+                            seen::Column::DuplicateDismissed,
+                            Expr::value(input.action == DuplicateSeenHistoryAction::Dismiss),
+                        )
+                        .filter(seen::Column::Id.eq(duplicate.id))
+                        .exec(&self.db)
+                        .await?;
+                }
+                (DuplicateSeenHistoryAction::Delete, _) => {
+                    duplicate.delete(&self.db).await?;
+                }
+                (DuplicateSeenHistoryAction::Merge, Some(original)) => {
+                    SeenAttachment::update_many()
+                        .col_expr(seen_attachment::Column::SeenId, Expr::value(original.id))
+                        .filter(seen_attachment::Column::SeenId.eq(duplicate.id))
+                        .exec(&self.db)
+                        .await?;
+                    SeenCompanion::update_many()
+                        .col_expr(seen_companion::Column::SeenId, Expr::value(original.id))
+                        .filter(seen_companion::Column::SeenId.eq(duplicate.id))
+                        .exec(&self.db)
+                        .await?;
+                    let started_on = original.started_on.or(duplicate.started_on);
+                    let rating = original.rating.or(duplicate.rating);
+                    let context = original.context.clone().or(duplicate.context.clone());
+                    let mut original: seen::ActiveModel = original.into();
+                    original.started_on = ActiveValue::Set(started_on);
+                    original.rating = ActiveValue::Set(rating);
+                    original.context = ActiveValue::Set(context);
+                    original.update(&self.db).await?;
+                    duplicate.delete(&self.db).await?;
                 }
             }
         }
+        if resolved > 0 && input.action != DuplicateSeenHistoryAction::Dismiss {
+            self.deploy_recalculate_summary_job(user_id).await?;
+        }
+        Ok(resolved)
+    }
 
-        let all_metadata_items = Metadata::find()
-            .join(
-                JoinType::Join,
-                user_to_metadata::Relation::Metadata.def().rev(),
-            )
-            .join(
-                JoinType::Join,
-                metadata_to_collection::Relation::Metadata.def().rev(),
+    async fn announcements(&self) -> Result<Vec<announcement::Model>> {
+        let announcements = Announcement::find()
+            .filter(
+                Condition::any()
+                    .add(announcement::Column::ExpiresOn.is_null())
+                    .add(announcement::Column::ExpiresOn.gt(Utc::now())),
             )
-            .filter(metadata_to_collection::Column::CollectionId.eq(collection.id))
-            .order_by_desc(user_to_metadata::Column::LastUpdatedOn)
-            .paginate(
-                &self.db,
-                input
-                    .take
-                    .unwrap_or_else(|| self.config.frontend.page_size.try_into().unwrap()),
-            );
+            .order_by_desc(announcement::Column::CreatedOn)
+            .all(&self.db)
+            .await?;
+        Ok(announcements)
+    }
 
+    async fn audit_log(&self, page: Option<i32>) -> Result<SearchResults<audit_log::Model>> {
+        let page: u64 = page.unwrap_or(1).max(1).try_into().unwrap();
+        let paginator = AuditLog::find()
+            .order_by_desc(audit_log::Column::CreatedOn)
+            .order_by_desc(audit_log::Column::Id)
+            .paginate(&self.db, self.config.frontend.page_size.try_into().unwrap());
         let ItemsAndPagesNumber {
             number_of_items,
             number_of_pages,
-        } = all_metadata_items.num_items_and_pages().await?;
-        let mut meta_data = vec![];
-        for m in all_metadata_items.fetch_page(page - 1).await? {
-            meta_data.push((
-                MediaSearchItem {
-                    identifier: m.id.to_string(),
-                    image: self.metadata_assets(&m).await?.images.first().cloned(),
-                    title: m.title,
-                    publish_year: m.publish_year,
-                },
-                m.lot,
-            ));
-        }
-        let items = meta_data
-            .into_iter()
-            .map(|a| MediaSearchItemWithLot {
-                details: a.0,
-                lot: a.1,
-            })
-            .collect();
-        let user = collection.find_related(User).one(&self.db).await?.unwrap();
-        Ok(CollectionContents {
-            details: collection,
-            results: SearchResults {
-                details: SearchDetails {
-                    total: number_of_items.try_into().unwrap(),
-                    next_page: if page < number_of_pages {
-                        Some((page + 1).try_into().unwrap())
-                    } else {
-                        None
-                    },
+        } = paginator.num_items_and_pages().await?;
+        let items = paginator.fetch_page(page - 1).await?;
+        Ok(SearchResults {
+            details: SearchDetails {
+                total: number_of_items.try_into().unwrap(),
+                next_page: if page < number_of_pages {
+                    Some((page + 1).try_into().unwrap())
+                } else {
+                    None
                 },
-                items,
             },
-            user,
+            items,
         })
     }
 
-    pub async fn post_review(&self, user_id: i32, input: PostReviewInput) -> Result<IdObject> {
-        if self.config.users.reviews_disabled {
-            return Err(Error::new("Posting reviews on this instance is disabled"));
-        }
-        let review_id = match input.review_id {
-            Some(i) => ActiveValue::Set(i),
-            None => ActiveValue::NotSet,
-        };
-        let extra_information = if let (Some(season), Some(episode)) =
-            (input.show_season_number, input.show_episode_number)
-        {
-            Some(SeenOrReviewOrCalendarEventExtraInformation::Show(
-                SeenShowExtraInformation { season, episode },
-            ))
-        } else {
-            input.podcast_episode_number.map(|episode| {
-                SeenOrReviewOrCalendarEventExtraInformation::Podcast(SeenPodcastExtraInformation {
-                    episode,
-                })
-            })
-        };
-        if input.rating.is_none() && input.text.is_none() {
-            return Err(Error::new("At-least one of rating or review is required."));
-        }
-
-        let preferences = user_by_id(&self.db, user_id).await?.preferences;
-        let mut review_obj = review::ActiveModel {
-            id: review_id,
-            rating: ActiveValue::Set(input.rating.map(
-                |r| match preferences.general.review_scale {
-                    UserReviewScale::OutOfFive => r * dec!(20),
-                    UserReviewScale::OutOfHundred => r,
-                },
-            )),
-            text: ActiveValue::Set(input.text),
-            user_id: ActiveValue::Set(user_id.to_owned()),
-            metadata_id: ActiveValue::Set(input.metadata_id),
-            creator_id: ActiveValue::Set(input.creator_id),
-            extra_information: ActiveValue::Set(extra_information),
-            comments: ActiveValue::Set(ReviewComments(vec![])),
+    /// Save an event in the audit log. A failure is only logged, so that it never
+    /// fails the request that the event is about.
+    async fn record_audit_event(
+        &self,
+        event: AuditLogEvent,
+        user_id: Option<i32>,
+        ip: Option<IpAddr>,
+        details: Option<String>,
+    ) {
+        let entry = audit_log::ActiveModel {
+            event: ActiveValue::Set(event),
+            user_id: ActiveValue::Set(user_id),
+            ip: ActiveValue::Set(ip.map(|ip| ip.to_string())),
+            details: ActiveValue::Set(details),
             ..Default::default()
         };
-        if let Some(s) = input.spoiler {
-            review_obj.spoiler = ActiveValue::Set(s);
+        if let Err(e) = entry.insert(&self.db).await {
+            tracing::error!("Error while saving an audit log entry: {:?}", e);
         }
-        if let Some(v) = input.visibility {
-            review_obj.visibility = ActiveValue::Set(v);
+    }
+
+    async fn create_announcement(&self, input: CreateAnnouncementInput) -> Result<IdObject> {
+        let message = input.message.trim().to_owned();
+        if message.is_empty() {
+            return Err(Error::new(
+                "The message of an announcement can not be empty",
+            ));
         }
-        if let Some(d) = input.date {
-            review_obj.posted_on = ActiveValue::Set(d);
+        let announcement = announcement::ActiveModel {
+            message: ActiveValue::Set(message),
+            severity: ActiveValue::Set(input.severity),
+            expires_on: ActiveValue::Set(input.expires_on),
+            ..Default::default()
         }
-        let insert = review_obj.save(&self.db).await.unwrap();
+        .insert(&self.db)
+        .await?;
         Ok(IdObject {
-            id: insert.id.unwrap(),
+            id: announcement.id,
         })
     }
 
-    pub async fn delete_review(&self, user_id: i32, review_id: i32) -> Result<bool> {
-        let review = Review::find()
-            .filter(review::Column::Id.eq(review_id))
-            .one(&self.db)
-            .await
-            .unwrap();
-        match review {
-            Some(r) => {
-                if r.user_id == user_id {
-                    r.delete(&self.db).await?;
-                    Ok(true)
-                } else {
-                    Err(Error::new("This review does not belong to you".to_owned()))
-                }
-            }
-            None => Ok(false),
+    async fn delete_announcement(&self, announcement_id: i32) -> Result<bool> {
+        let result = Announcement::delete_by_id(announcement_id)
+            .exec(&self.db)
+            .await?;
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn metadata_corrections(
+        &self,
+        user_id: i32,
+        state: Option<MetadataCorrectionState>,
+    ) -> Result<Vec<metadata_correction::Model>> {
+        let user = user_by_id(&self.db, user_id).await?;
+        let mut query = MetadataCorrection::find();
+        if user.lot != UserLot::Admin {
+            query = query.filter(metadata_correction::Column::UserId.eq(user_id));
         }
+        if let Some(state) = state {
+            query = query.filter(metadata_correction::Column::State.eq(state));
+        }
+        let corrections = query
+            .order_by_desc(metadata_correction::Column::CreatedOn)
+            .all(&self.db)
+            .await?;
+        Ok(corrections)
     }
 
-    pub async fn create_or_update_collection(
+    async fn create_metadata_correction(
         &self,
         user_id: i32,
-        input: CreateOrUpdateCollectionInput,
+        input: CreateMetadataCorrectionInput,
     ) -> Result<IdObject> {
-        let meta = Collection::find()
-            .filter(collection::Column::Name.eq(input.name.clone()))
-            .filter(collection::Column::UserId.eq(user_id.to_owned()))
+        let meta = Metadata::find_by_id(input.metadata_id)
             .one(&self.db)
-            .await
-            .unwrap();
-        match meta {
-            Some(m) if input.update_id.is_none() => Ok(IdObject { id: m.id }),
-            _ => {
-                let col = collection::ActiveModel {
-                    id: match input.update_id {
-                        Some(i) => ActiveValue::Unchanged(i),
-                        None => ActiveValue::NotSet,
-                    },
-                    name: ActiveValue::Set(input.name),
-                    user_id: ActiveValue::Set(user_id.to_owned()),
-                    description: ActiveValue::Set(input.description),
-                    visibility: match input.visibility {
-                        None => ActiveValue::NotSet,
-                        Some(v) => ActiveValue::Set(v),
-                    },
-                    ..Default::default()
-                };
-                let inserted = col.save(&self.db).await.map_err(|_| {
-                    Error::new("There was an error creating the collection".to_owned())
-                })?;
-                Ok(IdObject {
-                    id: inserted.id.unwrap(),
-                })
+            .await?
+            .ok_or_else(|| Error::new("There is no media with this id"))?;
+        let value = input
+            .value
+            .map(|v| v.trim().to_owned())
+            .filter(|v| !v.is_empty());
+        let is_valid = match (input.field, &value) {
+            (
+                MetadataCorrectionField::Title
+                | MetadataCorrectionField::Image
+                | MetadataCorrectionField::Episode,
+                None,
+            ) => false,
+            (_, None) => true,
+            (MetadataCorrectionField::Episode, Some(v)) => {
+                meta.lot == MetadataLot::Show
+                    && serde_json::from_str::<EpisodeCorrection>(v).is_ok()
             }
+            (MetadataCorrectionField::Image, Some(v)) => surf::Url::parse(v).is_ok(),
+            (MetadataCorrectionField::PublishYear, Some(v)) => v.parse::<i32>().is_ok(),
+            (MetadataCorrectionField::PublishDate, Some(v)) => {
+                NaiveDate::parse_from_str(v, "%Y-%m-%d").is_ok()
+            }
+            (MetadataCorrectionField::Title | MetadataCorrectionField::Description, Some(_)) => {
+                true
+            }
+        };
+        if !is_valid {
+            return Err(Error::new(format!(
+                "This is not a valid value for the {:?} of a media",
+                input.field
+            )));
+        }
+        let correction = metadata_correction::ActiveModel {
+            user_id: ActiveValue::Set(user_id),
+            metadata_id: ActiveValue::Set(meta.id),
+            field: ActiveValue::Set(input.field),
+            value: ActiveValue::Set(value),
+            comment: ActiveValue::Set(input.comment.filter(|c| !c.trim().is_empty())),
+            state: ActiveValue::Set(MetadataCorrectionState::Pending),
+            ..Default::default()
         }
+        .insert(&self.db)
+        .await?;
+        Ok(IdObject { id: correction.id })
     }
 
-    pub async fn delete_collection(&self, user_id: i32, name: &str) -> Result<bool> {
-        if DefaultCollection::iter().any(|col_name| col_name.to_string() == name) {
-            return Err(Error::new("Can not delete a default collection".to_owned()));
-        }
-        let collection = Collection::find()
-            .filter(collection::Column::Name.eq(name))
-            .filter(collection::Column::UserId.eq(user_id.to_owned()))
+    async fn review_metadata_correction(&self, correction_id: i32, approve: bool) -> Result<bool> {
+        let correction = MetadataCorrection::find_by_id(correction_id)
+            .filter(metadata_correction::Column::State.eq(MetadataCorrectionState::Pending))
             .one(&self.db)
-            .await?;
-        let resp = if let Some(c) = collection {
-            Collection::delete_by_id(c.id).exec(&self.db).await.is_ok()
-        } else {
-            false
+            .await?
+            .ok_or_else(|| Error::new("There is no pending correction with this id"))?;
+        let user_id = correction.user_id;
+        let metadata_id = correction.metadata_id;
+        let field = correction.field;
+        let state = match approve {
+            true => MetadataCorrectionState::Approved,
+            false => MetadataCorrectionState::Rejected,
         };
-        Ok(resp)
+        let mut correction: metadata_correction::ActiveModel = correction.into();
+        correction.state = ActiveValue::Set(state);
+        correction.reviewed_on = ActiveValue::Set(Some(Utc::now()));
+        correction.update(&self.db).await?;
+        if approve {
+            self.apply_metadata_corrections(metadata_id).await?;
+        }
+        let meta = Metadata::find_by_id(metadata_id)
+            .one(&self.db)
+            .await?
+            .unwrap();
+        self.send_notifications_to_user_platforms(
+            user_id,
+            &format!(
+                "Your correction to the {:?} of {} was {}.",
+                field,
+                meta.title,
+                match approve {
+                    true => "approved",
+                    false => "rejected",
+                }
+            ),
+        )
+        .await
+        .ok();
+        Ok(true)
     }
 
-    pub async fn remove_media_from_collection(
-        &self,
-        user_id: i32,
-        metadata_id: &i32,
-        collection_name: &str,
-    ) -> Result<IdObject> {
-        let collect = Collection::find()
-            .filter(collection::Column::Name.eq(collection_name.to_owned()))
-            .filter(collection::Column::UserId.eq(user_id.to_owned()))
+    async fn delete_metadata_correction(&self, user_id: i32, correction_id: i32) -> Result<bool> {
+        let user = user_by_id(&self.db, user_id).await?;
+        let mut query = MetadataCorrection::find_by_id(correction_id);
+        if user.lot != UserLot::Admin {
+            query = query
+                .filter(metadata_correction::Column::UserId.eq(user_id))
+                .filter(metadata_correction::Column::State.eq(MetadataCorrectionState::Pending));
+        }
+        let correction = query
             .one(&self.db)
-            .await
-            .unwrap()
-            .unwrap();
-        let col = metadata_to_collection::ActiveModel {
-            metadata_id: ActiveValue::Set(metadata_id.to_owned()),
-            collection_id: ActiveValue::Set(collect.id),
-        };
-        let id = col.collection_id.clone().unwrap();
-        col.delete(&self.db).await.ok();
-        Ok(IdObject { id })
+            .await?
+            .ok_or_else(|| Error::new("There is no correction with this id that can be deleted"))?;
+        correction.delete(&self.db).await?;
+        Ok(true)
     }
 
-    pub async fn add_media_to_collection(
-        &self,
-        user_id: i32,
-        input: AddMediaToCollection,
-    ) -> Result<bool> {
-        let collection = Collection::find()
-            .filter(collection::Column::UserId.eq(user_id.to_owned()))
-            .filter(collection::Column::Name.eq(input.collection_name))
+    /// Override the details of a media with the corrections that were approved
+    /// for it, the most recently approved ones taking precedence.
+    async fn apply_metadata_corrections(&self, metadata_id: i32) -> Result<()> {
+        let corrections = MetadataCorrection::find()
+            .filter(metadata_correction::Column::MetadataId.eq(metadata_id))
+            .filter(metadata_correction::Column::State.eq(MetadataCorrectionState::Approved))
+            .order_by_asc(metadata_correction::Column::ReviewedOn)
+            .all(&self.db)
+            .await?;
+        if corrections.is_empty() {
+            return Ok(());
+        }
+        let meta = Metadata::find_by_id(metadata_id)
             .one(&self.db)
-            .await
-            .unwrap()
+            .await?
             .unwrap();
-        let col = metadata_to_collection::ActiveModel {
-            metadata_id: ActiveValue::Set(input.media_id),
-            collection_id: ActiveValue::Set(collection.id),
-        };
-        Ok(col.clone().insert(&self.db).await.is_ok())
+        let mut images = meta.images.clone().unwrap_or_default().0;
+        let mut specifics = meta.specifics.clone();
+        let mut meta: metadata::ActiveModel = meta.into();
+        for correction in corrections {
+            match (correction.field, correction.value) {
+                (MetadataCorrectionField::Title, Some(v)) => meta.title = ActiveValue::Set(v),
+                (MetadataCorrectionField::Description, v) => meta.description = ActiveValue::Set(v),
+                (MetadataCorrectionField::Image, Some(v)) => {
+                    let image = MetadataImage {
+                        url: StoredUrl::Url(v),
+                        lot: MetadataImageLot::Poster,
+                    };
+                    images.retain(|i| i != &image);
+                    images.insert(0, image);
+                }
+                (MetadataCorrectionField::PublishYear, v) => {
+                    meta.publish_year = ActiveValue::Set(v.and_then(|v| v.parse().ok()))
+                }
+                (MetadataCorrectionField::PublishDate, v) => {
+                    meta.publish_date = ActiveValue::Set(
+                        v.and_then(|v| NaiveDate::parse_from_str(&v, "%Y-%m-%d").ok()),
+                    )
+                }
+                (MetadataCorrectionField::Episode, Some(v)) => {
+                    if let (Ok(episode), MediaSpecifics::Show(show)) = (
+                        serde_json::from_str::<EpisodeCorrection>(&v),
+                        &mut specifics,
+                    ) {
+                        episode.apply(show);
+                    }
+                }
+                (
+                    MetadataCorrectionField::Title
+                    | MetadataCorrectionField::Image
+                    | MetadataCorrectionField::Episode,
+                    None,
+                ) => {}
+            }
+        }
+        meta.images = ActiveValue::Set(Some(MetadataImages(images)));
+        meta.specifics = ActiveValue::Set(specifics);
+        meta.update(&self.db).await?;
+        Ok(())
     }
 
     pub async fn delete_seen_item(&self, seen_id: i32, user_id: i32) -> Result<IdObject> {
@@ -3749,6 +6993,7 @@ impl MiscellaneousService {
                 ));
             }
             si.delete(&self.db).await.ok();
+            self.deploy_recalculate_summary_job(user_id).await?;
             if progress < 100 {
                 self.remove_media_from_collection(
                     user_id,
@@ -3769,29 +7014,93 @@ impl MiscellaneousService {
         metadata_id: i32,
     ) -> Result<Vec<(String, MediaStateChanged)>> {
         tracing::trace!("Updating metadata for {:?}", metadata_id);
-        let maybe_details = self
-            .details_from_provider_for_existing_media(metadata_id)
-            .await;
+        let maybe_details =
+            http_cache::refreshing(self.details_from_provider_for_existing_media(metadata_id))
+                .await;
         let notifications = match maybe_details {
-            Ok(details) => {
-                self.update_media(
-                    metadata_id,
-                    details.title,
-                    details.is_nsfw,
-                    details.description,
-                    details.provider_rating,
-                    details.images,
-                    details.videos,
-                    details.specifics,
-                    details.creators,
-                    details.genres,
-                    details.production_status,
-                    details.publish_year,
-                    details.publish_date,
-                    details.suggestions,
-                    details.groups,
-                )
-                .await?
+            Ok((details, language)) => {
+                let meta = Metadata::find_by_id(metadata_id)
+                    .one(&self.db)
+                    .await?
+                    .unwrap();
+                let localized_languages = meta
+                    .localizations
+                    .clone()
+                    .map(|l| l.0.into_keys().collect_vec())
+                    .unwrap_or_default();
+                // DEV: The provider redirected us to another item, eg: when
+                // Openlibrary merges two works.
+                if details.identifier != meta.identifier {
+                    if let Some(existing) = Metadata::find()
+                        .filter(metadata::Column::Lot.eq(meta.lot))
+                        .filter(metadata::Column::Source.eq(meta.source))
+                        .filter(metadata::Column::Identifier.eq(&details.identifier))
+                        .one(&self.db)
+                        .await?
+                    {
+                        tracing::debug!(
+                            "Metadata = {:?} was merged into {:?} by the provider",
+                            metadata_id,
+                            existing.id
+                        );
+                        self.merge_metadata(metadata_id, existing.id).await?;
+                        return Ok(vec![]);
+                    }
+                    let mut meta: metadata::ActiveModel = meta.into();
+                    meta.identifier = ActiveValue::Set(details.identifier.clone());
+                    meta.update(&self.db).await?;
+                }
+                let notifications = self
+                    .update_media(
+                        metadata_id,
+                        details.title,
+                        details.alternative_titles,
+                        details.is_nsfw,
+                        details.description,
+                        details.provider_rating,
+                        details.images,
+                        details.videos,
+                        details.specifics,
+                        details.creators,
+                        details.genres,
+                        details.production_status,
+                        details.publish_year,
+                        details.publish_date,
+                        details.suggestions,
+                        details.groups,
+                        language,
+                    )
+                    .await?;
+                self.deploy_enrich_metadata_awards_job(metadata_id).await?;
+                for language in localized_languages {
+                    self.perform_application_job
+                        .clone()
+                        .push(ApplicationJob::LocalizeMetadata(metadata_id, language))
+                        .await?;
+                }
+                notifications
+            }
+            Err(e) if e.downcast_ref::<MediaDeletedOnProvider>().is_some() => {
+                let meta = Metadata::find_by_id(metadata_id)
+                    .one(&self.db)
+                    .await?
+                    .unwrap();
+                if meta.is_deleted_on_provider {
+                    vec![]
+                } else {
+                    tracing::warn!("Metadata = {:?} was deleted on the provider", metadata_id);
+                    let title = meta.title.clone();
+                    let mut meta: metadata::ActiveModel = meta.into();
+                    meta.is_deleted_on_provider = ActiveValue::Set(true);
+                    meta.update(&self.db).await?;
+                    vec![(
+                        format!(
+                            "{:?} no longer exists on the provider. Merge it into another media item or remove it.",
+                            title
+                        ),
+                        MediaStateChanged::StatusChanged,
+                    )]
+                }
             }
             Err(e) => {
                 tracing::error!("Error while updating metadata = {:?}: {:?}", metadata_id, e);
@@ -3809,6 +7118,7 @@ impl MiscellaneousService {
         let metadatas = Metadata::find()
             .select_only()
             .column(metadata::Column::Id)
+            .filter(metadata::Column::IsDeletedOnProvider.eq(false))
             .order_by_asc(metadata::Column::LastUpdatedOn)
             .into_tuple::<i32>()
             .all(&self.db)
@@ -3838,15 +7148,20 @@ impl MiscellaneousService {
     }
 
     pub async fn calculate_user_summary(&self, user_id: i32) -> Result<IdObject> {
+        // DEV: Removed before calculating so that changes made while doing so
+        // queue a new recalculation
+        self.pending_summary_recalculations
+            .lock()
+            .unwrap()
+            .remove(&user_id);
         let mut ls = UserSummary {
             calculated_on: Utc::now(),
             ..Default::default()
         };
-
-        let num_reviews = Review::find()
-            .filter(review::Column::UserId.eq(user_id.to_owned()))
-            .count(&self.db)
-            .await?;
+        let estimation = user_by_id(&self.db, user_id)
+            .await?
+            .preferences
+            .time_estimation;
 
         let num_measurements = UserMeasurement::find()
             .filter(user_measurement::Column::UserId.eq(user_id.to_owned()))
@@ -3858,10 +7173,68 @@ impl MiscellaneousService {
             .count(&self.db)
             .await?;
 
-        ls.media.reviews_posted = num_reviews;
+        ls.media.favorites = self.favorite_metadata_ids(user_id).await?.len();
         ls.fitness.measurements_recorded = num_measurements;
         ls.fitness.workouts_recorded = num_workouts;
 
+        // DEV: The counts are read from the aggregates instead of the seen items
+        // and reviews, which only need to be scanned for what is not counted
+        let statistics = UserStatistic::find()
+            .filter(user_statistic::Column::UserId.eq(user_id))
+            .all(&self.db)
+            .await?;
+        for statistic in statistics {
+            ls.media.reviews_posted += u64::try_from(statistic.review_count).unwrap_or_default();
+            let seen = statistic.seen_count;
+            match statistic.lot {
+                MetadataLot::AudioBook => ls.media.audio_books.played += seen,
+                MetadataLot::Anime => ls.media.anime.watched += seen,
+                MetadataLot::Manga => ls.media.manga.read += seen,
+                MetadataLot::Book => ls.media.books.read += seen,
+                MetadataLot::Movie => ls.media.movies.watched += seen,
+                MetadataLot::Comic => ls.media.comics.read += seen,
+                _ => {}
+            }
+        }
+
+        #[derive(Debug, FromQueryResult)]
+        struct WorkoutSetTotals {
+            volume: Option<Decimal>,
+            distance: Option<Decimal>,
+        }
+        let totals = WorkoutSet::find()
+            .select_only()
+            .column_as(
+                Expr::expr(Func::sum(Expr::col(workout_set::Column::Volume))),
+                "volume",
+            )
+            .column_as(
+                Expr::expr(Func::sum(Expr::col(workout_set::Column::Distance))),
+                "distance",
+            )
+            .filter(workout_set::Column::UserId.eq(user_id))
+            .into_model::<WorkoutSetTotals>()
+            .one(&self.db)
+            .await?;
+        if let Some(totals) = totals {
+            ls.fitness.total_volume = totals.volume.unwrap_or_default();
+            ls.fitness.total_distance = totals.distance.unwrap_or_default();
+        }
+
+        let mut workouts = Workout::find()
+            .filter(workout::Column::UserId.eq(user_id))
+            .stream(&self.db)
+            .await?;
+        while let Some(workout) = workouts.try_next().await? {
+            ls.fitness.personal_bests_achieved += workout
+                .information
+                .exercises
+                .iter()
+                .flat_map(|e| e.sets.iter())
+                .map(|s| s.personal_bests.len())
+                .sum::<usize>();
+        }
+
         let mut seen_items = Seen::find()
             .filter(seen::Column::UserId.eq(user_id.to_owned()))
             .filter(seen::Column::UserId.eq(user_id.to_owned()))
@@ -3871,6 +7244,9 @@ impl MiscellaneousService {
             .await?;
 
         let mut unique_visual_novels = HashSet::new();
+        let mut unique_articles = HashSet::new();
+        let mut unique_music = HashSet::new();
+        let mut unique_board_games = HashSet::new();
         let mut unique_video_games = HashSet::new();
         let mut unique_shows = HashSet::new();
         let mut unique_show_seasons = HashSet::new();
@@ -3888,32 +7264,32 @@ impl MiscellaneousService {
                 });
             match meta.specifics {
                 MediaSpecifics::AudioBook(item) => {
-                    ls.media.audio_books.played += 1;
                     if let Some(r) = item.runtime {
                         ls.media.audio_books.runtime += r;
                     }
                 }
                 MediaSpecifics::Anime(item) => {
-                    ls.media.anime.watched += 1;
                     if let Some(r) = item.episodes {
                         ls.media.anime.episodes += r;
                     }
                 }
                 MediaSpecifics::Manga(item) => {
-                    ls.media.manga.read += 1;
                     if let Some(r) = item.chapters {
                         ls.media.manga.chapters += r;
                     }
                 }
+                MediaSpecifics::Comic(item) => {
+                    if let Some(r) = item.issues {
+                        ls.media.comics.issues += r;
+                    }
+                }
                 MediaSpecifics::Book(item) => {
-                    ls.media.books.read += 1;
                     if let Some(pg) = item.pages {
                         ls.media.books.pages += pg;
                     }
                 }
 
                 MediaSpecifics::Movie(item) => {
-                    ls.media.movies.watched += 1;
                     if let Some(r) = item.runtime {
                         ls.media.movies.runtime += r;
                     }
@@ -3926,7 +7302,9 @@ impl MiscellaneousService {
                         }
                         SeenOrReviewOrCalendarEventExtraInformation::Show(s) => {
                             if let Some((season, episode)) = item.get_episode(s.season, s.episode) {
-                                if let Some(r) = episode.runtime {
+                                if let Some(r) =
+                                    episode.runtime.or(estimation.default_episode_runtime)
+                                {
                                     ls.media.shows.runtime += r;
                                 }
                                 ls.media.shows.watched_episodes += 1;
@@ -3943,7 +7321,9 @@ impl MiscellaneousService {
                         }
                         SeenOrReviewOrCalendarEventExtraInformation::Podcast(s) => {
                             if let Some(episode) = item.get_episode(s.episode) {
-                                if let Some(r) = episode.runtime {
+                                if let Some(r) =
+                                    episode.runtime.or(estimation.default_episode_runtime)
+                                {
                                     ls.media.podcasts.runtime += r;
                                 }
                                 unique_podcast_episodes.insert((s.episode, episode.id.clone()));
@@ -3960,36 +7340,271 @@ impl MiscellaneousService {
                         ls.media.visual_novels.runtime += r;
                     }
                 }
+                MediaSpecifics::Article(item) => {
+                    unique_articles.insert(seen.metadata_id);
+                    if let Some(r) = item.reading_time {
+                        ls.media.articles.runtime += r;
+                    }
+                }
+                MediaSpecifics::Music(item) => {
+                    unique_music.insert(seen.metadata_id);
+                    if let Some(r) = item.duration {
+                        ls.media.music.runtime += r;
+                    }
+                }
+                MediaSpecifics::BoardGame(item) => {
+                    unique_board_games.insert(seen.metadata_id);
+                    if let Some(r) = item.playtime {
+                        ls.media.board_games.runtime += r;
+                    }
+                }
                 MediaSpecifics::Unknown => {}
             }
         }
 
+        if estimation.podcast_playback_speed > Decimal::ZERO {
+            ls.media.podcasts.runtime = (Decimal::from(ls.media.podcasts.runtime)
+                / estimation.podcast_playback_speed)
+                .round()
+                .to_i32()
+                .unwrap_or_default();
+        }
+        if let Some(pages_per_hour) = estimation.pages_per_hour.filter(|p| *p > 0) {
+            ls.media.books.runtime = ls.media.books.pages * 60 / pages_per_hour;
+        }
+
         ls.media.podcasts.played += i32::try_from(unique_podcasts.len()).unwrap();
         ls.media.podcasts.played_episodes += i32::try_from(unique_podcast_episodes.len()).unwrap();
 
-        ls.media.shows.watched = i32::try_from(unique_shows.len()).unwrap();
-        ls.media.shows.watched_seasons += i32::try_from(unique_show_seasons.len()).unwrap();
-        ls.media.creators_interacted_with += unique_creators.len();
+        ls.media.shows.watched = i32::try_from(unique_shows.len()).unwrap();
+        ls.media.shows.watched_seasons += i32::try_from(unique_show_seasons.len()).unwrap();
+        ls.media.creators_interacted_with += unique_creators.len();
+
+        ls.media.video_games.played = i32::try_from(unique_video_games.len()).unwrap();
+
+        ls.media.visual_novels.played = i32::try_from(unique_visual_novels.len()).unwrap();
+
+        ls.media.articles.read = i32::try_from(unique_articles.len()).unwrap();
+
+        ls.media.music.listened = i32::try_from(unique_music.len()).unwrap();
+
+        ls.media.board_games.played = i32::try_from(unique_board_games.len()).unwrap();
+
+        let user_model = user::ActiveModel {
+            id: ActiveValue::Unchanged(user_id),
+            summary: ActiveValue::Set(Some(ls)),
+            ..Default::default()
+        };
+        let obj = user_model.update(&self.db).await.unwrap();
+        Ok(IdObject { id: obj.id })
+    }
+
+    fn email_verification_required(&self) -> bool {
+        self.config.users.require_email_verification && self.config.server.smtp.is_enabled()
+    }
+
+    fn password_reset_allowed(&self) -> bool {
+        self.config.users.allow_password_reset
+            && self.config.users.allow_changing_password
+            && self.config.server.smtp.is_enabled()
+    }
+
+    /// The link to a page of the frontend that handles the token sent by email.
+    fn email_token_link(&self, page: &str, token: &str) -> String {
+        format!(
+            "{}/auth/{}?token={}",
+            self.config.frontend.url.trim_end_matches('/'),
+            page,
+            token
+        )
+    }
+
+    async fn send_verification_email(&self, user: &user::Model) -> Result<()> {
+        let email = match &user.email {
+            Some(e) => e,
+            None => return Err(Error::new("User does not have an email")),
+        };
+        let token = jwt::sign_email_token(
+            user.id,
+            &self.config.users.jwt_secret,
+            EmailTokenPurpose::EmailVerification,
+            email,
+            7,
+        )?;
+        let body = format!(
+            "Hello {},\n\nPlease verify your email by opening the link below. It is valid for 7 days.\n\n{}\n\nVerification token: {}\n",
+            user.name,
+            self.email_token_link("verify-email", &token),
+            token
+        );
+        send_email(&self.config.server.smtp, email, "Verify your email", body).await?;
+        Ok(())
+    }
+
+    async fn send_password_reset_email(&self, user: &user::Model) -> Result<()> {
+        let email = match &user.email {
+            Some(e) => e,
+            None => return Err(Error::new("User does not have an email")),
+        };
+        // DEV: The token is tied to the current password hash so that it can
+        // only be used once
+        let token = jwt::sign_email_token(
+            user.id,
+            &self.config.users.jwt_secret,
+            EmailTokenPurpose::PasswordReset,
+            &user.password,
+            1,
+        )?;
+        let body = format!(
+            "Hello {},\n\nA password reset was requested for your account. Open the link below to choose a new password. It is valid for 1 day. If you did not request it, you can ignore this email.\n\n{}\n\nReset token: {}\n",
+            user.name,
+            self.email_token_link("reset-password", &token),
+            token
+        );
+        send_email(&self.config.server.smtp, email, "Reset your password", body).await?;
+        Ok(())
+    }
+
+    /// Find the user that an email token was sent to and verify the token
+    /// against the state returned by `state`.
+    async fn user_from_email_token(
+        &self,
+        token: &str,
+        purpose: EmailTokenPurpose,
+        state: impl Fn(&user::Model) -> String,
+    ) -> Result<user::Model> {
+        let invalid = || Error::new("This link is invalid or has expired");
+        let user_id = jwt::email_token_user_id(token).map_err(|_| invalid())?;
+        let user = User::find_by_id(user_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(invalid)?;
+        jwt::verify_email_token(token, &self.config.users.jwt_secret, purpose, &state(&user))
+            .map_err(|_| invalid())?;
+        Ok(user)
+    }
+
+    async fn verify_user_email(&self, token: &str) -> Result<bool> {
+        let user = self
+            .user_from_email_token(token, EmailTokenPurpose::EmailVerification, |u| {
+                u.email.clone().unwrap_or_default()
+            })
+            .await?;
+        let user = user::ActiveModel {
+            id: ActiveValue::Unchanged(user.id),
+            email_verification_pending: ActiveValue::Set(false),
+            ..Default::default()
+        };
+        user.update(&self.db).await?;
+        Ok(true)
+    }
 
-        ls.media.video_games.played = i32::try_from(unique_video_games.len()).unwrap();
+    async fn request_user_email_verification(&self, username: &str) -> Result<bool> {
+        let user = User::find()
+            .filter(user::Column::Name.eq(username))
+            .filter(user::Column::EmailVerificationPending.eq(true))
+            .one(&self.db)
+            .await?;
+        if let Some(user) = user.filter(|_| self.email_verification_required()) {
+            if let Err(e) = self.send_verification_email(&user).await {
+                tracing::error!("Error while sending the verification email: {:?}", e);
+            }
+        }
+        Ok(true)
+    }
 
-        ls.media.visual_novels.played = i32::try_from(unique_visual_novels.len()).unwrap();
+    async fn request_user_password_reset(&self, username: &str) -> Result<bool> {
+        if !self.password_reset_allowed() {
+            return Err(Error::new(
+                "Resetting passwords is not allowed on this instance",
+            ));
+        }
+        let user = User::find()
+            .filter(user::Column::Name.eq(username))
+            .one(&self.db)
+            .await?;
+        if let Some(user) = user {
+            if let Err(e) = self.send_password_reset_email(&user).await {
+                tracing::error!("Error while sending the password reset email: {:?}", e);
+            }
+        }
+        Ok(true)
+    }
 
-        let user_model = user::ActiveModel {
-            id: ActiveValue::Unchanged(user_id),
-            summary: ActiveValue::Set(Some(ls)),
+    async fn reset_user_password(&self, input: ResetUserPasswordInput) -> Result<bool> {
+        if !self.password_reset_allowed() {
+            return Err(Error::new(
+                "Resetting passwords is not allowed on this instance",
+            ));
+        }
+        let user = self
+            .user_from_email_token(&input.token, EmailTokenPurpose::PasswordReset, |u| {
+                u.password.clone()
+            })
+            .await?;
+        // DEV: The link was sent to their email, so it is verified as well
+        let user = user::ActiveModel {
+            id: ActiveValue::Unchanged(user.id),
+            password: ActiveValue::Set(password::hash(
+                &self.config.users.password_hashing,
+                &input.password,
+            )?),
+            email_verification_pending: ActiveValue::Set(false),
             ..Default::default()
         };
-        let obj = user_model.update(&self.db).await.unwrap();
-        Ok(IdObject { id: obj.id })
+        user.update(&self.db).await?;
+        Ok(true)
     }
 
-    async fn register_user(&self, username: &str, password: &str) -> Result<RegisterResult> {
+    async fn register_user(
+        &self,
+        username: &str,
+        password: &str,
+        email: Option<String>,
+    ) -> Result<RegisterResult> {
         if !self.config.users.allow_registration {
             return Ok(RegisterResult::Error(RegisterError {
                 error: RegisterErrorVariant::Disabled,
             }));
         }
+        self.create_user(username, password, email).await
+    }
+
+    async fn setup_admin_user(
+        &self,
+        username: &str,
+        password: &str,
+        email: Option<String>,
+        setup_token: &str,
+    ) -> Result<RegisterResult> {
+        if !constant_time_eq(setup_token.as_bytes(), self.setup_token.as_bytes()) {
+            return Err(Error::new("The setup token is not correct"));
+        }
+        let _guard = self.user_creation_lock.lock().await;
+        if User::find().count(&self.db).await? != 0 {
+            return Err(Error::new("The instance has already been set up"));
+        }
+        self.insert_user(username, password, email).await
+    }
+
+    async fn create_user(
+        &self,
+        username: &str,
+        password: &str,
+        email: Option<String>,
+    ) -> Result<RegisterResult> {
+        let _guard = self.user_creation_lock.lock().await;
+        self.insert_user(username, password, email).await
+    }
+
+    /// The first user that is created is made an admin. Must be called while
+    /// holding the user creation lock.
+    async fn insert_user(
+        &self,
+        username: &str,
+        password: &str,
+        email: Option<String>,
+    ) -> Result<RegisterResult> {
         if User::find()
             .filter(user::Column::Name.eq(username))
             .count(&self.db)
@@ -4006,11 +7621,25 @@ impl MiscellaneousService {
         } else {
             UserLot::Normal
         };
+        let email = email.map(|e| e.trim().to_owned()).filter(|e| !e.is_empty());
+        // DEV: The first user can not verify their email if the instance is
+        // misconfigured, so they are never locked out
+        let verification_pending = self.email_verification_required() && lot != UserLot::Admin;
+        if verification_pending && email.is_none() {
+            return Ok(RegisterResult::Error(RegisterError {
+                error: RegisterErrorVariant::EmailRequired,
+            }));
+        }
         let user = user::ActiveModel {
             name: ActiveValue::Set(username.to_owned()),
-            password: ActiveValue::Set(password.to_owned()),
+            password: ActiveValue::Set(password::hash(
+                &self.config.users.password_hashing,
+                password,
+            )?),
+            email: ActiveValue::Set(email),
+            email_verification_pending: ActiveValue::Set(verification_pending),
             lot: ActiveValue::Set(lot),
-            preferences: ActiveValue::Set(UserPreferences::default()),
+            preferences: ActiveValue::Set(self.user_preferences_template().await?),
             sink_integrations: ActiveValue::Set(UserSinkIntegrations(vec![])),
             notifications: ActiveValue::Set(UserNotifications(vec![])),
             ..Default::default()
@@ -4020,6 +7649,11 @@ impl MiscellaneousService {
             .clone()
             .push(ApplicationJob::UserCreated(user.id))
             .await?;
+        if verification_pending {
+            if let Err(e) = self.send_verification_email(&user).await {
+                tracing::error!("Error while sending the verification email: {:?}", e);
+            }
+        }
         Ok(RegisterResult::Ok(IdObject { id: user.id }))
     }
 
@@ -4029,26 +7663,70 @@ impl MiscellaneousService {
         password: &str,
         gql_ctx: &Context<'_>,
     ) -> Result<LoginResult> {
+        let throttling = &self.config.users.login_throttling;
+        let ip = gql_ctx.data_unchecked::<AuthContext>().ip;
+        let attempt_limits = [(
+            LoginAttemptsCache::Username(username.to_owned()),
+            throttling.max_attempts_per_user,
+        )]
+        .into_iter()
+        .chain(ip.map(|ip| (LoginAttemptsCache::Ip(ip), throttling.max_attempts_per_ip)))
+        .filter(|(_, max)| *max > 0)
+        .collect_vec();
+        for (key, max) in attempt_limits.iter() {
+            if self.failed_login_attempts(key).await >= *max {
+                tracing::warn!(
+                    "Refused login for {:?} from {:?} since {:?} is locked out",
+                    username,
+                    ip,
+                    key
+                );
+                return Ok(LoginResult::Error(LoginError {
+                    error: LoginErrorVariant::TooManyAttempts,
+                }));
+            }
+        }
         let user = User::find()
             .filter(user::Column::Name.eq(username))
             .one(&self.db)
             .await
             .unwrap();
         if user.is_none() {
+            self.record_failed_login(&attempt_limits, username, None, ip)
+                .await;
             return Ok(LoginResult::Error(LoginError {
                 error: LoginErrorVariant::UsernameDoesNotExist,
             }));
         };
         let user = user.unwrap();
-        let parsed_hash = PasswordHash::new(&user.password).unwrap();
-        if get_password_hasher()
-            .verify_password(password.as_bytes(), &parsed_hash)
-            .is_err()
-        {
+        let hashing = &self.config.users.password_hashing;
+        if !password::verify(hashing, password, &user.password)? {
+            self.record_failed_login(&attempt_limits, username, Some(user.id), ip)
+                .await;
             return Ok(LoginResult::Error(LoginError {
                 error: LoginErrorVariant::CredentialsMismatch,
             }));
         }
+        self.login_attempts_cache
+            .remove(&LoginAttemptsCache::Username(username.to_owned()))
+            .await;
+        // DEV: The password is only known now, so this is the only chance to
+        // upgrade a hash that was created with older settings
+        if password::needs_rehash(hashing, &user.password) {
+            let rehashed = user::ActiveModel {
+                id: ActiveValue::Unchanged(user.id),
+                password: ActiveValue::Set(password::hash(hashing, password)?),
+                ..Default::default()
+            };
+            if let Err(e) = rehashed.update(&self.db).await {
+                tracing::error!("Error while re-hashing the password: {:?}", e);
+            }
+        }
+        if user.email_verification_pending && self.email_verification_required() {
+            return Ok(LoginResult::Error(LoginError {
+                error: LoginErrorVariant::EmailNotVerified,
+            }));
+        }
         let jwt_key = jwt::sign(
             user.id,
             &self.config.users.jwt_secret,
@@ -4065,6 +7743,52 @@ impl MiscellaneousService {
         Ok(LoginResult::Ok(LoginResponse { api_key: jwt_key }))
     }
 
+    async fn failed_login_attempts(&self, key: &LoginAttemptsCache) -> u32 {
+        self.login_attempts_cache
+            .get(key)
+            .await
+            .map_or(0, |attempts| *attempts)
+    }
+
+    /// Count a failed login against the username and the IP address that it came
+    /// from. Each of them is locked out once it reaches its limit, until no
+    /// failed login was made for the lockout duration. The failed login and the
+    /// lockouts are saved in the audit log.
+    async fn record_failed_login(
+        &self,
+        attempt_limits: &[(LoginAttemptsCache, u32)],
+        username: &str,
+        user_id: Option<i32>,
+        ip: Option<IpAddr>,
+    ) {
+        tracing::warn!("Failed login for {:?} from {:?}", username, ip);
+        self.record_audit_event(
+            AuditLogEvent::FailedLogin,
+            user_id,
+            ip,
+            Some(username.to_owned()),
+        )
+        .await;
+        let lockout = ChronoDuration::minutes(self.config.users.login_throttling.lockout_minutes)
+            .to_std()
+            .unwrap_or_default();
+        for (key, max) in attempt_limits {
+            let attempts = self.failed_login_attempts(key).await + 1;
+            if attempts == *max {
+                tracing::warn!("Locked out {:?} after {} failed logins", key, attempts);
+                let (user_id, details) = match key {
+                    LoginAttemptsCache::Username(u) => (user_id, format!("Username {}", u)),
+                    LoginAttemptsCache::Ip(i) => (None, format!("IP address {}", i)),
+                };
+                self.record_audit_event(AuditLogEvent::Lockout, user_id, ip, Some(details))
+                    .await;
+            }
+            self.login_attempts_cache
+                .insert(key.clone(), attempts, lockout)
+                .await;
+        }
+    }
+
     async fn logout_user(&self, gql_ctx: &Context<'_>) -> Result<bool> {
         create_cookie(
             gql_ctx,
@@ -4094,26 +7818,37 @@ impl MiscellaneousService {
     }
 
     async fn update_user(&self, user_id: i32, input: UpdateUserInput) -> Result<IdObject> {
-        let mut user_obj: user::ActiveModel = User::find_by_id(user_id.to_owned())
+        let user = User::find_by_id(user_id.to_owned())
             .one(&self.db)
             .await
             .unwrap()
-            .unwrap()
-            .into();
+            .unwrap();
+        let mut user_obj: user::ActiveModel = user.clone().into();
         if let Some(n) = input.username {
             if self.config.users.allow_changing_username {
                 user_obj.name = ActiveValue::Set(n);
             }
         }
+        let mut email_changed = false;
         if let Some(e) = input.email {
+            email_changed = user.email.as_ref() != Some(&e);
+            if email_changed && self.email_verification_required() && user.lot != UserLot::Admin {
+                user_obj.email_verification_pending = ActiveValue::Set(true);
+            }
             user_obj.email = ActiveValue::Set(Some(e));
         }
         if let Some(p) = input.password {
             if self.config.users.allow_changing_password {
-                user_obj.password = ActiveValue::Set(p);
+                user_obj.password =
+                    ActiveValue::Set(password::hash(&self.config.users.password_hashing, &p)?);
             }
         }
         let user_obj = user_obj.update(&self.db).await.unwrap();
+        if email_changed && user_obj.email_verification_pending {
+            if let Err(e) = self.send_verification_email(&user_obj).await {
+                tracing::error!("Error while sending the verification email: {:?}", e);
+            }
+        }
         Ok(IdObject { id: user_obj.id })
     }
 
@@ -4126,6 +7861,7 @@ impl MiscellaneousService {
             .await
             .unwrap();
         for user_id in all_users {
+            self.recalculate_user_statistics(user_id).await?;
             self.calculate_user_summary(user_id).await?;
         }
         Ok(())
@@ -4179,7 +7915,32 @@ impl MiscellaneousService {
                 None => return err(),
                 Some(ref mut s) => MediaSpecifics::Manga(s.clone()),
             },
+            MetadataLot::Article => match input.article_specifics {
+                None => return err(),
+                Some(ref mut s) => MediaSpecifics::Article(s.clone()),
+            },
+            MetadataLot::Music => match input.music_specifics {
+                None => return err(),
+                Some(ref mut s) => MediaSpecifics::Music(s.clone()),
+            },
+            MetadataLot::BoardGame => match input.board_game_specifics {
+                None => return err(),
+                Some(ref mut s) => MediaSpecifics::BoardGame(s.clone()),
+            },
+            MetadataLot::Comic => match input.comic_specifics {
+                None => return err(),
+                Some(ref mut s) => MediaSpecifics::Comic(s.clone()),
+            },
         };
+        if input
+            .images
+            .iter()
+            .chain(input.videos.iter())
+            .flatten()
+            .any(|k| !FileStorageService::is_user_upload(k, user_id))
+        {
+            return Err(Error::new("This file was not uploaded by the user"));
+        }
         let identifier = Uuid::new_v4().to_string();
         let images = input
             .images
@@ -4212,6 +7973,7 @@ impl MiscellaneousService {
         let details = MediaDetails {
             identifier,
             title: input.title,
+            alternative_titles: vec![],
             description: input.description,
             lot: input.lot,
             source: MetadataSource::Custom,
@@ -4228,7 +7990,7 @@ impl MiscellaneousService {
             suggestions: vec![],
             groups: vec![],
         };
-        let media = self.commit_media_internal(details).await?;
+        let media = self.commit_media_internal(details, None).await?;
         self.add_media_to_collection(
             user_id,
             AddMediaToCollection {
@@ -4260,9 +8022,90 @@ impl MiscellaneousService {
         if !self.config.users.allow_changing_preferences {
             return Ok(false);
         }
-        let err = || Error::new("Incorrect property value encountered");
         let user_model = user_by_id(&self.db, user_id).await?;
         let mut preferences = user_model.preferences.clone();
+        Self::change_user_preference(&mut preferences, &input)?;
+        let (from, to) = (
+            user_model.preferences.general.nsfw_display,
+            preferences.general.nsfw_display,
+        );
+        let mut user_model: user::ActiveModel = user_model.into();
+        user_model.preferences = ActiveValue::Set(preferences);
+        user_model.update(&self.db).await?;
+        // DEV: The recommendations depend on what the user follows and whether
+        // they see NSFW items
+        self.coming_soon_cache.remove(&user_id).await;
+        if from != to {
+            self.record_audit_event(
+                AuditLogEvent::NsfwDisplayChanged,
+                Some(user_id),
+                None,
+                Some(format!("{:?} to {:?}", from, to)),
+            )
+            .await;
+        }
+        Ok(true)
+    }
+
+    async fn user_preferences_template(&self) -> Result<UserPreferences> {
+        Ok(InstanceSetting::find_by_id(INSTANCE_SETTING_ID)
+            .one(&self.db)
+            .await?
+            .and_then(|s| s.user_preferences_template)
+            .unwrap_or_default())
+    }
+
+    /// Change a property of the preferences template, or remove the template if
+    /// there is no change.
+    async fn update_user_preferences_template(
+        &self,
+        input: Option<UpdateUserPreferenceInput>,
+    ) -> Result<bool> {
+        let template = match input {
+            Some(input) => {
+                let mut template = self.user_preferences_template().await?;
+                Self::change_user_preference(&mut template, &input)?;
+                Some(template)
+            }
+            None => None,
+        };
+        let setting = instance_setting::ActiveModel {
+            id: ActiveValue::Set(INSTANCE_SETTING_ID),
+            user_preferences_template: ActiveValue::Set(template),
+        };
+        InstanceSetting::insert(setting)
+            .on_conflict(
+                OnConflict::column(instance_setting::Column::Id)
+                    .update_column(instance_setting::Column::UserPreferencesTemplate)
+                    .to_owned(),
+            )
+            .exec(&self.db)
+            .await?;
+        Ok(true)
+    }
+
+    /// Change the property of the preferences to the value, eg: `general.review_scale`.
+    fn change_user_preference(
+        preferences: &mut UserPreferences,
+        input: &UpdateUserPreferenceInput,
+    ) -> Result<()> {
+        let err = || Error::new("Incorrect property value encountered");
+        let lot_from_key = |key: &str| match key {
+            "anime" => Some(MetadataLot::Anime),
+            "article" => Some(MetadataLot::Article),
+            "audio_book" => Some(MetadataLot::AudioBook),
+            "board_game" => Some(MetadataLot::BoardGame),
+            "book" => Some(MetadataLot::Book),
+            "comic" => Some(MetadataLot::Comic),
+            "manga" => Some(MetadataLot::Manga),
+            "movie" => Some(MetadataLot::Movie),
+            "music" => Some(MetadataLot::Music),
+            "podcast" => Some(MetadataLot::Podcast),
+            "show" => Some(MetadataLot::Show),
+            "video_game" => Some(MetadataLot::VideoGame),
+            "visual_novel" => Some(MetadataLot::VisualNovel),
+            _ => None,
+        };
         let (left, right) = input.property.split_once('.').ok_or_else(err)?;
         let value_bool = input.value.parse::<bool>();
         let value_usize = input.value.parse::<usize>();
@@ -4387,6 +8230,29 @@ impl MiscellaneousService {
                             _ => return Err(err()),
                         }
                     }
+                    "equipment" => match right {
+                        "plates" => {
+                            preferences.fitness.equipment.plates =
+                                serde_json::from_str(&input.value).map_err(|_| err())?;
+                        }
+                        "bars" => {
+                            preferences.fitness.equipment.bars =
+                                serde_json::from_str(&input.value).map_err(|_| err())?;
+                        }
+                        "dumbbell_increment" => {
+                            preferences.fitness.equipment.dumbbell_increment =
+                                Decimal::from_str(&input.value).map_err(|_| err())?;
+                        }
+                        "available" => {
+                            // DEV: The values are the names of the enum in the
+                            // GraphQL schema
+                            let value = serde_json::from_str(&input.value).map_err(|_| err())?;
+                            let value = Value::from_json(value).map_err(|_| err())?;
+                            preferences.fitness.equipment.available =
+                                InputType::parse(Some(value)).map_err(|_| err())?;
+                        }
+                        _ => return Err(err()),
+                    },
                     "exercises" => match right {
                         "save_history" => {
                             preferences.fitness.exercises.save_history = value_usize.unwrap()
@@ -4441,569 +8307,1698 @@ impl MiscellaneousService {
                             "anime" => {
                                 preferences.features_enabled.media.anime = value_bool.unwrap()
                             }
+                            "article" => {
+                                preferences.features_enabled.media.article = value_bool.unwrap()
+                            }
+                            "music" => {
+                                preferences.features_enabled.media.music = value_bool.unwrap()
+                            }
+                            "board_game" => {
+                                preferences.features_enabled.media.board_game = value_bool.unwrap()
+                            }
+                            "comic" => {
+                                preferences.features_enabled.media.comic = value_bool.unwrap()
+                            }
                             _ => return Err(err()),
                         };
                     }
                     _ => return Err(err()),
                 }
-            }
-            "notifications" => match right {
-                "episode_released" => {
-                    preferences.notifications.episode_released = value_bool.unwrap()
+            }
+            "notifications" => match right {
+                "episode_released" => {
+                    preferences.notifications.episode_released = value_bool.unwrap()
+                }
+                "episode_name_changed" => {
+                    preferences.notifications.episode_name_changed = value_bool.unwrap()
+                }
+                "status_changed" => preferences.notifications.status_changed = value_bool.unwrap(),
+                "release_date_changed" => {
+                    preferences.notifications.release_date_changed = value_bool.unwrap()
+                }
+                "number_of_seasons_changed" => {
+                    preferences.notifications.number_of_seasons_changed = value_bool.unwrap()
+                }
+                "number_of_chapters_or_episodes_changed" => {
+                    preferences
+                        .notifications
+                        .number_of_chapters_or_episodes_changed = value_bool.unwrap()
+                }
+                "personal_best_achieved" => {
+                    preferences.notifications.personal_best_achieved = value_bool.unwrap()
+                }
+                _ => return Err(err()),
+            },
+            "general" => match right {
+                "review_scale" => {
+                    preferences.general.review_scale =
+                        UserReviewScale::from_str(&input.value).unwrap();
+                }
+                "nsfw_display" => {
+                    preferences.general.nsfw_display =
+                        UserNsfwDisplay::from_str(&input.value).map_err(|_| err())?;
+                }
+                _ => return Err(err()),
+            },
+            "time_estimation" => match right {
+                "podcast_playback_speed" => {
+                    let speed = Decimal::from_str(&input.value).map_err(|_| err())?;
+                    if speed <= Decimal::ZERO {
+                        return Err(err());
+                    }
+                    preferences.time_estimation.podcast_playback_speed = speed;
+                }
+                "default_episode_runtime" | "pages_per_hour" => {
+                    // DEV: An empty value means that the time should not be estimated
+                    let value = match input.value.is_empty() {
+                        true => None,
+                        false => Some(input.value.parse::<i32>().map_err(|_| err())?),
+                    };
+                    match right {
+                        "default_episode_runtime" => {
+                            preferences.time_estimation.default_episode_runtime = value
+                        }
+                        _ => preferences.time_estimation.pages_per_hour = value,
+                    };
+                }
+                _ => return Err(err()),
+            },
+            "languages" => {
+                let supported = match right {
+                    "tmdb" => TmdbService::supported_languages(),
+                    "anilist" => AnilistService::supported_languages(),
+                    _ => return Err(err()),
+                };
+                // DEV: An empty value resets it to the default language
+                let language = match input.value.is_empty() {
+                    true => None,
+                    false if supported.contains(&input.value) => Some(input.value.clone()),
+                    false => return Err(err()),
+                };
+                match right {
+                    "tmdb" => preferences.languages.tmdb = language,
+                    _ => preferences.languages.anilist = language,
+                };
+            }
+            "providers" => {
+                let lot = lot_from_key(right).ok_or_else(err)?;
+                // DEV: An empty value resets it to the default provider
+                *preferences.providers.for_lot_mut(lot) = match input.value.is_empty() {
+                    true => None,
+                    false => Some(MetadataSource::from_str(&input.value).map_err(|_| err())?),
+                };
+            }
+            "seen_contexts" => {
+                let lot = lot_from_key(right).ok_or_else(err)?;
+                let contexts: Vec<String> =
+                    serde_json::from_str(&input.value).map_err(|_| err())?;
+                *preferences.seen_contexts.for_lot_mut(lot) = contexts
+                    .into_iter()
+                    .map(|c| c.trim().to_owned())
+                    .filter(|c| !c.is_empty())
+                    .unique()
+                    .collect();
+            }
+            "dashboard" => match right {
+                "sections" => {
+                    // DEV: The values are in the format of `UserDashboardSectionInput`
+                    let value = serde_json::from_str(&input.value).map_err(|_| err())?;
+                    let sections: Vec<UserDashboardSection> =
+                        InputType::parse(Some(Value::from_json(value).unwrap()))
+                            .map_err(|_| err())?;
+                    let all_present = DashboardElementLot::iter()
+                        .all(|lot| sections.iter().filter(|s| s.section == lot).count() == 1);
+                    if !all_present || sections.len() != DashboardElementLot::iter().count() {
+                        return Err(Error::new(
+                            "Every dashboard section must be present exactly once".to_owned(),
+                        ));
+                    }
+                    preferences.dashboard.sections = sections;
+                }
+                "pinned_media" | "pinned_collections" => {
+                    let value_vector: Vec<i32> =
+                        serde_json::from_str(&input.value).map_err(|_| err())?;
+                    match right {
+                        "pinned_media" => preferences.dashboard.pinned_media = value_vector,
+                        _ => preferences.dashboard.pinned_collections = value_vector,
+                    };
+                }
+                _ => return Err(err()),
+            },
+            "following" => {
+                let value_vector: Vec<String> =
+                    serde_json::from_str(&input.value).map_err(|_| err())?;
+                match right {
+                    "genres" => preferences.following.genres = value_vector,
+                    "creators" => preferences.following.creators = value_vector,
+                    _ => return Err(err()),
+                };
+            }
+            _ => return Err(err()),
+        };
+        Ok(())
+    }
+
+    async fn user_integrations(&self, user_id: i32) -> Result<Vec<GraphqlUserIntegration>> {
+        let user = user_by_id(&self.db, user_id).await?;
+        let mut all_integrations = vec![];
+        let yank_integrations = if let Some(i) = user.yank_integrations {
+            i.0
+        } else {
+            vec![]
+        };
+        yank_integrations.into_iter().for_each(|i| {
+            let description = match i.settings {
+                UserYankIntegrationSetting::Audiobookshelf { base_url, .. } => {
+                    format!("Audiobookshelf URL: {}", base_url)
+                }
+                UserYankIntegrationSetting::Strava { athlete, .. } => {
+                    format!("Strava athlete: {}", athlete)
+                }
+                UserYankIntegrationSetting::Steam { steam_id, .. } => {
+                    format!("Steam profile: {}", steam_id)
+                }
+                UserYankIntegrationSetting::RetroAchievements { username, .. } => {
+                    format!("RetroAchievements user: {}", username)
+                }
+            };
+            all_integrations.push(GraphqlUserIntegration {
+                id: i.id,
+                lot: UserIntegrationLot::Yank,
+                description,
+                timestamp: i.timestamp,
+                slug: None,
+                secret: None,
+                progress_settings: i.progress_settings,
+                user_mappings: vec![],
+            })
+        });
+        let sink_integrations = user.sink_integrations.0;
+        sink_integrations.into_iter().for_each(|i| {
+            let (description, slug) = match i.settings {
+                UserSinkIntegrationSetting::Jellyfin { slug } => {
+                    (format!("Jellyfin slug: {}", &slug), slug)
+                }
+                UserSinkIntegrationSetting::Plex { slug, user } => (
+                    format!(
+                        "Plex slug: {},  Plex user: {}",
+                        &slug,
+                        user.unwrap_or_else(|| "N/A".to_owned())
+                    ),
+                    slug,
+                ),
+                UserSinkIntegrationSetting::Kodi { slug } => {
+                    (format!("Kodi slug: {}", &slug), slug)
+                }
+                UserSinkIntegrationSetting::Kobo { slug } => {
+                    (format!("Kobo slug: {}", &slug), slug)
                 }
-                "episode_name_changed" => {
-                    preferences.notifications.episode_name_changed = value_bool.unwrap()
+                UserSinkIntegrationSetting::Koreader { slug } => {
+                    (format!("KOReader slug: {}", &slug), slug)
                 }
-                "status_changed" => preferences.notifications.status_changed = value_bool.unwrap(),
-                "release_date_changed" => {
-                    preferences.notifications.release_date_changed = value_bool.unwrap()
+            };
+            all_integrations.push(GraphqlUserIntegration {
+                id: i.id,
+                lot: UserIntegrationLot::Sink,
+                description,
+                timestamp: i.timestamp,
+                slug: Some(slug),
+                secret: i.secret,
+                progress_settings: i.progress_settings,
+                user_mappings: i.user_mappings,
+            })
+        });
+        Ok(all_integrations)
+    }
+
+    async fn user_notification_platforms(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<GraphqlUserNotificationPlatform>> {
+        let user = user_by_id(&self.db, user_id).await?;
+        let mut all_notifications = vec![];
+        let notifications = user.notifications.0;
+        notifications.into_iter().for_each(|n| {
+            let description = match n.settings {
+                UserNotificationSetting::Apprise { url, key } => {
+                    format!("Apprise URL: {}, Key: {}", url, key)
                 }
-                "number_of_seasons_changed" => {
-                    preferences.notifications.number_of_seasons_changed = value_bool.unwrap()
+                UserNotificationSetting::Discord { url } => {
+                    format!("Discord webhook: {}", url)
                 }
-                "number_of_chapters_or_episodes_changed" => {
-                    preferences
-                        .notifications
-                        .number_of_chapters_or_episodes_changed = value_bool.unwrap()
+                UserNotificationSetting::Gotify { url, token, .. } => {
+                    format!("Gotify URL: {}, Token: {}", url, token)
+                }
+                UserNotificationSetting::Ntfy { url, topic, .. } => {
+                    format!("Ntfy URL: {:?}, Topic: {}", url, topic)
+                }
+                UserNotificationSetting::PushBullet { api_token } => {
+                    format!("Pushbullet API Token: {}", api_token)
+                }
+                UserNotificationSetting::PushOver { key, app_key } => {
+                    format!("PushOver Key: {}, App Key: {:?}", key, app_key)
+                }
+                UserNotificationSetting::PushSafer { key } => {
+                    format!("PushSafer Key: {}", key)
+                }
+            };
+            all_notifications.push(GraphqlUserNotificationPlatform {
+                id: n.id,
+                description,
+                timestamp: n.timestamp,
+            })
+        });
+        Ok(all_notifications)
+    }
+
+    async fn create_user_sink_integration(
+        &self,
+        user_id: i32,
+        input: CreateUserSinkIntegrationInput,
+    ) -> Result<usize> {
+        let progress_settings = input.progress_settings.unwrap_or_default();
+        validate_integration_progress_settings(&progress_settings)?;
+        let user = user_by_id(&self.db, user_id).await?;
+        let mut integrations = user.sink_integrations.clone().0;
+        let new_integration_id = integrations.len() + 1;
+        let new_integration = UserSinkIntegration {
+            id: new_integration_id,
+            timestamp: Utc::now(),
+            secret: Some(nanoid!(32)),
+            progress_settings,
+            user_mappings: vec![],
+            settings: {
+                let slug = get_id_hasher(&self.config.integration.hasher_salt)
+                    .encode(&[user_id.try_into().unwrap()]);
+                let slug = format!("{}--{}", slug, nanoid!(5));
+                match input.lot {
+                    UserSinkIntegrationSettingKind::Jellyfin => {
+                        UserSinkIntegrationSetting::Jellyfin { slug }
+                    }
+                    UserSinkIntegrationSettingKind::Plex => UserSinkIntegrationSetting::Plex {
+                        slug,
+                        user: input.username,
+                    },
+                    UserSinkIntegrationSettingKind::Kodi => {
+                        UserSinkIntegrationSetting::Kodi { slug }
+                    }
+                    UserSinkIntegrationSettingKind::Kobo => {
+                        UserSinkIntegrationSetting::Kobo { slug }
+                    }
+                    UserSinkIntegrationSettingKind::Koreader => {
+                        UserSinkIntegrationSetting::Koreader { slug }
+                    }
                 }
-                _ => return Err(err()),
             },
-            "general" => match right {
-                "review_scale" => {
-                    preferences.general.review_scale =
-                        UserReviewScale::from_str(&input.value).unwrap();
+        };
+        integrations.insert(0, new_integration);
+        let mut user: user::ActiveModel = user.into();
+        user.sink_integrations = ActiveValue::Set(UserSinkIntegrations(integrations));
+        user.update(&self.db).await?;
+        Ok(new_integration_id)
+    }
+
+    async fn create_user_yank_integration(
+        &self,
+        user_id: i32,
+        input: CreateUserYankIntegrationInput,
+    ) -> Result<usize> {
+        let progress_settings = input.progress_settings.unwrap_or_default();
+        validate_integration_progress_settings(&progress_settings)?;
+        let user = user_by_id(&self.db, user_id).await?;
+        let mut integrations = if let Some(i) = user.yank_integrations.clone() {
+            i.0
+        } else {
+            vec![]
+        };
+        let settings = match input.lot {
+            UserYankIntegrationSettingKind::Audiobookshelf => {
+                UserYankIntegrationSetting::Audiobookshelf {
+                    base_url: input.base_url,
+                    token: input.token,
+                }
+            }
+            UserYankIntegrationSettingKind::Strava => {
+                return Err(Error::new(
+                    "Strava needs to be connected using `connectStravaIntegration`.",
+                ))
+            }
+            // DEV: The base url is the link to the profile and the token is the API key
+            UserYankIntegrationSettingKind::Steam => {
+                let steam_id = self
+                    .get_integration_service()
+                    .steam_id(&input.token, &input.base_url)
+                    .await?;
+                UserYankIntegrationSetting::Steam {
+                    steam_id,
+                    api_key: input.token,
+                    playtimes: HashMap::new(),
                 }
-                "display_nsfw" => {
-                    preferences.general.display_nsfw = value_bool.unwrap();
+            }
+            // DEV: The base url is the link to the profile and the token is the web
+            // API key
+            UserYankIntegrationSettingKind::RetroAchievements => {
+                let profile = input.base_url.trim().trim_end_matches('/');
+                let username = profile.rsplit('/').next().unwrap_or(profile).to_owned();
+                self.get_integration_service()
+                    .retro_achievements_recent_games(&username, &input.token)
+                    .await
+                    .map_err(|_| Error::new("Could not connect to RetroAchievements"))?;
+                UserYankIntegrationSetting::RetroAchievements {
+                    username,
+                    api_key: input.token,
+                    last_synced_on: None,
+                    games: HashMap::new(),
                 }
-                _ => return Err(err()),
+            }
+        };
+        let new_integration_id = integrations.len() + 1;
+        let new_integration = UserYankIntegration {
+            id: new_integration_id,
+            timestamp: Utc::now(),
+            settings,
+            progress_settings,
+        };
+        integrations.insert(0, new_integration);
+        let mut user: user::ActiveModel = user.into();
+        user.yank_integrations = ActiveValue::Set(Some(UserYankIntegrations(integrations)));
+        user.update(&self.db).await?;
+        Ok(new_integration_id)
+    }
+
+    async fn delete_user_integration(
+        &self,
+        user_id: i32,
+        integration_id: usize,
+        integration_type: UserIntegrationLot,
+    ) -> Result<bool> {
+        let user = user_by_id(&self.db, user_id).await?;
+        let mut user_db: user::ActiveModel = user.clone().into();
+        match integration_type {
+            UserIntegrationLot::Yank => {
+                let integrations = if let Some(i) = user.yank_integrations.clone() {
+                    i.0
+                } else {
+                    vec![]
+                };
+                let remaining_integrations = integrations
+                    .into_iter()
+                    .filter(|i| i.id != integration_id)
+                    .collect_vec();
+                let update_value = if remaining_integrations.is_empty() {
+                    None
+                } else {
+                    Some(UserYankIntegrations(remaining_integrations))
+                };
+                user_db.yank_integrations = ActiveValue::Set(update_value);
+            }
+            UserIntegrationLot::Sink => {
+                let integrations = user.sink_integrations.clone().0;
+                let remaining_integrations = integrations
+                    .into_iter()
+                    .filter(|i| i.id != integration_id)
+                    .collect_vec();
+                let update_value = UserSinkIntegrations(remaining_integrations);
+                user_db.sink_integrations = ActiveValue::Set(update_value);
+            }
+        };
+        user_db.update(&self.db).await?;
+        Ok(true)
+    }
+
+    async fn update_user_integration_progress_settings(
+        &self,
+        user_id: i32,
+        integration_id: usize,
+        integration_type: UserIntegrationLot,
+        progress_settings: IntegrationProgressSettings,
+    ) -> Result<bool> {
+        validate_integration_progress_settings(&progress_settings)?;
+        let user = user_by_id(&self.db, user_id).await?;
+        let mut user_db: user::ActiveModel = user.clone().into();
+        let err = || Error::new("Integration with the given id does not exist");
+        match integration_type {
+            UserIntegrationLot::Yank => {
+                let mut integrations = user.yank_integrations.ok_or_else(err)?;
+                let integration = integrations
+                    .0
+                    .iter_mut()
+                    .find(|i| i.id == integration_id)
+                    .ok_or_else(err)?;
+                integration.progress_settings = progress_settings;
+                user_db.yank_integrations = ActiveValue::Set(Some(integrations));
+            }
+            UserIntegrationLot::Sink => {
+                let mut integrations = user.sink_integrations;
+                let integration = integrations
+                    .0
+                    .iter_mut()
+                    .find(|i| i.id == integration_id)
+                    .ok_or_else(err)?;
+                integration.progress_settings = progress_settings;
+                user_db.sink_integrations = ActiveValue::Set(integrations);
+            }
+        };
+        user_db.update(&self.db).await?;
+        Ok(true)
+    }
+
+    async fn update_user_integration_user_mappings(
+        &self,
+        user_id: i32,
+        integration_id: usize,
+        user_mappings: Vec<IntegrationUserMapping>,
+    ) -> Result<bool> {
+        if !user_mappings
+            .iter()
+            .map(|m| &m.server_username)
+            .all_unique()
+        {
+            return Err(Error::new("A profile can only be mapped to one user"));
+        }
+        for mapping in user_mappings.iter() {
+            user_by_id(&self.db, mapping.user_id).await?;
+        }
+        let user = user_by_id(&self.db, user_id).await?;
+        let mut integrations = user.sink_integrations.clone();
+        let integration = integrations
+            .0
+            .iter_mut()
+            .find(|i| i.id == integration_id)
+            .ok_or_else(|| Error::new("Integration with the given id does not exist"))?;
+        match integration.settings.kind() {
+            UserSinkIntegrationSettingKind::Jellyfin | UserSinkIntegrationSettingKind::Plex => {}
+            _ => {
+                return Err(Error::new(
+                    "Only Jellyfin and Plex integrations can map profiles to users",
+                ))
+            }
+        }
+        integration.user_mappings = user_mappings;
+        let mut user: user::ActiveModel = user.into();
+        user.sink_integrations = ActiveValue::Set(integrations);
+        user.update(&self.db).await?;
+        Ok(true)
+    }
+
+    async fn create_user_notification_platform(
+        &self,
+        user_id: i32,
+        input: CreateUserNotificationPlatformInput,
+    ) -> Result<usize> {
+        let user = user_by_id(&self.db, user_id).await?;
+        let mut notifications = user.notifications.clone().0;
+        let new_notification_id = notifications.len() + 1;
+        let new_notification = UserNotification {
+            id: new_notification_id,
+            timestamp: Utc::now(),
+            settings: match input.lot {
+                UserNotificationSettingKind::Apprise => UserNotificationSetting::Apprise {
+                    url: input.base_url.unwrap(),
+                    key: input.api_token.unwrap(),
+                },
+                UserNotificationSettingKind::Discord => UserNotificationSetting::Discord {
+                    url: input.base_url.unwrap(),
+                },
+                UserNotificationSettingKind::Gotify => UserNotificationSetting::Gotify {
+                    url: input.base_url.unwrap(),
+                    token: input.api_token.unwrap(),
+                    priority: input.priority,
+                },
+                UserNotificationSettingKind::Ntfy => UserNotificationSetting::Ntfy {
+                    url: input.base_url,
+                    topic: input.api_token.unwrap(),
+                    priority: input.priority,
+                },
+                UserNotificationSettingKind::PushBullet => UserNotificationSetting::PushBullet {
+                    api_token: input.api_token.unwrap(),
+                },
+                UserNotificationSettingKind::PushOver => UserNotificationSetting::PushOver {
+                    key: input.api_token.unwrap(),
+                    app_key: input.base_url,
+                },
+                UserNotificationSettingKind::PushSafer => UserNotificationSetting::PushSafer {
+                    key: input.api_token.unwrap(),
+                },
             },
-            _ => return Err(err()),
         };
-        let mut user_model: user::ActiveModel = user_model.into();
-        user_model.preferences = ActiveValue::Set(preferences);
-        user_model.update(&self.db).await?;
-        Ok(true)
+
+        notifications.insert(0, new_notification);
+        let mut user: user::ActiveModel = user.into();
+        user.notifications = ActiveValue::Set(UserNotifications(notifications));
+        user.update(&self.db).await?;
+        Ok(new_notification_id)
     }
 
-    async fn user_integrations(&self, user_id: i32) -> Result<Vec<GraphqlUserIntegration>> {
+    async fn delete_user_notification_platform(
+        &self,
+        user_id: i32,
+        notification_id: usize,
+    ) -> Result<bool> {
         let user = user_by_id(&self.db, user_id).await?;
-        let mut all_integrations = vec![];
-        let yank_integrations = if let Some(i) = user.yank_integrations {
-            i.0
-        } else {
-            vec![]
-        };
-        yank_integrations.into_iter().for_each(|i| {
-            let description = match i.settings {
-                UserYankIntegrationSetting::Audiobookshelf { base_url, .. } => {
-                    format!("Audiobookshelf URL: {}", base_url)
-                }
-            };
-            all_integrations.push(GraphqlUserIntegration {
-                id: i.id,
-                lot: UserIntegrationLot::Yank,
-                description,
-                timestamp: i.timestamp,
-                slug: None,
-            })
+        let mut user_db: user::ActiveModel = user.clone().into();
+        let notifications = user.notifications.clone().0;
+        let remaining_notifications = notifications
+            .into_iter()
+            .filter(|i| i.id != notification_id)
+            .collect_vec();
+        let update_value = UserNotifications(remaining_notifications);
+        user_db.notifications = ActiveValue::Set(update_value);
+        user_db.update(&self.db).await?;
+        Ok(true)
+    }
+
+    pub async fn media_exists_in_database(
+        &self,
+        lot: MetadataLot,
+        source: MetadataSource,
+        identifier: &str,
+    ) -> Result<Option<IdObject>> {
+        let media = Metadata::find()
+            .filter(metadata::Column::Lot.eq(lot))
+            .filter(metadata::Column::Source.eq(source))
+            .filter(metadata::Column::Identifier.eq(identifier))
+            .one(&self.db)
+            .await?;
+        Ok(media.map(|m| IdObject { id: m.id }))
+    }
+
+    /// Find a media item in the database whose title or one of its alternative
+    /// titles is the same as the given title, ignoring case and diacritics.
+    pub async fn media_exists_with_title(
+        &self,
+        lot: MetadataLot,
+        title: &str,
+    ) -> Result<Option<IdObject>> {
+        let normalized_title = normalize_text(title);
+        // DEV: The alternative titles are stored as a JSON array, so a title is
+        // only matched when it is a whole element of it
+        let alternative_title = serde_json::to_string(&title.to_lowercase())?
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        let candidates = Metadata::find()
+            .filter(metadata::Column::Lot.eq(lot))
+            .filter(
+                Condition::any()
+                    .add(metadata::Column::NormalizedTitle.eq(&normalized_title))
+                    .add(
+                        Expr::expr(Func::lower(Func::cast_as(
+                            Expr::col(metadata::Column::AlternativeTitles),
+                            Alias::new("text"),
+                        )))
+                        .like(LikeExpr::new(format!("%{}%", alternative_title)).escape('\\')),
+                    ),
+            )
+            .all(&self.db)
+            .await?;
+        let media = candidates.into_iter().find(|m| {
+            normalize_text(&m.title) == normalized_title
+                || m.alternative_titles
+                    .iter()
+                    .flat_map(|a| a.0.iter())
+                    .any(|a| normalize_text(a) == normalized_title)
         });
-        let sink_integrations = user.sink_integrations.0;
-        sink_integrations.into_iter().for_each(|i| {
-            let (description, slug) = match i.settings {
-                UserSinkIntegrationSetting::Jellyfin { slug } => {
-                    (format!("Jellyfin slug: {}", &slug), slug)
-                }
-                UserSinkIntegrationSetting::Plex { slug, user } => (
-                    format!(
-                        "Plex slug: {},  Plex user: {}",
-                        &slug,
-                        user.unwrap_or_else(|| "N/A".to_owned())
+        Ok(media.map(|m| IdObject { id: m.id }))
+    }
+
+    pub async fn media_sources_for_lot(&self, lot: MetadataLot) -> Vec<MetadataSource> {
+        match lot {
+            MetadataLot::AudioBook => vec![MetadataSource::Audible],
+            MetadataLot::Book => vec![MetadataSource::Openlibrary, MetadataSource::GoogleBooks],
+            MetadataLot::Podcast => vec![
+                MetadataSource::Itunes,
+                MetadataSource::Listennotes,
+                MetadataSource::PodcastIndex,
+            ],
+            MetadataLot::VideoGame => vec![MetadataSource::Igdb],
+            MetadataLot::Anime => vec![MetadataSource::Anilist, MetadataSource::Mal],
+            MetadataLot::Manga => vec![
+                MetadataSource::Anilist,
+                MetadataSource::MangaDex,
+                MetadataSource::MangaUpdates,
+                MetadataSource::Mal,
+            ],
+            MetadataLot::Movie | MetadataLot::Show => vec![MetadataSource::Tmdb],
+            MetadataLot::VisualNovel => vec![MetadataSource::Vndb],
+            MetadataLot::Article => vec![MetadataSource::Web],
+            MetadataLot::Music => vec![MetadataSource::MusicBrainz],
+            MetadataLot::BoardGame => vec![MetadataSource::BoardGameGeek],
+            MetadataLot::Comic => vec![MetadataSource::ComicVine],
+        }
+    }
+
+    /// The source that the user prefers for the lot, or the first one that is
+    /// available for it.
+    async fn preferred_source_for_lot(
+        &self,
+        lot: MetadataLot,
+        preferences: &UserPreferences,
+    ) -> MetadataSource {
+        let sources = self.media_sources_for_lot(lot).await;
+        // DEV: The preference is not checked when it is changed, so sources that
+        // are not available for the lot fall back to the default one
+        preferences
+            .providers
+            .for_lot(lot)
+            .filter(|s| sources.contains(s))
+            .unwrap_or(sources[0])
+    }
+
+    fn providers_language_information(&self) -> Vec<ProviderLanguageInformation> {
+        MetadataSource::iter()
+            .map(|source| {
+                let (supported, default) = match source {
+                    MetadataSource::Itunes => (
+                        ITunesService::supported_languages(),
+                        ITunesService::default_language(),
                     ),
-                    slug,
-                ),
-                UserSinkIntegrationSetting::Kodi { slug } => {
-                    (format!("Kodi slug: {}", &slug), slug)
+                    MetadataSource::Audible => (
+                        AudibleService::supported_languages(),
+                        AudibleService::default_language(),
+                    ),
+                    MetadataSource::Openlibrary => (
+                        OpenlibraryService::supported_languages(),
+                        OpenlibraryService::default_language(),
+                    ),
+                    MetadataSource::Tmdb => (
+                        TmdbService::supported_languages(),
+                        TmdbService::default_language(),
+                    ),
+                    MetadataSource::Listennotes => (
+                        ListennotesService::supported_languages(),
+                        ListennotesService::default_language(),
+                    ),
+                    MetadataSource::PodcastIndex => (
+                        PodcastIndexService::supported_languages(),
+                        PodcastIndexService::default_language(),
+                    ),
+                    MetadataSource::GoogleBooks => (
+                        GoogleBooksService::supported_languages(),
+                        GoogleBooksService::default_language(),
+                    ),
+                    MetadataSource::Igdb => (
+                        IgdbService::supported_languages(),
+                        IgdbService::default_language(),
+                    ),
+                    MetadataSource::MangaDex => (
+                        MangaDexService::supported_languages(),
+                        MangaDexService::default_language(),
+                    ),
+                    MetadataSource::MangaUpdates => (
+                        MangaUpdatesService::supported_languages(),
+                        MangaUpdatesService::default_language(),
+                    ),
+                    MetadataSource::Anilist => (
+                        AnilistService::supported_languages(),
+                        AnilistService::default_language(),
+                    ),
+                    MetadataSource::Mal => (
+                        MalService::supported_languages(),
+                        MalService::default_language(),
+                    ),
+                    MetadataSource::Custom => (
+                        CustomService::supported_languages(),
+                        CustomService::default_language(),
+                    ),
+                    MetadataSource::Vndb => (
+                        VndbService::supported_languages(),
+                        VndbService::default_language(),
+                    ),
+                    MetadataSource::Web => (
+                        WebService::supported_languages(),
+                        WebService::default_language(),
+                    ),
+                    MetadataSource::MusicBrainz => (
+                        MusicBrainzService::supported_languages(),
+                        MusicBrainzService::default_language(),
+                    ),
+                    MetadataSource::BoardGameGeek => (
+                        BggService::supported_languages(),
+                        BggService::default_language(),
+                    ),
+                    MetadataSource::ComicVine => (
+                        ComicVineService::supported_languages(),
+                        ComicVineService::default_language(),
+                    ),
+                };
+                ProviderLanguageInformation {
+                    supported,
+                    default,
+                    source,
                 }
-            };
-            all_integrations.push(GraphqlUserIntegration {
-                id: i.id,
-                lot: UserIntegrationLot::Sink,
-                description,
-                timestamp: i.timestamp,
-                slug: Some(slug),
             })
-        });
-        Ok(all_integrations)
+            .collect()
     }
 
-    async fn user_notification_platforms(
-        &self,
-        user_id: i32,
-    ) -> Result<Vec<GraphqlUserNotificationPlatform>> {
+    pub async fn yank_integrations_data_for_user(&self, user_id: i32) -> Result<usize> {
         let user = user_by_id(&self.db, user_id).await?;
-        let mut all_notifications = vec![];
-        let notifications = user.notifications.0;
-        notifications.into_iter().for_each(|n| {
-            let description = match n.settings {
-                UserNotificationSetting::Apprise { url, key } => {
-                    format!("Apprise URL: {}, Key: {}", url, key)
-                }
-                UserNotificationSetting::Discord { url } => {
-                    format!("Discord webhook: {}", url)
-                }
-                UserNotificationSetting::Gotify { url, token, .. } => {
-                    format!("Gotify URL: {}, Token: {}", url, token)
-                }
-                UserNotificationSetting::Ntfy { url, topic, .. } => {
-                    format!("Ntfy URL: {:?}, Topic: {}", url, topic)
-                }
-                UserNotificationSetting::PushBullet { api_token } => {
-                    format!("Pushbullet API Token: {}", api_token)
-                }
-                UserNotificationSetting::PushOver { key, app_key } => {
-                    format!("PushOver Key: {}, App Key: {:?}", key, app_key)
+        if let Some(mut integrations) = user.yank_integrations.clone() {
+            let mut progress_updates = vec![];
+            let mut settings_changed = false;
+            for integration in integrations.0.iter_mut() {
+                let response = match &mut integration.settings {
+                    UserYankIntegrationSetting::Audiobookshelf { base_url, token } => {
+                        self.get_integration_service()
+                            .audiobookshelf_progress(base_url, token)
+                            .await
+                    }
+                    // DEV: Strava activities are synced by the exercise service
+                    UserYankIntegrationSetting::Strava { .. } => continue,
+                    UserYankIntegrationSetting::Steam {
+                        steam_id,
+                        api_key,
+                        playtimes,
+                    } => {
+                        match self
+                            .steam_playtime_update(
+                                user_id,
+                                steam_id,
+                                api_key,
+                                playtimes,
+                                integration.timestamp,
+                            )
+                            .await
+                        {
+                            Ok(()) => settings_changed = true,
+                            Err(e) => tracing::error!("Could not sync Steam playtime: {:?}", e),
+                        }
+                        continue;
+                    }
+                    UserYankIntegrationSetting::RetroAchievements {
+                        username,
+                        api_key,
+                        last_synced_on,
+                        games,
+                    } => {
+                        match self
+                            .retro_achievements_progress_update(
+                                user_id,
+                                username,
+                                api_key,
+                                last_synced_on.unwrap_or(integration.timestamp),
+                                games,
+                            )
+                            .await
+                        {
+                            Ok(synced_on) => {
+                                *last_synced_on = synced_on.or(*last_synced_on);
+                                settings_changed = true;
+                            }
+                            Err(e) => {
+                                tracing::error!("Could not sync RetroAchievements: {:?}", e)
+                            }
+                        }
+                        continue;
+                    }
+                };
+                if let Ok(data) = response {
+                    let settings = &integration.progress_settings;
+                    progress_updates.extend(data.into_iter().map(|pu| (pu, settings.clone())));
                 }
-                UserNotificationSetting::PushSafer { key } => {
-                    format!("PushSafer Key: {}", key)
+            }
+            if settings_changed {
+                let mut user: user::ActiveModel = user.into();
+                user.yank_integrations = ActiveValue::Set(Some(integrations));
+                user.update(&self.db).await?;
+            }
+            let mut updated_count = 0;
+            for (pu, settings) in progress_updates.into_iter() {
+                if self
+                    .integration_progress_update(pu, user_id, &settings)
+                    .await
+                    .is_ok()
+                {
+                    updated_count += 1
                 }
-            };
-            all_notifications.push(GraphqlUserNotificationPlatform {
-                id: n.id,
-                description,
-                timestamp: n.timestamp,
-            })
-        });
-        Ok(all_notifications)
+            }
+            Ok(updated_count)
+        } else {
+            Ok(0)
+        }
     }
 
-    async fn create_user_sink_integration(
+    /// Update the playtime of the games that the user owns on Steam. The first sync
+    /// only records the playtimes, after which games that have been played since
+    /// are added to the library of the user and marked as in progress.
+    async fn steam_playtime_update(
         &self,
         user_id: i32,
-        input: CreateUserSinkIntegrationInput,
-    ) -> Result<usize> {
-        let user = user_by_id(&self.db, user_id).await?;
-        let mut integrations = user.sink_integrations.clone().0;
-        let new_integration_id = integrations.len() + 1;
-        let new_integration = UserSinkIntegration {
-            id: new_integration_id,
-            timestamp: Utc::now(),
-            settings: {
-                let slug = get_id_hasher(&self.config.integration.hasher_salt)
-                    .encode(&[user_id.try_into().unwrap()]);
-                let slug = format!("{}--{}", slug, nanoid!(5));
-                match input.lot {
-                    UserSinkIntegrationSettingKind::Jellyfin => {
-                        UserSinkIntegrationSetting::Jellyfin { slug }
-                    }
-                    UserSinkIntegrationSettingKind::Plex => UserSinkIntegrationSetting::Plex {
-                        slug,
-                        user: input.username,
-                    },
-                    UserSinkIntegrationSettingKind::Kodi => {
-                        UserSinkIntegrationSetting::Kodi { slug }
+        steam_id: &str,
+        api_key: &str,
+        playtimes: &mut HashMap<String, i32>,
+        connected_on: DateTimeUtc,
+    ) -> Result<()> {
+        let games = self
+            .get_integration_service()
+            .steam_owned_games(api_key, steam_id)
+            .await?;
+        let changed = games
+            .into_iter()
+            .filter(|g| g.playtime > 0 && playtimes.get(&g.app_id) != Some(&g.playtime))
+            .collect_vec();
+        if changed.is_empty() {
+            return Ok(());
+        }
+        let igdb_service =
+            IgdbService::new(&self.config.video_games, self.config.frontend.page_size).await;
+        let igdb_ids = igdb_service
+            .steam_games(&changed.iter().map(|g| g.app_id.clone()).collect_vec())
+            .await?;
+        for game in changed {
+            // DEV: A game was played since the last sync if we already knew its
+            // playtime, or if Steam says it was played after the integration was
+            // connected. This keeps the first sync from starting the whole library.
+            let newly_played = playtimes.contains_key(&game.app_id)
+                || game.last_played_on.map_or(false, |l| l >= connected_on);
+            playtimes.insert(game.app_id.clone(), game.playtime);
+            let identifier = match igdb_ids.get(&game.app_id) {
+                Some(i) => i,
+                None => {
+                    tracing::debug!("Could not find {:?} on IGDB", game.name);
+                    continue;
+                }
+            };
+            let metadata_id = match newly_played {
+                true => {
+                    self.commit_media_for_user(
+                        user_id,
+                        MetadataLot::VideoGame,
+                        MetadataSource::Igdb,
+                        identifier,
+                    )
+                    .await?
+                    .id
+                }
+                false => {
+                    let metadata = Metadata::find()
+                        .filter(metadata::Column::Lot.eq(MetadataLot::VideoGame))
+                        .filter(metadata::Column::Source.eq(MetadataSource::Igdb))
+                        .filter(metadata::Column::Identifier.eq(identifier))
+                        .one(&self.db)
+                        .await?;
+                    match metadata {
+                        Some(m) => m.id,
+                        None => continue,
                     }
                 }
-            },
-        };
-        integrations.insert(0, new_integration);
-        let mut user: user::ActiveModel = user.into();
-        user.sink_integrations = ActiveValue::Set(UserSinkIntegrations(integrations));
-        user.update(&self.db).await?;
-        Ok(new_integration_id)
+            };
+            let user_to_meta = match newly_played {
+                true => Some(associate_user_with_metadata(&user_id, &metadata_id, &self.db).await?),
+                false => get_user_and_metadata_association(&user_id, &metadata_id, &self.db).await,
+            };
+            if let Some(utm) = user_to_meta {
+                let mut utm: user_to_metadata::ActiveModel = utm.into();
+                utm.playtime = ActiveValue::Set(Some(game.playtime));
+                utm.update(&self.db).await?;
+            }
+            if !newly_played {
+                continue;
+            }
+            let in_progress = Seen::find()
+                .filter(seen::Column::UserId.eq(user_id))
+                .filter(seen::Column::MetadataId.eq(metadata_id))
+                .filter(seen::Column::State.eq(SeenState::InProgress))
+                .count(&self.db)
+                .await?;
+            if in_progress == 0 {
+                self.progress_update(
+                    ProgressUpdateInput {
+                        metadata_id,
+                        progress: Some(0),
+                        date: Some(Utc::now().date_naive()),
+                        show_season_number: None,
+                        show_episode_number: None,
+                        podcast_episode_number: None,
+                        change_state: None,
+                        context: None,
+                    },
+                    user_id,
+                )
+                .await
+                .ok();
+            }
+        }
+        Ok(())
     }
 
-    async fn create_user_yank_integration(
+    /// Start or update the progress of the games that the user played on
+    /// RetroAchievements after the given time. The progress of a game is the
+    /// fraction of its achievements that have been unlocked. Returns the time of
+    /// the most recent session that was synced.
+    async fn retro_achievements_progress_update(
         &self,
         user_id: i32,
-        input: CreateUserYankIntegrationInput,
-    ) -> Result<usize> {
-        let user = user_by_id(&self.db, user_id).await?;
-        let mut integrations = if let Some(i) = user.yank_integrations.clone() {
-            i.0
-        } else {
-            vec![]
-        };
-        let new_integration_id = integrations.len() + 1;
-        let new_integration = UserYankIntegration {
-            id: new_integration_id,
-            timestamp: Utc::now(),
-            settings: match input.lot {
-                UserYankIntegrationSettingKind::Audiobookshelf => {
-                    UserYankIntegrationSetting::Audiobookshelf {
-                        base_url: input.base_url,
-                        token: input.token,
+        username: &str,
+        api_key: &str,
+        synced_on: DateTimeUtc,
+        igdb_ids: &mut HashMap<String, String>,
+    ) -> Result<Option<DateTimeUtc>> {
+        let games = self
+            .get_integration_service()
+            .retro_achievements_recent_games(username, api_key)
+            .await?
+            .into_iter()
+            .filter(|g| g.last_played_on > synced_on)
+            .collect_vec();
+        let last_played_on = games.iter().map(|g| g.last_played_on).max();
+        if games.is_empty() {
+            return Ok(last_played_on);
+        }
+        let igdb_service =
+            IgdbService::new(&self.config.video_games, self.config.frontend.page_size).await;
+        for game in games {
+            let identifier = match igdb_ids.get(&game.game_id) {
+                Some(i) => i.clone(),
+                None => {
+                    // DEV: Hacks and homebrew are tagged like `~Hack~ Title`
+                    let title = game.title.rsplit('~').next().unwrap_or(&game.title).trim();
+                    let result = igdb_service
+                        .search(title, None, false, &MediaSearchFilters::default())
+                        .await?;
+                    match result.items.into_iter().next() {
+                        Some(i) => {
+                            igdb_ids.insert(game.game_id.clone(), i.identifier.clone());
+                            i.identifier
+                        }
+                        None => {
+                            tracing::debug!(
+                                "Could not find {:?} ({}) on IGDB",
+                                game.title,
+                                game.console
+                            );
+                            continue;
+                        }
                     }
                 }
-            },
+            };
+            let IdObject { id: metadata_id } = self
+                .commit_media_for_user(
+                    user_id,
+                    MetadataLot::VideoGame,
+                    MetadataSource::Igdb,
+                    &identifier,
+                )
+                .await?;
+            let metadata = Metadata::find_by_id(metadata_id)
+                .one(&self.db)
+                .await?
+                .ok_or_else(|| Error::new("The media does not exist"))?;
+            if let MediaSpecifics::VideoGame(mut specifics) = metadata.specifics.clone() {
+                if specifics.achievements != Some(game.achievements) {
+                    specifics.achievements = Some(game.achievements);
+                    let mut metadata: metadata::ActiveModel = metadata.into();
+                    metadata.specifics = ActiveValue::Set(MediaSpecifics::VideoGame(specifics));
+                    metadata.update(&self.db).await?;
+                }
+            }
+            let progress = match game.achievements {
+                0 => 0,
+                a => game.achieved * 100 / a,
+            };
+            // DEV: The same session can be reported again, so the progress is only
+            // updated when more achievements have been unlocked
+            let last_seen = Seen::find()
+                .filter(seen::Column::UserId.eq(user_id))
+                .filter(seen::Column::MetadataId.eq(metadata_id))
+                .order_by_desc(seen::Column::LastUpdatedOn)
+                .one(&self.db)
+                .await?;
+            let up_to_date = last_seen.map_or(false, |s| match s.state {
+                SeenState::InProgress => s.progress >= progress,
+                SeenState::Completed => progress == 100,
+                _ => false,
+            });
+            if up_to_date {
+                continue;
+            }
+            self.progress_update(
+                ProgressUpdateInput {
+                    metadata_id,
+                    progress: Some(progress),
+                    date: Some(Utc::now().date_naive()),
+                    show_season_number: None,
+                    show_episode_number: None,
+                    podcast_episode_number: None,
+                    change_state: None,
+                    context: None,
+                },
+                user_id,
+            )
+            .await
+            .ok();
+        }
+        Ok(last_played_on)
+    }
+
+    pub async fn yank_integrations_data(&self) -> Result<()> {
+        let users_with_integrations = User::find()
+            .filter(user::Column::YankIntegrations.is_not_null())
+            .select_only()
+            .column(user::Column::Id)
+            .into_tuple::<i32>()
+            .all(&self.db)
+            .await?;
+        for user_id in users_with_integrations {
+            self.yank_integrations_data_for_user(user_id).await?;
+        }
+        Ok(())
+    }
+
+    async fn setup_guard(&self, gql_ctx: &Context<'_>, setup_token: Option<String>) -> Result<()> {
+        if User::find().count(&self.db).await? == 0 {
+            return match setup_token {
+                Some(t) if constant_time_eq(t.as_bytes(), self.setup_token.as_bytes()) => Ok(()),
+                _ => Err(Error::new("The setup token is not correct")),
+            };
+        }
+        let user_id = self.user_id_from_ctx(gql_ctx).await?;
+        self.admin_account_guard(user_id).await
+    }
+
+    async fn setup_status(&self) -> Result<SetupStatus> {
+        let admin_exists = User::find()
+            .filter(user::Column::Lot.eq(UserLot::Admin))
+            .count(&self.db)
+            .await?
+            != 0;
+        let mut providers = vec![];
+        for lot in MetadataLot::iter() {
+            for source in self.media_sources_for_lot(lot).await {
+                providers.push(SetupProviderStatus {
+                    lot,
+                    source,
+                    missing_settings: self.config.missing_provider_settings(lot, source),
+                });
+            }
+        }
+        Ok(SetupStatus {
+            admin_exists,
+            providers,
+        })
+    }
+
+    async fn test_provider(&self, lot: MetadataLot, source: MetadataSource) -> ProviderTestResult {
+        let start = Instant::now();
+        // DEV: The cache is skipped so that the provider is actually contacted
+        let (result, response) = match self.get_provider(lot, source, None).await {
+            Ok(provider) => {
+                let (result, response) = http_cache::probe(provider.search(
+                    "the",
+                    None,
+                    false,
+                    &MediaSearchFilters::default(),
+                ))
+                .await;
+                (result.map(|_| ()).map_err(|e| e.to_string()), response)
+            }
+            Err(e) => (Err(e.message), None),
         };
-        integrations.insert(0, new_integration);
-        let mut user: user::ActiveModel = user.into();
-        user.yank_integrations = ActiveValue::Set(Some(UserYankIntegrations(integrations)));
-        user.update(&self.db).await?;
-        Ok(new_integration_id)
+        ProviderTestResult {
+            lot,
+            source,
+            success: result.is_ok(),
+            error: result.err(),
+            latency_ms: start.elapsed().as_millis().try_into().unwrap_or(i64::MAX),
+            status: response.as_ref().map(|r| r.status.into()),
+            rate_limit_headers: response
+                .map(|r| r.rate_limit_headers)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(name, value)| ProviderResponseHeader { name, value })
+                .collect(),
+        }
     }
 
-    async fn delete_user_integration(
+    async fn test_providers(&self) -> Vec<ProviderTestResult> {
+        let mut to_test = vec![];
+        for lot in MetadataLot::iter() {
+            for source in self.media_sources_for_lot(lot).await {
+                // DEV: Articles are fetched directly from their links
+                if source != MetadataSource::Web
+                    && self
+                        .config
+                        .missing_provider_settings(lot, source)
+                        .is_empty()
+                {
+                    to_test.push((lot, source));
+                }
+            }
+        }
+        join_all(
+            to_test
+                .into_iter()
+                .map(|(lot, source)| self.test_provider(lot, source)),
+        )
+        .await
+    }
+
+    async fn setup_save_config(&self, settings: Vec<SetupConfigSettingInput>) -> Result<bool> {
+        if let Some(s) = settings.iter().find(|s| !is_provider_setting(&s.key)) {
+            return Err(Error::new(format!(
+                "{} is not a setting of a provider",
+                s.key
+            )));
+        }
+        let settings = settings.into_iter().map(|s| (s.key, s.value)).collect();
+        save_app_config_settings(settings).map_err(|e| Error::new(e.to_string()))?;
+        Ok(true)
+    }
+
+    async fn export_instance_config(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(
+            &self.config.exportable_value(),
+        )?)
+    }
+
+    async fn import_instance_config(&self, config: String) -> Result<bool> {
+        let settings = serde_json::from_str(&config)?;
+        import_app_config(settings).map_err(|e| Error::new(e.to_string()))?;
+        Ok(true)
+    }
+
+    async fn admin_account_guard(&self, user_id: i32) -> Result<()> {
+        let main_user = user_by_id(&self.db, user_id).await?;
+        if main_user.lot != UserLot::Admin {
+            return Err(Error::new("Only admins can perform this operation."));
+        }
+        Ok(())
+    }
+
+    async fn users_list(&self) -> Result<Vec<user::Model>> {
+        Ok(User::find()
+            .order_by_asc(user::Column::Id)
+            .all(&self.db)
+            .await?)
+    }
+
+    async fn delete_user(&self, to_delete_user_id: i32) -> Result<bool> {
+        let maybe_user = User::find_by_id(to_delete_user_id).one(&self.db).await?;
+        if let Some(u) = maybe_user {
+            if self
+                .users_list()
+                .await?
+                .into_iter()
+                .filter(|u| u.lot == UserLot::Admin)
+                .collect_vec()
+                .len()
+                == 1
+                && u.lot == UserLot::Admin
+            {
+                return Ok(false);
+            }
+            u.delete(&self.db).await?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Find the user and the sink integration of the given kind that the slug
+    /// belongs to.
+    async fn user_by_integration_slug(
         &self,
-        user_id: i32,
-        integration_id: usize,
-        integration_type: UserIntegrationLot,
-    ) -> Result<bool> {
+        user_hash_id: &str,
+        integration: UserSinkIntegrationSettingKind,
+    ) -> Result<(user::Model, UserSinkIntegration)> {
+        let (user_hash, _) = user_hash_id
+            .split_once("--")
+            .ok_or(anyhow!("Unexpected format"))?;
+        let user_id = get_id_hasher(&self.config.integration.hasher_salt).decode(user_hash)?;
+        let user_id: i32 = user_id
+            .first()
+            .ok_or(anyhow!("Incorrect hash id provided"))?
+            .to_owned()
+            .try_into()?;
         let user = user_by_id(&self.db, user_id).await?;
-        let mut user_db: user::ActiveModel = user.clone().into();
-        match integration_type {
-            UserIntegrationLot::Yank => {
-                let integrations = if let Some(i) = user.yank_integrations.clone() {
-                    i.0
-                } else {
-                    vec![]
-                };
-                let remaining_integrations = integrations
-                    .into_iter()
-                    .filter(|i| i.id != integration_id)
-                    .collect_vec();
-                let update_value = if remaining_integrations.is_empty() {
-                    None
-                } else {
-                    Some(UserYankIntegrations(remaining_integrations))
+        let integration = user
+            .sink_integrations
+            .0
+            .clone()
+            .into_iter()
+            .find(|i| {
+                let slug = match &i.settings {
+                    UserSinkIntegrationSetting::Jellyfin { slug } => slug,
+                    UserSinkIntegrationSetting::Plex { slug, .. } => slug,
+                    UserSinkIntegrationSetting::Kodi { slug } => slug,
+                    UserSinkIntegrationSetting::Kobo { slug } => slug,
+                    UserSinkIntegrationSetting::Koreader { slug } => slug,
                 };
-                user_db.yank_integrations = ActiveValue::Set(update_value);
+                slug == user_hash_id && i.settings.kind() == integration
+            })
+            .ok_or_else(|| Error::new("Webhook URL does not match".to_owned()))?;
+        Ok((user, integration))
+    }
+
+    /// Find the user and the integration that a request to a webhook is for, and
+    /// check that the payload was signed with the secret of the integration.
+    pub async fn authenticate_integration_webhook(
+        &self,
+        user_hash_id: &str,
+        integration: &str,
+        payload: &str,
+        credentials: &WebhookCredentials,
+    ) -> Result<(user::Model, UserSinkIntegration)> {
+        let integration = match integration {
+            "jellyfin" => UserSinkIntegrationSettingKind::Jellyfin,
+            "plex" => UserSinkIntegrationSettingKind::Plex,
+            "kodi" => UserSinkIntegrationSettingKind::Kodi,
+            _ => return Err(anyhow!("Incorrect integration requested").into()),
+        };
+        let (user, integration) = self
+            .user_by_integration_slug(user_hash_id, integration)
+            .await?;
+        if !credentials.verify(integration_secret(&integration)?, payload) {
+            return Err(Error::new("The webhook payload is not signed"));
+        }
+        Ok((user, integration))
+    }
+
+    pub async fn process_integration_webhook(
+        &self,
+        user: user::Model,
+        integration: UserSinkIntegration,
+        payload: String,
+    ) -> Result<String> {
+        let movie_provider = self
+            .get_provider(MetadataLot::Movie, MetadataSource::Tmdb, None)
+            .await?;
+        let show_provider = self
+            .get_provider(MetadataLot::Show, MetadataSource::Tmdb, None)
+            .await?;
+        let maybe_progress_update = match &integration.settings {
+            UserSinkIntegrationSetting::Jellyfin { .. } => {
+                self.get_integration_service()
+                    .jellyfin_progress(&payload, &*movie_provider, &*show_provider)
+                    .await
             }
-            UserIntegrationLot::Sink => {
-                let integrations = user.sink_integrations.clone().0;
-                let remaining_integrations = integrations
-                    .into_iter()
-                    .filter(|i| i.id != integration_id)
-                    .collect_vec();
-                let update_value = UserSinkIntegrations(remaining_integrations);
-                user_db.sink_integrations = ActiveValue::Set(update_value);
+            UserSinkIntegrationSetting::Plex { .. } => {
+                self.get_integration_service()
+                    .plex_progress(&payload, &self.db, &*movie_provider, &*show_provider)
+                    .await
+            }
+            UserSinkIntegrationSetting::Kodi { .. } => {
+                self.get_integration_service().kodi_progress(&payload).await
             }
+            UserSinkIntegrationSetting::Kobo { .. }
+            | UserSinkIntegrationSetting::Koreader { .. } => unreachable!(),
         };
-        user_db.update(&self.db).await?;
-        Ok(true)
+        match maybe_progress_update {
+            Ok(pu) => {
+                let user_id = integration_webhook_user_id(user.id, &integration, &pu)?;
+                self.integration_progress_update(pu, user_id, &integration.progress_settings)
+                    .await?;
+                Ok("Progress updated successfully".to_owned())
+            }
+            Err(e) => Err(Error::new(e.to_string())),
+        }
     }
 
-    async fn create_user_notification_platform(
+    /// Authenticate a request made by KOReader to its sync server. The slug in the
+    /// URL identifies the user, and the username configured in KOReader must be
+    /// the one of the Ryot account. The password is the secret of the integration,
+    /// which KOReader sends the MD5 hash of.
+    pub async fn koreader_authenticate(
         &self,
-        user_id: i32,
-        input: CreateUserNotificationPlatformInput,
-    ) -> Result<usize> {
-        let user = user_by_id(&self.db, user_id).await?;
-        let mut notifications = user.notifications.clone().0;
-        let new_notification_id = notifications.len() + 1;
-        let new_notification = UserNotification {
-            id: new_notification_id,
-            timestamp: Utc::now(),
-            settings: match input.lot {
-                UserNotificationSettingKind::Apprise => UserNotificationSetting::Apprise {
-                    url: input.base_url.unwrap(),
-                    key: input.api_token.unwrap(),
-                },
-                UserNotificationSettingKind::Discord => UserNotificationSetting::Discord {
-                    url: input.base_url.unwrap(),
-                },
-                UserNotificationSettingKind::Gotify => UserNotificationSetting::Gotify {
-                    url: input.base_url.unwrap(),
-                    token: input.api_token.unwrap(),
-                    priority: input.priority,
-                },
-                UserNotificationSettingKind::Ntfy => UserNotificationSetting::Ntfy {
-                    url: input.base_url,
-                    topic: input.api_token.unwrap(),
-                    priority: input.priority,
-                },
-                UserNotificationSettingKind::PushBullet => UserNotificationSetting::PushBullet {
-                    api_token: input.api_token.unwrap(),
-                },
-                UserNotificationSettingKind::PushOver => UserNotificationSetting::PushOver {
-                    key: input.api_token.unwrap(),
-                    app_key: input.base_url,
-                },
-                UserNotificationSettingKind::PushSafer => UserNotificationSetting::PushSafer {
-                    key: input.api_token.unwrap(),
-                },
-            },
-        };
+        user_hash_id: &str,
+        username: &str,
+        key: &str,
+    ) -> Result<i32> {
+        let (user, integration) = self
+            .user_by_integration_slug(user_hash_id, UserSinkIntegrationSettingKind::Koreader)
+            .await?;
+        if user.name != username {
+            return Err(Error::new("The username does not match this integration"));
+        }
+        let expected = hex::encode(Md5::digest(integration_secret(&integration)?));
+        if !constant_time_eq(expected.as_bytes(), key.to_lowercase().as_bytes()) {
+            return Err(Error::new("The password does not match this integration"));
+        }
+        Ok(user.id)
+    }
 
-        notifications.insert(0, new_notification);
-        let mut user: user::ActiveModel = user.into();
-        user.notifications = ActiveValue::Set(UserNotifications(notifications));
-        user.update(&self.db).await?;
-        Ok(new_notification_id)
+    /// Authenticate a request made by a Kobo device that is configured to use
+    /// Ryot as its API endpoint. The device can not send extra headers, so the
+    /// secret of the integration is a part of the URL.
+    pub async fn kobo_authenticate(&self, user_hash_id: &str, secret: &str) -> Result<i32> {
+        let (user, integration) = self
+            .user_by_integration_slug(user_hash_id, UserSinkIntegrationSettingKind::Kobo)
+            .await?;
+        if !constant_time_eq(
+            integration_secret(&integration)?.as_bytes(),
+            secret.as_bytes(),
+        ) {
+            return Err(Error::new("The secret does not match this integration"));
+        }
+        Ok(user.id)
     }
 
-    async fn delete_user_notification_platform(
+    /// Update the progress of the books whose reading state the Kobo device
+    /// sent. The headers of the device are needed to get the details of the
+    /// books from the Kobo store.
+    pub async fn kobo_progress_update(
         &self,
         user_id: i32,
-        notification_id: usize,
-    ) -> Result<bool> {
-        let user = user_by_id(&self.db, user_id).await?;
-        let mut user_db: user::ActiveModel = user.clone().into();
-        let notifications = user.notifications.clone().0;
-        let remaining_notifications = notifications
-            .into_iter()
-            .filter(|i| i.id != notification_id)
-            .collect_vec();
-        let update_value = UserNotifications(remaining_notifications);
-        user_db.notifications = ActiveValue::Set(update_value);
-        user_db.update(&self.db).await?;
-        Ok(true)
+        payload: &str,
+        headers: &[(String, String)],
+    ) -> Result<()> {
+        let provider = self
+            .get_provider(MetadataLot::Book, MetadataSource::Openlibrary, None)
+            .await?;
+        let progress_updates = self
+            .get_integration_service()
+            .kobo_progress(payload, headers, &*provider)
+            .await?;
+        let settings = self
+            .sink_integration_progress_settings(user_id, UserSinkIntegrationSettingKind::Kobo)
+            .await?;
+        for pu in progress_updates {
+            if let Err(e) = self
+                .integration_progress_update(pu, user_id, &settings)
+                .await
+            {
+                tracing::debug!("Could not update the progress of a book: {:?}", e);
+            }
+        }
+        Ok(())
     }
 
-    async fn media_exists_in_database(
+    pub async fn koreader_progress(
         &self,
-        lot: MetadataLot,
-        source: MetadataSource,
-        identifier: &str,
-    ) -> Result<Option<IdObject>> {
-        let media = Metadata::find()
-            .filter(metadata::Column::Lot.eq(lot))
-            .filter(metadata::Column::Source.eq(source))
-            .filter(metadata::Column::Identifier.eq(identifier))
+        user_id: i32,
+        document: String,
+    ) -> Result<Option<KoreaderProgress>> {
+        let document = KoreaderDocument::find()
+            .filter(koreader_document::Column::UserId.eq(user_id))
+            .filter(koreader_document::Column::Document.eq(document))
             .one(&self.db)
             .await?;
-        Ok(media.map(|m| IdObject { id: m.id }))
-    }
-
-    async fn media_sources_for_lot(&self, lot: MetadataLot) -> Vec<MetadataSource> {
-        match lot {
-            MetadataLot::AudioBook => vec![MetadataSource::Audible],
-            MetadataLot::Book => vec![MetadataSource::Openlibrary, MetadataSource::GoogleBooks],
-            MetadataLot::Podcast => vec![MetadataSource::Itunes, MetadataSource::Listennotes],
-            MetadataLot::VideoGame => vec![MetadataSource::Igdb],
-            MetadataLot::Anime => vec![MetadataSource::Anilist, MetadataSource::Mal],
-            MetadataLot::Manga => vec![
-                MetadataSource::Anilist,
-                MetadataSource::MangaUpdates,
-                MetadataSource::Mal,
-            ],
-            MetadataLot::Movie | MetadataLot::Show => vec![MetadataSource::Tmdb],
-            MetadataLot::VisualNovel => vec![MetadataSource::Vndb],
+        Ok(document.map(|d| KoreaderProgress {
+            document: d.document,
+            progress: d.progress,
+            percentage: d.percentage.to_f64().unwrap_or_default(),
+            device: d.device,
+            device_id: d.device_id,
+            timestamp: Some(d.updated_on.timestamp()),
+        }))
+    }
+
+    /// Save the position that KOReader sent for a document and update the
+    /// progress of the book that it is linked to. A document that is not linked
+    /// yet is matched to the only book that the user is reading, if there is
+    /// exactly one that is not linked to another document.
+    pub async fn koreader_update_progress(
+        &self,
+        user_id: i32,
+        input: KoreaderProgress,
+    ) -> Result<DateTimeUtc> {
+        let percentage = Decimal::from_f64_retain(input.percentage)
+            .unwrap_or_default()
+            .clamp(dec!(0), dec!(1));
+        let now = Utc::now();
+        let existing = KoreaderDocument::find()
+            .filter(koreader_document::Column::UserId.eq(user_id))
+            .filter(koreader_document::Column::Document.eq(input.document.clone()))
+            .one(&self.db)
+            .await?;
+        let metadata_id = match existing.as_ref().and_then(|d| d.metadata_id) {
+            Some(m) => Some(m),
+            None => self.koreader_match_document(user_id).await?,
+        };
+        let mut document: koreader_document::ActiveModel = match existing {
+            Some(d) => d.into(),
+            None => koreader_document::ActiveModel {
+                user_id: ActiveValue::Set(user_id),
+                document: ActiveValue::Set(input.document),
+                ..Default::default()
+            },
+        };
+        document.metadata_id = ActiveValue::Set(metadata_id);
+        document.progress = ActiveValue::Set(input.progress);
+        document.percentage = ActiveValue::Set(percentage);
+        document.device = ActiveValue::Set(input.device);
+        document.device_id = ActiveValue::Set(input.device_id);
+        document.updated_on = ActiveValue::Set(now);
+        document.save(&self.db).await?;
+        if let Some(metadata_id) = metadata_id {
+            self.koreader_document_progress_update(user_id, metadata_id, percentage)
+                .await?;
         }
+        Ok(now)
     }
 
-    fn providers_language_information(&self) -> Vec<ProviderLanguageInformation> {
-        MetadataSource::iter()
-            .map(|source| {
-                let (supported, default) = match source {
-                    MetadataSource::Itunes => (
-                        ITunesService::supported_languages(),
-                        ITunesService::default_language(),
-                    ),
-                    MetadataSource::Audible => (
-                        AudibleService::supported_languages(),
-                        AudibleService::default_language(),
-                    ),
-                    MetadataSource::Openlibrary => (
-                        OpenlibraryService::supported_languages(),
-                        OpenlibraryService::default_language(),
-                    ),
-                    MetadataSource::Tmdb => (
-                        TmdbService::supported_languages(),
-                        TmdbService::default_language(),
-                    ),
-                    MetadataSource::Listennotes => (
-                        ListennotesService::supported_languages(),
-                        ListennotesService::default_language(),
-                    ),
-                    MetadataSource::GoogleBooks => (
-                        GoogleBooksService::supported_languages(),
-                        GoogleBooksService::default_language(),
-                    ),
-                    MetadataSource::Igdb => (
-                        IgdbService::supported_languages(),
-                        IgdbService::default_language(),
-                    ),
-                    MetadataSource::MangaUpdates => (
-                        MangaUpdatesService::supported_languages(),
-                        MangaUpdatesService::default_language(),
-                    ),
-                    MetadataSource::Anilist => (
-                        AnilistService::supported_languages(),
-                        AnilistService::default_language(),
-                    ),
-                    MetadataSource::Mal => (
-                        MalService::supported_languages(),
-                        MalService::default_language(),
-                    ),
-                    MetadataSource::Custom => (
-                        CustomService::supported_languages(),
-                        CustomService::default_language(),
-                    ),
-                    MetadataSource::Vndb => (
-                        VndbService::supported_languages(),
-                        VndbService::default_language(),
-                    ),
-                };
-                ProviderLanguageInformation {
-                    supported,
-                    default,
-                    source,
-                }
-            })
-            .collect()
+    async fn koreader_match_document(&self, user_id: i32) -> Result<Option<i32>> {
+        let linked = KoreaderDocument::find()
+            .select_only()
+            .column(koreader_document::Column::MetadataId)
+            .filter(koreader_document::Column::UserId.eq(user_id))
+            .filter(koreader_document::Column::MetadataId.is_not_null())
+            .into_tuple::<i32>()
+            .all(&self.db)
+            .await?;
+        let reading = Seen::find()
+            .select_only()
+            .column(seen::Column::MetadataId)
+            .distinct()
+            .inner_join(Metadata)
+            .filter(seen::Column::UserId.eq(user_id))
+            .filter(seen::Column::State.eq(SeenState::InProgress))
+            .filter(metadata::Column::Lot.eq(MetadataLot::Book))
+            .filter(seen::Column::MetadataId.is_not_in(linked))
+            .into_tuple::<i32>()
+            .all(&self.db)
+            .await?;
+        Ok(match reading.as_slice() {
+            [metadata_id] => Some(*metadata_id),
+            _ => None,
+        })
     }
 
-    pub async fn yank_integrations_data_for_user(&self, user_id: i32) -> Result<usize> {
-        if let Some(integrations) = user_by_id(&self.db, user_id).await?.yank_integrations {
-            let mut progress_updates = vec![];
-            for integration in integrations.0.iter() {
-                let response = match &integration.settings {
-                    UserYankIntegrationSetting::Audiobookshelf { base_url, token } => {
-                        self.get_integration_service()
-                            .audiobookshelf_progress(base_url, token)
-                            .await
-                    }
-                };
-                if let Ok(data) = response {
-                    progress_updates.extend(data);
-                }
-            }
-            let mut updated_count = 0;
-            for pu in progress_updates.into_iter() {
-                if self.integration_progress_update(pu, user_id).await.is_ok() {
-                    updated_count += 1
-                }
-            }
-            Ok(updated_count)
-        } else {
-            Ok(0)
-        }
+    async fn koreader_document_progress_update(
+        &self,
+        user_id: i32,
+        metadata_id: i32,
+        percentage: Decimal,
+    ) -> Result<()> {
+        let progress = (percentage * dec!(100))
+            .round()
+            .to_i32()
+            .unwrap_or_default();
+        let settings = self
+            .sink_integration_progress_settings(user_id, UserSinkIntegrationSettingKind::Koreader)
+            .await?;
+        let progress = match self.integration_progress(progress, &settings) {
+            Some(p) => p,
+            None => return Ok(()),
+        };
+        self.progress_update(
+            ProgressUpdateInput {
+                metadata_id,
+                progress: Some(progress),
+                date: Some(Utc::now().date_naive()),
+                show_season_number: None,
+                show_episode_number: None,
+                podcast_episode_number: None,
+                change_state: None,
+                context: Some("Ebook".to_owned()),
+            },
+            user_id,
+        )
+        .await
+        .ok();
+        Ok(())
     }
 
-    pub async fn yank_integrations_data(&self) -> Result<()> {
-        let users_with_integrations = User::find()
-            .filter(user::Column::YankIntegrations.is_not_null())
-            .select_only()
-            .column(user::Column::Id)
-            .into_tuple::<i32>()
+    async fn koreader_documents(&self, user_id: i32) -> Result<Vec<koreader_document::Model>> {
+        let documents = KoreaderDocument::find()
+            .filter(koreader_document::Column::UserId.eq(user_id))
+            .order_by_desc(koreader_document::Column::UpdatedOn)
             .all(&self.db)
             .await?;
-        for user_id in users_with_integrations {
-            self.yank_integrations_data_for_user(user_id).await?;
+        Ok(documents)
+    }
+
+    async fn link_koreader_document(
+        &self,
+        user_id: i32,
+        document_id: i32,
+        metadata_id: i32,
+    ) -> Result<bool> {
+        let document = KoreaderDocument::find_by_id(document_id)
+            .filter(koreader_document::Column::UserId.eq(user_id))
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| Error::new("This document does not belong to this user"))?;
+        let metadata = Metadata::find_by_id(metadata_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| Error::new("The media does not exist"))?;
+        if metadata.lot != MetadataLot::Book {
+            return Err(Error::new("Documents can only be linked to books"));
         }
-        Ok(())
+        let mut document: koreader_document::ActiveModel = document.into();
+        document.metadata_id = ActiveValue::Set(Some(metadata.id));
+        let document = document.update(&self.db).await?;
+        self.koreader_document_progress_update(user_id, metadata.id, document.percentage)
+            .await?;
+        Ok(true)
     }
 
-    async fn admin_account_guard(&self, user_id: i32) -> Result<()> {
-        let main_user = user_by_id(&self.db, user_id).await?;
-        if main_user.lot != UserLot::Admin {
-            return Err(Error::new("Only admins can perform this operation."));
-        }
-        Ok(())
+    async fn integration_mappings(&self, user_id: i32) -> Result<Vec<integration_mapping::Model>> {
+        let mappings = IntegrationMapping::find()
+            .filter(integration_mapping::Column::UserId.eq(user_id))
+            .order_by_desc(integration_mapping::Column::UpdatedOn)
+            .all(&self.db)
+            .await?;
+        Ok(mappings)
     }
 
-    async fn users_list(&self) -> Result<Vec<user::Model>> {
-        Ok(User::find()
-            .order_by_asc(user::Column::Id)
-            .all(&self.db)
-            .await?)
+    async fn link_integration_mapping(
+        &self,
+        user_id: i32,
+        mapping_id: i32,
+        metadata_id: i32,
+    ) -> Result<bool> {
+        let mapping = IntegrationMapping::find_by_id(mapping_id)
+            .filter(integration_mapping::Column::UserId.eq(user_id))
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| Error::new("This mapping does not belong to this user"))?;
+        let metadata = Metadata::find_by_id(metadata_id)
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| Error::new("The media does not exist"))?;
+        if metadata.lot != mapping.lot {
+            return Err(Error::new(format!(
+                "This mapping can only be linked to a {:?}",
+                mapping.lot
+            )));
+        }
+        let mut mapping: integration_mapping::ActiveModel = mapping.into();
+        mapping.metadata_id = ActiveValue::Set(Some(metadata.id));
+        mapping.error = ActiveValue::Set(None);
+        mapping.updated_on = ActiveValue::Set(Utc::now());
+        mapping.update(&self.db).await?;
+        Ok(true)
     }
 
-    async fn delete_user(&self, to_delete_user_id: i32) -> Result<bool> {
-        let maybe_user = User::find_by_id(to_delete_user_id).one(&self.db).await?;
-        if let Some(u) = maybe_user {
-            if self
-                .users_list()
-                .await?
-                .into_iter()
-                .filter(|u| u.lot == UserLot::Admin)
-                .collect_vec()
-                .len()
-                == 1
-                && u.lot == UserLot::Admin
-            {
-                return Ok(false);
+    /// Find the media that an item sent by an integration corresponds to. The
+    /// mappings of the user are consulted first, after which the identifier is
+    /// committed. Items that could not be found are recorded as unlinked
+    /// mappings so that they can be linked manually.
+    async fn integration_media_id(&self, pu: &IntegrationMedia, user_id: i32) -> Result<i32> {
+        let external_id = pu.external_id.clone().or_else(|| pu.identifier.clone());
+        let mapping = match &external_id {
+            Some(external_id) => {
+                IntegrationMapping::find()
+                    .filter(integration_mapping::Column::UserId.eq(user_id))
+                    .filter(integration_mapping::Column::Lot.eq(pu.lot))
+                    .filter(integration_mapping::Column::ExternalId.eq(external_id))
+                    .one(&self.db)
+                    .await?
             }
-            u.delete(&self.db).await?;
-            Ok(true)
-        } else {
-            Ok(false)
+            None => None,
+        };
+        if let Some(metadata_id) = mapping.and_then(|m| m.metadata_id) {
+            return Ok(metadata_id);
         }
+        let result = match &pu.identifier {
+            Some(identifier) => self
+                .commit_media_for_user(user_id, pu.lot, pu.source, identifier)
+                .await
+                .map(|IdObject { id }| id),
+            None => Err(Error::new("The integration did not send an identifier")),
+        };
+        if let (Err(e), Some(external_id)) = (&result, external_id) {
+            IntegrationMapping::insert(integration_mapping::ActiveModel {
+                user_id: ActiveValue::Set(user_id),
+                lot: ActiveValue::Set(pu.lot),
+                external_id: ActiveValue::Set(external_id),
+                metadata_id: ActiveValue::Set(None),
+                error: ActiveValue::Set(Some(e.message.clone())),
+                updated_on: ActiveValue::Set(Utc::now()),
+                ..Default::default()
+            })
+            .on_conflict(
+                OnConflict::columns([
+                    integration_mapping::Column::UserId,
+                    integration_mapping::Column::Lot,
+                    integration_mapping::Column::ExternalId,
+                ])
+                .update_columns([
+                    integration_mapping::Column::Error,
+                    integration_mapping::Column::UpdatedOn,
+                ])
+                .to_owned(),
+            )
+            .exec(&self.db)
+            .await?;
+        }
+        result
     }
 
-    pub async fn process_integration_webhook(
+    /// The settings of the first integration of this kind of the user, for the
+    /// integrations whose requests are not tied to one integration.
+    async fn sink_integration_progress_settings(
         &self,
-        user_hash_id: String,
-        integration: String,
-        payload: String,
-    ) -> Result<String> {
-        let integration = match integration.as_str() {
-            "jellyfin" => UserSinkIntegrationSettingKind::Jellyfin,
-            "plex" => UserSinkIntegrationSettingKind::Plex,
-            "kodi" => UserSinkIntegrationSettingKind::Kodi,
-            _ => return Err(anyhow!("Incorrect integration requested").into()),
-        };
-        let (user_hash, _) = user_hash_id
-            .split_once("--")
-            .ok_or(anyhow!("Unexpected format"))?;
-        let user_id = get_id_hasher(&self.config.integration.hasher_salt).decode(user_hash)?;
-        let user_id: i32 = user_id
-            .first()
-            .ok_or(anyhow!("Incorrect hash id provided"))?
-            .to_owned()
-            .try_into()?;
+        user_id: i32,
+        kind: UserSinkIntegrationSettingKind,
+    ) -> Result<IntegrationProgressSettings> {
         let user = user_by_id(&self.db, user_id).await?;
-        let integration = user
+        Ok(user
             .sink_integrations
             .0
             .into_iter()
-            .find(|i| match &i.settings {
-                UserSinkIntegrationSetting::Jellyfin { slug } => {
-                    slug == &user_hash_id && integration == UserSinkIntegrationSettingKind::Jellyfin
-                }
-                UserSinkIntegrationSetting::Plex { slug, .. } => {
-                    slug == &user_hash_id && integration == UserSinkIntegrationSettingKind::Plex
-                }
-                UserSinkIntegrationSetting::Kodi { slug } => {
-                    slug == &user_hash_id && integration == UserSinkIntegrationSettingKind::Kodi
-                }
-            })
-            .ok_or_else(|| Error::new("Webhook URL does not match".to_owned()))?;
-        let maybe_progress_update = match integration.settings {
-            UserSinkIntegrationSetting::Jellyfin { .. } => {
-                self.get_integration_service()
-                    .jellyfin_progress(&payload)
-                    .await
-            }
-            UserSinkIntegrationSetting::Plex { user, .. } => {
-                self.get_integration_service()
-                    .plex_progress(&payload, user, &self.db)
-                    .await
-            }
-            UserSinkIntegrationSetting::Kodi { .. } => {
-                self.get_integration_service().kodi_progress(&payload).await
-            }
-        };
-        match maybe_progress_update {
-            Ok(pu) => {
-                self.integration_progress_update(pu, user_id).await?;
-                Ok("Progress updated successfully".to_owned())
-            }
-            Err(e) => Err(Error::new(e.to_string())),
+            .find(|i| i.settings.kind() == kind)
+            .map(|i| i.progress_settings)
+            .unwrap_or_default())
+    }
+
+    /// The progress to save for the progress that an integration sent, or `None`
+    /// if it is too low to be saved.
+    fn integration_progress(
+        &self,
+        progress: i32,
+        settings: &IntegrationProgressSettings,
+    ) -> Option<i32> {
+        if progress < self.config.integration.minimum_progress_limit {
+            return None;
         }
+        let threshold = settings
+            .completion_threshold
+            .unwrap_or(self.config.integration.maximum_progress_limit);
+        Some(if progress >= threshold { 100 } else { progress })
     }
 
-    async fn integration_progress_update(&self, pu: IntegrationMedia, user_id: i32) -> Result<()> {
-        if pu.progress < self.config.integration.minimum_progress_limit {
+    async fn integration_progress_update(
+        &self,
+        pu: IntegrationMedia,
+        user_id: i32,
+        settings: &IntegrationProgressSettings,
+    ) -> Result<()> {
+        if pu.paused && settings.ignore_pauses {
             return Ok(());
         }
-        let progress = if pu.progress > self.config.integration.maximum_progress_limit {
-            100
-        } else {
-            pu.progress
+        if let (Some(minimum), Some(position)) = (settings.minimum_duration, pu.position) {
+            if position < minimum {
+                return Ok(());
+            }
+        }
+        let progress = match self.integration_progress(pu.progress, settings) {
+            Some(p) => p,
+            None => return Ok(()),
         };
-        let IdObject { id } = self.commit_media(pu.lot, pu.source, &pu.identifier).await?;
+        let metadata_id = self.integration_media_id(&pu, user_id).await?;
         self.progress_update(
             ProgressUpdateInput {
-                metadata_id: id,
+                metadata_id,
                 progress: Some(progress),
                 date: Some(Utc::now().date_naive()),
                 show_season_number: pu.show_season_number,
                 show_episode_number: pu.show_episode_number,
                 podcast_episode_number: pu.podcast_episode_number,
                 change_state: None,
+                context: None,
             },
             user_id,
         )
@@ -5132,14 +10127,7 @@ impl MiscellaneousService {
         user_id: i32,
         msg: &str,
     ) -> Result<bool> {
-        let user = user_by_id(&self.db, user_id).await?;
-        let mut success = true;
-        for notification in user.notifications.0 {
-            if notification.settings.send_message(msg).await.is_err() {
-                success = false;
-            }
-        }
-        Ok(success)
+        send_notifications_to_user_platforms(&self.db, user_id, msg).await
     }
 
     /// Given a metadata id, get all the users that need to be sent notifications
@@ -5181,6 +10169,7 @@ impl MiscellaneousService {
         for metadata_id in Metadata::find()
             .select_only()
             .column(metadata::Column::Id)
+            .filter(metadata::Column::IsDeletedOnProvider.eq(false))
             .into_tuple::<i32>()
             .all(&self.db)
             .await?
@@ -5271,6 +10260,44 @@ impl MiscellaneousService {
         Ok(new_monitored_value)
     }
 
+    async fn favorite_metadata_ids(&self, user_id: i32) -> Result<Vec<i32>> {
+        let favorites = MetadataToCollection::find()
+            .inner_join(Collection)
+            .filter(collection::Column::UserId.eq(user_id))
+            .filter(collection::Column::Name.eq(DefaultCollection::Favorites.to_string()))
+            .all(&self.db)
+            .await?;
+        Ok(favorites.into_iter().map(|m| m.metadata_id).collect())
+    }
+
+    async fn toggle_media_favorite(&self, user_id: i32, metadata_id: i32) -> Result<bool> {
+        // DEV: Users created before favorites were introduced do not have this
+        // collection yet, so it is created the first time it is needed.
+        let collection = self
+            .create_or_update_collection(
+                user_id,
+                CreateOrUpdateCollectionInput {
+                    name: DefaultCollection::Favorites.to_string(),
+                    description: Some(DefaultCollection::Favorites.meta().to_owned()),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let existing = MetadataToCollection::find_by_id((metadata_id, collection.id))
+            .one(&self.db)
+            .await?;
+        if let Some(e) = existing {
+            e.delete(&self.db).await?;
+            return Ok(false);
+        }
+        let favorite = metadata_to_collection::ActiveModel {
+            metadata_id: ActiveValue::Set(metadata_id),
+            collection_id: ActiveValue::Set(collection.id),
+        };
+        favorite.insert(&self.db).await?;
+        Ok(true)
+    }
+
     async fn get_monitored_status(
         &self,
         user_id: i32,
@@ -5388,11 +10415,16 @@ impl MiscellaneousService {
         })
     }
 
-    async fn creator_details(&self, creator_id: i32) -> Result<CreatorDetails> {
+    async fn creator_details(
+        &self,
+        user_id: Option<i32>,
+        creator_id: i32,
+    ) -> Result<CreatorDetails> {
         let details = Creator::find_by_id(creator_id)
             .one(&self.db)
             .await?
             .unwrap();
+        let hide_nsfw = self.user_nsfw_display(user_id).await? == UserNsfwDisplay::Hide;
         let associations = MetadataToCreator::find()
             .filter(metadata_to_creator::Column::CreatorId.eq(creator_id))
             .find_also_related(Metadata)
@@ -5402,6 +10434,9 @@ impl MiscellaneousService {
         let mut contents: HashMap<_, Vec<_>> = HashMap::new();
         for (assoc, metadata) in associations {
             let m = metadata.unwrap();
+            if m.is_nsfw && hide_nsfw {
+                continue;
+            }
             let image = if let Some(imgs) = m.images {
                 if let Some(i) = imgs.0.first() {
                     Some(get_stored_asset(i.url.clone(), &self.file_storage_service).await)
@@ -5434,7 +10469,11 @@ impl MiscellaneousService {
         Ok(CreatorDetails { details, contents })
     }
 
-    async fn metadata_group_details(&self, metadata_group_id: i32) -> Result<MetadataGroupDetails> {
+    async fn metadata_group_details(
+        &self,
+        user_id: Option<i32>,
+        metadata_group_id: i32,
+    ) -> Result<MetadataGroupDetails> {
         let mut group = MetadataGroup::find_by_id(metadata_group_id)
             .one(&self.db)
             .await?
@@ -5452,10 +10491,16 @@ impl MiscellaneousService {
             | MetadataSource::Anilist
             | MetadataSource::Listennotes
             | MetadataSource::Itunes
+            | MetadataSource::PodcastIndex
+            | MetadataSource::MangaDex
             | MetadataSource::MangaUpdates
             | MetadataSource::Mal
             | MetadataSource::Openlibrary
             | MetadataSource::Vndb
+            | MetadataSource::Web
+            | MetadataSource::MusicBrainz
+            | MetadataSource::BoardGameGeek
+            | MetadataSource::ComicVine
             | MetadataSource::GoogleBooks => None,
             MetadataSource::Audible => Some(format!(
                 "https://www.audible.com/series/{slug}/{identifier}"
@@ -5474,12 +10519,23 @@ impl MiscellaneousService {
             .into_tuple::<i32>()
             .all(&self.db)
             .await?;
-        let contents = PartialMetadataModel::find()
+        let mut contents = PartialMetadataModel::find()
             .filter(partial_metadata::Column::Id.is_in(associations))
             .left_join(PartialMetadataToMetadataGroup)
             .order_by_asc(partial_metadata_to_metadata_group::Column::Part)
             .all(&self.db)
             .await?;
+        if self.user_nsfw_display(user_id).await? == UserNsfwDisplay::Hide {
+            let nsfw: Vec<i32> = Metadata::find()
+                .filter(metadata::Column::Id.is_in(contents.iter().filter_map(|c| c.metadata_id)))
+                .filter(metadata::Column::IsNsfw.eq(true))
+                .select_only()
+                .column(metadata::Column::Id)
+                .into_tuple()
+                .all(&self.db)
+                .await?;
+            contents.retain(|c| c.metadata_id.map_or(true, |id| !nsfw.contains(&id)));
+        }
         Ok(MetadataGroupDetails {
             details: group,
             source_url,
@@ -5576,6 +10632,7 @@ impl MiscellaneousService {
                         show_season_number,
                         show_episode_number,
                         podcast_episode_number,
+                        state: Some(s.state),
                     }
                 })
                 .collect();
@@ -5597,14 +10654,30 @@ impl MiscellaneousService {
                 .into_iter()
                 .map(|c| c.name)
                 .collect();
+            let highlights = m
+                .find_related(Highlight)
+                .filter(highlight::Column::UserId.eq(user_id))
+                .order_by_asc(highlight::Column::Id)
+                .all(&self.db)
+                .await?
+                .into_iter()
+                .map(|h| ImportOrExportItemHighlight {
+                    text: h.text,
+                    page: h.page,
+                    chapter: h.chapter,
+                    note: h.note,
+                })
+                .collect();
             let exp = ImportOrExportMediaItem {
                 source_id: m.id.to_string(),
+                title: Some(m.title),
                 lot: m.lot,
                 source: m.source,
                 identifier: m.identifier,
                 seen_history,
                 reviews,
                 collections,
+                highlights,
             };
             resp.push(exp);
         }
@@ -5612,6 +10685,218 @@ impl MiscellaneousService {
         Ok(resp)
     }
 
+    pub async fn export_media_csv(&self, user_id: i32, lot: MetadataLot) -> Result<String> {
+        let rows = self.media_csv_rows(user_id, lot).await?;
+        Ok(media_to_csv(lot, rows)?)
+    }
+
+    pub async fn export_letterboxd_csv(&self, user_id: i32) -> Result<String> {
+        let mut rows = self.media_csv_rows(user_id, MetadataLot::Movie).await?;
+        rows.retain(|r| r.progress == 100);
+        // DEV: Movies that were rated without being marked as seen are added to the
+        // ratings of Letterboxd without a diary entry
+        let rated = Review::find()
+            .filter(review::Column::UserId.eq(user_id))
+            .filter(review::Column::Rating.is_not_null())
+            .find_also_related(Metadata)
+            .filter(metadata::Column::Lot.eq(MetadataLot::Movie))
+            .order_by_desc(review::Column::PostedOn)
+            .all(&self.db)
+            .await?;
+        let mut exported = rows
+            .iter()
+            .map(|r| r.identifier.clone())
+            .collect::<HashSet<_>>();
+        for (r, m) in rated {
+            let m = m.unwrap();
+            if !exported.insert(m.identifier.clone()) {
+                continue;
+            }
+            rows.push(MediaCsvRow {
+                title: m.title,
+                publish_year: m.publish_year,
+                source: m.source,
+                identifier: m.identifier,
+                started_on: None,
+                finished_on: None,
+                progress: 0,
+                show_season_number: None,
+                show_episode_number: None,
+                podcast_episode_number: None,
+                rating: r.rating,
+                review: r.text,
+            });
+        }
+        Ok(media_to_letterboxd_csv(rows)?)
+    }
+
+    pub async fn export_mal_xml(&self, user_id: i32, lot: MetadataLot) -> Result<String> {
+        let seen = Seen::find()
+            .filter(seen::Column::UserId.eq(user_id))
+            .find_also_related(Metadata)
+            .filter(metadata::Column::Lot.eq(lot))
+            .filter(metadata::Column::Source.eq(MetadataSource::Mal))
+            .order_by_asc(seen::Column::LastUpdatedOn)
+            .all(&self.db)
+            .await?;
+        let watchlist_ids = MetadataToCollection::find()
+            .inner_join(Collection)
+            .filter(collection::Column::UserId.eq(user_id))
+            .filter(collection::Column::Name.eq(DefaultCollection::Watchlist.to_string()))
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|m| m.metadata_id)
+            .collect_vec();
+        let watchlist = Metadata::find()
+            .filter(metadata::Column::Id.is_in(watchlist_ids))
+            .filter(metadata::Column::Lot.eq(lot))
+            .filter(metadata::Column::Source.eq(MetadataSource::Mal))
+            .all(&self.db)
+            .await?;
+        let ratings = Review::find()
+            .filter(review::Column::UserId.eq(user_id))
+            .filter(review::Column::Rating.is_not_null())
+            .order_by_desc(review::Column::PostedOn)
+            .all(&self.db)
+            .await?;
+        let mut items: Vec<(i32, MalXmlItem)> = vec![];
+        let metadata = seen
+            .iter()
+            .map(|(_, m)| m.clone().unwrap())
+            .chain(watchlist)
+            .unique_by(|m| m.id);
+        for m in metadata {
+            let total = match &m.specifics {
+                MediaSpecifics::Anime(a) => a.episodes,
+                MediaSpecifics::Manga(a) => a.chapters,
+                _ => None,
+            };
+            items.push((
+                m.id,
+                MalXmlItem {
+                    identifier: m.identifier,
+                    title: m.title,
+                    total,
+                    progress: 0,
+                    state: None,
+                    started_on: None,
+                    finished_on: None,
+                    times_completed: 0,
+                    rating: ratings
+                        .iter()
+                        .find(|r| r.metadata_id == Some(m.id))
+                        .and_then(|r| r.rating),
+                },
+            ));
+        }
+        // DEV: MAL only has a single entry per media, so the latest seen entry
+        // decides its state
+        for (s, _) in seen {
+            let (_, item) = items
+                .iter_mut()
+                .find(|(id, _)| *id == s.metadata_id)
+                .unwrap();
+            if s.state == SeenState::Completed {
+                item.times_completed += 1;
+            }
+            item.progress = s.progress;
+            item.state = Some(s.state);
+            item.started_on = s.started_on;
+            item.finished_on = s.finished_on;
+            item.rating = s.rating.or(item.rating);
+        }
+        let items = items.into_iter().map(|(_, i)| i).collect();
+        Ok(media_to_mal_xml(lot, items)?)
+    }
+
+    async fn media_csv_rows(&self, user_id: i32, lot: MetadataLot) -> Result<Vec<MediaCsvRow>> {
+        let mut seen = Seen::find()
+            .filter(seen::Column::UserId.eq(user_id))
+            .find_also_related(Metadata)
+            .filter(metadata::Column::Lot.eq(lot))
+            .order_by_asc(seen::Column::FinishedOn)
+            .all(&self.db)
+            .await?;
+        let metadata_ids = seen
+            .iter()
+            .map(|(s, _)| s.metadata_id)
+            .unique()
+            .collect_vec();
+        let reviews = Review::find()
+            .filter(review::Column::UserId.eq(user_id))
+            .filter(review::Column::MetadataId.is_in(metadata_ids))
+            .order_by_desc(review::Column::PostedOn)
+            .all(&self.db)
+            .await?;
+        let mut rows = vec![];
+        for (s, m) in seen.iter_mut() {
+            let m = m.as_ref().unwrap();
+            modify_seen_elements(std::slice::from_mut(s));
+            // DEV: Use the latest review of the same episode, falling back to the one
+            // for the entire media
+            let review = reviews
+                .iter()
+                .filter(|r| r.metadata_id == Some(m.id))
+                .find(|r| r.extra_information == s.extra_information)
+                .or_else(|| {
+                    reviews
+                        .iter()
+                        .find(|r| r.metadata_id == Some(m.id) && r.extra_information.is_none())
+                });
+            rows.push(MediaCsvRow {
+                title: m.title.clone(),
+                publish_year: m.publish_year,
+                source: m.source,
+                identifier: m.identifier.clone(),
+                started_on: s.started_on,
+                finished_on: s.finished_on,
+                progress: s.progress,
+                show_season_number: s.show_information.as_ref().map(|i| i.season),
+                show_episode_number: s.show_information.as_ref().map(|i| i.episode),
+                podcast_episode_number: s.podcast_information.as_ref().map(|i| i.episode),
+                rating: s.rating.or_else(|| review.and_then(|r| r.rating)),
+                review: review.and_then(|r| r.text.clone()),
+            });
+        }
+        Ok(rows)
+    }
+
+    pub async fn export_highlights_markdown(&self, user_id: i32) -> Result<String> {
+        let highlights = Highlight::find()
+            .filter(highlight::Column::UserId.eq(user_id))
+            .find_also_related(Metadata)
+            .order_by_asc(metadata::Column::Title)
+            .order_by_asc(highlight::Column::MetadataId)
+            .order_by_asc(highlight::Column::Page)
+            .order_by_asc(highlight::Column::Id)
+            .all(&self.db)
+            .await?;
+        let mut items: Vec<(i32, HighlightsMarkdownItem)> = vec![];
+        for (h, m) in highlights {
+            let m = m.unwrap();
+            let highlight = ImportOrExportItemHighlight {
+                text: h.text,
+                page: h.page,
+                chapter: h.chapter,
+                note: h.note,
+            };
+            match items.last_mut() {
+                Some((id, item)) if *id == m.id => item.highlights.push(highlight),
+                _ => items.push((
+                    m.id,
+                    HighlightsMarkdownItem {
+                        title: m.title,
+                        highlights: vec![highlight],
+                    },
+                )),
+            }
+        }
+        Ok(highlights_to_markdown(
+            items.into_iter().map(|(_, i)| i).collect(),
+        ))
+    }
+
     pub async fn export_people(&self, user_id: i32) -> Result<Vec<ImportOrExportPersonItem>> {
         let mut resp: Vec<ImportOrExportPersonItem> = vec![];
         let all_reviews = Review::find()
@@ -5700,6 +10985,82 @@ impl MiscellaneousService {
         Ok(true)
     }
 
+    pub async fn deploy_database_maintenance_job(&self) -> Result<bool> {
+        self.perform_application_job
+            .clone()
+            .push(ApplicationJob::DatabaseMaintenance)
+            .await?;
+        Ok(true)
+    }
+
+    async fn database_size(&self) -> Option<i64> {
+        let sql = match self.db.get_database_backend() {
+            DatabaseBackend::Postgres => "SELECT pg_database_size(current_database())",
+            DatabaseBackend::Sqlite => {
+                "SELECT page_count * page_size FROM pragma_page_count(), pragma_page_size()"
+            }
+            DatabaseBackend::MySql => {
+                "SELECT CAST(SUM(data_length + index_length) AS SIGNED) FROM information_schema.tables WHERE table_schema = DATABASE()"
+            }
+        };
+        let stmt = Statement::from_string(self.db.get_database_backend(), sql);
+        self.db
+            .query_one(stmt)
+            .await
+            .ok()
+            .flatten()
+            .and_then(|qr| qr.try_get_by_index::<i64>(0).ok())
+    }
+
+    /// Update the statistics of the query planner and rebuild the indexes of the
+    /// tables that are searched, after removing media without any user activity.
+    #[instrument(skip(self))]
+    pub async fn perform_database_maintenance(&self) -> Result<()> {
+        let started_on = Utc::now();
+        let size_before = self.database_size().await;
+        AuditLog::delete_many()
+            .filter(
+                audit_log::Column::CreatedOn
+                    .lt(Utc::now() - ChronoDuration::days(AUDIT_LOG_RETENTION_DAYS)),
+            )
+            .exec(&self.db)
+            .await?;
+        let metadata_before = Metadata::find().count(&self.db).await?;
+        self.cleanup_data_without_associated_user_activities()
+            .await?;
+        let metadata_after = Metadata::find().count(&self.db).await?;
+        let statements = match self.db.get_database_backend() {
+            DatabaseBackend::Postgres => vec![
+                "VACUUM ANALYZE",
+                "REINDEX TABLE metadata",
+                "REINDEX TABLE creator",
+            ],
+            DatabaseBackend::Sqlite => vec!["VACUUM", "ANALYZE", "REINDEX"],
+            DatabaseBackend::MySql => vec![
+                "ANALYZE TABLE metadata, creator, seen, review",
+                "OPTIMIZE TABLE metadata, creator",
+            ],
+        };
+        for sql in statements {
+            tracing::trace!("Running maintenance statement {:?}", sql);
+            self.db.execute_unprepared(sql).await?;
+        }
+        let size_after = self.database_size().await;
+        let report = DatabaseMaintenanceReport {
+            started_on,
+            finished_on: Utc::now(),
+            size_before,
+            size_after,
+            reclaimed: size_before
+                .zip(size_after)
+                .map(|(before, after)| (before - after).max(0)),
+            metadata_removed: metadata_before.saturating_sub(metadata_after),
+        };
+        tracing::debug!("Database maintenance finished: {:?}", report);
+        *self.last_maintenance_report.lock().unwrap() = Some(report);
+        Ok(())
+    }
+
     #[instrument(skip(self))]
     pub async fn recalculate_calendar_events(&self) -> Result<()> {
         let mut calendar_stream = CalendarEvent::find()
@@ -5849,6 +11210,15 @@ impl MiscellaneousService {
     }
 }
 
+/// Ratings are stored out of 100 in the database.
+fn rating_in_review_scale(rating: Decimal, review_scale: UserReviewScale) -> Decimal {
+    let divisor = match review_scale {
+        UserReviewScale::OutOfFive => dec!(20),
+        UserReviewScale::OutOfHundred => dec!(1),
+    };
+    (rating / divisor).round_dp(1)
+}
+
 fn modify_seen_elements(all_seen: &mut [seen::Model]) {
     all_seen.iter_mut().for_each(|s| {
         if let Some(i) = s.extra_information.as_ref() {
@@ -17,10 +17,10 @@ use serde_with::skip_serializing_none;
 use specta::Type;
 
 use crate::{
-    entities::{exercise::Model as ExerciseModel, metadata_group, user_measurement},
+    entities::{audit_log, exercise::Model as ExerciseModel, metadata_group, user_measurement},
     migrator::{
-        ExerciseEquipment, ExerciseForce, ExerciseLevel, ExerciseMechanic, ExerciseMuscle,
-        MetadataLot, MetadataSource, SeenState,
+        ExerciseEquipment, ExerciseForce, ExerciseLevel, ExerciseLot, ExerciseMechanic,
+        ExerciseMuscle, MetadataLot, MetadataSource, SeenState,
     },
 };
 
@@ -70,6 +70,8 @@ pub struct SearchDetails {
     params(media::MetadataGroupListItem)
 ))]
 #[graphql(concrete(name = "ExerciseSearchResults", params(ExerciseModel)))]
+#[graphql(concrete(name = "AuditLogResults", params(audit_log::Model)))]
+#[graphql(concrete(name = "SeasonalAnimeResults", params(media::SeasonalAnimeItem)))]
 pub struct SearchResults<T: OutputType> {
     pub details: SearchDetails,
     pub items: Vec<T>,
@@ -87,12 +89,16 @@ pub mod media {
     pub struct MediaSearchItemWithLot {
         pub details: MediaSearchItem,
         pub lot: MetadataLot,
+        /// Whether the item is NSFW and needs to be clicked through before it is shown.
+        pub blur: bool,
     }
 
     #[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
     pub struct MediaSearchItemResponse {
         pub item: MediaSearchItem,
         pub database_id: Option<i32>,
+        /// Whether the item is NSFW and needs to be clicked through before it is shown.
+        pub blur: bool,
     }
 
     #[derive(
@@ -122,18 +128,44 @@ pub mod media {
         pub media_count: i64,
     }
 
+    #[derive(Debug, Serialize, Deserialize, Enum, Clone, Copy, PartialEq, Eq)]
+    pub enum CollectionActionLot {
+        /// Monitor the media so that the user is notified about its releases.
+        Monitor,
+        /// Set a reminder for the day the media is released, if it has not been
+        /// released yet.
+        RemindOnRelease,
+        /// Also add the media to another collection of the user.
+        AddToCollection,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, SimpleObject, InputObject, Clone, PartialEq, Eq)]
+    #[graphql(input_name = "CollectionActionInput")]
+    pub struct CollectionAction {
+        pub lot: CollectionActionLot,
+        /// The name of the other collection, used by `AddToCollection`.
+        pub collection_name: Option<String>,
+    }
+
+    #[derive(Clone, Debug, PartialEq, FromJsonQueryResult, Eq, Serialize, Deserialize, Default)]
+    pub struct CollectionActions(pub Vec<CollectionAction>);
+
     #[derive(Debug, InputObject, Default)]
     pub struct CreateOrUpdateCollectionInput {
         pub name: String,
         pub description: Option<String>,
         pub visibility: Option<Visibility>,
         pub update_id: Option<i32>,
+        /// The actions that are run when a media is added to this collection.
+        pub actions: Option<Vec<CollectionAction>>,
     }
 
     #[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
     pub struct MediaListItem {
         pub data: MediaSearchItem,
         pub average_rating: Option<Decimal>,
+        /// Whether the item is NSFW and needs to be clicked through before it is shown.
+        pub blur: bool,
     }
 
     #[derive(Debug, Serialize, Deserialize, SimpleObject, Clone, FromQueryResult)]
@@ -356,6 +388,8 @@ pub mod media {
     #[graphql(input_name = "VideoGameSpecificsInput")]
     pub struct VideoGameSpecifics {
         pub platforms: Vec<String>,
+        /// The number of achievements of the game, as synced by an integration.
+        pub achievements: Option<i32>,
     }
 
     #[derive(
@@ -375,6 +409,67 @@ pub mod media {
         pub length: Option<i32>,
     }
 
+    #[derive(
+        Debug,
+        PartialEq,
+        Eq,
+        Serialize,
+        Deserialize,
+        SimpleObject,
+        Clone,
+        Default,
+        FromJsonQueryResult,
+        InputObject,
+    )]
+    #[graphql(input_name = "ArticleSpecificsInput")]
+    pub struct ArticleSpecifics {
+        /// The name of the website the article was published on.
+        pub site_name: Option<String>,
+        pub words: Option<i32>,
+        /// The estimated time to read the article, in minutes.
+        pub reading_time: Option<i32>,
+    }
+
+    #[derive(
+        Debug,
+        PartialEq,
+        Eq,
+        Serialize,
+        Deserialize,
+        SimpleObject,
+        Clone,
+        Default,
+        FromJsonQueryResult,
+        InputObject,
+    )]
+    #[graphql(input_name = "BoardGameSpecificsInput")]
+    pub struct BoardGameSpecifics {
+        pub min_players: Option<i32>,
+        pub max_players: Option<i32>,
+        /// The time it takes to play a game, in minutes.
+        pub playtime: Option<i32>,
+    }
+
+    #[derive(
+        Debug,
+        PartialEq,
+        Eq,
+        Serialize,
+        Deserialize,
+        SimpleObject,
+        Clone,
+        Default,
+        FromJsonQueryResult,
+        InputObject,
+    )]
+    #[graphql(input_name = "MusicSpecificsInput")]
+    pub struct MusicSpecifics {
+        /// The number of tracks on the album.
+        pub tracks: Option<i32>,
+        /// The total length of the album, in minutes.
+        pub duration: Option<i32>,
+    }
+
     #[derive(
         Debug,
         PartialEq,
@@ -392,6 +487,24 @@ pub mod media {
         pub episodes: Option<i32>,
     }
 
+    #[derive(
+        Debug,
+        PartialEq,
+        Eq,
+        Serialize,
+        Deserialize,
+        SimpleObject,
+        Clone,
+        Default,
+        FromJsonQueryResult,
+        InputObject,
+    )]
+    #[graphql(input_name = "ComicSpecificsInput")]
+    pub struct ComicSpecifics {
+        /// The number of issues in the volume.
+        pub issues: Option<i32>,
+    }
+
     #[derive(
         Debug,
         PartialEq,
@@ -419,6 +532,64 @@ pub mod media {
         pub publish_year: Option<i32>,
     }
 
+    /// The season of the year in which an anime started airing.
+    #[derive(Debug, Serialize, Deserialize, Enum, Clone, Copy, PartialEq, Eq)]
+    pub enum AnimeSeason {
+        Winter,
+        Spring,
+        Summer,
+        Fall,
+    }
+
+    /// An anime that started airing in a season, along with what the user has
+    /// already done with it.
+    #[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
+    pub struct SeasonalAnimeItem {
+        pub details: MediaSearchItemResponse,
+        /// The state of the latest seen entry of the user, if they have seen it.
+        pub state: Option<SeenState>,
+        /// Whether the anime is in the watchlist of the user.
+        pub in_watchlist: bool,
+    }
+
+    /// A media item that has not been released yet.
+    #[derive(Debug, Serialize, Deserialize, SimpleObject, Clone)]
+    pub struct MediaUpcomingItem {
+        pub item: MediaSearchItem,
+        pub release_date: NaiveDate,
+        pub genres: Vec<String>,
+        pub creators: Vec<String>,
+        /// Whether the provider marks the item as NSFW, if it says so.
+        pub is_nsfw: Option<bool>,
+    }
+
+    /// Filters that are passed through to the provider while searching. Providers
+    /// ignore the ones they do not support.
+    #[derive(Debug, Serialize, Deserialize, InputObject, Clone, Default)]
+    pub struct MediaSearchFilters {
+        /// Only return media released in or after this year.
+        pub year_from: Option<i32>,
+        /// Only return media released in or before this year.
+        pub year_to: Option<i32>,
+        /// The language the media was published in, eg: `eng` for Openlibrary.
+        pub language: Option<String>,
+        /// The format of the media, eg: `TV` or `MOVIE` for anime.
+        pub format: Option<String>,
+        /// The platform a video game was released on, eg: `PlayStation 5`.
+        pub platform: Option<String>,
+    }
+
+    impl MediaSearchFilters {
+        /// The year to filter by, for providers that can only filter by a single
+        /// year instead of a range.
+        pub fn exact_year(&self) -> Option<i32> {
+            match (self.year_from, self.year_to) {
+                (Some(from), Some(to)) if from == to => Some(from),
+                _ => None,
+            }
+        }
+    }
+
     #[derive(
         Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize, Enum,
     )]
@@ -488,9 +659,61 @@ pub mod media {
         Deserialize,
         FromJsonQueryResult,
     )]
+    pub struct BoardGamesSummary {
+        pub played: i32,
+        pub runtime: i32,
+    }
+
+    #[derive(
+        SimpleObject,
+        Debug,
+        PartialEq,
+        Eq,
+        Clone,
+        Default,
+        Serialize,
+        Deserialize,
+        FromJsonQueryResult,
+    )]
+    pub struct MusicSummary {
+        pub listened: i32,
+        pub runtime: i32,
+    }
+
+    #[derive(
+        SimpleObject,
+        Debug,
+        PartialEq,
+        Eq,
+        Clone,
+        Default,
+        Serialize,
+        Deserialize,
+        FromJsonQueryResult,
+    )]
+    pub struct ArticlesSummary {
+        pub read: i32,
+        pub runtime: i32,
+    }
+
+    #[derive(
+        SimpleObject,
+        Debug,
+        PartialEq,
+        Eq,
+        Clone,
+        Default,
+        Serialize,
+        Deserialize,
+        FromJsonQueryResult,
+    )]
+    #[serde(default)]
     pub struct BooksSummary {
         pub pages: i32,
         pub read: i32,
+        /// The estimated time spent reading, based on the reading speed in the
+        /// preferences.
+        pub runtime: i32,
     }
 
     #[derive(
@@ -560,6 +783,22 @@ pub mod media {
         pub read: i32,
     }
 
+    #[derive(
+        SimpleObject,
+        Debug,
+        PartialEq,
+        Eq,
+        Clone,
+        Default,
+        Serialize,
+        Deserialize,
+        FromJsonQueryResult,
+    )]
+    pub struct ComicsSummary {
+        pub issues: i32,
+        pub read: i32,
+    }
+
     #[derive(
         SimpleObject,
         Debug,
@@ -598,8 +837,14 @@ pub mod media {
         pub audio_books: AudioBooksSummary,
         pub anime: AnimeSummary,
         pub manga: MangaSummary,
+        pub articles: ArticlesSummary,
+        pub music: MusicSummary,
+        pub board_games: BoardGamesSummary,
+        pub comics: ComicsSummary,
         pub reviews_posted: u64,
         pub creators_interacted_with: usize,
+        /// The number of media items that the user has marked as a favorite.
+        pub favorites: usize,
     }
 
     #[derive(
@@ -617,6 +862,11 @@ pub mod media {
     pub struct UserFitnessSummary {
         pub measurements_recorded: u64,
         pub workouts_recorded: u64,
+        /// The total weight lifted, in kilograms.
+        pub total_volume: Decimal,
+        /// The total distance covered, in kilometers.
+        pub total_distance: Decimal,
+        pub personal_bests_achieved: usize,
     }
 
     #[derive(
@@ -660,6 +910,17 @@ pub mod media {
         pub podcast_episode_number: Option<i32>,
     }
 
+    #[derive(Debug, InputObject, Default)]
+    pub struct CreateOrUpdateHighlightInput {
+        /// ID of the highlight if this is an update to an existing highlight
+        pub highlight_id: Option<i32>,
+        pub metadata_id: i32,
+        pub text: String,
+        pub page: Option<i32>,
+        pub chapter: Option<String>,
+        pub note: Option<String>,
+    }
+
     #[derive(Debug, Serialize, Deserialize, InputObject, Clone)]
     pub struct ProgressUpdateInput {
         pub metadata_id: i32,
@@ -669,6 +930,9 @@ pub mod media {
         pub show_episode_number: Option<i32>,
         pub podcast_episode_number: Option<i32>,
         pub change_state: Option<SeenState>,
+        /// Where or how the media was seen. Must be one of the contexts that the
+        /// user has configured for the type of media.
+        pub context: Option<String>,
     }
 
     #[derive(Enum, Clone, Debug, Copy, PartialEq, Eq)]
@@ -703,6 +967,8 @@ pub mod media {
         pub identifier: String,
         pub is_nsfw: Option<bool>,
         pub title: String,
+        #[serde(default)]
+        pub alternative_titles: Vec<String>,
         pub source: MetadataSource,
         pub description: Option<String>,
         pub lot: MetadataLot,
@@ -719,6 +985,41 @@ pub mod media {
         pub provider_rating: Option<Decimal>,
     }
 
+    impl MediaDetails {
+        /// Whether any of the details that a fallback source can fill in are
+        /// missing.
+        pub fn is_incomplete(&self) -> bool {
+            self.description.is_none()
+                || self.images.is_empty()
+                || self.genres.is_empty()
+                || self.creators.is_empty()
+                || self.publish_year.is_none()
+        }
+
+        /// Fill in the missing details using the ones of the same media item from
+        /// another source. The details that are already present are kept as is.
+        pub fn merge_missing(&mut self, other: MediaDetails) {
+            if self.description.is_none() {
+                self.description = other.description;
+            }
+            if self.images.is_empty() {
+                self.images = other.images;
+            }
+            if self.genres.is_empty() {
+                self.genres = other.genres;
+            }
+            if self.creators.is_empty() {
+                self.creators = other.creators;
+            }
+            if self.publish_year.is_none() {
+                self.publish_year = other.publish_year;
+            }
+            if self.publish_date.is_none() {
+                self.publish_date = other.publish_date;
+            }
+        }
+    }
+
     #[derive(Debug, Serialize, Deserialize, Clone)]
     #[serde(untagged)]
     pub enum ImportOrExportItemIdentifier {
@@ -742,6 +1043,8 @@ pub mod media {
         pub show_episode_number: Option<i32>,
         /// If for a podcast, the episode which was seen.
         pub podcast_episode_number: Option<i32>,
+        /// The state to leave the media in if it was not finished, eg: dropped.
+        pub state: Option<SeenState>,
     }
 
     #[derive(Debug, Serialize, Deserialize, Clone, Type, Default)]
@@ -770,11 +1073,27 @@ pub mod media {
         pub comments: Option<Vec<ImportOrExportItemReviewComment>>,
     }
 
+    #[derive(Debug, Serialize, Deserialize, Clone, Type, Default)]
+    pub struct ImportOrExportItemHighlight {
+        /// The passage that was highlighted.
+        pub text: String,
+        /// The page the passage is on.
+        pub page: Option<i32>,
+        /// The chapter the passage is in.
+        pub chapter: Option<String>,
+        /// The note that the user wrote about the passage.
+        pub note: Option<String>,
+    }
+
     /// Details about a specific media item that needs to be imported or exported.
     #[derive(Debug, Serialize, Deserialize, Clone, Type)]
     pub struct ImportOrExportMediaItem<T> {
         /// An string to help identify it in the original source.
         pub source_id: String,
+        /// The title of the media in the original source. It is used to find the
+        /// media in the database when the provider does not have it.
+        #[serde(default)]
+        pub title: Option<String>,
         /// The type of media.
         pub lot: MetadataLot,
         /// The source of media.
@@ -787,6 +1106,9 @@ pub mod media {
         pub reviews: Vec<ImportOrExportItemRating>,
         /// The collections to add this media to.
         pub collections: Vec<String>,
+        /// The highlights that the user made in this media.
+        #[serde(default)]
+        pub highlights: Vec<ImportOrExportItemHighlight>,
     }
 
     /// Complete export of the user.
@@ -821,6 +1143,10 @@ pub mod media {
         VisualNovel(VisualNovelSpecifics),
         Anime(AnimeSpecifics),
         Manga(MangaSpecifics),
+        Article(ArticleSpecifics),
+        Music(MusicSpecifics),
+        BoardGame(BoardGameSpecifics),
+        Comic(ComicSpecifics),
         #[default]
         Unknown,
     }
@@ -889,6 +1215,83 @@ pub mod media {
     #[derive(Clone, Debug, PartialEq, FromJsonQueryResult, Eq, Serialize, Deserialize, Default)]
     pub struct MetadataVideos(pub Vec<MetadataVideo>);
 
+    #[derive(Clone, Debug, PartialEq, FromJsonQueryResult, Eq, Serialize, Deserialize, Default)]
+    pub struct MetadataAlternativeTitles(pub Vec<String>);
+
+    impl MetadataAlternativeTitles {
+        /// Remove empty titles, duplicates and anything that is the same as the
+        /// main title.
+        pub fn new(title: &str, titles: Vec<String>) -> Self {
+            let mut alternative_titles: Vec<String> = vec![];
+            for t in titles.into_iter().map(|t| t.trim().to_owned()) {
+                if !t.is_empty() && t != title && !alternative_titles.contains(&t) {
+                    alternative_titles.push(t);
+                }
+            }
+            Self(alternative_titles)
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+    pub struct MetadataLocalization {
+        pub title: String,
+        pub description: Option<String>,
+    }
+
+    /// The details of a media item in the languages that users prefer, other
+    /// than the one that it was fetched in.
+    #[derive(Clone, Debug, PartialEq, FromJsonQueryResult, Eq, Serialize, Deserialize, Default)]
+    pub struct MetadataLocalizations(pub HashMap<String, MetadataLocalization>);
+
+    /// An award that a media item has won or been nominated for.
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, SimpleObject)]
+    pub struct MetadataAward {
+        /// The name of the award, eg: `Academy Award for Best Picture`.
+        pub name: String,
+        /// The year the award was given out, if known.
+        pub year: Option<i32>,
+        /// Whether the item won the award. `false` for nominations.
+        pub won: bool,
+    }
+
+    #[derive(Clone, Debug, PartialEq, FromJsonQueryResult, Eq, Serialize, Deserialize, Default)]
+    pub struct MetadataAwards(pub Vec<MetadataAward>);
+
+    impl MetadataAwards {
+        /// Whether any of the awards match the given name, optionally only
+        /// considering the ones that were won.
+        pub fn contains(&self, name: &str, won_only: bool) -> bool {
+            let name = name.to_lowercase();
+            self.0
+                .iter()
+                .any(|a| (!won_only || a.won) && a.name.to_lowercase().contains(&name))
+        }
+    }
+
+    /// Returned by a provider when the requested item no longer exists on its
+    /// end, eg: it was deleted. Retrying will not help, so the item needs to be
+    /// resolved by the user.
+    #[derive(Debug)]
+    pub struct MediaDeletedOnProvider;
+
+    impl fmt::Display for MediaDeletedOnProvider {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "The media item no longer exists on the provider")
+        }
+    }
+
+    impl std::error::Error for MediaDeletedOnProvider {}
+
+    /// The kinds of identifiers from other services that a provider can convert
+    /// into its own identifier.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ExternalIdentifierType {
+        Isbn,
+        Imdb,
+        Mal,
+        Tvdb,
+    }
+
     #[derive(
         Clone,
         Debug,
@@ -1010,6 +1413,17 @@ pub mod fitness {
         pub images: Vec<String>,
         #[serde(default)]
         pub muscles: Vec<ExerciseMuscle>,
+        /// The names of the exercise in other languages.
+        #[serde(default)]
+        pub localized_names: Vec<ExerciseLocalizedName>,
+    }
+
+    #[derive(
+        Debug, Clone, Serialize, SimpleObject, Deserialize, FromJsonQueryResult, Eq, PartialEq,
+    )]
+    pub struct ExerciseLocalizedName {
+        pub language: String,
+        pub name: String,
     }
 
     #[derive(
@@ -1039,6 +1453,107 @@ pub mod fitness {
         pub name: String,
     }
 
+    /// Progress photos attached to a measurement. These are private to the user.
+    #[derive(Debug, Clone, Serialize, Deserialize, FromJsonQueryResult, Eq, PartialEq, Default)]
+    pub struct UserMeasurementPhotos(pub Vec<StoredUrl>);
+
+    #[derive(
+        Debug,
+        Clone,
+        Serialize,
+        Deserialize,
+        FromJsonQueryResult,
+        Eq,
+        PartialEq,
+        SimpleObject,
+        InputObject,
+    )]
+    #[graphql(input_name = "InProgressWorkoutSetInput")]
+    pub struct InProgressWorkoutSet {
+        pub statistic: SetStatistic,
+        pub lot: SetLot,
+        /// Whether the user has marked the set as done.
+        pub confirmed: bool,
+    }
+
+    #[derive(
+        Debug,
+        Clone,
+        Serialize,
+        Deserialize,
+        FromJsonQueryResult,
+        Eq,
+        PartialEq,
+        SimpleObject,
+        InputObject,
+    )]
+    #[graphql(input_name = "InProgressWorkoutExerciseInput")]
+    pub struct InProgressWorkoutExercise {
+        pub exercise_id: i32,
+        pub name: String,
+        pub lot: ExerciseLot,
+        pub notes: Vec<String>,
+        pub sets: Vec<InProgressWorkoutSet>,
+        pub rest_time: Option<u16>,
+    }
+
+    /// A workout that is still being recorded. It is stored on the server so that it
+    /// can be continued from another device.
+    #[derive(
+        Debug,
+        Clone,
+        Serialize,
+        Deserialize,
+        FromJsonQueryResult,
+        Eq,
+        PartialEq,
+        SimpleObject,
+        InputObject,
+    )]
+    #[graphql(input_name = "InProgressWorkoutInput")]
+    pub struct InProgressWorkout {
+        pub name: String,
+        pub comment: Option<String>,
+        pub start_time: DateTimeUtc,
+        pub exercises: Vec<InProgressWorkoutExercise>,
+        /// The index of the exercise that is currently being performed.
+        pub current_exercise_idx: Option<u16>,
+        /// When the running rest timer will end, if there is one.
+        pub rest_timer_ends_at: Option<DateTimeUtc>,
+        /// The total duration (in seconds) of the running rest timer.
+        pub rest_timer_duration: Option<u16>,
+        /// Set by the server whenever the workout is updated.
+        #[graphql(skip_input)]
+        pub updated_on: DateTimeUtc,
+    }
+
+    /// An activity recorded by an external service (eg: Strava, Garmin Connect).
+    /// All values are in metric units.
+    #[derive(Debug, Clone)]
+    pub struct ExternalWorkout {
+        /// The name of the service, eg: "Strava".
+        pub source: &'static str,
+        /// The identifier of the activity in the service.
+        pub identifier: String,
+        pub name: String,
+        pub sport_type: String,
+        pub start_time: DateTimeUtc,
+        pub end_time: DateTimeUtc,
+        /// In minutes.
+        pub duration: Decimal,
+        /// In kilometers.
+        pub distance: Option<Decimal>,
+        /// In meters.
+        pub elevation_gain: Option<Decimal>,
+        pub average_heart_rate: Option<u16>,
+    }
+
+    impl ExternalWorkout {
+        pub fn source_slug(&self) -> String {
+            self.source.to_lowercase().replace(' ', "-")
+        }
+    }
+
     #[skip_serializing_none]
     #[derive(
         Debug,
@@ -1051,6 +1566,7 @@ pub mod fitness {
         SimpleObject,
         InputObject,
         Type,
+        Default,
     )]
     #[graphql(input_name = "UserMeasurementDataInput")]
     pub struct UserMeasurementStats {
@@ -1102,6 +1618,8 @@ pub mod fitness {
         pub reps: usize,
         pub distance: Decimal,
         pub duration: Decimal,
+        #[serde(default)]
+        pub elevation_gain: Decimal,
     }
 
     #[derive(
@@ -1138,6 +1656,10 @@ pub mod fitness {
         pub distance: Option<Decimal>,
         pub reps: Option<usize>,
         pub weight: Option<Decimal>,
+        /// The average heart rate in beats per minute.
+        pub average_heart_rate: Option<u16>,
+        /// The elevation gained, in meters.
+        pub elevation_gain: Option<Decimal>,
     }
 
     #[derive(
@@ -1161,16 +1683,32 @@ pub mod fitness {
         Enum,
         Copy,
         Default,
+        Hash,
     )]
     pub enum WorkoutSetPersonalBest {
         #[default]
         Weight,
         OneRm,
         Volume,
+        Reps,
         Time,
         Pace,
     }
 
+    impl fmt::Display for WorkoutSetPersonalBest {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let name = match self {
+                Self::Weight => "max weight",
+                Self::OneRm => "estimated 1RM",
+                Self::Volume => "best volume",
+                Self::Reps => "max reps",
+                Self::Time => "longest time",
+                Self::Pace => "best pace",
+            };
+            write!(f, "{}", name)
+        }
+    }
+
     #[derive(
         Clone, Debug, Deserialize, Serialize, FromJsonQueryResult, Eq, PartialEq, SimpleObject,
     )]
@@ -1206,6 +1744,7 @@ pub mod fitness {
                 WorkoutSetPersonalBest::Time => self.statistic.duration,
                 WorkoutSetPersonalBest::OneRm => self.calculate_one_rm(),
                 WorkoutSetPersonalBest::Volume => self.calculate_volume(),
+                WorkoutSetPersonalBest::Reps => self.statistic.reps.and_then(Decimal::from_usize),
                 WorkoutSetPersonalBest::Pace => self.calculate_pace(),
             }
         }
@@ -1251,6 +1790,21 @@ pub mod fitness {
         pub history: Vec<UserToExerciseHistoryExtraInformation>,
         pub lifetime_stats: TotalMeasurement,
         pub personal_bests: Vec<UserToExerciseBestSetExtraInformation>,
+        #[graphql(skip)]
+        #[serde(default)]
+        pub assets: UserToExerciseAssets,
+        /// The rest time (in seconds) to use after each set of this exercise, unless
+        /// overridden in the workout.
+        #[serde(default)]
+        pub default_rest_time: Option<u16>,
+    }
+
+    /// Images and videos uploaded by the user for an exercise, eg: to document
+    /// their setup. These are private to the user.
+    #[derive(Debug, Clone, Serialize, Deserialize, FromJsonQueryResult, Eq, PartialEq, Default)]
+    pub struct UserToExerciseAssets {
+        pub images: Vec<StoredUrl>,
+        pub videos: Vec<StoredUrl>,
     }
 
     #[derive(
@@ -1265,16 +1819,91 @@ pub mod fitness {
         pub total: TotalMeasurement,
     }
 
+    #[derive(
+        Clone, Debug, Deserialize, Serialize, FromJsonQueryResult, Eq, PartialEq, Enum, Copy,
+    )]
+    pub enum ExerciseGroupLot {
+        /// Exercises performed back to back, resting only after the last one.
+        Superset,
+        /// Like a superset, but the exercises are repeated for multiple rounds.
+        Circuit,
+    }
+
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        FromJsonQueryResult,
+        Eq,
+        PartialEq,
+        SimpleObject,
+        InputObject,
+    )]
+    #[graphql(input_name = "ExerciseGroupInput")]
+    pub struct ExerciseGroup {
+        pub lot: ExerciseGroupLot,
+        /// The `exercise.idx` of the exercises in the group, in the order they are
+        /// performed.
+        pub exercises: Vec<u16>,
+        /// The rest time after each round, shared by all the exercises in the group.
+        pub rest_time: Option<u16>,
+    }
+
+    #[derive(
+        Clone, Debug, Deserialize, Serialize, FromJsonQueryResult, Eq, PartialEq, SimpleObject,
+    )]
+    pub struct ProcessedExerciseGroup {
+        pub lot: ExerciseGroupLot,
+        pub exercises: Vec<u16>,
+        pub rest_time: Option<u16>,
+        /// The combined totals of all the exercises in the group.
+        pub total: TotalMeasurement,
+    }
+
     #[derive(
         Clone, Debug, Deserialize, Serialize, FromJsonQueryResult, Eq, PartialEq, SimpleObject,
     )]
     pub struct WorkoutInformation {
-        /// Each grouped superset of exercises will be in a vector. They will contain
-        /// the `exercise.idx`.
-        pub supersets: Vec<Vec<u16>>,
+        /// The supersets and circuits in this workout.
+        pub groups: Vec<ProcessedExerciseGroup>,
         pub exercises: Vec<ProcessedExercise>,
     }
 
+    impl WorkoutInformation {
+        /// A template with the performed sets of the workout as the targets.
+        pub fn to_template(&self) -> WorkoutTemplateInformation {
+            WorkoutTemplateInformation {
+                groups: self
+                    .groups
+                    .iter()
+                    .map(|g| ExerciseGroup {
+                        lot: g.lot,
+                        exercises: g.exercises.clone(),
+                        rest_time: g.rest_time,
+                    })
+                    .collect(),
+                exercises: self
+                    .exercises
+                    .iter()
+                    .map(|e| WorkoutTemplateExercise {
+                        exercise_id: e.exercise_id,
+                        sets: e
+                            .sets
+                            .iter()
+                            .map(|s| WorkoutTemplateSet {
+                                lot: s.lot,
+                                target: s.statistic.clone(),
+                            })
+                            .collect(),
+                        notes: e.notes.clone(),
+                        rest_time: e.rest_time,
+                    })
+                    .collect(),
+            }
+        }
+    }
+
     #[derive(
         Clone, Debug, Deserialize, Serialize, FromJsonQueryResult, Eq, PartialEq, SimpleObject,
     )]
@@ -1291,4 +1920,127 @@ pub mod fitness {
         pub total: TotalMeasurement,
         pub exercises: Vec<WorkoutSummaryExercise>,
     }
+
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        FromJsonQueryResult,
+        Eq,
+        PartialEq,
+        SimpleObject,
+        InputObject,
+    )]
+    #[graphql(input_name = "WorkoutTemplateSetInput")]
+    pub struct WorkoutTemplateSet {
+        pub lot: SetLot,
+        /// The values the user is aiming for, eg: reps and weight.
+        pub target: SetStatistic,
+    }
+
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        FromJsonQueryResult,
+        Eq,
+        PartialEq,
+        SimpleObject,
+        InputObject,
+    )]
+    #[graphql(input_name = "WorkoutTemplateExerciseInput")]
+    pub struct WorkoutTemplateExercise {
+        pub exercise_id: i32,
+        pub sets: Vec<WorkoutTemplateSet>,
+        pub notes: Vec<String>,
+        pub rest_time: Option<u16>,
+    }
+
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        FromJsonQueryResult,
+        Eq,
+        PartialEq,
+        SimpleObject,
+        InputObject,
+    )]
+    #[graphql(input_name = "WorkoutTemplateInformationInput")]
+    pub struct WorkoutTemplateInformation {
+        /// The supersets and circuits in this template.
+        pub groups: Vec<ExerciseGroup>,
+        pub exercises: Vec<WorkoutTemplateExercise>,
+    }
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        FromJsonQueryResult,
+        Eq,
+        PartialEq,
+        SimpleObject,
+        InputObject,
+    )]
+    #[graphql(input_name = "TrainingProgramScheduleItemInput")]
+    pub struct TrainingProgramScheduleItem {
+        /// The day of the cycle, starting from `0`.
+        pub day: u16,
+        pub template_id: String,
+    }
+
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        FromJsonQueryResult,
+        Eq,
+        PartialEq,
+        SimpleObject,
+        InputObject,
+    )]
+    #[graphql(input_name = "TrainingProgramProgressionInput")]
+    pub struct TrainingProgramProgression {
+        pub exercise_id: i32,
+        /// Added to the target weights of the exercise in the template every time
+        /// all of its target sets are completed.
+        pub weight_increment: Decimal,
+    }
+
+    #[derive(
+        Clone,
+        Debug,
+        Deserialize,
+        Serialize,
+        FromJsonQueryResult,
+        Eq,
+        PartialEq,
+        SimpleObject,
+        InputObject,
+    )]
+    #[graphql(input_name = "TrainingProgramInformationInput")]
+    pub struct TrainingProgramInformation {
+        /// The number of days after which the schedule repeats, eg: `7` for a
+        /// weekly program.
+        pub cycle_length: u16,
+        /// The number of times the schedule is repeated.
+        pub num_cycles: u16,
+        pub schedule: Vec<TrainingProgramScheduleItem>,
+        pub progressions: Vec<TrainingProgramProgression>,
+    }
+
+    #[derive(Clone, Debug, Deserialize, Serialize, FromJsonQueryResult, Eq, PartialEq)]
+    pub struct TrainingProgramCompletion {
+        pub date: NaiveDate,
+        pub template_id: String,
+        pub workout_id: String,
+    }
+
+    #[derive(Clone, Debug, PartialEq, FromJsonQueryResult, Eq, Serialize, Deserialize, Default)]
+    pub struct TrainingProgramCompletions(pub Vec<TrainingProgramCompletion>);
 }
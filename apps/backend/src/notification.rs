@@ -1,8 +1,13 @@
 use anyhow::{anyhow, Result};
 use convert_case::{Case, Casing};
 use http_types::mime;
+use lettre::{
+    message::header::ContentType, transport::smtp::authentication::Credentials, AsyncSmtpTransport,
+    AsyncTransport, Message, Tokio1Executor,
+};
 
 use crate::{
+    config::SmtpConfig,
     users::UserNotificationSetting,
     utils::{AVATAR_URL, PROJECT_NAME},
 };
@@ -116,3 +121,22 @@ impl UserNotificationSetting {
         Ok(())
     }
 }
+
+/// Send a plain text email using the SMTP server of the instance.
+pub async fn send_email(config: &SmtpConfig, to: &str, subject: &str, body: String) -> Result<()> {
+    let email = Message::builder()
+        .from(config.mailbox.parse()?)
+        .to(to.parse()?)
+        .subject(subject)
+        .header(ContentType::TEXT_PLAIN)
+        .body(body)?;
+    let mailer = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.server)?
+        .port(config.port)
+        .credentials(Credentials::new(
+            config.user.clone(),
+            config.password.clone(),
+        ))
+        .build();
+    mailer.send(email).await?;
+    Ok(())
+}
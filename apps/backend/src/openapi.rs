@@ -0,0 +1,263 @@
+use convert_case::{Case, Casing};
+use sea_orm::Iterable;
+use serde_json::{json, Map, Value};
+
+use crate::{
+    integrations::{WEBHOOK_SECRET_HEADER, WEBHOOK_SECRET_QUERY, WEBHOOK_SIGNATURE_HEADER},
+    migrator::MetadataLot,
+    utils::{COOKIE_NAME, PROJECT_NAME, VERSION},
+};
+
+fn path_parameter(name: &str, values: Vec<String>) -> Value {
+    json!({
+        "name": name,
+        "in": "path",
+        "required": true,
+        "schema": { "type": "string", "enum": values }
+    })
+}
+
+fn text_response(content_type: &str) -> Value {
+    json!({
+        "200": {
+            "description": "The exported data",
+            "content": { content_type: { "schema": { "type": "string" } } }
+        },
+        "400": { "$ref": "#/components/responses/Error" },
+        "403": { "$ref": "#/components/responses/Error" }
+    })
+}
+
+fn export_operation(summary: &str, parameters: Vec<Value>, responses: Value) -> Value {
+    json!({
+        "get": {
+            "summary": summary,
+            "tags": ["export"],
+            "security": [{ "bearer": [] }, { "cookie": [] }],
+            "parameters": parameters,
+            "responses": responses
+        }
+    })
+}
+
+/// The OpenAPI document of the REST endpoints of the server. The GraphQL API is
+/// described by its SDL instead, and the endpoints that emulate the APIs of
+/// KOReader and Kobo are left out since they are only used by those devices.
+pub fn openapi_document() -> Value {
+    let lots: Vec<String> = MetadataLot::iter()
+        .map(|l| l.to_string().to_case(Case::Snake))
+        .collect();
+    let to_strings =
+        |values: &[&str]| -> Vec<String> { values.iter().map(|v| v.to_string()).collect() };
+    let mut paths = Map::new();
+    paths.insert(
+        "/graphql".to_owned(),
+        json!({
+            "post": {
+                "summary": "Execute a GraphQL request",
+                "tags": ["graphql"],
+                "security": [{ "bearer": [] }, { "cookie": [] }, {}],
+                "requestBody": {
+                    "required": true,
+                    "content": { "application/json": { "schema": {
+                        "type": "object",
+                        "required": ["query"],
+                        "properties": {
+                            "query": { "type": "string" },
+                            "operationName": { "type": "string" },
+                            "variables": { "type": "object" }
+                        }
+                    } } }
+                },
+                "responses": { "200": {
+                    "description": "The result of the request",
+                    "content": { "application/json": { "schema": { "type": "object" } } }
+                } }
+            }
+        }),
+    );
+    paths.insert(
+        "/graphql/schema.graphql".to_owned(),
+        json!({
+            "get": {
+                "summary": "The GraphQL schema of this version of the server as SDL",
+                "tags": ["graphql"],
+                "responses": { "200": {
+                    "description": "The schema",
+                    "content": { "text/plain": { "schema": { "type": "string" } } }
+                } }
+            }
+        }),
+    );
+    paths.insert(
+        "/openapi.json".to_owned(),
+        json!({
+            "get": {
+                "summary": "This document",
+                "tags": ["meta"],
+                "responses": { "200": {
+                    "description": "The OpenAPI document",
+                    "content": { "application/json": { "schema": { "type": "object" } } }
+                } }
+            }
+        }),
+    );
+    paths.insert(
+        "/config".to_owned(),
+        json!({
+            "get": {
+                "summary": "The configuration of the server, with the secrets masked",
+                "tags": ["meta"],
+                "responses": { "200": {
+                    "description": "The configuration",
+                    "content": { "application/json": { "schema": { "type": "object" } } }
+                } }
+            }
+        }),
+    );
+    paths.insert(
+        "/upload".to_owned(),
+        json!({
+            "post": {
+                "summary": "Upload files to use in imports",
+                "tags": ["import"],
+                "requestBody": {
+                    "required": true,
+                    "content": { "multipart/form-data": { "schema": {
+                        "type": "object",
+                        "additionalProperties": { "type": "string", "format": "binary" }
+                    } } }
+                },
+                "responses": { "200": {
+                    "description": "The paths that the files were saved to",
+                    "content": { "application/json": { "schema": {
+                        "type": "array",
+                        "items": { "type": "string" }
+                    } } }
+                } }
+            }
+        }),
+    );
+    paths.insert(
+        "/export/{export_type}".to_owned(),
+        export_operation(
+            "Export the data of the user as JSON",
+            vec![path_parameter(
+                "export_type",
+                to_strings(&["all", "media", "people", "measurements", "workouts"]),
+            )],
+            text_response("application/json"),
+        ),
+    );
+    paths.insert(
+        "/export/workouts/{format}".to_owned(),
+        export_operation(
+            "Export the workouts of the user in the format of another tracker",
+            vec![path_parameter("format", to_strings(&["strong", "hevy"]))],
+            text_response("text/csv"),
+        ),
+    );
+    paths.insert(
+        "/export/csv/{lot}".to_owned(),
+        export_operation(
+            "Export the media of a type as CSV",
+            vec![path_parameter("lot", lots)],
+            text_response("text/csv"),
+        ),
+    );
+    paths.insert(
+        "/export/letterboxd".to_owned(),
+        export_operation(
+            "Export the movies in the Letterboxd import format",
+            vec![],
+            text_response("text/csv"),
+        ),
+    );
+    paths.insert(
+        "/export/mal/{lot}".to_owned(),
+        export_operation(
+            "Export the anime or manga in the MyAnimeList import format",
+            vec![path_parameter("lot", to_strings(&["anime", "manga"]))],
+            text_response("application/xml"),
+        ),
+    );
+    paths.insert(
+        "/export/highlights".to_owned(),
+        export_operation(
+            "Export the highlights of the user as Markdown",
+            vec![],
+            text_response("text/markdown"),
+        ),
+    );
+    paths.insert(
+        "/webhooks/integrations/{integration}/{user_hash_id}".to_owned(),
+        json!({
+            "post": {
+                "summary": "Receive the progress of a media server",
+                "tags": ["integration"],
+                "parameters": [
+                    path_parameter("integration", to_strings(&["jellyfin", "plex", "kodi"])),
+                    {
+                        "name": "user_hash_id",
+                        "in": "path",
+                        "required": true,
+                        "schema": { "type": "string" }
+                    },
+                    {
+                        "name": WEBHOOK_SECRET_QUERY,
+                        "in": "query",
+                        "description": "The secret of the integration",
+                        "schema": { "type": "string" }
+                    },
+                    {
+                        "name": WEBHOOK_SECRET_HEADER,
+                        "in": "header",
+                        "description": "The secret of the integration",
+                        "schema": { "type": "string" }
+                    },
+                    {
+                        "name": WEBHOOK_SIGNATURE_HEADER,
+                        "in": "header",
+                        "description": "The hex encoded HMAC-SHA256 of the payload with the secret",
+                        "schema": { "type": "string" }
+                    }
+                ],
+                "requestBody": {
+                    "required": true,
+                    "content": { "application/json": { "schema": { "type": "object" } } }
+                },
+                "responses": {
+                    "200": {
+                        "description": "The payload was processed",
+                        "content": { "text/plain": { "schema": { "type": "string" } } }
+                    },
+                    "401": { "description": "The payload was not signed with the secret" },
+                    "422": { "description": "The payload could not be processed" }
+                }
+            }
+        }),
+    );
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": PROJECT_NAME,
+            "version": VERSION,
+        },
+        "paths": paths,
+        "components": {
+            "securitySchemes": {
+                "bearer": { "type": "http", "scheme": "bearer" },
+                "cookie": { "type": "apiKey", "in": "cookie", "name": COOKIE_NAME }
+            },
+            "responses": {
+                "Error": {
+                    "description": "The request could not be completed",
+                    "content": { "application/json": { "schema": {
+                        "type": "object",
+                        "properties": { "err": { "type": "string" } }
+                    } } }
+                }
+            }
+        }
+    })
+}
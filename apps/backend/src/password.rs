@@ -0,0 +1,75 @@
+use anyhow::{anyhow, Result};
+use argon2::{
+    password_hash::{rand_core::OsRng, SaltString},
+    Algorithm, Argon2, KeyId, Params, ParamsBuilder, PasswordHash, PasswordHasher,
+    PasswordVerifier, Version,
+};
+
+use crate::config::PasswordHashingConfig;
+
+/// Stored in the hashes that were created using the pepper, so that the ones
+/// created before it was configured can still be verified.
+static PEPPER_KEY_ID: &[u8] = b"pepper";
+
+fn get_hasher(config: &PasswordHashingConfig, peppered: bool) -> Result<Argon2<'_>> {
+    let mut params = ParamsBuilder::new();
+    params
+        .m_cost(config.memory_cost)
+        .t_cost(config.time_cost)
+        .p_cost(config.parallelism);
+    if peppered {
+        params.keyid(KeyId::new(PEPPER_KEY_ID).map_err(|e| anyhow!(e))?);
+    }
+    let params = params.build().map_err(|e| anyhow!(e))?;
+    let hasher = match peppered {
+        true => Argon2::new_with_secret(
+            config.pepper.as_bytes(),
+            Algorithm::Argon2id,
+            Version::V0x13,
+            params,
+        )
+        .map_err(|e| anyhow!(e))?,
+        false => Argon2::new(Algorithm::Argon2id, Version::V0x13, params),
+    };
+    Ok(hasher)
+}
+
+fn is_peppered(hash: &PasswordHash) -> bool {
+    Params::try_from(hash).map_or(false, |p| p.keyid() == PEPPER_KEY_ID)
+}
+
+pub fn hash(config: &PasswordHashingConfig, password: &str) -> Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = get_hasher(config, !config.pepper.is_empty())?
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| anyhow!(e))?
+        .to_string();
+    Ok(hash)
+}
+
+/// Check a password against its stored hash. The parameters are read from the
+/// hash itself, so the ones created with older settings are still accepted.
+pub fn verify(config: &PasswordHashingConfig, password: &str, hash: &str) -> Result<bool> {
+    let hash = PasswordHash::new(hash).map_err(|e| anyhow!(e))?;
+    let hasher = get_hasher(config, is_peppered(&hash))?;
+    Ok(hasher.verify_password(password.as_bytes(), &hash).is_ok())
+}
+
+/// Whether a hash was created with other settings than the current ones and
+/// should be replaced the next time the password is known.
+pub fn needs_rehash(config: &PasswordHashingConfig, hash: &str) -> bool {
+    let hash = match PasswordHash::new(hash) {
+        Ok(h) => h,
+        Err(_) => return true,
+    };
+    let params = match Params::try_from(&hash) {
+        Ok(p) => p,
+        Err(_) => return true,
+    };
+    hash.algorithm != Algorithm::Argon2id.ident()
+        || hash.version != Some(Version::V0x13.into())
+        || params.m_cost() != config.memory_cost
+        || params.t_cost() != config.time_cost
+        || params.p_cost() != config.parallelism
+        || is_peppered(&hash) == config.pepper.is_empty()
+}
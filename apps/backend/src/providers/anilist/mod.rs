@@ -11,9 +11,9 @@ use crate::{
     migrator::{MetadataLot, MetadataSource},
     models::{
         media::{
-            AnimeSpecifics, MangaSpecifics, MediaDetails, MediaSearchItem, MediaSpecifics,
-            MetadataCreator, MetadataImage, MetadataImageLot, MetadataVideo, MetadataVideoSource,
-            PartialMetadata,
+            AnimeSpecifics, ExternalIdentifierType, MangaSpecifics, MediaDetails,
+            MediaSearchFilters, MediaSearchItem, MediaSpecifics, MetadataCreator, MetadataImage,
+            MetadataImageLot, MetadataVideo, MetadataVideoSource, PartialMetadata,
         },
         SearchDetails, SearchResults, StoredUrl,
     },
@@ -23,6 +23,8 @@ use crate::{
 
 static URL: &str = "https://graphql.anilist.co";
 
+type FuzzyDateInt = i64;
+
 #[derive(GraphQLQuery)]
 #[graphql(
     schema_path = "src/providers/anilist/schema.json",
@@ -41,19 +43,32 @@ struct SearchQuery;
 )]
 struct DetailsQuery;
 
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "src/providers/anilist/schema.json",
+    query_path = "src/providers/anilist/id_from_mal.graphql",
+    response_derives = "Debug",
+    variables_derives = "Debug"
+)]
+struct IdFromMalQuery;
+
 #[derive(Debug, Clone)]
 pub struct AnilistService {
     client: Client,
     page_limit: i32,
+    language: String,
 }
 
 impl MediaProviderLanguages for AnilistService {
     fn supported_languages() -> Vec<String> {
-        ["us"].into_iter().map(String::from).collect()
+        ["romaji", "english", "native"]
+            .into_iter()
+            .map(String::from)
+            .collect()
     }
 
     fn default_language() -> String {
-        "us".to_owned()
+        "romaji".to_owned()
     }
 }
 
@@ -66,15 +81,27 @@ impl AnilistAnimeService {
     pub async fn new(_config: &AnimeAnilistConfig, page_limit: i32) -> Self {
         let client = get_client_config(URL).await;
         Self {
-            base: AnilistService { client, page_limit },
+            base: AnilistService {
+                client,
+                page_limit,
+                language: AnilistService::default_language(),
+            },
         }
     }
+
+    /// Use titles in the given language instead of the default one.
+    pub fn with_language(mut self, language: Option<String>) -> Self {
+        if let Some(l) = language {
+            self.base.language = l;
+        }
+        self
+    }
 }
 
 #[async_trait]
 impl MediaProvider for AnilistAnimeService {
     async fn details(&self, identifier: &str) -> Result<MediaDetails> {
-        let details = details(&self.base.client, identifier).await?;
+        let details = details(&self.base.client, identifier, &self.base.language).await?;
         Ok(details)
     }
 
@@ -83,14 +110,51 @@ impl MediaProvider for AnilistAnimeService {
         query: &str,
         page: Option<i32>,
         display_nsfw: bool,
+        filters: &MediaSearchFilters,
     ) -> Result<SearchResults<MediaSearchItem>> {
         let (items, total, next_page) = search(
             &self.base.client,
             search_query::MediaType::ANIME,
-            query,
+            Some(query),
             page,
             self.base.page_limit,
             display_nsfw,
+            &self.base.language,
+            filters,
+        )
+        .await?;
+        Ok(SearchResults {
+            details: SearchDetails { total, next_page },
+            items,
+        })
+    }
+
+    async fn id_from_external(
+        &self,
+        id_type: ExternalIdentifierType,
+        value: &str,
+    ) -> Result<Option<String>> {
+        if id_type != ExternalIdentifierType::Mal {
+            return Ok(None);
+        }
+        id_from_mal(
+            &self.base.client,
+            id_from_mal_query::MediaType::ANIME,
+            value,
+        )
+        .await
+    }
+
+    async fn trending(&self, page: Option<i32>) -> Result<SearchResults<MediaSearchItem>> {
+        let (items, total, next_page) = search(
+            &self.base.client,
+            search_query::MediaType::ANIME,
+            None,
+            page,
+            self.base.page_limit,
+            false,
+            &self.base.language,
+            &MediaSearchFilters::default(),
         )
         .await?;
         Ok(SearchResults {
@@ -109,15 +173,27 @@ impl AnilistMangaService {
     pub async fn new(_config: &MangaAnilistConfig, page_limit: i32) -> Self {
         let client = get_client_config(URL).await;
         Self {
-            base: AnilistService { client, page_limit },
+            base: AnilistService {
+                client,
+                page_limit,
+                language: AnilistService::default_language(),
+            },
         }
     }
+
+    /// Use titles in the given language instead of the default one.
+    pub fn with_language(mut self, language: Option<String>) -> Self {
+        if let Some(l) = language {
+            self.base.language = l;
+        }
+        self
+    }
 }
 
 #[async_trait]
 impl MediaProvider for AnilistMangaService {
     async fn details(&self, identifier: &str) -> Result<MediaDetails> {
-        let details = details(&self.base.client, identifier).await?;
+        let details = details(&self.base.client, identifier, &self.base.language).await?;
         Ok(details)
     }
 
@@ -126,14 +202,51 @@ impl MediaProvider for AnilistMangaService {
         query: &str,
         page: Option<i32>,
         display_nsfw: bool,
+        filters: &MediaSearchFilters,
     ) -> Result<SearchResults<MediaSearchItem>> {
         let (items, total, next_page) = search(
             &self.base.client,
             search_query::MediaType::MANGA,
-            query,
+            Some(query),
             page,
             self.base.page_limit,
             display_nsfw,
+            &self.base.language,
+            filters,
+        )
+        .await?;
+        Ok(SearchResults {
+            details: SearchDetails { total, next_page },
+            items,
+        })
+    }
+
+    async fn id_from_external(
+        &self,
+        id_type: ExternalIdentifierType,
+        value: &str,
+    ) -> Result<Option<String>> {
+        if id_type != ExternalIdentifierType::Mal {
+            return Ok(None);
+        }
+        id_from_mal(
+            &self.base.client,
+            id_from_mal_query::MediaType::MANGA,
+            value,
+        )
+        .await
+    }
+
+    async fn trending(&self, page: Option<i32>) -> Result<SearchResults<MediaSearchItem>> {
+        let (items, total, next_page) = search(
+            &self.base.client,
+            search_query::MediaType::MANGA,
+            None,
+            page,
+            self.base.page_limit,
+            false,
+            &self.base.language,
+            &MediaSearchFilters::default(),
         )
         .await?;
         Ok(SearchResults {
@@ -144,10 +257,38 @@ impl MediaProvider for AnilistMangaService {
 }
 
 async fn get_client_config(url: &str) -> Client {
-    get_base_http_client(url, vec![(ACCEPT, mime::JSON)])
+    get_base_http_client(
+        url,
+        vec![(ACCEPT, mime::JSON)],
+        Some(MetadataSource::Anilist),
+    )
 }
 
-async fn details(client: &Client, id: &str) -> Result<MediaDetails> {
+async fn id_from_mal(
+    client: &Client,
+    media_type: id_from_mal_query::MediaType,
+    mal_id: &str,
+) -> Result<Option<String>> {
+    let variables = id_from_mal_query::Variables {
+        id_mal: mal_id.parse::<i64>()?,
+        type_: media_type,
+    };
+    let body = IdFromMalQuery::build_query(variables);
+    let data = client
+        .post("")
+        .body_json(&body)
+        .unwrap()
+        .send()
+        .await
+        .map_err(|e| anyhow!(e))?
+        .body_json::<Response<id_from_mal_query::ResponseData>>()
+        .await
+        .map_err(|e| anyhow!(e))?
+        .data;
+    Ok(data.and_then(|d| d.media).map(|m| m.id.to_string()))
+}
+
+async fn details(client: &Client, id: &str, language: &str) -> Result<MediaDetails> {
     let variables = details_query::Variables {
         id: id.parse::<i64>().unwrap(),
     };
@@ -258,9 +399,26 @@ async fn details(client: &Client, id: &str) -> Result<MediaDetails> {
             _ => unreachable!(),
         },
     }));
+    let title = details.title.unwrap();
+    let alternative_titles = [
+        title.romaji.clone(),
+        title.english.clone(),
+        title.native.clone(),
+    ]
+    .into_iter()
+    .flatten()
+    .chain(details.synonyms.unwrap_or_default().into_iter().flatten())
+    .collect();
     Ok(MediaDetails {
         identifier: details.id.to_string(),
-        title: details.title.unwrap().user_preferred.unwrap(),
+        title: title_in_language(
+            language,
+            title.romaji,
+            title.english,
+            title.native,
+            title.user_preferred,
+        ),
+        alternative_titles,
         is_nsfw: details.is_adult,
         production_status: "Released".to_owned(),
         source: MetadataSource::Anilist,
@@ -282,17 +440,30 @@ async fn details(client: &Client, id: &str) -> Result<MediaDetails> {
 async fn search(
     client: &Client,
     media_type: search_query::MediaType,
-    query: &str,
+    query: Option<&str>,
     page: Option<i32>,
     page_limit: i32,
     _is_adult: bool,
+    language: &str,
+    filters: &MediaSearchFilters,
 ) -> Result<(Vec<MediaSearchItem>, i32, Option<i32>)> {
     let page = page.unwrap_or(1);
     let variables = search_query::Variables {
         page: page.into(),
-        search: query.to_owned(),
+        search: query.map(|q| q.to_owned()),
         type_: media_type,
         per_page: page_limit.into(),
+        format: filters
+            .format
+            .as_ref()
+            .map(|f| search_query::MediaFormat::Other(f.to_uppercase())),
+        // DEV: Dates are of the form `YYYYMMDD` and the comparisons are exclusive
+        start_date_greater: filters.year_from.map(|y| i64::from(y) * 10000),
+        start_date_lesser: filters.year_to.map(|y| i64::from(y + 1) * 10000),
+        // DEV: Without a query, the most trending media are returned
+        sort: query
+            .is_none()
+            .then(|| vec![Some(search_query::MediaSort::TRENDING_DESC)]),
     };
     let body = SearchQuery::build_query(variables);
     let search = client
@@ -320,14 +491,41 @@ async fn search(
         .unwrap()
         .into_iter()
         .flatten()
-        .map(|b| MediaSearchItem {
-            identifier: b.id.to_string(),
-            title: b.title.unwrap().user_preferred.unwrap(),
-            image: b.banner_image,
-            publish_year: b
-                .start_date
-                .and_then(|b| b.year.map(|y| y.try_into().unwrap())),
+        .map(|b| {
+            let title = b.title.unwrap();
+            MediaSearchItem {
+                identifier: b.id.to_string(),
+                title: title_in_language(
+                    language,
+                    title.romaji,
+                    title.english,
+                    title.native,
+                    title.user_preferred,
+                ),
+                image: b.banner_image,
+                publish_year: b
+                    .start_date
+                    .and_then(|b| b.year.map(|y| y.try_into().unwrap())),
+            }
         })
         .collect();
     Ok((media, total, next_page))
 }
+
+/// Not all media have titles in every language, so fall back to the one that
+/// Anilist prefers.
+fn title_in_language(
+    language: &str,
+    romaji: Option<String>,
+    english: Option<String>,
+    native: Option<String>,
+    user_preferred: Option<String>,
+) -> String {
+    match language {
+        "english" => english,
+        "native" => native,
+        _ => romaji,
+    }
+    .or(user_preferred)
+    .unwrap()
+}
@@ -15,8 +15,8 @@ use crate::{
     migrator::{MetadataLot, MetadataSource},
     models::{
         media::{
-            AudioBookSpecifics, MediaDetails, MediaSearchItem, MediaSpecifics, MetadataCreator,
-            MetadataImage, MetadataImageLot, MetadataImages, PartialMetadata,
+            AudioBookSpecifics, MediaDetails, MediaSearchFilters, MediaSearchItem, MediaSpecifics,
+            MetadataCreator, MetadataImage, MetadataImageLot, MetadataImages, PartialMetadata,
         },
         NamedObject, SearchDetails, SearchResults, StoredUrl,
     },
@@ -164,7 +164,11 @@ impl AudibleService {
 
     pub async fn new(config: &AudibleConfig, page_limit: i32) -> Self {
         let url = Self::url_from_locale(&config.locale);
-        let client = get_base_http_client(&url, vec![(ACCEPT, mime::JSON)]);
+        let client = get_base_http_client(
+            &url,
+            vec![(ACCEPT, mime::JSON)],
+            Some(MetadataSource::Audible),
+        );
         Self { client, page_limit }
     }
 }
@@ -220,6 +224,7 @@ impl MediaProvider for AudibleService {
         query: &str,
         page: Option<i32>,
         _display_nsfw: bool,
+        _filters: &MediaSearchFilters,
     ) -> Result<SearchResults<MediaSearchItem>> {
         let page = page.unwrap_or(1);
         #[derive(Serialize, Deserialize, Debug)]
@@ -384,6 +389,7 @@ impl AudibleService {
             is_nsfw: item.is_adult_product,
             production_status: "Released".to_owned(),
             title: item.title,
+            alternative_titles: vec![],
             description,
             creators,
             genres: item
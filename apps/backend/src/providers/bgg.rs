@@ -0,0 +1,276 @@
+// Responsible for tracking board games using the XML API of BoardGameGeek.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use http_types::mime;
+use itertools::Itertools;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use surf::{http::headers::ACCEPT, Client};
+
+use crate::{
+    config::BoardGameConfig,
+    migrator::{MetadataLot, MetadataSource},
+    models::{
+        media::{
+            BoardGameSpecifics, MediaDetails, MediaSearchFilters, MediaSearchItem, MediaSpecifics,
+            MetadataCreator, MetadataImage, MetadataImageLot,
+        },
+        SearchDetails, SearchResults, StoredUrl,
+    },
+    traits::{MediaProvider, MediaProviderLanguages},
+    utils::get_base_http_client,
+};
+
+static URL: &str = "https://boardgamegeek.com/xmlapi2/";
+
+#[derive(Debug, Clone)]
+pub struct BggService {
+    client: Client,
+    page_limit: i32,
+}
+
+impl MediaProviderLanguages for BggService {
+    fn supported_languages() -> Vec<String> {
+        vec!["us".to_owned()]
+    }
+
+    fn default_language() -> String {
+        "us".to_owned()
+    }
+}
+
+impl BggService {
+    pub async fn new(_config: &BoardGameConfig, page_limit: i32) -> Self {
+        let client = get_base_http_client(
+            URL,
+            vec![(ACCEPT, mime::XML)],
+            Some(MetadataSource::BoardGameGeek),
+        );
+        Self { client, page_limit }
+    }
+}
+
+/// Most of the details are sent as the `value` attribute of an empty element.
+#[derive(Serialize, Deserialize, Debug)]
+struct Value {
+    #[serde(rename = "@value")]
+    value: String,
+}
+
+impl Value {
+    fn parse<T: std::str::FromStr>(value: Option<Value>) -> Option<T> {
+        value.and_then(|v| v.value.parse().ok())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Name {
+    #[serde(rename = "@type")]
+    lot: String,
+    #[serde(rename = "@value")]
+    value: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Link {
+    #[serde(rename = "@type")]
+    lot: String,
+    #[serde(rename = "@value")]
+    value: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Ratings {
+    average: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Statistics {
+    ratings: Ratings,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Item {
+    #[serde(rename = "@id")]
+    id: String,
+    #[serde(default, rename = "name")]
+    names: Vec<Name>,
+    description: Option<String>,
+    thumbnail: Option<String>,
+    image: Option<String>,
+    yearpublished: Option<Value>,
+    minplayers: Option<Value>,
+    maxplayers: Option<Value>,
+    playingtime: Option<Value>,
+    #[serde(default, rename = "link")]
+    links: Vec<Link>,
+    statistics: Option<Statistics>,
+}
+
+impl Item {
+    fn title(&self) -> String {
+        self.names
+            .iter()
+            .find(|n| n.lot == "primary")
+            .or_else(|| self.names.first())
+            .map(|n| n.value.clone())
+            .unwrap_or_default()
+    }
+
+    fn links(&self, lot: &str) -> Vec<String> {
+        self.links
+            .iter()
+            .filter(|l| l.lot == lot)
+            .map(|l| l.value.clone())
+            .unique()
+            .collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Items {
+    #[serde(default, rename = "item")]
+    items: Vec<Item>,
+}
+
+impl BggService {
+    async fn things(&self, ids: &[String], stats: bool) -> Result<Vec<Item>> {
+        let mut query = serde_json::json!({ "id": ids.join(",") });
+        if stats {
+            query["stats"] = serde_json::json!(1);
+        }
+        let body = self
+            .client
+            .get("thing")
+            .query(&query)
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .body_string()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let things: Items = quick_xml::de::from_str(&body)?;
+        Ok(things.items)
+    }
+}
+
+#[async_trait]
+impl MediaProvider for BggService {
+    async fn details(&self, identifier: &str) -> Result<MediaDetails> {
+        let item = self
+            .things(&[identifier.to_owned()], true)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow!("No board game found with this identifier"))?;
+        let title = item.title();
+        let genres = item.links("boardgamecategory");
+        let creators = item
+            .links("boardgamedesigner")
+            .into_iter()
+            .map(|name| MetadataCreator {
+                name,
+                role: "Designer".to_owned(),
+                image: None,
+            })
+            .collect();
+        let alternative_titles = item
+            .names
+            .iter()
+            .filter(|n| n.lot != "primary")
+            .map(|n| n.value.clone())
+            .unique()
+            .collect();
+        // DEV: BGG reports `0` for the details that are not known
+        let specifics = BoardGameSpecifics {
+            min_players: Value::parse(item.minplayers).filter(|p| *p > 0),
+            max_players: Value::parse(item.maxplayers).filter(|p| *p > 0),
+            playtime: Value::parse(item.playingtime).filter(|p| *p > 0),
+        };
+        let provider_rating = item
+            .statistics
+            .and_then(|s| Value::parse::<Decimal>(s.ratings.average))
+            .filter(|r| !r.is_zero());
+        Ok(MediaDetails {
+            identifier: item.id,
+            lot: MetadataLot::BoardGame,
+            source: MetadataSource::BoardGameGeek,
+            production_status: "Released".to_owned(),
+            title,
+            alternative_titles,
+            description: item.description.map(|d| d.trim().to_owned()),
+            creators,
+            genres,
+            publish_year: Value::parse(item.yearpublished).filter(|y| *y > 0),
+            publish_date: None,
+            specifics: MediaSpecifics::BoardGame(specifics),
+            provider_rating,
+            images: item
+                .image
+                .into_iter()
+                .map(|i| MetadataImage {
+                    url: StoredUrl::Url(i),
+                    lot: MetadataImageLot::Poster,
+                })
+                .collect(),
+            is_nsfw: None,
+            videos: vec![],
+            suggestions: vec![],
+            groups: vec![],
+        })
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _display_nsfw: bool,
+        _filters: &MediaSearchFilters,
+    ) -> Result<SearchResults<MediaSearchItem>> {
+        let page = page.unwrap_or(1);
+        let body = self
+            .client
+            .get("search")
+            .query(&serde_json::json!({ "query": query, "type": "boardgame" }))
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .body_string()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let search: Items = quick_xml::de::from_str(&body)?;
+        // DEV: The search is not paginated and does not include the images, so
+        // only the items of the requested page are fetched again
+        let total = i32::try_from(search.items.len()).unwrap();
+        let ids = search
+            .items
+            .into_iter()
+            .skip(((page - 1) * self.page_limit).try_into().unwrap())
+            .take(self.page_limit.try_into().unwrap())
+            .map(|i| i.id)
+            .collect_vec();
+        let next_page = if page * self.page_limit < total {
+            Some(page + 1)
+        } else {
+            None
+        };
+        let items = match ids.is_empty() {
+            true => vec![],
+            false => self.things(&ids, false).await?,
+        };
+        let items = items
+            .into_iter()
+            .map(|i| MediaSearchItem {
+                title: i.title(),
+                image: i.thumbnail,
+                publish_year: Value::parse(i.yearpublished).filter(|y| *y > 0),
+                identifier: i.id,
+            })
+            .collect();
+        Ok(SearchResults {
+            details: SearchDetails { total, next_page },
+            items,
+        })
+    }
+}
@@ -0,0 +1,262 @@
+// Responsible for tracking western comics using the volumes of ComicVine.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use http_types::mime;
+use itertools::Itertools;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use surf::{http::headers::ACCEPT, Client};
+
+use crate::{
+    config::ComicConfig,
+    migrator::{MetadataLot, MetadataSource},
+    models::{
+        media::{
+            ComicSpecifics, MediaDetails, MediaSearchFilters, MediaSearchItem, MediaSpecifics,
+            MetadataCreator, MetadataImage, MetadataImageLot,
+        },
+        SearchDetails, SearchResults, StoredUrl,
+    },
+    traits::{MediaProvider, MediaProviderLanguages},
+    utils::get_base_http_client,
+};
+
+static URL: &str = "https://comicvine.gamespot.com/api/";
+// DEV: The resource type ids that prefix the identifiers in the URLs
+static VOLUME_PREFIX: &str = "4050";
+static ISSUE_PREFIX: &str = "4000";
+static VOLUME_FIELDS: &str =
+    "id,name,deck,description,start_year,count_of_issues,image,first_issue";
+
+#[derive(Debug, Clone)]
+pub struct ComicVineService {
+    client: Client,
+    api_key: String,
+    page_limit: i32,
+}
+
+impl MediaProviderLanguages for ComicVineService {
+    fn supported_languages() -> Vec<String> {
+        vec!["us".to_owned()]
+    }
+
+    fn default_language() -> String {
+        "us".to_owned()
+    }
+}
+
+impl ComicVineService {
+    pub async fn new(config: &ComicConfig, page_limit: i32) -> Self {
+        let client = get_base_http_client(
+            URL,
+            vec![(ACCEPT, mime::JSON)],
+            Some(MetadataSource::ComicVine),
+        );
+        Self {
+            client,
+            api_key: config.comic_vine.api_key.clone(),
+            page_limit,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Response<T> {
+    error: String,
+    status_code: i32,
+    #[serde(default)]
+    number_of_total_results: i32,
+    results: T,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Image {
+    original_url: Option<String>,
+    small_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct IdObject {
+    id: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Volume {
+    id: i32,
+    name: String,
+    deck: Option<String>,
+    description: Option<String>,
+    start_year: Option<String>,
+    count_of_issues: Option<i32>,
+    image: Option<Image>,
+    first_issue: Option<IdObject>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct PersonCredit {
+    name: String,
+    /// A comma separated list of the roles, eg: `writer, penciler`.
+    role: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Issue {
+    #[serde(default)]
+    person_credits: Vec<PersonCredit>,
+}
+
+/// The roles that ComicVine uses are more fine grained than what is shown for
+/// the creators, so the ones that draw the comic are all grouped as artists.
+fn creator_role(role: &str) -> Option<String> {
+    match role.trim() {
+        "writer" | "plotter" | "scripter" => Some("Writer".to_owned()),
+        "artist" | "penciler" | "penciller" | "inker" | "cover" => Some("Artist".to_owned()),
+        "colorist" => Some("Colorist".to_owned()),
+        "letterer" => Some("Letterer".to_owned()),
+        "editor" => Some("Editor".to_owned()),
+        _ => None,
+    }
+}
+
+impl ComicVineService {
+    async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: serde_json::Value,
+    ) -> Result<Response<T>> {
+        let mut query = query;
+        query["api_key"] = serde_json::json!(self.api_key);
+        query["format"] = serde_json::json!("json");
+        let response: Response<T> = self
+            .client
+            .get(path)
+            .query(&query)
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .body_json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        // DEV: Errors are reported in the body with a status code of `1` for success
+        if response.status_code != 1 {
+            return Err(anyhow!(response.error));
+        }
+        Ok(response)
+    }
+}
+
+#[async_trait]
+impl MediaProvider for ComicVineService {
+    async fn details(&self, identifier: &str) -> Result<MediaDetails> {
+        let volume: Volume = self
+            .get(
+                &format!("volume/{}-{}/", VOLUME_PREFIX, identifier),
+                serde_json::json!({ "field_list": VOLUME_FIELDS }),
+            )
+            .await?
+            .results;
+        // DEV: The roles of the creators are only known for each issue, so the
+        // ones of the first issue are used for the whole volume
+        let credits = match volume.first_issue {
+            Some(issue) => {
+                self.get::<Issue>(
+                    &format!("issue/{}-{}/", ISSUE_PREFIX, issue.id),
+                    serde_json::json!({ "field_list": "person_credits" }),
+                )
+                .await?
+                .results
+                .person_credits
+            }
+            None => vec![],
+        };
+        let creators = credits
+            .into_iter()
+            .flat_map(|c| {
+                c.role
+                    .split(',')
+                    .filter_map(creator_role)
+                    .map(|role| MetadataCreator {
+                        name: c.name.clone(),
+                        role,
+                        image: None,
+                    })
+                    .collect_vec()
+            })
+            .unique()
+            .collect();
+        Ok(MediaDetails {
+            identifier: volume.id.to_string(),
+            lot: MetadataLot::Comic,
+            source: MetadataSource::ComicVine,
+            production_status: "Released".to_owned(),
+            title: volume.name,
+            alternative_titles: vec![],
+            description: volume.description.or(volume.deck),
+            creators,
+            genres: vec![],
+            publish_year: volume.start_year.and_then(|y| y.parse().ok()),
+            publish_date: None,
+            specifics: MediaSpecifics::Comic(ComicSpecifics {
+                issues: volume.count_of_issues,
+            }),
+            provider_rating: None,
+            images: volume
+                .image
+                .and_then(|i| i.original_url)
+                .into_iter()
+                .map(|url| MetadataImage {
+                    url: StoredUrl::Url(url),
+                    lot: MetadataImageLot::Poster,
+                })
+                .collect(),
+            is_nsfw: None,
+            videos: vec![],
+            suggestions: vec![],
+            groups: vec![],
+        })
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _display_nsfw: bool,
+        _filters: &MediaSearchFilters,
+    ) -> Result<SearchResults<MediaSearchItem>> {
+        let page = page.unwrap_or(1);
+        let search = self
+            .get::<Vec<Volume>>(
+                "search/",
+                serde_json::json!({
+                    "query": query,
+                    "resources": "volume",
+                    "field_list": "id,name,start_year,image",
+                    "limit": self.page_limit,
+                    "page": page
+                }),
+            )
+            .await?;
+        let next_page = if page * self.page_limit < search.number_of_total_results {
+            Some(page + 1)
+        } else {
+            None
+        };
+        let items = search
+            .results
+            .into_iter()
+            .map(|v| MediaSearchItem {
+                identifier: v.id.to_string(),
+                title: v.name,
+                image: v.image.and_then(|i| i.small_url),
+                publish_year: v.start_year.and_then(|y| y.parse().ok()),
+            })
+            .collect();
+        Ok(SearchResults {
+            details: SearchDetails {
+                total: search.number_of_total_results,
+                next_page,
+            },
+            items,
+        })
+    }
+}
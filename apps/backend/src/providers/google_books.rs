@@ -12,8 +12,8 @@ use crate::{
     migrator::{MetadataLot, MetadataSource},
     models::{
         media::{
-            BookSpecifics, MediaDetails, MediaSearchItem, MediaSpecifics, MetadataCreator,
-            MetadataImage, MetadataImageLot,
+            BookSpecifics, ExternalIdentifierType, MediaDetails, MediaSearchFilters,
+            MediaSearchItem, MediaSpecifics, MetadataCreator, MetadataImage, MetadataImageLot,
         },
         SearchDetails, SearchResults, StoredUrl,
     },
@@ -26,6 +26,7 @@ static URL: &str = "https://www.googleapis.com/books/v1/volumes/";
 #[derive(Debug, Clone)]
 pub struct GoogleBooksService {
     client: Client,
+    api_key: Option<String>,
     page_limit: i32,
 }
 
@@ -40,9 +41,18 @@ impl MediaProviderLanguages for GoogleBooksService {
 }
 
 impl GoogleBooksService {
-    pub async fn new(_config: &GoogleBooksConfig, page_limit: i32) -> Self {
-        let client = get_base_http_client(URL, vec![(ACCEPT, mime::JSON)]);
-        Self { client, page_limit }
+    pub async fn new(config: &GoogleBooksConfig, page_limit: i32) -> Self {
+        let client = get_base_http_client(
+            URL,
+            vec![(ACCEPT, mime::JSON)],
+            Some(MetadataSource::GoogleBooks),
+        );
+        let api_key = Some(config.api_key.clone()).filter(|k| !k.is_empty());
+        Self {
+            client,
+            api_key,
+            page_limit,
+        }
     }
 }
 
@@ -89,29 +99,64 @@ struct SearchResponse {
 #[async_trait]
 impl MediaProvider for GoogleBooksService {
     async fn details(&self, identifier: &str) -> Result<MediaDetails> {
-        let mut rsp = self.client.get(identifier).await.map_err(|e| anyhow!(e))?;
+        let mut rsp = self
+            .client
+            .get(identifier)
+            .query(&self.query(serde_json::json!({})))
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?;
         let data: ItemResponse = rsp.body_json().await.map_err(|e| anyhow!(e))?;
         let d = self.google_books_response_to_search_response(data.volume_info, data.id);
         Ok(d)
     }
 
+    async fn id_from_external(
+        &self,
+        id_type: ExternalIdentifierType,
+        value: &str,
+    ) -> Result<Option<String>> {
+        if id_type != ExternalIdentifierType::Isbn {
+            return Ok(None);
+        }
+        let mut rsp = self
+            .client
+            .get("")
+            .query(&self.query(serde_json::json!({ "q": format!("isbn:{}", value) })))
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let search: SearchResponse = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+        Ok(search
+            .items
+            .unwrap_or_default()
+            .into_iter()
+            .next()
+            .map(|i| i.id))
+    }
+
     async fn search(
         &self,
         query: &str,
         page: Option<i32>,
         _display_nsfw: bool,
+        _filters: &MediaSearchFilters,
     ) -> Result<SearchResults<MediaSearchItem>> {
         let page = page.unwrap_or(1);
         let index = (page - 1) * self.page_limit;
+        let q = match as_isbn(query) {
+            Some(isbn) => format!("isbn:{}", isbn),
+            None => format!("intitle:{}", query),
+        };
         let mut rsp = self
             .client
             .get("")
-            .query(&serde_json::json!({
-                "q": format!("intitle:{}", query),
+            .query(&self.query(serde_json::json!({
+                "q": q,
                 "maxResults": self.page_limit,
                 "printType": "books",
                 "startIndex": index
-            }))
+            })))
             .unwrap()
             .await
             .map_err(|e| anyhow!(e))?;
@@ -160,7 +205,33 @@ impl MediaProvider for GoogleBooksService {
     }
 }
 
+/// The query as an ISBN-10 or ISBN-13 without the separators, if it looks like
+/// one. This allows searching for a specific edition by scanning its barcode.
+fn as_isbn(query: &str) -> Option<String> {
+    let isbn = query
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect::<String>();
+    let (body, check) = isbn.split_at(isbn.len().saturating_sub(1));
+    let valid = match isbn.len() {
+        10 => check
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == 'X' || c == 'x'),
+        13 => check.chars().all(|c| c.is_ascii_digit()),
+        _ => false,
+    };
+    Some(isbn.to_uppercase()).filter(|_| valid && body.chars().all(|c| c.is_ascii_digit()))
+}
+
 impl GoogleBooksService {
+    /// Add the API key to the query, if one is configured.
+    fn query(&self, mut query: serde_json::Value) -> serde_json::Value {
+        if let Some(key) = &self.api_key {
+            query["key"] = serde_json::json!(key);
+        }
+        query
+    }
+
     fn google_books_response_to_search_response(
         &self,
         item: ItemVolumeInfo,
@@ -223,6 +294,7 @@ impl GoogleBooksService {
             source: MetadataSource::GoogleBooks,
             production_status: "Released".to_owned(),
             title: item.title,
+            alternative_titles: vec![],
             description: item.description,
             creators: creators.into_iter().unique().collect(),
             genres: genres.into_iter().unique().collect(),
@@ -1,8 +1,8 @@
-use std::sync::OnceLock;
+use std::{collections::HashMap, sync::OnceLock};
 
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
-use chrono::Datelike;
+use chrono::{Datelike, Duration, NaiveDate, Utc};
 use itertools::Itertools;
 use rust_decimal::Decimal;
 use sea_orm::prelude::DateTimeUtc;
@@ -17,9 +17,9 @@ use crate::{
     migrator::{MetadataLot, MetadataSource},
     models::{
         media::{
-            MediaDetails, MediaSearchItem, MediaSpecifics, MetadataCreator, MetadataImage,
-            MetadataImageLot, MetadataImages, MetadataVideo, MetadataVideoSource, PartialMetadata,
-            VideoGameSpecifics,
+            MediaDetails, MediaSearchFilters, MediaSearchItem, MediaSpecifics, MediaUpcomingItem,
+            MetadataCreator, MetadataImage, MetadataImageLot, MetadataImages, MetadataVideo,
+            MetadataVideoSource, PartialMetadata, VideoGameSpecifics,
         },
         IdObject, NamedObject, SearchDetails, SearchResults, StoredUrl,
     },
@@ -35,6 +35,7 @@ static GAME_FIELDS: &str = "
 fields
     id,
     name,
+    alternative_names.name,
     summary,
     cover.*,
     first_release_date,
@@ -83,6 +84,7 @@ struct IgdbImage {
 struct IgdbSearchResponse {
     id: i32,
     name: Option<String>,
+    alternative_names: Option<Vec<NamedObject>>,
     rating: Option<Decimal>,
     games: Option<Vec<IgdbSearchResponse>>,
     summary: Option<String>,
@@ -128,6 +130,43 @@ impl IgdbService {
             page_limit,
         }
     }
+
+    /// Get the ids of the games on IGDB for the given Steam app ids. The app ids
+    /// that do not have a game on IGDB are not present in the result.
+    pub async fn steam_games(&self, app_ids: &[String]) -> Result<HashMap<String, String>> {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct ExternalGame {
+            game: Option<i32>,
+            uid: String,
+        }
+        let client = get_client(&self.config).await;
+        let mut games = HashMap::new();
+        // DEV: Category `1` is Steam and IGDB returns at most 500 items per request
+        for chunk in app_ids.chunks(500) {
+            let req_body = format!(
+                r#"
+fields game, uid;
+where category = 1 & uid = ({uids});
+limit 500;
+            "#,
+                uids = chunk.iter().map(|i| format!("\"{}\"", i)).join(",")
+            );
+            let external: Vec<ExternalGame> = client
+                .post("external_games")
+                .body_string(req_body)
+                .await
+                .map_err(|e| anyhow!(e))?
+                .body_json()
+                .await
+                .map_err(|e| anyhow!(e))?;
+            for e in external {
+                if let Some(game) = e.game {
+                    games.insert(e.uid, game.to_string());
+                }
+            }
+        }
+        Ok(games)
+    }
 }
 
 #[async_trait]
@@ -164,17 +203,42 @@ where id = {id};
         query: &str,
         page: Option<i32>,
         _display_nsfw: bool,
+        filters: &MediaSearchFilters,
     ) -> Result<SearchResults<MediaSearchItem>> {
         let page = page.unwrap_or(1);
         let client = get_client(&self.config).await;
+        let year_timestamp = |year: i32| {
+            NaiveDate::from_ymd_opt(year, 1, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .timestamp()
+        };
+        // DEV: The last `where` clause takes precedence, so the one in the
+        // fields needs to be repeated
+        let mut conditions = vec!["version_parent = null".to_owned()];
+        if let Some(year) = filters.year_from {
+            conditions.push(format!("first_release_date >= {}", year_timestamp(year)));
+        }
+        if let Some(year) = filters.year_to {
+            conditions.push(format!("first_release_date < {}", year_timestamp(year + 1)));
+        }
+        if let Some(platform) = &filters.platform {
+            conditions.push(format!(
+                r#"platforms.name ~ *"{}"*"#,
+                escape_string(platform)
+            ));
+        }
         let req_body = format!(
             r#"
 {field}
 search "{query}";
+where {conditions};
 limit {limit};
 offset: {offset};
             "#,
             field = GAME_FIELDS,
+            conditions = conditions.join(" & "),
             limit = self.page_limit,
             offset = (page - 1) * self.page_limit
         );
@@ -191,24 +255,7 @@ offset: {offset};
 
         let resp = search
             .into_iter()
-            .map(|r| {
-                let a = self.igdb_response_to_search_response(r);
-                MediaSearchItem {
-                    identifier: a.identifier,
-                    title: a.title,
-                    image: a
-                        .images
-                        .into_iter()
-                        .map(|i| match i.url {
-                            StoredUrl::S3(_u) => unreachable!(),
-                            StoredUrl::Url(u) => u,
-                        })
-                        .collect_vec()
-                        .get(0)
-                        .cloned(),
-                    publish_year: a.publish_year,
-                }
-            })
+            .map(|r| self.igdb_response_to_search_item(r))
             .collect_vec();
         Ok(SearchResults {
             details: SearchDetails {
@@ -218,9 +265,124 @@ offset: {offset};
             items: resp,
         })
     }
+
+    async fn trending(&self, page: Option<i32>) -> Result<SearchResults<MediaSearchItem>> {
+        let page = page.unwrap_or(1);
+        let client = get_client(&self.config).await;
+        let one_year_ago = Utc::now() - Duration::days(365);
+        // DEV: IGDB does not have a trending endpoint, so use the most rated games
+        // that were released recently
+        let req_body = format!(
+            r#"
+{field}
+where version_parent = null & total_rating_count != null & first_release_date > {since};
+sort total_rating_count desc;
+limit {limit};
+offset: {offset};
+            "#,
+            field = GAME_FIELDS,
+            since = one_year_ago.timestamp(),
+            limit = self.page_limit,
+            offset = (page - 1) * self.page_limit
+        );
+        let mut rsp = client
+            .post("games")
+            .body_string(req_body)
+            .await
+            .map_err(|e| anyhow!(e))?;
+        // DEV: IGDB sends the number of games that match the query in a header
+        let count = rsp
+            .header("x-count")
+            .and_then(|h| h.last().as_str().parse::<i32>().ok());
+        let trending: Vec<IgdbSearchResponse> = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+        let items = trending
+            .into_iter()
+            .map(|r| self.igdb_response_to_search_item(r))
+            .collect_vec();
+        let num_items = i32::try_from(items.len()).unwrap();
+        let fetched = (page - 1) * self.page_limit + num_items;
+        let next_page = (num_items >= self.page_limit && count.map_or(true, |c| c > fetched))
+            .then_some(page + 1);
+        Ok(SearchResults {
+            details: SearchDetails {
+                total: count.unwrap_or(fetched),
+                next_page,
+            },
+            items,
+        })
+    }
+
+    async fn upcoming(&self, _creators: &[String]) -> Result<Vec<MediaUpcomingItem>> {
+        let client = get_client(&self.config).await;
+        // DEV: Only games that people are looking forward to, otherwise the
+        // results are full of shovelware
+        let req_body = format!(
+            r#"
+{field}
+where version_parent = null & hypes != null & first_release_date > {now};
+sort first_release_date asc;
+limit {limit};
+            "#,
+            field = GAME_FIELDS,
+            now = Utc::now().timestamp(),
+            limit = 100
+        );
+        let mut rsp = client
+            .post("games")
+            .body_string(req_body)
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let upcoming: Vec<IgdbSearchResponse> = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+        let items = upcoming
+            .into_iter()
+            .filter_map(|r| {
+                let release_date = r.first_release_date?.date_naive();
+                let details = self.igdb_response_to_search_response(r);
+                Some(MediaUpcomingItem {
+                    item: MediaSearchItem {
+                        identifier: details.identifier,
+                        title: details.title,
+                        image: details
+                            .images
+                            .into_iter()
+                            .map(|i| match i.url {
+                                StoredUrl::S3(_u) => unreachable!(),
+                                StoredUrl::Url(u) => u,
+                            })
+                            .next(),
+                        publish_year: Some(release_date.year()),
+                    },
+                    release_date,
+                    genres: details.genres,
+                    creators: details.creators.into_iter().map(|c| c.name).collect(),
+                    is_nsfw: details.is_nsfw,
+                })
+            })
+            .collect();
+        Ok(items)
+    }
 }
 
 impl IgdbService {
+    fn igdb_response_to_search_item(&self, item: IgdbSearchResponse) -> MediaSearchItem {
+        let a = self.igdb_response_to_search_response(item);
+        MediaSearchItem {
+            identifier: a.identifier,
+            title: a.title,
+            image: a
+                .images
+                .into_iter()
+                .map(|i| match i.url {
+                    StoredUrl::S3(_u) => unreachable!(),
+                    StoredUrl::Url(u) => u,
+                })
+                .collect_vec()
+                .get(0)
+                .cloned(),
+            publish_year: a.publish_year,
+        }
+    }
+
     async fn group_details(
         &self,
         identifier: &str,
@@ -339,6 +501,12 @@ where id = {id};
             source: MetadataSource::Igdb,
             production_status: "Released".to_owned(),
             title: item.name.unwrap(),
+            alternative_titles: item
+                .alternative_names
+                .unwrap_or_default()
+                .into_iter()
+                .map(|n| n.name)
+                .collect(),
             description: item.summary,
             creators,
             images,
@@ -359,6 +527,7 @@ where id = {id};
                     .into_iter()
                     .map(|p| p.name)
                     .collect(),
+                achievements: None,
             }),
             suggestions: item
                 .similar_games
@@ -441,5 +610,11 @@ async fn get_client(config: &VideoGameConfig) -> Client {
             ("Client-ID".into(), config.twitch.client_id.to_owned()),
             (AUTHORIZATION, access_token),
         ],
+        Some(MetadataSource::Igdb),
     )
 }
+
+/// Escape a value so that it can be used in a string of an IGDB query.
+fn escape_string(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -12,8 +12,8 @@ use crate::{
     migrator::{MetadataLot, MetadataSource},
     models::{
         media::{
-            MediaDetails, MediaSearchItem, MediaSpecifics, MetadataCreator, MetadataImage,
-            MetadataImageLot, PodcastEpisode, PodcastSpecifics,
+            MediaDetails, MediaSearchFilters, MediaSearchItem, MediaSpecifics, MetadataCreator,
+            MetadataImage, MetadataImageLot, PodcastEpisode, PodcastSpecifics,
         },
         NamedObject, SearchDetails, SearchResults, StoredUrl,
     },
@@ -42,7 +42,11 @@ impl MediaProviderLanguages for ITunesService {
 
 impl ITunesService {
     pub async fn new(config: &ITunesConfig, page_limit: i32) -> Self {
-        let client = get_base_http_client(URL, vec![(ACCEPT, mime::JSON)]);
+        let client = get_base_http_client(
+            URL,
+            vec![(ACCEPT, mime::JSON)],
+            Some(MetadataSource::Itunes),
+        );
         Self {
             client,
             language: config.locale.clone(),
@@ -165,6 +169,7 @@ impl MediaProvider for ITunesService {
         Ok(MediaDetails {
             identifier: details.identifier,
             title: details.title,
+            alternative_titles: vec![],
             production_status: "Released".to_owned(),
             publish_date,
             publish_year: publish_date.map(|d| d.year()),
@@ -193,6 +198,7 @@ impl MediaProvider for ITunesService {
         query: &str,
         page: Option<i32>,
         _display_nsfw: bool,
+        _filters: &MediaSearchFilters,
     ) -> Result<SearchResults<MediaSearchItem>> {
         let page = page.unwrap_or(1);
         let mut rsp = self
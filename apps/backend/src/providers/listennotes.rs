@@ -16,8 +16,8 @@ use crate::{
     migrator::{MetadataLot, MetadataSource},
     models::{
         media::{
-            MediaDetails, MediaSearchItem, MediaSpecifics, MetadataCreator, MetadataImage,
-            MetadataImageLot, PartialMetadata, PodcastEpisode, PodcastSpecifics,
+            MediaDetails, MediaSearchFilters, MediaSearchItem, MediaSpecifics, MetadataCreator,
+            MetadataImage, MetadataImageLot, PartialMetadata, PodcastEpisode, PodcastSpecifics,
         },
         SearchDetails, SearchResults, StoredUrl,
     },
@@ -125,6 +125,7 @@ impl MediaProvider for ListennotesService {
         query: &str,
         page: Option<i32>,
         _display_nsfw: bool,
+        _filters: &MediaSearchFilters,
     ) -> Result<SearchResults<MediaSearchItem>> {
         let page = page.unwrap_or(1);
         #[serde_as]
@@ -217,6 +218,7 @@ impl ListennotesService {
         Ok(MediaDetails {
             identifier: podcast_data.id,
             title: podcast_data.title,
+            alternative_titles: vec![],
             is_nsfw: podcast_data.explicit_content,
             production_status: "Released".to_owned(),
             description: podcast_data.description,
@@ -261,7 +263,11 @@ impl ListennotesService {
 }
 
 async fn get_client_config(url: &str, api_token: &str) -> Client {
-    let client: Client = get_base_http_client(url, vec![("X-ListenAPI-Key", api_token)]);
+    let client: Client = get_base_http_client(
+        url,
+        vec![("X-ListenAPI-Key", api_token)],
+        Some(MetadataSource::Listennotes),
+    );
     if GENRES.get().is_none() {
         #[derive(Debug, Serialize, Deserialize, Default)]
         struct Genre {
@@ -1,19 +1,21 @@
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
+use http_types::mime;
+use itertools::Itertools;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use surf::Client;
+use surf::{http::headers::ACCEPT, Client};
 
 use crate::{
     config::{AnimeMalConfig, MangaMalConfig},
     migrator::{MetadataLot, MetadataSource},
     models::{
         media::{
-            AnimeSpecifics, MangaSpecifics, MediaDetails, MediaSearchItem, MediaSpecifics,
-            MetadataImage, MetadataImageLot, PartialMetadata,
+            AnimeSeason, AnimeSpecifics, MangaSpecifics, MediaDetails, MediaSearchFilters,
+            MediaSearchItem, MediaSpecifics, MetadataImage, MetadataImageLot, PartialMetadata,
         },
         NamedObject, SearchDetails, SearchResults, StoredUrl,
     },
@@ -22,6 +24,7 @@ use crate::{
 };
 
 static URL: &str = "https://api.myanimelist.net/v2/";
+static JIKAN_URL: &str = "https://api.jikan.moe/v4/";
 
 #[derive(Debug, Clone)]
 pub struct MalService {
@@ -42,13 +45,22 @@ impl MediaProviderLanguages for MalService {
 #[derive(Debug, Clone)]
 pub struct MalAnimeService {
     base: MalService,
+    jikan_client: Client,
 }
 
 impl MalAnimeService {
     pub async fn new(config: &AnimeMalConfig, page_limit: i32) -> Self {
         let client = get_client_config(URL, &config.client_id).await;
+        // DEV: Jikan is an unofficial API that uses the same ids as MAL and does
+        // not need a client id
+        let jikan_client = get_base_http_client(
+            JIKAN_URL,
+            vec![(ACCEPT, mime::JSON)],
+            Some(MetadataSource::Mal),
+        );
         Self {
             base: MalService { client, page_limit },
+            jikan_client,
         }
     }
 }
@@ -65,6 +77,7 @@ impl MediaProvider for MalAnimeService {
         query: &str,
         page: Option<i32>,
         _display_nsfw: bool,
+        _filters: &MediaSearchFilters,
     ) -> Result<SearchResults<MediaSearchItem>> {
         let (items, total, next_page) = search(
             &self.base.client,
@@ -79,6 +92,74 @@ impl MediaProvider for MalAnimeService {
             items,
         })
     }
+
+    async fn seasonal(
+        &self,
+        year: i32,
+        season: AnimeSeason,
+        page: Option<i32>,
+    ) -> Result<SearchResults<MediaSearchItem>> {
+        #[derive(Serialize, Deserialize, Debug)]
+        struct JikanImage {
+            large_image_url: Option<String>,
+        }
+        #[derive(Serialize, Deserialize, Debug)]
+        struct JikanImages {
+            jpg: JikanImage,
+        }
+        #[derive(Serialize, Deserialize, Debug)]
+        struct JikanAnime {
+            mal_id: i128,
+            title: String,
+            images: JikanImages,
+            year: Option<i32>,
+        }
+        #[derive(Serialize, Deserialize, Debug)]
+        struct JikanItems {
+            total: i32,
+        }
+        #[derive(Serialize, Deserialize, Debug)]
+        struct JikanPagination {
+            has_next_page: bool,
+            items: JikanItems,
+        }
+        #[derive(Serialize, Deserialize, Debug)]
+        struct JikanResponse {
+            data: Vec<JikanAnime>,
+            pagination: JikanPagination,
+        }
+        let page = page.unwrap_or(1);
+        let season = format!("{:?}", season).to_lowercase();
+        let rsp: JikanResponse = self
+            .jikan_client
+            .get(format!("seasons/{}/{}", year, season))
+            .query(&json!({ "page": page }))
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .body_json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        // DEV: Anime that air across seasons can be listed more than once
+        let items = rsp
+            .data
+            .into_iter()
+            .unique_by(|a| a.mal_id)
+            .map(|a| MediaSearchItem {
+                identifier: a.mal_id.to_string(),
+                title: a.title,
+                image: a.images.jpg.large_image_url,
+                publish_year: a.year,
+            })
+            .collect();
+        Ok(SearchResults {
+            details: SearchDetails {
+                total: rsp.pagination.items.total,
+                next_page: rsp.pagination.has_next_page.then_some(page + 1),
+            },
+            items,
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -107,6 +188,7 @@ impl MediaProvider for MalMangaService {
         query: &str,
         page: Option<i32>,
         _display_nsfw: bool,
+        _filters: &MediaSearchFilters,
     ) -> Result<SearchResults<MediaSearchItem>> {
         let (items, total, next_page) = search(
             &self.base.client,
@@ -124,7 +206,11 @@ impl MediaProvider for MalMangaService {
 }
 
 async fn get_client_config(url: &str, client_id: &str) -> Client {
-    get_base_http_client(url, vec![("X-MAL-CLIENT-ID", client_id)])
+    get_base_http_client(
+        url,
+        vec![("X-MAL-CLIENT-ID", client_id)],
+        Some(MetadataSource::Mal),
+    )
 }
 
 async fn search(
@@ -172,10 +258,18 @@ struct ItemImage {
     large: String,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+struct ItemAlternativeTitles {
+    synonyms: Option<Vec<String>>,
+    en: Option<String>,
+    ja: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct ItemNode {
     id: i128,
     title: String,
+    alternative_titles: Option<ItemAlternativeTitles>,
     main_picture: ItemImage,
     nsfw: Option<String>,
     synopsis: Option<String>,
@@ -200,7 +294,7 @@ struct ItemData {
 async fn details(client: &Client, media_type: &str, id: &str) -> Result<MediaDetails> {
     let details: ItemNode = client
         .get(format!("{}/{}", media_type, id))
-        .query(&json!({ "fields": "start_date,end_date,synopsis,genres,status,num_episodes,num_volumes,num_chapters,recommendations,related_manga,related_anime,mean,nsfw" }))
+        .query(&json!({ "fields": "alternative_titles,start_date,end_date,synopsis,genres,status,num_episodes,num_volumes,num_chapters,recommendations,related_manga,related_anime,mean,nsfw" }))
         .unwrap()
         .await
         .map_err(|e| anyhow!(e))?
@@ -257,6 +351,17 @@ async fn details(client: &Client, media_type: &str, id: &str) -> Result<MediaDet
     let data = MediaDetails {
         identifier: details.id.to_string(),
         title: details.title,
+        alternative_titles: details
+            .alternative_titles
+            .map(|a| {
+                [a.en, a.ja]
+                    .into_iter()
+                    .flatten()
+                    .chain(a.synonyms.unwrap_or_default())
+                    .filter(|t| !t.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default(),
         source: MetadataSource::Mal,
         description: details.synopsis,
         lot,
@@ -0,0 +1,322 @@
+// Responsible for tracking manga using the API of MangaDex.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use convert_case::{Case, Casing};
+use http_types::mime;
+use itertools::Itertools;
+use rust_decimal::Decimal;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use surf::{http::headers::ACCEPT, Client};
+
+use crate::{
+    config::MangaMangaDexConfig,
+    migrator::{MetadataLot, MetadataSource},
+    models::{
+        media::{
+            MangaSpecifics, MediaDetails, MediaSearchFilters, MediaSearchItem, MediaSpecifics,
+            MetadataCreator, MetadataImage, MetadataImageLot,
+        },
+        SearchDetails, SearchResults, StoredUrl,
+    },
+    traits::{MediaProvider, MediaProviderLanguages},
+    utils::get_base_http_client,
+};
+
+static URL: &str = "https://api.mangadex.org/";
+static COVERS_URL: &str = "https://uploads.mangadex.org/covers";
+static SAFE_CONTENT_RATINGS: [&str; 2] = ["safe", "suggestive"];
+static NSFW_CONTENT_RATINGS: [&str; 2] = ["erotica", "pornographic"];
+
+#[derive(Debug, Clone)]
+pub struct MangaDexService {
+    client: Client,
+    page_limit: i32,
+}
+
+impl MediaProviderLanguages for MangaDexService {
+    fn supported_languages() -> Vec<String> {
+        vec!["us".to_owned()]
+    }
+
+    fn default_language() -> String {
+        "us".to_owned()
+    }
+}
+
+impl MangaDexService {
+    pub async fn new(_config: &MangaMangaDexConfig, page_limit: i32) -> Self {
+        let client = get_base_http_client(
+            URL,
+            vec![(ACCEPT, mime::JSON)],
+            Some(MetadataSource::MangaDex),
+        );
+        Self { client, page_limit }
+    }
+}
+
+/// The texts are sent in all the languages that they are available in, keyed by
+/// the language code.
+type LocalizedString = HashMap<String, String>;
+
+fn localized(text: &LocalizedString, language: &str) -> Option<String> {
+    text.get(language).or_else(|| text.values().next()).cloned()
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Response<T> {
+    data: T,
+    #[serde(default)]
+    total: i32,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct TagAttributes {
+    name: LocalizedString,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Tag {
+    attributes: TagAttributes,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct MangaAttributes {
+    title: LocalizedString,
+    #[serde(default)]
+    alt_titles: Vec<LocalizedString>,
+    #[serde(default)]
+    description: LocalizedString,
+    status: Option<String>,
+    year: Option<i32>,
+    last_volume: Option<String>,
+    last_chapter: Option<String>,
+    content_rating: Option<String>,
+    #[serde(default)]
+    tags: Vec<Tag>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct RelationshipAttributes {
+    name: Option<String>,
+    file_name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Relationship {
+    #[serde(rename = "type")]
+    lot: String,
+    attributes: Option<RelationshipAttributes>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Manga {
+    id: String,
+    attributes: MangaAttributes,
+    #[serde(default)]
+    relationships: Vec<Relationship>,
+}
+
+impl Manga {
+    fn title(&self) -> String {
+        localized(&self.attributes.title, "en").unwrap_or_default()
+    }
+
+    /// The url of the cover, `size` being `256` or `512` for the thumbnails.
+    fn cover(&self, size: Option<i32>) -> Option<String> {
+        self.relationships
+            .iter()
+            .find(|r| r.lot == "cover_art")
+            .and_then(|r| r.attributes.as_ref())
+            .and_then(|a| a.file_name.as_ref())
+            .map(|f| match size {
+                Some(s) => format!("{}/{}/{}.{}.jpg", COVERS_URL, self.id, f, s),
+                None => format!("{}/{}/{}", COVERS_URL, self.id, f),
+            })
+    }
+
+    fn is_nsfw(&self) -> bool {
+        self.attributes
+            .content_rating
+            .as_deref()
+            .map_or(false, |r| NSFW_CONTENT_RATINGS.contains(&r))
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Rating {
+    bayesian: Option<Decimal>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Statistics {
+    rating: Option<Rating>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct StatisticsResponse {
+    statistics: HashMap<String, Statistics>,
+}
+
+impl MangaDexService {
+    async fn get<T: DeserializeOwned>(&self, path: &str, query: Vec<(&str, String)>) -> Result<T> {
+        self.client
+            .get(path)
+            .query(&query)
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .body_json()
+            .await
+            .map_err(|e| anyhow!(e))
+    }
+}
+
+#[async_trait]
+impl MediaProvider for MangaDexService {
+    async fn details(&self, identifier: &str) -> Result<MediaDetails> {
+        let manga = self
+            .get::<Response<Manga>>(
+                &format!("manga/{}", identifier),
+                vec![
+                    ("includes[]", "cover_art".to_owned()),
+                    ("includes[]", "author".to_owned()),
+                    ("includes[]", "artist".to_owned()),
+                ],
+            )
+            .await?
+            .data;
+        let provider_rating = self
+            .get::<StatisticsResponse>(&format!("statistics/manga/{}", identifier), vec![])
+            .await
+            .ok()
+            .and_then(|s| s.statistics.into_values().next())
+            .and_then(|s| s.rating)
+            .and_then(|r| r.bayesian)
+            .filter(|r| !r.is_zero());
+        let title = manga.title();
+        let images = manga
+            .cover(None)
+            .into_iter()
+            .map(|url| MetadataImage {
+                url: StoredUrl::Url(url),
+                lot: MetadataImageLot::Poster,
+            })
+            .collect();
+        let is_nsfw = manga.is_nsfw();
+        let creators = manga
+            .relationships
+            .iter()
+            .filter_map(|r| {
+                let role = match r.lot.as_str() {
+                    "author" => "Author",
+                    "artist" => "Artist",
+                    _ => return None,
+                };
+                r.attributes
+                    .as_ref()
+                    .and_then(|a| a.name.clone())
+                    .map(|name| MetadataCreator {
+                        name,
+                        role: role.to_owned(),
+                        image: None,
+                    })
+            })
+            .unique()
+            .collect();
+        let attributes = manga.attributes;
+        // DEV: These are empty until the manga is finished
+        let parse_last = |l: Option<String>| {
+            l.and_then(|l| l.parse::<f32>().ok())
+                .map(|l| l.floor() as i32)
+                .filter(|l| *l > 0)
+        };
+        Ok(MediaDetails {
+            identifier: manga.id,
+            lot: MetadataLot::Manga,
+            source: MetadataSource::MangaDex,
+            production_status: attributes
+                .status
+                .map(|s| s.to_case(Case::Title))
+                .unwrap_or_else(|| "Released".to_owned()),
+            title: title.clone(),
+            alternative_titles: attributes
+                .alt_titles
+                .iter()
+                .flat_map(|t| t.values().cloned())
+                .filter(|t| t != &title)
+                .unique()
+                .collect(),
+            description: localized(&attributes.description, "en"),
+            creators,
+            genres: attributes
+                .tags
+                .iter()
+                .filter_map(|t| localized(&t.attributes.name, "en"))
+                .unique()
+                .collect(),
+            publish_year: attributes.year,
+            publish_date: None,
+            specifics: MediaSpecifics::Manga(MangaSpecifics {
+                chapters: parse_last(attributes.last_chapter),
+                volumes: parse_last(attributes.last_volume),
+                url: None,
+            }),
+            provider_rating,
+            images,
+            is_nsfw: Some(is_nsfw),
+            videos: vec![],
+            suggestions: vec![],
+            groups: vec![],
+        })
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        display_nsfw: bool,
+        _filters: &MediaSearchFilters,
+    ) -> Result<SearchResults<MediaSearchItem>> {
+        let page = page.unwrap_or(1);
+        let mut params = vec![
+            ("title", query.to_owned()),
+            ("limit", self.page_limit.to_string()),
+            ("offset", ((page - 1) * self.page_limit).to_string()),
+            ("includes[]", "cover_art".to_owned()),
+        ];
+        let content_ratings = SAFE_CONTENT_RATINGS
+            .iter()
+            .chain(NSFW_CONTENT_RATINGS.iter().filter(|_| display_nsfw));
+        for rating in content_ratings {
+            params.push(("contentRating[]", rating.to_string()));
+        }
+        let search = self.get::<Response<Vec<Manga>>>("manga", params).await?;
+        let next_page = if page * self.page_limit < search.total {
+            Some(page + 1)
+        } else {
+            None
+        };
+        let items = search
+            .data
+            .into_iter()
+            .map(|m| MediaSearchItem {
+                title: m.title(),
+                image: m.cover(Some(256)),
+                publish_year: m.attributes.year,
+                identifier: m.id,
+            })
+            .collect();
+        Ok(SearchResults {
+            details: SearchDetails {
+                total: search.total,
+                next_page,
+            },
+            items,
+        })
+    }
+}
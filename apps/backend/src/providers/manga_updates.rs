@@ -10,8 +10,8 @@ use crate::{
     migrator::{MetadataLot, MetadataSource},
     models::{
         media::{
-            MangaSpecifics, MediaDetails, MediaSearchItem, MediaSpecifics, MetadataCreator,
-            MetadataImage, MetadataImageLot, PartialMetadata,
+            MangaSpecifics, MediaDetails, MediaSearchFilters, MediaSearchItem, MediaSpecifics,
+            MetadataCreator, MetadataImage, MetadataImageLot, PartialMetadata,
         },
         SearchDetails, SearchResults, StoredUrl,
     },
@@ -39,7 +39,11 @@ impl MediaProviderLanguages for MangaUpdatesService {
 
 impl MangaUpdatesService {
     pub async fn new(_config: &MangaMangaUpdatesConfig, page_limit: i32) -> Self {
-        let client = get_base_http_client(URL, vec![(ACCEPT, mime::JSON)]);
+        let client = get_base_http_client(
+            URL,
+            vec![(ACCEPT, mime::JSON)],
+            Some(MetadataSource::MangaUpdates),
+        );
         Self { client, page_limit }
     }
 }
@@ -74,11 +78,17 @@ struct ItemAuthor {
     lot: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+struct ItemAssociatedTitle {
+    title: String,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct ItemRecord {
     series_id: Option<i128>,
     related_series_id: Option<i128>,
     title: Option<String>,
+    associated: Option<Vec<ItemAssociatedTitle>>,
     description: Option<String>,
     image: Option<ItemImage>,
     status: Option<String>,
@@ -164,6 +174,12 @@ impl MediaProvider for MangaUpdatesService {
         let data = MediaDetails {
             identifier: data.series_id.unwrap().to_string(),
             title: data.title.unwrap(),
+            alternative_titles: data
+                .associated
+                .unwrap_or_default()
+                .into_iter()
+                .map(|a| a.title)
+                .collect(),
             description: data.description,
             source: MetadataSource::MangaUpdates,
             lot: MetadataLot::Manga,
@@ -209,6 +225,7 @@ impl MediaProvider for MangaUpdatesService {
         query: &str,
         page: Option<i32>,
         _display_nsfw: bool,
+        _filters: &MediaSearchFilters,
     ) -> Result<SearchResults<MediaSearchItem>> {
         let page = page.unwrap_or(1);
         let search: SearchResponse = self
@@ -1,11 +1,19 @@
 pub mod anilist;
 pub mod audible;
+pub mod bgg;
+pub mod comic_vine;
 pub mod google_books;
 pub mod igdb;
 pub mod itunes;
 pub mod listennotes;
 pub mod mal;
+pub mod manga_dex;
 pub mod manga_updates;
+pub mod musicbrainz;
 pub mod openlibrary;
+pub mod podcast_index;
 pub mod tmdb;
+pub mod upcitemdb;
 pub mod vndb;
+pub mod web;
+pub mod wikidata;
@@ -0,0 +1,260 @@
+// Responsible for tracking music albums using the release groups of MusicBrainz.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use http_types::mime;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use surf::{http::headers::ACCEPT, Client};
+
+use crate::{
+    config::MusicConfig,
+    migrator::{MetadataLot, MetadataSource},
+    models::{
+        media::{
+            MediaDetails, MediaSearchFilters, MediaSearchItem, MediaSpecifics, MetadataCreator,
+            MetadataImage, MetadataImageLot, MusicSpecifics,
+        },
+        NamedObject, SearchDetails, SearchResults, StoredUrl,
+    },
+    traits::{MediaProvider, MediaProviderLanguages},
+    utils::{convert_date_to_year, convert_string_to_date, get_base_http_client},
+};
+
+static URL: &str = "https://musicbrainz.org/ws/2/";
+static COVER_ART_URL: &str = "https://coverartarchive.org/release-group";
+
+#[derive(Debug, Clone)]
+pub struct MusicBrainzService {
+    client: Client,
+    page_limit: i32,
+}
+
+impl MediaProviderLanguages for MusicBrainzService {
+    fn supported_languages() -> Vec<String> {
+        vec!["us".to_owned()]
+    }
+
+    fn default_language() -> String {
+        "us".to_owned()
+    }
+}
+
+impl MusicBrainzService {
+    pub async fn new(_config: &MusicConfig, page_limit: i32) -> Self {
+        let client = get_base_http_client(
+            URL,
+            vec![(ACCEPT, mime::JSON)],
+            Some(MetadataSource::MusicBrainz),
+        );
+        Self { client, page_limit }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ArtistCredit {
+    name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Release {
+    id: String,
+    status: Option<String>,
+    date: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+struct ReleaseGroup {
+    id: String,
+    title: String,
+    first_release_date: Option<String>,
+    #[serde(default)]
+    artist_credit: Vec<ArtistCredit>,
+    #[serde(default)]
+    releases: Vec<Release>,
+    #[serde(default)]
+    genres: Vec<NamedObject>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Track {
+    /// The length of the track, in milliseconds.
+    length: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "kebab-case")]
+struct Medium {
+    track_count: i32,
+    #[serde(default)]
+    tracks: Vec<Track>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct ReleaseWithRecordings {
+    #[serde(default)]
+    media: Vec<Medium>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct SearchResponse {
+    count: i32,
+    offset: i32,
+    #[serde(rename = "release-groups")]
+    release_groups: Vec<ReleaseGroup>,
+}
+
+fn cover_art(identifier: &str) -> String {
+    format!("{}/{}/front", COVER_ART_URL, identifier)
+}
+
+#[async_trait]
+impl MediaProvider for MusicBrainzService {
+    async fn details(&self, identifier: &str) -> Result<MediaDetails> {
+        let item: ReleaseGroup = self
+            .client
+            .get(format!("release-group/{}", identifier))
+            .query(&serde_json::json!({
+                "inc": "artist-credits releases genres",
+                "fmt": "json"
+            }))
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .body_json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        // DEV: A release group is the album as a whole, while the tracks are only
+        // known for each of its releases, so the earliest official one is used
+        let release = item
+            .releases
+            .iter()
+            .sorted_by_key(|r| {
+                (
+                    r.status.as_deref() != Some("Official"),
+                    r.date.clone().filter(|d| !d.is_empty()).is_none(),
+                    r.date.clone(),
+                )
+            })
+            .next();
+        let specifics = match release {
+            Some(r) => {
+                let release: ReleaseWithRecordings = self
+                    .client
+                    .get(format!("release/{}", r.id))
+                    .query(&serde_json::json!({ "inc": "recordings", "fmt": "json" }))
+                    .unwrap()
+                    .await
+                    .map_err(|e| anyhow!(e))?
+                    .body_json()
+                    .await
+                    .map_err(|e| anyhow!(e))?;
+                let tracks = release.media.iter().map(|m| m.track_count).sum::<i32>();
+                let milliseconds = release
+                    .media
+                    .iter()
+                    .flat_map(|m| m.tracks.iter())
+                    .filter_map(|t| t.length)
+                    .sum::<i64>();
+                let duration = i32::try_from((milliseconds + 30_000) / 60_000).unwrap_or(i32::MAX);
+                MusicSpecifics {
+                    tracks: Some(tracks).filter(|t| *t > 0),
+                    duration: Some(duration).filter(|d| *d > 0),
+                }
+            }
+            None => MusicSpecifics::default(),
+        };
+        Ok(MediaDetails {
+            identifier: item.id.clone(),
+            lot: MetadataLot::Music,
+            source: MetadataSource::MusicBrainz,
+            production_status: "Released".to_owned(),
+            title: item.title,
+            alternative_titles: vec![],
+            description: None,
+            creators: item
+                .artist_credit
+                .into_iter()
+                .map(|a| MetadataCreator {
+                    name: a.name,
+                    role: "Artist".to_owned(),
+                    image: None,
+                })
+                .unique()
+                .collect(),
+            genres: item.genres.into_iter().map(|g| g.name).unique().collect(),
+            publish_year: item
+                .first_release_date
+                .as_ref()
+                .and_then(|d| convert_date_to_year(d)),
+            publish_date: item
+                .first_release_date
+                .and_then(|d| convert_string_to_date(&d)),
+            specifics: MediaSpecifics::Music(specifics),
+            provider_rating: None,
+            images: vec![MetadataImage {
+                url: StoredUrl::Url(cover_art(&item.id)),
+                lot: MetadataImageLot::Poster,
+            }],
+            is_nsfw: None,
+            videos: vec![],
+            suggestions: vec![],
+            groups: vec![],
+        })
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        _display_nsfw: bool,
+        _filters: &MediaSearchFilters,
+    ) -> Result<SearchResults<MediaSearchItem>> {
+        let page = page.unwrap_or(1);
+        let search: SearchResponse = self
+            .client
+            .get("release-group")
+            .query(&serde_json::json!({
+                "query": query,
+                "limit": self.page_limit,
+                "offset": (page - 1) * self.page_limit,
+                "fmt": "json"
+            }))
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .body_json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let next_page = if search.offset + self.page_limit < search.count {
+            Some(page + 1)
+        } else {
+            None
+        };
+        let items = search
+            .release_groups
+            .into_iter()
+            .map(|r| MediaSearchItem {
+                image: Some(format!("{}-250", cover_art(&r.id))),
+                title: match r.artist_credit.is_empty() {
+                    true => r.title,
+                    false => format!(
+                        "{} - {}",
+                        r.artist_credit.into_iter().map(|a| a.name).join(", "),
+                        r.title
+                    ),
+                },
+                publish_year: r.first_release_date.and_then(|d| convert_date_to_year(&d)),
+                identifier: r.id,
+            })
+            .collect();
+        Ok(SearchResults {
+            details: SearchDetails {
+                total: search.count,
+                next_page,
+            },
+            items,
+        })
+    }
+}
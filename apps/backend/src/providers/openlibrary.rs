@@ -1,32 +1,37 @@
+use std::collections::HashMap;
+
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, NaiveDate, Utc};
 use convert_case::{Case, Casing};
 use http_types::mime;
 use itertools::Itertools;
 use scraper::{Html, Selector};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use surf::{http::headers::ACCEPT, Client};
-use surf_governor::GovernorMiddleware;
-use surf_retry::{ExponentialBackoff, RetryMiddleware};
+use surf::{
+    http::{headers::ACCEPT, StatusCode},
+    Client,
+};
 
 use crate::{
     config::OpenlibraryConfig,
     migrator::{MetadataLot, MetadataSource},
     models::{
         media::{
-            BookSpecifics, MediaDetails, MediaSearchItem, MediaSpecifics, MetadataCreator,
-            MetadataImage, MetadataImageLot, PartialMetadata,
+            BookSpecifics, ExternalIdentifierType, MediaDeletedOnProvider, MediaDetails,
+            MediaSearchFilters, MediaSearchItem, MediaSpecifics, MediaUpcomingItem,
+            MetadataCreator, MetadataImage, MetadataImageLot, PartialMetadata,
         },
         SearchDetails, SearchResults, StoredUrl,
     },
     traits::{MediaProvider, MediaProviderLanguages},
-    utils::get_base_http_client,
+    utils::{get_base_http_client, normalize_text},
 };
 
 static URL: &str = "https://openlibrary.org/";
 static IMAGE_BASE_URL: &str = "https://covers.openlibrary.org";
+static MAX_REDIRECTS: usize = 5;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 struct BookSearchResults {
@@ -58,6 +63,7 @@ pub struct OpenlibraryService {
     image_size: String,
     client: Client,
     page_limit: i32,
+    dedupe_search_results: bool,
 }
 
 impl MediaProviderLanguages for OpenlibraryService {
@@ -72,12 +78,17 @@ impl MediaProviderLanguages for OpenlibraryService {
 
 impl OpenlibraryService {
     pub async fn new(config: &OpenlibraryConfig, page_limit: i32) -> Self {
-        let client = get_base_http_client(URL, vec![(ACCEPT, mime::JSON)]);
+        let client = get_base_http_client(
+            URL,
+            vec![(ACCEPT, mime::JSON)],
+            Some(MetadataSource::Openlibrary),
+        );
         Self {
             image_url: IMAGE_BASE_URL.to_owned(),
             image_size: config.cover_image_size.to_string(),
             client,
             page_limit,
+            dedupe_search_results: config.dedupe_search_results,
         }
     }
 }
@@ -88,8 +99,9 @@ impl MediaProvider for OpenlibraryService {
         #[derive(Debug, Serialize, Deserialize, Clone)]
         struct OpenlibraryAuthor {
             author: OpenlibraryKey,
-            #[serde(rename = "type", flatten)]
-            role: Option<OpenlibraryKey>,
+            #[serde(rename = "type")]
+            lot: Option<OpenlibraryKey>,
+            role: Option<String>,
         }
         #[derive(Debug, Serialize, Deserialize, Clone)]
         #[serde(untagged)]
@@ -116,18 +128,44 @@ impl MediaProvider for OpenlibraryService {
             authors: Option<Vec<OpenlibraryAuthorResponse>>,
             subjects: Option<Vec<String>>,
         }
-        let mut rsp = self
-            .client
-            .get(format!("works/{}.json", identifier))
-            .await
-            .map_err(|e| anyhow!(e))?;
-
-        let data: OpenlibraryBook = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+        #[derive(Debug, Serialize, Deserialize, Clone)]
+        struct OpenlibraryWorkType {
+            #[serde(rename = "type")]
+            lot: OpenlibraryKey,
+            location: Option<String>,
+        }
+        let mut identifier = identifier.to_owned();
+        let mut redirects = 0;
+        let data = loop {
+            let mut rsp = self
+                .client
+                .get(format!("works/{}.json", identifier))
+                .await
+                .map_err(|e| anyhow!(e))?;
+            if rsp.status() == StatusCode::NotFound {
+                return Err(MediaDeletedOnProvider.into());
+            }
+            let data: serde_json::Value = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+            let work_type: OpenlibraryWorkType = serde_json::from_value(data.clone())?;
+            match (work_type.lot.key.as_str(), work_type.location) {
+                // DEV: Openlibrary leaves a redirect behind when a work is merged
+                // into another one.
+                ("/type/redirect", Some(location)) if redirects < MAX_REDIRECTS => {
+                    identifier = get_key(&location);
+                    redirects += 1;
+                }
+                ("/type/redirect", _) | ("/type/delete", _) => {
+                    return Err(MediaDeletedOnProvider.into());
+                }
+                _ => break serde_json::from_value::<OpenlibraryBook>(data)?,
+            }
+        };
 
         let identifier = get_key(&data.key);
 
         #[derive(Debug, Serialize, Deserialize, Clone)]
         struct OpenlibraryEdition {
+            title: Option<String>,
             publish_date: Option<String>,
             number_of_pages: Option<i32>,
             covers: Option<Vec<i64>>,
@@ -145,6 +183,13 @@ impl MediaProvider for OpenlibraryService {
             rsp.body_json().await.map_err(|e| anyhow!(e))?;
 
         let entries = editions.entries.unwrap_or_default();
+        // DEV: Editions are usually translations, so their titles are the names
+        // the book is known by in other languages
+        let alternative_titles = entries
+            .iter()
+            .filter_map(|f| f.title.clone())
+            .unique()
+            .collect();
         let all_pages = entries
             .iter()
             .filter_map(|f| f.number_of_pages)
@@ -165,18 +210,28 @@ impl MediaProvider for OpenlibraryService {
             name: String,
             photos: Option<Vec<i64>>,
         }
+        // DEV: The same author is repeated for every edition they appear in, so
+        // collapse them before requesting their details.
+        let authors = data
+            .authors
+            .unwrap_or_default()
+            .into_iter()
+            .map(|a| match a {
+                OpenlibraryAuthorResponse::Flat(s) => (s.key, "Author".to_owned()),
+                OpenlibraryAuthorResponse::Nested(s) => {
+                    let role = s
+                        .role
+                        .filter(|r| !r.trim().is_empty())
+                        .map(|r| r.trim().to_case(Case::Title))
+                        .or_else(|| s.lot.map(|l| get_role_from_key(&l.key)))
+                        .unwrap_or_else(|| "Author".to_owned());
+                    (s.author.key, role)
+                }
+            })
+            .unique()
+            .collect_vec();
         let mut creators = vec![];
-        for a in data.authors.unwrap_or_default().iter() {
-            let (key, role) = match a {
-                OpenlibraryAuthorResponse::Flat(s) => (s.key.to_owned(), "Author".to_owned()),
-                OpenlibraryAuthorResponse::Nested(s) => (
-                    s.author.key.to_owned(),
-                    s.role
-                        .as_ref()
-                        .map(|r| r.key.clone())
-                        .unwrap_or_else(|| "Author".to_owned()),
-                ),
-            };
+        for (key, role) in authors {
             let mut rsp = self
                 .client
                 .get(format!("{}.json", key))
@@ -291,6 +346,7 @@ impl MediaProvider for OpenlibraryService {
         Ok(MediaDetails {
             identifier: get_key(&data.key),
             title: data.title,
+            alternative_titles,
             production_status: "Released".to_owned(),
             description,
             lot: MetadataLot::Book,
@@ -316,6 +372,7 @@ impl MediaProvider for OpenlibraryService {
         query: &str,
         page: Option<i32>,
         _display_nsfw: bool,
+        filters: &MediaSearchFilters,
     ) -> Result<SearchResults<MediaSearchItem>> {
         let page = page.unwrap_or(1);
         #[derive(Debug, Serialize, Deserialize)]
@@ -341,11 +398,23 @@ impl MediaProvider for OpenlibraryService {
             "first_publish_year",
         ]
         .join(",");
+        let mut query = query.to_owned();
+        if filters.year_from.is_some() || filters.year_to.is_some() {
+            let year = |y: Option<i32>| y.map(|y| y.to_string()).unwrap_or_else(|| "*".to_owned());
+            query.push_str(&format!(
+                " first_publish_year:[{} TO {}]",
+                year(filters.year_from),
+                year(filters.year_to)
+            ));
+        }
+        if let Some(language) = &filters.language {
+            query.push_str(&format!(" language:{}", language));
+        }
         let mut rsp = self
             .client
             .get("search.json")
             .query(&json!({
-                "q": query.to_owned(),
+                "q": query,
                 "fields": fields,
                 "offset": (page - 1) * self.page_limit,
                 "limit": self.page_limit,
@@ -375,6 +444,11 @@ impl MediaProvider for OpenlibraryService {
                 }
             })
             .collect_vec();
+        let resp = if self.dedupe_search_results {
+            dedupe_search_items(resp)
+        } else {
+            resp
+        };
         let data = BookSearchResults {
             total: search.num_found,
             items: resp,
@@ -401,6 +475,93 @@ impl MediaProvider for OpenlibraryService {
                 .collect(),
         })
     }
+
+    async fn upcoming(&self, creators: &[String]) -> Result<Vec<MediaUpcomingItem>> {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct OpenlibraryBook {
+            key: String,
+            title: String,
+            author_name: Option<Vec<String>>,
+            cover_i: Option<i64>,
+            publish_date: Option<Vec<String>>,
+            subject: Option<Vec<String>>,
+        }
+        #[derive(Serialize, Deserialize, Debug)]
+        struct OpenLibrarySearchResponse {
+            docs: Vec<OpenlibraryBook>,
+        }
+        let today = Utc::now().date_naive();
+        let mut items = vec![];
+        // DEV: Openlibrary does not know about upcoming books in general, only
+        // the new works of a particular author can be looked up
+        for creator in creators {
+            let mut rsp = self
+                .client
+                .get("search.json")
+                .query(&json!({
+                    "author": creator,
+                    "sort": "new",
+                    "fields": "key,title,author_name,cover_i,publish_date,subject",
+                    "limit": self.page_limit,
+                }))
+                .unwrap()
+                .await
+                .map_err(|e| anyhow!(e))?;
+            let search: OpenLibrarySearchResponse =
+                rsp.body_json().await.map_err(|e| anyhow!(e))?;
+            for book in search.docs {
+                let release_date = book
+                    .publish_date
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|d| Self::parse_date(d))
+                    .filter(|d| d > &today)
+                    .min();
+                if let Some(release_date) = release_date {
+                    items.push(MediaUpcomingItem {
+                        item: MediaSearchItem {
+                            identifier: get_key(&book.key),
+                            title: book.title,
+                            image: book.cover_i.map(|c| self.get_book_cover_image_url(c)),
+                            publish_year: Some(release_date.year()),
+                        },
+                        release_date,
+                        genres: book.subject.unwrap_or_default(),
+                        creators: book.author_name.unwrap_or_default(),
+                        is_nsfw: None,
+                    });
+                }
+            }
+        }
+        Ok(items
+            .into_iter()
+            .unique_by(|i| i.item.identifier.clone())
+            .collect())
+    }
+
+    async fn id_from_external(
+        &self,
+        id_type: ExternalIdentifierType,
+        value: &str,
+    ) -> Result<Option<String>> {
+        if id_type != ExternalIdentifierType::Isbn {
+            return Ok(None);
+        }
+        let mut rsp = self
+            .client
+            .get(format!("isbn/{}.json", value))
+            .await
+            .map_err(|e| anyhow!(e))?;
+        if rsp.status() == StatusCode::NotFound {
+            return Ok(None);
+        }
+        #[derive(Debug, Serialize, Deserialize, Clone)]
+        struct Response {
+            works: Vec<OpenlibraryKey>,
+        }
+        let details: Response = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+        Ok(details.works.first().map(|k| get_key(&k.key)))
+    }
 }
 
 impl OpenlibraryService {
@@ -428,27 +589,50 @@ impl OpenlibraryService {
         }
         None
     }
+}
 
-    /// Get a book's ID from its ISBN
-    pub async fn id_from_isbn(&self, isbn: &str) -> Option<String> {
-        let mut resp = self
-            .client
-            .clone()
-            .with(GovernorMiddleware::per_second(1).ok()?)
-            .with(RetryMiddleware::new(
-                3,
-                ExponentialBackoff::builder().build_with_max_retries(3),
-                1,
-            ))
-            .get(format!("isbn/{}.json", isbn))
-            .await
-            .ok()?;
-        #[derive(Debug, Serialize, Deserialize, Clone)]
-        struct Response {
-            works: Vec<OpenlibraryKey>,
+/// Openlibrary returns a lot of near duplicate works for popular books. Collapse
+/// the ones that have the same title and authors into the first one, keeping the
+/// covers of all of them.
+fn dedupe_search_items(items: Vec<BookSearchItem>) -> Vec<BookSearchItem> {
+    let mut deduped: Vec<BookSearchItem> = vec![];
+    let mut keys: HashMap<(String, Vec<String>), usize> = HashMap::new();
+    for item in items {
+        let key = (
+            normalize_text(&item.title),
+            item.author_names
+                .iter()
+                .map(|a| normalize_text(a))
+                .sorted()
+                .collect_vec(),
+        );
+        if let Some(idx) = keys.get(&key) {
+            let existing = &mut deduped[*idx];
+            for image in item.images {
+                if !existing.images.contains(&image) {
+                    existing.images.push(image);
+                }
+            }
+            existing.publish_year = match (existing.publish_year, item.publish_year) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
+        } else {
+            keys.insert(key, deduped.len());
+            deduped.push(item);
         }
-        let details: Response = resp.body_json().await.ok()?;
-        details.works.first().map(|k| get_key(&k.key))
+    }
+    deduped
+}
+
+/// Convert a role reference like `/type/author_role` into a readable label
+/// like `Author`.
+fn get_role_from_key(key: &str) -> String {
+    let role = get_key(key);
+    let role = role.strip_suffix("_role").unwrap_or(&role);
+    match role {
+        "" | "author" => "Author".to_owned(),
+        r => r.to_case(Case::Title),
     }
 }
 
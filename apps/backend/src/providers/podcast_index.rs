@@ -0,0 +1,279 @@
+// Responsible for tracking podcasts using the API of Podcast Index.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::Datelike;
+use http_types::mime;
+use itertools::Itertools;
+use sea_orm::prelude::DateTimeUtc;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use surf::{
+    http::headers::{ACCEPT, AUTHORIZATION},
+    Client,
+};
+
+use crate::{
+    config::PodcastIndexConfig,
+    migrator::{MetadataLot, MetadataSource},
+    models::{
+        media::{
+            MediaDetails, MediaSearchFilters, MediaSearchItem, MediaSpecifics, MetadataCreator,
+            MetadataImage, MetadataImageLot, PodcastEpisode, PodcastSpecifics,
+        },
+        SearchDetails, SearchResults, StoredUrl,
+    },
+    traits::{MediaProvider, MediaProviderLanguages},
+    utils::{get_base_http_client, get_now_timestamp},
+};
+
+static URL: &str = "https://api.podcastindex.org/api/1.0/";
+// DEV: The search is not paginated, so this many results are fetched and then
+// paginated locally
+static SEARCH_LIMIT: i32 = 100;
+static EPISODES_LIMIT: i32 = 1000;
+
+#[derive(Debug, Clone)]
+pub struct PodcastIndexService {
+    client: Client,
+    api_key: String,
+    api_secret: String,
+    page_limit: i32,
+}
+
+impl MediaProviderLanguages for PodcastIndexService {
+    fn supported_languages() -> Vec<String> {
+        vec!["us".to_owned()]
+    }
+
+    fn default_language() -> String {
+        "us".to_owned()
+    }
+}
+
+impl PodcastIndexService {
+    pub async fn new(config: &PodcastIndexConfig, page_limit: i32) -> Self {
+        let client = get_base_http_client(
+            URL,
+            vec![(ACCEPT, mime::JSON)],
+            Some(MetadataSource::PodcastIndex),
+        );
+        Self {
+            client,
+            api_key: config.api_key.clone(),
+            api_secret: config.api_secret.clone(),
+            page_limit,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Feed {
+    id: i64,
+    title: String,
+    description: Option<String>,
+    author: Option<String>,
+    owner_name: Option<String>,
+    image: Option<String>,
+    artwork: Option<String>,
+    /// The unix timestamp of the newest episode.
+    newest_item_publish_time: Option<i64>,
+    /// The categories keyed by their id.
+    categories: Option<HashMap<String, String>>,
+    episode_count: Option<i32>,
+    #[serde(default)]
+    explicit: bool,
+}
+
+impl Feed {
+    fn image(&self) -> Option<String> {
+        self.artwork
+            .clone()
+            .or_else(|| self.image.clone())
+            .filter(|i| !i.is_empty())
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Episode {
+    id: i64,
+    title: String,
+    description: Option<String>,
+    /// The unix timestamp at which the episode was published.
+    date_published: i64,
+    /// The length of the episode, in seconds.
+    duration: Option<i32>,
+    image: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct FeedResponse {
+    feed: Feed,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct SearchResponse {
+    #[serde(default)]
+    feeds: Vec<Feed>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct EpisodesResponse {
+    #[serde(default)]
+    items: Vec<Episode>,
+}
+
+fn from_timestamp(timestamp: i64) -> Option<DateTimeUtc> {
+    DateTimeUtc::from_timestamp(timestamp, 0)
+}
+
+impl PodcastIndexService {
+    /// Every request is authenticated with a hash of the credentials and the
+    /// current time, which must be sent along with it.
+    async fn get<T: DeserializeOwned>(&self, path: &str, query: serde_json::Value) -> Result<T> {
+        let now = (get_now_timestamp() / 1000).to_string();
+        let mut hasher = Sha1::new();
+        hasher.update(format!("{}{}{}", self.api_key, self.api_secret, now));
+        let authorization = hex::encode(hasher.finalize());
+        self.client
+            .get(path)
+            .query(&query)
+            .unwrap()
+            .header("X-Auth-Key", self.api_key.as_str())
+            .header("X-Auth-Date", now.as_str())
+            .header(AUTHORIZATION, authorization.as_str())
+            .await
+            .map_err(|e| anyhow!(e))?
+            .body_json()
+            .await
+            .map_err(|e| anyhow!(e))
+    }
+}
+
+#[async_trait]
+impl MediaProvider for PodcastIndexService {
+    async fn details(&self, identifier: &str) -> Result<MediaDetails> {
+        let feed = self
+            .get::<FeedResponse>("podcasts/byfeedid", serde_json::json!({ "id": identifier }))
+            .await?
+            .feed;
+        let episodes = self
+            .get::<EpisodesResponse>(
+                "episodes/byfeedid",
+                serde_json::json!({ "id": identifier, "max": EPISODES_LIMIT }),
+            )
+            .await?
+            .items;
+        let episodes = episodes
+            .into_iter()
+            .filter_map(|e| from_timestamp(e.date_published).map(|d| (d, e)))
+            .sorted_by_key(|(d, _)| *d)
+            .enumerate()
+            .map(|(idx, (date, e))| PodcastEpisode {
+                number: i32::try_from(idx).unwrap() + 1,
+                id: e.id.to_string(),
+                runtime: e.duration.map(|d| d / 60).filter(|d| *d > 0),
+                overview: e.description,
+                title: e.title,
+                publish_date: date.date_naive(),
+                thumbnail: e.image.filter(|i| !i.is_empty()),
+            })
+            .collect_vec();
+        let publish_date = episodes.first().map(|e| e.publish_date);
+        let creators = feed
+            .author
+            .clone()
+            .or_else(|| feed.owner_name.clone())
+            .filter(|a| !a.is_empty())
+            .into_iter()
+            .map(|name| MetadataCreator {
+                name,
+                role: "Publishing".to_owned(),
+                image: None,
+            })
+            .collect();
+        let images = feed
+            .image()
+            .into_iter()
+            .map(|url| MetadataImage {
+                url: StoredUrl::Url(url),
+                lot: MetadataImageLot::Poster,
+            })
+            .collect();
+        Ok(MediaDetails {
+            identifier: feed.id.to_string(),
+            lot: MetadataLot::Podcast,
+            source: MetadataSource::PodcastIndex,
+            production_status: "Released".to_owned(),
+            title: feed.title,
+            alternative_titles: vec![],
+            description: feed.description.filter(|d| !d.is_empty()),
+            creators,
+            genres: feed
+                .categories
+                .unwrap_or_default()
+                .into_values()
+                .unique()
+                .collect(),
+            publish_year: publish_date.map(|d| d.year()),
+            publish_date,
+            specifics: MediaSpecifics::Podcast(PodcastSpecifics {
+                total_episodes: feed
+                    .episode_count
+                    .unwrap_or_else(|| i32::try_from(episodes.len()).unwrap()),
+                episodes,
+            }),
+            // DEV: API does not return any ratings
+            provider_rating: None,
+            images,
+            is_nsfw: Some(feed.explicit),
+            videos: vec![],
+            suggestions: vec![],
+            groups: vec![],
+        })
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        page: Option<i32>,
+        display_nsfw: bool,
+        _filters: &MediaSearchFilters,
+    ) -> Result<SearchResults<MediaSearchItem>> {
+        let page = page.unwrap_or(1);
+        let mut params = serde_json::json!({ "q": query, "max": SEARCH_LIMIT });
+        if !display_nsfw {
+            params["clean"] = serde_json::json!(true);
+        }
+        let search = self.get::<SearchResponse>("search/byterm", params).await?;
+        let total = i32::try_from(search.feeds.len()).unwrap();
+        let next_page = if page * self.page_limit < total {
+            Some(page + 1)
+        } else {
+            None
+        };
+        let items = search
+            .feeds
+            .into_iter()
+            .skip(((page - 1) * self.page_limit).try_into().unwrap())
+            .take(self.page_limit.try_into().unwrap())
+            .map(|f| MediaSearchItem {
+                image: f.image(),
+                publish_year: f
+                    .newest_item_publish_time
+                    .and_then(from_timestamp)
+                    .map(|d| d.year()),
+                identifier: f.id.to_string(),
+                title: f.title,
+            })
+            .collect();
+        Ok(SearchResults {
+            details: SearchDetails { total, next_page },
+            items,
+        })
+    }
+}
@@ -2,13 +2,17 @@ use std::sync::OnceLock;
 
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
+use chrono::Datelike;
 use hashbag::HashBag;
 use itertools::Itertools;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use surf::{http::headers::AUTHORIZATION, Client};
+use surf::{
+    http::{headers::AUTHORIZATION, StatusCode},
+    Client,
+};
 
 use crate::{
     config::{MoviesTmdbConfig, ShowsTmdbConfig},
@@ -16,7 +20,8 @@ use crate::{
     migrator::{MetadataLot, MetadataSource},
     models::{
         media::{
-            MediaDetails, MediaSearchItem, MediaSpecifics, MetadataCreator, MetadataImage,
+            ExternalIdentifierType, MediaDeletedOnProvider, MediaDetails, MediaSearchFilters,
+            MediaSearchItem, MediaSpecifics, MediaUpcomingItem, MetadataCreator, MetadataImage,
             MetadataImageLot, MetadataImages, MetadataVideo, MetadataVideoSource, MovieSpecifics,
             PartialMetadata, ShowEpisode, ShowSeason, ShowSpecifics,
         },
@@ -84,10 +89,33 @@ struct TmdbVideoResults {
     results: Vec<TmdbVideo>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct TmdbAlternativeTitle {
+    title: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+struct TmdbAlternativeTitles {
+    // DEV: Movies return `titles` while shows return `results`
+    #[serde(alias = "results")]
+    titles: Vec<TmdbAlternativeTitle>,
+}
+
+impl TmdbAlternativeTitles {
+    fn with_original(self, original: Option<String>) -> Vec<String> {
+        original
+            .into_iter()
+            .chain(self.titles.into_iter().map(|t| t.title))
+            .collect()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct TmdbMovie {
     id: i32,
     title: String,
+    original_title: Option<String>,
+    alternative_titles: Option<TmdbAlternativeTitles>,
     adult: Option<bool>,
     vote_average: Option<Decimal>,
     overview: Option<String>,
@@ -142,6 +170,14 @@ impl TmdbMovieService {
         }
     }
 
+    /// Request details in the given language instead of the configured locale.
+    pub fn with_language(mut self, language: Option<String>) -> Self {
+        if let Some(l) = language {
+            self.base.language = l;
+        }
+        self
+    }
+
     async fn group_details(
         &self,
         identifier: &str,
@@ -222,11 +258,14 @@ impl MediaProvider for TmdbMovieService {
             .get(format!("movie/{}", &identifier))
             .query(&json!({
                 "language": self.base.language,
-                "append_to_response": "videos",
+                "append_to_response": "videos,alternative_titles",
             }))
             .unwrap()
             .await
             .map_err(|e| anyhow!(e))?;
+        if rsp.status() == StatusCode::NotFound {
+            return Err(MediaDeletedOnProvider.into());
+        }
         let data: TmdbMovie = rsp.body_json().await.map_err(|e| anyhow!(e))?;
         let mut videos = vec![];
         if let Some(vid) = data.videos {
@@ -342,6 +381,10 @@ impl MediaProvider for TmdbMovieService {
             source: MetadataSource::Tmdb,
             production_status: data.status.unwrap_or_else(|| "Released".to_owned()),
             title: data.title,
+            alternative_titles: data
+                .alternative_titles
+                .unwrap_or_default()
+                .with_original(data.original_title),
             genres: data
                 .genres
                 .unwrap_or_default()
@@ -386,17 +429,22 @@ impl MediaProvider for TmdbMovieService {
         query: &str,
         page: Option<i32>,
         display_nsfw: bool,
+        filters: &MediaSearchFilters,
     ) -> Result<SearchResults<MediaSearchItem>> {
         let page = page.unwrap_or(1);
+        let mut params = json!({
+            "query": query.to_owned(),
+            "page": page,
+            "language": self.base.language,
+            "include_adult": display_nsfw,
+        });
+        if let Some(year) = filters.exact_year() {
+            params["primary_release_year"] = json!(year);
+        }
         let mut rsp = self
             .client
             .get("search/movie")
-            .query(&json!({
-                "query": query.to_owned(),
-                "page": page,
-                "language": self.base.language,
-                "include_adult": display_nsfw,
-            }))
+            .query(&params)
             .unwrap()
             .await
             .map_err(|e| anyhow!(e))?;
@@ -425,6 +473,86 @@ impl MediaProvider for TmdbMovieService {
             items: resp.to_vec(),
         })
     }
+
+    async fn id_from_external(
+        &self,
+        id_type: ExternalIdentifierType,
+        value: &str,
+    ) -> Result<Option<String>> {
+        self.base
+            .find_by_external_id(&self.client, id_type, value, MetadataLot::Movie)
+            .await
+    }
+
+    async fn trending(&self, page: Option<i32>) -> Result<SearchResults<MediaSearchItem>> {
+        self.base.trending(&self.client, "movie", page).await
+    }
+
+    async fn upcoming(&self, _creators: &[String]) -> Result<Vec<MediaUpcomingItem>> {
+        #[derive(Debug, Serialize, Deserialize, Clone)]
+        struct TmdbGenre {
+            id: i32,
+            name: String,
+        }
+        #[derive(Debug, Serialize, Deserialize, Clone)]
+        struct TmdbGenresResponse {
+            genres: Vec<TmdbGenre>,
+        }
+        #[derive(Debug, Serialize, Deserialize, Clone)]
+        struct TmdbUpcomingMovie {
+            id: i32,
+            title: String,
+            poster_path: Option<String>,
+            release_date: Option<String>,
+            genre_ids: Vec<i32>,
+            adult: Option<bool>,
+        }
+        let genres: TmdbGenresResponse = self
+            .client
+            .get("genre/movie/list")
+            .query(&json!({ "language": self.base.language }))
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .body_json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let upcoming: TmdbListResponse<TmdbUpcomingMovie> = self
+            .client
+            .get("movie/upcoming")
+            .query(&json!({ "language": self.base.language }))
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?
+            .body_json()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let items = upcoming
+            .results
+            .into_iter()
+            .filter_map(|m| {
+                let release_date = m.release_date.and_then(|d| convert_string_to_date(&d))?;
+                Some(MediaUpcomingItem {
+                    item: MediaSearchItem {
+                        identifier: m.id.to_string(),
+                        title: m.title,
+                        image: m.poster_path.map(|p| self.base.get_cover_image_url(p)),
+                        publish_year: Some(release_date.year()),
+                    },
+                    release_date,
+                    genres: genres
+                        .genres
+                        .iter()
+                        .filter(|g| m.genre_ids.contains(&g.id))
+                        .map(|g| g.name.clone())
+                        .collect(),
+                    creators: vec![],
+                    is_nsfw: m.adult,
+                })
+            })
+            .collect();
+        Ok(items)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -443,6 +571,14 @@ impl TmdbShowService {
             },
         }
     }
+
+    /// Request details in the given language instead of the configured locale.
+    pub fn with_language(mut self, language: Option<String>) -> Self {
+        if let Some(l) = language {
+            self.base.language = l;
+        }
+        self
+    }
 }
 
 #[async_trait]
@@ -456,6 +592,8 @@ impl MediaProvider for TmdbShowService {
         struct TmdbShow {
             id: i32,
             name: String,
+            original_name: Option<String>,
+            alternative_titles: Option<TmdbAlternativeTitles>,
             adult: Option<bool>,
             overview: Option<String>,
             poster_path: Option<String>,
@@ -473,11 +611,14 @@ impl MediaProvider for TmdbShowService {
             .get(format!("tv/{}", &identifier))
             .query(&json!({
                 "language": self.base.language,
-                "append_to_response": "videos",
+                "append_to_response": "videos,alternative_titles",
             }))
             .unwrap()
             .await
             .map_err(|e| anyhow!(e))?;
+        if rsp.status() == StatusCode::NotFound {
+            return Err(MediaDeletedOnProvider.into());
+        }
         let show_data: TmdbShow = rsp.body_json().await.map_err(|e| anyhow!(e))?;
         let mut videos = vec![];
         if let Some(vid) = show_data.videos {
@@ -609,6 +750,10 @@ impl MediaProvider for TmdbShowService {
         Ok(MediaDetails {
             identifier: show_data.id.to_string(),
             title: show_data.name,
+            alternative_titles: show_data
+                .alternative_titles
+                .unwrap_or_default()
+                .with_original(show_data.original_name),
             is_nsfw: show_data.adult,
             lot: MetadataLot::Show,
             production_status: show_data.status.unwrap_or_else(|| "Released".to_owned()),
@@ -694,17 +839,22 @@ impl MediaProvider for TmdbShowService {
         query: &str,
         page: Option<i32>,
         display_nsfw: bool,
+        filters: &MediaSearchFilters,
     ) -> Result<SearchResults<MediaSearchItem>> {
         let page = page.unwrap_or(1);
+        let mut params = json!({
+            "query": query.to_owned(),
+            "page": page,
+            "language": self.base.language,
+            "include_adult": display_nsfw,
+        });
+        if let Some(year) = filters.exact_year() {
+            params["first_air_date_year"] = json!(year);
+        }
         let mut rsp = self
             .client
             .get("search/tv")
-            .query(&json!({
-                "query": query.to_owned(),
-                "page": page,
-                "language": self.base.language,
-                "include_adult": display_nsfw,
-            }))
+            .query(&params)
             .unwrap()
             .await
             .map_err(|e| anyhow!(e))?;
@@ -732,11 +882,28 @@ impl MediaProvider for TmdbShowService {
             items: resp.to_vec(),
         })
     }
+
+    async fn id_from_external(
+        &self,
+        id_type: ExternalIdentifierType,
+        value: &str,
+    ) -> Result<Option<String>> {
+        self.base
+            .find_by_external_id(&self.client, id_type, value, MetadataLot::Show)
+            .await
+    }
+
+    async fn trending(&self, page: Option<i32>) -> Result<SearchResults<MediaSearchItem>> {
+        self.base.trending(&self.client, "tv", page).await
+    }
 }
 
 async fn get_client_config(url: &str, access_token: &str) -> Client {
-    let client: Client =
-        get_base_http_client(url, vec![(AUTHORIZATION, format!("Bearer {access_token}"))]);
+    let client: Client = get_base_http_client(
+        url,
+        vec![(AUTHORIZATION, format!("Bearer {access_token}"))],
+        Some(MetadataSource::Tmdb),
+    );
     if IMAGE_URL.get().is_none() {
         #[derive(Debug, Serialize, Deserialize, Clone)]
         struct TmdbImageConfiguration {
@@ -754,6 +921,96 @@ async fn get_client_config(url: &str, access_token: &str) -> Client {
 }
 
 impl TmdbService {
+    /// Get the movies or shows that are trending this week.
+    async fn trending(
+        &self,
+        client: &Client,
+        typ: &str,
+        page: Option<i32>,
+    ) -> Result<SearchResults<MediaSearchItem>> {
+        let page = page.unwrap_or(1);
+        let mut rsp = client
+            .get(format!("trending/{}/week", typ))
+            .query(&json!({
+                "page": page,
+                "language": self.language,
+            }))
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let trending: TmdbListResponse = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+        let items = trending
+            .results
+            .into_iter()
+            .map(|d| MediaSearchItem {
+                identifier: d.id.to_string(),
+                title: d.title.or(d.name).unwrap_or_default(),
+                publish_year: d
+                    .release_date
+                    .or(d.first_air_date)
+                    .and_then(|d| convert_date_to_year(&d)),
+                image: d.poster_path.map(|p| self.get_cover_image_url(p)),
+            })
+            .collect_vec();
+        let next_page = if page < trending.total_pages {
+            Some(page + 1)
+        } else {
+            None
+        };
+        Ok(SearchResults {
+            details: SearchDetails {
+                total: trending.total_results,
+                next_page,
+            },
+            items,
+        })
+    }
+
+    /// Find the TMDB identifier of the movie or show with the given IMDb or TVDB
+    /// ID.
+    async fn find_by_external_id(
+        &self,
+        client: &Client,
+        id_type: ExternalIdentifierType,
+        value: &str,
+        lot: MetadataLot,
+    ) -> Result<Option<String>> {
+        #[derive(Debug, Serialize, Deserialize, Clone)]
+        struct TmdbFindEpisode {
+            show_id: i32,
+        }
+        #[derive(Debug, Serialize, Deserialize, Clone)]
+        struct TmdbFindResponse {
+            movie_results: Vec<IdObject>,
+            tv_results: Vec<IdObject>,
+            #[serde(default)]
+            tv_episode_results: Vec<TmdbFindEpisode>,
+        }
+        let external_source = match id_type {
+            ExternalIdentifierType::Imdb => "imdb_id",
+            ExternalIdentifierType::Tvdb => "tvdb_id",
+            _ => return Ok(None),
+        };
+        let mut rsp = client
+            .get(format!("find/{}", value))
+            .query(&json!({ "external_source": external_source }))
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let data: TmdbFindResponse = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+        let id = match lot {
+            // DEV: The ID can also be the one of an episode, in which case the
+            // show that it belongs to is used
+            MetadataLot::Show => data
+                .tv_results
+                .first()
+                .map(|r| r.id)
+                .or_else(|| data.tv_episode_results.first().map(|e| e.show_id)),
+            _ => data.movie_results.first().map(|r| r.id),
+        };
+        Ok(id.map(|id| id.to_string()))
+    }
+
     async fn save_all_images(
         &self,
         client: &Client,
@@ -0,0 +1,105 @@
+use std::sync::OnceLock;
+
+use anyhow::{anyhow, Result};
+use http_types::mime;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use surf::{http::headers::ACCEPT, Client};
+
+use crate::{config::UpcItemDbConfig, utils::get_base_http_client};
+
+static URL: &str = "https://api.upcitemdb.com/prod/v1/";
+static TRIAL_URL: &str = "https://api.upcitemdb.com/prod/trial/";
+
+#[derive(Serialize, Deserialize, Debug)]
+struct UpcItemDbItem {
+    title: String,
+    category: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct UpcItemDbLookupResponse {
+    code: String,
+    items: Option<Vec<UpcItemDbItem>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpcItemDbService {
+    client: Client,
+}
+
+impl UpcItemDbService {
+    pub async fn new(config: &UpcItemDbConfig) -> Self {
+        let client = if config.user_key.is_empty() {
+            get_base_http_client(TRIAL_URL, vec![(ACCEPT, mime::JSON.to_string())], None)
+        } else {
+            get_base_http_client(
+                URL,
+                vec![
+                    (ACCEPT, mime::JSON.to_string()),
+                    ("user_key".into(), config.user_key.clone()),
+                    ("key_type".into(), "3scale".to_owned()),
+                ],
+                None,
+            )
+        };
+        Self { client }
+    }
+
+    /// Get the title of the product that has the given UPC/EAN barcode. The
+    /// title is cleaned up so that it can be used as a search query for other
+    /// providers.
+    pub async fn title_from_barcode(&self, barcode: &str) -> Result<Option<String>> {
+        let barcode = barcode
+            .chars()
+            .filter(|c| c.is_ascii_digit())
+            .collect::<String>();
+        if barcode.is_empty() {
+            return Ok(None);
+        }
+        let mut rsp = self
+            .client
+            .get("lookup")
+            .query(&json!({ "upc": barcode }))
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let data: UpcItemDbLookupResponse = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+        match data.code.as_str() {
+            "OK" => {}
+            // DEV: The barcode is not known, which is not a failure of the lookup
+            "INVALID_UPC" | "NOT_FOUND" => return Ok(None),
+            code => return Err(anyhow!("UPCitemdb returned code: {}", code)),
+        }
+        Ok(data
+            .items
+            .unwrap_or_default()
+            .into_iter()
+            .next()
+            .map(|i| clean_product_title(&i.title))
+            .filter(|t| !t.is_empty()))
+    }
+}
+
+/// Product titles usually contain information about the packaging, eg: `Inception
+/// (Blu-ray + DVD) [2010]`. Remove all of it so that only the title remains.
+fn clean_product_title(title: &str) -> String {
+    static BRACKETS: OnceLock<Regex> = OnceLock::new();
+    static PACKAGING: OnceLock<Regex> = OnceLock::new();
+    let brackets = BRACKETS.get_or_init(|| Regex::new(r"[\(\[\{][^\)\]\}]*[\)\]\}]").unwrap());
+    let packaging = PACKAGING.get_or_init(|| {
+        Regex::new(
+            r"(?i)\b(blu-?ray|dvd|4k|ultra hd|uhd|digital( copy| hd)?|widescreen|full ?screen|steelbook|special edition|collector'?s edition|ps[1-5]|playstation \d|xbox( one| series [xs]| 360)?|nintendo switch|wii u?)\b",
+        )
+        .unwrap()
+    });
+    let title = brackets.replace_all(title, " ");
+    let title = packaging.replace_all(&title, " ");
+    title
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_matches(|c: char| c == '-' || c == ':' || c == '+' || c.is_whitespace())
+        .to_owned()
+}
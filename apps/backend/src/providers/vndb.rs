@@ -11,8 +11,8 @@ use crate::{
     migrator::{MetadataLot, MetadataSource},
     models::{
         media::{
-            MediaDetails, MediaSearchItem, MediaSpecifics, MetadataCreator, MetadataImage,
-            MetadataImageLot, VisualNovelSpecifics,
+            MediaDetails, MediaSearchFilters, MediaSearchItem, MediaSpecifics, MetadataCreator,
+            MetadataImage, MetadataImageLot, VisualNovelSpecifics,
         },
         NamedObject, SearchDetails, SearchResults, StoredUrl,
     },
@@ -21,12 +21,17 @@ use crate::{
 };
 
 static URL: &str = "https://api.vndb.org/kana/";
-const FIELDS_SMALL: &str = "title,image.url,released,screenshots.url";
+const FIELDS_SMALL: &str = "title,image.url,image.sexual,released,screenshots.url";
 const FIELDS: &str = const_str::concat!(
     FIELDS_SMALL,
     ",",
-    "length_minutes,tags.name,developers.name,devstatus,description,rating"
+    "alttitle,aliases,length_minutes,tags.name,tags.category,tags.rating,developers.name,",
+    "staff.name,staff.role,devstatus,description,rating"
 );
+// DEV: The images are flagged by the users, `2` being explicit
+const EXPLICIT_IMAGE: f32 = 1.5;
+// DEV: The tags are voted by the users, `3` being the most relevant
+const RELEVANT_TAG: f32 = 2.0;
 
 #[derive(Debug, Clone)]
 pub struct VndbService {
@@ -46,7 +51,8 @@ impl MediaProviderLanguages for VndbService {
 
 impl VndbService {
     pub async fn new(_config: &VisualNovelConfig, page_limit: i32) -> Self {
-        let client = get_base_http_client(URL, vec![(ACCEPT, mime::JSON)]);
+        let client =
+            get_base_http_client(URL, vec![(ACCEPT, mime::JSON)], Some(MetadataSource::Vndb));
         Self { client, page_limit }
     }
 }
@@ -54,21 +60,56 @@ impl VndbService {
 #[derive(Serialize, Deserialize, Debug)]
 struct ImageLinks {
     url: String,
+    sexual: Option<f32>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Tag {
+    name: String,
+    /// One of `cont` (content), `ero` (sexual content) or `tech` (technical).
+    category: Option<String>,
+    rating: Option<f32>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Staff {
+    name: String,
+    role: String,
+}
+
+fn staff_role(role: &str) -> String {
+    match role {
+        "scenario" => "Writer",
+        "director" => "Director",
+        "chardesign" => "Character Design",
+        "art" => "Artist",
+        "music" => "Composer",
+        "songs" => "Vocals",
+        "translator" => "Translator",
+        "editor" => "Editor",
+        "qa" => "Quality Assurance",
+        _ => "Staff",
+    }
+    .to_owned()
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 struct ItemResponse {
     id: String,
     title: String,
+    alttitle: Option<String>,
+    aliases: Option<Vec<String>>,
     rating: Option<Decimal>,
     released: Option<String>,
     description: Option<String>,
+    #[serde(rename = "image")]
     image_links: Option<ImageLinks>,
     length_minutes: Option<i32>,
     devstatus: Option<i32>,
     developers: Option<Vec<NamedObject>>,
+    staff: Option<Vec<Staff>>,
     screenshots: Option<Vec<ImageLinks>>,
-    tags: Option<Vec<NamedObject>>,
+    tags: Option<Vec<Tag>>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -102,7 +143,8 @@ impl MediaProvider for VndbService {
         &self,
         query: &str,
         page: Option<i32>,
-        _display_nsfw: bool,
+        display_nsfw: bool,
+        _filters: &MediaSearchFilters,
     ) -> Result<SearchResults<MediaSearchItem>> {
         let page = page.unwrap_or(1);
         let mut rsp = self
@@ -123,14 +165,16 @@ impl MediaProvider for VndbService {
             .results
             .unwrap_or_default()
             .into_iter()
-            .map(|b| {
+            .map(|b| self.google_books_response_to_search_response(b))
+            .filter(|d| display_nsfw || d.is_nsfw != Some(true))
+            .map(|d| {
                 let MediaDetails {
                     identifier,
                     title,
                     images,
                     publish_year,
                     ..
-                } = self.google_books_response_to_search_response(b);
+                } = d;
                 let image = images
                     .into_iter()
                     .map(|i| match i.url {
@@ -161,6 +205,18 @@ impl MediaProvider for VndbService {
 
 impl VndbService {
     fn google_books_response_to_search_response(&self, item: ItemResponse) -> MediaDetails {
+        let tags = item.tags.unwrap_or_default();
+        // DEV: The search results do not include the tags, so only the image is
+        // used to know whether they are NSFW
+        let is_nsfw = item
+            .image_links
+            .as_ref()
+            .and_then(|i| i.sexual)
+            .map_or(false, |s| s >= EXPLICIT_IMAGE)
+            || tags.iter().any(|t| {
+                t.category.as_deref() == Some("ero")
+                    && t.rating.map_or(false, |r| r >= RELEVANT_TAG)
+            });
         let mut images = vec![];
         if let Some(il) = item.image_links {
             images.push(il.url);
@@ -181,13 +237,18 @@ impl VndbService {
                 role: "Developer".to_owned(),
                 image: None,
             })
+            .chain(
+                item.staff
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|s| MetadataCreator {
+                        name: s.name,
+                        role: staff_role(&s.role),
+                        image: None,
+                    }),
+            )
             .collect_vec();
-        let genres = item
-            .tags
-            .unwrap_or_default()
-            .into_iter()
-            .map(|t| t.name)
-            .collect_vec();
+        let genres = tags.into_iter().map(|t| t.name).collect_vec();
         MediaDetails {
             identifier: item.id,
             lot: MetadataLot::VisualNovel,
@@ -202,6 +263,11 @@ impl VndbService {
                 })
                 .unwrap_or_else(|| "Released".to_owned()),
             title: item.title,
+            alternative_titles: item
+                .alttitle
+                .into_iter()
+                .chain(item.aliases.unwrap_or_default())
+                .collect(),
             description: item.description,
             creators: creators.into_iter().unique().collect(),
             genres: genres.into_iter().unique().collect(),
@@ -212,7 +278,7 @@ impl VndbService {
             }),
             provider_rating: item.rating,
             images: images.unique().collect(),
-            is_nsfw: None,
+            is_nsfw: Some(is_nsfw),
             videos: vec![],
             suggestions: vec![],
             groups: vec![],
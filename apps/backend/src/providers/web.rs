@@ -0,0 +1,302 @@
+// Responsible for turning the URL of an article into a trackable item using the
+// OpenGraph tags of the page.
+
+use std::net::IpAddr;
+
+use anyhow::{anyhow, bail, Result};
+use async_trait::async_trait;
+use futures::AsyncReadExt;
+use http_types::url::Host;
+use itertools::Itertools;
+use scraper::{Html, Selector};
+use surf::{
+    http::headers::{LOCATION, USER_AGENT},
+    Client, Config, Url,
+};
+use tokio::net::lookup_host;
+
+use crate::{
+    config::ArticleConfig,
+    migrator::{MetadataLot, MetadataSource},
+    models::{
+        media::{
+            ArticleSpecifics, MediaDetails, MediaSearchFilters, MediaSearchItem, MediaSpecifics,
+            MetadataCreator, MetadataImage, MetadataImageLot,
+        },
+        SearchDetails, SearchResults, StoredUrl,
+    },
+    traits::{MediaProvider, MediaProviderLanguages},
+    utils::{convert_date_to_year, convert_string_to_date, USER_AGENT_STR},
+};
+
+/// The largest page that is downloaded to get the details of an article.
+static MAX_PAGE_BYTES: u64 = 5 * 1024 * 1024;
+/// The number of redirects that are followed to get to the page of an article.
+static MAX_REDIRECTS: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct WebService {
+    client: Client,
+    words_per_minute: i32,
+}
+
+impl MediaProviderLanguages for WebService {
+    fn supported_languages() -> Vec<String> {
+        vec!["us".to_owned()]
+    }
+
+    fn default_language() -> String {
+        "us".to_owned()
+    }
+}
+
+impl WebService {
+    pub async fn new(config: &ArticleConfig) -> Self {
+        let client = Config::new()
+            .add_header(USER_AGENT, USER_AGENT_STR)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        Self {
+            client,
+            words_per_minute: config.words_per_minute,
+        }
+    }
+
+    /// Download the page of an article, following its redirects. Every link
+    /// is checked before it is requested, since the client does not follow
+    /// redirects on its own.
+    async fn fetch_page(&self, identifier: &str) -> Result<String> {
+        let mut url = Url::parse(identifier)?;
+        for _ in 0..=MAX_REDIRECTS {
+            check_url(&url).await?;
+            let mut rsp = self.client.get(url.clone()).await.map_err(|e| anyhow!(e))?;
+            if rsp.status().is_redirection() {
+                let location = rsp
+                    .header(LOCATION)
+                    .map(|l| l.last().as_str().to_owned())
+                    .ok_or_else(|| anyhow!("The page redirected without a location"))?;
+                url = url.join(&location)?;
+                continue;
+            }
+            if !rsp.status().is_success() {
+                bail!("The page returned status {}", rsp.status());
+            }
+            let mut body = vec![];
+            rsp.take_body()
+                .take(MAX_PAGE_BYTES + 1)
+                .read_to_end(&mut body)
+                .await?;
+            if body.len() as u64 > MAX_PAGE_BYTES {
+                bail!("The page is larger than {} bytes", MAX_PAGE_BYTES);
+            }
+            return Ok(String::from_utf8_lossy(&body).into_owned());
+        }
+        bail!("The page redirected more than {} times", MAX_REDIRECTS)
+    }
+}
+
+/// Whether an address can only be reached from the network of the server, which
+/// links to articles are not allowed to point to.
+fn is_internal_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            ip.is_private()
+                || ip.is_loopback()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_internal_address(IpAddr::V4(ip)),
+            None => {
+                let first = ip.segments()[0];
+                ip.is_loopback()
+                    || ip.is_unspecified()
+                    // DEV: Unique local (fc00::/7) and link local (fe80::/10)
+                    || first & 0xfe00 == 0xfc00
+                    || first & 0xffc0 == 0xfe80
+            }
+        },
+    }
+}
+
+/// Check that a link to an article uses http(s), and that its host does not
+/// resolve to an internal address.
+async fn check_url(url: &Url) -> Result<()> {
+    if url.scheme() != "http" && url.scheme() != "https" {
+        bail!("Only http and https links are supported");
+    }
+    let port = url.port_or_known_default().unwrap_or(80);
+    let ips = match url.host() {
+        Some(Host::Domain(domain)) => lookup_host((domain, port))
+            .await?
+            .map(|a| a.ip())
+            .collect_vec(),
+        Some(Host::Ipv4(ip)) => vec![IpAddr::V4(ip)],
+        Some(Host::Ipv6(ip)) => vec![IpAddr::V6(ip)],
+        None => bail!("The link does not have a host"),
+    };
+    if ips.is_empty() || ips.into_iter().any(is_internal_address) {
+        bail!("The link does not point to a public address");
+    }
+    Ok(())
+}
+
+/// Get the value of the first meta tag that matches any of the given selectors.
+fn meta_content(document: &Html, selectors: &[&str]) -> Option<String> {
+    selectors.iter().find_map(|s| {
+        let selector = Selector::parse(s).unwrap();
+        document
+            .select(&selector)
+            .filter_map(|e| e.value().attr("content"))
+            .map(|c| c.trim().to_owned())
+            .find(|c| !c.is_empty())
+    })
+}
+
+#[async_trait]
+impl MediaProvider for WebService {
+    async fn details(&self, identifier: &str) -> Result<MediaDetails> {
+        let html = self.fetch_page(identifier).await?;
+        let document = Html::parse_document(&html);
+        let title = meta_content(&document, &[r#"meta[property="og:title"]"#])
+            .or_else(|| {
+                let selector = Selector::parse("title").unwrap();
+                document
+                    .select(&selector)
+                    .next()
+                    .map(|t| t.text().collect::<String>().trim().to_owned())
+            })
+            .filter(|t| !t.is_empty())
+            .unwrap_or_else(|| identifier.to_owned());
+        let description = meta_content(
+            &document,
+            &[
+                r#"meta[property="og:description"]"#,
+                r#"meta[name="description"]"#,
+            ],
+        );
+        // DEV: `article:author` is sometimes the link to the profile of the author
+        // instead of their name
+        let author = meta_content(
+            &document,
+            &[
+                r#"meta[name="author"]"#,
+                r#"meta[property="article:author"]"#,
+            ],
+        )
+        .filter(|a| Url::parse(a).is_err());
+        let published = meta_content(&document, &[r#"meta[property="article:published_time"]"#])
+            .map(|p| p.chars().take(10).collect::<String>());
+        let genres = {
+            let selector = Selector::parse(r#"meta[property="article:tag"]"#).unwrap();
+            document
+                .select(&selector)
+                .filter_map(|e| e.value().attr("content"))
+                .map(|t| t.trim().to_owned())
+                .filter(|t| !t.is_empty())
+                .unique()
+                .collect()
+        };
+        let images = meta_content(&document, &[r#"meta[property="og:image"]"#])
+            .into_iter()
+            .map(|i| MetadataImage {
+                url: StoredUrl::Url(i),
+                lot: MetadataImageLot::Poster,
+            })
+            .collect();
+        // DEV: Only the paragraphs are counted so that the navigation and footer of
+        // the page do not inflate the reading time
+        let words = {
+            let article = Selector::parse("article p").unwrap();
+            let paragraphs = Selector::parse("p").unwrap();
+            let mut elements = document.select(&article).peekable();
+            let elements = match elements.peek() {
+                Some(_) => elements.collect_vec(),
+                None => document.select(&paragraphs).collect_vec(),
+            };
+            elements
+                .into_iter()
+                .map(|e| e.text().flat_map(|t| t.split_whitespace()).count())
+                .sum::<usize>()
+        };
+        let words = i32::try_from(words).unwrap_or(i32::MAX);
+        let words_per_minute = self.words_per_minute.max(1);
+        let reading_time = match words {
+            0 => None,
+            w => Some((w + words_per_minute - 1) / words_per_minute),
+        };
+        Ok(MediaDetails {
+            identifier: identifier.to_owned(),
+            lot: MetadataLot::Article,
+            source: MetadataSource::Web,
+            production_status: "Released".to_owned(),
+            title,
+            alternative_titles: vec![],
+            description,
+            creators: author
+                .into_iter()
+                .map(|name| MetadataCreator {
+                    name,
+                    role: "Author".to_owned(),
+                    image: None,
+                })
+                .collect(),
+            genres,
+            publish_year: published.as_ref().and_then(|d| convert_date_to_year(d)),
+            publish_date: published.and_then(|d| convert_string_to_date(&d)),
+            specifics: MediaSpecifics::Article(ArticleSpecifics {
+                site_name: meta_content(&document, &[r#"meta[property="og:site_name"]"#]),
+                words: Some(words).filter(|w| *w > 0),
+                reading_time,
+            }),
+            provider_rating: None,
+            images,
+            is_nsfw: None,
+            videos: vec![],
+            suggestions: vec![],
+            groups: vec![],
+        })
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        _page: Option<i32>,
+        _display_nsfw: bool,
+        _filters: &MediaSearchFilters,
+    ) -> Result<SearchResults<MediaSearchItem>> {
+        // DEV: Articles can not be searched for, so the query is expected to be the
+        // link to the article itself
+        let url = match Url::parse(query.trim()) {
+            Ok(u) if u.scheme() == "http" || u.scheme() == "https" => u,
+            _ => {
+                return Ok(SearchResults {
+                    details: SearchDetails {
+                        total: 0,
+                        next_page: None,
+                    },
+                    items: vec![],
+                })
+            }
+        };
+        let details = self.details(url.as_str()).await?;
+        let image = details.images.into_iter().find_map(|i| match i.url {
+            StoredUrl::Url(u) => Some(u),
+            StoredUrl::S3(_) => None,
+        });
+        Ok(SearchResults {
+            details: SearchDetails {
+                total: 1,
+                next_page: None,
+            },
+            items: vec![MediaSearchItem {
+                identifier: details.identifier,
+                title: details.title,
+                image,
+                publish_year: details.publish_year,
+            }],
+        })
+    }
+}
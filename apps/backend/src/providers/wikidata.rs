@@ -0,0 +1,113 @@
+use anyhow::{anyhow, Result};
+use http_types::mime;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use surf::{http::headers::ACCEPT, Client};
+
+use crate::{
+    migrator::{MetadataLot, MetadataSource},
+    models::media::MetadataAward,
+    utils::get_base_http_client,
+};
+
+static URL: &str = "https://query.wikidata.org/";
+
+#[derive(Serialize, Deserialize, Debug)]
+struct WikidataValue {
+    value: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct WikidataBinding {
+    #[serde(rename = "awardLabel")]
+    award_label: WikidataValue,
+    kind: WikidataValue,
+    year: Option<WikidataValue>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct WikidataResults {
+    bindings: Vec<WikidataBinding>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct WikidataResponse {
+    results: WikidataResults,
+}
+
+#[derive(Debug, Clone)]
+pub struct WikidataService {
+    client: Client,
+}
+
+impl WikidataService {
+    pub async fn new() -> Self {
+        let client = get_base_http_client(URL, vec![(ACCEPT, mime::JSON.to_string())], None);
+        Self { client }
+    }
+
+    /// The Wikidata property that stores the identifier of the given provider.
+    /// Returns `None` for providers that Wikidata does not link to.
+    fn property_for(lot: MetadataLot, source: MetadataSource) -> Option<&'static str> {
+        match (source, lot) {
+            (MetadataSource::Tmdb, MetadataLot::Movie) => Some("P4947"),
+            (MetadataSource::Tmdb, MetadataLot::Show) => Some("P4983"),
+            (MetadataSource::Openlibrary, _) => Some("P648"),
+            (MetadataSource::Anilist, MetadataLot::Anime) => Some("P8729"),
+            (MetadataSource::Anilist, MetadataLot::Manga) => Some("P8731"),
+            (MetadataSource::Mal, MetadataLot::Anime) => Some("P4086"),
+            (MetadataSource::Mal, MetadataLot::Manga) => Some("P4087"),
+            _ => None,
+        }
+    }
+
+    /// Get all the awards (eg: Academy Awards, Hugo Awards, Booker Prize) that
+    /// the item has won or been nominated for.
+    pub async fn awards(
+        &self,
+        lot: MetadataLot,
+        source: MetadataSource,
+        identifier: &str,
+    ) -> Result<Vec<MetadataAward>> {
+        let property = match Self::property_for(lot, source) {
+            Some(p) => p,
+            None => return Ok(vec![]),
+        };
+        let identifier = identifier.replace(['"', '\\'], "");
+        let query = format!(
+            r#"
+SELECT ?awardLabel ?kind ?year WHERE {{
+  ?item wdt:{property} "{identifier}" .
+  {{ ?item p:P166 ?statement . ?statement ps:P166 ?award . BIND("won" AS ?kind) }}
+  UNION
+  {{ ?item p:P1411 ?statement . ?statement ps:P1411 ?award . BIND("nominated" AS ?kind) }}
+  OPTIONAL {{ ?statement pq:P585 ?date . BIND(YEAR(?date) AS ?year) }}
+  SERVICE wikibase:label {{ bd:serviceParam wikibase:language "en". }}
+}}
+"#
+        );
+        let mut rsp = self
+            .client
+            .get("sparql")
+            .query(&json!({ "query": query, "format": "json" }))
+            .unwrap()
+            .await
+            .map_err(|e| anyhow!(e))?;
+        let data: WikidataResponse = rsp.body_json().await.map_err(|e| anyhow!(e))?;
+        let awards = data
+            .results
+            .bindings
+            .into_iter()
+            .map(|b| MetadataAward {
+                name: b.award_label.value,
+                year: b.year.and_then(|y| y.value.parse().ok()),
+                won: b.kind.value == "won",
+            })
+            // DEV: Wikidata falls back to the entity id when there is no label
+            .filter(|a| !(a.name.starts_with('Q') && a.name[1..].parse::<u64>().is_ok()))
+            .unique()
+            .collect();
+        Ok(awards)
+    }
+}
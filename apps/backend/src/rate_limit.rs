@@ -0,0 +1,75 @@
+// Limits the rate of the requests made to each provider. The limits are shared by
+// all the clients of a provider, since a new one is created for each use.
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+    time::Duration,
+};
+
+use surf::{
+    middleware::{Middleware, Next},
+    Client, Request, Response,
+};
+use surf_retry::{ExponentialBackoff, RetryMiddleware};
+use tokio::time::{sleep_until, Instant};
+
+use crate::{config::ProviderRateLimitConfig, migrator::MetadataSource};
+
+static CONFIG: OnceLock<ProviderRateLimitConfig> = OnceLock::new();
+static NEXT_SLOTS: OnceLock<Mutex<HashMap<MetadataSource, Instant>>> = OnceLock::new();
+
+/// Set the limits to apply. No limits or retries are applied if this is never
+/// called.
+pub fn init(config: ProviderRateLimitConfig) {
+    CONFIG.set(config).ok();
+}
+
+/// Add the retries and rate limit configured for the provider to the client.
+pub fn apply(client: Client, source: MetadataSource) -> Client {
+    let config = match CONFIG.get() {
+        Some(config) => config,
+        None => return client,
+    };
+    let mut client = client;
+    let retries = config.max_retries(source);
+    if retries > 0 {
+        client = client.with(RetryMiddleware::new(
+            retries,
+            ExponentialBackoff::builder().build_with_max_retries(retries),
+            1,
+        ));
+    }
+    // DEV: Added after the retries so that each retry waits for its own slot
+    if let Some(requests_per_second) = config.requests_per_second(source) {
+        client = client.with(RateLimitMiddleware {
+            source,
+            interval: Duration::from_secs(1) / requests_per_second,
+        });
+    }
+    client
+}
+
+struct RateLimitMiddleware {
+    source: MetadataSource,
+    interval: Duration,
+}
+
+#[surf::utils::async_trait]
+impl Middleware for RateLimitMiddleware {
+    async fn handle(&self, req: Request, client: Client, next: Next<'_>) -> surf::Result<Response> {
+        let slot = {
+            let mut slots = NEXT_SLOTS.get_or_init(Default::default).lock().unwrap();
+            let now = Instant::now();
+            let slot = slots
+                .get(&self.source)
+                .copied()
+                .filter(|s| *s > now)
+                .unwrap_or(now);
+            slots.insert(self.source, slot + self.interval);
+            slot
+        };
+        sleep_until(slot).await;
+        next.run(req, client).await
+    }
+}
@@ -1,21 +1,37 @@
-use std::{fs::write, path::PathBuf, sync::Arc};
+use std::{collections::HashMap, fs::write, path::PathBuf, str::FromStr, sync::Arc};
 
 use anyhow::Result;
-use async_graphql::http::GraphiQLSource;
-use async_graphql_axum::{GraphQLRequest, GraphQLResponse};
+use async_graphql::{
+    http::{GraphiQLSource, ALL_WEBSOCKET_PROTOCOLS},
+    parser::{parse_query, types::OperationType},
+    Data, Response as GraphqlResponse, ServerError,
+};
+use async_graphql_axum::{GraphQLProtocol, GraphQLRequest, GraphQLResponse, GraphQLWebSocket};
 use axum::{
-    body::{boxed, Full},
-    extract::{Multipart, Path},
-    http::{header, StatusCode, Uri},
+    body::{boxed, Bytes, Full},
+    extract::{ws::Message, Multipart, OriginalUri, Path, Query, WebSocketUpgrade},
+    http::{header, HeaderMap, Method, StatusCode, Uri},
     response::{Html, IntoResponse, Response},
     Extension, Json,
 };
+use convert_case::{Case, Casing};
+use futures::StreamExt;
 use rust_embed::RustEmbed;
+use sea_orm::Iterable;
 use serde_json::json;
 
 use crate::{
-    config::AppConfig, fitness::resolver::ExerciseService, graphql::GraphqlSchema,
-    miscellaneous::resolver::MiscellaneousService, models::media::ExportAllResponse,
+    config::AppConfig,
+    fitness::{export::WorkoutCsvFormat, resolver::ExerciseService},
+    graphql::{GraphqlRateLimiter, GraphqlSchema},
+    integrations::{
+        IntegrationService, KoreaderProgress, WebhookCredentials, WEBHOOK_SECRET_HEADER,
+        WEBHOOK_SECRET_QUERY, WEBHOOK_SIGNATURE_HEADER,
+    },
+    migrator::MetadataLot,
+    miscellaneous::resolver::MiscellaneousService,
+    models::media::ExportAllResponse,
+    openapi::openapi_document,
     utils::AuthContext,
 };
 
@@ -72,14 +88,128 @@ pub async fn not_found() -> Response {
 
 pub async fn graphql_handler(
     schema: Extension<GraphqlSchema>,
+    Extension(rate_limiter): Extension<Arc<GraphqlRateLimiter>>,
     gql_ctx: AuthContext,
     req: GraphQLRequest,
 ) -> GraphQLResponse {
+    if !rate_limiter.check(&gql_ctx).await {
+        return GraphqlResponse::from_errors(vec![ServerError::new(
+            "Too many requests, please try again in a minute",
+            None,
+        )])
+        .into();
+    }
     schema.execute(req.into_inner().data(gql_ctx)).await.into()
 }
 
+/// Whether a websocket request comes from a page that is allowed to use the API:
+/// the frontend served by the server itself, `frontend.url` or one of the CORS
+/// origins. Browsers send the cookie with websocket requests from any site, but
+/// always send the origin with them too.
+fn is_allowed_origin(headers: &HeaderMap, config: &AppConfig) -> bool {
+    let origin = match headers.get(header::ORIGIN) {
+        Some(o) => o.to_str().unwrap_or_default().trim_end_matches('/'),
+        // DEV: Clients that are not browsers do not have to send it
+        None => return true,
+    };
+    let host = headers
+        .get(header::HOST)
+        .and_then(|h| h.to_str().ok())
+        .unwrap_or_default();
+    origin.split_once("://").map(|(_, authority)| authority) == Some(host)
+        || config.frontend.url.trim_end_matches('/') == origin
+        || config
+            .server
+            .cors_origins
+            .iter()
+            .any(|o| o.trim_end_matches('/') == origin)
+}
+
+/// Whether a message sent over the websocket is allowed. Queries and mutations
+/// have to be sent to `/graphql`, so that only subscriptions are started here.
+fn is_allowed_subscription_message(text: &str) -> bool {
+    let message = match serde_json::from_str::<serde_json::Value>(text) {
+        Ok(m) => m,
+        Err(_) => return false,
+    };
+    match message["type"].as_str() {
+        Some("subscribe" | "start") => {}
+        _ => return true,
+    }
+    let query = message["payload"]["query"].as_str().unwrap_or_default();
+    match parse_query(query) {
+        Ok(document) => document
+            .operations
+            .iter()
+            .all(|(_, operation)| operation.node.ty == OperationType::Subscription),
+        Err(_) => false,
+    }
+}
+
+/// Subscriptions are served over websockets. The user is authenticated the same
+/// way as for the other requests, ie: using the cookie sent with the upgrade request,
+/// which is why the request has to come from an allowed origin. The messages sent
+/// by the client count towards its rate limit, and the socket is closed once it
+/// goes over it or tries to run something other than a subscription.
+pub async fn graphql_subscription_handler(
+    Extension(schema): Extension<GraphqlSchema>,
+    Extension(rate_limiter): Extension<Arc<GraphqlRateLimiter>>,
+    Extension(config): Extension<Arc<AppConfig>>,
+    headers: HeaderMap,
+    gql_ctx: AuthContext,
+    protocol: GraphQLProtocol,
+    websocket: WebSocketUpgrade,
+) -> Response {
+    if !is_allowed_origin(&headers, &config) {
+        return (StatusCode::FORBIDDEN, "This origin is not allowed").into_response();
+    }
+    let client = GraphqlRateLimiter::client(&gql_ctx);
+    websocket
+        .protocols(ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |socket| {
+            let (sink, stream) = socket.split();
+            let stream = stream.take_while(move |message| {
+                let text = match message {
+                    Ok(Message::Text(t)) => Some(t.clone()),
+                    _ => None,
+                };
+                let rate_limiter = rate_limiter.clone();
+                let client = client.clone();
+                async move {
+                    match text {
+                        Some(t) => {
+                            is_allowed_subscription_message(&t)
+                                && rate_limiter.check_client(client.as_deref()).await
+                        }
+                        None => true,
+                    }
+                }
+            });
+            let mut data = Data::default();
+            data.insert(gql_ctx);
+            GraphQLWebSocket::new_with_pair(sink, stream, schema, protocol)
+                .with_data(data)
+                .serve()
+        })
+}
+
 pub async fn graphql_playground() -> impl IntoResponse {
-    Html(GraphiQLSource::build().endpoint("/graphql").finish())
+    Html(
+        GraphiQLSource::build()
+            .endpoint("/graphql")
+            .subscription_endpoint("/graphql/ws")
+            .finish(),
+    )
+}
+
+/// The GraphQL schema as SDL, so that clients can be generated against the exact
+/// version of the server that is running.
+pub async fn graphql_sdl_handler(Extension(schema): Extension<GraphqlSchema>) -> impl IntoResponse {
+    ([(header::CONTENT_TYPE, "text/plain")], schema.sdl())
+}
+
+pub async fn openapi_handler() -> impl IntoResponse {
+    Json(openapi_document())
 }
 
 pub async fn config_handler(Extension(config): Extension<Arc<AppConfig>>) -> impl IntoResponse {
@@ -138,6 +268,9 @@ pub async fn json_export(
         "measurements" => {
             json!(exercise_service.export_measurements(user_id).await.unwrap())
         }
+        "workouts" => {
+            json!(exercise_service.export_workouts(user_id).await.unwrap())
+        }
         _ => Err((
             StatusCode::BAD_REQUEST,
             Json(json!({"err": "This type of export is not supported"})),
@@ -146,13 +279,136 @@ pub async fn json_export(
     Ok(Json(resp))
 }
 
+/// Export the workouts as a CSV file in the format of another workout tracker.
+pub async fn workouts_csv_export(
+    Path(format): Path<String>,
+    Extension(exercise_service): Extension<Arc<ExerciseService>>,
+    ctx: AuthContext,
+) -> Result<Response, (StatusCode, Json<serde_json::Value>)> {
+    let user_id = ctx.user_id.ok_or_else(|| {
+        (
+            StatusCode::FORBIDDEN,
+            Json(json!({"err": "User is not authenticated"})),
+        )
+    })?;
+    let format = WorkoutCsvFormat::from_str(&format).map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(json!({"err": "This format of export is not supported"})),
+        )
+    })?;
+    let csv = exercise_service
+        .export_workouts_csv(user_id, format)
+        .await
+        .unwrap();
+    Ok(([(header::CONTENT_TYPE, "text/csv")], csv).into_response())
+}
+
+pub async fn media_csv_export(
+    Path(lot): Path<String>,
+    Extension(media_service): Extension<Arc<MiscellaneousService>>,
+    ctx: AuthContext,
+) -> Result<Response, (StatusCode, Json<serde_json::Value>)> {
+    let user_id = ctx.user_id.ok_or_else(|| {
+        (
+            StatusCode::FORBIDDEN,
+            Json(json!({"err": "User is not authenticated"})),
+        )
+    })?;
+    let lot = MetadataLot::iter()
+        .find(|l| l.to_string().to_case(Case::Snake) == lot)
+        .ok_or_else(|| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(json!({"err": "This type of media does not exist"})),
+            )
+        })?;
+    let csv = media_service.export_media_csv(user_id, lot).await.unwrap();
+    Ok(([(header::CONTENT_TYPE, "text/csv")], csv).into_response())
+}
+
+pub async fn letterboxd_export(
+    Extension(media_service): Extension<Arc<MiscellaneousService>>,
+    ctx: AuthContext,
+) -> Result<Response, (StatusCode, Json<serde_json::Value>)> {
+    let user_id = ctx.user_id.ok_or_else(|| {
+        (
+            StatusCode::FORBIDDEN,
+            Json(json!({"err": "User is not authenticated"})),
+        )
+    })?;
+    let csv = media_service.export_letterboxd_csv(user_id).await.unwrap();
+    Ok(([(header::CONTENT_TYPE, "text/csv")], csv).into_response())
+}
+
+pub async fn highlights_export(
+    Extension(media_service): Extension<Arc<MiscellaneousService>>,
+    ctx: AuthContext,
+) -> Result<Response, (StatusCode, Json<serde_json::Value>)> {
+    let user_id = ctx.user_id.ok_or_else(|| {
+        (
+            StatusCode::FORBIDDEN,
+            Json(json!({"err": "User is not authenticated"})),
+        )
+    })?;
+    let markdown = media_service
+        .export_highlights_markdown(user_id)
+        .await
+        .unwrap();
+    Ok(([(header::CONTENT_TYPE, "text/markdown")], markdown).into_response())
+}
+
+pub async fn mal_export(
+    Path(lot): Path<String>,
+    Extension(media_service): Extension<Arc<MiscellaneousService>>,
+    ctx: AuthContext,
+) -> Result<Response, (StatusCode, Json<serde_json::Value>)> {
+    let user_id = ctx.user_id.ok_or_else(|| {
+        (
+            StatusCode::FORBIDDEN,
+            Json(json!({"err": "User is not authenticated"})),
+        )
+    })?;
+    let lot = match lot.as_str() {
+        "anime" => MetadataLot::Anime,
+        "manga" => MetadataLot::Manga,
+        _ => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(json!({"err": "Only anime and manga can be exported to MAL"})),
+            ))
+        }
+    };
+    let xml = media_service.export_mal_xml(user_id, lot).await.unwrap();
+    Ok(([(header::CONTENT_TYPE, "application/xml")], xml).into_response())
+}
+
 pub async fn integration_webhook(
     Path((integration, user_hash_id)): Path<(String, String)>,
+    Query(query): Query<HashMap<String, String>>,
     Extension(media_service): Extension<Arc<MiscellaneousService>>,
+    headers: HeaderMap,
     payload: String,
 ) -> std::result::Result<(StatusCode, String), StatusCode> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(String::from)
+    };
+    let credentials = WebhookCredentials {
+        signature: header(WEBHOOK_SIGNATURE_HEADER),
+        secret: header(WEBHOOK_SECRET_HEADER).or_else(|| query.get(WEBHOOK_SECRET_QUERY).cloned()),
+    };
+    let (user, integration) = media_service
+        .authenticate_integration_webhook(&user_hash_id, &integration, &payload, &credentials)
+        .await
+        .map_err(|e| {
+            tracing::error!("{:?}", e);
+            StatusCode::UNAUTHORIZED
+        })?;
     let response = media_service
-        .process_integration_webhook(user_hash_id, integration, payload)
+        .process_integration_webhook(user, integration, payload)
         .await
         .map_err(|e| {
             tracing::error!("{:?}", e);
@@ -160,3 +416,238 @@ pub async fn integration_webhook(
         })?;
     Ok((StatusCode::OK, response))
 }
+
+/// Get the user that KOReader is syncing the progress for. KOReader sends the
+/// username and the hash of the password in the headers of every request.
+async fn koreader_user(
+    user_hash_id: &str,
+    headers: &HeaderMap,
+    media_service: &MiscellaneousService,
+) -> Result<i32, (StatusCode, Json<serde_json::Value>)> {
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+    };
+    media_service
+        .koreader_authenticate(user_hash_id, header("x-auth-user"), header("x-auth-key"))
+        .await
+        .map_err(|e| {
+            tracing::error!("{:?}", e);
+            (
+                StatusCode::UNAUTHORIZED,
+                Json(json!({"message": "Unauthorized"})),
+            )
+        })
+}
+
+pub async fn koreader_auth(
+    Path(user_hash_id): Path<String>,
+    Extension(media_service): Extension<Arc<MiscellaneousService>>,
+    headers: HeaderMap,
+) -> Result<Json<serde_json::Value>, (StatusCode, Json<serde_json::Value>)> {
+    koreader_user(&user_hash_id, &headers, &media_service).await?;
+    Ok(Json(json!({"authorized": "OK"})))
+}
+
+pub async fn koreader_create_user() -> (StatusCode, Json<serde_json::Value>) {
+    (
+        StatusCode::FORBIDDEN,
+        Json(json!({
+            "message": "Registration is disabled, login with the username of your Ryot account"
+        })),
+    )
+}
+
+pub async fn koreader_get_progress(
+    Path((user_hash_id, document)): Path<(String, String)>,
+    Extension(media_service): Extension<Arc<MiscellaneousService>>,
+    headers: HeaderMap,
+) -> Result<Json<serde_json::Value>, (StatusCode, Json<serde_json::Value>)> {
+    let user_id = koreader_user(&user_hash_id, &headers, &media_service).await?;
+    let progress = media_service
+        .koreader_progress(user_id, document)
+        .await
+        .map_err(|e| {
+            tracing::error!("{:?}", e);
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(json!({"message": "Could not get the progress"})),
+            )
+        })?;
+    // DEV: KOReader expects an empty object for documents that were never synced
+    Ok(Json(match progress {
+        Some(p) => json!(p),
+        None => json!({}),
+    }))
+}
+
+pub async fn koreader_update_progress(
+    Path(user_hash_id): Path<String>,
+    Extension(media_service): Extension<Arc<MiscellaneousService>>,
+    headers: HeaderMap,
+    Json(input): Json<KoreaderProgress>,
+) -> Result<Json<serde_json::Value>, (StatusCode, Json<serde_json::Value>)> {
+    let user_id = koreader_user(&user_hash_id, &headers, &media_service).await?;
+    let document = input.document.clone();
+    let updated_on = media_service
+        .koreader_update_progress(user_id, input)
+        .await
+        .map_err(|e| {
+            tracing::error!("{:?}", e);
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(json!({"message": "Could not update the progress"})),
+            )
+        })?;
+    Ok(Json(
+        json!({"document": document, "timestamp": updated_on.timestamp()}),
+    ))
+}
+
+/// The headers of a request made by a Kobo device that need to be passed on to
+/// the Kobo store.
+fn kobo_headers(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .filter(|(name, _)| {
+            ![
+                header::HOST,
+                header::CONTENT_LENGTH,
+                header::CONNECTION,
+                header::ACCEPT_ENCODING,
+                header::TRANSFER_ENCODING,
+            ]
+            .contains(name)
+        })
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_owned())))
+        .collect()
+}
+
+async fn kobo_store_request(
+    method: &Method,
+    path: &str,
+    headers: &[(String, String)],
+    body: Vec<u8>,
+) -> Result<(u16, Option<String>, Vec<u8>), StatusCode> {
+    IntegrationService::new()
+        .kobo_store_request(method.as_str(), path, headers, body)
+        .await
+        .map_err(|e| {
+            tracing::error!("{:?}", e);
+            StatusCode::BAD_GATEWAY
+        })
+}
+
+fn kobo_response(status: u16, content_type: Option<String>, body: Vec<u8>) -> Response {
+    let mut response =
+        Response::builder().status(StatusCode::from_u16(status).unwrap_or(StatusCode::BAD_GATEWAY));
+    if let Some(content_type) = content_type {
+        response = response.header(header::CONTENT_TYPE, content_type);
+    }
+    response.body(boxed(Full::from(body))).unwrap()
+}
+
+async fn kobo_user(
+    user_hash_id: &str,
+    secret: &str,
+    media_service: &MiscellaneousService,
+) -> Result<i32, StatusCode> {
+    media_service
+        .kobo_authenticate(user_hash_id, secret)
+        .await
+        .map_err(|e| {
+            tracing::error!("{:?}", e);
+            StatusCode::UNAUTHORIZED
+        })
+}
+
+/// The device gets the URLs of the endpoints it uses from this response, so the
+/// one that it sends the reading state to is changed to point to Ryot.
+pub async fn kobo_initialization(
+    Path((user_hash_id, secret)): Path<(String, String)>,
+    Extension(media_service): Extension<Arc<MiscellaneousService>>,
+    OriginalUri(uri): OriginalUri,
+    headers: HeaderMap,
+) -> Result<Response, StatusCode> {
+    kobo_user(&user_hash_id, &secret, &media_service).await?;
+    let (status, content_type, body) = kobo_store_request(
+        &Method::GET,
+        "/v1/initialization",
+        &kobo_headers(&headers),
+        vec![],
+    )
+    .await?;
+    let mut initialization = match serde_json::from_slice::<serde_json::Value>(&body) {
+        Ok(i) if status == 200 => i,
+        _ => return Ok(kobo_response(status, content_type, body)),
+    };
+    let host = headers
+        .get(header::HOST)
+        .and_then(|h| h.to_str().ok())
+        .unwrap_or_default();
+    let scheme = headers
+        .get("x-forwarded-proto")
+        .and_then(|p| p.to_str().ok())
+        .unwrap_or("http");
+    let base = uri.path().trim_end_matches("/v1/initialization");
+    if let Some(resources) = initialization
+        .get_mut("Resources")
+        .and_then(|r| r.as_object_mut())
+    {
+        resources.insert(
+            "reading_state".to_owned(),
+            json!(format!(
+                "{}://{}{}/v1/library/{{Ids}}/state",
+                scheme, host, base
+            )),
+        );
+    }
+    Ok(Json(initialization).into_response())
+}
+
+/// Pass the reading state on to the Kobo store so that it stays in sync with
+/// the device, and update the progress of the book in Ryot.
+pub async fn kobo_reading_state(
+    Path((user_hash_id, secret, book_id)): Path<(String, String, String)>,
+    Extension(media_service): Extension<Arc<MiscellaneousService>>,
+    headers: HeaderMap,
+    payload: String,
+) -> Result<Response, StatusCode> {
+    let user_id = kobo_user(&user_hash_id, &secret, &media_service).await?;
+    let headers = kobo_headers(&headers);
+    let (status, content_type, body) = kobo_store_request(
+        &Method::PUT,
+        &format!("/v1/library/{}/state", book_id),
+        &headers,
+        payload.clone().into_bytes(),
+    )
+    .await?;
+    // DEV: Failing to find the book should not stop the device from syncing
+    if let Err(e) = media_service
+        .kobo_progress_update(user_id, &payload, &headers)
+        .await
+    {
+        tracing::error!("{:?}", e);
+    }
+    Ok(kobo_response(status, content_type, body))
+}
+
+pub async fn kobo_proxy(
+    Path((user_hash_id, secret, path)): Path<(String, String, String)>,
+    Extension(media_service): Extension<Arc<MiscellaneousService>>,
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Response, StatusCode> {
+    kobo_user(&user_hash_id, &secret, &media_service).await?;
+    let path = match uri.query() {
+        Some(query) => format!("/{}?{}", path, query),
+        None => format!("/{}", path),
+    };
+    let (status, content_type, body) =
+        kobo_store_request(&method, &path, &kobo_headers(&headers), body.to_vec()).await?;
+    Ok(kobo_response(status, content_type, body))
+}
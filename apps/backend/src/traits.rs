@@ -4,8 +4,11 @@ use async_trait::async_trait;
 
 use crate::{
     models::{
-        media::{MediaDetails, MediaSearchItem},
-        SearchResults,
+        media::{
+            AnimeSeason, ExternalIdentifierType, MediaDetails, MediaSearchFilters, MediaSearchItem,
+            MediaUpcomingItem,
+        },
+        SearchDetails, SearchResults,
     },
     utils::AuthContext,
 };
@@ -18,10 +21,56 @@ pub trait MediaProvider {
         query: &str,
         page: Option<i32>,
         display_nsfw: bool,
+        filters: &MediaSearchFilters,
     ) -> Result<SearchResults<MediaSearchItem>>;
 
+    /// Get the media items that are currently trending on this provider.
+    async fn trending(&self, _page: Option<i32>) -> Result<SearchResults<MediaSearchItem>> {
+        Ok(SearchResults {
+            details: SearchDetails {
+                total: 0,
+                next_page: None,
+            },
+            items: vec![],
+        })
+    }
+
+    /// Get the anime that started airing in a season. Only anime providers
+    /// support this.
+    async fn seasonal(
+        &self,
+        _year: i32,
+        _season: AnimeSeason,
+        _page: Option<i32>,
+    ) -> Result<SearchResults<MediaSearchItem>> {
+        Ok(SearchResults {
+            details: SearchDetails {
+                total: 0,
+                next_page: None,
+            },
+            items: vec![],
+        })
+    }
+
+    /// Get the media items that will be released soon. Providers that can only
+    /// look up upcoming releases by creator use the given creators.
+    async fn upcoming(&self, _creators: &[String]) -> Result<Vec<MediaUpcomingItem>> {
+        Ok(vec![])
+    }
+
     /// Get details about a media item for the particular identifier.
     async fn details(&self, identifier: &str) -> Result<MediaDetails>;
+
+    /// Get the identifier of a media item on this provider from its identifier
+    /// on another service, eg: an ISBN. Returns `None` if the identifier type is
+    /// not supported or no such item could be found.
+    async fn id_from_external(
+        &self,
+        _id_type: ExternalIdentifierType,
+        _value: &str,
+    ) -> Result<Option<String>> {
+        Ok(None)
+    }
 }
 
 pub trait MediaProviderLanguages {
@@ -1,8 +1,17 @@
-use async_graphql::{Enum, SimpleObject};
+use std::collections::HashMap;
+
+use async_graphql::{Enum, InputObject, SimpleObject};
 use kinded::Kinded;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use sea_orm::{prelude::DateTimeUtc, FromJsonQueryResult};
 use serde::{Deserialize, Serialize};
-use strum::EnumString;
+use strum::{EnumIter, EnumString, IntoEnumIterator};
+
+use crate::{
+    config::NsfwDisplayFloor,
+    migrator::{ExerciseEquipment, MetadataLot, MetadataSource},
+};
 
 #[derive(
     Debug, Serialize, Deserialize, SimpleObject, Clone, Eq, PartialEq, FromJsonQueryResult,
@@ -17,6 +26,8 @@ pub struct UserNotificationsPreferences {
     pub number_of_seasons_changed: bool,
     // Anime and Manga
     pub number_of_chapters_or_episodes_changed: bool,
+    // Fitness
+    pub personal_best_achieved: bool,
 }
 
 impl Default for UserNotificationsPreferences {
@@ -28,6 +39,7 @@ impl Default for UserNotificationsPreferences {
             release_date_changed: true,
             number_of_seasons_changed: true,
             number_of_chapters_or_episodes_changed: true,
+            personal_best_achieved: true,
         }
     }
 }
@@ -40,10 +52,14 @@ impl Default for UserNotificationsPreferences {
 pub struct UserMediaFeaturesEnabledPreferences {
     pub enabled: bool,
     pub anime: bool,
+    pub article: bool,
     pub audio_book: bool,
+    pub board_game: bool,
+    pub comic: bool,
     pub book: bool,
     pub manga: bool,
     pub movie: bool,
+    pub music: bool,
     pub podcast: bool,
     pub show: bool,
     pub video_game: bool,
@@ -55,10 +71,14 @@ impl Default for UserMediaFeaturesEnabledPreferences {
         Self {
             enabled: true,
             anime: true,
+            article: true,
             audio_book: true,
+            board_game: true,
+            comic: true,
             book: true,
             manga: true,
             movie: true,
+            music: true,
             podcast: true,
             show: true,
             video_game: true,
@@ -232,6 +252,44 @@ pub struct UserFeaturesEnabledPreferences {
     pub fitness: UserFitnessFeaturesEnabledPreferences,
 }
 
+/// The equipment available to the user, in the units of their preferred unit
+/// system. Used to calculate the plates to load for a weight and to suggest
+/// exercise substitutions.
+#[derive(
+    Debug, Serialize, Deserialize, SimpleObject, Clone, Eq, PartialEq, FromJsonQueryResult,
+)]
+#[serde(default)]
+pub struct UserEquipmentPreferences {
+    /// The weights of the plates that are available in pairs.
+    pub plates: Vec<Decimal>,
+    /// The weights of the bars that are available.
+    pub bars: Vec<Decimal>,
+    /// The difference in weight between consecutive dumbbells.
+    pub dumbbell_increment: Decimal,
+    /// The kinds of equipment the user has access to. Empty means that all of
+    /// them are available.
+    pub available: Vec<ExerciseEquipment>,
+}
+
+impl Default for UserEquipmentPreferences {
+    fn default() -> Self {
+        Self {
+            plates: vec![
+                dec!(25),
+                dec!(20),
+                dec!(15),
+                dec!(10),
+                dec!(5),
+                dec!(2.5),
+                dec!(1.25),
+            ],
+            bars: vec![dec!(20), dec!(15)],
+            dumbbell_increment: dec!(2.5),
+            available: vec![],
+        }
+    }
+}
+
 #[derive(
     Debug, Serialize, Deserialize, SimpleObject, Clone, Eq, PartialEq, Default, FromJsonQueryResult,
 )]
@@ -239,6 +297,7 @@ pub struct UserFeaturesEnabledPreferences {
 pub struct UserFitnessPreferences {
     pub exercises: UserExercisePreferences,
     pub measurements: UserMeasurementsPreferences,
+    pub equipment: UserEquipmentPreferences,
 }
 
 #[derive(
@@ -261,13 +320,305 @@ pub enum UserReviewScale {
     OutOfHundred,
 }
 
+#[derive(
+    Debug,
+    Serialize,
+    Default,
+    Deserialize,
+    Enum,
+    Clone,
+    Eq,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    FromJsonQueryResult,
+    Copy,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive, serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum UserNsfwDisplay {
+    /// Do not return NSFW media at all.
+    #[default]
+    Hide,
+    /// Return NSFW media, but it needs to be clicked through before it is shown.
+    Blur,
+    /// Return NSFW media like any other media.
+    Show,
+}
+
+impl UserNsfwDisplay {
+    /// The setting that is actually used, after applying the instance wide floor.
+    pub fn with_floor(self, floor: &NsfwDisplayFloor) -> Self {
+        let floor = match floor {
+            NsfwDisplayFloor::Hide => Self::Hide,
+            NsfwDisplayFloor::Blur => Self::Blur,
+            NsfwDisplayFloor::Show => Self::Show,
+        };
+        self.min(floor)
+    }
+}
+
 #[derive(
     Debug, Serialize, Deserialize, SimpleObject, Clone, Eq, PartialEq, Default, FromJsonQueryResult,
 )]
 #[serde(default)]
 pub struct UserGeneralPreferences {
     pub review_scale: UserReviewScale,
-    pub display_nsfw: bool,
+    pub nsfw_display: UserNsfwDisplay,
+}
+
+/// The assumptions used to estimate the time spent on media in the summary.
+#[derive(
+    Debug, Serialize, Deserialize, SimpleObject, Clone, Eq, PartialEq, FromJsonQueryResult,
+)]
+#[serde(default)]
+pub struct UserTimeEstimationPreferences {
+    /// The speed at which podcasts are usually played, eg: `1.5`.
+    pub podcast_playback_speed: Decimal,
+    /// The runtime (in minutes) of the episodes of shows and podcasts that do
+    /// not have one.
+    pub default_episode_runtime: Option<i32>,
+    /// The reading speed used to estimate the time spent reading books.
+    pub pages_per_hour: Option<i32>,
+}
+
+impl Default for UserTimeEstimationPreferences {
+    fn default() -> Self {
+        Self {
+            podcast_playback_speed: dec!(1),
+            default_episode_runtime: None,
+            pages_per_hour: None,
+        }
+    }
+}
+
+#[derive(
+    Debug, Serialize, Deserialize, SimpleObject, Clone, Eq, PartialEq, Default, FromJsonQueryResult,
+)]
+#[serde(default)]
+pub struct UserLanguagePreferences {
+    /// The language to request from TMDB. Uses the server locale if not set.
+    pub tmdb: Option<String>,
+    /// The language of the titles to use from Anilist.
+    pub anilist: Option<String>,
+}
+
+impl UserLanguagePreferences {
+    pub fn for_source(&self, source: MetadataSource) -> Option<String> {
+        match source {
+            MetadataSource::Tmdb => self.tmdb.clone(),
+            MetadataSource::Anilist => self.anilist.clone(),
+            _ => None,
+        }
+    }
+}
+
+/// The provider used for each type of media when searching all of them at once
+/// or discovering trending media. Uses the first provider of the type if not set.
+#[derive(
+    Debug, Serialize, Deserialize, SimpleObject, Clone, Eq, PartialEq, Default, FromJsonQueryResult,
+)]
+#[serde(default)]
+pub struct UserProviderPreferences {
+    pub anime: Option<MetadataSource>,
+    pub article: Option<MetadataSource>,
+    pub audio_book: Option<MetadataSource>,
+    pub board_game: Option<MetadataSource>,
+    pub book: Option<MetadataSource>,
+    pub comic: Option<MetadataSource>,
+    pub manga: Option<MetadataSource>,
+    pub movie: Option<MetadataSource>,
+    pub music: Option<MetadataSource>,
+    pub podcast: Option<MetadataSource>,
+    pub show: Option<MetadataSource>,
+    pub video_game: Option<MetadataSource>,
+    pub visual_novel: Option<MetadataSource>,
+}
+
+impl UserProviderPreferences {
+    pub fn for_lot(&self, lot: MetadataLot) -> Option<MetadataSource> {
+        match lot {
+            MetadataLot::Anime => self.anime,
+            MetadataLot::Article => self.article,
+            MetadataLot::AudioBook => self.audio_book,
+            MetadataLot::BoardGame => self.board_game,
+            MetadataLot::Book => self.book,
+            MetadataLot::Comic => self.comic,
+            MetadataLot::Manga => self.manga,
+            MetadataLot::Movie => self.movie,
+            MetadataLot::Music => self.music,
+            MetadataLot::Podcast => self.podcast,
+            MetadataLot::Show => self.show,
+            MetadataLot::VideoGame => self.video_game,
+            MetadataLot::VisualNovel => self.visual_novel,
+        }
+    }
+
+    pub fn for_lot_mut(&mut self, lot: MetadataLot) -> &mut Option<MetadataSource> {
+        match lot {
+            MetadataLot::Anime => &mut self.anime,
+            MetadataLot::Article => &mut self.article,
+            MetadataLot::AudioBook => &mut self.audio_book,
+            MetadataLot::BoardGame => &mut self.board_game,
+            MetadataLot::Book => &mut self.book,
+            MetadataLot::Comic => &mut self.comic,
+            MetadataLot::Manga => &mut self.manga,
+            MetadataLot::Movie => &mut self.movie,
+            MetadataLot::Music => &mut self.music,
+            MetadataLot::Podcast => &mut self.podcast,
+            MetadataLot::Show => &mut self.show,
+            MetadataLot::VideoGame => &mut self.video_game,
+            MetadataLot::VisualNovel => &mut self.visual_novel,
+        }
+    }
+}
+
+#[derive(
+    Debug, Serialize, Deserialize, SimpleObject, Clone, Eq, PartialEq, Default, FromJsonQueryResult,
+)]
+#[serde(default)]
+pub struct UserFollowingPreferences {
+    /// Upcoming releases in these genres are shown to the user.
+    pub genres: Vec<String>,
+    /// Upcoming releases by these people or companies are shown to the user.
+    pub creators: Vec<String>,
+}
+
+#[derive(
+    Debug, Serialize, Deserialize, Enum, Clone, Copy, Eq, PartialEq, EnumIter, FromJsonQueryResult,
+)]
+pub enum DashboardElementLot {
+    InProgress,
+    Upcoming,
+    Summary,
+    Recommendations,
+}
+
+#[derive(
+    Debug,
+    Serialize,
+    Deserialize,
+    SimpleObject,
+    InputObject,
+    Clone,
+    Eq,
+    PartialEq,
+    FromJsonQueryResult,
+)]
+#[graphql(input_name = "UserDashboardSectionInput")]
+pub struct UserDashboardSection {
+    pub section: DashboardElementLot,
+    pub hidden: bool,
+}
+
+#[derive(
+    Debug, Serialize, Deserialize, SimpleObject, Clone, Eq, PartialEq, FromJsonQueryResult,
+)]
+#[serde(default)]
+pub struct UserDashboardPreferences {
+    /// The IDs of the media that are always shown on the dashboard.
+    pub pinned_media: Vec<i32>,
+    /// The IDs of the collections that are always shown on the dashboard.
+    pub pinned_collections: Vec<i32>,
+    /// The sections of the dashboard in the order they should be displayed.
+    /// Every section is present exactly once.
+    pub sections: Vec<UserDashboardSection>,
+}
+
+impl Default for UserDashboardPreferences {
+    fn default() -> Self {
+        Self {
+            pinned_media: vec![],
+            pinned_collections: vec![],
+            sections: DashboardElementLot::iter()
+                .map(|section| UserDashboardSection {
+                    section,
+                    hidden: false,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The contexts (where or how the media was seen) that can be picked for a seen
+/// item, for each type of media. An empty list disables them for that type.
+#[derive(
+    Debug, Serialize, Deserialize, SimpleObject, Clone, Eq, PartialEq, FromJsonQueryResult,
+)]
+#[serde(default)]
+pub struct UserSeenContextPreferences {
+    pub anime: Vec<String>,
+    pub article: Vec<String>,
+    pub audio_book: Vec<String>,
+    pub board_game: Vec<String>,
+    pub comic: Vec<String>,
+    pub book: Vec<String>,
+    pub manga: Vec<String>,
+    pub movie: Vec<String>,
+    pub music: Vec<String>,
+    pub podcast: Vec<String>,
+    pub show: Vec<String>,
+    pub video_game: Vec<String>,
+    pub visual_novel: Vec<String>,
+}
+
+impl Default for UserSeenContextPreferences {
+    fn default() -> Self {
+        let contexts = |c: &[&str]| c.iter().map(|c| c.to_string()).collect();
+        Self {
+            anime: contexts(&["Streaming", "Television", "Cinema"]),
+            article: vec![],
+            audio_book: vec![],
+            board_game: contexts(&["Physical", "Online"]),
+            comic: contexts(&["Print", "Digital"]),
+            book: contexts(&["Physical", "Ebook", "Audiobook"]),
+            manga: contexts(&["Physical", "Digital"]),
+            movie: contexts(&["Cinema", "Home"]),
+            music: contexts(&["Streaming", "Vinyl", "CD"]),
+            podcast: vec![],
+            show: contexts(&["Streaming", "Television"]),
+            video_game: contexts(&["PC", "PlayStation", "Xbox", "Nintendo Switch", "Mobile"]),
+            visual_novel: contexts(&["PC", "Mobile"]),
+        }
+    }
+}
+
+impl UserSeenContextPreferences {
+    pub fn for_lot(&self, lot: MetadataLot) -> &Vec<String> {
+        match lot {
+            MetadataLot::Anime => &self.anime,
+            MetadataLot::Article => &self.article,
+            MetadataLot::AudioBook => &self.audio_book,
+            MetadataLot::BoardGame => &self.board_game,
+            MetadataLot::Comic => &self.comic,
+            MetadataLot::Book => &self.book,
+            MetadataLot::Manga => &self.manga,
+            MetadataLot::Movie => &self.movie,
+            MetadataLot::Music => &self.music,
+            MetadataLot::Podcast => &self.podcast,
+            MetadataLot::Show => &self.show,
+            MetadataLot::VideoGame => &self.video_game,
+            MetadataLot::VisualNovel => &self.visual_novel,
+        }
+    }
+
+    pub fn for_lot_mut(&mut self, lot: MetadataLot) -> &mut Vec<String> {
+        match lot {
+            MetadataLot::Anime => &mut self.anime,
+            MetadataLot::Article => &mut self.article,
+            MetadataLot::AudioBook => &mut self.audio_book,
+            MetadataLot::BoardGame => &mut self.board_game,
+            MetadataLot::Comic => &mut self.comic,
+            MetadataLot::Book => &mut self.book,
+            MetadataLot::Manga => &mut self.manga,
+            MetadataLot::Movie => &mut self.movie,
+            MetadataLot::Music => &mut self.music,
+            MetadataLot::Podcast => &mut self.podcast,
+            MetadataLot::Show => &mut self.show,
+            MetadataLot::VideoGame => &mut self.video_game,
+            MetadataLot::VisualNovel => &mut self.visual_novel,
+        }
+    }
 }
 
 #[derive(
@@ -279,13 +630,84 @@ pub struct UserPreferences {
     pub notifications: UserNotificationsPreferences,
     pub fitness: UserFitnessPreferences,
     pub general: UserGeneralPreferences,
+    pub time_estimation: UserTimeEstimationPreferences,
+    pub languages: UserLanguagePreferences,
+    pub providers: UserProviderPreferences,
+    pub following: UserFollowingPreferences,
+    pub dashboard: UserDashboardPreferences,
+    pub seen_contexts: UserSeenContextPreferences,
 }
 
 #[derive(Kinded, Debug, Serialize, Deserialize, Clone, Eq, PartialEq, FromJsonQueryResult)]
 #[serde(tag = "t", content = "d")]
 #[kinded(derive(Enum, Serialize, Deserialize, Clone, Debug, Copy, PartialEq, Eq))]
 pub enum UserYankIntegrationSetting {
-    Audiobookshelf { base_url: String, token: String },
+    Audiobookshelf {
+        base_url: String,
+        token: String,
+    },
+    Strava {
+        athlete: String,
+        access_token: String,
+        refresh_token: String,
+        /// The unix timestamp after which the access token needs to be refreshed.
+        expires_at: i64,
+        /// The start time of the most recent activity that was synced.
+        last_synced_on: Option<DateTimeUtc>,
+    },
+    Steam {
+        steam_id: String,
+        api_key: String,
+        /// The playtime in minutes of each game when it was last synced, by the
+        /// id of the game on Steam.
+        #[serde(default)]
+        playtimes: HashMap<String, i32>,
+    },
+    RetroAchievements {
+        username: String,
+        api_key: String,
+        /// The time of the most recent session that was synced.
+        last_synced_on: Option<DateTimeUtc>,
+        /// The ids of the games on IGDB, by the id of the game on RetroAchievements.
+        #[serde(default)]
+        games: HashMap<String, String>,
+    },
+}
+
+/// How the progress that an integration sends is applied to the media.
+#[derive(Debug, Serialize, Deserialize, SimpleObject, InputObject, Clone, Eq, PartialEq)]
+#[graphql(input_name = "IntegrationProgressSettingsInput")]
+#[serde(default)]
+pub struct IntegrationProgressSettings {
+    /// The progress in percent from which the media is marked as completed.
+    /// Defaults to `90`. Uses the one configured for the server if not set.
+    pub completion_threshold: Option<i32>,
+    /// The playback position in seconds that the media has to reach before its
+    /// progress is updated. It is compared with the position in the media, not
+    /// the time spent playing it. Only applies to integrations that send the
+    /// position.
+    pub minimum_duration: Option<i32>,
+    /// Whether the progress sent when the media is paused should be ignored.
+    pub ignore_pauses: bool,
+}
+
+impl Default for IntegrationProgressSettings {
+    fn default() -> Self {
+        Self {
+            completion_threshold: Some(90),
+            minimum_duration: None,
+            ignore_pauses: false,
+        }
+    }
+}
+
+/// A profile on a media server whose progress is saved to another user.
+#[derive(Debug, Serialize, Deserialize, SimpleObject, InputObject, Clone, Eq, PartialEq)]
+#[graphql(input_name = "IntegrationUserMappingInput")]
+pub struct IntegrationUserMapping {
+    /// The name of the profile on the media server.
+    pub server_username: String,
+    pub user_id: i32,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, FromJsonQueryResult)]
@@ -294,6 +716,8 @@ pub struct UserYankIntegration {
     pub settings: UserYankIntegrationSetting,
     /// the date and time it was added on
     pub timestamp: DateTimeUtc,
+    #[serde(default)]
+    pub progress_settings: IntegrationProgressSettings,
 }
 
 // FIXME: Remove this
@@ -307,6 +731,8 @@ pub enum UserSinkIntegrationSetting {
     Jellyfin { slug: String },
     Plex { slug: String, user: Option<String> },
     Kodi { slug: String },
+    Kobo { slug: String },
+    Koreader { slug: String },
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq, FromJsonQueryResult)]
@@ -315,6 +741,18 @@ pub struct UserSinkIntegration {
     pub settings: UserSinkIntegrationSetting,
     /// the date and time it was added on
     pub timestamp: DateTimeUtc,
+    /// The secret that payloads sent to the webhook of the integration have to be
+    /// signed with. Integrations that were added before webhooks were verified
+    /// do not have one, and their webhooks are rejected.
+    #[serde(default)]
+    pub secret: Option<String>,
+    #[serde(default)]
+    pub progress_settings: IntegrationProgressSettings,
+    /// The users that the progress of other profiles on the media server is
+    /// saved to. Profiles that are not mapped are saved to the owner of the
+    /// integration.
+    #[serde(default)]
+    pub user_mappings: Vec<IntegrationUserMapping>,
 }
 
 // FIXME: Remove this
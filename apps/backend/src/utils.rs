@@ -1,4 +1,5 @@
 use std::{
+    net::{IpAddr, SocketAddr},
     sync::Arc,
     time::{SystemTime, UNIX_EPOCH},
 };
@@ -7,37 +8,43 @@ use apalis::sqlite::SqliteStorage;
 use async_graphql::{Error, Result};
 use axum::{
     async_trait,
-    extract::FromRequestParts,
+    extract::{ConnectInfo, FromRequestParts},
     http::{request::Parts, StatusCode},
     Extension, RequestPartsExt,
 };
 use axum_extra::extract::cookie::CookieJar;
-use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use http::header::AUTHORIZATION;
 use http_types::headers::HeaderName;
+use rust_decimal::Decimal;
 use sea_orm::{
     prelude::DateTimeUtc, ActiveModelTrait, ActiveValue, ColumnTrait, ConnectionTrait,
-    DatabaseConnection, EntityTrait, QueryFilter,
+    DatabaseConnection, DbErr, EntityTrait, QueryFilter, QuerySelect,
 };
-use sea_query::{BinOper, Expr, Func, SimpleExpr};
+use sea_query::{BinOper, Expr, Func, OnConflict, SimpleExpr};
 use surf::{
     http::headers::{ToHeaderValues, USER_AGENT},
     Client, Config, Url,
 };
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
 
 use crate::{
     background::ApplicationJob,
     config::AppConfig,
     entities::{
-        prelude::{User, UserToMetadata},
-        user, user_to_metadata,
+        metadata,
+        prelude::{Metadata, User, UserStatistic, UserToMetadata},
+        user, user_statistic, user_to_metadata,
     },
     file_storage::FileStorageService,
     fitness::resolver::ExerciseService,
+    http_cache::HttpCacheMiddleware,
     importer::ImporterService,
     jwt,
+    migrator::{MetadataLot, MetadataSource},
     miscellaneous::resolver::MiscellaneousService,
     models::StoredUrl,
+    rate_limit,
 };
 
 pub static BASE_DIR: &str = env!("CARGO_MANIFEST_DIR");
@@ -78,6 +85,7 @@ pub async fn create_app_services(
     let file_storage_service = Arc::new(FileStorageService::new(
         s3_client,
         config.file_storage.s3_bucket_name.clone(),
+        config.file_storage.signed_url_expiry_minutes,
     ));
     let exercise_service = Arc::new(ExerciseService::new(
         &db,
@@ -95,7 +103,10 @@ pub async fn create_app_services(
         )
         .await,
     );
-    let importer_service = Arc::new(ImporterService::new(media_service.clone()));
+    let importer_service = Arc::new(ImporterService::new(
+        media_service.clone(),
+        exercise_service.clone(),
+    ));
     AppServices {
         config,
         media_service,
@@ -144,6 +155,87 @@ where
     })
 }
 
+/// A change to the statistics of a user for a single month.
+#[derive(Debug, Default, Clone)]
+pub struct UserStatisticDelta {
+    pub seen: i32,
+    pub reviews: i32,
+    pub rating_sum: Decimal,
+    pub ratings: i32,
+}
+
+/// Apply the change to the statistics of the month that the date is in. The
+/// counts are incremented by the database so that concurrent writes do not
+/// overwrite each other.
+pub async fn update_user_statistic<C>(
+    user_id: i32,
+    metadata_id: i32,
+    date: NaiveDate,
+    delta: UserStatisticDelta,
+    db: &C,
+) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    let lot = Metadata::find_by_id(metadata_id)
+        .select_only()
+        .column(metadata::Column::Lot)
+        .into_tuple::<MetadataLot>()
+        .one(db)
+        .await?;
+    let lot = match lot {
+        Some(lot) => lot,
+        None => return Ok(()),
+    };
+    let increment = |column: user_statistic::Column, value: SimpleExpr| {
+        Expr::col((UserStatistic, column)).add(value)
+    };
+    let statistic = user_statistic::ActiveModel {
+        user_id: ActiveValue::Set(user_id),
+        lot: ActiveValue::Set(lot),
+        month: ActiveValue::Set(date.with_day(1).unwrap()),
+        seen_count: ActiveValue::Set(delta.seen),
+        review_count: ActiveValue::Set(delta.reviews),
+        rating_sum: ActiveValue::Set(delta.rating_sum),
+        rating_count: ActiveValue::Set(delta.ratings),
+        ..Default::default()
+    };
+    UserStatistic::insert(statistic)
+        .on_conflict(
+            OnConflict::columns([
+                user_statistic::Column::UserId,
+                user_statistic::Column::Lot,
+                user_statistic::Column::Month,
+            ])
+            .value(
+                user_statistic::Column::SeenCount,
+                increment(user_statistic::Column::SeenCount, delta.seen.into()),
+            )
+            .value(
+                user_statistic::Column::ReviewCount,
+                increment(user_statistic::Column::ReviewCount, delta.reviews.into()),
+            )
+            .value(
+                user_statistic::Column::RatingSum,
+                increment(user_statistic::Column::RatingSum, delta.rating_sum.into()),
+            )
+            .value(
+                user_statistic::Column::RatingCount,
+                increment(user_statistic::Column::RatingCount, delta.ratings.into()),
+            )
+            .to_owned(),
+        )
+        .exec(db)
+        .await?;
+    Ok(())
+}
+
+/// Compare two values in a time that does not depend on where they differ, so
+/// that a secret can not be guessed one character at a time.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 pub fn user_id_from_token(token: &str, jwt_secret: &str) -> Result<i32> {
     jwt::verify(token, jwt_secret)
         .map(|c| c.sub.parse().unwrap())
@@ -172,9 +264,12 @@ pub fn get_now_timestamp() -> u128 {
         .as_millis()
 }
 
+/// The responses of the providers are cached and their requests are rate limited.
+/// Pass `None` as the source for the other services.
 pub fn get_base_http_client(
     url: &str,
     headers: Vec<(impl Into<HeaderName>, impl ToHeaderValues)>,
+    source: Option<MetadataSource>,
 ) -> Client {
     let mut config = Config::new()
         .add_header(USER_AGENT, USER_AGENT_STR)
@@ -182,10 +277,14 @@ pub fn get_base_http_client(
     for (header, value) in headers.into_iter() {
         config = config.add_header(header, value).unwrap();
     }
-    config
+    let client: Client = config
         .set_base_url(Url::parse(url).unwrap())
         .try_into()
-        .unwrap()
+        .unwrap();
+    match source {
+        Some(source) => rate_limit::apply(client.with(HttpCacheMiddleware), source),
+        None => client,
+    }
 }
 
 pub fn get_case_insensitive_like_query<E>(expr: E, v: &str) -> SimpleExpr
@@ -199,6 +298,15 @@ where
     )
 }
 
+/// Lowercase the text and remove all diacritics from it so that "Misérables" and
+/// "miserables" are considered equal.
+pub fn normalize_text(text: &str) -> String {
+    text.nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .collect::<String>()
+        .to_lowercase()
+}
+
 pub async fn get_stored_asset(
     url: StoredUrl,
     files_storage_service: &Arc<FileStorageService>,
@@ -209,6 +317,22 @@ pub async fn get_stored_asset(
     }
 }
 
+/// Send a message to all the notification platforms configured by the user.
+pub async fn send_notifications_to_user_platforms(
+    db: &DatabaseConnection,
+    user_id: i32,
+    msg: &str,
+) -> Result<bool> {
+    let user = user_by_id(db, user_id).await?;
+    let mut success = true;
+    for notification in user.notifications.0 {
+        if notification.settings.send_message(msg).await.is_err() {
+            success = false;
+        }
+    }
+    Ok(success)
+}
+
 pub async fn user_by_id(db: &DatabaseConnection, user_id: i32) -> Result<user::Model> {
     User::find_by_id(user_id)
         .one(db)
@@ -231,6 +355,8 @@ pub fn get_first_and_last_day_of_month(year: i32, month: u32) -> (NaiveDate, Nai
 pub struct AuthContext {
     pub auth_token: Option<String>,
     pub user_id: Option<i32>,
+    /// The IP address of the client that made the request.
+    pub ip: Option<IpAddr>,
 }
 
 #[async_trait]
@@ -251,12 +377,28 @@ where
         } else if let Some(h) = parts.headers.get("X-Auth-Token") {
             ctx.auth_token = h.to_str().map(String::from).ok();
         }
+        let Extension(config) = parts.extract::<Extension<Arc<AppConfig>>>().await.unwrap();
         if let Some(auth_token) = ctx.auth_token.as_ref() {
-            let Extension(config) = parts.extract::<Extension<Arc<AppConfig>>>().await.unwrap();
             if let Ok(user_id) = user_id_from_token(auth_token, &config.users.jwt_secret) {
                 ctx.user_id = Some(user_id);
             }
         }
+        let throttling = &config.users.login_throttling;
+        // DEV: Every proxy appends the address it got the request from, so only
+        // the last entries can be trusted
+        let forwarded_for = parts
+            .headers
+            .get("X-Forwarded-For")
+            .and_then(|h| h.to_str().ok())
+            .and_then(|h| h.rsplit(',').take(throttling.trusted_proxies.max(1)).last())
+            .and_then(|ip| ip.trim().parse().ok())
+            .filter(|_| throttling.trust_forwarded_for);
+        ctx.ip = forwarded_for.or_else(|| {
+            parts
+                .extensions
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|c| c.0.ip())
+        });
         Ok(ctx)
     }
 }
@@ -4,6 +4,9 @@ export const APP_ROUTES = {
 	auth: {
 		login: "/auth/login",
 		register: "/auth/register",
+		forgotPassword: "/auth/forgot-password",
+		resetPassword: "/auth/reset-password",
+		verifyEmail: "/auth/verify-email",
 	},
 	settings: {
 		profile: "/settings/profile",
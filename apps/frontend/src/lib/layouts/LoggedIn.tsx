@@ -4,6 +4,7 @@ import { useCoreDetails } from "@/lib/hooks/graphql";
 import { gqlClient } from "@/lib/services/api";
 import { getLot } from "@/lib/utilities";
 import {
+	Alert,
 	Anchor,
 	AppShell,
 	Box,
@@ -28,6 +29,8 @@ import {
 import { upperFirst, useDisclosure, useLocalStorage } from "@mantine/hooks";
 import { notifications } from "@mantine/notifications";
 import {
+	AnnouncementSeverity,
+	AnnouncementsDocument,
 	LogoutUserDocument,
 	UpgradeType,
 	UserDetailsDocument,
@@ -57,6 +60,43 @@ import { withQuery } from "ufo";
 
 const AUTH_COOKIE = "auth";
 
+const Announcements = () => {
+	const [dismissed, setDismissed] = useLocalStorage<number[]>({
+		key: "dismissedAnnouncements",
+		defaultValue: [],
+		getInitialValueInEffect: true,
+	});
+	const announcements = useQuery({
+		queryKey: ["announcements"],
+		queryFn: async () => {
+			const { announcements } = await gqlClient.request(AnnouncementsDocument);
+			return announcements;
+		},
+	});
+	const visible = (announcements.data || []).filter(
+		(a) => !dismissed.includes(a.id),
+	);
+
+	return visible.length > 0 ? (
+		<Stack px="md" spacing="xs">
+			{visible.map((a) => (
+				<Alert
+					key={a.id}
+					color={match(a.severity)
+						.with(AnnouncementSeverity.Info, () => "blue")
+						.with(AnnouncementSeverity.Warning, () => "yellow")
+						.with(AnnouncementSeverity.Critical, () => "red")
+						.exhaustive()}
+					withCloseButton
+					onClose={() => setDismissed([...dismissed, a.id])}
+				>
+					{a.message}
+				</Alert>
+			))}
+		</Stack>
+	) : undefined;
+};
+
 const Footer = () => {
 	const coreDetails = useCoreDetails();
 	const [color, text] = match(coreDetails.data?.upgrade)
@@ -577,6 +617,7 @@ export default function ({ children }: { children: ReactElement }) {
 						/>
 					</Flex>
 				</MediaQuery>
+				<Announcements />
 				<Box mt="md" style={{ flexGrow: 1 }}>
 					{children}
 				</Box>
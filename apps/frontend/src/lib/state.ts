@@ -35,7 +35,7 @@ type InProgressWorkout = Immutable<{
 	comment?: string;
 	exercises: Array<Exercise>;
 	// TODO: Superset support pending
-	// supersets: Array<Array<number>>;
+	// groups: Array<ExerciseGroupInput>;
 }>;
 
 export const currentWorkoutAtom = atomWithStorage<InProgressWorkout | null>(
@@ -57,7 +57,7 @@ export const getDefaultWorkout = (): InProgressWorkout => {
 		name: `${getTimeOfDay(date)} Workout`,
 		startTime: date.toISOString(),
 		exercises: [],
-		// supersets: [],
+		// groups: [],
 	};
 };
 
@@ -70,7 +70,7 @@ export const currentWorkoutToCreateWorkoutInput = (
 			startTime: new Date(currentWorkout.startTime),
 			name: currentWorkout.name,
 			comment: currentWorkout.comment,
-			supersets: [],
+			groups: [],
 			exercises: [],
 		},
 	};
@@ -4,15 +4,19 @@ import {
 	SetLot,
 } from "@ryot/generated/graphql/backend/graphql";
 import {
+	IconArticle,
 	IconBook,
 	IconBook2,
 	IconBooks,
 	IconBrandAppleArcade,
+	IconChess,
 	IconDeviceDesktop,
 	IconDeviceTv,
 	IconDeviceTvOld,
 	IconHeadphones,
 	IconMicrophone,
+	IconMusic,
+	IconNotebook,
 } from "@tabler/icons-react";
 import { match } from "ts-pattern";
 
@@ -33,8 +37,20 @@ export const getLot = (lot: unknown) => {
 	return match(newLot)
 		.with("anime", "animes", () => MetadataLot.Anime)
 		.with("manga", "mangas", () => MetadataLot.Manga)
+		.with("article", "articles", () => MetadataLot.Article)
+		.with(
+			"board_game",
+			"board_games",
+			"boardgame",
+			"boardgames",
+			"board game",
+			"board games",
+			() => MetadataLot.BoardGame,
+		)
 		.with("books", "book", () => MetadataLot.Book)
+		.with("comics", "comic", () => MetadataLot.Comic)
 		.with("movies", "movie", () => MetadataLot.Movie)
+		.with("music", "album", "albums", () => MetadataLot.Music)
 		.with("tv", "show", "shows", () => MetadataLot.Show)
 		.with(
 			"visual_novel",
@@ -66,7 +82,9 @@ export const getLot = (lot: unknown) => {
 export const getLotGradient = (lot: MetadataLot) =>
 	match(lot)
 		.with(MetadataLot.AudioBook, () => ({ from: "indigo", to: "cyan" }))
+		.with(MetadataLot.BoardGame, () => ({ from: "orange", to: "yellow" }))
 		.with(MetadataLot.Book, () => ({ from: "teal", to: "lime" }))
+		.with(MetadataLot.Comic, () => ({ from: "red", to: "orange" }))
 		.with(MetadataLot.Movie, () => ({ from: "teal", to: "blue" }))
 		.with(MetadataLot.Show, () => ({ from: "orange", to: "red" }))
 		.with(MetadataLot.VideoGame, () => ({
@@ -89,6 +107,14 @@ export const getLotGradient = (lot: MetadataLot) =>
 			from: "green",
 			to: "yellow",
 		}))
+		.with(MetadataLot.Article, () => ({
+			from: "gray",
+			to: "teal",
+		}))
+		.with(MetadataLot.Music, () => ({
+			from: "pink",
+			to: "violet",
+		}))
 		.exhaustive();
 
 /**
@@ -100,11 +126,17 @@ export const getSource = (source: unknown) => {
 	return match(newLot)
 		.with("anilist", () => MetadataSource.Anilist)
 		.with("audible", () => MetadataSource.Audible)
+		.with("boardgamegeek", "bgg", () => MetadataSource.BoardGameGeek)
+		.with("comicvine", () => MetadataSource.ComicVine)
 		.with("custom", () => MetadataSource.Custom)
 		.with("igdb", () => MetadataSource.Igdb)
 		.with("listennotes", () => MetadataSource.Listennotes)
+		.with("mangadex", () => MetadataSource.MangaDex)
+		.with("musicbrainz", () => MetadataSource.MusicBrainz)
 		.with("openlibrary", () => MetadataSource.Openlibrary)
+		.with("podcastindex", () => MetadataSource.PodcastIndex)
 		.with("tmdb", () => MetadataSource.Tmdb)
+		.with("web", () => MetadataSource.Web)
 		.otherwise(() => undefined);
 };
 
@@ -116,7 +148,13 @@ export const getVerb = (verb: Verb, lot: MetadataLot) => {
 	return match(verb)
 		.with(Verb.Read, () => {
 			return match(lot)
-				.with(MetadataLot.Book, MetadataLot.Manga, () => "read")
+				.with(
+					MetadataLot.Book,
+					MetadataLot.Manga,
+					MetadataLot.Comic,
+					MetadataLot.Article,
+					() => "read",
+				)
 				.with(
 					MetadataLot.Movie,
 					MetadataLot.Show,
@@ -128,6 +166,8 @@ export const getVerb = (verb: Verb, lot: MetadataLot) => {
 					MetadataLot.AudioBook,
 					MetadataLot.VideoGame,
 					MetadataLot.Podcast,
+					MetadataLot.Music,
+					MetadataLot.BoardGame,
 					() => "play",
 				)
 				.otherwise(() => {
@@ -183,5 +223,9 @@ export const getMetadataIcon = (lot: MetadataLot) => {
 		.with(MetadataLot.Manga, () => IconDeviceTvOld)
 		.with(MetadataLot.Anime, () => IconBooks)
 		.with(MetadataLot.VisualNovel, () => IconBook2)
+		.with(MetadataLot.Article, () => IconArticle)
+		.with(MetadataLot.Music, () => IconMusic)
+		.with(MetadataLot.BoardGame, () => IconChess)
+		.with(MetadataLot.Comic, () => IconNotebook)
 		.exhaustive();
 };
@@ -0,0 +1,84 @@
+import { APP_ROUTES } from "@/lib/constants";
+import { gqlClient } from "@/lib/services/api";
+import { Anchor, Box, Button, Text, TextInput } from "@mantine/core";
+import { useForm, zodResolver } from "@mantine/form";
+import { notifications } from "@mantine/notifications";
+import { RequestUserPasswordResetDocument } from "@ryot/generated/graphql/backend/graphql";
+import { useMutation } from "@tanstack/react-query";
+import Head from "next/head";
+import Link from "next/link";
+import { z } from "zod";
+
+const formSchema = z.object({ username: z.string() });
+type FormSchema = z.infer<typeof formSchema>;
+
+export default function Page() {
+	const requestPasswordReset = useMutation({
+		mutationFn: async (username: string) => {
+			const { requestUserPasswordReset } = await gqlClient.request(
+				RequestUserPasswordResetDocument,
+				{ username },
+			);
+			return requestUserPasswordReset;
+		},
+		onSuccess() {
+			notifications.show({
+				title: "Success",
+				message:
+					"If this user has an email, a link to reset the password has been sent to it",
+				color: "green",
+			});
+		},
+	});
+	const form = useForm<FormSchema>({ validate: zodResolver(formSchema) });
+
+	return (
+		<>
+			<Head>
+				<title>Forgot Password | Ryot</title>
+			</Head>
+			<Box
+				component="form"
+				my={"auto"}
+				mx={"auto"}
+				onSubmit={form.onSubmit((values) => {
+					requestPasswordReset.mutate(values.username);
+				})}
+				sx={(t) => ({
+					width: "80%",
+					[t.fn.largerThan("sm")]: { width: "60%" },
+					[t.fn.largerThan("md")]: { width: "50%" },
+					[t.fn.largerThan("lg")]: { width: "40%" },
+					[t.fn.largerThan("xl")]: { width: "30%" },
+				})}
+			>
+				<Text size="sm" color="dimmed">
+					Enter your username and a link to reset your password will be sent to
+					your email.
+				</Text>
+				<TextInput
+					label="Username"
+					mt="md"
+					{...form.getInputProps("username")}
+					required
+					autoFocus
+				/>
+				<Button
+					mt="md"
+					type="submit"
+					loading={requestPasswordReset.isLoading}
+					w="100%"
+				>
+					Send reset link
+				</Button>
+				<Box mt="lg" style={{ textAlign: "right" }}>
+					Remembered it? Login{" "}
+					<Link href={APP_ROUTES.auth.login} passHref legacyBehavior>
+						<Anchor>here</Anchor>
+					</Link>
+					.
+				</Box>
+			</Box>
+		</>
+	);
+}
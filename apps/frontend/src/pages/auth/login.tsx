@@ -7,6 +7,7 @@ import { notifications } from "@mantine/notifications";
 import {
 	LoginErrorVariant,
 	LoginUserDocument,
+	RequestUserEmailVerificationDocument,
 	type UserInput,
 } from "@ryot/generated/graphql/backend/graphql";
 import { useMutation } from "@tanstack/react-query";
@@ -28,6 +29,15 @@ export default function Page() {
 	const router = useRouter();
 	const coreDetails = useCoreDetails();
 	const enabledFeatures = useEnabledCoreFeatures();
+	const requestEmailVerification = useMutation({
+		mutationFn: async (username: string) => {
+			const { requestUserEmailVerification } = await gqlClient.request(
+				RequestUserEmailVerificationDocument,
+				{ username },
+			);
+			return requestUserEmailVerification;
+		},
+	});
 	const loginUser = useMutation({
 		mutationFn: async (input: UserInput) => {
 			const { loginUser } = await gqlClient.request(LoginUserDocument, {
@@ -35,7 +45,7 @@ export default function Page() {
 			});
 			return loginUser;
 		},
-		onSuccess: (data) => {
+		onSuccess: (data, input) => {
 			if (data.__typename === "LoginResponse") {
 				router.push(APP_ROUTES.dashboard);
 				return;
@@ -49,6 +59,14 @@ export default function Page() {
 						LoginErrorVariant.UsernameDoesNotExist,
 						() => "The username provided does not exist",
 					)
+					.with(LoginErrorVariant.EmailNotVerified, () => {
+						requestEmailVerification.mutate(input.username);
+						return "Your email is not verified yet. A new verification link has been sent to it.";
+					})
+					.with(
+						LoginErrorVariant.TooManyAttempts,
+						() => "Too many failed logins, please try again later",
+					)
 					.exhaustive();
 				notifications.show({
 					title: "Error in login",
@@ -99,6 +117,17 @@ export default function Page() {
 					{...form.getInputProps("password")}
 					required
 				/>
+				{enabledFeatures.data?.passwordResetAllowed ? (
+					<Box mt="xs" style={{ textAlign: "right" }}>
+						<Link
+							href={APP_ROUTES.auth.forgotPassword}
+							passHref
+							legacyBehavior
+						>
+							<Anchor size="sm">Forgot password?</Anchor>
+						</Link>
+					</Box>
+				) : undefined}
 				<input
 					style={{ display: "none" }}
 					{...form.getInputProps("honeypot")}
@@ -27,6 +27,7 @@ const formSchema = z
 	.object({
 		honeypot: z.string().length(0).optional(),
 		username: z.string(),
+		email: z.string().email().optional(),
 		password: z
 			.string()
 			.min(8, "Password should be at least 8 characters long"),
@@ -56,6 +57,10 @@ export default function Page() {
 						RegisterErrorVariant.UsernameAlreadyExists,
 						() => "This username already exists",
 					)
+					.with(
+						RegisterErrorVariant.EmailRequired,
+						() => "An email is required to register",
+					)
 					.exhaustive();
 				notifications.show({
 					title: "Error with registration",
@@ -65,7 +70,9 @@ export default function Page() {
 			} else {
 				notifications.show({
 					title: "Success",
-					message: "Please login with your new credentials",
+					message: enabledFeatures.data?.emailVerificationRequired
+						? "Please verify your email using the link that was sent to it"
+						: "Please login with your new credentials",
 					color: "green",
 				});
 				router.push(APP_ROUTES.auth.login);
@@ -88,6 +95,7 @@ export default function Page() {
 					registerUser.mutate({
 						username: values.username,
 						password: values.password,
+						email: values.email || undefined,
 					});
 				})}
 				sx={(t) => ({
@@ -104,6 +112,13 @@ export default function Page() {
 					required
 					autoFocus
 				/>
+				<TextInput
+					label="Email"
+					type="email"
+					mt="md"
+					{...form.getInputProps("email")}
+					required={enabledFeatures.data?.emailVerificationRequired}
+				/>
 				<PasswordInput
 					label="Password"
 					mt="md"
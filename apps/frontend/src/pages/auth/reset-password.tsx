@@ -0,0 +1,101 @@
+import { APP_ROUTES } from "@/lib/constants";
+import { gqlClient } from "@/lib/services/api";
+import { Box, Button, PasswordInput } from "@mantine/core";
+import { useForm, zodResolver } from "@mantine/form";
+import { notifications } from "@mantine/notifications";
+import {
+	ResetUserPasswordDocument,
+	type ResetUserPasswordInput,
+} from "@ryot/generated/graphql/backend/graphql";
+import { useMutation } from "@tanstack/react-query";
+import Head from "next/head";
+import { useRouter } from "next/router";
+import { z } from "zod";
+
+const formSchema = z
+	.object({
+		password: z
+			.string()
+			.min(8, "Password should be at least 8 characters long"),
+		confirm: z.string(),
+	})
+	.refine((data) => data.password === data.confirm, {
+		message: "Passwords do not match",
+		path: ["confirm"],
+	});
+type FormSchema = z.infer<typeof formSchema>;
+
+export default function Page() {
+	const router = useRouter();
+	const token = router.query.token?.toString();
+	const resetPassword = useMutation({
+		mutationFn: async (input: ResetUserPasswordInput) => {
+			const { resetUserPassword } = await gqlClient.request(
+				ResetUserPasswordDocument,
+				{ input },
+			);
+			return resetUserPassword;
+		},
+		onSuccess() {
+			notifications.show({
+				title: "Success",
+				message: "Please login with your new password",
+				color: "green",
+			});
+			router.push(APP_ROUTES.auth.login);
+		},
+		onError() {
+			notifications.show({
+				title: "Error resetting password",
+				message: "This link is invalid or has expired",
+				color: "red",
+			});
+		},
+	});
+	const form = useForm<FormSchema>({ validate: zodResolver(formSchema) });
+
+	return (
+		<>
+			<Head>
+				<title>Reset Password | Ryot</title>
+			</Head>
+			<Box
+				component="form"
+				my={"auto"}
+				mx={"auto"}
+				onSubmit={form.onSubmit((values) => {
+					if (token) resetPassword.mutate({ token, password: values.password });
+				})}
+				sx={(t) => ({
+					width: "80%",
+					[t.fn.largerThan("sm")]: { width: "60%" },
+					[t.fn.largerThan("md")]: { width: "50%" },
+					[t.fn.largerThan("lg")]: { width: "40%" },
+					[t.fn.largerThan("xl")]: { width: "30%" },
+				})}
+			>
+				<PasswordInput
+					label="New password"
+					{...form.getInputProps("password")}
+					required
+					autoFocus
+				/>
+				<PasswordInput
+					label="Confirm password"
+					mt="md"
+					{...form.getInputProps("confirm")}
+					required
+				/>
+				<Button
+					mt="md"
+					type="submit"
+					disabled={!token}
+					loading={resetPassword.isLoading}
+					w="100%"
+				>
+					Reset password
+				</Button>
+			</Box>
+		</>
+	);
+}
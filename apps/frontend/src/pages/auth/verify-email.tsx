@@ -0,0 +1,50 @@
+import { APP_ROUTES } from "@/lib/constants";
+import { gqlClient } from "@/lib/services/api";
+import { Anchor, Box, Loader, Text } from "@mantine/core";
+import { VerifyUserEmailDocument } from "@ryot/generated/graphql/backend/graphql";
+import { useMutation } from "@tanstack/react-query";
+import Head from "next/head";
+import Link from "next/link";
+import { useRouter } from "next/router";
+import { useEffect } from "react";
+
+export default function Page() {
+	const router = useRouter();
+	const token = router.query.token?.toString();
+	const verifyEmail = useMutation({
+		mutationFn: async (token: string) => {
+			const { verifyUserEmail } = await gqlClient.request(
+				VerifyUserEmailDocument,
+				{ token },
+			);
+			return verifyUserEmail;
+		},
+	});
+
+	useEffect(() => {
+		if (token) verifyEmail.mutate(token);
+	}, [token]);
+
+	return (
+		<>
+			<Head>
+				<title>Verify Email | Ryot</title>
+			</Head>
+			<Box my={"auto"} mx={"auto"} style={{ textAlign: "center" }}>
+				{verifyEmail.isSuccess ? (
+					<Text>
+						Your email has been verified. You can now login{" "}
+						<Link href={APP_ROUTES.auth.login} passHref legacyBehavior>
+							<Anchor>here</Anchor>
+						</Link>
+						.
+					</Text>
+				) : verifyEmail.isError || (router.isReady && !token) ? (
+					<Text color="red">This link is invalid or has expired.</Text>
+				) : (
+					<Loader />
+				)}
+			</Box>
+		</>
+	);
+}
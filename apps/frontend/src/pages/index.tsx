@@ -16,6 +16,7 @@ import {
 	Container,
 	Divider,
 	Flex,
+	Group,
 	Paper,
 	RingProgress,
 	SimpleGrid,
@@ -24,12 +25,16 @@ import {
 	Title,
 	useMantineTheme,
 } from "@mantine/core";
+import { notifications } from "@mantine/notifications";
 import {
 	type CalendarEventPartFragment,
 	CollectionContentsDocument,
 	CollectionsDocument,
 	LatestUserSummaryDocument,
 	MetadataLot,
+	PendingSeenCompanionsDocument,
+	RespondToSeenCompanionDocument,
+	type RespondToSeenCompanionMutationVariables,
 	UserUpcomingCalendarEventsDocument,
 } from "@ryot/generated/graphql/backend/graphql";
 import { formatTimeAgo } from "@ryot/ts-utils";
@@ -39,8 +44,9 @@ import {
 	IconFriends,
 	IconPhotoPlus,
 	IconScaleOutline,
+	IconUsers,
 } from "@tabler/icons-react";
-import { useQuery } from "@tanstack/react-query";
+import { useMutation, useQuery } from "@tanstack/react-query";
 import humanFormat from "human-format";
 import {
 	HumanizeDuration,
@@ -203,6 +209,32 @@ const Page: NextPageWithLayout = () => {
 		},
 		{ retry: false },
 	);
+	const pendingSeenCompanions = useQuery(
+		["pendingSeenCompanions"],
+		async () => {
+			const { pendingSeenCompanions } = await gqlClient.request(
+				PendingSeenCompanionsDocument,
+			);
+			return pendingSeenCompanions;
+		},
+	);
+	const respondToSeenCompanion = useMutation({
+		mutationFn: async (variables: RespondToSeenCompanionMutationVariables) => {
+			const { respondToSeenCompanion } = await gqlClient.request(
+				RespondToSeenCompanionDocument,
+				variables,
+			);
+			return respondToSeenCompanion;
+		},
+		onSuccess: (_data, variables) => {
+			pendingSeenCompanions.refetch();
+			if (variables.accept)
+				notifications.show({
+					title: "Success",
+					message: "The record has been added to your history",
+				});
+		},
+	});
 
 	return userPreferences.data &&
 		latestUserSummary.data &&
@@ -235,6 +267,63 @@ const Page: NextPageWithLayout = () => {
 							</Text>
 						</Alert>
 					) : undefined}
+					{pendingSeenCompanions.data?.map((p) => (
+						<Alert
+							key={p.companionId}
+							icon={<IconUsers size="1rem" />}
+							variant="outline"
+						>
+							<Group position="apart">
+								<Text>
+									{p.taggedBy} says you saw{" "}
+									<Link
+										passHref
+										legacyBehavior
+										href={withQuery(
+											APP_ROUTES.media.individualMediaItem.details,
+											{ id: p.metadataId },
+										)}
+									>
+										<Anchor>{p.metadataTitle}</Anchor>
+									</Link>
+									{p.seen.showInformation
+										? ` (S${p.seen.showInformation.season}-E${p.seen.showInformation.episode})`
+										: undefined}
+									{p.seen.podcastInformation
+										? ` (EP-${p.seen.podcastInformation.episode})`
+										: undefined}{" "}
+									with them.
+								</Text>
+								<Group spacing="xs">
+									<Button
+										compact
+										variant="outline"
+										onClick={() =>
+											respondToSeenCompanion.mutate({
+												companionId: p.companionId,
+												accept: true,
+											})
+										}
+									>
+										Add to history
+									</Button>
+									<Button
+										compact
+										variant="outline"
+										color="red"
+										onClick={() =>
+											respondToSeenCompanion.mutate({
+												companionId: p.companionId,
+												accept: false,
+											})
+										}
+									>
+										Decline
+									</Button>
+								</Group>
+							</Group>
+						</Alert>
+					))}
 					{upcomingMedia.data.length > 0 ? (
 						<>
 							<Title>Upcoming</Title>
@@ -347,6 +436,51 @@ const Page: NextPageWithLayout = () => {
 								},
 							]}
 						/>
+						<DisplayStatForMediaType
+							lot={MetadataLot.Article}
+							data={[
+								{
+									label: "Articles",
+									value: latestUserSummary.data.media.articles.read,
+									type: "number",
+								},
+								{
+									label: "Runtime",
+									value: latestUserSummary.data.media.articles.runtime,
+									type: "duration",
+								},
+							]}
+						/>
+						<DisplayStatForMediaType
+							lot={MetadataLot.Music}
+							data={[
+								{
+									label: "Albums",
+									value: latestUserSummary.data.media.music.listened,
+									type: "number",
+								},
+								{
+									label: "Runtime",
+									value: latestUserSummary.data.media.music.runtime,
+									type: "duration",
+								},
+							]}
+						/>
+						<DisplayStatForMediaType
+							lot={MetadataLot.BoardGame}
+							data={[
+								{
+									label: "Games",
+									value: latestUserSummary.data.media.boardGames.played,
+									type: "number",
+								},
+								{
+									label: "Runtime",
+									value: latestUserSummary.data.media.boardGames.runtime,
+									type: "duration",
+								},
+							]}
+						/>
 						<DisplayStatForMediaType
 							lot={MetadataLot.AudioBook}
 							data={[
@@ -375,6 +509,11 @@ const Page: NextPageWithLayout = () => {
 									value: latestUserSummary.data.media.books.pages,
 									type: "number",
 								},
+								{
+									label: "Runtime",
+									value: latestUserSummary.data.media.books.runtime,
+									type: "duration",
+								},
 							]}
 						/>
 						<DisplayStatForMediaType
@@ -412,6 +551,21 @@ const Page: NextPageWithLayout = () => {
 								},
 							]}
 						/>
+						<DisplayStatForMediaType
+							lot={MetadataLot.Comic}
+							data={[
+								{
+									label: "Comics",
+									value: latestUserSummary.data.media.comics.read,
+									type: "number",
+								},
+								{
+									label: "Issues",
+									value: latestUserSummary.data.media.comics.issues,
+									type: "number",
+								},
+							]}
+						/>
 						<DisplayStatForMediaType
 							lot={MetadataLot.Anime}
 							data={[
@@ -443,6 +597,11 @@ const Page: NextPageWithLayout = () => {
 										value: latestUserSummary.data.media.creatorsInteractedWith,
 										type: "number",
 									},
+									{
+										label: "Favorites",
+										value: latestUserSummary.data.media.favorites,
+										type: "number",
+									},
 								]}
 							/>
 						) : undefined}
@@ -462,6 +621,22 @@ const Page: NextPageWithLayout = () => {
 										value: latestUserSummary.data.fitness.workoutsRecorded,
 										type: "number",
 									},
+									{
+										label: "Kg Lifted",
+										value: Number(latestUserSummary.data.fitness.totalVolume),
+										type: "number",
+									},
+									{
+										label: "Km Covered",
+										value: Number(latestUserSummary.data.fitness.totalDistance),
+										type: "number",
+									},
+									{
+										label: "Personal Bests",
+										value:
+											latestUserSummary.data.fitness.personalBestsAchieved,
+										type: "number",
+									},
 								]}
 							/>
 						) : undefined}
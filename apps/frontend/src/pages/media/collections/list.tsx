@@ -11,7 +11,9 @@ import {
 	Flex,
 	Input,
 	Modal,
+	MultiSelect,
 	SegmentedControl,
+	Select,
 	SimpleGrid,
 	Stack,
 	Text,
@@ -23,6 +25,7 @@ import { useForm, zodResolver } from "@mantine/form";
 import { useDisclosure } from "@mantine/hooks";
 import { notifications } from "@mantine/notifications";
 import {
+	CollectionActionLot,
 	CollectionsDocument,
 	CreateOrUpdateCollectionDocument,
 	type CreateOrUpdateCollectionMutationVariables,
@@ -44,6 +47,8 @@ const formSchema = z.object({
 	name: z.string(),
 	description: z.string().optional(),
 	visibility: z.nativeEnum(Visibility).default(Visibility.Private),
+	actions: z.array(z.nativeEnum(CollectionActionLot)).default([]),
+	alsoAddTo: z.string().optional(),
 });
 type FormSchema = z.infer<typeof formSchema>;
 
@@ -160,6 +165,16 @@ const Page: NextPageWithLayout = () => {
 												name: c?.name,
 												description: c?.description ?? undefined,
 												visibility: c?.visibility,
+												actions: c?.actions
+													.map((a) => a.lot)
+													.filter(
+														(a) => a !== CollectionActionLot.AddToCollection,
+													),
+												alsoAddTo:
+													c?.actions.find(
+														(a) =>
+															a.lot === CollectionActionLot.AddToCollection,
+													)?.collectionName ?? undefined,
 											});
 											open();
 										}}
@@ -193,9 +208,23 @@ const Page: NextPageWithLayout = () => {
 					>
 						<Box
 							component="form"
-							onSubmit={form.onSubmit((values) => {
+							onSubmit={form.onSubmit(({ alsoAddTo, actions, ...values }) => {
 								createOrUpdateCollection.mutate({
-									input: { ...values, updateId: toUpdateCollection },
+									input: {
+										...values,
+										updateId: toUpdateCollection,
+										actions: [
+											...(actions ?? []).map((lot) => ({ lot })),
+											...(alsoAddTo
+												? [
+														{
+															lot: CollectionActionLot.AddToCollection,
+															collectionName: alsoAddTo,
+														},
+												  ]
+												: []),
+										],
+									},
 								});
 								form.reset();
 								close();
@@ -231,6 +260,25 @@ const Page: NextPageWithLayout = () => {
 									label="Description"
 									{...form.getInputProps("description")}
 								/>
+								<MultiSelect
+									label="When an item is added"
+									data={[
+										{ label: "Monitor it", value: CollectionActionLot.Monitor },
+										{
+											label: "Remind me on release",
+											value: CollectionActionLot.RemindOnRelease,
+										},
+									]}
+									{...form.getInputProps("actions")}
+								/>
+								<Select
+									label="Also add it to"
+									clearable
+									data={collections.data
+										.map((c) => c.name)
+										.filter((c) => c !== form.values.name)}
+									{...form.getInputProps("alsoAddTo")}
+								/>
 								<Button variant="outline" type="submit">
 									{toUpdateCollection ? "Update" : "Create"}
 								</Button>
@@ -4,7 +4,11 @@ import {
 } from "@/lib/components/MediaComponents";
 import MediaDetailsLayout from "@/lib/components/MediaDetailsLayout";
 import { APP_ROUTES } from "@/lib/constants";
-import { useCoreDetails, useUserPreferences } from "@/lib/hooks/graphql";
+import {
+	useCoreDetails,
+	useEnabledCoreFeatures,
+	useUserPreferences,
+} from "@/lib/hooks/graphql";
 import LoadingPage from "@/lib/layouts/LoadingPage";
 import LoggedIn from "@/lib/layouts/LoggedIn";
 import { gqlClient } from "@/lib/services/api";
@@ -19,6 +23,7 @@ import {
 	Box,
 	Button,
 	Container,
+	FileButton,
 	Flex,
 	Group,
 	Image,
@@ -32,9 +37,11 @@ import {
 	SimpleGrid,
 	Slider,
 	Stack,
+	Switch,
 	Tabs,
 	Text,
 	TextInput,
+	Textarea,
 	Title,
 	TypographyStylesProvider,
 	useMantineTheme,
@@ -45,32 +52,54 @@ import { notifications } from "@mantine/notifications";
 import {
 	AddMediaToCollectionDocument,
 	type AddMediaToCollectionMutationVariables,
+	AddSeenAttachmentDocument,
+	AddSeenCompanionDocument,
+	type AddSeenCompanionMutationVariables,
 	CollectionsDocument,
 	CreateMediaReminderDocument,
+	CreateOrUpdateHighlightDocument,
+	type CreateOrUpdateHighlightMutationVariables,
 	type CreateMediaReminderMutationVariables,
+	DeleteHighlightDocument,
+	type DeleteHighlightMutationVariables,
 	DeleteMediaReminderDocument,
 	type DeleteMediaReminderMutationVariables,
+	DeleteSeenAttachmentDocument,
+	type DeleteSeenAttachmentMutationVariables,
+	DeleteSeenCompanionDocument,
+	type DeleteSeenCompanionMutationVariables,
 	DeleteSeenItemDocument,
 	type DeleteSeenItemMutationVariables,
 	DeployUpdateMetadataJobDocument,
 	type DeployUpdateMetadataJobMutationVariables,
+	HighlightsDocument,
+	KoreaderDocumentsDocument,
+	LinkKoreaderDocumentDocument,
+	type LinkKoreaderDocumentMutationVariables,
 	MediaDetailsDocument,
 	MergeMetadataDocument,
 	type MergeMetadataMutationVariables,
 	MetadataLot,
 	MetadataSource,
 	MetadataVideoSource,
+	PresignedPutUrlDocument,
 	ProgressUpdateDocument,
 	type ProgressUpdateMutationVariables,
 	RemoveMediaFromCollectionDocument,
 	type RemoveMediaFromCollectionMutationVariables,
 	SeenState,
+	ToggleMediaFavoriteDocument,
+	type ToggleMediaFavoriteMutationVariables,
 	ToggleMediaMonitorDocument,
 	type ToggleMediaMonitorMutationVariables,
 	UserMediaDetailsDocument,
 	UserReviewScale,
 } from "@ryot/generated/graphql/backend/graphql";
-import { changeCase, formatDateToNaiveDate } from "@ryot/ts-utils";
+import {
+	changeCase,
+	formatDateToNaiveDate,
+	formatTimeAgo,
+} from "@ryot/ts-utils";
 import {
 	IconAlertCircle,
 	IconBook,
@@ -78,13 +107,18 @@ import {
 	IconBulb,
 	IconClock,
 	IconDeviceTv,
+	IconEdit,
 	IconInfoCircle,
 	IconMessageCircle2,
+	IconNotebook,
+	IconPaperclip,
 	IconPercentage,
 	IconPlayerPlay,
+	IconQuote,
 	IconRotateClockwise,
 	IconStarFilled,
 	IconUser,
+	IconUsers,
 	IconVideo,
 	IconX,
 } from "@tabler/icons-react";
@@ -140,10 +174,13 @@ function ProgressModal(props: {
 			<IconBrandPagekit size="1.5rem" />,
 			"Chapters",
 		])
+		.with(MetadataLot.Comic, () => [<IconNotebook size="1.5rem" />, "Issues"])
 		.with(
 			MetadataLot.Movie,
 			MetadataLot.VisualNovel,
 			MetadataLot.AudioBook,
+			MetadataLot.Article,
+			MetadataLot.Music,
 			() => [<IconClock size="1.5rem" />, "Minutes"],
 		)
 		.otherwise(() => [null, null]);
@@ -293,6 +330,99 @@ function SelectCollectionModal(props: {
 	) : undefined;
 }
 
+function LinkKoreaderDocumentModal(props: {
+	opened: boolean;
+	onClose: () => void;
+	metadataId: number;
+	refetchUserMedia: () => void;
+}) {
+	const [selectedDocument, setSelectedDocument] = useState<string | null>(
+		null,
+	);
+
+	const koreaderDocuments = useQuery({
+		queryKey: ["koreaderDocuments"],
+		queryFn: async () => {
+			const { koreaderDocuments } = await gqlClient.request(
+				KoreaderDocumentsDocument,
+			);
+			return koreaderDocuments.filter(
+				(d) => d.metadataId !== props.metadataId,
+			);
+		},
+		enabled: props.opened,
+	});
+	const linkKoreaderDocument = useMutation({
+		mutationFn: async (variables: LinkKoreaderDocumentMutationVariables) => {
+			const { linkKoreaderDocument } = await gqlClient.request(
+				LinkKoreaderDocumentDocument,
+				variables,
+			);
+			return linkKoreaderDocument;
+		},
+		// biome-ignore lint/suspicious/noExplicitAny: required
+		onError: (e: any) => {
+			notifications.show({
+				title: "Error in operation",
+				message: e.response.errors[0].message,
+				color: "red",
+			});
+		},
+		onSuccess: () => {
+			props.refetchUserMedia();
+			props.onClose();
+		},
+	});
+
+	return (
+		<Modal
+			opened={props.opened}
+			onClose={props.onClose}
+			withCloseButton={false}
+			centered
+		>
+			<Stack>
+				<Title order={3}>Link KOReader document</Title>
+				{koreaderDocuments.data && koreaderDocuments.data.length > 0 ? (
+					<Select
+						withinPortal
+						data={koreaderDocuments.data.map((d) => ({
+							value: d.id.toString(),
+							label: `${d.device}: ${Math.round(
+								Number(d.percentage) * 100,
+							)}% (${formatTimeAgo(d.updatedOn)})`,
+							group: d.metadataId ? "Linked to another book" : "Not linked",
+						}))}
+						onChange={setSelectedDocument}
+					/>
+				) : (
+					<Text>
+						No documents have been synced yet. Configure the KOReader
+						integration and sync the progress of the book from your device.
+					</Text>
+				)}
+				<Button
+					data-autofocus
+					variant="outline"
+					disabled={!selectedDocument}
+					onClick={() => {
+						if (selectedDocument)
+							linkKoreaderDocument.mutate({
+								documentId: parseInt(selectedDocument),
+								metadataId: props.metadataId,
+							});
+					}}
+				>
+					Link
+				</Button>
+				<Button variant="outline" color="red" onClick={props.onClose}>
+					Cancel
+				</Button>
+			</Stack>
+		</Modal>
+	);
+}
+
 function CreateReminderModal(props: {
 	opened: boolean;
 	onClose: () => void;
@@ -372,6 +502,244 @@ function CreateReminderModal(props: {
 	);
 }
 
+function TagCompanionModal(props: {
+	opened: boolean;
+	onClose: () => void;
+	seenId?: number;
+	refetchUserMediaDetails: () => void;
+}) {
+	const [name, setName] = useState("");
+	const [onInstance, setOnInstance] = useState(false);
+
+	const addSeenCompanion = useMutation({
+		mutationFn: async (variables: AddSeenCompanionMutationVariables) => {
+			const { addSeenCompanion } = await gqlClient.request(
+				AddSeenCompanionDocument,
+				variables,
+			);
+			return addSeenCompanion;
+		},
+		onSuccess: () => {
+			props.refetchUserMediaDetails();
+			notifications.show({
+				title: "Tagged",
+				message: onInstance
+					? "They will be asked to confirm before it is added to their history"
+					: "Companion added to this record",
+			});
+			setName("");
+			props.onClose();
+		},
+		// biome-ignore lint/suspicious/noExplicitAny: required
+		onError: (e: any) => {
+			notifications.show({
+				title: "Error in operation",
+				message: e.response.errors[0].message,
+				color: "red",
+			});
+		},
+	});
+
+	return (
+		<Modal
+			opened={props.opened}
+			onClose={props.onClose}
+			withCloseButton={false}
+			centered
+		>
+			<Stack>
+				<Title order={3}>Seen with</Title>
+				<TextInput
+					label={onInstance ? "Username" : "Name"}
+					value={name}
+					onChange={(e) => setName(e.currentTarget.value)}
+					data-autofocus
+				/>
+				<Switch
+					label="They have an account on this instance"
+					checked={onInstance}
+					onChange={(e) => setOnInstance(e.currentTarget.checked)}
+				/>
+				<Button
+					variant="outline"
+					disabled={!props.seenId || !name.trim()}
+					loading={addSeenCompanion.isLoading}
+					onClick={() => {
+						if (!props.seenId) return;
+						addSeenCompanion.mutate({
+							input: {
+								seenId: props.seenId,
+								...(onInstance
+									? { username: name.trim() }
+									: { name: name.trim() }),
+							},
+						});
+					}}
+				>
+					Tag
+				</Button>
+			</Stack>
+		</Modal>
+	);
+}
+
+function HighlightsPanel(props: { metadataId: number }) {
+	const [highlightId, setHighlightId] = useState<number>();
+	const [text, setText] = useState("");
+	const [page, setPage] = useState<number | "">("");
+	const [chapter, setChapter] = useState("");
+	const [note, setNote] = useState("");
+
+	const highlights = useQuery({
+		queryKey: ["highlights", props.metadataId],
+		queryFn: async () => {
+			const { highlights } = await gqlClient.request(HighlightsDocument, {
+				metadataId: props.metadataId,
+			});
+			return highlights;
+		},
+	});
+
+	const resetForm = () => {
+		setHighlightId(undefined);
+		setText("");
+		setPage("");
+		setChapter("");
+		setNote("");
+	};
+
+	const createOrUpdateHighlight = useMutation({
+		mutationFn: async (variables: CreateOrUpdateHighlightMutationVariables) => {
+			const { createOrUpdateHighlight } = await gqlClient.request(
+				CreateOrUpdateHighlightDocument,
+				variables,
+			);
+			return createOrUpdateHighlight;
+		},
+		onSuccess: () => {
+			resetForm();
+			highlights.refetch();
+		},
+		// biome-ignore lint/suspicious/noExplicitAny: required
+		onError: (e: any) => {
+			notifications.show({
+				title: "Error in operation",
+				message: e.response.errors[0].message,
+				color: "red",
+			});
+		},
+	});
+	const deleteHighlight = useMutation({
+		mutationFn: async (variables: DeleteHighlightMutationVariables) => {
+			const { deleteHighlight } = await gqlClient.request(
+				DeleteHighlightDocument,
+				variables,
+			);
+			return deleteHighlight;
+		},
+		onSuccess: () => {
+			highlights.refetch();
+		},
+	});
+
+	return (
+		<Stack>
+			<Textarea
+				label="Passage"
+				autosize
+				minRows={2}
+				value={text}
+				onChange={(e) => setText(e.currentTarget.value)}
+			/>
+			<Group grow>
+				<NumberInput label="Page" min={1} value={page} onChange={setPage} />
+				<TextInput
+					label="Chapter"
+					value={chapter}
+					onChange={(e) => setChapter(e.currentTarget.value)}
+				/>
+			</Group>
+			<Textarea
+				label="Note"
+				autosize
+				value={note}
+				onChange={(e) => setNote(e.currentTarget.value)}
+			/>
+			<Group>
+				<Button
+					variant="outline"
+					disabled={!text.trim()}
+					loading={createOrUpdateHighlight.isLoading}
+					onClick={() => {
+						createOrUpdateHighlight.mutate({
+							input: {
+								highlightId,
+								metadataId: props.metadataId,
+								text,
+								page: page === "" ? null : page,
+								chapter: chapter || null,
+								note: note || null,
+							},
+						});
+					}}
+				>
+					{highlightId ? "Update" : "Add"} highlight
+				</Button>
+				{highlightId ? (
+					<Button variant="outline" color="red" onClick={resetForm}>
+						Cancel
+					</Button>
+				) : undefined}
+			</Group>
+			{highlights.data?.map((h) => (
+				<Paper key={h.id} withBorder p="xs">
+					<Flex justify="space-between" gap="xs">
+						<Text style={{ whiteSpace: "pre-wrap" }} fs="italic">
+							{h.text}
+						</Text>
+						<Flex gap={4}>
+							<ActionIcon
+								onClick={() => {
+									setHighlightId(h.id);
+									setText(h.text);
+									setPage(h.page ?? "");
+									setChapter(h.chapter ?? "");
+									setNote(h.note ?? "");
+								}}
+							>
+								<IconEdit size="1rem" />
+							</ActionIcon>
+							<ActionIcon
+								color="red"
+								onClick={() => {
+									const yes = confirm(
+										"Are you sure you want to delete this highlight?",
+									);
+									if (yes) deleteHighlight.mutate({ highlightId: h.id });
+								}}
+							>
+								<IconX size="1rem" />
+							</ActionIcon>
+						</Flex>
+					</Flex>
+					{h.page || h.chapter ? (
+						<Text size="sm" color="dimmed">
+							{[h.page ? `Page ${h.page}` : undefined, h.chapter]
+								.filter(Boolean)
+								.join(", ")}
+						</Text>
+					) : undefined}
+					{h.note ? (
+						<Text size="sm" mt="xs" style={{ whiteSpace: "pre-wrap" }}>
+							{h.note}
+						</Text>
+					) : undefined}
+				</Paper>
+			))}
+		</Stack>
+	);
+}
+
 const AccordionLabel = ({
 	name,
 	id,
@@ -444,6 +812,7 @@ const Page: NextPageWithLayout = () => {
 	const colors = Object.keys(theme.colors);
 	const coreDetails = useCoreDetails();
 	const preferences = useUserPreferences();
+	const enabledFeatures = useEnabledCoreFeatures();
 
 	const [
 		progressModalOpened,
@@ -460,6 +829,14 @@ const Page: NextPageWithLayout = () => {
 			close: createMediaReminderModalClose,
 		},
 	] = useDisclosure(false);
+	const [
+		linkKoreaderDocumentModalOpened,
+		{
+			open: linkKoreaderDocumentModalOpen,
+			close: linkKoreaderDocumentModalClose,
+		},
+	] = useDisclosure(false);
+	const [tagCompanionSeenId, setTagCompanionSeenId] = useState<number>();
 	const [activeTab, setActiveTab] = useLocalStorage({
 		key: "savedActiveItemDetailsTab",
 		getInitialValueInEffect: true,
@@ -483,6 +860,8 @@ const Page: NextPageWithLayout = () => {
 				![MetadataLot.Show, MetadataLot.Podcast].includes(data.lot)
 			)
 				setActiveTab("overview");
+			if (activeTab === "highlights" && data.lot !== MetadataLot.Book)
+				setActiveTab("overview");
 		},
 	});
 	const userMediaDetails = useQuery({
@@ -520,6 +899,18 @@ const Page: NextPageWithLayout = () => {
 			userMediaDetails.refetch();
 		},
 	});
+	const toggleMediaFavorite = useMutation({
+		mutationFn: async (variables: ToggleMediaFavoriteMutationVariables) => {
+			const { toggleMediaFavorite } = await gqlClient.request(
+				ToggleMediaFavoriteDocument,
+				variables,
+			);
+			return toggleMediaFavorite;
+		},
+		onSuccess: () => {
+			userMediaDetails.refetch();
+		},
+	});
 	const toggleMediaMonitor = useMutation({
 		mutationFn: async (variables: ToggleMediaMonitorMutationVariables) => {
 			const { toggleMediaMonitor } = await gqlClient.request(
@@ -548,6 +939,53 @@ const Page: NextPageWithLayout = () => {
 			});
 		},
 	});
+	const deleteSeenAttachment = useMutation({
+		mutationFn: async (variables: DeleteSeenAttachmentMutationVariables) => {
+			const { deleteSeenAttachment } = await gqlClient.request(
+				DeleteSeenAttachmentDocument,
+				variables,
+			);
+			return deleteSeenAttachment;
+		},
+		onSuccess: () => {
+			userMediaDetails.refetch();
+		},
+	});
+	const deleteSeenCompanion = useMutation({
+		mutationFn: async (variables: DeleteSeenCompanionMutationVariables) => {
+			const { deleteSeenCompanion } = await gqlClient.request(
+				DeleteSeenCompanionDocument,
+				variables,
+			);
+			return deleteSeenCompanion;
+		},
+		onSuccess: () => {
+			userMediaDetails.refetch();
+		},
+	});
+
+	const uploadSeenAttachments = async (seenId: number, files: File[]) => {
+		for (const file of files) {
+			const uploadUrl = await gqlClient.request(PresignedPutUrlDocument, {
+				fileName: file.name,
+			});
+			await fetch(uploadUrl.presignedPutUrl.uploadUrl, {
+				method: "PUT",
+				body: file,
+				headers: { "Content-Type": file.type },
+			});
+			await gqlClient.request(AddSeenAttachmentDocument, {
+				input: { seenId, key: uploadUrl.presignedPutUrl.key },
+			});
+		}
+		if (files.length > 0) {
+			notifications.show({
+				title: "Success",
+				message: `Attached ${files.length} files`,
+			});
+			userMediaDetails.refetch();
+		}
+	};
 	const deployUpdateMetadataJob = useMutation({
 		mutationFn: async (variables: DeployUpdateMetadataJobMutationVariables) => {
 			const { deployUpdateMetadataJob } = await gqlClient.request(
@@ -754,6 +1192,13 @@ const Page: NextPageWithLayout = () => {
 								• {mediaDetails.data.mangaSpecifics.volumes} volumes
 							</Text>
 						) : undefined}
+						{mediaDetails.data.videoGameSpecifics?.achievements ? (
+							<Text color="dimmed">
+								{" "}
+								• {mediaDetails.data.videoGameSpecifics.achievements}{" "}
+								achievements
+							</Text>
+						) : undefined}
 						{mediaDetails.data.movieSpecifics?.runtime ? (
 							<Text color="dimmed">
 								{" "}
@@ -763,6 +1208,60 @@ const Page: NextPageWithLayout = () => {
 								)}
 							</Text>
 						) : undefined}
+						{mediaDetails.data.articleSpecifics?.siteName ? (
+							<Text color="dimmed">
+								{" "}
+								• {mediaDetails.data.articleSpecifics.siteName}
+							</Text>
+						) : undefined}
+						{mediaDetails.data.articleSpecifics?.readingTime ? (
+							<Text color="dimmed">
+								{" "}
+								• {mediaDetails.data.articleSpecifics.readingTime} min read
+							</Text>
+						) : undefined}
+						{mediaDetails.data.musicSpecifics?.tracks ? (
+							<Text color="dimmed">
+								{" "}
+								• {mediaDetails.data.musicSpecifics.tracks} tracks
+							</Text>
+						) : undefined}
+						{mediaDetails.data.musicSpecifics?.duration ? (
+							<Text color="dimmed">
+								{" "}
+								•{" "}
+								{humanizer.humanize(
+									mediaDetails.data.musicSpecifics.duration * 1000 * 60,
+								)}
+							</Text>
+						) : undefined}
+						{mediaDetails.data.comicSpecifics?.issues ? (
+							<Text color="dimmed">
+								{" "}
+								• {mediaDetails.data.comicSpecifics.issues} issues
+							</Text>
+						) : undefined}
+						{mediaDetails.data.boardGameSpecifics?.maxPlayers ? (
+							<Text color="dimmed">
+								{" "}
+								•{" "}
+								{mediaDetails.data.boardGameSpecifics.minPlayers &&
+								mediaDetails.data.boardGameSpecifics.minPlayers !==
+									mediaDetails.data.boardGameSpecifics.maxPlayers
+									? `${mediaDetails.data.boardGameSpecifics.minPlayers}-`
+									: ""}
+								{mediaDetails.data.boardGameSpecifics.maxPlayers} players
+							</Text>
+						) : undefined}
+						{mediaDetails.data.boardGameSpecifics?.playtime ? (
+							<Text color="dimmed">
+								{" "}
+								•{" "}
+								{humanizer.humanize(
+									mediaDetails.data.boardGameSpecifics.playtime * 1000 * 60,
+								)}
+							</Text>
+						) : undefined}
 						{mediaDetails.data.showSpecifics ? (
 							<Text color="dimmed">
 								{" "}
@@ -821,7 +1320,16 @@ const Page: NextPageWithLayout = () => {
 											.with(MetadataSource.Openlibrary, () => "openlibrary.svg")
 											.with(MetadataSource.Tmdb, () => "tmdb.svg")
 											.with(MetadataSource.Vndb, () => "vndb.ico")
-											.with(MetadataSource.Custom, () => undefined)
+											.with(
+												MetadataSource.BoardGameGeek,
+												MetadataSource.ComicVine,
+												MetadataSource.Custom,
+												MetadataSource.MangaDex,
+												MetadataSource.MusicBrainz,
+												MetadataSource.PodcastIndex,
+												MetadataSource.Web,
+												() => undefined,
+											)
 											.exhaustive()}`}
 									/>
 
@@ -842,14 +1350,20 @@ const Page: NextPageWithLayout = () => {
 												() => "/5",
 											)
 											.with(
+												MetadataSource.BoardGameGeek,
 												MetadataSource.Mal,
+												MetadataSource.MangaDex,
 												MetadataSource.MangaUpdates,
 												() => "/10",
 											)
 											.with(
+												MetadataSource.ComicVine,
 												MetadataSource.Custom,
 												MetadataSource.Itunes,
+												MetadataSource.MusicBrainz,
 												MetadataSource.Openlibrary,
+												MetadataSource.PodcastIndex,
+												MetadataSource.Web,
 												() => undefined,
 											)
 											.exhaustive()}
@@ -943,6 +1457,11 @@ const Page: NextPageWithLayout = () => {
 									Reviews
 								</Tabs.Tab>
 							) : undefined}
+							{mediaDetails.data.lot === MetadataLot.Book ? (
+								<Tabs.Tab value="highlights" icon={<IconQuote size="1rem" />}>
+									Highlights
+								</Tabs.Tab>
+							) : undefined}
 							{mediaDetails.data.suggestions.length > 0 ? (
 								<Tabs.Tab value="suggestions" icon={<IconBulb size="1rem" />}>
 									Suggestions
@@ -1048,8 +1567,11 @@ const Page: NextPageWithLayout = () => {
 												mediaDetails.data.bookSpecifics?.pages ||
 												mediaDetails.data.movieSpecifics?.runtime ||
 												mediaDetails.data.mangaSpecifics?.chapters ||
+												mediaDetails.data.comicSpecifics?.issues ||
 												mediaDetails.data.animeSpecifics?.episodes ||
-												mediaDetails.data.visualNovelSpecifics?.length
+												mediaDetails.data.visualNovelSpecifics?.length ||
+												mediaDetails.data.articleSpecifics?.readingTime ||
+												mediaDetails.data.musicSpecifics?.duration
 											}
 										/>
 									) : undefined}
@@ -1221,6 +1743,15 @@ const Page: NextPageWithLayout = () => {
 											refetchUserMedia={userMediaDetails.refetch}
 										/>
 									</>
+									<Button
+										variant="outline"
+										onClick={() => {
+											toggleMediaFavorite.mutate({ metadataId });
+										}}
+									>
+										{userMediaDetails.data.isFavorite ? "Remove from" : "Add to"}{" "}
+										favorites
+									</Button>
 									<Button
 										variant="outline"
 										onClick={() => {
@@ -1258,6 +1789,22 @@ const Page: NextPageWithLayout = () => {
 											</Button>
 										</>
 									)}
+									{mediaDetails.data.lot === MetadataLot.Book ? (
+										<>
+											<LinkKoreaderDocumentModal
+												onClose={linkKoreaderDocumentModalClose}
+												opened={linkKoreaderDocumentModalOpened}
+												metadataId={metadataId}
+												refetchUserMedia={userMediaDetails.refetch}
+											/>
+											<Button
+												variant="outline"
+												onClick={linkKoreaderDocumentModalOpen}
+											>
+												Link KOReader document
+											</Button>
+										</>
+									) : undefined}
 									<Button
 										variant="outline"
 										onClick={() => {
@@ -1295,12 +1842,26 @@ const Page: NextPageWithLayout = () => {
 						<Tabs.Panel value="history">
 							<MediaScrollArea>
 								<Stack>
+									<TagCompanionModal
+										opened={tagCompanionSeenId !== undefined}
+										onClose={() => setTagCompanionSeenId(undefined)}
+										seenId={tagCompanionSeenId}
+										refetchUserMediaDetails={userMediaDetails.refetch}
+									/>
 									<Text>
 										Seen by all users {userMediaDetails.data.seenBy} time
 										{userMediaDetails.data.seenBy > 1 ? "s" : ""} and{" "}
 										{userMediaDetails.data.history.length} time
 										{userMediaDetails.data.history.length > 1 ? "s" : ""} by you
 									</Text>
+									{userMediaDetails.data.playtime ? (
+										<Text>
+											Played for{" "}
+											{humanizer.humanize(
+												userMediaDetails.data.playtime * 1000 * 60,
+											)}
+										</Text>
+									) : undefined}
 									{userMediaDetails.data.history.map((h) => (
 										<Flex
 											key={h.id}
@@ -1325,6 +1886,12 @@ const Page: NextPageWithLayout = () => {
 														EP-{h.podcastInformation.episode}
 													</Text>
 												) : undefined}
+												{h.rating ? (
+													<Text color="dimmed">Rated {Number(h.rating)}</Text>
+												) : undefined}
+												{h.context ? (
+													<Text color="dimmed">{h.context}</Text>
+												) : undefined}
 											</Flex>
 											<Flex ml="sm" direction={"column"} gap={4}>
 												<Flex gap="xl">
@@ -1367,7 +1934,106 @@ const Page: NextPageWithLayout = () => {
 													>
 														Delete
 													</Button>
+													{enabledFeatures.data?.fileStorage ? (
+														<FileButton
+															multiple
+															accept="image/png,image/jpeg,image/jpg"
+															onChange={(f) => uploadSeenAttachments(h.id, f)}
+														>
+															{(props) => (
+																<Button
+																	{...props}
+																	variant="outline"
+																	leftIcon={<IconPaperclip size="1.2rem" />}
+																	compact
+																>
+																	Attach
+																</Button>
+															)}
+														</FileButton>
+													) : undefined}
+													<Button
+														variant="outline"
+														leftIcon={<IconUsers size="1.2rem" />}
+														compact
+														onClick={() => setTagCompanionSeenId(h.id)}
+													>
+														Tag
+													</Button>
 												</Flex>
+												{h.companions.length > 0 ? (
+													<Flex gap="xs" wrap="wrap" align="center">
+														<Text size="sm">With:</Text>
+														{h.companions.map((c) => (
+															<Badge
+																key={c.id}
+																variant="outline"
+																color={
+																	c.userId && !c.mirroredSeenId
+																		? "yellow"
+																		: undefined
+																}
+																title={
+																	c.userId && !c.mirroredSeenId
+																		? "Waiting for them to confirm"
+																		: undefined
+																}
+																rightSection={
+																	<IconX
+																		size="0.7rem"
+																		style={{ cursor: "pointer" }}
+																		onClick={() => {
+																			const yes = confirm(
+																				`Are you sure you want to remove ${c.name} from this record?`,
+																			);
+																			if (yes)
+																				deleteSeenCompanion.mutate({
+																					companionId: c.id,
+																				});
+																		}}
+																	/>
+																}
+															>
+																{c.name}
+															</Badge>
+														))}
+													</Flex>
+												) : undefined}
+												{h.attachments.length > 0 ? (
+													<Flex gap="xs" wrap="wrap">
+														{h.attachments.map((a) => (
+															<Indicator
+																key={a.id}
+																size={16}
+																color="red"
+																label={
+																	<IconX
+																		size="0.7rem"
+																		style={{ cursor: "pointer" }}
+																		onClick={() => {
+																			const yes = confirm(
+																				"Are you sure you want to remove this attachment?",
+																			);
+																			if (yes)
+																				deleteSeenAttachment.mutate({
+																					attachmentId: a.id,
+																				});
+																		}}
+																	/>
+																}
+															>
+																<Anchor href={a.url} target="_blank">
+																	<Image
+																		src={a.url}
+																		height={80}
+																		width={80}
+																		radius="sm"
+																	/>
+																</Anchor>
+															</Indicator>
+														))}
+													</Flex>
+												) : undefined}
 											</Flex>
 										</Flex>
 									))}
@@ -1541,6 +2207,13 @@ const Page: NextPageWithLayout = () => {
 								</MediaScrollArea>
 							</Tabs.Panel>
 						) : undefined}
+						{mediaDetails.data.lot === MetadataLot.Book ? (
+							<Tabs.Panel value="highlights">
+								<MediaScrollArea>
+									<HighlightsPanel metadataId={metadataId} />
+								</MediaScrollArea>
+							</Tabs.Panel>
+						) : undefined}
 						<Tabs.Panel value="suggestions">
 							<MediaScrollArea>
 								<SimpleGrid
@@ -1,4 +1,5 @@
 import { APP_ROUTES } from "@/lib/constants";
+import { useUserPreferences } from "@/lib/hooks/graphql";
 import LoadingPage from "@/lib/layouts/LoadingPage";
 import LoggedIn from "@/lib/layouts/LoggedIn";
 import { gqlClient } from "@/lib/services/api";
@@ -24,6 +25,7 @@ import {
 	ProgressUpdateDocument,
 	type ProgressUpdateMutationVariables,
 } from "@ryot/generated/graphql/backend/graphql";
+import { camelCase } from "@ryot/ts-utils";
 import { IconAlertCircle } from "@tabler/icons-react";
 import { useMutation, useQuery } from "@tanstack/react-query";
 import { DateTime } from "luxon";
@@ -53,6 +55,8 @@ const Page: NextPageWithLayout = () => {
 		);
 	const [selectedDate, setSelectedDate] = useState<Date | null>(null);
 	const [allSeasonsBefore, setAllSeasonsBefore] = useState(false);
+	const [selectedContext, setSelectedContext] = useState<string | null>(null);
+	const preferences = useUserPreferences();
 
 	const mediaDetails = useQuery({
 		queryKey: ["details", metadataId],
@@ -145,6 +149,14 @@ const Page: NextPageWithLayout = () => {
 	});
 
 	const title = mediaDetails.data?.title;
+	const contexts =
+		mediaDetails.data && preferences.data
+			? preferences.data.seenContexts[
+					camelCase(
+						mediaDetails.data.lot,
+					) as keyof typeof preferences.data.seenContexts
+			  ]
+			: [];
 
 	const mutationInput = {
 		metadataId: metadataId || 0,
@@ -152,6 +164,7 @@ const Page: NextPageWithLayout = () => {
 		showEpisodeNumber: Number(selectedShowEpisodeNumber),
 		showSeasonNumber: Number(selectedShowSeasonNumber),
 		podcastEpisodeNumber: Number(selectedPodcastEpisodeNumber),
+		context: selectedContext,
 	};
 
 	return mediaDetails.data && title ? (
@@ -242,6 +255,17 @@ const Page: NextPageWithLayout = () => {
 							</>
 						)
 					) : undefined}
+					{contexts.length > 0 ? (
+						<Select
+							label={`Where did you ${getVerb(
+								Verb.Read,
+								mediaDetails.data.lot,
+							)} it?`}
+							data={contexts}
+							onChange={setSelectedContext}
+							clearable
+						/>
+					) : undefined}
 					<Title order={6}>
 						When did you {getVerb(Verb.Read, mediaDetails.data.lot)} it?
 					</Title>
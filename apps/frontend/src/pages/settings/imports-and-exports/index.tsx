@@ -8,11 +8,13 @@ import {
 	Anchor,
 	Box,
 	Button,
+	Checkbox,
 	Container,
 	CopyButton,
 	FileInput,
 	Flex,
 	Group,
+	NumberInput,
 	PasswordInput,
 	Progress,
 	Select,
@@ -29,8 +31,11 @@ import {
 	type DeployImportJobMutationVariables,
 	GenerateAuthTokenDocument,
 	type GenerateAuthTokenMutationVariables,
+	GenericCsvIdentifierType,
+	ImportConflictPolicy,
 	ImportLot,
 	ImportSource,
+	MetadataLot,
 } from "@ryot/generated/graphql/backend/graphql";
 import { changeCase } from "@ryot/ts-utils";
 import { IconCheck, IconCopy } from "@tabler/icons-react";
@@ -58,6 +63,7 @@ type MediaTrackerImportFormSchema = z.infer<
 
 const traktImportFormSchema = z.object({
 	username: z.string(),
+	accessToken: z.string().optional(),
 });
 type TraktImportFormSchema = z.infer<typeof traktImportFormSchema>;
 
@@ -66,6 +72,13 @@ const goodreadsImportFormSchema = z.object({
 });
 type GoodreadsImportFormSchema = z.infer<typeof goodreadsImportFormSchema>;
 
+const goodreadsCsvImportFormSchema = z.object({
+	export: z.any(),
+});
+type GoodreadsCsvImportFormSchema = z.infer<
+	typeof goodreadsCsvImportFormSchema
+>;
+
 const movaryImportFormSchema = z.object({
 	ratings: z.any(),
 	history: z.any(),
@@ -78,6 +91,16 @@ const storyGraphImportFormSchema = z.object({
 });
 type StoryGraphImportFormSchema = z.infer<typeof storyGraphImportFormSchema>;
 
+const kindleImportFormSchema = z.object({
+	clippings: z.any(),
+});
+type KindleImportFormSchema = z.infer<typeof kindleImportFormSchema>;
+
+const consoleImportFormSchema = z.object({
+	export: z.any(),
+});
+type ConsoleImportFormSchema = z.infer<typeof consoleImportFormSchema>;
+
 const mediaJsonImportFormSchema = z.object({
 	export: z.any(),
 });
@@ -89,6 +112,44 @@ const malImportFormSchema = z.object({
 });
 type MalImportFormSchema = z.infer<typeof malImportFormSchema>;
 
+const letterboxdImportFormSchema = z.object({
+	exportPath: z.string(),
+});
+type LetterboxdImportFormSchema = z.infer<typeof letterboxdImportFormSchema>;
+
+const tvTimeImportFormSchema = z.object({
+	exportPath: z.string(),
+});
+type TvTimeImportFormSchema = z.infer<typeof tvTimeImportFormSchema>;
+
+const genericCsvImportFormSchema = z.object({
+	csvPath: z.string(),
+	lot: z.nativeEnum(MetadataLot),
+	titleColumn: z.string(),
+	identifierColumn: z.string().optional(),
+	identifierType: z.nativeEnum(GenericCsvIdentifierType).optional(),
+	dateColumn: z.string().optional(),
+	dateFormat: z.string().optional(),
+	ratingColumn: z.string().optional(),
+	ratingScale: z.number().optional(),
+});
+type GenericCsvImportFormSchema = z.infer<typeof genericCsvImportFormSchema>;
+
+const garminConnectImportFormSchema = z.object({
+	activitiesPath: z.string(),
+	bioMetricsPath: z.string().optional(),
+});
+type GarminConnectImportFormSchema = z.infer<
+	typeof garminConnectImportFormSchema
+>;
+
+const ryotImportFormSchema = z.object({
+	url: z.string().url(),
+	username: z.string(),
+	password: z.string(),
+});
+type RyotImportFormSchema = z.infer<typeof ryotImportFormSchema>;
+
 export const ImportSourceElement = (props: {
 	children: JSX.Element | JSX.Element[];
 }) => {
@@ -111,6 +172,10 @@ export const ImportSourceElement = (props: {
 
 const Page: NextPageWithLayout = () => {
 	const [deployImportSource, setDeployImportSource] = useState<ImportSource>();
+	const [conflictPolicy, setConflictPolicy] = useState(
+		ImportConflictPolicy.Append,
+	);
+	const [dryRun, setDryRun] = useState(false);
 	const [progress, setProgress] = useState<number | null>(null);
 
 	const mediaTrackerImportForm = useForm<MediaTrackerImportFormSchema>({
@@ -119,6 +184,9 @@ const Page: NextPageWithLayout = () => {
 	const goodreadsImportForm = useForm<GoodreadsImportFormSchema>({
 		validate: zodResolver(goodreadsImportFormSchema),
 	});
+	const goodreadsCsvImportForm = useForm<GoodreadsCsvImportFormSchema>({
+		validate: zodResolver(goodreadsCsvImportFormSchema),
+	});
 	const traktImportForm = useForm<TraktImportFormSchema>({
 		validate: zodResolver(traktImportFormSchema),
 	});
@@ -128,12 +196,33 @@ const Page: NextPageWithLayout = () => {
 	const storyGraphImportForm = useForm<StoryGraphImportFormSchema>({
 		validate: zodResolver(storyGraphImportFormSchema),
 	});
+	const kindleImportForm = useForm<KindleImportFormSchema>({
+		validate: zodResolver(kindleImportFormSchema),
+	});
+	const consoleImportForm = useForm<ConsoleImportFormSchema>({
+		validate: zodResolver(consoleImportFormSchema),
+	});
 	const mediaJsonImportForm = useForm<MediaJsonImportFormSchema>({
 		validate: zodResolver(mediaJsonImportFormSchema),
 	});
 	const malImportForm = useForm<MalImportFormSchema>({
 		validate: zodResolver(malImportFormSchema),
 	});
+	const letterboxdImportForm = useForm<LetterboxdImportFormSchema>({
+		validate: zodResolver(letterboxdImportFormSchema),
+	});
+	const tvTimeImportForm = useForm<TvTimeImportFormSchema>({
+		validate: zodResolver(tvTimeImportFormSchema),
+	});
+	const genericCsvImportForm = useForm<GenericCsvImportFormSchema>({
+		validate: zodResolver(genericCsvImportFormSchema),
+	});
+	const garminConnectImportForm = useForm<GarminConnectImportFormSchema>({
+		validate: zodResolver(garminConnectImportFormSchema),
+	});
+	const ryotImportForm = useForm<RyotImportFormSchema>({
+		validate: zodResolver(ryotImportFormSchema),
+	});
 
 	const deployImportJob = useMutation({
 		mutationFn: async (variables: DeployImportJobMutationVariables) => {
@@ -204,6 +293,13 @@ const Page: NextPageWithLayout = () => {
 										.with(ImportSource.Goodreads, () => ({
 											goodreads: goodreadsImportForm.values,
 										}))
+										.with(ImportSource.GoodreadsCsv, async () => ({
+											goodreadsCsv: {
+												export: await fileToText(
+													goodreadsCsvImportForm.values.export,
+												),
+											},
+										}))
 										.with(ImportSource.Trakt, () => ({
 											trakt: traktImportForm.values,
 										}))
@@ -230,6 +326,27 @@ const Page: NextPageWithLayout = () => {
 												),
 											},
 										}))
+										.with(ImportSource.Kindle, async () => ({
+											kindle: {
+												clippings: await fileToText(
+													kindleImportForm.values.clippings,
+												),
+											},
+										}))
+										.with(ImportSource.Playstation, async () => ({
+											playstation: {
+												export: await fileToText(
+													consoleImportForm.values.export,
+												),
+											},
+										}))
+										.with(ImportSource.Xbox, async () => ({
+											xbox: {
+												export: await fileToText(
+													consoleImportForm.values.export,
+												),
+											},
+										}))
 										.with(ImportSource.MediaJson, async () => ({
 											mediaJson: {
 												export: await fileToText(
@@ -243,15 +360,44 @@ const Page: NextPageWithLayout = () => {
 												mangaPath: malImportForm.values.mangaPath,
 											},
 										}))
+										.with(ImportSource.Letterboxd, () => ({
+											letterboxd: letterboxdImportForm.values,
+										}))
+										.with(ImportSource.TvTime, () => ({
+											tvTime: tvTimeImportForm.values,
+										}))
+										.with(ImportSource.GenericCsv, () => ({
+											genericCsv: genericCsvImportForm.values,
+										}))
+										.with(ImportSource.GarminConnect, () => ({
+											garminConnect: garminConnectImportForm.values,
+										}))
+										.with(ImportSource.Ryot, () => ({
+											ryot: ryotImportForm.values,
+										}))
 										.exhaustive();
 									if (values) {
 										deployImportJob.mutate({
 											input: {
-												lot: ImportLot.Media,
+												lot:
+													deployImportSource === ImportSource.GarminConnect
+														? ImportLot.Exercise
+														: ImportLot.Media,
 												source: deployImportSource,
+												conflictPolicy,
+												dryRun,
 												...values,
 											},
 										});
+										// the measurements are imported in a separate job
+										if (deployImportSource === ImportSource.Ryot && !dryRun)
+											deployImportJob.mutate({
+												input: {
+													lot: ImportLot.Exercise,
+													source: deployImportSource,
+													...values,
+												},
+											});
 									}
 								}
 							}
@@ -359,6 +505,24 @@ const Page: NextPageWithLayout = () => {
 										if (v) setDeployImportSource(v as ImportSource);
 									}}
 								/>
+								<Select
+									label="Existing data"
+									description="What to do with media that already has seen history, reviews or highlights"
+									data={Object.values(ImportConflictPolicy).map((cp) => ({
+										label: changeCase(cp),
+										value: cp,
+									}))}
+									value={conflictPolicy}
+									onChange={(v) => {
+										if (v) setConflictPolicy(v as ImportConflictPolicy);
+									}}
+								/>
+								<Checkbox
+									label="Dry run"
+									description="Only find the media and show what would be imported in the report"
+									checked={dryRun}
+									onChange={(e) => setDryRun(e.currentTarget.checked)}
+								/>
 								{deployImportSource ? (
 									<ImportSourceElement>
 										{match(deployImportSource)
@@ -386,6 +550,16 @@ const Page: NextPageWithLayout = () => {
 													/>
 												</>
 											))
+											.with(ImportSource.GoodreadsCsv, () => (
+												<>
+													<FileInput
+														label="CSV export file"
+														accept=".csv"
+														required
+														{...goodreadsCsvImportForm.getInputProps("export")}
+													/>
+												</>
+											))
 											.with(ImportSource.Trakt, () => (
 												<>
 													<TextInput
@@ -393,6 +567,12 @@ const Page: NextPageWithLayout = () => {
 														required
 														{...traktImportForm.getInputProps("username")}
 													/>
+													<PasswordInput
+														mt="sm"
+														label="Access token"
+														description="Only needed if your profile is private"
+														{...traktImportForm.getInputProps("accessToken")}
+													/>
 												</>
 											))
 											.with(ImportSource.Movary, () => (
@@ -427,6 +607,26 @@ const Page: NextPageWithLayout = () => {
 													/>
 												</>
 											))
+											.with(ImportSource.Kindle, () => (
+												<>
+													<FileInput
+														label="My Clippings.txt file"
+														accept=".txt"
+														required
+														{...kindleImportForm.getInputProps("clippings")}
+													/>
+												</>
+											))
+											.with(ImportSource.Playstation, ImportSource.Xbox, () => (
+												<>
+													<FileInput
+														label="JSON played titles file"
+														accept=".json"
+														required
+														{...consoleImportForm.getInputProps("export")}
+													/>
+												</>
+											))
 											.with(ImportSource.MediaJson, () => (
 												<>
 													<FileInput
@@ -463,6 +663,162 @@ const Page: NextPageWithLayout = () => {
 													/>
 												</>
 											))
+											.with(ImportSource.Letterboxd, () => (
+												<>
+													<FileInput
+														label="ZIP export file"
+														accept=".zip"
+														required
+														onChange={async (file) => {
+															if (file) {
+																const path =
+																	await uploadFileToServiceAndGetPath(file);
+																letterboxdImportForm.setFieldValue(
+																	"exportPath",
+																	path,
+																);
+															}
+														}}
+													/>
+												</>
+											))
+											.with(ImportSource.TvTime, () => (
+												<>
+													<FileInput
+														label="ZIP export file"
+														accept=".zip"
+														required
+														onChange={async (file) => {
+															if (file) {
+																const path =
+																	await uploadFileToServiceAndGetPath(file);
+																tvTimeImportForm.setFieldValue("exportPath", path);
+															}
+														}}
+													/>
+												</>
+											))
+											.with(ImportSource.GenericCsv, () => (
+												<>
+													<FileInput
+														label="CSV file"
+														accept=".csv"
+														required
+														onChange={async (file) => {
+															if (file) {
+																const path =
+																	await uploadFileToServiceAndGetPath(file);
+																genericCsvImportForm.setFieldValue(
+																	"csvPath",
+																	path,
+																);
+															}
+														}}
+													/>
+													<Select
+														label="Type of media"
+														required
+														data={Object.values(MetadataLot).map((lot) => ({
+															label: changeCase(lot),
+															value: lot,
+														}))}
+														{...genericCsvImportForm.getInputProps("lot")}
+													/>
+													<TextInput
+														label="Title column"
+														required
+														{...genericCsvImportForm.getInputProps("titleColumn")}
+													/>
+													<TextInput
+														label="Identifier column"
+														description="Used to find the items before searching for their titles"
+														{...genericCsvImportForm.getInputProps(
+															"identifierColumn",
+														)}
+													/>
+													<Select
+														label="Identifier type"
+														data={Object.values(GenericCsvIdentifierType).map(
+															(it) => ({ label: changeCase(it), value: it }),
+														)}
+														{...genericCsvImportForm.getInputProps(
+															"identifierType",
+														)}
+													/>
+													<TextInput
+														label="Date column"
+														{...genericCsvImportForm.getInputProps("dateColumn")}
+													/>
+													<TextInput
+														label="Date format"
+														description="Eg: %d/%m/%Y"
+														{...genericCsvImportForm.getInputProps("dateFormat")}
+													/>
+													<TextInput
+														label="Rating column"
+														{...genericCsvImportForm.getInputProps("ratingColumn")}
+													/>
+													<NumberInput
+														label="Highest rating"
+														description="Eg: 5 or 10. Defaults to 100."
+														{...genericCsvImportForm.getInputProps("ratingScale")}
+													/>
+												</>
+											))
+											.with(ImportSource.GarminConnect, () => (
+												<>
+													<FileInput
+														label="Activities file"
+														accept=".json"
+														required
+														onChange={async (file) => {
+															if (file) {
+																const path =
+																	await uploadFileToServiceAndGetPath(file);
+																garminConnectImportForm.setFieldValue(
+																	"activitiesPath",
+																	path,
+																);
+															}
+														}}
+													/>
+													<FileInput
+														label="Body composition file"
+														accept=".json"
+														onChange={async (file) => {
+															if (file) {
+																const path =
+																	await uploadFileToServiceAndGetPath(file);
+																garminConnectImportForm.setFieldValue(
+																	"bioMetricsPath",
+																	path,
+																);
+															}
+														}}
+													/>
+												</>
+											))
+											.with(ImportSource.Ryot, () => (
+												<>
+													<TextInput
+														label="Instance Url"
+														required
+														{...ryotImportForm.getInputProps("url")}
+													/>
+													<TextInput
+														mt="sm"
+														label="Username"
+														required
+														{...ryotImportForm.getInputProps("username")}
+													/>
+													<PasswordInput
+														mt="sm"
+														label="Password"
+														required
+														{...ryotImportForm.getInputProps("password")}
+													/>
+												</>
+											))
 											.exhaustive()}
 									</ImportSourceElement>
 								) : undefined}
@@ -5,11 +5,13 @@ import {
 	ActionIcon,
 	Box,
 	Button,
+	Checkbox,
 	Container,
 	CopyButton,
 	Flex,
 	Group,
 	Modal,
+	NumberInput,
 	Paper,
 	Select,
 	Stack,
@@ -31,10 +33,11 @@ import {
 	UserYankIntegrationSettingKind,
 } from "@ryot/generated/graphql/backend/graphql";
 import { formatTimeAgo } from "@ryot/ts-utils";
-import { IconCopy, IconTrash } from "@tabler/icons-react";
+import { IconCopy, IconKey, IconTrash } from "@tabler/icons-react";
 import { useMutation, useQuery } from "@tanstack/react-query";
 import Head from "next/head";
 import { type ReactElement, useState } from "react";
+import { match } from "ts-pattern";
 import { z } from "zod";
 import type { NextPageWithLayout } from "../_app";
 
@@ -42,11 +45,24 @@ const createUserYankIntegrationSchema = z.object({
 	baseUrl: z.string().url().optional(),
 	token: z.string().optional(),
 	username: z.string().optional(),
+	completionThreshold: z.number().int().min(0).max(100).optional(),
+	minimumDuration: z.number().int().min(0).optional(),
+	ignorePauses: z.boolean().optional(),
 });
 type CreateUserYankIntegationSchema = z.infer<
 	typeof createUserYankIntegrationSchema
 >;
 
+const integrationUrl = (kind: string, slug: string, secret?: string | null) => {
+	const url = `${window.location.origin}/webhooks/integrations/${kind}/${slug}`;
+	if (!secret) return url;
+	// Kobo devices append the paths of the store to the URL
+	if (kind === "kobo") return `${url}/${secret}`;
+	// KOReader sends the secret as the password of the user instead
+	if (kind === "koreader") return url;
+	return `${url}?secret=${secret}`;
+};
+
 const Page: NextPageWithLayout = () => {
 	const [
 		createUserYankIntegrationModalOpened,
@@ -59,9 +75,19 @@ const Page: NextPageWithLayout = () => {
 		useState<UserYankIntegrationSettingKind>();
 	const [createUserSinkIntegrationLot, setCreateUserSinkIntegrationLot] =
 		useState<UserSinkIntegrationSettingKind>();
+	const createUserYankIntegrationLabels = match(createUserYankIntegrationLot)
+		.with(
+			UserYankIntegrationSettingKind.Steam,
+			UserYankIntegrationSettingKind.RetroAchievements,
+			() => ({ baseUrl: "Profile Url", token: "API key" }),
+		)
+		.otherwise(() => ({ baseUrl: "Base Url", token: "Token" }));
 
 	const createUserYankIntegrationForm = useForm<CreateUserYankIntegationSchema>(
-		{ validate: zodResolver(createUserYankIntegrationSchema) },
+		{
+			validate: zodResolver(createUserYankIntegrationSchema),
+			initialValues: { completionThreshold: 90 },
+		},
 	);
 
 	const userIntegrations = useQuery(["userIntegrations"], async () => {
@@ -134,12 +160,11 @@ const Page: NextPageWithLayout = () => {
 									<Group>
 										{i.slug ? (
 											<CopyButton
-												value={`${
-													window.location.origin
-												}/webhooks/integrations/${i.description
-													.toLowerCase()
-													.split(" ")
-													.at(0)}/${i.slug}`}
+												value={integrationUrl(
+													i.description.toLowerCase().split(" ").at(0) || "",
+													i.slug,
+													i.secret,
+												)}
 											>
 												{({ copy }) => (
 													<ActionIcon color="green" onClick={copy}>
@@ -148,6 +173,15 @@ const Page: NextPageWithLayout = () => {
 												)}
 											</CopyButton>
 										) : undefined}
+										{i.secret && i.description.startsWith("KOReader") ? (
+											<CopyButton value={i.secret}>
+												{({ copy }) => (
+													<ActionIcon color="blue" onClick={copy}>
+														<IconKey />
+													</ActionIcon>
+												)}
+											</CopyButton>
+										) : undefined}
 										<ActionIcon
 											color="red"
 											size="sm"
@@ -188,6 +222,11 @@ const Page: NextPageWithLayout = () => {
 							<Box
 								component="form"
 								onSubmit={createUserYankIntegrationForm.onSubmit((values) => {
+									const progressSettings = {
+										completionThreshold: values.completionThreshold,
+										minimumDuration: values.minimumDuration,
+										ignorePauses: values.ignorePauses || false,
+									};
 									if (createUserYankIntegrationLot) {
 										createUserYankIntegration.mutate({
 											input: {
@@ -196,6 +235,7 @@ const Page: NextPageWithLayout = () => {
 												// biome-ignore lint/style/noNonNullAssertion: any is required here
 												token: values.token!,
 												lot: createUserYankIntegrationLot,
+												progressSettings,
 											},
 										});
 									} else if (createUserSinkIntegrationLot) {
@@ -203,6 +243,7 @@ const Page: NextPageWithLayout = () => {
 											input: {
 												lot: createUserSinkIntegrationLot,
 												username: values.username,
+												progressSettings,
 											},
 										});
 									}
@@ -218,7 +259,10 @@ const Page: NextPageWithLayout = () => {
 										required
 										withinPortal
 										data={[
-											...Object.values(UserYankIntegrationSettingKind),
+											// Strava is connected via OAuth, not a base url and token
+											...Object.values(UserYankIntegrationSettingKind).filter(
+												(k) => k !== UserYankIntegrationSettingKind.Strava,
+											),
 											...Object.values(UserSinkIntegrationSettingKind),
 										]}
 										// biome-ignore lint/suspicious/noExplicitAny: required here
@@ -250,14 +294,14 @@ const Page: NextPageWithLayout = () => {
 									{createUserYankIntegrationLot ? (
 										<>
 											<TextInput
-												label="Base Url"
+												label={createUserYankIntegrationLabels.baseUrl}
 												required
 												{...createUserYankIntegrationForm.getInputProps(
 													"baseUrl",
 												)}
 											/>
 											<TextInput
-												label="Token"
+												label={createUserYankIntegrationLabels.token}
 												required
 												{...createUserYankIntegrationForm.getInputProps(
 													"token",
@@ -276,6 +320,35 @@ const Page: NextPageWithLayout = () => {
 											/>
 										</>
 									) : undefined}
+									{createUserYankIntegrationLot ||
+									createUserSinkIntegrationLot ? (
+										<>
+											<NumberInput
+												label="Completion threshold"
+												description="The progress after which the media is marked as completed"
+												min={0}
+												max={100}
+												{...createUserYankIntegrationForm.getInputProps(
+													"completionThreshold",
+												)}
+											/>
+											<NumberInput
+												label="Minimum duration"
+												description="The seconds the media has to be played for before its progress is updated"
+												min={0}
+												{...createUserYankIntegrationForm.getInputProps(
+													"minimumDuration",
+												)}
+											/>
+											<Checkbox
+												label="Ignore pauses"
+												{...createUserYankIntegrationForm.getInputProps(
+													"ignorePauses",
+													{ type: "checkbox" },
+												)}
+											/>
+										</>
+									) : undefined}
 									<Button
 										type="submit"
 										loading={
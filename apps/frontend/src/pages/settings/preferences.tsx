@@ -7,6 +7,7 @@ import {
 	Container,
 	Divider,
 	JsonInput,
+	MultiSelect,
 	NumberInput,
 	Select,
 	SimpleGrid,
@@ -14,12 +15,15 @@ import {
 	Switch,
 	Tabs,
 	Text,
+	TextInput,
 	Title,
 } from "@mantine/core";
 import { useLocalStorage } from "@mantine/hooks";
 import {
+	ExerciseEquipment,
 	UpdateUserPreferenceDocument,
 	type UpdateUserPreferenceMutationVariables,
+	UserNsfwDisplay,
 	UserReviewScale,
 } from "@ryot/generated/graphql/backend/graphql";
 import { changeCase, snakeCase, startCase } from "@ryot/ts-utils";
@@ -135,22 +139,121 @@ const Page: NextPageWithLayout = () => {
 											});
 									}}
 								/>
-								<Switch
+								<Select
+									size="xs"
+									label="How NSFW media will be displayed"
+									data={Object.values(UserNsfwDisplay).map((c) => ({
+										label: startCase(snakeCase(c)),
+										value: c,
+									}))}
+									defaultValue={userPreferences.data.general.nsfwDisplay}
+									disabled={!coreDetails.data.preferencesChangeAllowed}
+									onChange={(val) => {
+										if (val)
+											updateUserEnabledFeatures.mutate({
+												input: {
+													property: "general.nsfw_display",
+													value: val,
+												},
+											});
+									}}
+								/>
+							</SimpleGrid>
+							<Divider />
+							<Title order={3} mb={-16}>
+								Time estimation
+							</Title>
+							<SimpleGrid cols={2} style={{ alignItems: "end" }}>
+								<NumberInput
+									size="xs"
+									label="Speed at which podcasts are played"
+									defaultValue={Number(
+										userPreferences.data.timeEstimation.podcastPlaybackSpeed,
+									)}
+									precision={2}
+									step={0.25}
+									min={0.25}
+									disabled={!coreDetails.data.preferencesChangeAllowed}
+									onChange={(num) => {
+										if (num)
+											updateUserEnabledFeatures.mutate({
+												input: {
+													property: "time_estimation.podcast_playback_speed",
+													value: String(num),
+												},
+											});
+									}}
+								/>
+								<NumberInput
+									size="xs"
+									label="Runtime of episodes without one (in minutes)"
+									defaultValue={
+										userPreferences.data.timeEstimation.defaultEpisodeRuntime ??
+										undefined
+									}
+									min={1}
+									disabled={!coreDetails.data.preferencesChangeAllowed}
+									onChange={(num) => {
+										updateUserEnabledFeatures.mutate({
+											input: {
+												property: "time_estimation.default_episode_runtime",
+												value: num ? String(num) : "",
+											},
+										});
+									}}
+								/>
+								<NumberInput
 									size="xs"
-									mt="md"
-									label={"Whether NSFW will be displayed"}
-									checked={userPreferences.data.general.displayNsfw}
+									label="Pages read in an hour"
+									defaultValue={
+										userPreferences.data.timeEstimation.pagesPerHour ??
+										undefined
+									}
+									min={1}
 									disabled={!coreDetails.data.preferencesChangeAllowed}
-									onChange={(ev) => {
+									onChange={(num) => {
 										updateUserEnabledFeatures.mutate({
 											input: {
-												property: "general.display_nsfw",
-												value: String(ev.currentTarget.checked),
+												property: "time_estimation.pages_per_hour",
+												value: num ? String(num) : "",
 											},
 										});
 									}}
 								/>
 							</SimpleGrid>
+							<Divider />
+							<Title order={3} mb={-16}>
+								Seen contexts
+							</Title>
+							<Text size="sm" color="dimmed">
+								Where or how you consumed a media, separated by commas. Leave
+								empty to not ask for it.
+							</Text>
+							<SimpleGrid cols={2}>
+								{Object.entries(userPreferences.data.seenContexts).map(
+									([name, contexts]) => (
+										<TextInput
+											size="xs"
+											key={name}
+											label={changeCase(snakeCase(name))}
+											defaultValue={contexts.join(", ")}
+											disabled={!coreDetails.data.preferencesChangeAllowed}
+											onBlur={(ev) => {
+												const value = ev.currentTarget.value
+													.split(",")
+													.map((c) => c.trim())
+													.filter(Boolean);
+												updateUserEnabledFeatures.mutate({
+													input: {
+														property: `seen_contexts.${snakeCase(name)}`,
+														value: JSON.stringify(value),
+													},
+												});
+											}}
+										/>
+									),
+								)}
+							</SimpleGrid>
 						</Stack>
 					</Tabs.Panel>
 					<Tabs.Panel value="notifications" mt="md">
@@ -188,6 +291,10 @@ const Page: NextPageWithLayout = () => {
 													() =>
 														"Number of chapters/episodes changes for manga/anime",
 												)
+												.with(
+													"personalBestAchieved",
+													() => "A personal best is achieved in a workout",
+												)
 												.otherwise(() => undefined)}
 											checked={isEnabled}
 											disabled={!coreDetails.data.preferencesChangeAllowed}
@@ -290,6 +397,81 @@ const Page: NextPageWithLayout = () => {
 									}}
 								/>
 							</SimpleGrid>
+							<Divider />
+							<Text>
+								Equipment (used to calculate the plates to load and to suggest
+								substitutions)
+							</Text>
+							<MultiSelect
+								size="xs"
+								label="The kinds of equipment available (leave empty for all)"
+								data={Object.values(ExerciseEquipment).map((e) => ({
+									value: e,
+									label: startCase(snakeCase(e)),
+								}))}
+								defaultValue={userPreferences.data.fitness.equipment.available}
+								disabled={!coreDetails.data.preferencesChangeAllowed}
+								onChange={(val) => {
+									updateUserEnabledFeatures.mutate({
+										input: {
+											property: "fitness.equipment.available",
+											value: JSON.stringify(val),
+										},
+									});
+								}}
+							/>
+							<JsonInput
+								label="The weights of the plates available in pairs"
+								defaultValue={JSON.stringify(
+									userPreferences.data.fitness.equipment.plates,
+								)}
+								disabled={!coreDetails.data.preferencesChangeAllowed}
+								autosize
+								formatOnBlur
+								onChange={(v) => {
+									updateUserEnabledFeatures.mutate({
+										input: {
+											property: "fitness.equipment.plates",
+											value: v,
+										},
+									});
+								}}
+							/>
+							<JsonInput
+								label="The weights of the bars available"
+								defaultValue={JSON.stringify(
+									userPreferences.data.fitness.equipment.bars,
+								)}
+								disabled={!coreDetails.data.preferencesChangeAllowed}
+								autosize
+								formatOnBlur
+								onChange={(v) => {
+									updateUserEnabledFeatures.mutate({
+										input: {
+											property: "fitness.equipment.bars",
+											value: v,
+										},
+									});
+								}}
+							/>
+							<NumberInput
+								size="xs"
+								label="The difference in weight between consecutive dumbbells"
+								defaultValue={Number(
+									userPreferences.data.fitness.equipment.dumbbellIncrement,
+								)}
+								precision={2}
+								disabled={!coreDetails.data.preferencesChangeAllowed}
+								onChange={(num) => {
+									if (num)
+										updateUserEnabledFeatures.mutate({
+											input: {
+												property: "fitness.equipment.dumbbell_increment",
+												value: String(num),
+											},
+										});
+								}}
+							/>
 						</Stack>
 					</Tabs.Panel>
 				</Tabs>
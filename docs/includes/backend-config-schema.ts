@@ -18,6 +18,11 @@ export interface AnimeConfig {
 	mal: AnimeMalConfig;
 }
 
+export interface ArticleConfig {
+	/** The reading speed used to estimate how long an article takes to read. */
+	words_per_minute: number;
+}
+
 export interface AudibleConfig {
 	/** Settings related to locale for making requests Audible. */
 	locale: string;
@@ -28,15 +33,28 @@ export interface AudioBookConfig {
 	audible: AudibleConfig;
 }
 
-export interface GoogleBooksConfig {
+export interface BoardGameConfig {
 
 }
 
+export interface GoogleBooksConfig {
+	/**
+	 * The API key for the Google Books API. Optional, but the requests made
+	 * without one share a much lower quota.
+	 */
+	api_key: string;
+}
+
 export type OpenlibraryCoverImageSize = 'S' | 'M' | 'L';
 
 export interface OpenlibraryConfig {
 	/** The image sizes to fetch from Openlibrary. */
 	cover_image_size: OpenlibraryCoverImageSize;
+	/**
+	 * Whether to collapse search results that have the same title and authors
+	 * into a single result.
+	 */
+	dedupe_search_results: boolean;
 }
 
 export interface BookConfig {
@@ -46,6 +64,16 @@ export interface BookConfig {
 	openlibrary: OpenlibraryConfig;
 }
 
+export interface ComicVineConfig {
+	/** The API key for the ComicVine API. **Required** to enable comics. */
+	api_key: string;
+}
+
+export interface ComicConfig {
+	/** Settings related to ComicVine. */
+	comic_vine: ComicVineConfig;
+}
+
 export interface DatabaseConfig {
 	/**
 	 * The database connection string. Supports SQLite, MySQL and Postgres.
@@ -54,8 +82,32 @@ export interface DatabaseConfig {
 	url: string;
 }
 
-export interface ExerciseConfig {
+export interface StravaConfig {
+	/**
+	 * The client ID of the Strava API application. **Required*to enable the
+	 * Strava integration. [More information](/docs/guides/fitness.md)
+	 */
+	client_id: string;
+	/**
+	 * The client secret of the Strava API application. **Required*to enable
+	 * the Strava integration.
+	 */
+	client_secret: string;
+}
+
+export interface WgerConfig {
+	/**
+	 * The base URL of the wger instance (eg: `https://wger.de`). If set, the
+	 * exercise library is supplemented with the exercises of this instance.
+	 */
+	url: string;
+}
 
+export interface ExerciseConfig {
+	/** Settings related to Strava (activity sync). */
+	strava: StravaConfig;
+	/** Settings related to wger (exercise library). */
+	wger: WgerConfig;
 }
 
 export interface FileStorageConfig {
@@ -78,6 +130,13 @@ export interface FileStorageConfig {
 	s3_secret_access_key: string;
 	/** The URL for the S3 compatible file storage. */
 	s3_url: string;
+	/**
+	 * The number of minutes that the signed URLs of the uploaded files are
+	 * valid for. The files are only served using these, so the bucket does not
+	 * need to be public.
+	 * @default 90
+	 */
+	signed_url_expiry_minutes: number;
 }
 
 export interface FrontendConfig {
@@ -91,6 +150,11 @@ export interface FrontendConfig {
 	 * @default 20
 	 */
 	page_size: number;
+	/**
+	 * The URL where the frontend is hosted, used for the links in the emails
+	 * sent to the users. Eg: `https://ryot.example.com`.
+	 */
+	url: string;
 }
 
 export interface IntegrationConfig {
@@ -98,6 +162,7 @@ export interface IntegrationConfig {
 	hasher_salt: string;
 	/**
 	 * The maximum progress limit after which a media is considered to be completed.
+	 * Integrations can override it with their own completion threshold.
 	 * @default 95
 	 */
 	maximum_progress_limit: number;
@@ -123,6 +188,10 @@ export interface MangaMalConfig {
 	client_id: string;
 }
 
+export interface MangaMangaDexConfig {
+
+}
+
 export interface MangaMangaUpdatesConfig {
 
 }
@@ -132,12 +201,112 @@ export interface MangaConfig {
 	anilist: MangaAnilistConfig;
 	/** Settings related to MAL (manga). */
 	mal: MangaMalConfig;
+	/** Settings related to MangaDex. */
+	manga_dex: MangaMangaDexConfig;
 	/** Settings related to MangaUpdates. */
 	manga_updates: MangaMangaUpdatesConfig;
 }
 
-export interface MediaConfig {
+/** The sources are given by their names, eg: `GoogleBooks` or `Anilist`. */
+export interface MetadataFallbackConfig {
+	/** The sources to fill in the missing details of anime from. */
+	anime: string[];
+	/** The sources to fill in the missing details of articles from. */
+	article: string[];
+	/** The sources to fill in the missing details of audio books from. */
+	audio_book: string[];
+	/** The sources to fill in the missing details of board games from. */
+	board_game: string[];
+	/**
+	 * The sources to fill in the missing details of books from.
+	 * @default ["GoogleBooks"]
+	 */
+	book: string[];
+	/** The sources to fill in the missing details of comics from. */
+	comic: string[];
+	/** The sources to fill in the missing details of manga from. */
+	manga: string[];
+	/** The sources to fill in the missing details of movies from. */
+	movie: string[];
+	/** The sources to fill in the missing details of music from. */
+	music: string[];
+	/** The sources to fill in the missing details of podcasts from. */
+	podcast: string[];
+	/** The sources to fill in the missing details of shows from. */
+	show: string[];
+	/** The sources to fill in the missing details of video games from. */
+	video_game: string[];
+	/** The sources to fill in the missing details of visual novels from. */
+	visual_novel: string[];
+}
+
+/**
+ * The settings of each provider are given as `Source=number` pairs, eg:
+ * `Tmdb=20`.
+ */
+export interface ProviderRateLimitConfig {
+	/**
+	 * The number of times a request is retried when it is rate limited or
+	 * fails, for the providers that are not listed in `max_retries`.
+	 * @default 3
+	 */
+	default_max_retries: number;
+	/**
+	 * The number of times a request to a provider is retried, with an
+	 * exponential backoff.
+	 */
+	max_retries: string[];
+	/**
+	 * The maximum number of requests sent to a provider in a second. The
+	 * providers that are not listed are not limited.
+	 * @default ["MusicBrainz=1","Openlibrary=3","Tmdb=20"]
+	 */
+	requests_per_second: string[];
+}
+
+export interface UpcItemDbConfig {
+	/**
+	 * The user key for the UPCitemdb API. If not provided, the rate limited
+	 * trial endpoint will be used for barcode lookups.
+	 */
+	user_key: string;
+}
+
+export interface WikidataConfig {
+	/** Whether to tag media with the awards and nominations listed on Wikidata. */
+	enabled: boolean;
+}
 
+export interface MediaConfig {
+	/**
+	 * The other sources that are tried, in order, when the details of a media
+	 * item are missing a description, images, genres, creators or release
+	 * date. The first search result with a matching title is used.
+	 */
+	fallbacks: MetadataFallbackConfig;
+	/**
+	 * The number of days a media item that no user is associated with is kept
+	 * for before it is removed, along with its images, by the cleanup job.
+	 * Such items are created when they are opened from the search results.
+	 * @default 0
+	 */
+	orphaned_metadata_retention_days: number;
+	/**
+	 * The number of minutes the responses of the providers are cached for, so
+	 * that the same details are not requested again. They are always requested
+	 * again when the media is updated. Set to `0` to disable.
+	 * @default 60
+	 */
+	provider_cache_minutes: number;
+	/**
+	 * Settings related to the rate limits and retries of the requests made to
+	 * the providers.
+	 */
+	rate_limits: ProviderRateLimitConfig;
+	/** Settings related to UPCitemdb (barcode lookups). */
+	upcitemdb: UpcItemDbConfig;
+	/** Settings related to Wikidata (award enrichment). */
+	wikidata: WikidataConfig;
 }
 
 export interface MoviesTmdbConfig {
@@ -152,6 +321,10 @@ export interface MovieConfig {
 	tmdb: MoviesTmdbConfig;
 }
 
+export interface MusicConfig {
+
+}
+
 export interface ITunesConfig {
 	/** The locale to use for making requests to iTunes API. */
 	locale: string;
@@ -162,11 +335,20 @@ export interface ListenNotesConfig {
 	api_token: string;
 }
 
+export interface PodcastIndexConfig {
+	/** The API key for the Podcast Index API. */
+	api_key: string;
+	/** The API secret for the Podcast Index API. */
+	api_secret: string;
+}
+
 export interface PodcastConfig {
 	/** Settings related to iTunes. */
 	itunes: ITunesConfig;
 	/** Settings related to Listennotes. */
 	listennotes: ListenNotesConfig;
+	/** Settings related to Podcast Index. */
+	podcast_index: PodcastIndexConfig;
 }
 
 export interface SchedulerConfig {
@@ -175,6 +357,20 @@ export interface SchedulerConfig {
 	 * @default 'sqlite::memory:'
 	 */
 	database_url: string;
+	/**
+	 * The maximum number of media items whose details are fetched from a
+	 * provider at the same time during an import. Providers with strict rate
+	 * limits always use fewer.
+	 * @default 10
+	 */
+	import_concurrency: number;
+	/**
+	 * Deploy a job every x hours that analyzes the database, rebuilds the
+	 * indexes of the tables that are searched and removes media that no user
+	 * is linked to. Disabled if `0`.
+	 * @default 0
+	 */
+	maintenance_every: number;
 	/**
 	 * The number of jobs to process every 5 seconds when updating metadata in
 	 * the background.
@@ -189,6 +385,47 @@ export interface SchedulerConfig {
 	user_cleanup_every: number;
 }
 
+export interface SmtpConfig {
+	/**
+	 * The mailbox that the emails are sent from.
+	 * @default 'Ryot <no-reply@ryot.io>'
+	 */
+	mailbox: string;
+	/** The password used to authenticate with the SMTP server. */
+	password: string;
+	/**
+	 * The port of the SMTP server.
+	 * @default 587
+	 */
+	port: number;
+	/** The host of the SMTP server. **Required** to send emails. */
+	server: string;
+	/** The username used to authenticate with the SMTP server. */
+	user: string;
+}
+
+export interface GraphqlConfig {
+	/**
+	 * The maximum number of fields that a query can select, counting the nested
+	 * ones. Set to `0` to disable.
+	 * @default 1000
+	 */
+	max_complexity: number;
+	/**
+	 * The maximum depth to which the fields of a query can be nested. Set to
+	 * `0` to disable.
+	 * @default 20
+	 */
+	max_depth: number;
+	/**
+	 * The maximum number of requests that can be made in a minute using the
+	 * user, or from the same IP address when not logged in. Set to
+	 * `0` to disable.
+	 * @default 300
+	 */
+	max_requests_per_minute: number;
+}
+
 export interface ServerConfig {
 	/** The path where the config file will be written once the server boots up. */
 	config_dump_path: string;
@@ -205,6 +442,11 @@ export interface ServerConfig {
 	 * @default true
 	 */
 	deploy_admin_jobs_allowed: boolean;
+	/**
+	 * Settings related to limiting the GraphQL queries that can be made, to
+	 * protect the instance from expensive ones.
+	 */
+	graphql: GraphqlConfig;
 	/**
 	 * This will make auth cookies insecure and should be set to `true` if you
 	 * are running the server on `localhost`.
@@ -225,6 +467,11 @@ export interface ServerConfig {
 	progress_update_threshold: number;
 	/** This will set SameSite=None on the auth cookies. */
 	samesite_none: boolean;
+	/**
+	 * Settings related to sending emails, like the ones to verify an email
+	 * or reset a password.
+	 */
+	smtp: SmtpConfig;
 	/**
 	 * Whether videos will be displayed in the media details.
 	 * @default false
@@ -244,6 +491,64 @@ export interface ShowConfig {
 	tmdb: ShowsTmdbConfig;
 }
 
+export type NsfwDisplayFloor = 'hide' | 'blur' | 'show';
+
+export interface LoginThrottlingConfig {
+	/**
+	 * The number of minutes a lockout lasts, counted from the last failed login.
+	 * @default 15
+	 */
+	lockout_minutes: number;
+	/**
+	 * The number of failed logins from an IP address after which it is locked
+	 * out. Set to `0` to disable.
+	 * @default 20
+	 */
+	max_attempts_per_ip: number;
+	/**
+	 * The number of failed logins for a username after which it is locked out.
+	 * Set to `0` to disable.
+	 * @default 5
+	 */
+	max_attempts_per_user: number;
+	/**
+	 * Whether the IP address is read from the `X-Forwarded-For` header. Only
+	 * enable this if the server is behind a reverse proxy that sets it.
+	 */
+	trust_forwarded_for: boolean;
+	/**
+	 * The number of reverse proxies in front of the server that add to the
+	 * `X-Forwarded-For` header. The IP address is read from the entry that the
+	 * farthest of them added, since the ones before it can be set by the client.
+	 * @default 1
+	 */
+	trusted_proxies: number;
+}
+
+export interface PasswordHashingConfig {
+	/**
+	 * The memory (in KiB) used by Argon2id to hash a password.
+	 * @default 19456
+	 */
+	memory_cost: number;
+	/**
+	 * The degree of parallelism used by Argon2id to hash a password.
+	 * @default 1
+	 */
+	parallelism: number;
+	/**
+	 * A secret that is mixed into the password hashes. It is not stored in the
+	 * database, so the hashes can not be cracked without it. Changing it once
+	 * set makes the existing passwords unusable.
+	 */
+	pepper: string;
+	/**
+	 * The number of iterations used by Argon2id to hash a password.
+	 * @default 2
+	 */
+	time_cost: number;
+}
+
 export interface UsersConfig {
 	/**
 	 * Whether users will be allowed to change their password in their profile
@@ -263,6 +568,12 @@ export interface UsersConfig {
 	 * @default true
 	 */
 	allow_changing_username: boolean;
+	/**
+	 * Whether users will be allowed to reset their password using a link sent
+	 * to their email. Requires SMTP to be configured.
+	 * @default true
+	 */
+	allow_password_reset: boolean;
 	/**
 	 * Whether new users will be allowed to sign up to this instance.
 	 * @default true
@@ -270,6 +581,27 @@ export interface UsersConfig {
 	allow_registration: boolean;
 	/** The secret used for generating JWT tokens. */
 	jwt_secret: string;
+	/**
+	 * Settings related to locking out the users and IP addresses with too many
+	 * failed logins.
+	 */
+	login_throttling: LoginThrottlingConfig;
+	/**
+	 * The most permissive way NSFW media can be displayed on this instance.
+	 * Users can still choose a stricter setting for themselves.
+	 */
+	nsfw_display_floor: NsfwDisplayFloor;
+	/**
+	 * Settings related to how the passwords are hashed. The passwords hashed
+	 * with other settings are hashed again when the user logs in.
+	 */
+	password_hashing: PasswordHashingConfig;
+	/**
+	 * Whether new users need to verify their email before they can login.
+	 * Requires SMTP to be configured.
+	 * @default false
+	 */
+	require_email_verification: boolean;
 	/**
 	 * Whether users will be allowed to post reviews on this instance.
 	 * @default false
@@ -316,10 +648,16 @@ export interface VisualNovelConfig {
 export interface AppConfig {
 	/** Settings related to anime. */
 	anime: AnimeConfig;
+	/** Settings related to articles. */
+	articles: ArticleConfig;
 	/** Settings related to audio books. */
 	audio_books: AudioBookConfig;
+	/** Settings related to board games. */
+	board_games: BoardGameConfig;
 	/** Settings related to books. */
 	books: BookConfig;
+	/** Settings related to comics. */
+	comics: ComicConfig;
 	/** The database related settings. */
 	database: DatabaseConfig;
 	/** Settings related to exercises. */
@@ -336,6 +674,8 @@ export interface AppConfig {
 	media: MediaConfig;
 	/** Settings related to movies. */
 	movies: MovieConfig;
+	/** Settings related to music. */
+	music: MusicConfig;
 	/** Settings related to podcasts. */
 	podcasts: PodcastConfig;
 	/** Settings related to scheduler. */
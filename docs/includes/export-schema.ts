@@ -32,6 +32,22 @@ export type ExportUserMeasurementItem = {
 	stats: UserMeasurementStats;
 };
 
+export type ImportOrExportItemHighlight = {
+	/**
+	 * The passage that was highlighted.
+	 */
+	text: string /**
+	 * The page the passage is on.
+	 */;
+	page: number | null /**
+	 * The chapter the passage is in.
+	 */;
+	chapter: string | null /**
+	 * The note that the user wrote about the passage.
+	 */;
+	note: string | null;
+};
+
 export type ImportOrExportItemRating = {
 	/**
 	 * Data about the review.
@@ -85,6 +101,10 @@ export type ImportOrExportMediaItem<T> = {
 	 * An string to help identify it in the original source.
 	 */
 	source_id: string /**
+	 * The title of the media in the original source. It is used to find the
+	 * media in the database when the provider does not have it.
+	 */;
+	title: string | null /**
 	 * The type of media.
 	 */;
 	lot: MetadataLot /**
@@ -102,7 +122,10 @@ export type ImportOrExportMediaItem<T> = {
 	reviews: ImportOrExportItemRating[] /**
 	 * The collections to add this media to.
 	 */;
-	collections: string[];
+	collections: string[] /**
+	 * The highlights that the user made in this media.
+	 */;
+	highlights: ImportOrExportItemHighlight[];
 };
 
 export type ImportOrExportMediaItemSeen = {
@@ -124,7 +147,10 @@ export type ImportOrExportMediaItemSeen = {
 	show_episode_number: number | null /**
 	 * If for a podcast, the episode which was seen.
 	 */;
-	podcast_episode_number: number | null;
+	podcast_episode_number: number | null /**
+	 * The state to leave the media in if it was not finished, eg: dropped.
+	 */;
+	state: SeenState | null;
 };
 
 /**
@@ -143,12 +169,16 @@ export type ImportOrExportPersonItem = {
 export type Infallible = never;
 
 export type MetadataLot =
+	| "Article"
 	| "AudioBook"
 	| "Anime"
+	| "BoardGame"
 	| "Book"
+	| "Comic"
 	| "Podcast"
 	| "Manga"
 	| "Movie"
+	| "Music"
 	| "Show"
 	| "VideoGame"
 	| "VisualNovel";
@@ -156,19 +186,27 @@ export type MetadataLot =
 export type MetadataSource =
 	| "Anilist"
 	| "Audible"
+	| "BoardGameGeek"
+	| "ComicVine"
 	| "Custom"
 	| "GoogleBooks"
 	| "Igdb"
 	| "Itunes"
 	| "Listennotes"
+	| "MangaDex"
 	| "MangaUpdates"
 	| "Mal"
+	| "MusicBrainz"
 	| "Openlibrary"
+	| "PodcastIndex"
 	| "Tmdb"
-	| "Vndb";
+	| "Vndb"
+	| "Web";
 
 export type ReviewCommentUser = { id: number; name: string };
 
+export type SeenState = "Completed" | "Dropped" | "InProgress" | "OnAHold";
+
 export type UserMeasurementStats = {
 	weight?: string | null;
 	body_mass_index?: string | null;
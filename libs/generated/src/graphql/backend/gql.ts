@@ -14,11 +14,14 @@ import { TypedDocumentNode as DocumentNode } from '@graphql-typed-document-node/
  */
 const documents = {
     "mutation AddMediaToCollection($input: AddMediaToCollection!) {\n  addMediaToCollection(input: $input)\n}": types.AddMediaToCollectionDocument,
+    "mutation AddSeenAttachment($input: AddSeenAttachmentInput!) {\n  addSeenAttachment(input: $input) {\n    id\n  }\n}": types.AddSeenAttachmentDocument,
+    "mutation AddSeenCompanion($input: AddSeenCompanionInput!) {\n  addSeenCompanion(input: $input) {\n    id\n  }\n}": types.AddSeenCompanionDocument,
     "mutation BulkProgressUpdate($input: [ProgressUpdateInput!]!) {\n  bulkProgressUpdate(input: $input)\n}": types.BulkProgressUpdateDocument,
     "mutation CommitMedia($lot: MetadataLot!, $source: MetadataSource!, $identifier: String!) {\n  commitMedia(lot: $lot, source: $source, identifier: $identifier) {\n    id\n  }\n}": types.CommitMediaDocument,
     "mutation CreateCustomMedia($input: CreateCustomMediaInput!) {\n  createCustomMedia(input: $input) {\n    __typename\n    ... on IdObject {\n      id\n    }\n    ... on CreateCustomMediaError {\n      error\n    }\n  }\n}": types.CreateCustomMediaDocument,
     "mutation CreateMediaReminder($input: CreateMediaReminderInput!) {\n  createMediaReminder(input: $input)\n}": types.CreateMediaReminderDocument,
     "mutation CreateOrUpdateCollection($input: CreateOrUpdateCollectionInput!) {\n  createOrUpdateCollection(input: $input) {\n    id\n  }\n}": types.CreateOrUpdateCollectionDocument,
+    "mutation CreateOrUpdateHighlight($input: CreateOrUpdateHighlightInput!) {\n  createOrUpdateHighlight(input: $input) {\n    id\n  }\n}": types.CreateOrUpdateHighlightDocument,
     "mutation CreateReviewComment($input: CreateReviewCommentInput!) {\n  createReviewComment(input: $input)\n}": types.CreateReviewCommentDocument,
     "mutation CreateUserMeasurement($input: UserMeasurementInput!) {\n  createUserMeasurement(input: $input)\n}": types.CreateUserMeasurementDocument,
     "mutation CreateUserNotificationPlatform($input: CreateUserNotificationPlatformInput!) {\n  createUserNotificationPlatform(input: $input)\n}": types.CreateUserNotificationPlatformDocument,
@@ -26,8 +29,11 @@ const documents = {
     "mutation CreateUserWorkout($input: UserWorkoutInput!) {\n  createUserWorkout(input: $input)\n}": types.CreateUserWorkoutDocument,
     "mutation CreateUserYankIntegration($input: CreateUserYankIntegrationInput!) {\n  createUserYankIntegration(input: $input)\n}": types.CreateUserYankIntegrationDocument,
     "mutation DeleteCollection($collectionName: String!) {\n  deleteCollection(collectionName: $collectionName)\n}": types.DeleteCollectionDocument,
+    "mutation DeleteHighlight($highlightId: Int!) {\n  deleteHighlight(highlightId: $highlightId)\n}": types.DeleteHighlightDocument,
     "mutation DeleteMediaReminder($metadataId: Int!) {\n  deleteMediaReminder(metadataId: $metadataId)\n}": types.DeleteMediaReminderDocument,
     "mutation DeleteReview($reviewId: Int!) {\n  deleteReview(reviewId: $reviewId)\n}": types.DeleteReviewDocument,
+    "mutation DeleteSeenAttachment($attachmentId: Int!) {\n  deleteSeenAttachment(attachmentId: $attachmentId)\n}": types.DeleteSeenAttachmentDocument,
+    "mutation DeleteSeenCompanion($companionId: Int!) {\n  deleteSeenCompanion(companionId: $companionId)\n}": types.DeleteSeenCompanionDocument,
     "mutation DeleteSeenItem($seenId: Int!) {\n  deleteSeenItem(seenId: $seenId) {\n    id\n  }\n}": types.DeleteSeenItemDocument,
     "mutation DeleteUser($toDeleteUserId: Int!) {\n  deleteUser(toDeleteUserId: $toDeleteUserId)\n}": types.DeleteUserDocument,
     "mutation DeleteUserIntegration($integrationId: Int!, $integrationLot: UserIntegrationLot!) {\n  deleteUserIntegration(\n    integrationId: $integrationId\n    integrationLot: $integrationLot\n  )\n}": types.DeleteUserIntegrationDocument,
@@ -37,6 +43,7 @@ const documents = {
     "mutation DeployRecalculateCalendarEventsJob {\n  deployRecalculateCalendarEventsJob\n}": types.DeployRecalculateCalendarEventsJobDocument,
     "mutation DeployUpdateMetadataJob($metadataId: Int!) {\n  deployUpdateMetadataJob(metadataId: $metadataId)\n}": types.DeployUpdateMetadataJobDocument,
     "mutation GenerateAuthToken {\n  generateAuthToken\n}": types.GenerateAuthTokenDocument,
+    "mutation LinkKoreaderDocument($documentId: Int!, $metadataId: Int!) {\n  linkKoreaderDocument(documentId: $documentId, metadataId: $metadataId)\n}": types.LinkKoreaderDocumentDocument,
     "mutation LoginUser($input: UserInput!) {\n  loginUser(input: $input) {\n    __typename\n    ... on LoginError {\n      error\n    }\n    ... on LoginResponse {\n      apiKey\n    }\n  }\n}": types.LoginUserDocument,
     "mutation LogoutUser {\n  logoutUser\n}": types.LogoutUserDocument,
     "mutation MergeMetadata($mergeFrom: Int!, $mergeInto: Int!) {\n  mergeMetadata(mergeFrom: $mergeFrom, mergeInto: $mergeInto)\n}": types.MergeMetadataDocument,
@@ -46,41 +53,51 @@ const documents = {
     "mutation RegenerateUserSummary {\n  regenerateUserSummary\n}": types.RegenerateUserSummaryDocument,
     "mutation RegisterUser($input: UserInput!) {\n  registerUser(input: $input) {\n    __typename\n    ... on RegisterError {\n      error\n    }\n    ... on IdObject {\n      id\n    }\n  }\n}": types.RegisterUserDocument,
     "mutation RemoveMediaFromCollection($metadataId: Int!, $collectionName: String!) {\n  removeMediaFromCollection(\n    metadataId: $metadataId\n    collectionName: $collectionName\n  ) {\n    id\n  }\n}": types.RemoveMediaFromCollectionDocument,
+    "mutation RequestUserEmailVerification($username: String!) {\n  requestUserEmailVerification(username: $username)\n}": types.RequestUserEmailVerificationDocument,
+    "mutation RequestUserPasswordReset($username: String!) {\n  requestUserPasswordReset(username: $username)\n}": types.RequestUserPasswordResetDocument,
+    "mutation ResetUserPassword($input: ResetUserPasswordInput!) {\n  resetUserPassword(input: $input)\n}": types.ResetUserPasswordDocument,
+    "mutation RespondToSeenCompanion($companionId: Int!, $accept: Boolean!) {\n  respondToSeenCompanion(companionId: $companionId, accept: $accept)\n}": types.RespondToSeenCompanionDocument,
     "mutation TestUserNotificationPlatforms {\n  testUserNotificationPlatforms\n}": types.TestUserNotificationPlatformsDocument,
+    "mutation ToggleMediaFavorite($metadataId: Int!) {\n  toggleMediaFavorite(metadataId: $metadataId)\n}": types.ToggleMediaFavoriteDocument,
     "mutation ToggleMediaMonitor($toMonitorMetadataId: Int!) {\n  toggleMediaMonitor(toMonitorMetadataId: $toMonitorMetadataId)\n}": types.ToggleMediaMonitorDocument,
     "mutation UpdateAllMetadata {\n  updateAllMetadata\n}": types.UpdateAllMetadataDocument,
     "mutation UpdateUser($input: UpdateUserInput!) {\n  updateUser(input: $input) {\n    id\n  }\n}": types.UpdateUserDocument,
     "mutation UpdateUserPreference($input: UpdateUserPreferenceInput!) {\n  updateUserPreference(input: $input)\n}": types.UpdateUserPreferenceDocument,
+    "mutation VerifyUserEmail($token: String!) {\n  verifyUserEmail(token: $token)\n}": types.VerifyUserEmailDocument,
     "mutation YankIntegrationData {\n  yankIntegrationData\n}": types.YankIntegrationDataDocument,
+    "query Announcements {\n  announcements {\n    id\n    message\n    severity\n    createdOn\n    expiresOn\n  }\n}": types.AnnouncementsDocument,
     "query CollectionContents($input: CollectionContentsInput!) {\n  collectionContents(input: $input) {\n    user {\n      name\n    }\n    results {\n      details {\n        total\n        nextPage\n      }\n      items {\n        lot\n        details {\n          identifier\n          title\n          image\n          publishYear\n        }\n      }\n    }\n    details {\n      name\n      description\n      visibility\n      createdOn\n    }\n  }\n}": types.CollectionContentsDocument,
-    "query Collections($input: CollectionInput) {\n  collections(input: $input) {\n    id\n    name\n    description\n    visibility\n    numItems\n  }\n}": types.CollectionsDocument,
+    "query Collections($input: CollectionInput) {\n  collections(input: $input) {\n    id\n    name\n    description\n    visibility\n    numItems\n    actions {\n      lot\n      collectionName\n    }\n  }\n}": types.CollectionsDocument,
     "query CoreDetails {\n  coreDetails {\n    version\n    authorName\n    repositoryLink\n    docsLink\n    defaultCredentials\n    passwordChangeAllowed\n    preferencesChangeAllowed\n    usernameChangeAllowed\n    itemDetailsHeight\n    reviewsDisabled\n    videosDisabled\n    upgrade\n    pageLimit\n    deployAdminJobsAllowed\n  }\n}": types.CoreDetailsDocument,
-    "query CoreEnabledFeatures {\n  coreEnabledFeatures {\n    fileStorage\n    signupAllowed\n  }\n}": types.CoreEnabledFeaturesDocument,
+    "query CoreEnabledFeatures {\n  coreEnabledFeatures {\n    fileStorage\n    signupAllowed\n    passwordResetAllowed\n    emailVerificationRequired\n  }\n}": types.CoreEnabledFeaturesDocument,
     "query CreatorDetails($creatorId: Int!) {\n  creatorDetails(creatorId: $creatorId) {\n    details {\n      id\n      name\n      image\n    }\n    contents {\n      name\n      items {\n        metadataId\n        title\n        image\n      }\n    }\n  }\n}": types.CreatorDetailsDocument,
     "query CreatorsList($input: SearchInput!) {\n  creatorsList(input: $input) {\n    details {\n      total\n      nextPage\n    }\n    items {\n      id\n      name\n      image\n      mediaCount\n    }\n  }\n}": types.CreatorsListDocument,
     "query ExerciseDetails($exerciseId: Int!) {\n  exerciseDetails(exerciseId: $exerciseId) {\n    name\n    lot\n    level\n    force\n    mechanic\n    equipment\n    attributes {\n      instructions\n      images\n      muscles\n    }\n  }\n}": types.ExerciseDetailsDocument,
     "query ExerciseParameters {\n  exerciseParameters {\n    filters {\n      type\n      level\n      force\n      mechanic\n      equipment\n      muscle\n    }\n    downloadRequired\n  }\n}": types.ExerciseParametersDocument,
     "query ExercisesList($input: ExercisesListInput!) {\n  exercisesList(input: $input) {\n    details {\n      total\n      nextPage\n    }\n    items {\n      id\n      name\n      lot\n      attributes {\n        images\n        muscles\n      }\n    }\n  }\n}": types.ExercisesListDocument,
     "query GetPresignedUrl($key: String!) {\n  getPresignedUrl(key: $key)\n}": types.GetPresignedUrlDocument,
+    "query Highlights($metadataId: Int) {\n  highlights(metadataId: $metadataId) {\n    id\n    metadataId\n    text\n    page\n    chapter\n    note\n  }\n}": types.HighlightsDocument,
     "query ImportReports {\n  importReports {\n    id\n    source\n    startedOn\n    finishedOn\n    success\n    details {\n      import {\n        total\n      }\n      failedItems {\n        lot\n        step\n        identifier\n        error\n      }\n    }\n  }\n}": types.ImportReportsDocument,
-    "query LatestUserSummary {\n  latestUserSummary {\n    calculatedOn\n    fitness {\n      measurementsRecorded\n      workoutsRecorded\n    }\n    media {\n      reviewsPosted\n      creatorsInteractedWith\n      manga {\n        chapters\n        read\n      }\n      books {\n        pages\n        read\n      }\n      movies {\n        runtime\n        watched\n      }\n      anime {\n        episodes\n        watched\n      }\n      podcasts {\n        runtime\n        played\n        playedEpisodes\n      }\n      visualNovels {\n        played\n        runtime\n      }\n      videoGames {\n        played\n      }\n      shows {\n        runtime\n        watchedEpisodes\n        watchedSeasons\n        watched\n      }\n      audioBooks {\n        runtime\n        played\n      }\n    }\n  }\n}": types.LatestUserSummaryDocument,
-    "query MediaDetails($metadataId: Int!) {\n  mediaDetails(metadataId: $metadataId) {\n    title\n    description\n    identifier\n    lot\n    source\n    isNsfw\n    creators {\n      name\n      items {\n        id\n        name\n        image\n      }\n    }\n    assets {\n      images\n      videos {\n        videoId\n        source\n      }\n    }\n    providerRating\n    publishYear\n    publishDate\n    genres\n    sourceUrl\n    group {\n      id\n      name\n      part\n    }\n    suggestions {\n      lot\n      source\n      identifier\n      title\n      image\n      metadataId\n    }\n    animeSpecifics {\n      episodes\n    }\n    audioBookSpecifics {\n      runtime\n    }\n    bookSpecifics {\n      pages\n    }\n    movieSpecifics {\n      runtime\n    }\n    mangaSpecifics {\n      volumes\n      chapters\n    }\n    podcastSpecifics {\n      episodes {\n        title\n        overview\n        thumbnail\n        number\n        runtime\n        publishDate\n      }\n      totalEpisodes\n    }\n    showSpecifics {\n      seasons {\n        seasonNumber\n        name\n        overview\n        backdropImages\n        posterImages\n        episodes {\n          id\n          name\n          posterImages\n          episodeNumber\n          publishDate\n          name\n          overview\n          runtime\n        }\n      }\n    }\n    visualNovelSpecifics {\n      length\n    }\n    videoGameSpecifics {\n      platforms\n    }\n  }\n}": types.MediaDetailsDocument,
+    "query KoreaderDocuments {\n  koreaderDocuments {\n    id\n    document\n    metadataId\n    percentage\n    device\n    updatedOn\n  }\n}": types.KoreaderDocumentsDocument,
+    "query LatestUserSummary {\n  latestUserSummary {\n    calculatedOn\n    fitness {\n      measurementsRecorded\n      workoutsRecorded\n      totalVolume\n      totalDistance\n      personalBestsAchieved\n    }\n    media {\n      reviewsPosted\n      creatorsInteractedWith\n      favorites\n      manga {\n        chapters\n        read\n      }\n      books {\n        pages\n        read\n        runtime\n      }\n      movies {\n        runtime\n        watched\n      }\n      anime {\n        episodes\n        watched\n      }\n      podcasts {\n        runtime\n        played\n        playedEpisodes\n      }\n      visualNovels {\n        played\n        runtime\n      }\n      articles {\n        read\n        runtime\n      }\n      music {\n        listened\n        runtime\n      }\n      boardGames {\n        played\n        runtime\n      }\n      comics {\n        issues\n        read\n      }\n      videoGames {\n        played\n      }\n      shows {\n        runtime\n        watchedEpisodes\n        watchedSeasons\n        watched\n      }\n      audioBooks {\n        runtime\n        played\n      }\n    }\n  }\n}": types.LatestUserSummaryDocument,
+    "query MediaDetails($metadataId: Int!) {\n  mediaDetails(metadataId: $metadataId) {\n    title\n    description\n    identifier\n    lot\n    source\n    isNsfw\n    creators {\n      name\n      items {\n        id\n        name\n        image\n      }\n    }\n    assets {\n      images\n      videos {\n        videoId\n        source\n      }\n    }\n    providerRating\n    publishYear\n    publishDate\n    genres\n    sourceUrl\n    group {\n      id\n      name\n      part\n    }\n    suggestions {\n      lot\n      source\n      identifier\n      title\n      image\n      metadataId\n    }\n    animeSpecifics {\n      episodes\n    }\n    audioBookSpecifics {\n      runtime\n    }\n    boardGameSpecifics {\n      minPlayers\n      maxPlayers\n      playtime\n    }\n    bookSpecifics {\n      pages\n    }\n    comicSpecifics {\n      issues\n    }\n    movieSpecifics {\n      runtime\n    }\n    mangaSpecifics {\n      volumes\n      chapters\n    }\n    podcastSpecifics {\n      episodes {\n        title\n        overview\n        thumbnail\n        number\n        runtime\n        publishDate\n      }\n      totalEpisodes\n    }\n    showSpecifics {\n      seasons {\n        seasonNumber\n        name\n        overview\n        backdropImages\n        posterImages\n        episodes {\n          id\n          name\n          posterImages\n          episodeNumber\n          publishDate\n          name\n          overview\n          runtime\n        }\n      }\n    }\n    articleSpecifics {\n      siteName\n      words\n      readingTime\n    }\n    musicSpecifics {\n      tracks\n      duration\n    }\n    visualNovelSpecifics {\n      length\n    }\n    videoGameSpecifics {\n      platforms\n      achievements\n    }\n  }\n}": types.MediaDetailsDocument,
     "query MediaList($input: MediaListInput!) {\n  mediaList(input: $input) {\n    details {\n      total\n      nextPage\n    }\n    items {\n      averageRating\n      data {\n        identifier\n        title\n        image\n        publishYear\n      }\n    }\n  }\n}": types.MediaListDocument,
     "query MediaSearch($lot: MetadataLot!, $source: MetadataSource!, $input: SearchInput!) {\n  mediaSearch(lot: $lot, source: $source, input: $input) {\n    details {\n      total\n      nextPage\n    }\n    items {\n      databaseId\n      item {\n        identifier\n        title\n        image\n        publishYear\n      }\n    }\n  }\n}": types.MediaSearchDocument,
     "query MediaSourcesForLot($lot: MetadataLot!) {\n  mediaSourcesForLot(lot: $lot)\n}": types.MediaSourcesForLotDocument,
     "query MetadataGroupDetails($metadataGroupId: Int!) {\n  metadataGroupDetails(metadataGroupId: $metadataGroupId) {\n    details {\n      id\n      title\n      lot\n      source\n      displayImages\n      parts\n    }\n    sourceUrl\n    contents {\n      identifier\n      title\n      image\n      metadataId\n      lot\n      source\n    }\n  }\n}": types.MetadataGroupDetailsDocument,
     "query MetadataGroupsList($input: SearchInput!) {\n  metadataGroupsList(input: $input) {\n    details {\n      total\n      nextPage\n    }\n    items {\n      id\n      title\n      lot\n      parts\n      image\n    }\n  }\n}": types.MetadataGroupsListDocument,
+    "query PendingSeenCompanions {\n  pendingSeenCompanions {\n    companionId\n    taggedBy\n    metadataId\n    metadataTitle\n    seen {\n      id\n      state\n      finishedOn\n      showInformation {\n        episode\n        season\n      }\n      podcastInformation {\n        episode\n      }\n    }\n  }\n}": types.PendingSeenCompanionsDocument,
     "query ProvidersLanguageInformation {\n  providersLanguageInformation {\n    supported\n    default\n    source\n  }\n}": types.ProvidersLanguageInformationDocument,
     "query Review($reviewId: Int!) {\n  review(reviewId: $reviewId) {\n    rating\n    text\n    visibility\n    spoiler\n    showSeason\n    showEpisode\n    podcastEpisode\n  }\n}": types.ReviewDocument,
     "fragment CalendarEventPart on GraphqlCalendarEvent {\n  calendarEventId\n  metadataId\n  metadataTitle\n  metadataLot\n  metadataImage\n  date\n  showSeasonNumber\n  showEpisodeNumber\n  podcastEpisodeNumber\n}\n\nquery UserCalendarEvents($input: UserCalendarEventInput!) {\n  userCalendarEvents(input: $input) {\n    date\n    events {\n      ...CalendarEventPart\n    }\n  }\n}": types.CalendarEventPartFragmentDoc,
     "query UserCreatorDetails($creatorId: Int!) {\n  userCreatorDetails(creatorId: $creatorId) {\n    reviews {\n      id\n      rating\n      text\n      spoiler\n      visibility\n      postedOn\n      postedBy {\n        id\n        name\n      }\n      comments {\n        id\n        text\n        createdOn\n        user {\n          id\n          name\n        }\n        likedBy\n      }\n    }\n  }\n}": types.UserCreatorDetailsDocument,
     "query UserDetails {\n  userDetails {\n    __typename\n    ... on User {\n      id\n      email\n      name\n      lot\n    }\n  }\n}": types.UserDetailsDocument,
     "query UserExerciseDetails($exerciseId: Int!) {\n  userExerciseDetails(exerciseId: $exerciseId) {\n    history {\n      workoutId\n      workoutName\n      workoutTime\n      sets {\n        lot\n        statistic {\n          duration\n          distance\n          reps\n          weight\n        }\n      }\n    }\n    details {\n      exerciseId\n      numTimesPerformed\n      lastUpdatedOn\n      extraInformation {\n        lifetimeStats {\n          weight\n          reps\n          distance\n          duration\n        }\n        personalBests {\n          lot\n          sets {\n            workoutId\n            setIdx\n            data {\n              statistic {\n                duration\n                distance\n                reps\n                weight\n              }\n              lot\n              personalBests\n            }\n          }\n        }\n      }\n    }\n  }\n}": types.UserExerciseDetailsDocument,
-    "query UserIntegrations {\n  userIntegrations {\n    id\n    lot\n    description\n    timestamp\n    slug\n  }\n}": types.UserIntegrationsDocument,
+    "query UserIntegrations {\n  userIntegrations {\n    id\n    lot\n    description\n    timestamp\n    slug\n    secret\n  }\n}": types.UserIntegrationsDocument,
     "query UserMeasurementsList($input: UserMeasurementsListInput!) {\n  userMeasurementsList(input: $input) {\n    timestamp\n    name\n    comment\n    stats {\n      weight\n      bodyMassIndex\n      totalBodyWater\n      muscle\n      leanBodyMass\n      bodyFat\n      boneMass\n      visceralFat\n      waistCircumference\n      waistToHeightRatio\n      hipCircumference\n      waistToHipRatio\n      chestCircumference\n      thighCircumference\n      bicepsCircumference\n      neckCircumference\n      bodyFatCaliper\n      chestSkinfold\n      abdominalSkinfold\n      thighSkinfold\n      basalMetabolicRate\n      totalDailyEnergyExpenditure\n      calories\n      custom\n    }\n  }\n}": types.UserMeasurementsListDocument,
-    "fragment SeenPart on Seen {\n  id\n  progress\n  state\n  startedOn\n  finishedOn\n  lastUpdatedOn\n  numTimesUpdated\n  showInformation {\n    episode\n    season\n  }\n  podcastInformation {\n    episode\n  }\n}\n\nquery UserMediaDetails($metadataId: Int!) {\n  userMediaDetails(metadataId: $metadataId) {\n    collections {\n      id\n      name\n    }\n    inProgress {\n      ...SeenPart\n    }\n    history {\n      ...SeenPart\n    }\n    averageRating\n    reviews {\n      id\n      rating\n      text\n      spoiler\n      visibility\n      showSeason\n      showEpisode\n      podcastEpisode\n      postedOn\n      postedBy {\n        id\n        name\n      }\n      comments {\n        id\n        text\n        createdOn\n        user {\n          id\n          name\n        }\n        likedBy\n      }\n    }\n    reminder {\n      remindOn\n      message\n    }\n    isMonitored\n    seenBy\n    nextEpisode {\n      seasonNumber\n      episodeNumber\n    }\n  }\n}": types.SeenPartFragmentDoc,
+    "fragment SeenPart on Seen {\n  id\n  progress\n  state\n  startedOn\n  finishedOn\n  lastUpdatedOn\n  numTimesUpdated\n  rating\n  context\n  showInformation {\n    episode\n    season\n  }\n  podcastInformation {\n    episode\n  }\n  attachments {\n    id\n    url\n  }\n  companions {\n    id\n    name\n    userId\n    mirroredSeenId\n  }\n}\n\nquery UserMediaDetails($metadataId: Int!) {\n  userMediaDetails(metadataId: $metadataId) {\n    collections {\n      id\n      name\n    }\n    inProgress {\n      ...SeenPart\n    }\n    history {\n      ...SeenPart\n    }\n    averageRating\n    averageSeenRating\n    reviews {\n      id\n      rating\n      text\n      spoiler\n      visibility\n      showSeason\n      showEpisode\n      podcastEpisode\n      postedOn\n      postedBy {\n        id\n        name\n      }\n      comments {\n        id\n        text\n        createdOn\n        user {\n          id\n          name\n        }\n        likedBy\n      }\n    }\n    reminder {\n      remindOn\n      message\n    }\n    isMonitored\n    isFavorite\n    seenBy\n    playtime\n    nextEpisode {\n      seasonNumber\n      episodeNumber\n    }\n  }\n}": types.SeenPartFragmentDoc,
     "query UserNotificationPlatforms {\n  userNotificationPlatforms {\n    id\n    description\n    timestamp\n  }\n}": types.UserNotificationPlatformsDocument,
-    "query UserPreferences {\n  userPreferences {\n    general {\n      reviewScale\n      displayNsfw\n    }\n    fitness {\n      measurements {\n        custom {\n          name\n          dataType\n        }\n        inbuilt {\n          weight\n          bodyMassIndex\n          totalBodyWater\n          muscle\n          leanBodyMass\n          bodyFat\n          boneMass\n          visceralFat\n          waistCircumference\n          waistToHeightRatio\n          hipCircumference\n          waistToHipRatio\n          chestCircumference\n          thighCircumference\n          bicepsCircumference\n          neckCircumference\n          bodyFatCaliper\n          chestSkinfold\n          abdominalSkinfold\n          thighSkinfold\n          basalMetabolicRate\n          totalDailyEnergyExpenditure\n          calories\n        }\n      }\n      exercises {\n        saveHistory\n        unitSystem\n      }\n    }\n    notifications {\n      episodeReleased\n      episodeNameChanged\n      statusChanged\n      releaseDateChanged\n      numberOfSeasonsChanged\n      numberOfChaptersOrEpisodesChanged\n    }\n    featuresEnabled {\n      fitness {\n        enabled\n        measurements\n      }\n      media {\n        enabled\n        anime\n        audioBook\n        book\n        manga\n        movie\n        podcast\n        show\n        videoGame\n        visualNovel\n      }\n    }\n  }\n}": types.UserPreferencesDocument,
+    "query UserPreferences {\n  userPreferences {\n    general {\n      reviewScale\n      nsfwDisplay\n    }\n    timeEstimation {\n      podcastPlaybackSpeed\n      defaultEpisodeRuntime\n      pagesPerHour\n    }\n    fitness {\n      measurements {\n        custom {\n          name\n          dataType\n        }\n        inbuilt {\n          weight\n          bodyMassIndex\n          totalBodyWater\n          muscle\n          leanBodyMass\n          bodyFat\n          boneMass\n          visceralFat\n          waistCircumference\n          waistToHeightRatio\n          hipCircumference\n          waistToHipRatio\n          chestCircumference\n          thighCircumference\n          bicepsCircumference\n          neckCircumference\n          bodyFatCaliper\n          chestSkinfold\n          abdominalSkinfold\n          thighSkinfold\n          basalMetabolicRate\n          totalDailyEnergyExpenditure\n          calories\n        }\n      }\n      exercises {\n        saveHistory\n        unitSystem\n      }\n      equipment {\n        plates\n        bars\n        dumbbellIncrement\n        available\n      }\n    }\n    notifications {\n      episodeReleased\n      episodeNameChanged\n      statusChanged\n      releaseDateChanged\n      numberOfSeasonsChanged\n      numberOfChaptersOrEpisodesChanged\n      personalBestAchieved\n    }\n    featuresEnabled {\n      fitness {\n        enabled\n        measurements\n      }\n      media {\n        enabled\n        anime\n        article\n        audioBook\n        boardGame\n        book\n        comic\n        manga\n        movie\n        music\n        podcast\n        show\n        videoGame\n        visualNovel\n      }\n    }\n    seenContexts {\n      anime\n      article\n      audioBook\n      boardGame\n      book\n      comic\n      manga\n      movie\n      music\n      podcast\n      show\n      videoGame\n      visualNovel\n    }\n  }\n}": types.UserPreferencesDocument,
     "query UserUpcomingCalendarEvents($input: UserUpcomingCalendarEventInput!) {\n  userUpcomingCalendarEvents(input: $input) {\n    ...CalendarEventPart\n  }\n}": types.UserUpcomingCalendarEventsDocument,
     "query UsersList {\n  usersList {\n    id\n    name\n    lot\n  }\n}": types.UsersListDocument,
 };
@@ -103,6 +120,14 @@ export function graphql(source: string): unknown;
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
 export function graphql(source: "mutation AddMediaToCollection($input: AddMediaToCollection!) {\n  addMediaToCollection(input: $input)\n}"): (typeof documents)["mutation AddMediaToCollection($input: AddMediaToCollection!) {\n  addMediaToCollection(input: $input)\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "mutation AddSeenAttachment($input: AddSeenAttachmentInput!) {\n  addSeenAttachment(input: $input) {\n    id\n  }\n}"): (typeof documents)["mutation AddSeenAttachment($input: AddSeenAttachmentInput!) {\n  addSeenAttachment(input: $input) {\n    id\n  }\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "mutation AddSeenCompanion($input: AddSeenCompanionInput!) {\n  addSeenCompanion(input: $input) {\n    id\n  }\n}"): (typeof documents)["mutation AddSeenCompanion($input: AddSeenCompanionInput!) {\n  addSeenCompanion(input: $input) {\n    id\n  }\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
@@ -123,6 +148,10 @@ export function graphql(source: "mutation CreateMediaReminder($input: CreateMedi
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
 export function graphql(source: "mutation CreateOrUpdateCollection($input: CreateOrUpdateCollectionInput!) {\n  createOrUpdateCollection(input: $input) {\n    id\n  }\n}"): (typeof documents)["mutation CreateOrUpdateCollection($input: CreateOrUpdateCollectionInput!) {\n  createOrUpdateCollection(input: $input) {\n    id\n  }\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "mutation CreateOrUpdateHighlight($input: CreateOrUpdateHighlightInput!) {\n  createOrUpdateHighlight(input: $input) {\n    id\n  }\n}"): (typeof documents)["mutation CreateOrUpdateHighlight($input: CreateOrUpdateHighlightInput!) {\n  createOrUpdateHighlight(input: $input) {\n    id\n  }\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
@@ -151,6 +180,10 @@ export function graphql(source: "mutation CreateUserYankIntegration($input: Crea
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
 export function graphql(source: "mutation DeleteCollection($collectionName: String!) {\n  deleteCollection(collectionName: $collectionName)\n}"): (typeof documents)["mutation DeleteCollection($collectionName: String!) {\n  deleteCollection(collectionName: $collectionName)\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "mutation DeleteHighlight($highlightId: Int!) {\n  deleteHighlight(highlightId: $highlightId)\n}"): (typeof documents)["mutation DeleteHighlight($highlightId: Int!) {\n  deleteHighlight(highlightId: $highlightId)\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
@@ -159,6 +192,14 @@ export function graphql(source: "mutation DeleteMediaReminder($metadataId: Int!)
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
 export function graphql(source: "mutation DeleteReview($reviewId: Int!) {\n  deleteReview(reviewId: $reviewId)\n}"): (typeof documents)["mutation DeleteReview($reviewId: Int!) {\n  deleteReview(reviewId: $reviewId)\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "mutation DeleteSeenAttachment($attachmentId: Int!) {\n  deleteSeenAttachment(attachmentId: $attachmentId)\n}"): (typeof documents)["mutation DeleteSeenAttachment($attachmentId: Int!) {\n  deleteSeenAttachment(attachmentId: $attachmentId)\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "mutation DeleteSeenCompanion($companionId: Int!) {\n  deleteSeenCompanion(companionId: $companionId)\n}"): (typeof documents)["mutation DeleteSeenCompanion($companionId: Int!) {\n  deleteSeenCompanion(companionId: $companionId)\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
@@ -195,6 +236,10 @@ export function graphql(source: "mutation DeployUpdateMetadataJob($metadataId: I
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
 export function graphql(source: "mutation GenerateAuthToken {\n  generateAuthToken\n}"): (typeof documents)["mutation GenerateAuthToken {\n  generateAuthToken\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "mutation LinkKoreaderDocument($documentId: Int!, $metadataId: Int!) {\n  linkKoreaderDocument(documentId: $documentId, metadataId: $metadataId)\n}"): (typeof documents)["mutation LinkKoreaderDocument($documentId: Int!, $metadataId: Int!) {\n  linkKoreaderDocument(documentId: $documentId, metadataId: $metadataId)\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
@@ -231,10 +276,30 @@ export function graphql(source: "mutation RegisterUser($input: UserInput!) {\n
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
 export function graphql(source: "mutation RemoveMediaFromCollection($metadataId: Int!, $collectionName: String!) {\n  removeMediaFromCollection(\n    metadataId: $metadataId\n    collectionName: $collectionName\n  ) {\n    id\n  }\n}"): (typeof documents)["mutation RemoveMediaFromCollection($metadataId: Int!, $collectionName: String!) {\n  removeMediaFromCollection(\n    metadataId: $metadataId\n    collectionName: $collectionName\n  ) {\n    id\n  }\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "mutation RequestUserEmailVerification($username: String!) {\n  requestUserEmailVerification(username: $username)\n}"): (typeof documents)["mutation RequestUserEmailVerification($username: String!) {\n  requestUserEmailVerification(username: $username)\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "mutation RequestUserPasswordReset($username: String!) {\n  requestUserPasswordReset(username: $username)\n}"): (typeof documents)["mutation RequestUserPasswordReset($username: String!) {\n  requestUserPasswordReset(username: $username)\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "mutation ResetUserPassword($input: ResetUserPasswordInput!) {\n  resetUserPassword(input: $input)\n}"): (typeof documents)["mutation ResetUserPassword($input: ResetUserPasswordInput!) {\n  resetUserPassword(input: $input)\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "mutation RespondToSeenCompanion($companionId: Int!, $accept: Boolean!) {\n  respondToSeenCompanion(companionId: $companionId, accept: $accept)\n}"): (typeof documents)["mutation RespondToSeenCompanion($companionId: Int!, $accept: Boolean!) {\n  respondToSeenCompanion(companionId: $companionId, accept: $accept)\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
 export function graphql(source: "mutation TestUserNotificationPlatforms {\n  testUserNotificationPlatforms\n}"): (typeof documents)["mutation TestUserNotificationPlatforms {\n  testUserNotificationPlatforms\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "mutation ToggleMediaFavorite($metadataId: Int!) {\n  toggleMediaFavorite(metadataId: $metadataId)\n}"): (typeof documents)["mutation ToggleMediaFavorite($metadataId: Int!) {\n  toggleMediaFavorite(metadataId: $metadataId)\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
@@ -251,10 +316,18 @@ export function graphql(source: "mutation UpdateUser($input: UpdateUserInput!) {
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
 export function graphql(source: "mutation UpdateUserPreference($input: UpdateUserPreferenceInput!) {\n  updateUserPreference(input: $input)\n}"): (typeof documents)["mutation UpdateUserPreference($input: UpdateUserPreferenceInput!) {\n  updateUserPreference(input: $input)\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "mutation VerifyUserEmail($token: String!) {\n  verifyUserEmail(token: $token)\n}"): (typeof documents)["mutation VerifyUserEmail($token: String!) {\n  verifyUserEmail(token: $token)\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
 export function graphql(source: "mutation YankIntegrationData {\n  yankIntegrationData\n}"): (typeof documents)["mutation YankIntegrationData {\n  yankIntegrationData\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "query Announcements {\n  announcements {\n    id\n    message\n    severity\n    createdOn\n    expiresOn\n  }\n}"): (typeof documents)["query Announcements {\n  announcements {\n    id\n    message\n    severity\n    createdOn\n    expiresOn\n  }\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
@@ -262,7 +335,7 @@ export function graphql(source: "query CollectionContents($input: CollectionCont
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
-export function graphql(source: "query Collections($input: CollectionInput) {\n  collections(input: $input) {\n    id\n    name\n    description\n    visibility\n    numItems\n  }\n}"): (typeof documents)["query Collections($input: CollectionInput) {\n  collections(input: $input) {\n    id\n    name\n    description\n    visibility\n    numItems\n  }\n}"];
+export function graphql(source: "query Collections($input: CollectionInput) {\n  collections(input: $input) {\n    id\n    name\n    description\n    visibility\n    numItems\n    actions {\n      lot\n      collectionName\n    }\n  }\n}"): (typeof documents)["query Collections($input: CollectionInput) {\n  collections(input: $input) {\n    id\n    name\n    description\n    visibility\n    numItems\n    actions {\n      lot\n      collectionName\n    }\n  }\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
@@ -270,7 +343,7 @@ export function graphql(source: "query CoreDetails {\n  coreDetails {\n    versi
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
-export function graphql(source: "query CoreEnabledFeatures {\n  coreEnabledFeatures {\n    fileStorage\n    signupAllowed\n  }\n}"): (typeof documents)["query CoreEnabledFeatures {\n  coreEnabledFeatures {\n    fileStorage\n    signupAllowed\n  }\n}"];
+export function graphql(source: "query CoreEnabledFeatures {\n  coreEnabledFeatures {\n    fileStorage\n    signupAllowed\n    passwordResetAllowed\n    emailVerificationRequired\n  }\n}"): (typeof documents)["query CoreEnabledFeatures {\n  coreEnabledFeatures {\n    fileStorage\n    signupAllowed\n    passwordResetAllowed\n    emailVerificationRequired\n  }\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
@@ -295,6 +368,10 @@ export function graphql(source: "query ExercisesList($input: ExercisesListInput!
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
 export function graphql(source: "query GetPresignedUrl($key: String!) {\n  getPresignedUrl(key: $key)\n}"): (typeof documents)["query GetPresignedUrl($key: String!) {\n  getPresignedUrl(key: $key)\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "query Highlights($metadataId: Int) {\n  highlights(metadataId: $metadataId) {\n    id\n    metadataId\n    text\n    page\n    chapter\n    note\n  }\n}"): (typeof documents)["query Highlights($metadataId: Int) {\n  highlights(metadataId: $metadataId) {\n    id\n    metadataId\n    text\n    page\n    chapter\n    note\n  }\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
@@ -302,11 +379,15 @@ export function graphql(source: "query ImportReports {\n  importReports {\n    i
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
-export function graphql(source: "query LatestUserSummary {\n  latestUserSummary {\n    calculatedOn\n    fitness {\n      measurementsRecorded\n      workoutsRecorded\n    }\n    media {\n      reviewsPosted\n      creatorsInteractedWith\n      manga {\n        chapters\n        read\n      }\n      books {\n        pages\n        read\n      }\n      movies {\n        runtime\n        watched\n      }\n      anime {\n        episodes\n        watched\n      }\n      podcasts {\n        runtime\n        played\n        playedEpisodes\n      }\n      visualNovels {\n        played\n        runtime\n      }\n      videoGames {\n        played\n      }\n      shows {\n        runtime\n        watchedEpisodes\n        watchedSeasons\n        watched\n      }\n      audioBooks {\n        runtime\n        played\n      }\n    }\n  }\n}"): (typeof documents)["query LatestUserSummary {\n  latestUserSummary {\n    calculatedOn\n    fitness {\n      measurementsRecorded\n      workoutsRecorded\n    }\n    media {\n      reviewsPosted\n      creatorsInteractedWith\n      manga {\n        chapters\n        read\n      }\n      books {\n        pages\n        read\n      }\n      movies {\n        runtime\n        watched\n      }\n      anime {\n        episodes\n        watched\n      }\n      podcasts {\n        runtime\n        played\n        playedEpisodes\n      }\n      visualNovels {\n        played\n        runtime\n      }\n      videoGames {\n        played\n      }\n      shows {\n        runtime\n        watchedEpisodes\n        watchedSeasons\n        watched\n      }\n      audioBooks {\n        runtime\n        played\n      }\n    }\n  }\n}"];
+export function graphql(source: "query KoreaderDocuments {\n  koreaderDocuments {\n    id\n    document\n    metadataId\n    percentage\n    device\n    updatedOn\n  }\n}"): (typeof documents)["query KoreaderDocuments {\n  koreaderDocuments {\n    id\n    document\n    metadataId\n    percentage\n    device\n    updatedOn\n  }\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
-export function graphql(source: "query MediaDetails($metadataId: Int!) {\n  mediaDetails(metadataId: $metadataId) {\n    title\n    description\n    identifier\n    lot\n    source\n    isNsfw\n    creators {\n      name\n      items {\n        id\n        name\n        image\n      }\n    }\n    assets {\n      images\n      videos {\n        videoId\n        source\n      }\n    }\n    providerRating\n    publishYear\n    publishDate\n    genres\n    sourceUrl\n    group {\n      id\n      name\n      part\n    }\n    suggestions {\n      lot\n      source\n      identifier\n      title\n      image\n      metadataId\n    }\n    animeSpecifics {\n      episodes\n    }\n    audioBookSpecifics {\n      runtime\n    }\n    bookSpecifics {\n      pages\n    }\n    movieSpecifics {\n      runtime\n    }\n    mangaSpecifics {\n      volumes\n      chapters\n    }\n    podcastSpecifics {\n      episodes {\n        title\n        overview\n        thumbnail\n        number\n        runtime\n        publishDate\n      }\n      totalEpisodes\n    }\n    showSpecifics {\n      seasons {\n        seasonNumber\n        name\n        overview\n        backdropImages\n        posterImages\n        episodes {\n          id\n          name\n          posterImages\n          episodeNumber\n          publishDate\n          name\n          overview\n          runtime\n        }\n      }\n    }\n    visualNovelSpecifics {\n      length\n    }\n    videoGameSpecifics {\n      platforms\n    }\n  }\n}"): (typeof documents)["query MediaDetails($metadataId: Int!) {\n  mediaDetails(metadataId: $metadataId) {\n    title\n    description\n    identifier\n    lot\n    source\n    isNsfw\n    creators {\n      name\n      items {\n        id\n        name\n        image\n      }\n    }\n    assets {\n      images\n      videos {\n        videoId\n        source\n      }\n    }\n    providerRating\n    publishYear\n    publishDate\n    genres\n    sourceUrl\n    group {\n      id\n      name\n      part\n    }\n    suggestions {\n      lot\n      source\n      identifier\n      title\n      image\n      metadataId\n    }\n    animeSpecifics {\n      episodes\n    }\n    audioBookSpecifics {\n      runtime\n    }\n    bookSpecifics {\n      pages\n    }\n    movieSpecifics {\n      runtime\n    }\n    mangaSpecifics {\n      volumes\n      chapters\n    }\n    podcastSpecifics {\n      episodes {\n        title\n        overview\n        thumbnail\n        number\n        runtime\n        publishDate\n      }\n      totalEpisodes\n    }\n    showSpecifics {\n      seasons {\n        seasonNumber\n        name\n        overview\n        backdropImages\n        posterImages\n        episodes {\n          id\n          name\n          posterImages\n          episodeNumber\n          publishDate\n          name\n          overview\n          runtime\n        }\n      }\n    }\n    visualNovelSpecifics {\n      length\n    }\n    videoGameSpecifics {\n      platforms\n    }\n  }\n}"];
+export function graphql(source: "query LatestUserSummary {\n  latestUserSummary {\n    calculatedOn\n    fitness {\n      measurementsRecorded\n      workoutsRecorded\n      totalVolume\n      totalDistance\n      personalBestsAchieved\n    }\n    media {\n      reviewsPosted\n      creatorsInteractedWith\n      favorites\n      manga {\n        chapters\n        read\n      }\n      books {\n        pages\n        read\n        runtime\n      }\n      movies {\n        runtime\n        watched\n      }\n      anime {\n        episodes\n        watched\n      }\n      podcasts {\n        runtime\n        played\n        playedEpisodes\n      }\n      visualNovels {\n        played\n        runtime\n      }\n      articles {\n        read\n        runtime\n      }\n      music {\n        listened\n        runtime\n      }\n      boardGames {\n        played\n        runtime\n      }\n      comics {\n        issues\n        read\n      }\n      videoGames {\n        played\n      }\n      shows {\n        runtime\n        watchedEpisodes\n        watchedSeasons\n        watched\n      }\n      audioBooks {\n        runtime\n        played\n      }\n    }\n  }\n}"): (typeof documents)["query LatestUserSummary {\n  latestUserSummary {\n    calculatedOn\n    fitness {\n      measurementsRecorded\n      workoutsRecorded\n      totalVolume\n      totalDistance\n      personalBestsAchieved\n    }\n    media {\n      reviewsPosted\n      creatorsInteractedWith\n      favorites\n      manga {\n        chapters\n        read\n      }\n      books {\n        pages\n        read\n        runtime\n      }\n      movies {\n        runtime\n        watched\n      }\n      anime {\n        episodes\n        watched\n      }\n      podcasts {\n        runtime\n        played\n        playedEpisodes\n      }\n      visualNovels {\n        played\n        runtime\n      }\n      articles {\n        read\n        runtime\n      }\n      music {\n        listened\n        runtime\n      }\n      boardGames {\n        played\n        runtime\n      }\n      comics {\n        issues\n        read\n      }\n      videoGames {\n        played\n      }\n      shows {\n        runtime\n        watchedEpisodes\n        watchedSeasons\n        watched\n      }\n      audioBooks {\n        runtime\n        played\n      }\n    }\n  }\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "query MediaDetails($metadataId: Int!) {\n  mediaDetails(metadataId: $metadataId) {\n    title\n    description\n    identifier\n    lot\n    source\n    isNsfw\n    creators {\n      name\n      items {\n        id\n        name\n        image\n      }\n    }\n    assets {\n      images\n      videos {\n        videoId\n        source\n      }\n    }\n    providerRating\n    publishYear\n    publishDate\n    genres\n    sourceUrl\n    group {\n      id\n      name\n      part\n    }\n    suggestions {\n      lot\n      source\n      identifier\n      title\n      image\n      metadataId\n    }\n    animeSpecifics {\n      episodes\n    }\n    audioBookSpecifics {\n      runtime\n    }\n    boardGameSpecifics {\n      minPlayers\n      maxPlayers\n      playtime\n    }\n    bookSpecifics {\n      pages\n    }\n    comicSpecifics {\n      issues\n    }\n    movieSpecifics {\n      runtime\n    }\n    mangaSpecifics {\n      volumes\n      chapters\n    }\n    podcastSpecifics {\n      episodes {\n        title\n        overview\n        thumbnail\n        number\n        runtime\n        publishDate\n      }\n      totalEpisodes\n    }\n    showSpecifics {\n      seasons {\n        seasonNumber\n        name\n        overview\n        backdropImages\n        posterImages\n        episodes {\n          id\n          name\n          posterImages\n          episodeNumber\n          publishDate\n          name\n          overview\n          runtime\n        }\n      }\n    }\n    articleSpecifics {\n      siteName\n      words\n      readingTime\n    }\n    musicSpecifics {\n      tracks\n      duration\n    }\n    visualNovelSpecifics {\n      length\n    }\n    videoGameSpecifics {\n      platforms\n      achievements\n    }\n  }\n}"): (typeof documents)["query MediaDetails($metadataId: Int!) {\n  mediaDetails(metadataId: $metadataId) {\n    title\n    description\n    identifier\n    lot\n    source\n    isNsfw\n    creators {\n      name\n      items {\n        id\n        name\n        image\n      }\n    }\n    assets {\n      images\n      videos {\n        videoId\n        source\n      }\n    }\n    providerRating\n    publishYear\n    publishDate\n    genres\n    sourceUrl\n    group {\n      id\n      name\n      part\n    }\n    suggestions {\n      lot\n      source\n      identifier\n      title\n      image\n      metadataId\n    }\n    animeSpecifics {\n      episodes\n    }\n    audioBookSpecifics {\n      runtime\n    }\n    boardGameSpecifics {\n      minPlayers\n      maxPlayers\n      playtime\n    }\n    bookSpecifics {\n      pages\n    }\n    comicSpecifics {\n      issues\n    }\n    movieSpecifics {\n      runtime\n    }\n    mangaSpecifics {\n      volumes\n      chapters\n    }\n    podcastSpecifics {\n      episodes {\n        title\n        overview\n        thumbnail\n        number\n        runtime\n        publishDate\n      }\n      totalEpisodes\n    }\n    showSpecifics {\n      seasons {\n        seasonNumber\n        name\n        overview\n        backdropImages\n        posterImages\n        episodes {\n          id\n          name\n          posterImages\n          episodeNumber\n          publishDate\n          name\n          overview\n          runtime\n        }\n      }\n    }\n    articleSpecifics {\n      siteName\n      words\n      readingTime\n    }\n    musicSpecifics {\n      tracks\n      duration\n    }\n    visualNovelSpecifics {\n      length\n    }\n    videoGameSpecifics {\n      platforms\n      achievements\n    }\n  }\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
@@ -327,6 +408,10 @@ export function graphql(source: "query MetadataGroupDetails($metadataGroupId: In
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
 export function graphql(source: "query MetadataGroupsList($input: SearchInput!) {\n  metadataGroupsList(input: $input) {\n    details {\n      total\n      nextPage\n    }\n    items {\n      id\n      title\n      lot\n      parts\n      image\n    }\n  }\n}"): (typeof documents)["query MetadataGroupsList($input: SearchInput!) {\n  metadataGroupsList(input: $input) {\n    details {\n      total\n      nextPage\n    }\n    items {\n      id\n      title\n      lot\n      parts\n      image\n    }\n  }\n}"];
+/**
+ * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
+ */
+export function graphql(source: "query PendingSeenCompanions {\n  pendingSeenCompanions {\n    companionId\n    taggedBy\n    metadataId\n    metadataTitle\n    seen {\n      id\n      state\n      finishedOn\n      showInformation {\n        episode\n        season\n      }\n      podcastInformation {\n        episode\n      }\n    }\n  }\n}"): (typeof documents)["query PendingSeenCompanions {\n  pendingSeenCompanions {\n    companionId\n    taggedBy\n    metadataId\n    metadataTitle\n    seen {\n      id\n      state\n      finishedOn\n      showInformation {\n        episode\n        season\n      }\n      podcastInformation {\n        episode\n      }\n    }\n  }\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
@@ -354,7 +439,7 @@ export function graphql(source: "query UserExerciseDetails($exerciseId: Int!) {\
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
-export function graphql(source: "query UserIntegrations {\n  userIntegrations {\n    id\n    lot\n    description\n    timestamp\n    slug\n  }\n}"): (typeof documents)["query UserIntegrations {\n  userIntegrations {\n    id\n    lot\n    description\n    timestamp\n    slug\n  }\n}"];
+export function graphql(source: "query UserIntegrations {\n  userIntegrations {\n    id\n    lot\n    description\n    timestamp\n    slug\n    secret\n  }\n}"): (typeof documents)["query UserIntegrations {\n  userIntegrations {\n    id\n    lot\n    description\n    timestamp\n    slug\n    secret\n  }\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
@@ -362,7 +447,7 @@ export function graphql(source: "query UserMeasurementsList($input: UserMeasurem
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
-export function graphql(source: "fragment SeenPart on Seen {\n  id\n  progress\n  state\n  startedOn\n  finishedOn\n  lastUpdatedOn\n  numTimesUpdated\n  showInformation {\n    episode\n    season\n  }\n  podcastInformation {\n    episode\n  }\n}\n\nquery UserMediaDetails($metadataId: Int!) {\n  userMediaDetails(metadataId: $metadataId) {\n    collections {\n      id\n      name\n    }\n    inProgress {\n      ...SeenPart\n    }\n    history {\n      ...SeenPart\n    }\n    averageRating\n    reviews {\n      id\n      rating\n      text\n      spoiler\n      visibility\n      showSeason\n      showEpisode\n      podcastEpisode\n      postedOn\n      postedBy {\n        id\n        name\n      }\n      comments {\n        id\n        text\n        createdOn\n        user {\n          id\n          name\n        }\n        likedBy\n      }\n    }\n    reminder {\n      remindOn\n      message\n    }\n    isMonitored\n    seenBy\n    nextEpisode {\n      seasonNumber\n      episodeNumber\n    }\n  }\n}"): (typeof documents)["fragment SeenPart on Seen {\n  id\n  progress\n  state\n  startedOn\n  finishedOn\n  lastUpdatedOn\n  numTimesUpdated\n  showInformation {\n    episode\n    season\n  }\n  podcastInformation {\n    episode\n  }\n}\n\nquery UserMediaDetails($metadataId: Int!) {\n  userMediaDetails(metadataId: $metadataId) {\n    collections {\n      id\n      name\n    }\n    inProgress {\n      ...SeenPart\n    }\n    history {\n      ...SeenPart\n    }\n    averageRating\n    reviews {\n      id\n      rating\n      text\n      spoiler\n      visibility\n      showSeason\n      showEpisode\n      podcastEpisode\n      postedOn\n      postedBy {\n        id\n        name\n      }\n      comments {\n        id\n        text\n        createdOn\n        user {\n          id\n          name\n        }\n        likedBy\n      }\n    }\n    reminder {\n      remindOn\n      message\n    }\n    isMonitored\n    seenBy\n    nextEpisode {\n      seasonNumber\n      episodeNumber\n    }\n  }\n}"];
+export function graphql(source: "fragment SeenPart on Seen {\n  id\n  progress\n  state\n  startedOn\n  finishedOn\n  lastUpdatedOn\n  numTimesUpdated\n  rating\n  context\n  showInformation {\n    episode\n    season\n  }\n  podcastInformation {\n    episode\n  }\n  attachments {\n    id\n    url\n  }\n  companions {\n    id\n    name\n    userId\n    mirroredSeenId\n  }\n}\n\nquery UserMediaDetails($metadataId: Int!) {\n  userMediaDetails(metadataId: $metadataId) {\n    collections {\n      id\n      name\n    }\n    inProgress {\n      ...SeenPart\n    }\n    history {\n      ...SeenPart\n    }\n    averageRating\n    averageSeenRating\n    reviews {\n      id\n      rating\n      text\n      spoiler\n      visibility\n      showSeason\n      showEpisode\n      podcastEpisode\n      postedOn\n      postedBy {\n        id\n        name\n      }\n      comments {\n        id\n        text\n        createdOn\n        user {\n          id\n          name\n        }\n        likedBy\n      }\n    }\n    reminder {\n      remindOn\n      message\n    }\n    isMonitored\n    isFavorite\n    seenBy\n    playtime\n    nextEpisode {\n      seasonNumber\n      episodeNumber\n    }\n  }\n}"): (typeof documents)["fragment SeenPart on Seen {\n  id\n  progress\n  state\n  startedOn\n  finishedOn\n  lastUpdatedOn\n  numTimesUpdated\n  rating\n  context\n  showInformation {\n    episode\n    season\n  }\n  podcastInformation {\n    episode\n  }\n  attachments {\n    id\n    url\n  }\n  companions {\n    id\n    name\n    userId\n    mirroredSeenId\n  }\n}\n\nquery UserMediaDetails($metadataId: Int!) {\n  userMediaDetails(metadataId: $metadataId) {\n    collections {\n      id\n      name\n    }\n    inProgress {\n      ...SeenPart\n    }\n    history {\n      ...SeenPart\n    }\n    averageRating\n    averageSeenRating\n    reviews {\n      id\n      rating\n      text\n      spoiler\n      visibility\n      showSeason\n      showEpisode\n      podcastEpisode\n      postedOn\n      postedBy {\n        id\n        name\n      }\n      comments {\n        id\n        text\n        createdOn\n        user {\n          id\n          name\n        }\n        likedBy\n      }\n    }\n    reminder {\n      remindOn\n      message\n    }\n    isMonitored\n    isFavorite\n    seenBy\n    playtime\n    nextEpisode {\n      seasonNumber\n      episodeNumber\n    }\n  }\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
@@ -370,7 +455,7 @@ export function graphql(source: "query UserNotificationPlatforms {\n  userNotifi
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
-export function graphql(source: "query UserPreferences {\n  userPreferences {\n    general {\n      reviewScale\n      displayNsfw\n    }\n    fitness {\n      measurements {\n        custom {\n          name\n          dataType\n        }\n        inbuilt {\n          weight\n          bodyMassIndex\n          totalBodyWater\n          muscle\n          leanBodyMass\n          bodyFat\n          boneMass\n          visceralFat\n          waistCircumference\n          waistToHeightRatio\n          hipCircumference\n          waistToHipRatio\n          chestCircumference\n          thighCircumference\n          bicepsCircumference\n          neckCircumference\n          bodyFatCaliper\n          chestSkinfold\n          abdominalSkinfold\n          thighSkinfold\n          basalMetabolicRate\n          totalDailyEnergyExpenditure\n          calories\n        }\n      }\n      exercises {\n        saveHistory\n        unitSystem\n      }\n    }\n    notifications {\n      episodeReleased\n      episodeNameChanged\n      statusChanged\n      releaseDateChanged\n      numberOfSeasonsChanged\n      numberOfChaptersOrEpisodesChanged\n    }\n    featuresEnabled {\n      fitness {\n        enabled\n        measurements\n      }\n      media {\n        enabled\n        anime\n        audioBook\n        book\n        manga\n        movie\n        podcast\n        show\n        videoGame\n        visualNovel\n      }\n    }\n  }\n}"): (typeof documents)["query UserPreferences {\n  userPreferences {\n    general {\n      reviewScale\n      displayNsfw\n    }\n    fitness {\n      measurements {\n        custom {\n          name\n          dataType\n        }\n        inbuilt {\n          weight\n          bodyMassIndex\n          totalBodyWater\n          muscle\n          leanBodyMass\n          bodyFat\n          boneMass\n          visceralFat\n          waistCircumference\n          waistToHeightRatio\n          hipCircumference\n          waistToHipRatio\n          chestCircumference\n          thighCircumference\n          bicepsCircumference\n          neckCircumference\n          bodyFatCaliper\n          chestSkinfold\n          abdominalSkinfold\n          thighSkinfold\n          basalMetabolicRate\n          totalDailyEnergyExpenditure\n          calories\n        }\n      }\n      exercises {\n        saveHistory\n        unitSystem\n      }\n    }\n    notifications {\n      episodeReleased\n      episodeNameChanged\n      statusChanged\n      releaseDateChanged\n      numberOfSeasonsChanged\n      numberOfChaptersOrEpisodesChanged\n    }\n    featuresEnabled {\n      fitness {\n        enabled\n        measurements\n      }\n      media {\n        enabled\n        anime\n        audioBook\n        book\n        manga\n        movie\n        podcast\n        show\n        videoGame\n        visualNovel\n      }\n    }\n  }\n}"];
+export function graphql(source: "query UserPreferences {\n  userPreferences {\n    general {\n      reviewScale\n      nsfwDisplay\n    }\n    timeEstimation {\n      podcastPlaybackSpeed\n      defaultEpisodeRuntime\n      pagesPerHour\n    }\n    fitness {\n      measurements {\n        custom {\n          name\n          dataType\n        }\n        inbuilt {\n          weight\n          bodyMassIndex\n          totalBodyWater\n          muscle\n          leanBodyMass\n          bodyFat\n          boneMass\n          visceralFat\n          waistCircumference\n          waistToHeightRatio\n          hipCircumference\n          waistToHipRatio\n          chestCircumference\n          thighCircumference\n          bicepsCircumference\n          neckCircumference\n          bodyFatCaliper\n          chestSkinfold\n          abdominalSkinfold\n          thighSkinfold\n          basalMetabolicRate\n          totalDailyEnergyExpenditure\n          calories\n        }\n      }\n      exercises {\n        saveHistory\n        unitSystem\n      }\n      equipment {\n        plates\n        bars\n        dumbbellIncrement\n        available\n      }\n    }\n    notifications {\n      episodeReleased\n      episodeNameChanged\n      statusChanged\n      releaseDateChanged\n      numberOfSeasonsChanged\n      numberOfChaptersOrEpisodesChanged\n      personalBestAchieved\n    }\n    featuresEnabled {\n      fitness {\n        enabled\n        measurements\n      }\n      media {\n        enabled\n        anime\n        article\n        audioBook\n        boardGame\n        book\n        comic\n        manga\n        movie\n        music\n        podcast\n        show\n        videoGame\n        visualNovel\n      }\n    }\n    seenContexts {\n      anime\n      article\n      audioBook\n      boardGame\n      book\n      comic\n      manga\n      movie\n      music\n      podcast\n      show\n      videoGame\n      visualNovel\n    }\n  }\n}"): (typeof documents)["query UserPreferences {\n  userPreferences {\n    general {\n      reviewScale\n      nsfwDisplay\n    }\n    timeEstimation {\n      podcastPlaybackSpeed\n      defaultEpisodeRuntime\n      pagesPerHour\n    }\n    fitness {\n      measurements {\n        custom {\n          name\n          dataType\n        }\n        inbuilt {\n          weight\n          bodyMassIndex\n          totalBodyWater\n          muscle\n          leanBodyMass\n          bodyFat\n          boneMass\n          visceralFat\n          waistCircumference\n          waistToHeightRatio\n          hipCircumference\n          waistToHipRatio\n          chestCircumference\n          thighCircumference\n          bicepsCircumference\n          neckCircumference\n          bodyFatCaliper\n          chestSkinfold\n          abdominalSkinfold\n          thighSkinfold\n          basalMetabolicRate\n          totalDailyEnergyExpenditure\n          calories\n        }\n      }\n      exercises {\n        saveHistory\n        unitSystem\n      }\n      equipment {\n        plates\n        bars\n        dumbbellIncrement\n        available\n      }\n    }\n    notifications {\n      episodeReleased\n      episodeNameChanged\n      statusChanged\n      releaseDateChanged\n      numberOfSeasonsChanged\n      numberOfChaptersOrEpisodesChanged\n      personalBestAchieved\n    }\n    featuresEnabled {\n      fitness {\n        enabled\n        measurements\n      }\n      media {\n        enabled\n        anime\n        article\n        audioBook\n        boardGame\n        book\n        comic\n        manga\n        movie\n        music\n        podcast\n        show\n        videoGame\n        visualNovel\n      }\n    }\n    seenContexts {\n      anime\n      article\n      audioBook\n      boardGame\n      book\n      comic\n      manga\n      movie\n      music\n      podcast\n      show\n      videoGame\n      visualNovel\n    }\n  }\n}"];
 /**
  * The graphql function is used to parse GraphQL queries into a document that can be used by GraphQL clients.
  */
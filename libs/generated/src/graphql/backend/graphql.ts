@@ -40,6 +40,23 @@ export type AddMediaToCollection = {
   mediaId: Scalars['Int']['input'];
 };
 
+export type AddSeenAttachmentInput = {
+  /** The key of the file that was uploaded using a presigned URL. */
+  key: Scalars['String']['input'];
+  seenId: Scalars['Int']['input'];
+};
+
+export type AddSeenCompanionInput = {
+  /** The name of someone who does not use this instance. */
+  name?: InputMaybe<Scalars['String']['input']>;
+  seenId: Scalars['Int']['input'];
+  /**
+   * The username of a user of this instance. They get a copy of the seen
+   * item once they confirm it.
+   */
+  username?: InputMaybe<Scalars['String']['input']>;
+};
+
 export type AnimeSpecifics = {
   episodes?: Maybe<Scalars['Int']['output']>;
 };
@@ -53,6 +70,42 @@ export type AnimeSummary = {
   watched: Scalars['Int']['output'];
 };
 
+export type Announcement = {
+  createdOn: Scalars['DateTime']['output'];
+  /** The time after which the announcement is no longer displayed. */
+  expiresOn?: Maybe<Scalars['DateTime']['output']>;
+  id: Scalars['Int']['output'];
+  message: Scalars['String']['output'];
+  severity: AnnouncementSeverity;
+};
+
+export enum AnnouncementSeverity {
+  Critical = 'CRITICAL',
+  Info = 'INFO',
+  Warning = 'WARNING'
+}
+
+export type ArticleSpecifics = {
+  /** The estimated time to read the article, in minutes. */
+  readingTime?: Maybe<Scalars['Int']['output']>;
+  /** The name of the website the article was published on. */
+  siteName?: Maybe<Scalars['String']['output']>;
+  words?: Maybe<Scalars['Int']['output']>;
+};
+
+export type ArticleSpecificsInput = {
+  /** The estimated time to read the article, in minutes. */
+  readingTime?: InputMaybe<Scalars['Int']['input']>;
+  /** The name of the website the article was published on. */
+  siteName?: InputMaybe<Scalars['String']['input']>;
+  words?: InputMaybe<Scalars['Int']['input']>;
+};
+
+export type ArticlesSummary = {
+  read: Scalars['Int']['output'];
+  runtime: Scalars['Int']['output'];
+};
+
 export type AudioBookSpecifics = {
   runtime?: Maybe<Scalars['Int']['output']>;
 };
@@ -66,6 +119,48 @@ export type AudioBooksSummary = {
   runtime: Scalars['Int']['output'];
 };
 
+export type AuditLogEntry = {
+  createdOn: Scalars['DateTime']['output'];
+  /** What the event is about, eg: the username of a failed login. */
+  details?: Maybe<Scalars['String']['output']>;
+  event: AuditLogEvent;
+  id: Scalars['Int']['output'];
+  /** The IP address that the request came from. */
+  ip?: Maybe<Scalars['String']['output']>;
+  /** The user that the event is about, if it is about one that exists. */
+  userId?: Maybe<Scalars['Int']['output']>;
+};
+
+export enum AuditLogEvent {
+  FailedLogin = 'FAILED_LOGIN',
+  Lockout = 'LOCKOUT',
+  NsfwDisplayChanged = 'NSFW_DISPLAY_CHANGED'
+}
+
+export type AuditLogResults = {
+  details: SearchDetails;
+  items: Array<AuditLogEntry>;
+};
+
+export type BoardGameSpecifics = {
+  maxPlayers?: Maybe<Scalars['Int']['output']>;
+  minPlayers?: Maybe<Scalars['Int']['output']>;
+  /** The time it takes to play a game, in minutes. */
+  playtime?: Maybe<Scalars['Int']['output']>;
+};
+
+export type BoardGameSpecificsInput = {
+  maxPlayers?: InputMaybe<Scalars['Int']['input']>;
+  minPlayers?: InputMaybe<Scalars['Int']['input']>;
+  /** The time it takes to play a game, in minutes. */
+  playtime?: InputMaybe<Scalars['Int']['input']>;
+};
+
+export type BoardGamesSummary = {
+  played: Scalars['Int']['output'];
+  runtime: Scalars['Int']['output'];
+};
+
 export type BookSpecifics = {
   pages?: Maybe<Scalars['Int']['output']>;
 };
@@ -77,6 +172,11 @@ export type BookSpecificsInput = {
 export type BooksSummary = {
   pages: Scalars['Int']['output'];
   read: Scalars['Int']['output'];
+  /**
+   * The estimated time spent reading, based on the reading speed in the
+   * preferences.
+   */
+  runtime: Scalars['Int']['output'];
 };
 
 export type Collection = {
@@ -87,6 +187,30 @@ export type Collection = {
   visibility: Visibility;
 };
 
+export type CollectionAction = {
+  /** The name of the other collection, used by `AddToCollection`. */
+  collectionName?: Maybe<Scalars['String']['output']>;
+  lot: CollectionActionLot;
+};
+
+export type CollectionActionInput = {
+  /** The name of the other collection, used by `AddToCollection`. */
+  collectionName?: InputMaybe<Scalars['String']['input']>;
+  lot: CollectionActionLot;
+};
+
+export enum CollectionActionLot {
+  /** Also add the media to another collection of the user. */
+  AddToCollection = 'ADD_TO_COLLECTION',
+  /** Monitor the media so that the user is notified about its releases. */
+  Monitor = 'MONITOR',
+  /**
+   * Set a reminder for the day the media is released, if it has not been
+   * released yet.
+   */
+  RemindOnRelease = 'REMIND_ON_RELEASE'
+}
+
 export type CollectionContents = {
   details: Collection;
   results: MediaCollectionContentsResults;
@@ -104,6 +228,7 @@ export type CollectionInput = {
 };
 
 export type CollectionItem = {
+  actions: Array<CollectionAction>;
   description?: Maybe<Scalars['String']['output']>;
   id: Scalars['Int']['output'];
   name: Scalars['String']['output'];
@@ -111,6 +236,21 @@ export type CollectionItem = {
   visibility: Visibility;
 };
 
+export type ComicSpecifics = {
+  /** The number of issues in the volume. */
+  issues?: Maybe<Scalars['Int']['output']>;
+};
+
+export type ComicSpecificsInput = {
+  /** The number of issues in the volume. */
+  issues?: InputMaybe<Scalars['Int']['input']>;
+};
+
+export type ComicsSummary = {
+  issues: Scalars['Int']['output'];
+  read: Scalars['Int']['output'];
+};
+
 export type CoreDetails = {
   authorName: Scalars['String']['output'];
   defaultCredentials: Scalars['Boolean']['output'];
@@ -138,8 +278,11 @@ export enum CreateCustomMediaErrorVariant {
 
 export type CreateCustomMediaInput = {
   animeSpecifics?: InputMaybe<AnimeSpecificsInput>;
+  articleSpecifics?: InputMaybe<ArticleSpecificsInput>;
   audioBookSpecifics?: InputMaybe<AudioBookSpecificsInput>;
+  boardGameSpecifics?: InputMaybe<BoardGameSpecificsInput>;
   bookSpecifics?: InputMaybe<BookSpecificsInput>;
+  comicSpecifics?: InputMaybe<ComicSpecificsInput>;
   creators?: InputMaybe<Array<Scalars['String']['input']>>;
   description?: InputMaybe<Scalars['String']['input']>;
   genres?: InputMaybe<Array<Scalars['String']['input']>>;
@@ -148,6 +291,7 @@ export type CreateCustomMediaInput = {
   lot: MetadataLot;
   mangaSpecifics?: InputMaybe<MangaSpecificsInput>;
   movieSpecifics?: InputMaybe<MovieSpecificsInput>;
+  musicSpecifics?: InputMaybe<MusicSpecificsInput>;
   podcastSpecifics?: InputMaybe<PodcastSpecificsInput>;
   publishYear?: InputMaybe<Scalars['Int']['input']>;
   showSpecifics?: InputMaybe<ShowSpecificsInput>;
@@ -166,12 +310,24 @@ export type CreateMediaReminderInput = {
 };
 
 export type CreateOrUpdateCollectionInput = {
+  /** The actions that are run when a media is added to this collection. */
+  actions?: InputMaybe<Array<CollectionActionInput>>;
   description?: InputMaybe<Scalars['String']['input']>;
   name: Scalars['String']['input'];
   updateId?: InputMaybe<Scalars['Int']['input']>;
   visibility?: InputMaybe<Visibility>;
 };
 
+export type CreateOrUpdateHighlightInput = {
+  chapter?: InputMaybe<Scalars['String']['input']>;
+  /** ID of the highlight if this is an update to an existing highlight */
+  highlightId?: InputMaybe<Scalars['Int']['input']>;
+  metadataId: Scalars['Int']['input'];
+  note?: InputMaybe<Scalars['String']['input']>;
+  page?: InputMaybe<Scalars['Int']['input']>;
+  text: Scalars['String']['input'];
+};
+
 export type CreateReviewCommentInput = {
   commentId?: InputMaybe<Scalars['String']['input']>;
   decrementLikes?: InputMaybe<Scalars['Boolean']['input']>;
@@ -191,12 +347,14 @@ export type CreateUserNotificationPlatformInput = {
 
 export type CreateUserSinkIntegrationInput = {
   lot: UserSinkIntegrationSettingKind;
+  progressSettings?: InputMaybe<IntegrationProgressSettingsInput>;
   username?: InputMaybe<Scalars['String']['input']>;
 };
 
 export type CreateUserYankIntegrationInput = {
   baseUrl: Scalars['String']['input'];
   lot: UserYankIntegrationSettingKind;
+  progressSettings?: InputMaybe<IntegrationProgressSettingsInput>;
   token: Scalars['String']['input'];
 };
 
@@ -223,20 +381,88 @@ export type CreatorExtraInformation = {
   active: Scalars['Boolean']['output'];
 };
 
+export type DeployGarminConnectImportInput = {
+  /**
+   * The `summarizedActivities.json` file path from the export (uploaded via
+   * temporary upload).
+   */
+  activitiesPath: Scalars['String']['input'];
+  /**
+   * The `userBioMetrics.json` file path from the export (uploaded via
+   * temporary upload).
+   */
+  bioMetricsPath?: InputMaybe<Scalars['String']['input']>;
+};
+
+/** A CSV file from any service. The columns are referred to by their headers. */
+export type DeployGenericCsvImportInput = {
+  /** The CSV file path (uploaded via temporary upload). */
+  csvPath: Scalars['String']['input'];
+  /** The column with the date that the item was consumed on. Every row is imported as consumed, even when it does not have a date. */
+  dateColumn?: InputMaybe<Scalars['String']['input']>;
+  /** The format of the dates (eg: `%d/%m/%Y`). By default, dates like `2023-10-19` and `2023-10-19 20:30:00` are recognized. */
+  dateFormat?: InputMaybe<Scalars['String']['input']>;
+  /** The column that identifies the items, used before searching for their titles. */
+  identifierColumn?: InputMaybe<Scalars['String']['input']>;
+  /** Defaults to the identifier of the provider. */
+  identifierType?: InputMaybe<GenericCsvIdentifierType>;
+  /** The type of media in the file. The items are looked up in the default provider of this type. */
+  lot: MetadataLot;
+  ratingColumn?: InputMaybe<Scalars['String']['input']>;
+  /** The highest rating possible in the file (eg: `5` or `10`). Defaults to `100`. */
+  ratingScale?: InputMaybe<Scalars['Decimal']['input']>;
+  titleColumn: Scalars['String']['input'];
+};
+
+export type DeployGoodreadsCsvImportInput = {
+  /** The CSV contents of the library export. */
+  export: Scalars['String']['input'];
+};
+
 export type DeployGoodreadsImportInput = {
   rssUrl: Scalars['String']['input'];
 };
 
 export type DeployImportJobInput = {
+  /**
+   * How to handle media that the user already has data for. Defaults to
+   * appending. Fitness data is never imported twice, so this only applies to
+   * media.
+   */
+  conflictPolicy?: InputMaybe<ImportConflictPolicy>;
+  /**
+   * Find the media items without importing anything, and save what the
+   * import would have done in its report instead. Only supported for media.
+   */
+  dryRun?: Scalars['Boolean']['input'];
+  garminConnect?: InputMaybe<DeployGarminConnectImportInput>;
+  genericCsv?: InputMaybe<DeployGenericCsvImportInput>;
   goodreads?: InputMaybe<DeployGoodreadsImportInput>;
+  goodreadsCsv?: InputMaybe<DeployGoodreadsCsvImportInput>;
+  kindle?: InputMaybe<DeployKindleImportInput>;
+  letterboxd?: InputMaybe<DeployLetterboxdImportInput>;
   lot: ImportLot;
   mal?: InputMaybe<DeployMalImportInput>;
   mediaJson?: InputMaybe<DeployMediaJsonImportInput>;
   mediaTracker?: InputMaybe<DeployMediaTrackerImportInput>;
   movary?: InputMaybe<DeployMovaryImportInput>;
+  playstation?: InputMaybe<DeployPlaystationImportInput>;
+  ryot?: InputMaybe<DeployRyotImportInput>;
   source: ImportSource;
   storyGraph?: InputMaybe<DeployStoryGraphImportInput>;
   trakt?: InputMaybe<DeployTraktImportInput>;
+  tvTime?: InputMaybe<DeployTvTimeImportInput>;
+  xbox?: InputMaybe<DeployXboxImportInput>;
+};
+
+export type DeployKindleImportInput = {
+  /** The contents of the `My Clippings.txt` file from the Kindle. */
+  clippings: Scalars['String']['input'];
+};
+
+export type DeployLetterboxdImportInput = {
+  /** The ZIP data export file path (uploaded via temporary upload). */
+  exportPath: Scalars['String']['input'];
 };
 
 export type DeployMalImportInput = {
@@ -263,14 +489,40 @@ export type DeployMovaryImportInput = {
   watchlist: Scalars['String']['input'];
 };
 
+export type DeployPlaystationImportInput = {
+  /** The JSON list of played titles from the PlayStation Network. */
+  export: Scalars['String']['input'];
+};
+
+export type DeployRyotImportInput = {
+  /** The password of the user on the other instance. */
+  password: Scalars['String']['input'];
+  /** The base url of the other instance. */
+  url: Scalars['String']['input'];
+  /** The username of the user on the other instance. */
+  username: Scalars['String']['input'];
+};
+
 export type DeployStoryGraphImportInput = {
   export: Scalars['String']['input'];
 };
 
 export type DeployTraktImportInput = {
+  /** An OAuth access token of the user, needed to import a private profile. */
+  accessToken?: InputMaybe<Scalars['String']['input']>;
   username: Scalars['String']['input'];
 };
 
+export type DeployTvTimeImportInput = {
+  /** The ZIP data export file path (uploaded via temporary upload). */
+  exportPath: Scalars['String']['input'];
+};
+
+export type DeployXboxImportInput = {
+  /** The JSON title history from the Xbox network. */
+  export: Scalars['String']['input'];
+};
+
 export type Exercise = {
   attributes: ExerciseAttributes;
   equipment?: Maybe<ExerciseEquipment>;
@@ -281,11 +533,14 @@ export type Exercise = {
   lot: ExerciseLot;
   mechanic?: Maybe<ExerciseMechanic>;
   name: Scalars['String']['output'];
+  source: ExerciseSource;
 };
 
 export type ExerciseAttributes = {
   images: Array<Scalars['String']['output']>;
   instructions: Array<Scalars['String']['output']>;
+  /** The names of the exercise in other languages. */
+  localizedNames: Array<ExerciseLocalizedName>;
   muscles: Array<ExerciseMuscle>;
 };
 
@@ -325,6 +580,24 @@ export enum ExerciseForce {
   Static = 'STATIC'
 }
 
+export type ExerciseGroupInput = {
+  /**
+   * The `exercise.idx` of the exercises in the group, in the order they are
+   * performed.
+   */
+  exercises: Array<Scalars['Int']['input']>;
+  lot: ExerciseGroupLot;
+  /** The rest time after each round, shared by all the exercises in the group. */
+  restTime?: InputMaybe<Scalars['Int']['input']>;
+};
+
+export enum ExerciseGroupLot {
+  /** Like a superset, but the exercises are repeated for multiple rounds. */
+  Circuit = 'CIRCUIT',
+  /** Exercises performed back to back, resting only after the last one. */
+  Superset = 'SUPERSET'
+}
+
 export enum ExerciseLevel {
   Beginner = 'BEGINNER',
   Expert = 'EXPERT',
@@ -340,6 +613,11 @@ export type ExerciseListFilter = {
   type?: InputMaybe<ExerciseLot>;
 };
 
+export type ExerciseLocalizedName = {
+  language: Scalars['String']['output'];
+  name: Scalars['String']['output'];
+};
+
 export enum ExerciseLot {
   DistanceAndDuration = 'DISTANCE_AND_DURATION',
   Duration = 'DURATION',
@@ -382,6 +660,12 @@ export type ExerciseSearchResults = {
   items: Array<Exercise>;
 };
 
+export enum ExerciseSource {
+  Custom = 'CUSTOM',
+  Github = 'GITHUB',
+  Wger = 'WGER'
+}
+
 export type ExercisesListInput = {
   filter?: InputMaybe<ExerciseListFilter>;
   page: Scalars['Int']['input'];
@@ -389,10 +673,20 @@ export type ExercisesListInput = {
 };
 
 export type GeneralFeatures = {
+  emailVerificationRequired: Scalars['Boolean']['output'];
   fileStorage: Scalars['Boolean']['output'];
+  passwordResetAllowed: Scalars['Boolean']['output'];
   signupAllowed: Scalars['Boolean']['output'];
 };
 
+/** What the values of the identifier column of a CSV file are. */
+export enum GenericCsvIdentifierType {
+  Imdb = 'IMDB',
+  Isbn = 'ISBN',
+  /** The identifier of the item in the default provider of the type of media. */
+  Provider = 'PROVIDER'
+}
+
 export type GraphqlCalendarEvent = {
   calendarEventId: Scalars['Int']['output'];
   date: Scalars['NaiveDate']['output'];
@@ -412,19 +706,25 @@ export type GraphqlMediaAssets = {
 
 export type GraphqlMediaDetails = {
   animeSpecifics?: Maybe<AnimeSpecifics>;
+  articleSpecifics?: Maybe<ArticleSpecifics>;
   assets: GraphqlMediaAssets;
   audioBookSpecifics?: Maybe<AudioBookSpecifics>;
+  awards: Array<MetadataAward>;
+  boardGameSpecifics?: Maybe<BoardGameSpecifics>;
   bookSpecifics?: Maybe<BookSpecifics>;
+  comicSpecifics?: Maybe<ComicSpecifics>;
   creators: Array<MetadataCreatorGroupedByRole>;
   description?: Maybe<Scalars['String']['output']>;
   genres: Array<Scalars['String']['output']>;
   group?: Maybe<GraphqlMediaGroup>;
   id: Scalars['Int']['output'];
   identifier: Scalars['String']['output'];
+  isDeletedOnProvider: Scalars['Boolean']['output'];
   isNsfw: Scalars['Boolean']['output'];
   lot: MetadataLot;
   mangaSpecifics?: Maybe<MangaSpecifics>;
   movieSpecifics?: Maybe<MovieSpecifics>;
+  musicSpecifics?: Maybe<MusicSpecifics>;
   podcastSpecifics?: Maybe<PodcastSpecifics>;
   productionStatus: Scalars['String']['output'];
   providerRating?: Maybe<Scalars['Decimal']['output']>;
@@ -449,8 +749,15 @@ export type GraphqlUserIntegration = {
   description: Scalars['String']['output'];
   id: Scalars['Int']['output'];
   lot: UserIntegrationLot;
+  progressSettings: IntegrationProgressSettings;
+  /**
+   * The secret that requests made by the integration have to be authenticated
+   * with.
+   */
+  secret?: Maybe<Scalars['String']['output']>;
   slug?: Maybe<Scalars['String']['output']>;
   timestamp: Scalars['DateTime']['output'];
+  userMappings: Array<IntegrationUserMapping>;
 };
 
 export type GraphqlUserNotificationPlatform = {
@@ -469,16 +776,54 @@ export type GroupedCalendarEvent = {
   events: Array<GraphqlCalendarEvent>;
 };
 
+export type Highlight = {
+  chapter?: Maybe<Scalars['String']['output']>;
+  createdOn: Scalars['DateTime']['output'];
+  id: Scalars['Int']['output'];
+  metadataId: Scalars['Int']['output'];
+  /** The note that the user wrote about the passage. */
+  note?: Maybe<Scalars['String']['output']>;
+  page?: Maybe<Scalars['Int']['output']>;
+  /** The passage that was highlighted. */
+  text: Scalars['String']['output'];
+};
+
 export type IdObject = {
   id: Scalars['Int']['output'];
 };
 
+/**
+ * How the data of a media item that the user already has data for is handled.
+ * Seen history, reviews and highlights are each handled separately, and only
+ * when the import has data of that kind for the item.
+ */
+export enum ImportConflictPolicy {
+  /** Add the imported data to the existing data. */
+  Append = 'APPEND',
+  /** Remove the existing data and replace it with the imported data. */
+  Overwrite = 'OVERWRITE',
+  /** Keep the existing data and ignore the imported data. */
+  Skip = 'SKIP'
+}
+
+/** How far a media import got, so that it can be resumed from there if it fails. */
+export type ImportCheckpoint = {
+  /** The identifier of the media item that was imported last. */
+  lastItem: Scalars['String']['output'];
+  /** The number of media items that were imported. */
+  processed: Scalars['Int']['output'];
+  /** When the checkpoint was saved. */
+  savedOn?: Maybe<Scalars['DateTime']['output']>;
+};
+
 export type ImportDetails = {
   total: Scalars['Int']['output'];
 };
 
 /** The various steps in which media importing can fail */
 export enum ImportFailStep {
+  /** Failed to save a highlight */
+  HighlightConversion = 'HIGHLIGHT_CONVERSION',
   /** Failed to transform the data into the required format */
   InputTransformation = 'INPUT_TRANSFORMATION',
   /** Failed to get details from the source itself (for eg: MediaTracker, Goodreads etc.) */
@@ -494,10 +839,27 @@ export enum ImportFailStep {
 export type ImportFailedItem = {
   error?: Maybe<Scalars['String']['output']>;
   identifier: Scalars['String']['output'];
+  /**
+   * The position of the seen history item, review or highlight of the item
+   * that could not be saved, for the steps that save them.
+   */
+  index?: Maybe<Scalars['Int']['output']>;
   lot: MetadataLot;
   step: ImportFailStep;
 };
 
+/**
+ * A different identifier to look up an item that failed to import with when it
+ * is retried.
+ */
+export type ImportFailedItemOverride = {
+  /** The identifier of the failed item in the report. */
+  identifier: Scalars['String']['input'];
+  lot: MetadataLot;
+  /** The identifier of the media item with the provider that the import uses. */
+  providerIdentifier: Scalars['String']['input'];
+};
+
 export enum ImportLot {
   Exercise = 'EXERCISE',
   Media = 'MEDIA'
@@ -512,7 +874,38 @@ export type ImportOrExportItemReviewComment = {
   user: ReviewCommentUser;
 };
 
+/** What a media import would have done if it was not a dry run. */
+export type ImportPreview = {
+  /** The collections that would be created for the user. */
+  collections: Array<Scalars['String']['output']>;
+  /** The number of highlights that would be added. */
+  highlights: Scalars['Int']['output'];
+  /** The items that were found, either in the database or with their provider. */
+  matched: Array<ImportPreviewItem>;
+  /** The number of reviews that would be added. */
+  reviews: Scalars['Int']['output'];
+  /** The number of seen history items that would be added. */
+  seenHistory: Scalars['Int']['output'];
+  /** The items that could not be found. The reasons are in the failed items. */
+  unmatched: Array<ImportPreviewItem>;
+};
+
+/** A media item of an import that was a dry run. */
+export type ImportPreviewItem = {
+  /** The identifier of the item in the source of the import. */
+  identifier: Scalars['String']['output'];
+  /** Whether the item is not in the database yet and would be created. */
+  isNew: Scalars['Boolean']['output'];
+  lot: MetadataLot;
+  source: MetadataSource;
+};
+
 export type ImportReport = {
+  /**
+   * How far the import got. It is kept until the import succeeds, so that it
+   * can be resumed from there if it fails.
+   */
+  checkpoint?: Maybe<ImportCheckpoint>;
   details?: Maybe<ImportResultResponse>;
   finishedOn?: Maybe<Scalars['DateTime']['output']>;
   id: Scalars['Int']['output'];
@@ -525,24 +918,113 @@ export type ImportReport = {
 export type ImportResultResponse = {
   failedItems: Array<ImportFailedItem>;
   import: ImportDetails;
+  /** What the import would have done, if it was a dry run. */
+  preview?: Maybe<ImportPreview>;
 };
 
 export enum ImportSource {
+  GarminConnect = 'GARMIN_CONNECT',
+  GenericCsv = 'GENERIC_CSV',
   Goodreads = 'GOODREADS',
+  GoodreadsCsv = 'GOODREADS_CSV',
+  Kindle = 'KINDLE',
+  Letterboxd = 'LETTERBOXD',
   Mal = 'MAL',
   MediaJson = 'MEDIA_JSON',
   MediaTracker = 'MEDIA_TRACKER',
   Movary = 'MOVARY',
+  Playstation = 'PLAYSTATION',
+  Ryot = 'RYOT',
   StoryGraph = 'STORY_GRAPH',
-  Trakt = 'TRAKT'
+  Trakt = 'TRAKT',
+  TvTime = 'TV_TIME',
+  Xbox = 'XBOX'
 }
 
+export type IntegrationMapping = {
+  /** Why the item could not be matched the last time it was received. */
+  error?: Maybe<Scalars['String']['output']>;
+  /** The title or the ID that the integration identified the item with. */
+  externalId: Scalars['String']['output'];
+  id: Scalars['Int']['output'];
+  lot: MetadataLot;
+  /** The media that the item is mapped to, if it has been mapped yet. */
+  metadataId?: Maybe<Scalars['Int']['output']>;
+  updatedOn: Scalars['DateTime']['output'];
+};
+
+/** How the progress that an integration sends is applied to the media. */
+export type IntegrationProgressSettings = {
+  /**
+   * The progress in percent from which the media is marked as completed.
+   * Defaults to `90`. Uses the one configured for the server if not set.
+   */
+  completionThreshold?: Maybe<Scalars['Int']['output']>;
+  /** Whether the progress sent when the media is paused should be ignored. */
+  ignorePauses: Scalars['Boolean']['output'];
+  /**
+   * The playback position in seconds that the media has to reach before its
+   * progress is updated. It is compared with the position in the media, not
+   * the time spent playing it. Only applies to integrations that send the
+   * position.
+   */
+  minimumDuration?: Maybe<Scalars['Int']['output']>;
+};
+
+/** How the progress that an integration sends is applied to the media. */
+export type IntegrationProgressSettingsInput = {
+  /**
+   * The progress in percent from which the media is marked as completed.
+   * Defaults to `90`. Uses the one configured for the server if not set.
+   */
+  completionThreshold?: InputMaybe<Scalars['Int']['input']>;
+  /** Whether the progress sent when the media is paused should be ignored. */
+  ignorePauses: Scalars['Boolean']['input'];
+  /**
+   * The playback position in seconds that the media has to reach before its
+   * progress is updated. It is compared with the position in the media, not
+   * the time spent playing it. Only applies to integrations that send the
+   * position.
+   */
+  minimumDuration?: InputMaybe<Scalars['Int']['input']>;
+};
+
+/** A profile on a media server whose progress is saved to another user. */
+export type IntegrationUserMapping = {
+  /** The name of the profile on the media server. */
+  serverUsername: Scalars['String']['output'];
+  userId: Scalars['Int']['output'];
+};
+
+/** A profile on a media server whose progress is saved to another user. */
+export type IntegrationUserMappingInput = {
+  /** The name of the profile on the media server. */
+  serverUsername: Scalars['String']['input'];
+  userId: Scalars['Int']['input'];
+};
+
+export type KoreaderDocument = {
+  device: Scalars['String']['output'];
+  deviceId: Scalars['String']['output'];
+  /** The hash that KOReader uses to identify the document. */
+  document: Scalars['String']['output'];
+  id: Scalars['Int']['output'];
+  /** The book that the document is linked to, if it has been matched yet. */
+  metadataId?: Maybe<Scalars['Int']['output']>;
+  percentage: Scalars['Decimal']['output'];
+  /** The position in the document, in the format used by KOReader. */
+  progress: Scalars['String']['output'];
+  updatedOn: Scalars['DateTime']['output'];
+};
+
 export type LoginError = {
   error: LoginErrorVariant;
 };
 
 export enum LoginErrorVariant {
   CredentialsMismatch = 'CREDENTIALS_MISMATCH',
+  EmailNotVerified = 'EMAIL_NOT_VERIFIED',
+  TooManyAttempts = 'TOO_MANY_ATTEMPTS',
   UsernameDoesNotExist = 'USERNAME_DOES_NOT_EXIST'
 }
 
@@ -574,6 +1056,13 @@ export type MediaCollectionContentsResults = {
   items: Array<MediaSearchItemWithLot>;
 };
 
+export type MediaAwardFilter = {
+  /** Part of the name of the award, eg: `Best Picture`. */
+  name: Scalars['String']['input'];
+  /** Only include media that won the award, not the ones that were nominated. */
+  won?: Scalars['Boolean']['input'];
+};
+
 export type MediaCreatorSearchItem = {
   id: Scalars['Int']['output'];
   image?: Maybe<Scalars['String']['output']>;
@@ -587,6 +1076,7 @@ export type MediaCreatorSearchResults = {
 };
 
 export type MediaFilter = {
+  award?: InputMaybe<MediaAwardFilter>;
   collection?: InputMaybe<Scalars['Int']['input']>;
   general?: InputMaybe<MediaGeneralFilter>;
 };
@@ -594,6 +1084,7 @@ export type MediaFilter = {
 export enum MediaGeneralFilter {
   All = 'ALL',
   Completed = 'COMPLETED',
+  DeletedOnProvider = 'DELETED_ON_PROVIDER',
   Dropped = 'DROPPED',
   ExplicitlyMonitored = 'EXPLICITLY_MONITORED',
   InProgress = 'IN_PROGRESS',
@@ -621,6 +1112,23 @@ export type MediaListResults = {
   items: Array<MediaListItem>;
 };
 
+/**
+ * Filters that are passed through to the provider while searching. Providers
+ * ignore the ones they do not support.
+ */
+export type MediaSearchFilters = {
+  /** The format of the media, eg: `TV` or `MOVIE` for anime. */
+  format?: InputMaybe<Scalars['String']['input']>;
+  /** The language the media was published in, eg: `eng` for Openlibrary. */
+  language?: InputMaybe<Scalars['String']['input']>;
+  /** The platform a video game was released on, eg: `PlayStation 5`. */
+  platform?: InputMaybe<Scalars['String']['input']>;
+  /** Only return media released in or after this year. */
+  yearFrom?: InputMaybe<Scalars['Int']['input']>;
+  /** Only return media released in or before this year. */
+  yearTo?: InputMaybe<Scalars['Int']['input']>;
+};
+
 export type MediaSearchItem = {
   identifier: Scalars['String']['output'];
   image?: Maybe<Scalars['String']['output']>;
@@ -648,7 +1156,12 @@ export enum MediaSortBy {
   LastUpdated = 'LAST_UPDATED',
   Rating = 'RATING',
   ReleaseDate = 'RELEASE_DATE',
-  Title = 'TITLE'
+  Title = 'TITLE',
+  /**
+   * The favorites of the user first, each group ordered by the rating given by
+   * the user.
+   */
+  TopFavorites = 'TOP_FAVORITES'
 }
 
 export type MediaSortInput = {
@@ -661,6 +1174,16 @@ export enum MediaSortOrder {
   Desc = 'DESC'
 }
 
+/** An award that a media item has won or been nominated for. */
+export type MetadataAward = {
+  /** The name of the award, eg: `Academy Award for Best Picture`. */
+  name: Scalars['String']['output'];
+  /** Whether the item won the award. `false` for nominations. */
+  won: Scalars['Boolean']['output'];
+  /** The year the award was given out, if known. */
+  year?: Maybe<Scalars['Int']['output']>;
+};
+
 export type MetadataCreatorGroupedByRole = {
   items: Array<Creator>;
   name: Scalars['String']['output'];
@@ -699,10 +1222,14 @@ export type MetadataGroupListResults = {
 
 export enum MetadataLot {
   Anime = 'ANIME',
+  Article = 'ARTICLE',
   AudioBook = 'AUDIO_BOOK',
+  BoardGame = 'BOARD_GAME',
   Book = 'BOOK',
+  Comic = 'COMIC',
   Manga = 'MANGA',
   Movie = 'MOVIE',
+  Music = 'MUSIC',
   Podcast = 'PODCAST',
   Show = 'SHOW',
   VideoGame = 'VIDEO_GAME',
@@ -712,16 +1239,22 @@ export enum MetadataLot {
 export enum MetadataSource {
   Anilist = 'ANILIST',
   Audible = 'AUDIBLE',
+  BoardGameGeek = 'BOARD_GAME_GEEK',
+  ComicVine = 'COMIC_VINE',
   Custom = 'CUSTOM',
   GoogleBooks = 'GOOGLE_BOOKS',
   Igdb = 'IGDB',
   Itunes = 'ITUNES',
   Listennotes = 'LISTENNOTES',
   Mal = 'MAL',
+  MangaDex = 'MANGA_DEX',
   MangaUpdates = 'MANGA_UPDATES',
+  MusicBrainz = 'MUSIC_BRAINZ',
   Openlibrary = 'OPENLIBRARY',
+  PodcastIndex = 'PODCAST_INDEX',
   Tmdb = 'TMDB',
-  Vndb = 'VNDB'
+  Vndb = 'VNDB',
+  Web = 'WEB'
 }
 
 export enum MetadataVideoSource {
@@ -743,9 +1276,35 @@ export type MoviesSummary = {
   watched: Scalars['Int']['output'];
 };
 
+export type MusicSpecifics = {
+  /** The total length of the album, in minutes. */
+  duration?: Maybe<Scalars['Int']['output']>;
+  /** The number of tracks on the album. */
+  tracks?: Maybe<Scalars['Int']['output']>;
+};
+
+export type MusicSpecificsInput = {
+  /** The total length of the album, in minutes. */
+  duration?: InputMaybe<Scalars['Int']['input']>;
+  /** The number of tracks on the album. */
+  tracks?: InputMaybe<Scalars['Int']['input']>;
+};
+
+export type MusicSummary = {
+  listened: Scalars['Int']['output'];
+  runtime: Scalars['Int']['output'];
+};
+
 export type MutationRoot = {
   /** Add a media item to a collection if it is not there, otherwise do nothing. */
   addMediaToCollection: Scalars['Boolean']['output'];
+  /** Attach an uploaded file, like a ticket stub or a screenshot, to a seen item. */
+  addSeenAttachment: IdObject;
+  /**
+   * Tag someone as having seen the media along with the user. If they are a
+   * user of this instance, they are asked to confirm it.
+   */
+  addSeenCompanion: IdObject;
   /** Update progress in bulk. */
   bulkProgressUpdate: Scalars['Boolean']['output'];
   /** Fetch details about a media and create a media item in the database. */
@@ -756,6 +1315,11 @@ export type MutationRoot = {
   createMediaReminder: Scalars['Boolean']['output'];
   /** Create a new collection for the logged in user or edit details of an existing one. */
   createOrUpdateCollection: IdObject;
+  /**
+   * Save a passage of a book that the user highlighted, or update an existing
+   * one.
+   */
+  createOrUpdateHighlight: IdObject;
   /** Create, like or delete a comment on a review. */
   createReviewComment: Scalars['Boolean']['output'];
   /** Create a user measurement. */
@@ -770,10 +1334,19 @@ export type MutationRoot = {
   createUserYankIntegration: Scalars['Int']['output'];
   /** Delete a collection. */
   deleteCollection: Scalars['Boolean']['output'];
+  /** Delete a highlight of the user. */
+  deleteHighlight: Scalars['Boolean']['output'];
   /** Delete a reminder on a media for a user if it exists. */
   deleteMediaReminder: Scalars['Boolean']['output'];
   /** Delete a review if it belongs to the currently logged in user. */
   deleteReview: Scalars['Boolean']['output'];
+  /** Remove an attachment from a seen item. */
+  deleteSeenAttachment: Scalars['Boolean']['output'];
+  /**
+   * Remove a companion from a seen item. The copy of the seen item that they
+   * confirmed, if any, is not removed.
+   */
+  deleteSeenCompanion: Scalars['Boolean']['output'];
   /** Delete a seen item from a user's history. */
   deleteSeenItem: IdObject;
   /** Delete a user. The account making the user must an `Admin`. */
@@ -794,6 +1367,16 @@ export type MutationRoot = {
   deployUpdateMetadataJob: Scalars['String']['output'];
   /** Generate an auth token without any expiry. */
   generateAuthToken: Scalars['String']['output'];
+  /**
+   * Map a title or ID sent by an integration to a media item, so that it is
+   * used the next time the integration sends it.
+   */
+  linkIntegrationMapping: Scalars['Boolean']['output'];
+  /**
+   * Link a document synced by KOReader to a book so that its progress is
+   * updated when the document is read.
+   */
+  linkKoreaderDocument: Scalars['Boolean']['output'];
   /** Login a user using their username and password and return an auth token. */
   loginUser: LoginResult;
   /** Logout a user from the server and delete their login token. */
@@ -810,7 +1393,10 @@ export type MutationRoot = {
   presignedPutUrl: PresignedPutUrlResponse;
   /** Mark a user's progress on a specific media item. */
   progressUpdate: ProgressUpdateResultUnion;
-  /** Delete all summaries for the currently logged in user and then generate one from scratch. */
+  /**
+   * Recalculate the summary of the currently logged in user from scratch in
+   * the background. Admins can also recalculate the summary of another user.
+   */
   regenerateUserSummary: Scalars['Boolean']['output'];
   /**
    * Create a new user for the service. Also set their `lot` as admin if
@@ -819,16 +1405,51 @@ export type MutationRoot = {
   registerUser: RegisterResult;
   /** Remove a media item from a collection if it is not there, otherwise do nothing. */
   removeMediaFromCollection: IdObject;
+  /**
+   * Send the email to verify the email of a user again. Does not reveal
+   * whether a user with this username exists.
+   */
+  requestUserEmailVerification: Scalars['Boolean']['output'];
+  /**
+   * Send an email with a link to reset the password of a user. Does not
+   * reveal whether a user with this username exists.
+   */
+  requestUserPasswordReset: Scalars['Boolean']['output'];
+  /** Reset the password of a user using the token that was sent to their email. */
+  resetUserPassword: Scalars['Boolean']['output'];
+  /**
+   * Confirm or decline being tagged in the seen item of another user.
+   * Confirming adds a copy of it to the history of the user.
+   */
+  respondToSeenCompanion: Scalars['Boolean']['output'];
   /** Test all notification platforms for the currently logged in user. */
   testUserNotificationPlatforms: Scalars['Boolean']['output'];
+  /**
+   * Toggle whether a media is a favorite of the user. Returns whether it is
+   * now a favorite.
+   */
+  toggleMediaFavorite: Scalars['Boolean']['output'];
   /** Toggle the monitor on a media for a user. */
   toggleMediaMonitor: Scalars['Boolean']['output'];
   /** Deploy jobs to update all media item's metadata. */
   updateAllMetadata: Scalars['Boolean']['output'];
   /** Update a user's profile details. */
   updateUser: IdObject;
+  /**
+   * Change how the progress that an integration of the currently logged in
+   * user sends is applied.
+   */
+  updateUserIntegrationProgressSettings: Scalars['Boolean']['output'];
+  /**
+   * Save the progress of profiles on the media server of a Jellyfin or Plex
+   * integration of the currently logged in user to other users. Only admins
+   * can map profiles, since the progress is saved to the accounts of others.
+   */
+  updateUserIntegrationUserMappings: Scalars['Boolean']['output'];
   /** Change a user's preferences. */
   updateUserPreference: Scalars['Boolean']['output'];
+  /** Verify the email of a user using the token that was sent to it. */
+  verifyUserEmail: Scalars['Boolean']['output'];
   /** Yank data from all integrations for the currently logged in user. */
   yankIntegrationData: Scalars['Int']['output'];
 };
@@ -839,6 +1460,16 @@ export type MutationRootAddMediaToCollectionArgs = {
 };
 
 
+export type MutationRootAddSeenAttachmentArgs = {
+  input: AddSeenAttachmentInput;
+};
+
+
+export type MutationRootAddSeenCompanionArgs = {
+  input: AddSeenCompanionInput;
+};
+
+
 export type MutationRootBulkProgressUpdateArgs = {
   input: Array<ProgressUpdateInput>;
 };
@@ -866,6 +1497,11 @@ export type MutationRootCreateOrUpdateCollectionArgs = {
 };
 
 
+export type MutationRootCreateOrUpdateHighlightArgs = {
+  input: CreateOrUpdateHighlightInput;
+};
+
+
 export type MutationRootCreateReviewCommentArgs = {
   input: CreateReviewCommentInput;
 };
@@ -901,6 +1537,11 @@ export type MutationRootDeleteCollectionArgs = {
 };
 
 
+export type MutationRootDeleteHighlightArgs = {
+  highlightId: Scalars['Int']['input'];
+};
+
+
 export type MutationRootDeleteMediaReminderArgs = {
   metadataId: Scalars['Int']['input'];
 };
@@ -911,6 +1552,16 @@ export type MutationRootDeleteReviewArgs = {
 };
 
 
+export type MutationRootDeleteSeenAttachmentArgs = {
+  attachmentId: Scalars['Int']['input'];
+};
+
+
+export type MutationRootDeleteSeenCompanionArgs = {
+  companionId: Scalars['Int']['input'];
+};
+
+
 export type MutationRootDeleteSeenItemArgs = {
   seenId: Scalars['Int']['input'];
 };
@@ -947,6 +1598,18 @@ export type MutationRootDeployUpdateMetadataJobArgs = {
 };
 
 
+export type MutationRootLinkIntegrationMappingArgs = {
+  mappingId: Scalars['Int']['input'];
+  metadataId: Scalars['Int']['input'];
+};
+
+
+export type MutationRootLinkKoreaderDocumentArgs = {
+  documentId: Scalars['Int']['input'];
+  metadataId: Scalars['Int']['input'];
+};
+
+
 export type MutationRootLoginUserArgs = {
   input: UserInput;
 };
@@ -973,6 +1636,11 @@ export type MutationRootProgressUpdateArgs = {
 };
 
 
+export type MutationRootRegenerateUserSummaryArgs = {
+  userId?: InputMaybe<Scalars['Int']['input']>;
+};
+
+
 export type MutationRootRegisterUserArgs = {
   input: UserInput;
 };
@@ -984,6 +1652,32 @@ export type MutationRootRemoveMediaFromCollectionArgs = {
 };
 
 
+export type MutationRootRequestUserEmailVerificationArgs = {
+  username: Scalars['String']['input'];
+};
+
+
+export type MutationRootRequestUserPasswordResetArgs = {
+  username: Scalars['String']['input'];
+};
+
+
+export type MutationRootResetUserPasswordArgs = {
+  input: ResetUserPasswordInput;
+};
+
+
+export type MutationRootRespondToSeenCompanionArgs = {
+  accept: Scalars['Boolean']['input'];
+  companionId: Scalars['Int']['input'];
+};
+
+
+export type MutationRootToggleMediaFavoriteArgs = {
+  metadataId: Scalars['Int']['input'];
+};
+
+
 export type MutationRootToggleMediaMonitorArgs = {
   toMonitorMetadataId: Scalars['Int']['input'];
 };
@@ -994,10 +1688,28 @@ export type MutationRootUpdateUserArgs = {
 };
 
 
+export type MutationRootUpdateUserIntegrationProgressSettingsArgs = {
+  integrationId: Scalars['Int']['input'];
+  integrationLot: UserIntegrationLot;
+  progressSettings: IntegrationProgressSettingsInput;
+};
+
+
+export type MutationRootUpdateUserIntegrationUserMappingsArgs = {
+  integrationId: Scalars['Int']['input'];
+  userMappings: Array<IntegrationUserMappingInput>;
+};
+
+
 export type MutationRootUpdateUserPreferenceArgs = {
   input: UpdateUserPreferenceInput;
 };
 
+
+export type MutationRootVerifyUserEmailArgs = {
+  token: Scalars['String']['input'];
+};
+
 export type PartialMetadata = {
   identifier: Scalars['String']['output'];
   image?: Maybe<Scalars['String']['output']>;
@@ -1007,6 +1719,19 @@ export type PartialMetadata = {
   title: Scalars['String']['output'];
 };
 
+/**
+ * A seen item of another user that the current user was tagged in and has not
+ * responded to yet.
+ */
+export type PendingSeenCompanion = {
+  companionId: Scalars['Int']['output'];
+  metadataId: Scalars['Int']['output'];
+  metadataTitle: Scalars['String']['output'];
+  seen: Seen;
+  /** The username of the user that tagged them. */
+  taggedBy: Scalars['String']['output'];
+};
+
 export type PodcastEpisode = {
   id: Scalars['String']['output'];
   number: Scalars['Int']['output'];
@@ -1075,6 +1800,11 @@ export enum ProgressUpdateErrorVariant {
 
 export type ProgressUpdateInput = {
   changeState?: InputMaybe<SeenState>;
+  /**
+   * Where or how the media was seen. Must be one of the contexts that the
+   * user has configured for the type of media.
+   */
+  context?: InputMaybe<Scalars['String']['input']>;
   date?: InputMaybe<Scalars['NaiveDate']['input']>;
   metadataId: Scalars['Int']['input'];
   podcastEpisodeNumber?: InputMaybe<Scalars['Int']['input']>;
@@ -1092,6 +1822,16 @@ export type ProviderLanguageInformation = {
 };
 
 export type QueryRoot = {
+  /**
+   * Get the announcements made by the admins that have not expired yet, the
+   * most recent first.
+   */
+  announcements: Array<Announcement>;
+  /**
+   * Get the entries of the audit log, eg: failed logins and lockouts, the most
+   * recent first. User must be an `Admin`.
+   */
+  auditLog: AuditLogResults;
   /** Get the contents of a collection and respect visibility. */
   collectionContents: CollectionContents;
   /** Get all collections for the currently logged in user. */
@@ -1108,12 +1848,32 @@ export type QueryRoot = {
   exerciseDetails: Exercise;
   /** Get all the parameters related to exercises. */
   exerciseParameters: ExerciseParameters;
+  /**
+   * Get exercises that work the same muscles as an exercise and can be
+   * performed with the equipment available to the user.
+   */
+  exerciseSubstitutions: Array<Exercise>;
   /** Get a paginated list of exercises in the database. */
   exercisesList: ExerciseSearchResults;
-  /** Get a presigned URL (valid for 90 minutes) for a given key. */
+  /** Get a short-lived presigned URL for a file uploaded by the user. */
   getPresignedUrl: Scalars['String']['output'];
+  /**
+   * Get the highlights that the currently logged in user made, optionally
+   * only the ones in a specific book.
+   */
+  highlights: Array<Highlight>;
   /** Get all the import jobs deployed by the user. */
   importReports: Array<ImportReport>;
+  /**
+   * Get the titles and IDs that the integrations of the currently logged in
+   * user sent, along with the media that they are mapped to.
+   */
+  integrationMappings: Array<IntegrationMapping>;
+  /**
+   * Get the documents that KOReader synced the progress of for the currently
+   * logged in user.
+   */
+  koreaderDocuments: Array<KoreaderDocument>;
   /** Get a summary of all the media items that have been consumed by this user. */
   latestUserSummary: UserSummary;
   /** Get details about a media present in the database. */
@@ -1128,6 +1888,11 @@ export type QueryRoot = {
   metadataGroupDetails: MetadataGroupDetails;
   /** Get paginated list of metadata groups. */
   metadataGroupsList: MetadataGroupListResults;
+  /**
+   * Get the seen items of other users that the currently logged in user was
+   * tagged in and has not confirmed or declined yet.
+   */
+  pendingSeenCompanions: Array<PendingSeenCompanion>;
   /** Get all languages supported by all the providers. */
   providersLanguageInformation: Array<ProviderLanguageInformation>;
   /** Get a review by its ID. */
@@ -1157,6 +1922,11 @@ export type QueryRoot = {
 };
 
 
+export type QueryRootAuditLogArgs = {
+  page?: InputMaybe<Scalars['Int']['input']>;
+};
+
+
 export type QueryRootCollectionContentsArgs = {
   input: CollectionContentsInput;
 };
@@ -1182,6 +1952,11 @@ export type QueryRootExerciseDetailsArgs = {
 };
 
 
+export type QueryRootExerciseSubstitutionsArgs = {
+  exerciseId: Scalars['Int']['input'];
+};
+
+
 export type QueryRootExercisesListArgs = {
   input: ExercisesListInput;
 };
@@ -1192,6 +1967,11 @@ export type QueryRootGetPresignedUrlArgs = {
 };
 
 
+export type QueryRootHighlightsArgs = {
+  metadataId?: InputMaybe<Scalars['Int']['input']>;
+};
+
+
 export type QueryRootMediaDetailsArgs = {
   metadataId: Scalars['Int']['input'];
 };
@@ -1203,6 +1983,7 @@ export type QueryRootMediaListArgs = {
 
 
 export type QueryRootMediaSearchArgs = {
+  filters?: InputMaybe<MediaSearchFilters>;
   input: SearchInput;
   lot: MetadataLot;
   source: MetadataSource;
@@ -1264,11 +2045,18 @@ export type RegisterError = {
 
 export enum RegisterErrorVariant {
   Disabled = 'DISABLED',
+  EmailRequired = 'EMAIL_REQUIRED',
   UsernameAlreadyExists = 'USERNAME_ALREADY_EXISTS'
 }
 
 export type RegisterResult = IdObject | RegisterError;
 
+export type ResetUserPasswordInput = {
+  password: Scalars['String']['input'];
+  /** The token that was sent to the email of the user. */
+  token: Scalars['String']['input'];
+};
+
 export type ReviewCommentUser = {
   id: Scalars['Int']['output'];
   name: Scalars['String']['output'];
@@ -1304,6 +2092,10 @@ export type SearchInput = {
 };
 
 export type Seen = {
+  attachments: Array<SeenAttachment>;
+  companions: Array<SeenCompanion>;
+  /** Where or how the media was seen, eg: `Cinema` or `Ebook`. */
+  context?: Maybe<Scalars['String']['output']>;
   finishedOn?: Maybe<Scalars['NaiveDate']['output']>;
   id: Scalars['Int']['output'];
   lastUpdatedOn: Scalars['DateTime']['output'];
@@ -1311,12 +2103,38 @@ export type Seen = {
   numTimesUpdated?: Maybe<Scalars['Int']['output']>;
   podcastInformation?: Maybe<SeenPodcastExtraInformation>;
   progress: Scalars['Int']['output'];
+  /**
+   * The rating given at the time the media was seen, in the review scale of
+   * the user.
+   */
+  rating?: Maybe<Scalars['Decimal']['output']>;
   showInformation?: Maybe<SeenShowExtraInformation>;
   startedOn?: Maybe<Scalars['NaiveDate']['output']>;
   state: SeenState;
   userId: Scalars['Int']['output'];
 };
 
+export type SeenAttachment = {
+  createdOn: Scalars['DateTime']['output'];
+  id: Scalars['Int']['output'];
+  /** The presigned URL of the file. */
+  url: Scalars['String']['output'];
+};
+
+export type SeenCompanion = {
+  createdOn: Scalars['DateTime']['output'];
+  id: Scalars['Int']['output'];
+  /**
+   * The copy of the seen item that was created for the tagged user. `None`
+   * until they confirm it.
+   */
+  mirroredSeenId?: Maybe<Scalars['Int']['output']>;
+  /** The name of the companion, or their username if they use this instance. */
+  name: Scalars['String']['output'];
+  /** The user of this instance that was tagged, if any. */
+  userId?: Maybe<Scalars['Int']['output']>;
+};
+
 export type SeenPodcastExtraInformation = {
   episode: Scalars['Int']['output'];
 };
@@ -1341,15 +2159,23 @@ export enum SetLot {
 }
 
 export type SetStatistic = {
+  /** The average heart rate in beats per minute. */
+  averageHeartRate?: Maybe<Scalars['Int']['output']>;
   distance?: Maybe<Scalars['Decimal']['output']>;
   duration?: Maybe<Scalars['Decimal']['output']>;
+  /** The elevation gained, in meters. */
+  elevationGain?: Maybe<Scalars['Decimal']['output']>;
   reps?: Maybe<Scalars['Int']['output']>;
   weight?: Maybe<Scalars['Decimal']['output']>;
 };
 
 export type SetStatisticInput = {
+  /** The average heart rate in beats per minute. */
+  averageHeartRate?: InputMaybe<Scalars['Int']['input']>;
   distance?: InputMaybe<Scalars['Decimal']['input']>;
   duration?: InputMaybe<Scalars['Decimal']['input']>;
+  /** The elevation gained, in meters. */
+  elevationGain?: InputMaybe<Scalars['Decimal']['input']>;
   reps?: InputMaybe<Scalars['Int']['input']>;
   weight?: InputMaybe<Scalars['Decimal']['input']>;
 };
@@ -1414,6 +2240,7 @@ export type ShowsSummary = {
 export type TotalMeasurement = {
   distance: Scalars['Decimal']['output'];
   duration: Scalars['Decimal']['output'];
+  elevationGain: Scalars['Decimal']['output'];
   /** The number of personal bests achieved. */
   personalBestsAchieved: Scalars['Int']['output'];
   reps: Scalars['Int']['output'];
@@ -1471,6 +2298,24 @@ export enum UserDetailsErrorVariant {
 
 export type UserDetailsResult = User | UserDetailsError;
 
+/**
+ * The equipment available to the user, in the units of their preferred unit
+ * system. Used to calculate the plates to load for a weight.
+ */
+export type UserEquipmentPreferences = {
+  /**
+   * The kinds of equipment the user has access to. Empty means that all of
+   * them are available.
+   */
+  available: Array<ExerciseEquipment>;
+  /** The weights of the bars that are available. */
+  bars: Array<Scalars['Decimal']['output']>;
+  /** The difference in weight between consecutive dumbbells. */
+  dumbbellIncrement: Scalars['Decimal']['output'];
+  /** The weights of the plates that are available in pairs. */
+  plates: Array<Scalars['Decimal']['output']>;
+};
+
 export type UserExerciseHistoryInformation = {
   sets: Array<WorkoutSetRecord>;
   workoutId: Scalars['String']['output'];
@@ -1481,6 +2326,8 @@ export type UserExerciseHistoryInformation = {
 export type UserExerciseInformation = {
   details: UserToExercise;
   history: Array<UserExerciseHistoryInformation>;
+  images: Array<Scalars['String']['output']>;
+  videos: Array<Scalars['String']['output']>;
 };
 
 export type UserExerciseInput = {
@@ -1506,21 +2353,32 @@ export type UserFitnessFeaturesEnabledPreferences = {
 };
 
 export type UserFitnessPreferences = {
+  equipment: UserEquipmentPreferences;
   exercises: UserExercisePreferences;
   measurements: UserMeasurementsPreferences;
 };
 
 export type UserFitnessSummary = {
   measurementsRecorded: Scalars['Int']['output'];
+  personalBestsAchieved: Scalars['Int']['output'];
+  /** The total distance covered, in kilometers. */
+  totalDistance: Scalars['Decimal']['output'];
+  /** The total weight lifted, in kilograms. */
+  totalVolume: Scalars['Decimal']['output'];
   workoutsRecorded: Scalars['Int']['output'];
 };
 
 export type UserGeneralPreferences = {
-  displayNsfw: Scalars['Boolean']['output'];
+  nsfwDisplay: UserNsfwDisplay;
   reviewScale: UserReviewScale;
 };
 
 export type UserInput = {
+  /**
+   * Only used when registering. Required if the instance needs new users to
+   * verify their email.
+   */
+  email?: InputMaybe<Scalars['String']['input']>;
   password: Scalars['String']['input'];
   username: Scalars['String']['input'];
 };
@@ -1642,16 +2500,28 @@ export type UserMeasurementsPreferences = {
 export type UserMediaDetails = {
   /** The average rating of this media in this service. */
   averageRating?: Maybe<Scalars['Decimal']['output']>;
+  /**
+   * The average of the ratings given when this media was seen by the users of
+   * this service.
+   */
+  averageSeenRating?: Maybe<Scalars['Decimal']['output']>;
   /** The collections in which this media is present. */
   collections: Array<Collection>;
   /** The seen history of this media. */
   history: Array<Seen>;
   /** The seen item if it is in progress. */
   inProgress?: Maybe<Seen>;
+  /** Whether the user has marked this media as a favorite. */
+  isFavorite: Scalars['Boolean']['output'];
   /** Whether the user is monitoring this media. */
   isMonitored: Scalars['Boolean']['output'];
   /** The next episode of this media. */
   nextEpisode?: Maybe<UserMediaNextEpisode>;
+  /**
+   * The total time in minutes that the user played this game for, as synced
+   * by an integration.
+   */
+  playtime?: Maybe<Scalars['Int']['output']>;
   /** The reminder that the user has set for this media. */
   reminder?: Maybe<UserMediaReminder>;
   /** The public reviews of this media. */
@@ -1662,11 +2532,15 @@ export type UserMediaDetails = {
 
 export type UserMediaFeaturesEnabledPreferences = {
   anime: Scalars['Boolean']['output'];
+  article: Scalars['Boolean']['output'];
   audioBook: Scalars['Boolean']['output'];
+  boardGame: Scalars['Boolean']['output'];
   book: Scalars['Boolean']['output'];
+  comic: Scalars['Boolean']['output'];
   enabled: Scalars['Boolean']['output'];
   manga: Scalars['Boolean']['output'];
   movie: Scalars['Boolean']['output'];
+  music: Scalars['Boolean']['output'];
   podcast: Scalars['Boolean']['output'];
   show: Scalars['Boolean']['output'];
   videoGame: Scalars['Boolean']['output'];
@@ -1685,11 +2559,17 @@ export type UserMediaReminder = {
 
 export type UserMediaSummary = {
   anime: AnimeSummary;
+  articles: ArticlesSummary;
   audioBooks: AudioBooksSummary;
+  boardGames: BoardGamesSummary;
   books: BooksSummary;
+  comics: ComicsSummary;
   creatorsInteractedWith: Scalars['Int']['output'];
+  /** The number of media items that the user has marked as a favorite. */
+  favorites: Scalars['Int']['output'];
   manga: MangaSummary;
   movies: MoviesSummary;
+  music: MusicSummary;
   podcasts: PodcastsSummary;
   reviewsPosted: Scalars['Int']['output'];
   shows: ShowsSummary;
@@ -1712,15 +2592,27 @@ export type UserNotificationsPreferences = {
   episodeReleased: Scalars['Boolean']['output'];
   numberOfChaptersOrEpisodesChanged: Scalars['Boolean']['output'];
   numberOfSeasonsChanged: Scalars['Boolean']['output'];
+  personalBestAchieved: Scalars['Boolean']['output'];
   releaseDateChanged: Scalars['Boolean']['output'];
   statusChanged: Scalars['Boolean']['output'];
 };
 
+export enum UserNsfwDisplay {
+  /** Return NSFW media, but it needs to be clicked through before it is shown. */
+  Blur = 'BLUR',
+  /** Do not return NSFW media at all. */
+  Hide = 'HIDE',
+  /** Return NSFW media like any other media. */
+  Show = 'SHOW'
+}
+
 export type UserPreferences = {
   featuresEnabled: UserFeaturesEnabledPreferences;
   fitness: UserFitnessPreferences;
   general: UserGeneralPreferences;
   notifications: UserNotificationsPreferences;
+  seenContexts: UserSeenContextPreferences;
+  timeEstimation: UserTimeEstimationPreferences;
 };
 
 export enum UserReviewScale {
@@ -1728,9 +2620,31 @@ export enum UserReviewScale {
   OutOfHundred = 'OUT_OF_HUNDRED'
 }
 
+/**
+ * The contexts (where or how the media was seen) that can be picked for a seen
+ * item, for each type of media. An empty list disables them for that type.
+ */
+export type UserSeenContextPreferences = {
+  anime: Array<Scalars['String']['output']>;
+  article: Array<Scalars['String']['output']>;
+  audioBook: Array<Scalars['String']['output']>;
+  boardGame: Array<Scalars['String']['output']>;
+  book: Array<Scalars['String']['output']>;
+  comic: Array<Scalars['String']['output']>;
+  manga: Array<Scalars['String']['output']>;
+  movie: Array<Scalars['String']['output']>;
+  music: Array<Scalars['String']['output']>;
+  podcast: Array<Scalars['String']['output']>;
+  show: Array<Scalars['String']['output']>;
+  videoGame: Array<Scalars['String']['output']>;
+  visualNovel: Array<Scalars['String']['output']>;
+};
+
 export enum UserSinkIntegrationSettingKind {
   Jellyfin = 'JELLYFIN',
+  Kobo = 'KOBO',
   Kodi = 'KODI',
+  Koreader = 'KOREADER',
   Plex = 'PLEX'
 }
 
@@ -1740,6 +2654,19 @@ export type UserSummary = {
   media: UserMediaSummary;
 };
 
+/** The assumptions used to estimate the time spent on media in the summary. */
+export type UserTimeEstimationPreferences = {
+  /**
+   * The runtime (in minutes) of the episodes of shows and podcasts that do
+   * not have one.
+   */
+  defaultEpisodeRuntime?: Maybe<Scalars['Int']['output']>;
+  /** The reading speed used to estimate the time spent reading books. */
+  pagesPerHour?: Maybe<Scalars['Int']['output']>;
+  /** The speed at which podcasts are usually played, eg: `1.5`. */
+  podcastPlaybackSpeed: Scalars['Decimal']['output'];
+};
+
 export type UserToExercise = {
   exerciseId: Scalars['Int']['output'];
   extraInformation: UserToExerciseExtraInformation;
@@ -1753,6 +2680,11 @@ export type UserToExerciseBestSetExtraInformation = {
 };
 
 export type UserToExerciseExtraInformation = {
+  /**
+   * The rest time (in seconds) to use after each set of this exercise, unless
+   * overridden in the workout.
+   */
+  defaultRestTime?: Maybe<Scalars['Int']['output']>;
   history: Array<UserToExerciseHistoryExtraInformation>;
   lifetimeStats: TotalMeasurement;
   personalBests: Array<UserToExerciseBestSetExtraInformation>;
@@ -1777,9 +2709,11 @@ export type UserWorkoutInput = {
   comment?: InputMaybe<Scalars['String']['input']>;
   endTime: Scalars['DateTime']['input'];
   exercises: Array<UserExerciseInput>;
+  groups: Array<ExerciseGroupInput>;
   name?: InputMaybe<Scalars['String']['input']>;
   startTime: Scalars['DateTime']['input'];
-  supersets: Array<Array<Scalars['Int']['input']>>;
+  /** The template this workout was started from, if any. */
+  templateId?: InputMaybe<Scalars['String']['input']>;
 };
 
 export type UserWorkoutSetRecord = {
@@ -1788,14 +2722,21 @@ export type UserWorkoutSetRecord = {
 };
 
 export enum UserYankIntegrationSettingKind {
-  Audiobookshelf = 'AUDIOBOOKSHELF'
+  Audiobookshelf = 'AUDIOBOOKSHELF',
+  RetroAchievements = 'RETRO_ACHIEVEMENTS',
+  Steam = 'STEAM',
+  Strava = 'STRAVA'
 }
 
 export type VideoGameSpecifics = {
+  /** The number of achievements of the game, as synced by an integration. */
+  achievements?: Maybe<Scalars['Int']['output']>;
   platforms: Array<Scalars['String']['output']>;
 };
 
 export type VideoGameSpecificsInput = {
+  /** The number of achievements of the game, as synced by an integration. */
+  achievements?: InputMaybe<Scalars['Int']['input']>;
   platforms: Array<Scalars['String']['input']>;
 };
 
@@ -1824,6 +2765,7 @@ export type VisualNovelsSummary = {
 export enum WorkoutSetPersonalBest {
   OneRm = 'ONE_RM',
   Pace = 'PACE',
+  Reps = 'REPS',
   Time = 'TIME',
   Volume = 'VOLUME',
   Weight = 'WEIGHT'
@@ -1842,6 +2784,20 @@ export type AddMediaToCollectionMutationVariables = Exact<{
 
 export type AddMediaToCollectionMutation = { addMediaToCollection: boolean };
 
+export type AddSeenAttachmentMutationVariables = Exact<{
+  input: AddSeenAttachmentInput;
+}>;
+
+
+export type AddSeenAttachmentMutation = { addSeenAttachment: { id: number } };
+
+export type AddSeenCompanionMutationVariables = Exact<{
+  input: AddSeenCompanionInput;
+}>;
+
+
+export type AddSeenCompanionMutation = { addSeenCompanion: { id: number } };
+
 export type BulkProgressUpdateMutationVariables = Exact<{
   input: Array<ProgressUpdateInput> | ProgressUpdateInput;
 }>;
@@ -1879,6 +2835,13 @@ export type CreateOrUpdateCollectionMutationVariables = Exact<{
 
 export type CreateOrUpdateCollectionMutation = { createOrUpdateCollection: { id: number } };
 
+export type CreateOrUpdateHighlightMutationVariables = Exact<{
+  input: CreateOrUpdateHighlightInput;
+}>;
+
+
+export type CreateOrUpdateHighlightMutation = { createOrUpdateHighlight: { id: number } };
+
 export type CreateReviewCommentMutationVariables = Exact<{
   input: CreateReviewCommentInput;
 }>;
@@ -1928,6 +2891,13 @@ export type DeleteCollectionMutationVariables = Exact<{
 
 export type DeleteCollectionMutation = { deleteCollection: boolean };
 
+export type DeleteHighlightMutationVariables = Exact<{
+  highlightId: Scalars['Int']['input'];
+}>;
+
+
+export type DeleteHighlightMutation = { deleteHighlight: boolean };
+
 export type DeleteMediaReminderMutationVariables = Exact<{
   metadataId: Scalars['Int']['input'];
 }>;
@@ -1942,6 +2912,20 @@ export type DeleteReviewMutationVariables = Exact<{
 
 export type DeleteReviewMutation = { deleteReview: boolean };
 
+export type DeleteSeenAttachmentMutationVariables = Exact<{
+  attachmentId: Scalars['Int']['input'];
+}>;
+
+
+export type DeleteSeenAttachmentMutation = { deleteSeenAttachment: boolean };
+
+export type DeleteSeenCompanionMutationVariables = Exact<{
+  companionId: Scalars['Int']['input'];
+}>;
+
+
+export type DeleteSeenCompanionMutation = { deleteSeenCompanion: boolean };
+
 export type DeleteSeenItemMutationVariables = Exact<{
   seenId: Scalars['Int']['input'];
 }>;
@@ -2002,6 +2986,14 @@ export type GenerateAuthTokenMutationVariables = Exact<{ [key: string]: never; }
 
 export type GenerateAuthTokenMutation = { generateAuthToken: string };
 
+export type LinkKoreaderDocumentMutationVariables = Exact<{
+  documentId: Scalars['Int']['input'];
+  metadataId: Scalars['Int']['input'];
+}>;
+
+
+export type LinkKoreaderDocumentMutation = { linkKoreaderDocument: boolean };
+
 export type LoginUserMutationVariables = Exact<{
   input: UserInput;
 }>;
@@ -2063,11 +3055,47 @@ export type RemoveMediaFromCollectionMutationVariables = Exact<{
 
 export type RemoveMediaFromCollectionMutation = { removeMediaFromCollection: { id: number } };
 
+export type RequestUserEmailVerificationMutationVariables = Exact<{
+  username: Scalars['String']['input'];
+}>;
+
+
+export type RequestUserEmailVerificationMutation = { requestUserEmailVerification: boolean };
+
+export type RequestUserPasswordResetMutationVariables = Exact<{
+  username: Scalars['String']['input'];
+}>;
+
+
+export type RequestUserPasswordResetMutation = { requestUserPasswordReset: boolean };
+
+export type ResetUserPasswordMutationVariables = Exact<{
+  input: ResetUserPasswordInput;
+}>;
+
+
+export type ResetUserPasswordMutation = { resetUserPassword: boolean };
+
+export type RespondToSeenCompanionMutationVariables = Exact<{
+  companionId: Scalars['Int']['input'];
+  accept: Scalars['Boolean']['input'];
+}>;
+
+
+export type RespondToSeenCompanionMutation = { respondToSeenCompanion: boolean };
+
 export type TestUserNotificationPlatformsMutationVariables = Exact<{ [key: string]: never; }>;
 
 
 export type TestUserNotificationPlatformsMutation = { testUserNotificationPlatforms: boolean };
 
+export type ToggleMediaFavoriteMutationVariables = Exact<{
+  metadataId: Scalars['Int']['input'];
+}>;
+
+
+export type ToggleMediaFavoriteMutation = { toggleMediaFavorite: boolean };
+
 export type ToggleMediaMonitorMutationVariables = Exact<{
   toMonitorMetadataId: Scalars['Int']['input'];
 }>;
@@ -2094,11 +3122,23 @@ export type UpdateUserPreferenceMutationVariables = Exact<{
 
 export type UpdateUserPreferenceMutation = { updateUserPreference: boolean };
 
+export type VerifyUserEmailMutationVariables = Exact<{
+  token: Scalars['String']['input'];
+}>;
+
+
+export type VerifyUserEmailMutation = { verifyUserEmail: boolean };
+
 export type YankIntegrationDataMutationVariables = Exact<{ [key: string]: never; }>;
 
 
 export type YankIntegrationDataMutation = { yankIntegrationData: number };
 
+export type AnnouncementsQueryVariables = Exact<{ [key: string]: never; }>;
+
+
+export type AnnouncementsQuery = { announcements: Array<{ id: number, message: string, severity: AnnouncementSeverity, createdOn: any, expiresOn?: any | null }> };
+
 export type CollectionContentsQueryVariables = Exact<{
   input: CollectionContentsInput;
 }>;
@@ -2111,7 +3151,7 @@ export type CollectionsQueryVariables = Exact<{
 }>;
 
 
-export type CollectionsQuery = { collections: Array<{ id: number, name: string, description?: string | null, visibility: Visibility, numItems: number }> };
+export type CollectionsQuery = { collections: Array<{ id: number, name: string, description?: string | null, visibility: Visibility, numItems: number, actions: Array<{ lot: CollectionActionLot, collectionName?: string | null }> }> };
 
 export type CoreDetailsQueryVariables = Exact<{ [key: string]: never; }>;
 
@@ -2121,7 +3161,7 @@ export type CoreDetailsQuery = { coreDetails: { version: string, authorName: str
 export type CoreEnabledFeaturesQueryVariables = Exact<{ [key: string]: never; }>;
 
 
-export type CoreEnabledFeaturesQuery = { coreEnabledFeatures: { fileStorage: boolean, signupAllowed: boolean } };
+export type CoreEnabledFeaturesQuery = { coreEnabledFeatures: { fileStorage: boolean, signupAllowed: boolean, passwordResetAllowed: boolean, emailVerificationRequired: boolean } };
 
 export type CreatorDetailsQueryVariables = Exact<{
   creatorId: Scalars['Int']['input'];
@@ -2163,22 +3203,34 @@ export type GetPresignedUrlQueryVariables = Exact<{
 
 export type GetPresignedUrlQuery = { getPresignedUrl: string };
 
+export type HighlightsQueryVariables = Exact<{
+  metadataId?: InputMaybe<Scalars['Int']['input']>;
+}>;
+
+
+export type HighlightsQuery = { highlights: Array<{ id: number, metadataId: number, text: string, page?: number | null, chapter?: string | null, note?: string | null }> };
+
 export type ImportReportsQueryVariables = Exact<{ [key: string]: never; }>;
 
 
 export type ImportReportsQuery = { importReports: Array<{ id: number, source: ImportSource, startedOn: Date, finishedOn?: Date | null, success?: boolean | null, details?: { import: { total: number }, failedItems: Array<{ lot: MetadataLot, step: ImportFailStep, identifier: string, error?: string | null }> } | null }> };
 
+export type KoreaderDocumentsQueryVariables = Exact<{ [key: string]: never; }>;
+
+
+export type KoreaderDocumentsQuery = { koreaderDocuments: Array<{ id: number, document: string, metadataId?: number | null, percentage: any, device: string, updatedOn: Date }> };
+
 export type LatestUserSummaryQueryVariables = Exact<{ [key: string]: never; }>;
 
 
-export type LatestUserSummaryQuery = { latestUserSummary: { calculatedOn: Date, fitness: { measurementsRecorded: number, workoutsRecorded: number }, media: { reviewsPosted: number, creatorsInteractedWith: number, manga: { chapters: number, read: number }, books: { pages: number, read: number }, movies: { runtime: number, watched: number }, anime: { episodes: number, watched: number }, podcasts: { runtime: number, played: number, playedEpisodes: number }, visualNovels: { played: number, runtime: number }, videoGames: { played: number }, shows: { runtime: number, watchedEpisodes: number, watchedSeasons: number, watched: number }, audioBooks: { runtime: number, played: number } } } };
+export type LatestUserSummaryQuery = { latestUserSummary: { calculatedOn: Date, fitness: { measurementsRecorded: number, workoutsRecorded: number, totalVolume: any, totalDistance: any, personalBestsAchieved: number }, media: { reviewsPosted: number, creatorsInteractedWith: number, favorites: number, manga: { chapters: number, read: number }, books: { pages: number, read: number, runtime: number }, movies: { runtime: number, watched: number }, anime: { episodes: number, watched: number }, podcasts: { runtime: number, played: number, playedEpisodes: number }, visualNovels: { played: number, runtime: number }, articles: { read: number, runtime: number }, music: { listened: number, runtime: number }, boardGames: { played: number, runtime: number }, comics: { issues: number, read: number }, videoGames: { played: number }, shows: { runtime: number, watchedEpisodes: number, watchedSeasons: number, watched: number }, audioBooks: { runtime: number, played: number } } } };
 
 export type MediaDetailsQueryVariables = Exact<{
   metadataId: Scalars['Int']['input'];
 }>;
 
 
-export type MediaDetailsQuery = { mediaDetails: { title: string, description?: string | null, identifier: string, lot: MetadataLot, source: MetadataSource, isNsfw: boolean, providerRating?: any | null, publishYear?: number | null, publishDate?: string | null, genres: Array<string>, sourceUrl?: string | null, creators: Array<{ name: string, items: Array<{ id: number, name: string, image?: string | null }> }>, assets: { images: Array<string>, videos: Array<{ videoId: string, source: MetadataVideoSource }> }, group?: { id: number, name: string, part: number } | null, suggestions: Array<{ lot: MetadataLot, source: MetadataSource, identifier: string, title: string, image?: string | null, metadataId?: number | null }>, animeSpecifics?: { episodes?: number | null } | null, audioBookSpecifics?: { runtime?: number | null } | null, bookSpecifics?: { pages?: number | null } | null, movieSpecifics?: { runtime?: number | null } | null, mangaSpecifics?: { volumes?: number | null, chapters?: number | null } | null, podcastSpecifics?: { totalEpisodes: number, episodes: Array<{ title: string, overview?: string | null, thumbnail?: string | null, number: number, runtime?: number | null, publishDate: string }> } | null, showSpecifics?: { seasons: Array<{ seasonNumber: number, name: string, overview?: string | null, backdropImages: Array<string>, posterImages: Array<string>, episodes: Array<{ id: number, name: string, posterImages: Array<string>, episodeNumber: number, publishDate?: string | null, overview?: string | null, runtime?: number | null }> }> } | null, visualNovelSpecifics?: { length?: number | null } | null, videoGameSpecifics?: { platforms: Array<string> } | null } };
+export type MediaDetailsQuery = { mediaDetails: { title: string, description?: string | null, identifier: string, lot: MetadataLot, source: MetadataSource, isNsfw: boolean, providerRating?: any | null, publishYear?: number | null, publishDate?: string | null, genres: Array<string>, sourceUrl?: string | null, creators: Array<{ name: string, items: Array<{ id: number, name: string, image?: string | null }> }>, assets: { images: Array<string>, videos: Array<{ videoId: string, source: MetadataVideoSource }> }, group?: { id: number, name: string, part: number } | null, suggestions: Array<{ lot: MetadataLot, source: MetadataSource, identifier: string, title: string, image?: string | null, metadataId?: number | null }>, animeSpecifics?: { episodes?: number | null } | null, audioBookSpecifics?: { runtime?: number | null } | null, boardGameSpecifics?: { minPlayers?: number | null, maxPlayers?: number | null, playtime?: number | null } | null, bookSpecifics?: { pages?: number | null } | null, comicSpecifics?: { issues?: number | null } | null, movieSpecifics?: { runtime?: number | null } | null, mangaSpecifics?: { volumes?: number | null, chapters?: number | null } | null, podcastSpecifics?: { totalEpisodes: number, episodes: Array<{ title: string, overview?: string | null, thumbnail?: string | null, number: number, runtime?: number | null, publishDate: string }> } | null, showSpecifics?: { seasons: Array<{ seasonNumber: number, name: string, overview?: string | null, backdropImages: Array<string>, posterImages: Array<string>, episodes: Array<{ id: number, name: string, posterImages: Array<string>, episodeNumber: number, publishDate?: string | null, overview?: string | null, runtime?: number | null }> }> } | null, articleSpecifics?: { siteName?: string | null, words?: number | null, readingTime?: number | null } | null, musicSpecifics?: { tracks?: number | null, duration?: number | null } | null, visualNovelSpecifics?: { length?: number | null } | null, videoGameSpecifics?: { platforms: Array<string>, achievements?: number | null } | null } };
 
 export type MediaListQueryVariables = Exact<{
   input: MediaListInput;
@@ -2217,6 +3269,11 @@ export type MetadataGroupsListQueryVariables = Exact<{
 
 export type MetadataGroupsListQuery = { metadataGroupsList: { details: { total: number, nextPage?: number | null }, items: Array<{ id: number, title: string, lot: MetadataLot, parts: number, image?: string | null }> } };
 
+export type PendingSeenCompanionsQueryVariables = Exact<{ [key: string]: never; }>;
+
+
+export type PendingSeenCompanionsQuery = { pendingSeenCompanions: Array<{ companionId: number, taggedBy: string, metadataId: number, metadataTitle: string, seen: { id: number, state: SeenState, finishedOn?: any | null, showInformation?: { episode: number, season: number } | null, podcastInformation?: { episode: number } | null } }> };
+
 export type ProvidersLanguageInformationQueryVariables = Exact<{ [key: string]: never; }>;
 
 
@@ -2260,7 +3317,7 @@ export type UserExerciseDetailsQuery = { userExerciseDetails?: { history: Array<
 export type UserIntegrationsQueryVariables = Exact<{ [key: string]: never; }>;
 
 
-export type UserIntegrationsQuery = { userIntegrations: Array<{ id: number, lot: UserIntegrationLot, description: string, timestamp: Date, slug?: string | null }> };
+export type UserIntegrationsQuery = { userIntegrations: Array<{ id: number, lot: UserIntegrationLot, description: string, timestamp: Date, slug?: string | null, secret?: string | null }> };
 
 export type UserMeasurementsListQueryVariables = Exact<{
   input: UserMeasurementsListInput;
@@ -2269,14 +3326,14 @@ export type UserMeasurementsListQueryVariables = Exact<{
 
 export type UserMeasurementsListQuery = { userMeasurementsList: Array<{ timestamp: Date, name?: string | null, comment?: string | null, stats: { weight?: any | null, bodyMassIndex?: any | null, totalBodyWater?: any | null, muscle?: any | null, leanBodyMass?: any | null, bodyFat?: any | null, boneMass?: any | null, visceralFat?: any | null, waistCircumference?: any | null, waistToHeightRatio?: any | null, hipCircumference?: any | null, waistToHipRatio?: any | null, chestCircumference?: any | null, thighCircumference?: any | null, bicepsCircumference?: any | null, neckCircumference?: any | null, bodyFatCaliper?: any | null, chestSkinfold?: any | null, abdominalSkinfold?: any | null, thighSkinfold?: any | null, basalMetabolicRate?: any | null, totalDailyEnergyExpenditure?: any | null, calories?: any | null, custom?: any | null } }> };
 
-export type SeenPartFragment = { id: number, progress: number, state: SeenState, startedOn?: string | null, finishedOn?: string | null, lastUpdatedOn: Date, numTimesUpdated?: number | null, showInformation?: { episode: number, season: number } | null, podcastInformation?: { episode: number } | null };
+export type SeenPartFragment = { id: number, progress: number, state: SeenState, startedOn?: string | null, finishedOn?: string | null, lastUpdatedOn: Date, numTimesUpdated?: number | null, rating?: any | null, context?: string | null, showInformation?: { episode: number, season: number } | null, podcastInformation?: { episode: number } | null, attachments: Array<{ id: number, url: string }>, companions: Array<{ id: number, name: string, userId?: number | null, mirroredSeenId?: number | null }> };
 
 export type UserMediaDetailsQueryVariables = Exact<{
   metadataId: Scalars['Int']['input'];
 }>;
 
 
-export type UserMediaDetailsQuery = { userMediaDetails: { averageRating?: any | null, isMonitored: boolean, seenBy: number, collections: Array<{ id: number, name: string }>, inProgress?: { id: number, progress: number, state: SeenState, startedOn?: string | null, finishedOn?: string | null, lastUpdatedOn: Date, numTimesUpdated?: number | null, showInformation?: { episode: number, season: number } | null, podcastInformation?: { episode: number } | null } | null, history: Array<{ id: number, progress: number, state: SeenState, startedOn?: string | null, finishedOn?: string | null, lastUpdatedOn: Date, numTimesUpdated?: number | null, showInformation?: { episode: number, season: number } | null, podcastInformation?: { episode: number } | null }>, reviews: Array<{ id: number, rating?: any | null, text?: string | null, spoiler: boolean, visibility: Visibility, showSeason?: number | null, showEpisode?: number | null, podcastEpisode?: number | null, postedOn: Date, postedBy: { id: number, name: string }, comments: Array<{ id: string, text: string, createdOn: Date, likedBy: Array<number>, user: { id: number, name: string } }> }>, reminder?: { remindOn: string, message: string } | null, nextEpisode?: { seasonNumber?: number | null, episodeNumber?: number | null } | null } };
+export type UserMediaDetailsQuery = { userMediaDetails: { averageRating?: any | null, averageSeenRating?: any | null, isMonitored: boolean, isFavorite: boolean, seenBy: number, playtime?: number | null, collections: Array<{ id: number, name: string }>, inProgress?: { id: number, progress: number, state: SeenState, startedOn?: string | null, finishedOn?: string | null, lastUpdatedOn: Date, numTimesUpdated?: number | null, rating?: any | null, context?: string | null, showInformation?: { episode: number, season: number } | null, podcastInformation?: { episode: number } | null, attachments: Array<{ id: number, url: string }>, companions: Array<{ id: number, name: string, userId?: number | null, mirroredSeenId?: number | null }> } | null, history: Array<{ id: number, progress: number, state: SeenState, startedOn?: string | null, finishedOn?: string | null, lastUpdatedOn: Date, numTimesUpdated?: number | null, rating?: any | null, context?: string | null, showInformation?: { episode: number, season: number } | null, podcastInformation?: { episode: number } | null, attachments: Array<{ id: number, url: string }>, companions: Array<{ id: number, name: string, userId?: number | null, mirroredSeenId?: number | null }> }>, reviews: Array<{ id: number, rating?: any | null, text?: string | null, spoiler: boolean, visibility: Visibility, showSeason?: number | null, showEpisode?: number | null, podcastEpisode?: number | null, postedOn: Date, postedBy: { id: number, name: string }, comments: Array<{ id: string, text: string, createdOn: Date, likedBy: Array<number>, user: { id: number, name: string } }> }>, reminder?: { remindOn: string, message: string } | null, nextEpisode?: { seasonNumber?: number | null, episodeNumber?: number | null } | null } };
 
 export type UserNotificationPlatformsQueryVariables = Exact<{ [key: string]: never; }>;
 
@@ -2286,7 +3343,7 @@ export type UserNotificationPlatformsQuery = { userNotificationPlatforms: Array<
 export type UserPreferencesQueryVariables = Exact<{ [key: string]: never; }>;
 
 
-export type UserPreferencesQuery = { userPreferences: { general: { reviewScale: UserReviewScale, displayNsfw: boolean }, fitness: { measurements: { custom: Array<{ name: string, dataType: UserCustomMeasurementDataType }>, inbuilt: { weight: boolean, bodyMassIndex: boolean, totalBodyWater: boolean, muscle: boolean, leanBodyMass: boolean, bodyFat: boolean, boneMass: boolean, visceralFat: boolean, waistCircumference: boolean, waistToHeightRatio: boolean, hipCircumference: boolean, waistToHipRatio: boolean, chestCircumference: boolean, thighCircumference: boolean, bicepsCircumference: boolean, neckCircumference: boolean, bodyFatCaliper: boolean, chestSkinfold: boolean, abdominalSkinfold: boolean, thighSkinfold: boolean, basalMetabolicRate: boolean, totalDailyEnergyExpenditure: boolean, calories: boolean } }, exercises: { saveHistory: number, unitSystem: UserUnitSystem } }, notifications: { episodeReleased: boolean, episodeNameChanged: boolean, statusChanged: boolean, releaseDateChanged: boolean, numberOfSeasonsChanged: boolean, numberOfChaptersOrEpisodesChanged: boolean }, featuresEnabled: { fitness: { enabled: boolean, measurements: boolean }, media: { enabled: boolean, anime: boolean, audioBook: boolean, book: boolean, manga: boolean, movie: boolean, podcast: boolean, show: boolean, videoGame: boolean, visualNovel: boolean } } } };
+export type UserPreferencesQuery = { userPreferences: { general: { reviewScale: UserReviewScale, nsfwDisplay: UserNsfwDisplay }, timeEstimation: { podcastPlaybackSpeed: any, defaultEpisodeRuntime?: number | null, pagesPerHour?: number | null }, fitness: { measurements: { custom: Array<{ name: string, dataType: UserCustomMeasurementDataType }>, inbuilt: { weight: boolean, bodyMassIndex: boolean, totalBodyWater: boolean, muscle: boolean, leanBodyMass: boolean, bodyFat: boolean, boneMass: boolean, visceralFat: boolean, waistCircumference: boolean, waistToHeightRatio: boolean, hipCircumference: boolean, waistToHipRatio: boolean, chestCircumference: boolean, thighCircumference: boolean, bicepsCircumference: boolean, neckCircumference: boolean, bodyFatCaliper: boolean, chestSkinfold: boolean, abdominalSkinfold: boolean, thighSkinfold: boolean, basalMetabolicRate: boolean, totalDailyEnergyExpenditure: boolean, calories: boolean } }, exercises: { saveHistory: number, unitSystem: UserUnitSystem }, equipment: { plates: Array<any>, bars: Array<any>, dumbbellIncrement: any, available: Array<ExerciseEquipment> } }, notifications: { episodeReleased: boolean, episodeNameChanged: boolean, statusChanged: boolean, releaseDateChanged: boolean, numberOfSeasonsChanged: boolean, numberOfChaptersOrEpisodesChanged: boolean, personalBestAchieved: boolean }, featuresEnabled: { fitness: { enabled: boolean, measurements: boolean }, media: { enabled: boolean, anime: boolean, article: boolean, audioBook: boolean, boardGame: boolean, book: boolean, comic: boolean, manga: boolean, movie: boolean, music: boolean, podcast: boolean, show: boolean, videoGame: boolean, visualNovel: boolean } }, seenContexts: { anime: Array<string>, article: Array<string>, audioBook: Array<string>, boardGame: Array<string>, book: Array<string>, comic: Array<string>, manga: Array<string>, movie: Array<string>, music: Array<string>, podcast: Array<string>, show: Array<string>, videoGame: Array<string>, visualNovel: Array<string> } } };
 
 export type UserUpcomingCalendarEventsQueryVariables = Exact<{
   input: UserUpcomingCalendarEventInput;
@@ -2301,13 +3358,16 @@ export type UsersListQueryVariables = Exact<{ [key: string]: never; }>;
 export type UsersListQuery = { usersList: Array<{ id: number, name: string, lot: UserLot }> };
 
 export const CalendarEventPartFragmentDoc = {"kind":"Document","definitions":[{"kind":"FragmentDefinition","name":{"kind":"Name","value":"CalendarEventPart"},"typeCondition":{"kind":"NamedType","name":{"kind":"Name","value":"GraphqlCalendarEvent"}},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"calendarEventId"}},{"kind":"Field","name":{"kind":"Name","value":"metadataId"}},{"kind":"Field","name":{"kind":"Name","value":"metadataTitle"}},{"kind":"Field","name":{"kind":"Name","value":"metadataLot"}},{"kind":"Field","name":{"kind":"Name","value":"metadataImage"}},{"kind":"Field","name":{"kind":"Name","value":"date"}},{"kind":"Field","name":{"kind":"Name","value":"showSeasonNumber"}},{"kind":"Field","name":{"kind":"Name","value":"showEpisodeNumber"}},{"kind":"Field","name":{"kind":"Name","value":"podcastEpisodeNumber"}}]}}]} as unknown as DocumentNode<CalendarEventPartFragment, unknown>;
-export const SeenPartFragmentDoc = {"kind":"Document","definitions":[{"kind":"FragmentDefinition","name":{"kind":"Name","value":"SeenPart"},"typeCondition":{"kind":"NamedType","name":{"kind":"Name","value":"Seen"}},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"progress"}},{"kind":"Field","name":{"kind":"Name","value":"state"}},{"kind":"Field","name":{"kind":"Name","value":"startedOn"}},{"kind":"Field","name":{"kind":"Name","value":"finishedOn"}},{"kind":"Field","name":{"kind":"Name","value":"lastUpdatedOn"}},{"kind":"Field","name":{"kind":"Name","value":"numTimesUpdated"}},{"kind":"Field","name":{"kind":"Name","value":"showInformation"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episode"}},{"kind":"Field","name":{"kind":"Name","value":"season"}}]}},{"kind":"Field","name":{"kind":"Name","value":"podcastInformation"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episode"}}]}}]}}]} as unknown as DocumentNode<SeenPartFragment, unknown>;
+export const SeenPartFragmentDoc = {"kind":"Document","definitions":[{"kind":"FragmentDefinition","name":{"kind":"Name","value":"SeenPart"},"typeCondition":{"kind":"NamedType","name":{"kind":"Name","value":"Seen"}},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"progress"}},{"kind":"Field","name":{"kind":"Name","value":"state"}},{"kind":"Field","name":{"kind":"Name","value":"startedOn"}},{"kind":"Field","name":{"kind":"Name","value":"finishedOn"}},{"kind":"Field","name":{"kind":"Name","value":"lastUpdatedOn"}},{"kind":"Field","name":{"kind":"Name","value":"numTimesUpdated"}},{"kind":"Field","name":{"kind":"Name","value":"rating"}},{"kind":"Field","name":{"kind":"Name","value":"context"}},{"kind":"Field","name":{"kind":"Name","value":"showInformation"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episode"}},{"kind":"Field","name":{"kind":"Name","value":"season"}}]}},{"kind":"Field","name":{"kind":"Name","value":"podcastInformation"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episode"}}]}},{"kind":"Field","name":{"kind":"Name","value":"attachments"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"url"}}]}},{"kind":"Field","name":{"kind":"Name","value":"companions"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"userId"}},{"kind":"Field","name":{"kind":"Name","value":"mirroredSeenId"}}]}}]}}]} as unknown as DocumentNode<SeenPartFragment, unknown>;
 export const AddMediaToCollectionDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"AddMediaToCollection"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"AddMediaToCollection"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"addMediaToCollection"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}]}]}}]} as unknown as DocumentNode<AddMediaToCollectionMutation, AddMediaToCollectionMutationVariables>;
+export const AddSeenAttachmentDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"AddSeenAttachment"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"AddSeenAttachmentInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"addSeenAttachment"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}}]}}]}}]} as unknown as DocumentNode<AddSeenAttachmentMutation, AddSeenAttachmentMutationVariables>;
+export const AddSeenCompanionDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"AddSeenCompanion"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"AddSeenCompanionInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"addSeenCompanion"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}}]}}]}}]} as unknown as DocumentNode<AddSeenCompanionMutation, AddSeenCompanionMutationVariables>;
 export const BulkProgressUpdateDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"BulkProgressUpdate"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"ListType","type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"ProgressUpdateInput"}}}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"bulkProgressUpdate"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}]}]}}]} as unknown as DocumentNode<BulkProgressUpdateMutation, BulkProgressUpdateMutationVariables>;
 export const CommitMediaDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"CommitMedia"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"lot"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"MetadataLot"}}}},{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"source"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"MetadataSource"}}}},{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"identifier"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"String"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"commitMedia"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"lot"},"value":{"kind":"Variable","name":{"kind":"Name","value":"lot"}}},{"kind":"Argument","name":{"kind":"Name","value":"source"},"value":{"kind":"Variable","name":{"kind":"Name","value":"source"}}},{"kind":"Argument","name":{"kind":"Name","value":"identifier"},"value":{"kind":"Variable","name":{"kind":"Name","value":"identifier"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}}]}}]}}]} as unknown as DocumentNode<CommitMediaMutation, CommitMediaMutationVariables>;
 export const CreateCustomMediaDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"CreateCustomMedia"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"CreateCustomMediaInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"createCustomMedia"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"__typename"}},{"kind":"InlineFragment","typeCondition":{"kind":"NamedType","name":{"kind":"Name","value":"IdObject"}},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}}]}},{"kind":"InlineFragment","typeCondition":{"kind":"NamedType","name":{"kind":"Name","value":"CreateCustomMediaError"}},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"error"}}]}}]}}]}}]} as unknown as DocumentNode<CreateCustomMediaMutation, CreateCustomMediaMutationVariables>;
 export const CreateMediaReminderDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"CreateMediaReminder"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"CreateMediaReminderInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"createMediaReminder"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}]}]}}]} as unknown as DocumentNode<CreateMediaReminderMutation, CreateMediaReminderMutationVariables>;
 export const CreateOrUpdateCollectionDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"CreateOrUpdateCollection"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"CreateOrUpdateCollectionInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"createOrUpdateCollection"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}}]}}]}}]} as unknown as DocumentNode<CreateOrUpdateCollectionMutation, CreateOrUpdateCollectionMutationVariables>;
+export const CreateOrUpdateHighlightDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"CreateOrUpdateHighlight"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"CreateOrUpdateHighlightInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"createOrUpdateHighlight"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}}]}}]}}]} as unknown as DocumentNode<CreateOrUpdateHighlightMutation, CreateOrUpdateHighlightMutationVariables>;
 export const CreateReviewCommentDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"CreateReviewComment"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"CreateReviewCommentInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"createReviewComment"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}]}]}}]} as unknown as DocumentNode<CreateReviewCommentMutation, CreateReviewCommentMutationVariables>;
 export const CreateUserMeasurementDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"CreateUserMeasurement"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"UserMeasurementInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"createUserMeasurement"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}]}]}}]} as unknown as DocumentNode<CreateUserMeasurementMutation, CreateUserMeasurementMutationVariables>;
 export const CreateUserNotificationPlatformDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"CreateUserNotificationPlatform"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"CreateUserNotificationPlatformInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"createUserNotificationPlatform"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}]}]}}]} as unknown as DocumentNode<CreateUserNotificationPlatformMutation, CreateUserNotificationPlatformMutationVariables>;
@@ -2315,8 +3375,11 @@ export const CreateUserSinkIntegrationDocument = {"kind":"Document","definitions
 export const CreateUserWorkoutDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"CreateUserWorkout"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"UserWorkoutInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"createUserWorkout"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}]}]}}]} as unknown as DocumentNode<CreateUserWorkoutMutation, CreateUserWorkoutMutationVariables>;
 export const CreateUserYankIntegrationDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"CreateUserYankIntegration"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"CreateUserYankIntegrationInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"createUserYankIntegration"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}]}]}}]} as unknown as DocumentNode<CreateUserYankIntegrationMutation, CreateUserYankIntegrationMutationVariables>;
 export const DeleteCollectionDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"DeleteCollection"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"collectionName"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"String"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"deleteCollection"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"collectionName"},"value":{"kind":"Variable","name":{"kind":"Name","value":"collectionName"}}}]}]}}]} as unknown as DocumentNode<DeleteCollectionMutation, DeleteCollectionMutationVariables>;
+export const DeleteHighlightDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"DeleteHighlight"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"highlightId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"deleteHighlight"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"highlightId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"highlightId"}}}]}]}}]} as unknown as DocumentNode<DeleteHighlightMutation, DeleteHighlightMutationVariables>;
 export const DeleteMediaReminderDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"DeleteMediaReminder"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"deleteMediaReminder"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"metadataId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}}}]}]}}]} as unknown as DocumentNode<DeleteMediaReminderMutation, DeleteMediaReminderMutationVariables>;
 export const DeleteReviewDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"DeleteReview"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"reviewId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"deleteReview"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"reviewId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"reviewId"}}}]}]}}]} as unknown as DocumentNode<DeleteReviewMutation, DeleteReviewMutationVariables>;
+export const DeleteSeenAttachmentDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"DeleteSeenAttachment"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"attachmentId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"deleteSeenAttachment"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"attachmentId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"attachmentId"}}}]}]}}]} as unknown as DocumentNode<DeleteSeenAttachmentMutation, DeleteSeenAttachmentMutationVariables>;
+export const DeleteSeenCompanionDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"DeleteSeenCompanion"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"companionId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"deleteSeenCompanion"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"companionId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"companionId"}}}]}]}}]} as unknown as DocumentNode<DeleteSeenCompanionMutation, DeleteSeenCompanionMutationVariables>;
 export const DeleteSeenItemDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"DeleteSeenItem"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"seenId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"deleteSeenItem"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"seenId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"seenId"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}}]}}]}}]} as unknown as DocumentNode<DeleteSeenItemMutation, DeleteSeenItemMutationVariables>;
 export const DeleteUserDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"DeleteUser"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"toDeleteUserId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"deleteUser"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"toDeleteUserId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"toDeleteUserId"}}}]}]}}]} as unknown as DocumentNode<DeleteUserMutation, DeleteUserMutationVariables>;
 export const DeleteUserIntegrationDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"DeleteUserIntegration"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"integrationId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}},{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"integrationLot"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"UserIntegrationLot"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"deleteUserIntegration"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"integrationId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"integrationId"}}},{"kind":"Argument","name":{"kind":"Name","value":"integrationLot"},"value":{"kind":"Variable","name":{"kind":"Name","value":"integrationLot"}}}]}]}}]} as unknown as DocumentNode<DeleteUserIntegrationMutation, DeleteUserIntegrationMutationVariables>;
@@ -2326,6 +3389,7 @@ export const DeployImportJobDocument = {"kind":"Document","definitions":[{"kind"
 export const DeployRecalculateCalendarEventsJobDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"DeployRecalculateCalendarEventsJob"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"deployRecalculateCalendarEventsJob"}}]}}]} as unknown as DocumentNode<DeployRecalculateCalendarEventsJobMutation, DeployRecalculateCalendarEventsJobMutationVariables>;
 export const DeployUpdateMetadataJobDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"DeployUpdateMetadataJob"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"deployUpdateMetadataJob"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"metadataId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}}}]}]}}]} as unknown as DocumentNode<DeployUpdateMetadataJobMutation, DeployUpdateMetadataJobMutationVariables>;
 export const GenerateAuthTokenDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"GenerateAuthToken"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"generateAuthToken"}}]}}]} as unknown as DocumentNode<GenerateAuthTokenMutation, GenerateAuthTokenMutationVariables>;
+export const LinkKoreaderDocumentDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"LinkKoreaderDocument"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"documentId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}},{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"linkKoreaderDocument"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"documentId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"documentId"}}},{"kind":"Argument","name":{"kind":"Name","value":"metadataId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}}}]}]}}]} as unknown as DocumentNode<LinkKoreaderDocumentMutation, LinkKoreaderDocumentMutationVariables>;
 export const LoginUserDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"LoginUser"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"UserInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"loginUser"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"__typename"}},{"kind":"InlineFragment","typeCondition":{"kind":"NamedType","name":{"kind":"Name","value":"LoginError"}},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"error"}}]}},{"kind":"InlineFragment","typeCondition":{"kind":"NamedType","name":{"kind":"Name","value":"LoginResponse"}},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"apiKey"}}]}}]}}]}}]} as unknown as DocumentNode<LoginUserMutation, LoginUserMutationVariables>;
 export const LogoutUserDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"LogoutUser"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"logoutUser"}}]}}]} as unknown as DocumentNode<LogoutUserMutation, LogoutUserMutationVariables>;
 export const MergeMetadataDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"MergeMetadata"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"mergeFrom"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}},{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"mergeInto"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"mergeMetadata"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"mergeFrom"},"value":{"kind":"Variable","name":{"kind":"Name","value":"mergeFrom"}}},{"kind":"Argument","name":{"kind":"Name","value":"mergeInto"},"value":{"kind":"Variable","name":{"kind":"Name","value":"mergeInto"}}}]}]}}]} as unknown as DocumentNode<MergeMetadataMutation, MergeMetadataMutationVariables>;
@@ -2335,40 +3399,50 @@ export const ProgressUpdateDocument = {"kind":"Document","definitions":[{"kind":
 export const RegenerateUserSummaryDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"RegenerateUserSummary"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"regenerateUserSummary"}}]}}]} as unknown as DocumentNode<RegenerateUserSummaryMutation, RegenerateUserSummaryMutationVariables>;
 export const RegisterUserDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"RegisterUser"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"UserInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"registerUser"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"__typename"}},{"kind":"InlineFragment","typeCondition":{"kind":"NamedType","name":{"kind":"Name","value":"RegisterError"}},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"error"}}]}},{"kind":"InlineFragment","typeCondition":{"kind":"NamedType","name":{"kind":"Name","value":"IdObject"}},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}}]}}]}}]}}]} as unknown as DocumentNode<RegisterUserMutation, RegisterUserMutationVariables>;
 export const RemoveMediaFromCollectionDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"RemoveMediaFromCollection"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}},{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"collectionName"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"String"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"removeMediaFromCollection"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"metadataId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}}},{"kind":"Argument","name":{"kind":"Name","value":"collectionName"},"value":{"kind":"Variable","name":{"kind":"Name","value":"collectionName"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}}]}}]}}]} as unknown as DocumentNode<RemoveMediaFromCollectionMutation, RemoveMediaFromCollectionMutationVariables>;
+export const RequestUserEmailVerificationDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"RequestUserEmailVerification"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"username"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"String"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"requestUserEmailVerification"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"username"},"value":{"kind":"Variable","name":{"kind":"Name","value":"username"}}}]}]}}]} as unknown as DocumentNode<RequestUserEmailVerificationMutation, RequestUserEmailVerificationMutationVariables>;
+export const RequestUserPasswordResetDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"RequestUserPasswordReset"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"username"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"String"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"requestUserPasswordReset"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"username"},"value":{"kind":"Variable","name":{"kind":"Name","value":"username"}}}]}]}}]} as unknown as DocumentNode<RequestUserPasswordResetMutation, RequestUserPasswordResetMutationVariables>;
+export const ResetUserPasswordDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"ResetUserPassword"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"ResetUserPasswordInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"resetUserPassword"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}]}]}}]} as unknown as DocumentNode<ResetUserPasswordMutation, ResetUserPasswordMutationVariables>;
+export const RespondToSeenCompanionDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"RespondToSeenCompanion"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"companionId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}},{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"accept"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Boolean"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"respondToSeenCompanion"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"companionId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"companionId"}}},{"kind":"Argument","name":{"kind":"Name","value":"accept"},"value":{"kind":"Variable","name":{"kind":"Name","value":"accept"}}}]}]}}]} as unknown as DocumentNode<RespondToSeenCompanionMutation, RespondToSeenCompanionMutationVariables>;
 export const TestUserNotificationPlatformsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"TestUserNotificationPlatforms"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"testUserNotificationPlatforms"}}]}}]} as unknown as DocumentNode<TestUserNotificationPlatformsMutation, TestUserNotificationPlatformsMutationVariables>;
+export const ToggleMediaFavoriteDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"ToggleMediaFavorite"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"toggleMediaFavorite"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"metadataId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}}}]}]}}]} as unknown as DocumentNode<ToggleMediaFavoriteMutation, ToggleMediaFavoriteMutationVariables>;
 export const ToggleMediaMonitorDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"ToggleMediaMonitor"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"toMonitorMetadataId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"toggleMediaMonitor"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"toMonitorMetadataId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"toMonitorMetadataId"}}}]}]}}]} as unknown as DocumentNode<ToggleMediaMonitorMutation, ToggleMediaMonitorMutationVariables>;
 export const UpdateAllMetadataDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"UpdateAllMetadata"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"updateAllMetadata"}}]}}]} as unknown as DocumentNode<UpdateAllMetadataMutation, UpdateAllMetadataMutationVariables>;
 export const UpdateUserDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"UpdateUser"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"UpdateUserInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"updateUser"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}}]}}]}}]} as unknown as DocumentNode<UpdateUserMutation, UpdateUserMutationVariables>;
 export const UpdateUserPreferenceDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"UpdateUserPreference"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"UpdateUserPreferenceInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"updateUserPreference"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}]}]}}]} as unknown as DocumentNode<UpdateUserPreferenceMutation, UpdateUserPreferenceMutationVariables>;
+export const VerifyUserEmailDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"VerifyUserEmail"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"token"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"String"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"verifyUserEmail"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"token"},"value":{"kind":"Variable","name":{"kind":"Name","value":"token"}}}]}]}}]} as unknown as DocumentNode<VerifyUserEmailMutation, VerifyUserEmailMutationVariables>;
 export const YankIntegrationDataDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"mutation","name":{"kind":"Name","value":"YankIntegrationData"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"yankIntegrationData"}}]}}]} as unknown as DocumentNode<YankIntegrationDataMutation, YankIntegrationDataMutationVariables>;
+export const AnnouncementsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"Announcements"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"announcements"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"message"}},{"kind":"Field","name":{"kind":"Name","value":"severity"}},{"kind":"Field","name":{"kind":"Name","value":"createdOn"}},{"kind":"Field","name":{"kind":"Name","value":"expiresOn"}}]}}]}}]} as unknown as DocumentNode<AnnouncementsQuery, AnnouncementsQueryVariables>;
 export const CollectionContentsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"CollectionContents"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"CollectionContentsInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"collectionContents"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"user"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"name"}}]}},{"kind":"Field","name":{"kind":"Name","value":"results"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"details"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"total"}},{"kind":"Field","name":{"kind":"Name","value":"nextPage"}}]}},{"kind":"Field","name":{"kind":"Name","value":"items"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"details"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"identifier"}},{"kind":"Field","name":{"kind":"Name","value":"title"}},{"kind":"Field","name":{"kind":"Name","value":"image"}},{"kind":"Field","name":{"kind":"Name","value":"publishYear"}}]}}]}}]}},{"kind":"Field","name":{"kind":"Name","value":"details"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"description"}},{"kind":"Field","name":{"kind":"Name","value":"visibility"}},{"kind":"Field","name":{"kind":"Name","value":"createdOn"}}]}}]}}]}}]} as unknown as DocumentNode<CollectionContentsQuery, CollectionContentsQueryVariables>;
-export const CollectionsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"Collections"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NamedType","name":{"kind":"Name","value":"CollectionInput"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"collections"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"description"}},{"kind":"Field","name":{"kind":"Name","value":"visibility"}},{"kind":"Field","name":{"kind":"Name","value":"numItems"}}]}}]}}]} as unknown as DocumentNode<CollectionsQuery, CollectionsQueryVariables>;
+export const CollectionsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"Collections"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NamedType","name":{"kind":"Name","value":"CollectionInput"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"collections"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"description"}},{"kind":"Field","name":{"kind":"Name","value":"visibility"}},{"kind":"Field","name":{"kind":"Name","value":"numItems"}},{"kind":"Field","name":{"kind":"Name","value":"actions"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"collectionName"}}]}}]}}]}}]} as unknown as DocumentNode<CollectionsQuery, CollectionsQueryVariables>;
 export const CoreDetailsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"CoreDetails"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"coreDetails"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"version"}},{"kind":"Field","name":{"kind":"Name","value":"authorName"}},{"kind":"Field","name":{"kind":"Name","value":"repositoryLink"}},{"kind":"Field","name":{"kind":"Name","value":"docsLink"}},{"kind":"Field","name":{"kind":"Name","value":"defaultCredentials"}},{"kind":"Field","name":{"kind":"Name","value":"passwordChangeAllowed"}},{"kind":"Field","name":{"kind":"Name","value":"preferencesChangeAllowed"}},{"kind":"Field","name":{"kind":"Name","value":"usernameChangeAllowed"}},{"kind":"Field","name":{"kind":"Name","value":"itemDetailsHeight"}},{"kind":"Field","name":{"kind":"Name","value":"reviewsDisabled"}},{"kind":"Field","name":{"kind":"Name","value":"videosDisabled"}},{"kind":"Field","name":{"kind":"Name","value":"upgrade"}},{"kind":"Field","name":{"kind":"Name","value":"pageLimit"}},{"kind":"Field","name":{"kind":"Name","value":"deployAdminJobsAllowed"}}]}}]}}]} as unknown as DocumentNode<CoreDetailsQuery, CoreDetailsQueryVariables>;
-export const CoreEnabledFeaturesDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"CoreEnabledFeatures"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"coreEnabledFeatures"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"fileStorage"}},{"kind":"Field","name":{"kind":"Name","value":"signupAllowed"}}]}}]}}]} as unknown as DocumentNode<CoreEnabledFeaturesQuery, CoreEnabledFeaturesQueryVariables>;
+export const CoreEnabledFeaturesDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"CoreEnabledFeatures"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"coreEnabledFeatures"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"fileStorage"}},{"kind":"Field","name":{"kind":"Name","value":"signupAllowed"}},{"kind":"Field","name":{"kind":"Name","value":"passwordResetAllowed"}},{"kind":"Field","name":{"kind":"Name","value":"emailVerificationRequired"}}]}}]}}]} as unknown as DocumentNode<CoreEnabledFeaturesQuery, CoreEnabledFeaturesQueryVariables>;
 export const CreatorDetailsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"CreatorDetails"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"creatorId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"creatorDetails"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"creatorId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"creatorId"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"details"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"image"}}]}},{"kind":"Field","name":{"kind":"Name","value":"contents"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"items"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"metadataId"}},{"kind":"Field","name":{"kind":"Name","value":"title"}},{"kind":"Field","name":{"kind":"Name","value":"image"}}]}}]}}]}}]}}]} as unknown as DocumentNode<CreatorDetailsQuery, CreatorDetailsQueryVariables>;
 export const CreatorsListDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"CreatorsList"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"SearchInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"creatorsList"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"details"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"total"}},{"kind":"Field","name":{"kind":"Name","value":"nextPage"}}]}},{"kind":"Field","name":{"kind":"Name","value":"items"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"image"}},{"kind":"Field","name":{"kind":"Name","value":"mediaCount"}}]}}]}}]}}]} as unknown as DocumentNode<CreatorsListQuery, CreatorsListQueryVariables>;
 export const ExerciseDetailsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"ExerciseDetails"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"exerciseId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"exerciseDetails"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"exerciseId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"exerciseId"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"level"}},{"kind":"Field","name":{"kind":"Name","value":"force"}},{"kind":"Field","name":{"kind":"Name","value":"mechanic"}},{"kind":"Field","name":{"kind":"Name","value":"equipment"}},{"kind":"Field","name":{"kind":"Name","value":"attributes"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"instructions"}},{"kind":"Field","name":{"kind":"Name","value":"images"}},{"kind":"Field","name":{"kind":"Name","value":"muscles"}}]}}]}}]}}]} as unknown as DocumentNode<ExerciseDetailsQuery, ExerciseDetailsQueryVariables>;
 export const ExerciseParametersDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"ExerciseParameters"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"exerciseParameters"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"filters"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"type"}},{"kind":"Field","name":{"kind":"Name","value":"level"}},{"kind":"Field","name":{"kind":"Name","value":"force"}},{"kind":"Field","name":{"kind":"Name","value":"mechanic"}},{"kind":"Field","name":{"kind":"Name","value":"equipment"}},{"kind":"Field","name":{"kind":"Name","value":"muscle"}}]}},{"kind":"Field","name":{"kind":"Name","value":"downloadRequired"}}]}}]}}]} as unknown as DocumentNode<ExerciseParametersQuery, ExerciseParametersQueryVariables>;
 export const ExercisesListDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"ExercisesList"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"ExercisesListInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"exercisesList"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"details"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"total"}},{"kind":"Field","name":{"kind":"Name","value":"nextPage"}}]}},{"kind":"Field","name":{"kind":"Name","value":"items"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"attributes"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"images"}},{"kind":"Field","name":{"kind":"Name","value":"muscles"}}]}}]}}]}}]}}]} as unknown as DocumentNode<ExercisesListQuery, ExercisesListQueryVariables>;
 export const GetPresignedUrlDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"GetPresignedUrl"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"key"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"String"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"getPresignedUrl"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"key"},"value":{"kind":"Variable","name":{"kind":"Name","value":"key"}}}]}]}}]} as unknown as DocumentNode<GetPresignedUrlQuery, GetPresignedUrlQueryVariables>;
+export const HighlightsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"Highlights"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}},"type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"highlights"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"metadataId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"metadataId"}},{"kind":"Field","name":{"kind":"Name","value":"text"}},{"kind":"Field","name":{"kind":"Name","value":"page"}},{"kind":"Field","name":{"kind":"Name","value":"chapter"}},{"kind":"Field","name":{"kind":"Name","value":"note"}}]}}]}}]} as unknown as DocumentNode<HighlightsQuery, HighlightsQueryVariables>;
 export const ImportReportsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"ImportReports"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"importReports"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"source"}},{"kind":"Field","name":{"kind":"Name","value":"startedOn"}},{"kind":"Field","name":{"kind":"Name","value":"finishedOn"}},{"kind":"Field","name":{"kind":"Name","value":"success"}},{"kind":"Field","name":{"kind":"Name","value":"details"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"import"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"total"}}]}},{"kind":"Field","name":{"kind":"Name","value":"failedItems"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"step"}},{"kind":"Field","name":{"kind":"Name","value":"identifier"}},{"kind":"Field","name":{"kind":"Name","value":"error"}}]}}]}}]}}]}}]} as unknown as DocumentNode<ImportReportsQuery, ImportReportsQueryVariables>;
-export const LatestUserSummaryDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"LatestUserSummary"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"latestUserSummary"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"calculatedOn"}},{"kind":"Field","name":{"kind":"Name","value":"fitness"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"measurementsRecorded"}},{"kind":"Field","name":{"kind":"Name","value":"workoutsRecorded"}}]}},{"kind":"Field","name":{"kind":"Name","value":"media"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"reviewsPosted"}},{"kind":"Field","name":{"kind":"Name","value":"creatorsInteractedWith"}},{"kind":"Field","name":{"kind":"Name","value":"manga"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"chapters"}},{"kind":"Field","name":{"kind":"Name","value":"read"}}]}},{"kind":"Field","name":{"kind":"Name","value":"books"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"pages"}},{"kind":"Field","name":{"kind":"Name","value":"read"}}]}},{"kind":"Field","name":{"kind":"Name","value":"movies"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"runtime"}},{"kind":"Field","name":{"kind":"Name","value":"watched"}}]}},{"kind":"Field","name":{"kind":"Name","value":"anime"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episodes"}},{"kind":"Field","name":{"kind":"Name","value":"watched"}}]}},{"kind":"Field","name":{"kind":"Name","value":"podcasts"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"runtime"}},{"kind":"Field","name":{"kind":"Name","value":"played"}},{"kind":"Field","name":{"kind":"Name","value":"playedEpisodes"}}]}},{"kind":"Field","name":{"kind":"Name","value":"visualNovels"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"played"}},{"kind":"Field","name":{"kind":"Name","value":"runtime"}}]}},{"kind":"Field","name":{"kind":"Name","value":"videoGames"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"played"}}]}},{"kind":"Field","name":{"kind":"Name","value":"shows"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"runtime"}},{"kind":"Field","name":{"kind":"Name","value":"watchedEpisodes"}},{"kind":"Field","name":{"kind":"Name","value":"watchedSeasons"}},{"kind":"Field","name":{"kind":"Name","value":"watched"}}]}},{"kind":"Field","name":{"kind":"Name","value":"audioBooks"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"runtime"}},{"kind":"Field","name":{"kind":"Name","value":"played"}}]}}]}}]}}]}}]} as unknown as DocumentNode<LatestUserSummaryQuery, LatestUserSummaryQueryVariables>;
-export const MediaDetailsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"MediaDetails"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"mediaDetails"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"metadataId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"title"}},{"kind":"Field","name":{"kind":"Name","value":"description"}},{"kind":"Field","name":{"kind":"Name","value":"identifier"}},{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"source"}},{"kind":"Field","name":{"kind":"Name","value":"isNsfw"}},{"kind":"Field","name":{"kind":"Name","value":"creators"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"items"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"image"}}]}}]}},{"kind":"Field","name":{"kind":"Name","value":"assets"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"images"}},{"kind":"Field","name":{"kind":"Name","value":"videos"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"videoId"}},{"kind":"Field","name":{"kind":"Name","value":"source"}}]}}]}},{"kind":"Field","name":{"kind":"Name","value":"providerRating"}},{"kind":"Field","name":{"kind":"Name","value":"publishYear"}},{"kind":"Field","name":{"kind":"Name","value":"publishDate"}},{"kind":"Field","name":{"kind":"Name","value":"genres"}},{"kind":"Field","name":{"kind":"Name","value":"sourceUrl"}},{"kind":"Field","name":{"kind":"Name","value":"group"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"part"}}]}},{"kind":"Field","name":{"kind":"Name","value":"suggestions"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"source"}},{"kind":"Field","name":{"kind":"Name","value":"identifier"}},{"kind":"Field","name":{"kind":"Name","value":"title"}},{"kind":"Field","name":{"kind":"Name","value":"image"}},{"kind":"Field","name":{"kind":"Name","value":"metadataId"}}]}},{"kind":"Field","name":{"kind":"Name","value":"animeSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episodes"}}]}},{"kind":"Field","name":{"kind":"Name","value":"audioBookSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"runtime"}}]}},{"kind":"Field","name":{"kind":"Name","value":"bookSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"pages"}}]}},{"kind":"Field","name":{"kind":"Name","value":"movieSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"runtime"}}]}},{"kind":"Field","name":{"kind":"Name","value":"mangaSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"volumes"}},{"kind":"Field","name":{"kind":"Name","value":"chapters"}}]}},{"kind":"Field","name":{"kind":"Name","value":"podcastSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episodes"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"title"}},{"kind":"Field","name":{"kind":"Name","value":"overview"}},{"kind":"Field","name":{"kind":"Name","value":"thumbnail"}},{"kind":"Field","name":{"kind":"Name","value":"number"}},{"kind":"Field","name":{"kind":"Name","value":"runtime"}},{"kind":"Field","name":{"kind":"Name","value":"publishDate"}}]}},{"kind":"Field","name":{"kind":"Name","value":"totalEpisodes"}}]}},{"kind":"Field","name":{"kind":"Name","value":"showSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"seasons"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"seasonNumber"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"overview"}},{"kind":"Field","name":{"kind":"Name","value":"backdropImages"}},{"kind":"Field","name":{"kind":"Name","value":"posterImages"}},{"kind":"Field","name":{"kind":"Name","value":"episodes"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"posterImages"}},{"kind":"Field","name":{"kind":"Name","value":"episodeNumber"}},{"kind":"Field","name":{"kind":"Name","value":"publishDate"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"overview"}},{"kind":"Field","name":{"kind":"Name","value":"runtime"}}]}}]}}]}},{"kind":"Field","name":{"kind":"Name","value":"visualNovelSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"length"}}]}},{"kind":"Field","name":{"kind":"Name","value":"videoGameSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"platforms"}}]}}]}}]}}]} as unknown as DocumentNode<MediaDetailsQuery, MediaDetailsQueryVariables>;
+export const KoreaderDocumentsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"KoreaderDocuments"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"koreaderDocuments"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"document"}},{"kind":"Field","name":{"kind":"Name","value":"metadataId"}},{"kind":"Field","name":{"kind":"Name","value":"percentage"}},{"kind":"Field","name":{"kind":"Name","value":"device"}},{"kind":"Field","name":{"kind":"Name","value":"updatedOn"}}]}}]}}]} as unknown as DocumentNode<KoreaderDocumentsQuery, KoreaderDocumentsQueryVariables>;
+export const LatestUserSummaryDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"LatestUserSummary"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"latestUserSummary"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"calculatedOn"}},{"kind":"Field","name":{"kind":"Name","value":"fitness"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"measurementsRecorded"}},{"kind":"Field","name":{"kind":"Name","value":"workoutsRecorded"}},{"kind":"Field","name":{"kind":"Name","value":"totalVolume"}},{"kind":"Field","name":{"kind":"Name","value":"totalDistance"}},{"kind":"Field","name":{"kind":"Name","value":"personalBestsAchieved"}}]}},{"kind":"Field","name":{"kind":"Name","value":"media"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"reviewsPosted"}},{"kind":"Field","name":{"kind":"Name","value":"creatorsInteractedWith"}},{"kind":"Field","name":{"kind":"Name","value":"favorites"}},{"kind":"Field","name":{"kind":"Name","value":"manga"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"chapters"}},{"kind":"Field","name":{"kind":"Name","value":"read"}}]}},{"kind":"Field","name":{"kind":"Name","value":"books"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"pages"}},{"kind":"Field","name":{"kind":"Name","value":"read"}},{"kind":"Field","name":{"kind":"Name","value":"runtime"}}]}},{"kind":"Field","name":{"kind":"Name","value":"movies"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"runtime"}},{"kind":"Field","name":{"kind":"Name","value":"watched"}}]}},{"kind":"Field","name":{"kind":"Name","value":"anime"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episodes"}},{"kind":"Field","name":{"kind":"Name","value":"watched"}}]}},{"kind":"Field","name":{"kind":"Name","value":"podcasts"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"runtime"}},{"kind":"Field","name":{"kind":"Name","value":"played"}},{"kind":"Field","name":{"kind":"Name","value":"playedEpisodes"}}]}},{"kind":"Field","name":{"kind":"Name","value":"visualNovels"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"played"}},{"kind":"Field","name":{"kind":"Name","value":"runtime"}}]}},{"kind":"Field","name":{"kind":"Name","value":"articles"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"read"}},{"kind":"Field","name":{"kind":"Name","value":"runtime"}}]}},{"kind":"Field","name":{"kind":"Name","value":"music"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"listened"}},{"kind":"Field","name":{"kind":"Name","value":"runtime"}}]}},{"kind":"Field","name":{"kind":"Name","value":"boardGames"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"played"}},{"kind":"Field","name":{"kind":"Name","value":"runtime"}}]}},{"kind":"Field","name":{"kind":"Name","value":"comics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"issues"}},{"kind":"Field","name":{"kind":"Name","value":"read"}}]}},{"kind":"Field","name":{"kind":"Name","value":"videoGames"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"played"}}]}},{"kind":"Field","name":{"kind":"Name","value":"shows"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"runtime"}},{"kind":"Field","name":{"kind":"Name","value":"watchedEpisodes"}},{"kind":"Field","name":{"kind":"Name","value":"watchedSeasons"}},{"kind":"Field","name":{"kind":"Name","value":"watched"}}]}},{"kind":"Field","name":{"kind":"Name","value":"audioBooks"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"runtime"}},{"kind":"Field","name":{"kind":"Name","value":"played"}}]}}]}}]}}]}}]} as unknown as DocumentNode<LatestUserSummaryQuery, LatestUserSummaryQueryVariables>;
+export const MediaDetailsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"MediaDetails"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"mediaDetails"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"metadataId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"title"}},{"kind":"Field","name":{"kind":"Name","value":"description"}},{"kind":"Field","name":{"kind":"Name","value":"identifier"}},{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"source"}},{"kind":"Field","name":{"kind":"Name","value":"isNsfw"}},{"kind":"Field","name":{"kind":"Name","value":"creators"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"items"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"image"}}]}}]}},{"kind":"Field","name":{"kind":"Name","value":"assets"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"images"}},{"kind":"Field","name":{"kind":"Name","value":"videos"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"videoId"}},{"kind":"Field","name":{"kind":"Name","value":"source"}}]}}]}},{"kind":"Field","name":{"kind":"Name","value":"providerRating"}},{"kind":"Field","name":{"kind":"Name","value":"publishYear"}},{"kind":"Field","name":{"kind":"Name","value":"publishDate"}},{"kind":"Field","name":{"kind":"Name","value":"genres"}},{"kind":"Field","name":{"kind":"Name","value":"sourceUrl"}},{"kind":"Field","name":{"kind":"Name","value":"group"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"part"}}]}},{"kind":"Field","name":{"kind":"Name","value":"suggestions"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"source"}},{"kind":"Field","name":{"kind":"Name","value":"identifier"}},{"kind":"Field","name":{"kind":"Name","value":"title"}},{"kind":"Field","name":{"kind":"Name","value":"image"}},{"kind":"Field","name":{"kind":"Name","value":"metadataId"}}]}},{"kind":"Field","name":{"kind":"Name","value":"animeSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episodes"}}]}},{"kind":"Field","name":{"kind":"Name","value":"audioBookSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"runtime"}}]}},{"kind":"Field","name":{"kind":"Name","value":"boardGameSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"minPlayers"}},{"kind":"Field","name":{"kind":"Name","value":"maxPlayers"}},{"kind":"Field","name":{"kind":"Name","value":"playtime"}}]}},{"kind":"Field","name":{"kind":"Name","value":"bookSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"pages"}}]}},{"kind":"Field","name":{"kind":"Name","value":"comicSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"issues"}}]}},{"kind":"Field","name":{"kind":"Name","value":"movieSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"runtime"}}]}},{"kind":"Field","name":{"kind":"Name","value":"mangaSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"volumes"}},{"kind":"Field","name":{"kind":"Name","value":"chapters"}}]}},{"kind":"Field","name":{"kind":"Name","value":"podcastSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episodes"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"title"}},{"kind":"Field","name":{"kind":"Name","value":"overview"}},{"kind":"Field","name":{"kind":"Name","value":"thumbnail"}},{"kind":"Field","name":{"kind":"Name","value":"number"}},{"kind":"Field","name":{"kind":"Name","value":"runtime"}},{"kind":"Field","name":{"kind":"Name","value":"publishDate"}}]}},{"kind":"Field","name":{"kind":"Name","value":"totalEpisodes"}}]}},{"kind":"Field","name":{"kind":"Name","value":"showSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"seasons"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"seasonNumber"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"overview"}},{"kind":"Field","name":{"kind":"Name","value":"backdropImages"}},{"kind":"Field","name":{"kind":"Name","value":"posterImages"}},{"kind":"Field","name":{"kind":"Name","value":"episodes"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"posterImages"}},{"kind":"Field","name":{"kind":"Name","value":"episodeNumber"}},{"kind":"Field","name":{"kind":"Name","value":"publishDate"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"overview"}},{"kind":"Field","name":{"kind":"Name","value":"runtime"}}]}}]}}]}},{"kind":"Field","name":{"kind":"Name","value":"articleSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"siteName"}},{"kind":"Field","name":{"kind":"Name","value":"words"}},{"kind":"Field","name":{"kind":"Name","value":"readingTime"}}]}},{"kind":"Field","name":{"kind":"Name","value":"musicSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"tracks"}},{"kind":"Field","name":{"kind":"Name","value":"duration"}}]}},{"kind":"Field","name":{"kind":"Name","value":"visualNovelSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"length"}}]}},{"kind":"Field","name":{"kind":"Name","value":"videoGameSpecifics"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"platforms"}},{"kind":"Field","name":{"kind":"Name","value":"achievements"}}]}}]}}]}}]} as unknown as DocumentNode<MediaDetailsQuery, MediaDetailsQueryVariables>;
 export const MediaListDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"MediaList"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"MediaListInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"mediaList"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"details"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"total"}},{"kind":"Field","name":{"kind":"Name","value":"nextPage"}}]}},{"kind":"Field","name":{"kind":"Name","value":"items"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"averageRating"}},{"kind":"Field","name":{"kind":"Name","value":"data"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"identifier"}},{"kind":"Field","name":{"kind":"Name","value":"title"}},{"kind":"Field","name":{"kind":"Name","value":"image"}},{"kind":"Field","name":{"kind":"Name","value":"publishYear"}}]}}]}}]}}]}}]} as unknown as DocumentNode<MediaListQuery, MediaListQueryVariables>;
 export const MediaSearchDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"MediaSearch"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"lot"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"MetadataLot"}}}},{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"source"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"MetadataSource"}}}},{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"SearchInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"mediaSearch"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"lot"},"value":{"kind":"Variable","name":{"kind":"Name","value":"lot"}}},{"kind":"Argument","name":{"kind":"Name","value":"source"},"value":{"kind":"Variable","name":{"kind":"Name","value":"source"}}},{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"details"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"total"}},{"kind":"Field","name":{"kind":"Name","value":"nextPage"}}]}},{"kind":"Field","name":{"kind":"Name","value":"items"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"databaseId"}},{"kind":"Field","name":{"kind":"Name","value":"item"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"identifier"}},{"kind":"Field","name":{"kind":"Name","value":"title"}},{"kind":"Field","name":{"kind":"Name","value":"image"}},{"kind":"Field","name":{"kind":"Name","value":"publishYear"}}]}}]}}]}}]}}]} as unknown as DocumentNode<MediaSearchQuery, MediaSearchQueryVariables>;
 export const MediaSourcesForLotDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"MediaSourcesForLot"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"lot"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"MetadataLot"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"mediaSourcesForLot"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"lot"},"value":{"kind":"Variable","name":{"kind":"Name","value":"lot"}}}]}]}}]} as unknown as DocumentNode<MediaSourcesForLotQuery, MediaSourcesForLotQueryVariables>;
 export const MetadataGroupDetailsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"MetadataGroupDetails"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"metadataGroupId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"metadataGroupDetails"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"metadataGroupId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"metadataGroupId"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"details"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"title"}},{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"source"}},{"kind":"Field","name":{"kind":"Name","value":"displayImages"}},{"kind":"Field","name":{"kind":"Name","value":"parts"}}]}},{"kind":"Field","name":{"kind":"Name","value":"sourceUrl"}},{"kind":"Field","name":{"kind":"Name","value":"contents"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"identifier"}},{"kind":"Field","name":{"kind":"Name","value":"title"}},{"kind":"Field","name":{"kind":"Name","value":"image"}},{"kind":"Field","name":{"kind":"Name","value":"metadataId"}},{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"source"}}]}}]}}]}}]} as unknown as DocumentNode<MetadataGroupDetailsQuery, MetadataGroupDetailsQueryVariables>;
 export const MetadataGroupsListDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"MetadataGroupsList"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"SearchInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"metadataGroupsList"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"details"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"total"}},{"kind":"Field","name":{"kind":"Name","value":"nextPage"}}]}},{"kind":"Field","name":{"kind":"Name","value":"items"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"title"}},{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"parts"}},{"kind":"Field","name":{"kind":"Name","value":"image"}}]}}]}}]}}]} as unknown as DocumentNode<MetadataGroupsListQuery, MetadataGroupsListQueryVariables>;
+export const PendingSeenCompanionsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"PendingSeenCompanions"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"pendingSeenCompanions"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"companionId"}},{"kind":"Field","name":{"kind":"Name","value":"taggedBy"}},{"kind":"Field","name":{"kind":"Name","value":"metadataId"}},{"kind":"Field","name":{"kind":"Name","value":"metadataTitle"}},{"kind":"Field","name":{"kind":"Name","value":"seen"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"state"}},{"kind":"Field","name":{"kind":"Name","value":"finishedOn"}},{"kind":"Field","name":{"kind":"Name","value":"showInformation"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episode"}},{"kind":"Field","name":{"kind":"Name","value":"season"}}]}},{"kind":"Field","name":{"kind":"Name","value":"podcastInformation"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episode"}}]}}]}}]}}]}}]} as unknown as DocumentNode<PendingSeenCompanionsQuery, PendingSeenCompanionsQueryVariables>;
 export const ProvidersLanguageInformationDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"ProvidersLanguageInformation"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"providersLanguageInformation"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"supported"}},{"kind":"Field","name":{"kind":"Name","value":"default"}},{"kind":"Field","name":{"kind":"Name","value":"source"}}]}}]}}]} as unknown as DocumentNode<ProvidersLanguageInformationQuery, ProvidersLanguageInformationQueryVariables>;
 export const ReviewDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"Review"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"reviewId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"review"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"reviewId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"reviewId"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"rating"}},{"kind":"Field","name":{"kind":"Name","value":"text"}},{"kind":"Field","name":{"kind":"Name","value":"visibility"}},{"kind":"Field","name":{"kind":"Name","value":"spoiler"}},{"kind":"Field","name":{"kind":"Name","value":"showSeason"}},{"kind":"Field","name":{"kind":"Name","value":"showEpisode"}},{"kind":"Field","name":{"kind":"Name","value":"podcastEpisode"}}]}}]}}]} as unknown as DocumentNode<ReviewQuery, ReviewQueryVariables>;
 export const UserCalendarEventsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"UserCalendarEvents"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"UserCalendarEventInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"userCalendarEvents"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"date"}},{"kind":"Field","name":{"kind":"Name","value":"events"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"FragmentSpread","name":{"kind":"Name","value":"CalendarEventPart"}}]}}]}}]}},{"kind":"FragmentDefinition","name":{"kind":"Name","value":"CalendarEventPart"},"typeCondition":{"kind":"NamedType","name":{"kind":"Name","value":"GraphqlCalendarEvent"}},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"calendarEventId"}},{"kind":"Field","name":{"kind":"Name","value":"metadataId"}},{"kind":"Field","name":{"kind":"Name","value":"metadataTitle"}},{"kind":"Field","name":{"kind":"Name","value":"metadataLot"}},{"kind":"Field","name":{"kind":"Name","value":"metadataImage"}},{"kind":"Field","name":{"kind":"Name","value":"date"}},{"kind":"Field","name":{"kind":"Name","value":"showSeasonNumber"}},{"kind":"Field","name":{"kind":"Name","value":"showEpisodeNumber"}},{"kind":"Field","name":{"kind":"Name","value":"podcastEpisodeNumber"}}]}}]} as unknown as DocumentNode<UserCalendarEventsQuery, UserCalendarEventsQueryVariables>;
 export const UserCreatorDetailsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"UserCreatorDetails"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"creatorId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"userCreatorDetails"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"creatorId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"creatorId"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"reviews"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"rating"}},{"kind":"Field","name":{"kind":"Name","value":"text"}},{"kind":"Field","name":{"kind":"Name","value":"spoiler"}},{"kind":"Field","name":{"kind":"Name","value":"visibility"}},{"kind":"Field","name":{"kind":"Name","value":"postedOn"}},{"kind":"Field","name":{"kind":"Name","value":"postedBy"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}}]}},{"kind":"Field","name":{"kind":"Name","value":"comments"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"text"}},{"kind":"Field","name":{"kind":"Name","value":"createdOn"}},{"kind":"Field","name":{"kind":"Name","value":"user"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}}]}},{"kind":"Field","name":{"kind":"Name","value":"likedBy"}}]}}]}}]}}]}}]} as unknown as DocumentNode<UserCreatorDetailsQuery, UserCreatorDetailsQueryVariables>;
 export const UserDetailsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"UserDetails"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"userDetails"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"__typename"}},{"kind":"InlineFragment","typeCondition":{"kind":"NamedType","name":{"kind":"Name","value":"User"}},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"email"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"lot"}}]}}]}}]}}]} as unknown as DocumentNode<UserDetailsQuery, UserDetailsQueryVariables>;
 export const UserExerciseDetailsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"UserExerciseDetails"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"exerciseId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"userExerciseDetails"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"exerciseId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"exerciseId"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"history"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"workoutId"}},{"kind":"Field","name":{"kind":"Name","value":"workoutName"}},{"kind":"Field","name":{"kind":"Name","value":"workoutTime"}},{"kind":"Field","name":{"kind":"Name","value":"sets"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"statistic"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"duration"}},{"kind":"Field","name":{"kind":"Name","value":"distance"}},{"kind":"Field","name":{"kind":"Name","value":"reps"}},{"kind":"Field","name":{"kind":"Name","value":"weight"}}]}}]}}]}},{"kind":"Field","name":{"kind":"Name","value":"details"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"exerciseId"}},{"kind":"Field","name":{"kind":"Name","value":"numTimesPerformed"}},{"kind":"Field","name":{"kind":"Name","value":"lastUpdatedOn"}},{"kind":"Field","name":{"kind":"Name","value":"extraInformation"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"lifetimeStats"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"weight"}},{"kind":"Field","name":{"kind":"Name","value":"reps"}},{"kind":"Field","name":{"kind":"Name","value":"distance"}},{"kind":"Field","name":{"kind":"Name","value":"duration"}}]}},{"kind":"Field","name":{"kind":"Name","value":"personalBests"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"sets"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"workoutId"}},{"kind":"Field","name":{"kind":"Name","value":"setIdx"}},{"kind":"Field","name":{"kind":"Name","value":"data"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"statistic"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"duration"}},{"kind":"Field","name":{"kind":"Name","value":"distance"}},{"kind":"Field","name":{"kind":"Name","value":"reps"}},{"kind":"Field","name":{"kind":"Name","value":"weight"}}]}},{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"personalBests"}}]}}]}}]}}]}}]}}]}}]}}]} as unknown as DocumentNode<UserExerciseDetailsQuery, UserExerciseDetailsQueryVariables>;
-export const UserIntegrationsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"UserIntegrations"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"userIntegrations"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"description"}},{"kind":"Field","name":{"kind":"Name","value":"timestamp"}},{"kind":"Field","name":{"kind":"Name","value":"slug"}}]}}]}}]} as unknown as DocumentNode<UserIntegrationsQuery, UserIntegrationsQueryVariables>;
+export const UserIntegrationsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"UserIntegrations"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"userIntegrations"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"lot"}},{"kind":"Field","name":{"kind":"Name","value":"description"}},{"kind":"Field","name":{"kind":"Name","value":"timestamp"}},{"kind":"Field","name":{"kind":"Name","value":"slug"}},{"kind":"Field","name":{"kind":"Name","value":"secret"}}]}}]}}]} as unknown as DocumentNode<UserIntegrationsQuery, UserIntegrationsQueryVariables>;
 export const UserMeasurementsListDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"UserMeasurementsList"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"UserMeasurementsListInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"userMeasurementsList"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"timestamp"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"comment"}},{"kind":"Field","name":{"kind":"Name","value":"stats"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"weight"}},{"kind":"Field","name":{"kind":"Name","value":"bodyMassIndex"}},{"kind":"Field","name":{"kind":"Name","value":"totalBodyWater"}},{"kind":"Field","name":{"kind":"Name","value":"muscle"}},{"kind":"Field","name":{"kind":"Name","value":"leanBodyMass"}},{"kind":"Field","name":{"kind":"Name","value":"bodyFat"}},{"kind":"Field","name":{"kind":"Name","value":"boneMass"}},{"kind":"Field","name":{"kind":"Name","value":"visceralFat"}},{"kind":"Field","name":{"kind":"Name","value":"waistCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"waistToHeightRatio"}},{"kind":"Field","name":{"kind":"Name","value":"hipCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"waistToHipRatio"}},{"kind":"Field","name":{"kind":"Name","value":"chestCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"thighCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"bicepsCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"neckCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"bodyFatCaliper"}},{"kind":"Field","name":{"kind":"Name","value":"chestSkinfold"}},{"kind":"Field","name":{"kind":"Name","value":"abdominalSkinfold"}},{"kind":"Field","name":{"kind":"Name","value":"thighSkinfold"}},{"kind":"Field","name":{"kind":"Name","value":"basalMetabolicRate"}},{"kind":"Field","name":{"kind":"Name","value":"totalDailyEnergyExpenditure"}},{"kind":"Field","name":{"kind":"Name","value":"calories"}},{"kind":"Field","name":{"kind":"Name","value":"custom"}}]}}]}}]}}]} as unknown as DocumentNode<UserMeasurementsListQuery, UserMeasurementsListQueryVariables>;
-export const UserMediaDetailsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"UserMediaDetails"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"userMediaDetails"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"metadataId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"collections"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}}]}},{"kind":"Field","name":{"kind":"Name","value":"inProgress"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"FragmentSpread","name":{"kind":"Name","value":"SeenPart"}}]}},{"kind":"Field","name":{"kind":"Name","value":"history"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"FragmentSpread","name":{"kind":"Name","value":"SeenPart"}}]}},{"kind":"Field","name":{"kind":"Name","value":"averageRating"}},{"kind":"Field","name":{"kind":"Name","value":"reviews"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"rating"}},{"kind":"Field","name":{"kind":"Name","value":"text"}},{"kind":"Field","name":{"kind":"Name","value":"spoiler"}},{"kind":"Field","name":{"kind":"Name","value":"visibility"}},{"kind":"Field","name":{"kind":"Name","value":"showSeason"}},{"kind":"Field","name":{"kind":"Name","value":"showEpisode"}},{"kind":"Field","name":{"kind":"Name","value":"podcastEpisode"}},{"kind":"Field","name":{"kind":"Name","value":"postedOn"}},{"kind":"Field","name":{"kind":"Name","value":"postedBy"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}}]}},{"kind":"Field","name":{"kind":"Name","value":"comments"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"text"}},{"kind":"Field","name":{"kind":"Name","value":"createdOn"}},{"kind":"Field","name":{"kind":"Name","value":"user"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}}]}},{"kind":"Field","name":{"kind":"Name","value":"likedBy"}}]}}]}},{"kind":"Field","name":{"kind":"Name","value":"reminder"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"remindOn"}},{"kind":"Field","name":{"kind":"Name","value":"message"}}]}},{"kind":"Field","name":{"kind":"Name","value":"isMonitored"}},{"kind":"Field","name":{"kind":"Name","value":"seenBy"}},{"kind":"Field","name":{"kind":"Name","value":"nextEpisode"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"seasonNumber"}},{"kind":"Field","name":{"kind":"Name","value":"episodeNumber"}}]}}]}}]}},{"kind":"FragmentDefinition","name":{"kind":"Name","value":"SeenPart"},"typeCondition":{"kind":"NamedType","name":{"kind":"Name","value":"Seen"}},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"progress"}},{"kind":"Field","name":{"kind":"Name","value":"state"}},{"kind":"Field","name":{"kind":"Name","value":"startedOn"}},{"kind":"Field","name":{"kind":"Name","value":"finishedOn"}},{"kind":"Field","name":{"kind":"Name","value":"lastUpdatedOn"}},{"kind":"Field","name":{"kind":"Name","value":"numTimesUpdated"}},{"kind":"Field","name":{"kind":"Name","value":"showInformation"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episode"}},{"kind":"Field","name":{"kind":"Name","value":"season"}}]}},{"kind":"Field","name":{"kind":"Name","value":"podcastInformation"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episode"}}]}}]}}]} as unknown as DocumentNode<UserMediaDetailsQuery, UserMediaDetailsQueryVariables>;
+export const UserMediaDetailsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"UserMediaDetails"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"Int"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"userMediaDetails"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"metadataId"},"value":{"kind":"Variable","name":{"kind":"Name","value":"metadataId"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"collections"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}}]}},{"kind":"Field","name":{"kind":"Name","value":"inProgress"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"FragmentSpread","name":{"kind":"Name","value":"SeenPart"}}]}},{"kind":"Field","name":{"kind":"Name","value":"history"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"FragmentSpread","name":{"kind":"Name","value":"SeenPart"}}]}},{"kind":"Field","name":{"kind":"Name","value":"averageRating"}},{"kind":"Field","name":{"kind":"Name","value":"averageSeenRating"}},{"kind":"Field","name":{"kind":"Name","value":"reviews"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"rating"}},{"kind":"Field","name":{"kind":"Name","value":"text"}},{"kind":"Field","name":{"kind":"Name","value":"spoiler"}},{"kind":"Field","name":{"kind":"Name","value":"visibility"}},{"kind":"Field","name":{"kind":"Name","value":"showSeason"}},{"kind":"Field","name":{"kind":"Name","value":"showEpisode"}},{"kind":"Field","name":{"kind":"Name","value":"podcastEpisode"}},{"kind":"Field","name":{"kind":"Name","value":"postedOn"}},{"kind":"Field","name":{"kind":"Name","value":"postedBy"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}}]}},{"kind":"Field","name":{"kind":"Name","value":"comments"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"text"}},{"kind":"Field","name":{"kind":"Name","value":"createdOn"}},{"kind":"Field","name":{"kind":"Name","value":"user"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}}]}},{"kind":"Field","name":{"kind":"Name","value":"likedBy"}}]}}]}},{"kind":"Field","name":{"kind":"Name","value":"reminder"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"remindOn"}},{"kind":"Field","name":{"kind":"Name","value":"message"}}]}},{"kind":"Field","name":{"kind":"Name","value":"isMonitored"}},{"kind":"Field","name":{"kind":"Name","value":"isFavorite"}},{"kind":"Field","name":{"kind":"Name","value":"seenBy"}},{"kind":"Field","name":{"kind":"Name","value":"playtime"}},{"kind":"Field","name":{"kind":"Name","value":"nextEpisode"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"seasonNumber"}},{"kind":"Field","name":{"kind":"Name","value":"episodeNumber"}}]}}]}}]}},{"kind":"FragmentDefinition","name":{"kind":"Name","value":"SeenPart"},"typeCondition":{"kind":"NamedType","name":{"kind":"Name","value":"Seen"}},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"progress"}},{"kind":"Field","name":{"kind":"Name","value":"state"}},{"kind":"Field","name":{"kind":"Name","value":"startedOn"}},{"kind":"Field","name":{"kind":"Name","value":"finishedOn"}},{"kind":"Field","name":{"kind":"Name","value":"lastUpdatedOn"}},{"kind":"Field","name":{"kind":"Name","value":"numTimesUpdated"}},{"kind":"Field","name":{"kind":"Name","value":"rating"}},{"kind":"Field","name":{"kind":"Name","value":"context"}},{"kind":"Field","name":{"kind":"Name","value":"showInformation"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episode"}},{"kind":"Field","name":{"kind":"Name","value":"season"}}]}},{"kind":"Field","name":{"kind":"Name","value":"podcastInformation"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episode"}}]}},{"kind":"Field","name":{"kind":"Name","value":"attachments"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"url"}}]}},{"kind":"Field","name":{"kind":"Name","value":"companions"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"userId"}},{"kind":"Field","name":{"kind":"Name","value":"mirroredSeenId"}}]}}]}}]} as unknown as DocumentNode<UserMediaDetailsQuery, UserMediaDetailsQueryVariables>;
 export const UserNotificationPlatformsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"UserNotificationPlatforms"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"userNotificationPlatforms"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"description"}},{"kind":"Field","name":{"kind":"Name","value":"timestamp"}}]}}]}}]} as unknown as DocumentNode<UserNotificationPlatformsQuery, UserNotificationPlatformsQueryVariables>;
-export const UserPreferencesDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"UserPreferences"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"userPreferences"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"general"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"reviewScale"}},{"kind":"Field","name":{"kind":"Name","value":"displayNsfw"}}]}},{"kind":"Field","name":{"kind":"Name","value":"fitness"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"measurements"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"custom"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"dataType"}}]}},{"kind":"Field","name":{"kind":"Name","value":"inbuilt"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"weight"}},{"kind":"Field","name":{"kind":"Name","value":"bodyMassIndex"}},{"kind":"Field","name":{"kind":"Name","value":"totalBodyWater"}},{"kind":"Field","name":{"kind":"Name","value":"muscle"}},{"kind":"Field","name":{"kind":"Name","value":"leanBodyMass"}},{"kind":"Field","name":{"kind":"Name","value":"bodyFat"}},{"kind":"Field","name":{"kind":"Name","value":"boneMass"}},{"kind":"Field","name":{"kind":"Name","value":"visceralFat"}},{"kind":"Field","name":{"kind":"Name","value":"waistCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"waistToHeightRatio"}},{"kind":"Field","name":{"kind":"Name","value":"hipCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"waistToHipRatio"}},{"kind":"Field","name":{"kind":"Name","value":"chestCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"thighCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"bicepsCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"neckCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"bodyFatCaliper"}},{"kind":"Field","name":{"kind":"Name","value":"chestSkinfold"}},{"kind":"Field","name":{"kind":"Name","value":"abdominalSkinfold"}},{"kind":"Field","name":{"kind":"Name","value":"thighSkinfold"}},{"kind":"Field","name":{"kind":"Name","value":"basalMetabolicRate"}},{"kind":"Field","name":{"kind":"Name","value":"totalDailyEnergyExpenditure"}},{"kind":"Field","name":{"kind":"Name","value":"calories"}}]}}]}},{"kind":"Field","name":{"kind":"Name","value":"exercises"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"saveHistory"}},{"kind":"Field","name":{"kind":"Name","value":"unitSystem"}}]}}]}},{"kind":"Field","name":{"kind":"Name","value":"notifications"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episodeReleased"}},{"kind":"Field","name":{"kind":"Name","value":"episodeNameChanged"}},{"kind":"Field","name":{"kind":"Name","value":"statusChanged"}},{"kind":"Field","name":{"kind":"Name","value":"releaseDateChanged"}},{"kind":"Field","name":{"kind":"Name","value":"numberOfSeasonsChanged"}},{"kind":"Field","name":{"kind":"Name","value":"numberOfChaptersOrEpisodesChanged"}}]}},{"kind":"Field","name":{"kind":"Name","value":"featuresEnabled"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"fitness"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"enabled"}},{"kind":"Field","name":{"kind":"Name","value":"measurements"}}]}},{"kind":"Field","name":{"kind":"Name","value":"media"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"enabled"}},{"kind":"Field","name":{"kind":"Name","value":"anime"}},{"kind":"Field","name":{"kind":"Name","value":"audioBook"}},{"kind":"Field","name":{"kind":"Name","value":"book"}},{"kind":"Field","name":{"kind":"Name","value":"manga"}},{"kind":"Field","name":{"kind":"Name","value":"movie"}},{"kind":"Field","name":{"kind":"Name","value":"podcast"}},{"kind":"Field","name":{"kind":"Name","value":"show"}},{"kind":"Field","name":{"kind":"Name","value":"videoGame"}},{"kind":"Field","name":{"kind":"Name","value":"visualNovel"}}]}}]}}]}}]}}]} as unknown as DocumentNode<UserPreferencesQuery, UserPreferencesQueryVariables>;
+export const UserPreferencesDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"UserPreferences"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"userPreferences"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"general"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"reviewScale"}},{"kind":"Field","name":{"kind":"Name","value":"nsfwDisplay"}}]}},{"kind":"Field","name":{"kind":"Name","value":"timeEstimation"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"podcastPlaybackSpeed"}},{"kind":"Field","name":{"kind":"Name","value":"defaultEpisodeRuntime"}},{"kind":"Field","name":{"kind":"Name","value":"pagesPerHour"}}]}},{"kind":"Field","name":{"kind":"Name","value":"fitness"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"measurements"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"custom"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"dataType"}}]}},{"kind":"Field","name":{"kind":"Name","value":"inbuilt"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"weight"}},{"kind":"Field","name":{"kind":"Name","value":"bodyMassIndex"}},{"kind":"Field","name":{"kind":"Name","value":"totalBodyWater"}},{"kind":"Field","name":{"kind":"Name","value":"muscle"}},{"kind":"Field","name":{"kind":"Name","value":"leanBodyMass"}},{"kind":"Field","name":{"kind":"Name","value":"bodyFat"}},{"kind":"Field","name":{"kind":"Name","value":"boneMass"}},{"kind":"Field","name":{"kind":"Name","value":"visceralFat"}},{"kind":"Field","name":{"kind":"Name","value":"waistCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"waistToHeightRatio"}},{"kind":"Field","name":{"kind":"Name","value":"hipCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"waistToHipRatio"}},{"kind":"Field","name":{"kind":"Name","value":"chestCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"thighCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"bicepsCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"neckCircumference"}},{"kind":"Field","name":{"kind":"Name","value":"bodyFatCaliper"}},{"kind":"Field","name":{"kind":"Name","value":"chestSkinfold"}},{"kind":"Field","name":{"kind":"Name","value":"abdominalSkinfold"}},{"kind":"Field","name":{"kind":"Name","value":"thighSkinfold"}},{"kind":"Field","name":{"kind":"Name","value":"basalMetabolicRate"}},{"kind":"Field","name":{"kind":"Name","value":"totalDailyEnergyExpenditure"}},{"kind":"Field","name":{"kind":"Name","value":"calories"}}]}}]}},{"kind":"Field","name":{"kind":"Name","value":"exercises"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"saveHistory"}},{"kind":"Field","name":{"kind":"Name","value":"unitSystem"}}]}},{"kind":"Field","name":{"kind":"Name","value":"equipment"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"plates"}},{"kind":"Field","name":{"kind":"Name","value":"bars"}},{"kind":"Field","name":{"kind":"Name","value":"dumbbellIncrement"}},{"kind":"Field","name":{"kind":"Name","value":"available"}}]}}]}},{"kind":"Field","name":{"kind":"Name","value":"notifications"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"episodeReleased"}},{"kind":"Field","name":{"kind":"Name","value":"episodeNameChanged"}},{"kind":"Field","name":{"kind":"Name","value":"statusChanged"}},{"kind":"Field","name":{"kind":"Name","value":"releaseDateChanged"}},{"kind":"Field","name":{"kind":"Name","value":"numberOfSeasonsChanged"}},{"kind":"Field","name":{"kind":"Name","value":"numberOfChaptersOrEpisodesChanged"}},{"kind":"Field","name":{"kind":"Name","value":"personalBestAchieved"}}]}},{"kind":"Field","name":{"kind":"Name","value":"featuresEnabled"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"fitness"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"enabled"}},{"kind":"Field","name":{"kind":"Name","value":"measurements"}}]}},{"kind":"Field","name":{"kind":"Name","value":"media"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"enabled"}},{"kind":"Field","name":{"kind":"Name","value":"anime"}},{"kind":"Field","name":{"kind":"Name","value":"article"}},{"kind":"Field","name":{"kind":"Name","value":"audioBook"}},{"kind":"Field","name":{"kind":"Name","value":"boardGame"}},{"kind":"Field","name":{"kind":"Name","value":"book"}},{"kind":"Field","name":{"kind":"Name","value":"comic"}},{"kind":"Field","name":{"kind":"Name","value":"manga"}},{"kind":"Field","name":{"kind":"Name","value":"movie"}},{"kind":"Field","name":{"kind":"Name","value":"music"}},{"kind":"Field","name":{"kind":"Name","value":"podcast"}},{"kind":"Field","name":{"kind":"Name","value":"show"}},{"kind":"Field","name":{"kind":"Name","value":"videoGame"}},{"kind":"Field","name":{"kind":"Name","value":"visualNovel"}}]}}]}},{"kind":"Field","name":{"kind":"Name","value":"seenContexts"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"anime"}},{"kind":"Field","name":{"kind":"Name","value":"article"}},{"kind":"Field","name":{"kind":"Name","value":"audioBook"}},{"kind":"Field","name":{"kind":"Name","value":"boardGame"}},{"kind":"Field","name":{"kind":"Name","value":"book"}},{"kind":"Field","name":{"kind":"Name","value":"comic"}},{"kind":"Field","name":{"kind":"Name","value":"manga"}},{"kind":"Field","name":{"kind":"Name","value":"movie"}},{"kind":"Field","name":{"kind":"Name","value":"music"}},{"kind":"Field","name":{"kind":"Name","value":"podcast"}},{"kind":"Field","name":{"kind":"Name","value":"show"}},{"kind":"Field","name":{"kind":"Name","value":"videoGame"}},{"kind":"Field","name":{"kind":"Name","value":"visualNovel"}}]}}]}}]}}]} as unknown as DocumentNode<UserPreferencesQuery, UserPreferencesQueryVariables>;
 export const UserUpcomingCalendarEventsDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"UserUpcomingCalendarEvents"},"variableDefinitions":[{"kind":"VariableDefinition","variable":{"kind":"Variable","name":{"kind":"Name","value":"input"}},"type":{"kind":"NonNullType","type":{"kind":"NamedType","name":{"kind":"Name","value":"UserUpcomingCalendarEventInput"}}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"userUpcomingCalendarEvents"},"arguments":[{"kind":"Argument","name":{"kind":"Name","value":"input"},"value":{"kind":"Variable","name":{"kind":"Name","value":"input"}}}],"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"FragmentSpread","name":{"kind":"Name","value":"CalendarEventPart"}}]}}]}},{"kind":"FragmentDefinition","name":{"kind":"Name","value":"CalendarEventPart"},"typeCondition":{"kind":"NamedType","name":{"kind":"Name","value":"GraphqlCalendarEvent"}},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"calendarEventId"}},{"kind":"Field","name":{"kind":"Name","value":"metadataId"}},{"kind":"Field","name":{"kind":"Name","value":"metadataTitle"}},{"kind":"Field","name":{"kind":"Name","value":"metadataLot"}},{"kind":"Field","name":{"kind":"Name","value":"metadataImage"}},{"kind":"Field","name":{"kind":"Name","value":"date"}},{"kind":"Field","name":{"kind":"Name","value":"showSeasonNumber"}},{"kind":"Field","name":{"kind":"Name","value":"showEpisodeNumber"}},{"kind":"Field","name":{"kind":"Name","value":"podcastEpisodeNumber"}}]}}]} as unknown as DocumentNode<UserUpcomingCalendarEventsQuery, UserUpcomingCalendarEventsQueryVariables>;
 export const UsersListDocument = {"kind":"Document","definitions":[{"kind":"OperationDefinition","operation":"query","name":{"kind":"Name","value":"UsersList"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"usersList"},"selectionSet":{"kind":"SelectionSet","selections":[{"kind":"Field","name":{"kind":"Name","value":"id"}},{"kind":"Field","name":{"kind":"Name","value":"name"}},{"kind":"Field","name":{"kind":"Name","value":"lot"}}]}}]}}]} as unknown as DocumentNode<UsersListQuery, UsersListQueryVariables>;
\ No newline at end of file